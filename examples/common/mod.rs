@@ -0,0 +1,101 @@
+//! Shared setup for the examples.
+//!
+//! Every example runs against testnet by default. With `SUI_EXAMPLE_MOCK=1` it runs against
+//! canned responses instead, so the examples can be exercised without a node or funds.
+#![allow(dead_code)]
+
+use std::sync::Arc;
+use sui_network_sdk::SuiClient;
+use sui_network_sdk::global::testnet;
+use sui_network_sdk::transport::MockTransport;
+use sui_network_sdk::types::{SuiClientConfig, SuiError};
+use sui_network_sdk::wallet::Wallet;
+
+/// address used as owner and recipient in mock mode
+pub const MOCK_ADDRESS: &str = "0x7d20dcdb2bca4f508ea9613994683eb4e76e9c4ed371169677c1be02aaf0b58e";
+
+/// whether the example runs against canned responses
+pub fn mock_mode() -> bool {
+    std::env::var("SUI_EXAMPLE_MOCK").is_ok_and(|v| v == "1")
+}
+
+/// testnet client, or a client answering from the mock transport in mock mode
+pub fn client(mock: MockTransport) -> SuiClient {
+    let config = SuiClientConfig {
+        rpc_url: testnet::RPC_URL.to_string(),
+        wss_url: testnet::WSS_URL.to_string(),
+        ..Default::default()
+    };
+    if mock_mode() {
+        SuiClient::new_with_transport(config, Arc::new(mock))
+    } else {
+        SuiClient::new(config)
+    }
+}
+
+/// wallet loaded from the base64 `SUI_PRIVATE_KEY`, or a fixed key in mock mode
+pub fn wallet() -> Result<Wallet, SuiError> {
+    if mock_mode() {
+        return Wallet::from_private_key(&[1u8; 32]);
+    }
+    let key = std::env::var("SUI_PRIVATE_KEY").map_err(|_| SuiError::InvalidPrivateKey)?;
+    Wallet::from_base64_private_key(&key)
+}
+
+/// environment variable, or the fallback in mock mode
+pub fn env_or_mock(name: &str, mock_value: &str) -> String {
+    if mock_mode() {
+        return mock_value.to_string();
+    }
+    std::env::var(name).unwrap_or_else(|_| panic!("{} is not set", name))
+}
+
+/// testnet explorer link of a transaction
+pub fn explorer_tx_url(digest: &str) -> String {
+    format!("https://suiscan.xyz/testnet/tx/{}", digest)
+}
+
+/// `suix_getCoins` page holding one SUI coin
+pub fn coins_fixture() -> serde_json::Value {
+    serde_json::json!({
+        "data": [{
+            "coinType": "0x2::sui::SUI",
+            "coinObjectId": "0x1a2b3c4d5e6f708192a3b4c5d6e7f8091a2b3c4d5e6f708192a3b4c5d6e7f809",
+            "version": "1187",
+            "digest": "8vHTUw1cTRc6tfv6f5BWq9vJjGmvBu8mX6x2Z1t8s5vd",
+            "balance": "5000000000",
+            "previousTransaction": "C4qkJDrMy4nsNsSx6cZXb2ANjzMNdojZBkKiyRCQALGx"
+        }],
+        "nextCursor": null,
+        "hasNextPage": false
+    })
+}
+
+/// transaction builder response
+pub fn tx_bytes_fixture() -> serde_json::Value {
+    serde_json::json!({
+        "txBytes": "AAACAAgAypo7AAAAAAAg",
+        "gas": [],
+        "inputObjects": []
+    })
+}
+
+/// successful transaction response creating the given objects
+pub fn transaction_fixture(digest: &str, created: serde_json::Value) -> serde_json::Value {
+    serde_json::json!({
+        "digest": digest,
+        "effects": {
+            "status": { "status": "success" },
+            "gasUsed": {
+                "computationCost": "1000000",
+                "storageCost": "1976000",
+                "storageRebate": "978120"
+            },
+            "transactionDigest": digest,
+            "created": created,
+            "mutated": [],
+            "deleted": []
+        },
+        "events": []
+    })
+}
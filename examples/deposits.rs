@@ -0,0 +1,89 @@
+//! Detect deposits to exchange addresses: credit each coin arrival once it is 2 checkpoints
+//! deep, keeping the scan position in a file so a restart continues where it stopped.
+//!
+//! ```shell
+//! SUI_DEPOSIT_ADDRESS=0x... cargo run --example deposits
+//! SUI_EXAMPLE_MOCK=1 cargo run --example deposits
+//! ```
+mod common;
+
+use sui_network_sdk::deposit::{DepositScanner, DepositSource, FileCursorStore};
+use sui_network_sdk::global::testnet;
+use sui_network_sdk::listener::SharedListener;
+use sui_network_sdk::transport::MockTransport;
+use sui_network_sdk::types::SuiError;
+
+/// checkpoints the tip must be past a deposit before it is credited
+const MIN_CONFIRMATIONS: u64 = 2;
+/// deposits credited before the example stops in mock mode
+const MOCK_DEPOSITS: usize = 2;
+
+#[tokio::main]
+async fn main() -> Result<(), SuiError> {
+    let address = common::env_or_mock("SUI_DEPOSIT_ADDRESS", common::MOCK_ADDRESS);
+    let client = common::client(mock(&address));
+    let source = if common::mock_mode() {
+        DepositSource::Polling
+    } else {
+        DepositSource::Listener(SharedListener::new(testnet::WSS_URL.to_string()))
+    };
+    let cursors = std::env::temp_dir().join("sui_deposit_cursors.json");
+    let mut scanner = DepositScanner::new(&client, source, &[&address], MIN_CONFIRMATIONS)?
+        .with_cursor_store(FileCursorStore::new(&cursors));
+
+    let mut credited = 0;
+    while !common::mock_mode() || credited < MOCK_DEPOSITS {
+        let deposit = scanner.next_deposit().await?;
+        // an exchange credits idempotently by (digest, address, coin type)
+        println!(
+            "checkpoint {}: {} {} to {} in {}",
+            deposit.checkpoint,
+            deposit.amount,
+            deposit.coin_type,
+            deposit.address,
+            common::explorer_tx_url(&deposit.digest)
+        );
+        credited += 1;
+    }
+    // mock runs start from scratch
+    let _ = std::fs::remove_file(cursors);
+    Ok(())
+}
+
+/// node with two deposits to the address, the second one not yet checkpointed at first
+fn mock(address: &str) -> MockTransport {
+    let deposit = |digest: &str, checkpoint: Option<&str>, amount: &str| {
+        serde_json::json!({
+            "digest": digest,
+            "checkpoint": checkpoint,
+            "balanceChanges": [{
+                "owner": { "AddressOwner": address },
+                "coinType": "0x2::sui::SUI",
+                "amount": amount
+            }]
+        })
+    };
+    let page = |data: Vec<serde_json::Value>| serde_json::json!({ "data": data, "nextCursor": null, "hasNextPage": false });
+    let first = "3Fc1yDqAxNpLCvVeC5nJcrzHRjTr1RZZiMZUe6ha9GSb";
+    let second = "C4qkJDrMy4nsNsSx6cZXb2ANjzMNdojZBkKiyRCQALGx";
+    MockTransport::new()
+        .with_result(
+            "sui_getLatestCheckpointSequenceNumber",
+            serde_json::json!("1020"),
+        )
+        .with_result(
+            "sui_getLatestCheckpointSequenceNumber",
+            serde_json::json!("1023"),
+        )
+        .with_result(
+            "suix_queryTransactionBlocks",
+            page(vec![
+                deposit(first, Some("1017"), "2500000000"),
+                deposit(second, None, "700000000"),
+            ]),
+        )
+        .with_result(
+            "suix_queryTransactionBlocks",
+            page(vec![deposit(second, Some("1021"), "700000000")]),
+        )
+}
@@ -0,0 +1,111 @@
+//! Publish the NFT package in `examples/move/nft` and mint one NFT from it.
+//!
+//! ```shell
+//! (cd examples/move/nft && sui move build)
+//! SUI_PRIVATE_KEY=<base64 key> \
+//! SUI_PACKAGE_BYTECODE_DIR=examples/move/nft/build/nft/bytecode_modules \
+//!     cargo run --example mint_nft
+//! SUI_EXAMPLE_MOCK=1 cargo run --example mint_nft
+//! ```
+mod common;
+
+use serde_json::Value;
+use sui_network_sdk::trade::Trade;
+use sui_network_sdk::transport::MockTransport;
+use sui_network_sdk::types::{Owner, SuiError, TransactionResponse};
+
+#[tokio::main]
+async fn main() -> Result<(), SuiError> {
+    let package_id = "0x5a0b2ce0e8a8e3ee1c4b1ab8ff8e1aa1c3c3ef0b2de1b1a9b2b1c0d0e0f0a1b2";
+    let nft_id = "0x9f8e7d6c5b4a39281706f5e4d3c2b1a09f8e7d6c5b4a39281706f5e4d3c2b1a0";
+    let mock = MockTransport::new()
+        .with_result("suix_getCoins", common::coins_fixture())
+        .with_result("unsafe_publish", common::tx_bytes_fixture())
+        .with_result("unsafe_moveCall", common::tx_bytes_fixture())
+        .with_result(
+            "sui_executeTransactionBlock",
+            common::transaction_fixture(
+                "9Qv9yFtQ4QPkM5wHnKn3eZ8nG2YpXdJ5vS7b1c3d5e7f",
+                serde_json::json!([{
+                    "owner": "Immutable",
+                    "reference": { "objectId": package_id, "version": 1, "digest": "2a8sSgYhH1Z8Q9" }
+                }]),
+            ),
+        )
+        .with_result(
+            "sui_executeTransactionBlock",
+            common::transaction_fixture(
+                "4Hn2sDk5TgMZ8c1Fq1zq3Y4vG7wP2kJ9xL6bN8mC1rT3",
+                serde_json::json!([{
+                    "owner": { "AddressOwner": common::MOCK_ADDRESS },
+                    "reference": { "objectId": nft_id, "version": 1188, "digest": "5b9tThZiI2A9R0" }
+                }]),
+            ),
+        );
+    let client = common::client(mock);
+    let wallet = common::wallet()?;
+    let trade = Trade::new(&client, &wallet).with_gas_budget(100_000_000);
+
+    // publish
+    let modules = if common::mock_mode() {
+        vec![vec![0xa1, 0x1c, 0xeb, 0x0b]]
+    } else {
+        read_modules(&common::env_or_mock("SUI_PACKAGE_BYTECODE_DIR", ""))?
+    };
+    let (tx_bytes, signature) = trade.publish_package(modules, vec!["0x1", "0x2"]).await?;
+    let published = client
+        .exe_transaction(tx_bytes, signature, wallet.sui_public_key())
+        .await?;
+    let package = created_ids(&published, |owner| matches!(owner, Owner::Immutable))
+        .into_iter()
+        .next()
+        .ok_or_else(|| SuiError::Transaction("No package in publish effects".to_string()))?;
+    println!("published package {}", package);
+    println!("{}", common::explorer_tx_url(&published.digest));
+
+    // mint
+    let (tx_bytes, signature) = trade
+        .call_contract_function(
+            &package,
+            "nft",
+            "mint",
+            vec![],
+            vec![
+                Value::from("Example NFT"),
+                Value::from("Minted by sui-network-sdk"),
+                Value::from("https://sui.io/logo.png"),
+            ],
+        )
+        .await?;
+    let minted = client
+        .exe_transaction(tx_bytes, signature, wallet.sui_public_key())
+        .await?;
+    for id in created_ids(&minted, |owner| matches!(owner, Owner::AddressOwner(_))) {
+        println!("minted NFT {}", id);
+    }
+    println!("{}", common::explorer_tx_url(&minted.digest));
+    Ok(())
+}
+
+/// compiled modules of a `sui move build` bytecode directory
+fn read_modules(dir: &str) -> Result<Vec<Vec<u8>>, SuiError> {
+    let mut modules = Vec::new();
+    for entry in std::fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.extension().is_some_and(|e| e == "mv") {
+            modules.push(std::fs::read(path)?);
+        }
+    }
+    Ok(modules)
+}
+
+/// ids of created objects whose owner matches
+fn created_ids(response: &TransactionResponse, owner: impl Fn(&Owner) -> bool) -> Vec<String> {
+    response
+        .effects
+        .iter()
+        .flat_map(|effects| effects.created.iter())
+        .filter(|created| owner(&created.owner))
+        .map(|created| created.reference.object_id.clone())
+        .collect()
+}
@@ -0,0 +1,27 @@
+/// Minimal NFT minted by the `mint_nft` example.
+module nft::nft;
+
+use std::string::{Self, String};
+
+public struct Nft has key, store {
+    id: UID,
+    name: String,
+    description: String,
+    url: String,
+}
+
+/// mint an NFT to the sender
+public entry fun mint(
+    name: vector<u8>,
+    description: vector<u8>,
+    url: vector<u8>,
+    ctx: &mut TxContext,
+) {
+    let nft = Nft {
+        id: object::new(ctx),
+        name: string::utf8(name),
+        description: string::utf8(description),
+        url: string::utf8(url),
+    };
+    transfer::public_transfer(nft, ctx.sender());
+}
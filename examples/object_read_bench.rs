@@ -0,0 +1,144 @@
+//! Compare reading 200 objects with JSON content against BCS-only reads.
+//!
+//! ```shell
+//! SUI_BENCH_ADDRESS=0x... cargo run --release --example object_read_bench
+//! SUI_EXAMPLE_MOCK=1 cargo run --release --example object_read_bench
+//! ```
+mod common;
+
+use serde_json::Value;
+use std::time::{Duration, Instant};
+use sui_network_sdk::transport::MockTransport;
+use sui_network_sdk::types::{Object, ObjectResponse, SuiError};
+
+/// objects read per run
+const OBJECTS: usize = 200;
+/// objects per multiGet request, the fullnode limit
+const CHUNK: usize = 50;
+/// runs per mode
+const RUNS: u32 = 5;
+
+#[tokio::main]
+async fn main() -> Result<(), SuiError> {
+    let ids: Vec<String> = (0..CHUNK).map(|i| format!("0x{:064x}", i + 1)).collect();
+    let mock = MockTransport::new()
+        .with_result(
+            "suix_getOwnedObjects",
+            serde_json::json!({
+                "data": ids.iter().map(|id| serde_json::json!({ "data": object_fixture(id, false) })).collect::<Vec<_>>(),
+                "nextCursor": null,
+                "hasNextPage": false
+            }),
+        );
+    let client = common::client(mock);
+    let address = common::env_or_mock("SUI_BENCH_ADDRESS", common::MOCK_ADDRESS);
+
+    // collect object ids owned by the address
+    let mut object_ids = Vec::new();
+    let mut cursor = Value::Null;
+    while object_ids.len() < OBJECTS {
+        let page: Value = client
+            .request(
+                "suix_getOwnedObjects",
+                vec![
+                    address.clone().into(),
+                    serde_json::json!({}),
+                    cursor,
+                    Value::Null,
+                ],
+            )
+            .await?;
+        for object in page["data"].as_array().into_iter().flatten() {
+            if let Some(id) = object["data"]["objectId"].as_str() {
+                object_ids.push(id.to_string());
+            }
+        }
+        if page["hasNextPage"].as_bool() != Some(true) || page["nextCursor"].is_null() {
+            break;
+        }
+        cursor = page["nextCursor"].clone();
+    }
+    // repeat the ids in mock mode or for small wallets
+    let object_ids: Vec<&str> = object_ids
+        .iter()
+        .map(|id| id.as_str())
+        .cycle()
+        .take(OBJECTS)
+        .collect();
+    if object_ids.is_empty() {
+        return Err(SuiError::Rpc(format!("{} owns no objects", address)));
+    }
+
+    let mut json = Duration::ZERO;
+    let mut bcs = Duration::ZERO;
+    for _ in 0..RUNS {
+        let start = Instant::now();
+        for chunk in object_ids.chunks(CHUNK) {
+            if common::mock_mode() {
+                decode_fixtures(chunk, false)?;
+            } else {
+                let ids: Vec<Value> = chunk.iter().map(|&id| id.into()).collect();
+                let options =
+                    serde_json::json!({ "showType": true, "showOwner": true, "showContent": true });
+                let objects: Vec<ObjectResponse> = client
+                    .request("sui_multiGetObjects", vec![ids.into(), options])
+                    .await?;
+                let _: Vec<Object> = objects.into_iter().filter_map(|o| o.data).collect();
+            }
+        }
+        json += start.elapsed();
+
+        let start = Instant::now();
+        for chunk in object_ids.chunks(CHUNK) {
+            if common::mock_mode() {
+                decode_fixtures(chunk, true)?;
+            } else {
+                client.multi_get_objects_bcs(chunk).await?;
+            }
+        }
+        bcs += start.elapsed();
+    }
+    println!("{} objects, {} runs", OBJECTS, RUNS);
+    println!("json content: {:?} per run", json / RUNS);
+    println!("bcs only:     {:?} per run", bcs / RUNS);
+    Ok(())
+}
+
+/// decode fixture responses, mock mode only measures decoding since the mock
+/// answers every request identically
+fn decode_fixtures(ids: &[&str], bcs: bool) -> Result<Vec<Object>, SuiError> {
+    let objects: Vec<ObjectResponse> = serde_json::from_value(Value::Array(
+        ids.iter()
+            .map(|id| serde_json::json!({ "data": object_fixture(id, bcs) }))
+            .collect(),
+    ))?;
+    Ok(objects.into_iter().filter_map(|o| o.data).collect())
+}
+
+/// coin object with JSON content or BCS contents
+fn object_fixture(id: &str, bcs: bool) -> Value {
+    let mut object = serde_json::json!({
+        "objectId": id,
+        "version": "1187",
+        "digest": "8vHTUw1cTRc6tfv6f5BWq9vJjGmvBu8mX6x2Z1t8s5vd",
+        "type": "0x2::coin::Coin<0x2::sui::SUI>"
+    });
+    if bcs {
+        object["bcs"] = serde_json::json!({
+            "dataType": "moveObject",
+            "type": "0x2::coin::Coin<0x2::sui::SUI>",
+            "hasPublicTransfer": true,
+            "version": 1187,
+            "bcsBytes": "GistPF5vcIGSo7TF1uf4CRorPF5vcIGSo7TF1uf4CQDyBSoBAAAA"
+        });
+    } else {
+        object["owner"] = serde_json::json!({ "AddressOwner": common::MOCK_ADDRESS });
+        object["content"] = serde_json::json!({
+            "dataType": "moveObject",
+            "type": "0x2::coin::Coin<0x2::sui::SUI>",
+            "hasPublicTransfer": true,
+            "fields": { "balance": "5000000000", "id": { "id": id } }
+        });
+    }
+    object
+}
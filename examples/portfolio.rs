@@ -0,0 +1,75 @@
+//! Print every coin balance and every NFT (object with display metadata) of an address.
+//!
+//! ```shell
+//! SUI_PORTFOLIO_ADDRESS=0x... cargo run --example portfolio
+//! SUI_EXAMPLE_MOCK=1 cargo run --example portfolio
+//! ```
+mod common;
+
+use serde_json::Value;
+use sui_network_sdk::transport::MockTransport;
+use sui_network_sdk::types::SuiError;
+
+#[tokio::main]
+async fn main() -> Result<(), SuiError> {
+    let mock = MockTransport::new()
+        .with_result(
+            "suix_getAllBalances",
+            serde_json::json!([
+                { "coinType": "0x2::sui::SUI", "coinObjectCount": 3, "totalBalance": "5000000000", "lockedBalance": {} }
+            ]),
+        )
+        .with_result(
+            "suix_getOwnedObjects",
+            serde_json::json!({
+                "data": [{
+                    "data": {
+                        "objectId": "0x9f8e7d6c5b4a39281706f5e4d3c2b1a09f8e7d6c5b4a39281706f5e4d3c2b1a0",
+                        "version": "1188",
+                        "digest": "5b9tThZiI2A9R0",
+                        "type": "0x5a0b::nft::Nft",
+                        "display": { "data": { "name": "Example NFT" }, "error": null }
+                    }
+                }],
+                "nextCursor": null,
+                "hasNextPage": false
+            }),
+        );
+    let client = common::client(mock);
+    let address = common::env_or_mock("SUI_PORTFOLIO_ADDRESS", common::MOCK_ADDRESS);
+
+    let balances = client.get_all_balances(&address).await?;
+    println!("balances of {}", address);
+    for balance in &balances {
+        println!("  {} {}", balance.total_balance, balance.coin_type);
+    }
+
+    println!("NFTs");
+    let mut cursor = Value::Null;
+    loop {
+        let query = serde_json::json!({ "options": { "showType": true, "showDisplay": true } });
+        let page: Value = client
+            .request(
+                "suix_getOwnedObjects",
+                vec![address.clone().into(), query, cursor, Value::Null],
+            )
+            .await?;
+        for object in page["data"].as_array().into_iter().flatten() {
+            let data = &object["data"];
+            if let Some(display) = data["display"]["data"].as_object() {
+                let name = display.get("name").and_then(|n| n.as_str()).unwrap_or("?");
+                println!(
+                    "  {} {} ({})",
+                    name,
+                    data["objectId"].as_str().unwrap_or_default(),
+                    data["type"].as_str().unwrap_or_default()
+                );
+            }
+        }
+        if page["hasNextPage"].as_bool() != Some(true) || page["nextCursor"].is_null() {
+            break;
+        }
+        cursor = page["nextCursor"].clone();
+    }
+    Ok(())
+}
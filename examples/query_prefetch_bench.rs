@@ -0,0 +1,141 @@
+//! Compare the serial page loop over `suix_queryTransactionBlocks` against
+//! `query_transactions_prefetch`, and check both yield the same transactions in the same order.
+//!
+//! ```shell
+//! SUI_BENCH_ADDRESS=0x... cargo run --release --example query_prefetch_bench
+//! SUI_EXAMPLE_MOCK=1 cargo run --release --example query_prefetch_bench
+//! ```
+//!
+//! In mock mode a request takes 20 ms for digests only and 150 ms when it asks for effects
+//! or events. Reading 20 pages of 50 transactions with effects and events took 3.0 s
+//! serially and 0.58 s with 8 pages prefetched. Against a node whose digest-only pages are
+//! not faster than full pages there is little gain, the listing stays serial.
+mod common;
+
+use futures::StreamExt;
+use futures::future::BoxFuture;
+use serde_json::Value;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use sui_network_sdk::SuiClient;
+use sui_network_sdk::transport::{MockTransport, Transport};
+use sui_network_sdk::types::{
+    SuiClientConfig, SuiError, TransactionBlockResponseOptions, TransactionFilter,
+};
+
+/// pages read per run
+const PAGES: usize = 20;
+/// transactions per page, the node's largest page
+const PAGE_SIZE: usize = 50;
+/// pages enriched concurrently
+const PREFETCH_PAGES: usize = 8;
+/// latency of a mock request for digests only
+const MOCK_LATENCY: Duration = Duration::from_millis(20);
+/// latency of a mock request for effects or events, larger pages take longer to serve
+const MOCK_CONTENT_LATENCY: Duration = Duration::from_millis(150);
+
+#[tokio::main]
+async fn main() -> Result<(), SuiError> {
+    let address = common::env_or_mock("SUI_BENCH_ADDRESS", common::MOCK_ADDRESS);
+    let filter = TransactionFilter::FromAddress(address);
+    let options = TransactionBlockResponseOptions {
+        show_effects: true,
+        show_events: true,
+        ..Default::default()
+    };
+
+    let client = bench_client();
+    let start = Instant::now();
+    let mut serial = Vec::new();
+    let mut cursor = None;
+    for _ in 0..PAGES {
+        let page = client
+            .query_transaction_blocks(filter.clone(), options, cursor, Some(PAGE_SIZE), false)
+            .await?;
+        serial.extend(page.data.into_iter().map(|tx| tx.digest));
+        match page.next_cursor {
+            Some(next) if page.has_next_page => cursor = Some(next),
+            _ => break,
+        }
+    }
+    let serial_time = start.elapsed();
+
+    let client = bench_client();
+    let start = Instant::now();
+    let mut prefetched = Vec::new();
+    let transactions = client.query_transactions_prefetch(filter, options, PREFETCH_PAGES);
+    let mut transactions = std::pin::pin!(transactions.take(serial.len()));
+    while let Some(transaction) = transactions.next().await {
+        prefetched.push(transaction?.digest);
+    }
+    let prefetch_time = start.elapsed();
+
+    assert_eq!(serial, prefetched, "prefetching changed the output order");
+    println!("{} transactions", serial.len());
+    println!("serial:              {:?}", serial_time);
+    println!("prefetch {} pages:    {:?}", PREFETCH_PAGES, prefetch_time);
+    Ok(())
+}
+
+/// testnet client, or in mock mode a slow client answering PAGES pages
+fn bench_client() -> SuiClient {
+    if !common::mock_mode() {
+        return common::client(MockTransport::new());
+    }
+    let mock = MockTransport::new();
+    for page in 0..PAGES {
+        let digests: Vec<String> = (0..PAGE_SIZE)
+            .map(|i| format!("TX{:04}", page * PAGE_SIZE + i))
+            .collect();
+        let last = page + 1 == PAGES;
+        mock.push_result(
+            "suix_queryTransactionBlocks",
+            serde_json::json!({
+                "data": digests.iter().map(|d| serde_json::json!({ "digest": d })).collect::<Vec<_>>(),
+                "nextCursor": if last { Value::Null } else { digests[PAGE_SIZE - 1].clone().into() },
+                "hasNextPage": !last
+            }),
+        );
+        mock.push_result(
+            "sui_multiGetTransactionBlocks",
+            digests
+                .iter()
+                .map(|d| serde_json::json!({ "digest": d, "checkpoint": "1" }))
+                .collect(),
+        );
+    }
+    SuiClient::new_with_transport(
+        SuiClientConfig::default(),
+        Arc::new(Latency { inner: mock }),
+    )
+}
+
+/// transport answering slower when a request asks for content
+struct Latency {
+    inner: MockTransport,
+}
+
+impl Transport for Latency {
+    fn send(&self, body: Value) -> BoxFuture<'_, Result<Value, SuiError>> {
+        Box::pin(async move {
+            let latency = if asks_for_content(&body["params"]) {
+                MOCK_CONTENT_LATENCY
+            } else {
+                MOCK_LATENCY
+            };
+            tokio::time::sleep(latency).await;
+            self.inner.send(body).await
+        })
+    }
+}
+
+/// whether any `show*` option is on
+fn asks_for_content(value: &Value) -> bool {
+    match value {
+        Value::Object(map) => map.iter().any(|(key, value)| {
+            (key.starts_with("show") && value == &Value::Bool(true)) || asks_for_content(value)
+        }),
+        Value::Array(values) => values.iter().any(asks_for_content),
+        _ => false,
+    }
+}
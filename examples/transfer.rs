@@ -0,0 +1,50 @@
+//! Transfer 0.01 SUI and wait for confirmation.
+//!
+//! ```shell
+//! SUI_PRIVATE_KEY=<base64 key> SUI_RECIPIENT=0x... cargo run --example transfer
+//! SUI_EXAMPLE_MOCK=1 cargo run --example transfer
+//! ```
+mod common;
+
+use sui_network_sdk::trade::Trade;
+use sui_network_sdk::transport::MockTransport;
+use sui_network_sdk::types::SuiError;
+
+/// 0.01 SUI in MIST
+const AMOUNT: u64 = 10_000_000;
+
+#[tokio::main]
+async fn main() -> Result<(), SuiError> {
+    let digest = "3Fc1yDqAxNpLCvVeC5nJcrzHRjTr1RZZiMZUe6ha9GSb";
+    let mock = MockTransport::new()
+        .with_result("suix_getCoins", common::coins_fixture())
+        .with_result("unsafe_transferSui", common::tx_bytes_fixture())
+        .with_result(
+            "sui_executeTransactionBlock",
+            common::transaction_fixture(digest, serde_json::json!([])),
+        )
+        .with_result(
+            "sui_getTransactionBlock",
+            common::transaction_fixture(digest, serde_json::json!([])),
+        );
+    let client = common::client(mock);
+    let wallet = common::wallet()?;
+    let recipient = common::env_or_mock("SUI_RECIPIENT", common::MOCK_ADDRESS);
+
+    let trade = Trade::new(&client, &wallet).with_gas_budget(10_000_000);
+    let (tx_bytes, signature) = trade.transfer_by_sui(&recipient, AMOUNT).await?;
+    let response = client
+        .exe_transaction(tx_bytes, signature, wallet.sui_public_key())
+        .await?;
+
+    // confirm the transaction is readable from the node
+    let confirmed = client.get_transaction_info(&response.digest).await?;
+    let status = confirmed
+        .effects
+        .map(|effects| effects.status.status)
+        .unwrap_or_else(|| "unknown".to_string());
+    println!("sent {} MIST to {}", AMOUNT, recipient);
+    println!("status: {}", status);
+    println!("{}", common::explorer_tx_url(&confirmed.digest));
+    Ok(())
+}
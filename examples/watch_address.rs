@@ -0,0 +1,72 @@
+//! Watch an address and print its SUI balance after every transaction,
+//! reconnecting with backoff when the subscription drops.
+//!
+//! ```shell
+//! SUI_WATCH_ADDRESS=0x... cargo run --example watch_address
+//! SUI_EXAMPLE_MOCK=1 cargo run --example watch_address
+//! ```
+mod common;
+
+use std::time::Duration;
+use sui_network_sdk::global::testnet;
+use sui_network_sdk::listener::Listener;
+use sui_network_sdk::transport::MockTransport;
+use sui_network_sdk::types::SuiError;
+use tokio::sync::mpsc;
+
+/// longest wait between reconnect attempts
+const MAX_BACKOFF: Duration = Duration::from_secs(30);
+
+#[tokio::main]
+async fn main() -> Result<(), SuiError> {
+    let mock = MockTransport::new()
+        .with_result(
+            "sui_getBalance",
+            serde_json::json!({ "coinType": "0x2::sui::SUI", "coinObjectCount": 1, "totalBalance": "5000000000" }),
+        )
+        .with_result(
+            "sui_getBalance",
+            serde_json::json!({ "coinType": "0x2::sui::SUI", "coinObjectCount": 1, "totalBalance": "4990000000" }),
+        );
+    let client = common::client(mock);
+    let address = common::env_or_mock("SUI_WATCH_ADDRESS", common::MOCK_ADDRESS);
+
+    let (sender, mut digests) = mpsc::unbounded_channel::<String>();
+    if common::mock_mode() {
+        sender
+            .send("3Fc1yDqAxNpLCvVeC5nJcrzHRjTr1RZZiMZUe6ha9GSb".to_string())
+            .ok();
+        drop(sender);
+    } else {
+        let address = address.clone();
+        tokio::spawn(async move {
+            let listener = Listener::new(testnet::WSS_URL.to_string());
+            let mut backoff = Duration::from_secs(1);
+            loop {
+                let sender = sender.clone();
+                let result = listener
+                    .listen_address_transactions(&address, move |digest| {
+                        sender.send(digest).ok();
+                    })
+                    .await;
+                if let Err(e) = result {
+                    eprintln!("subscription dropped: {}", e);
+                } else {
+                    backoff = Duration::from_secs(1);
+                }
+                tokio::time::sleep(backoff).await;
+                backoff = (backoff * 2).min(MAX_BACKOFF);
+            }
+        });
+    }
+
+    let mut balance = client.get_balance(&address, None).await?;
+    println!("{} balance: {} MIST", address, balance);
+    while let Some(digest) = digests.recv().await {
+        let current = client.get_balance(&address, None).await?;
+        let change = current as i128 - balance as i128;
+        println!("tx {}: balance {} MIST ({:+})", digest, current, change);
+        balance = current;
+    }
+    Ok(())
+}
@@ -0,0 +1,387 @@
+use crate::coin::{SUI_COIN_TYPE, normalize_coin_type};
+use crate::types::{Owner, SuiAddress, TransactionResponse};
+use std::collections::HashSet;
+use std::fmt;
+
+/// # Transfer direction
+///
+/// # Variants
+/// - In : received by an owned address from outside
+/// - Out : sent by an owned address outside
+/// - SelfTransfer : moved between owned addresses, or only gas spent
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TransferDirection {
+    In,
+    Out,
+    SelfTransfer,
+}
+
+impl fmt::Display for TransferDirection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TransferDirection::In => write!(f, "in"),
+            TransferDirection::Out => write!(f, "out"),
+            TransferDirection::SelfTransfer => write!(f, "self"),
+        }
+    }
+}
+
+/// Accounting row of a transaction, see [`TransactionResponse::classify_transfers`].
+///
+/// # Fields
+/// - digest : transaction digest
+/// - timestamp_ms : checkpoint timestamp in milliseconds, once checkpointed
+/// - address : owned address the row books
+/// - direction : direction of the transfer
+/// - counterparty : other side, `None` when there are several, or an object or shared owner
+/// - coin_type : coin type
+/// - amount : amount in the coin's smallest unit, gas excluded
+/// - fee_share : part of the gas fee in MIST, negative when the storage rebate exceeded the cost
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TransferRecord {
+    pub digest: String,
+    pub timestamp_ms: Option<u64>,
+    pub address: SuiAddress,
+    pub direction: TransferDirection,
+    pub counterparty: Option<SuiAddress>,
+    pub coin_type: String,
+    pub amount: u128,
+    pub fee_share: i128,
+}
+
+/// balance changes of one coin type
+struct CoinChanges {
+    coin_type: String,
+    // net change by owner, `None` for object and shared owners
+    changes: Vec<(Option<SuiAddress>, i128)>,
+}
+
+impl CoinChanges {
+    fn add(&mut self, owner: Option<SuiAddress>, amount: i128) {
+        match self.changes.iter_mut().find(|(o, _)| *o == owner) {
+            // a node reporting absurd amounts saturates instead of overflowing
+            Some((_, total)) => *total = total.saturating_add(amount),
+            None => self.changes.push((owner, amount)),
+        }
+    }
+}
+
+impl TransactionResponse {
+    /// # Classify transfers
+    ///
+    /// Books the balance changes of a transaction as accounting rows from the side of a set
+    /// of owned addresses. Gas is attributed to the sending side: when the gas owner is
+    /// owned, the fee is removed from its SUI change and split over its outgoing rows, or
+    /// booked on a `SelfTransfer` row of amount 0 when it sent nothing, e.g. a transfer to itself.
+    /// Gas paid by a sponsor outside the set is not booked. An outgoing payment of one
+    /// owned sender to several recipients gets a row per recipient.
+    ///
+    /// The response needs balance changes, effects and input (`showBalanceChanges`,
+    /// `showEffects`, `showInput`), without effects no fee is booked.
+    ///
+    /// ## Parameters
+    /// - owned : addresses booked
+    ///
+    /// ## Returns
+    /// rows in the order of the balance changes, empty without balance changes
+    ///
+    /// ## Example
+    /// ```rust
+    /// use std::collections::HashSet;
+    /// use sui_network_sdk::accounting::TransferDirection;
+    /// use sui_network_sdk::types::{SuiAddress, TransactionResponse};
+    ///
+    /// let alice: SuiAddress = "0xa11ce".parse().unwrap();
+    /// let bob: SuiAddress = "0xb0b".parse().unwrap();
+    /// let carol: SuiAddress = "0xca201".parse().unwrap();
+    /// // gas of 1_000_000 MIST net, paid by the gas owner
+    /// let tx = |gas_owner: &SuiAddress, changes: serde_json::Value| -> TransactionResponse {
+    ///     serde_json::from_value(serde_json::json!({
+    ///         "digest": "T1",
+    ///         "effects": {
+    ///             "status": { "status": "success" },
+    ///             "gasUsed": { "computationCost": "750000", "storageCost": "1988000", "storageRebate": "1738000" },
+    ///             "transactionDigest": "T1"
+    ///         },
+    ///         "transaction": { "data": {
+    ///             "sender": alice.to_string(),
+    ///             "gasData": { "payment": [], "owner": gas_owner.to_string(), "price": "750", "budget": "5000000" }
+    ///         }},
+    ///         "balanceChanges": changes
+    ///     }))
+    ///     .unwrap()
+    /// };
+    /// let change = |owner: &SuiAddress, coin: &str, amount: i128| serde_json::json!({
+    ///     "owner": { "AddressOwner": owner.to_string() }, "coinType": coin, "amount": amount.to_string()
+    /// });
+    ///
+    /// // a pay to bob and carol, the fee split over both rows
+    /// let pay = tx(&alice, serde_json::json!([
+    ///     change(&alice, "0x2::sui::SUI", -3_001_000_000),
+    ///     change(&bob, "0x2::sui::SUI", 1_000_000_000),
+    ///     change(&carol, "0x2::sui::SUI", 2_000_000_000),
+    /// ]));
+    /// let rows = pay.classify_transfers(&HashSet::from([alice.clone()]));
+    /// assert_eq!(rows.len(), 2);
+    /// assert!(rows.iter().all(|r| r.direction == TransferDirection::Out && r.fee_share == 500_000));
+    /// assert_eq!((rows[0].counterparty.as_ref(), rows[0].amount), (Some(&bob), 1_000_000_000));
+    /// assert_eq!((rows[1].counterparty.as_ref(), rows[1].amount), (Some(&carol), 2_000_000_000));
+    /// // bob's side of the same transaction
+    /// let rows = pay.classify_transfers(&HashSet::from([bob.clone()]));
+    /// assert_eq!(rows[0].direction, TransferDirection::In);
+    /// assert_eq!((rows[0].counterparty.as_ref(), rows[0].fee_share), (Some(&alice), 0));
+    ///
+    /// // a transfer to itself only spends gas
+    /// let own = tx(&alice, serde_json::json!([change(&alice, "0x2::sui::SUI", -1_000_000)]));
+    /// let rows = own.classify_transfers(&HashSet::from([alice.clone()]));
+    /// assert_eq!(rows.len(), 1);
+    /// assert_eq!((rows[0].direction, rows[0].amount, rows[0].fee_share), (TransferDirection::SelfTransfer, 0, 1_000_000));
+    /// // between two owned addresses
+    /// let internal = tx(&alice, serde_json::json!([
+    ///     change(&alice, "0x2::sui::SUI", -5_001_000_000),
+    ///     change(&bob, "0x2::sui::SUI", 5_000_000_000),
+    /// ]));
+    /// let rows = internal.classify_transfers(&HashSet::from([alice.clone(), bob.clone()]));
+    /// assert_eq!(rows.len(), 1);
+    /// assert_eq!((rows[0].direction, rows[0].counterparty.as_ref()), (TransferDirection::SelfTransfer, Some(&bob)));
+    /// assert_eq!((rows[0].amount, rows[0].fee_share), (5_000_000_000, 1_000_000));
+    ///
+    /// // gas sponsored by carol, alice's change is the transfer alone
+    /// let usdc = "0xa1ec7::usdc::USDC";
+    /// let sponsored = tx(&carol, serde_json::json!([
+    ///     change(&alice, usdc, -25_000_000),
+    ///     change(&bob, usdc, 25_000_000),
+    ///     change(&carol, "0x2::sui::SUI", -1_000_000),
+    /// ]));
+    /// let rows = sponsored.classify_transfers(&HashSet::from([alice.clone()]));
+    /// assert_eq!(rows.len(), 1);
+    /// assert_eq!((rows[0].coin_type.as_str(), rows[0].amount, rows[0].fee_share), (usdc, 25_000_000, 0));
+    /// ```
+    pub fn classify_transfers(&self, owned: &HashSet<SuiAddress>) -> Vec<TransferRecord> {
+        let Some(balance_changes) = &self.balance_changes else {
+            return Vec::new();
+        };
+        let sui = normalize_coin_type(SUI_COIN_TYPE);
+        let mut coins: Vec<CoinChanges> = Vec::new();
+        for change in balance_changes {
+            let coin_type = normalize_coin_type(&change.coin_type);
+            let owner = match &change.owner {
+                Owner::AddressOwner(address) => address.parse().ok(),
+                Owner::ConsensusAddressOwner(owner) => owner.owner.parse().ok(),
+                _ => None,
+            };
+            match coins
+                .iter_mut()
+                .find(|c| normalize_coin_type(&c.coin_type) == coin_type)
+            {
+                Some(coin) => coin.add(owner, change.amount),
+                None => coins.push(CoinChanges {
+                    coin_type: change.coin_type.clone(),
+                    changes: vec![(owner, change.amount)],
+                }),
+            }
+        }
+
+        // the fee, when an owned address paid it, leaves the transfers
+        let gas_owner: Option<SuiAddress> = self
+            .transaction
+            .as_ref()
+            .map(|tx| match &tx.data.gas_data {
+                Some(gas_data) => gas_data.owner.clone(),
+                None => tx.data.sender.clone(),
+            })
+            .and_then(|owner| owner.parse().ok())
+            .filter(|owner| owned.contains(owner));
+        let fee: i128 = self
+            .effects
+            .as_ref()
+            .map_or(0, |effects| effects.gas_used.net_cost());
+        if let Some(gas_owner) = &gas_owner {
+            match coins
+                .iter_mut()
+                .find(|c| normalize_coin_type(&c.coin_type) == sui)
+            {
+                Some(coin) => coin.add(Some(gas_owner.clone()), fee),
+                None => coins.push(CoinChanges {
+                    coin_type: SUI_COIN_TYPE.to_string(),
+                    changes: vec![(Some(gas_owner.clone()), fee)],
+                }),
+            }
+        }
+
+        let mut records = Vec::new();
+        for coin in &coins {
+            let mut senders: Vec<(&SuiAddress, i128)> = Vec::new();
+            let mut receivers: Vec<(&SuiAddress, i128)> = Vec::new();
+            // changes outside the owned set, paid to and charged from the other side
+            let mut paid: Vec<(Option<&SuiAddress>, i128)> = Vec::new();
+            let mut charged: Vec<(Option<&SuiAddress>, i128)> = Vec::new();
+            for (owner, amount) in &coin.changes {
+                match owner.as_ref().filter(|o| owned.contains(*o)) {
+                    Some(address) if *amount < 0 => senders.push((address, *amount)),
+                    Some(address) if *amount > 0 => receivers.push((address, *amount)),
+                    Some(_) => {}
+                    None if *amount > 0 => paid.push((owner.as_ref(), *amount)),
+                    None if *amount < 0 => charged.push((owner.as_ref(), *amount)),
+                    None => {}
+                }
+            }
+            let internal = paid.is_empty() && charged.is_empty();
+            let row = |address: &SuiAddress,
+                       direction,
+                       counterparty: Option<&SuiAddress>,
+                       amount: i128| {
+                TransferRecord {
+                    digest: self.digest.clone(),
+                    timestamp_ms: self.timestamp_ms,
+                    address: address.clone(),
+                    direction,
+                    counterparty: counterparty.cloned(),
+                    coin_type: coin.coin_type.clone(),
+                    amount: amount.unsigned_abs(),
+                    fee_share: 0,
+                }
+            };
+
+            for (address, amount) in &senders {
+                if internal {
+                    let counterparty = sole(receivers.iter().map(|(r, _)| Some(*r)));
+                    records.push(row(
+                        address,
+                        TransferDirection::SelfTransfer,
+                        counterparty,
+                        *amount,
+                    ));
+                } else if senders.len() == 1
+                    && paid.len() > 1
+                    && paid.iter().map(|(_, a)| a).sum::<i128>() == -amount
+                {
+                    for (recipient, paid) in &paid {
+                        records.push(row(address, TransferDirection::Out, *recipient, *paid));
+                    }
+                } else {
+                    let counterparty = sole(paid.iter().map(|(r, _)| *r));
+                    records.push(row(address, TransferDirection::Out, counterparty, *amount));
+                }
+            }
+            // internal moves are booked once, on the sending side
+            if !internal || senders.is_empty() {
+                for (address, amount) in &receivers {
+                    let counterparty = sole(charged.iter().map(|(c, _)| *c));
+                    records.push(row(address, TransferDirection::In, counterparty, *amount));
+                }
+            }
+        }
+
+        if let Some(gas_owner) = gas_owner
+            && fee != 0
+        {
+            let sui_coin = |record: &TransferRecord| normalize_coin_type(&record.coin_type) == sui;
+            let mut paying: Vec<&mut TransferRecord> = records
+                .iter_mut()
+                .filter(|r| {
+                    r.address == gas_owner && r.direction != TransferDirection::In && sui_coin(r)
+                })
+                .collect();
+            if paying.is_empty() {
+                records.push(TransferRecord {
+                    digest: self.digest.clone(),
+                    timestamp_ms: self.timestamp_ms,
+                    address: gas_owner,
+                    direction: TransferDirection::SelfTransfer,
+                    counterparty: None,
+                    coin_type: SUI_COIN_TYPE.to_string(),
+                    amount: 0,
+                    fee_share: fee,
+                });
+            } else {
+                // the remainder of an uneven split goes to the first row
+                let share = fee / paying.len() as i128;
+                let remainder = fee - share * paying.len() as i128;
+                for record in paying.iter_mut() {
+                    record.fee_share = share;
+                }
+                paying[0].fee_share += remainder;
+            }
+        }
+        records
+    }
+}
+
+/// # Transfers to CSV
+///
+/// Writes accounting rows as CSV with a header line, fields are quoted when they hold a
+/// comma, a quote or a line break, e.g. coin types with several type arguments.
+///
+/// ## Parameters
+/// - records : rows of [`TransactionResponse::classify_transfers`]
+///
+/// ## Returns
+/// CSV text, lines ending with CRLF
+///
+/// ## Example
+/// ```rust
+/// use sui_network_sdk::accounting::{TransferDirection, TransferRecord, transfers_to_csv};
+///
+/// let record = TransferRecord {
+///     digest: "T1".to_string(),
+///     timestamp_ms: Some(1_700_000_000_000),
+///     address: "0xa".parse().unwrap(),
+///     direction: TransferDirection::Out,
+///     counterparty: None,
+///     coin_type: "0xdee9::lp::LP<0x2::sui::SUI, 0xa1ec7::usdc::USDC>".to_string(),
+///     amount: 25,
+///     fee_share: -100,
+/// };
+/// let csv = transfers_to_csv(&[record]);
+/// let lines: Vec<&str> = csv.lines().collect();
+/// assert_eq!(lines[0], "digest,timestamp_ms,address,direction,counterparty,coin_type,amount,fee_share");
+/// assert!(lines[1].ends_with(",out,,\"0xdee9::lp::LP<0x2::sui::SUI, 0xa1ec7::usdc::USDC>\",25,-100"));
+/// ```
+#[cfg(feature = "csv")]
+pub fn transfers_to_csv(records: &[TransferRecord]) -> String {
+    let mut csv = String::from(
+        "digest,timestamp_ms,address,direction,counterparty,coin_type,amount,fee_share\r\n",
+    );
+    for record in records {
+        let fields = [
+            record.digest.clone(),
+            record
+                .timestamp_ms
+                .map(|t| t.to_string())
+                .unwrap_or_default(),
+            record.address.to_string(),
+            record.direction.to_string(),
+            record
+                .counterparty
+                .as_ref()
+                .map(|c| c.to_string())
+                .unwrap_or_default(),
+            record.coin_type.clone(),
+            record.amount.to_string(),
+            record.fee_share.to_string(),
+        ];
+        let fields: Vec<String> = fields.iter().map(|field| csv_field(field)).collect();
+        csv.push_str(&fields.join(","));
+        csv.push_str("\r\n");
+    }
+    csv
+}
+
+/// field quoted when it holds a separator, a quote or a line break
+#[cfg(feature = "csv")]
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\r', '\n']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+/// the only item, none when there are several
+fn sole<'a>(mut side: impl Iterator<Item = Option<&'a SuiAddress>>) -> Option<&'a SuiAddress> {
+    match (side.next(), side.next()) {
+        (Some(only), None) => only,
+        _ => None,
+    }
+}
@@ -0,0 +1,151 @@
+use crate::listener::spawn_named;
+use crate::ptb::TransactionData;
+use base64::Engine;
+use base64::prelude::BASE64_STANDARD;
+use serde_json::Value;
+use std::sync::Arc;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// placeholder of redacted signatures
+const REDACTED: &str = "<redacted>";
+
+/// # Audit hook
+///
+/// Called with a record of every request a [`SuiClient`] sent, see [`SuiClient::on_audit`].
+///
+/// [`SuiClient`]: crate::SuiClient
+/// [`SuiClient::on_audit`]: crate::SuiClient::on_audit
+pub type AuditHook = Box<dyn Fn(AuditRecord) + Send + Sync>;
+
+/// # Audit record
+///
+/// # Fields
+/// - method : rpc method name
+/// - params : params as canonical JSON, object keys sorted and no whitespace
+/// - endpoint : rpc url of the client
+/// - timestamp_ms : unix time the request was sent, in milliseconds
+/// - error : transport or JSON-RPC error of the request
+/// - transaction : decoded transaction of `executeTransactionBlock` requests
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AuditRecord {
+    pub method: String,
+    pub params: String,
+    pub endpoint: String,
+    pub timestamp_ms: u64,
+    pub error: Option<String>,
+    pub transaction: Option<AuditedTransaction>,
+}
+
+/// # Audited transaction
+///
+/// # Fields
+/// - sender : sender address
+/// - gas_owner : gas owner address, the sender unless sponsored
+/// - gas_budget : gas budget in MIST
+/// - commands : command summaries, see [`Command::summary`]
+///
+/// [`Command::summary`]: crate::ptb::Command::summary
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AuditedTransaction {
+    pub sender: String,
+    pub gas_owner: String,
+    pub gas_budget: u64,
+    pub commands: Vec<String>,
+}
+
+impl AuditedTransaction {
+    /// summary of transaction data
+    pub fn from_data(data: &TransactionData) -> Self {
+        Self {
+            sender: data.sender(),
+            gas_owner: data.gas_owner(),
+            gas_budget: data.gas_budget(),
+            commands: data
+                .programmable_transaction()
+                .commands
+                .iter()
+                .map(|command| command.summary())
+                .collect(),
+        }
+    }
+}
+
+/// unix time in milliseconds
+pub(crate) fn now_ms() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_millis() as u64)
+}
+
+/// hand the records of a sent body to the hook on a separate task
+pub(crate) fn spawn_records(
+    hook: Arc<AuditHook>,
+    redact_signatures: bool,
+    endpoint: String,
+    timestamp_ms: u64,
+    body: Value,
+    outcome: Result<Value, String>,
+) {
+    spawn_named("sui-audit/records", async move {
+        let requests = match body {
+            Value::Array(requests) => requests,
+            request => vec![request],
+        };
+        for request in requests {
+            let error = match &outcome {
+                Err(error) => Some(error.clone()),
+                Ok(response) => response_error(response, &request["id"]),
+            };
+            hook(audit_record(
+                &request,
+                &endpoint,
+                timestamp_ms,
+                error,
+                redact_signatures,
+            ));
+        }
+    });
+}
+
+/// record of one request, with the transaction of an execution decoded
+fn audit_record(
+    request: &Value,
+    endpoint: &str,
+    timestamp_ms: u64,
+    error: Option<String>,
+    redact_signatures: bool,
+) -> AuditRecord {
+    let method = request["method"].as_str().unwrap_or_default().to_string();
+    let mut params = request["params"].clone();
+    let mut transaction = None;
+    if method.ends_with("executeTransactionBlock") {
+        transaction = params[0]
+            .as_str()
+            .and_then(|tx_bytes| BASE64_STANDARD.decode(tx_bytes).ok())
+            .and_then(|tx_bytes| TransactionData::from_bytes(&tx_bytes).ok())
+            .map(|data| AuditedTransaction::from_data(&data));
+        if redact_signatures
+            && let Some(signatures) = params.get_mut(1).and_then(Value::as_array_mut)
+        {
+            signatures.fill(Value::from(REDACTED));
+        }
+    }
+    AuditRecord {
+        method,
+        // serde_json keeps object keys sorted, the compact form is canonical
+        params: params.to_string(),
+        endpoint: endpoint.to_string(),
+        timestamp_ms,
+        error,
+        transaction,
+    }
+}
+
+/// JSON-RPC error message of the response with the request's id
+fn response_error(response: &Value, id: &Value) -> Option<String> {
+    let response = match response {
+        Value::Array(responses) => responses.iter().find(|r| &r["id"] == id)?,
+        response => response,
+    };
+    response["error"]["message"].as_str().map(str::to_string)
+}
@@ -0,0 +1,254 @@
+use crate::SuiClient;
+use crate::math::{format_units, parse_units};
+use crate::types::{CoinAmountError, SuiError};
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+use std::sync::Mutex;
+
+/// SUI coin type
+pub(crate) const SUI_COIN_TYPE: &str = "0x2::sui::SUI";
+/// decimals of SUI, 1 SUI = 10^9 MIST
+pub(crate) const SUI_DECIMALS: u8 = 9;
+
+/// # Coin Registry
+///
+/// Decimals by coin type, to convert between base units and display amounts. Decimals come
+/// from the `CoinMetadata` object of a coin type and are cached after the first lookup.
+/// SUI is built in, coins without metadata need an override. Coin types are compared with
+/// their address normalized, `0x2::sui::SUI` and its long form are one entry.
+///
+/// Every [`SuiClient`] holds a registry, see [`SuiClient::coin_registry`].
+///
+/// ## Example
+/// ```rust
+/// use std::sync::Arc;
+/// use sui_network_sdk::SuiClient;
+/// use sui_network_sdk::coin::CoinRegistry;
+/// use sui_network_sdk::transport::MockTransport;
+/// use sui_network_sdk::types::{CoinAmountError, SuiClientConfig, SuiError};
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let mock = Arc::new(
+///         MockTransport::new()
+///             .with_result("suix_getCoinMetadata", serde_json::json!({ "decimals": 6, "symbol": "USDC" }))
+///             .with_result("suix_getCoinMetadata", serde_json::Value::Null),
+///     );
+///     let client = SuiClient::new_with_transport(SuiClientConfig::default(), mock.clone());
+///     let registry = CoinRegistry::new().with_override("0xbeef::points::POINTS", 2);
+///
+///     let usdc = "0xa1::usdc::USDC";
+///     assert_eq!(registry.format_amount(&client, usdc, 1_250_000).await.unwrap(), "1.25");
+///     assert_eq!(registry.parse_amount(&client, usdc, "0.5").await.unwrap(), 500_000);
+///     // cached, one metadata lookup
+///     assert_eq!(registry.decimals(&client, usdc).await.unwrap(), 6);
+///     assert_eq!(mock.request_count("suix_getCoinMetadata"), 1);
+///
+///     // built in and overridden coins need no lookup
+///     assert_eq!(registry.parse_amount(&client, "0x2::sui::SUI", "1.5").await.unwrap(), 1_500_000_000);
+///     assert_eq!(registry.format_amount(&client, "0xBEEF::points::POINTS", 1234).await.unwrap(), "12.34");
+///
+///     // no metadata and no override
+///     assert!(matches!(
+///         registry.decimals(&client, "0xa2::meme::MEME").await,
+///         Err(SuiError::CoinAmount(CoinAmountError::UnknownDecimals(_)))
+///     ));
+/// }
+/// ```
+#[derive(Debug)]
+pub struct CoinRegistry {
+    decimals: Mutex<HashMap<String, u8>>,
+}
+
+impl CoinRegistry {
+    /// # create registry
+    ///
+    /// ## Returns
+    /// registry knowing SUI only
+    pub fn new() -> Self {
+        Self {
+            decimals: Mutex::new(HashMap::from([(
+                normalize_coin_type(SUI_COIN_TYPE),
+                SUI_DECIMALS,
+            )])),
+        }
+    }
+
+    /// # set decimals of a coin type
+    ///
+    /// ## Parameters
+    /// - coin_type : coin type
+    /// - decimals : decimals, taking precedence over the coin metadata
+    ///
+    /// ## Returns
+    /// registry with the override
+    pub fn with_override(self, coin_type: &str, decimals: u8) -> Self {
+        self.insert(coin_type, decimals);
+        self
+    }
+
+    /// # Load overrides
+    ///
+    /// Reads decimals from a JSON file mapping coin types to decimals, e.g.
+    /// `{ "0xbeef::points::POINTS": 2 }`.
+    ///
+    /// ## Parameters
+    /// - path : overrides file
+    ///
+    /// ## Returns
+    /// - Ok(CoinRegistry) : registry with the overrides
+    /// - Err(SuiError) : the file can't be read or is not such a map
+    pub fn load_overrides<P: AsRef<Path>>(self, path: P) -> Result<Self, SuiError> {
+        let content = fs::read_to_string(path)?;
+        let overrides: HashMap<String, u8> = serde_json::from_str(&content)?;
+        for (coin_type, decimals) in overrides {
+            self.insert(&coin_type, decimals);
+        }
+        Ok(self)
+    }
+
+    /// set decimals of a coin type
+    pub fn insert(&self, coin_type: &str, decimals: u8) {
+        self.entries()
+            .insert(normalize_coin_type(coin_type), decimals);
+    }
+
+    /// decimals of a coin type when known without a lookup
+    pub fn cached(&self, coin_type: &str) -> Option<u8> {
+        self.entries().get(&normalize_coin_type(coin_type)).copied()
+    }
+
+    /// # Decimals
+    ///
+    /// ## Parameters
+    /// - client : client reading the coin metadata on a cache miss
+    /// - coin_type : coin type
+    ///
+    /// ## Returns
+    /// - Ok(u8) : decimals
+    /// - Err(SuiError) : `CoinAmountError::UnknownDecimals` when the coin has no metadata,
+    ///   or the lookup error
+    pub async fn decimals(&self, client: &SuiClient, coin_type: &str) -> Result<u8, SuiError> {
+        if let Some(decimals) = self.cached(coin_type) {
+            return Ok(decimals);
+        }
+        let metadata = client.get_coin_metadata(coin_type).await?.ok_or_else(|| {
+            SuiError::CoinAmount(CoinAmountError::UnknownDecimals(coin_type.to_string()))
+        })?;
+        self.insert(coin_type, metadata.decimals);
+        Ok(metadata.decimals)
+    }
+
+    /// # Format amount
+    ///
+    /// ## Parameters
+    /// - client : client reading the coin metadata on a cache miss
+    /// - coin_type : coin type
+    /// - raw : amount in base units
+    ///
+    /// ## Returns
+    /// - Ok(String) : display amount, see [`format_amount`]
+    /// - Err(SuiError) : decimals unknown
+    pub async fn format_amount(
+        &self,
+        client: &SuiClient,
+        coin_type: &str,
+        raw: u64,
+    ) -> Result<String, SuiError> {
+        Ok(format_amount(raw, self.decimals(client, coin_type).await?))
+    }
+
+    /// # Parse amount
+    ///
+    /// ## Parameters
+    /// - client : client reading the coin metadata on a cache miss
+    /// - coin_type : coin type
+    /// - amount : display amount, e.g. "1.25"
+    ///
+    /// ## Returns
+    /// - Ok(u64) : amount in base units, see [`parse_amount`]
+    /// - Err(SuiError) : decimals unknown or invalid amount
+    pub async fn parse_amount(
+        &self,
+        client: &SuiClient,
+        coin_type: &str,
+        amount: &str,
+    ) -> Result<u64, SuiError> {
+        let decimals = self.decimals(client, coin_type).await?;
+        parse_amount(amount, decimals).map_err(SuiError::CoinAmount)
+    }
+
+    /// cache entries, a poisoned lock still holds valid entries
+    fn entries(&self) -> std::sync::MutexGuard<'_, HashMap<String, u8>> {
+        self.decimals.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+impl Default for CoinRegistry {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// # Format amount
+///
+/// ## Parameters
+/// - raw : amount in base units
+/// - decimals : decimals of the coin
+///
+/// ## Returns
+/// display amount without trailing zeros
+///
+/// ## Example
+/// ```rust
+/// use sui_network_sdk::coin::format_amount;
+/// assert_eq!(format_amount(1_250_000_000, 9), "1.25");
+/// assert_eq!(format_amount(1_000_000_000, 9), "1");
+/// assert_eq!(format_amount(1, 9), "0.000000001");
+/// assert_eq!(format_amount(42, 0), "42");
+/// assert_eq!(format_amount(u64::MAX, 20), "0.18446744073709551615");
+/// ```
+pub fn format_amount(raw: u64, decimals: u8) -> String {
+    format_units(u128::from(raw), decimals)
+}
+
+/// # Parse amount
+///
+/// ## Parameters
+/// - amount : display amount, digits with an optional decimal point
+/// - decimals : decimals of the coin
+///
+/// ## Returns
+/// - Ok(u64) : amount in base units
+/// - Err(CoinAmountError) : invalid amount, more decimal places than the coin, or overflow
+///
+/// ## Example
+/// ```rust
+/// use sui_network_sdk::coin::parse_amount;
+/// use sui_network_sdk::types::CoinAmountError;
+/// assert_eq!(parse_amount("1.25", 9), Ok(1_250_000_000));
+/// assert_eq!(parse_amount("0.000000001", 9), Ok(1));
+/// assert_eq!(parse_amount(".5", 1), Ok(5));
+/// assert_eq!(parse_amount("7", 0), Ok(7));
+/// assert_eq!(parse_amount("0.0000000001", 9), Err(CoinAmountError::TooManyDecimals { decimals: 9 }));
+/// assert_eq!(parse_amount("18446744073.709551616", 9), Err(CoinAmountError::Overflow));
+/// assert!(matches!(parse_amount("-1", 9), Err(CoinAmountError::Invalid(_))));
+/// assert!(matches!(parse_amount("1.2.3", 9), Err(CoinAmountError::Invalid(_))));
+/// assert!(matches!(parse_amount(".", 9), Err(CoinAmountError::Invalid(_))));
+/// ```
+pub fn parse_amount(amount: &str, decimals: u8) -> Result<u64, CoinAmountError> {
+    u64::try_from(parse_units(amount, decimals)?).map_err(|_| CoinAmountError::Overflow)
+}
+
+/// coin type with its address normalized
+pub(crate) fn normalize_coin_type(coin_type: &str) -> String {
+    let coin_type = coin_type.trim();
+    match coin_type.split_once("::") {
+        Some((address, rest)) => match crate::types::address::normalize(address) {
+            Some(address) => format!("{}::{}", address, rest),
+            None => coin_type.to_string(),
+        },
+        None => coin_type.to_string(),
+    }
+}
@@ -0,0 +1,504 @@
+use crate::SuiClient;
+use crate::listener::{SharedListener, WatchList, WatchStream};
+use crate::math::received;
+use crate::types::{
+    Owner, SuiError, TransactionBlockResponseOptions, TransactionFilter, TransactionResponse,
+    address,
+};
+use futures::StreamExt;
+use std::collections::{BTreeMap, HashMap, HashSet, VecDeque};
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+/// transactions per query page, the node's largest page
+const DEPOSIT_PAGE_SIZE: usize = 50;
+/// default wait between scans finding nothing
+const DEFAULT_POLL_INTERVAL: Duration = Duration::from_secs(1);
+/// emitted transactions remembered to drop repeated deliveries
+const DEDUP_CAPACITY: usize = 10_000;
+
+/// # Deposit
+///
+/// # Fields
+/// - address : watched address the coins arrived at
+/// - coin_type : coin type, as the node reports it
+/// - amount : received amount in the coin's smallest unit
+/// - digest : transaction digest
+/// - checkpoint : checkpoint of the transaction
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Deposit {
+    pub address: String,
+    pub coin_type: String,
+    pub amount: u128,
+    pub digest: String,
+    pub checkpoint: u64,
+}
+
+/// # Cursor store
+///
+/// Persistence of the scan position of each watched address, so a restarted
+/// [`DepositScanner`] continues where it stopped.
+pub trait CursorStore: Send + Sync {
+    /// cursor saved under the key
+    fn load(&self, key: &str) -> Result<Option<String>, SuiError>;
+    /// save the cursor under the key
+    fn save(&self, key: &str, cursor: &str) -> Result<(), SuiError>;
+}
+
+/// # Memory cursor store
+///
+/// Cursors kept in memory, clones share them.
+#[derive(Debug, Clone, Default)]
+pub struct MemoryCursorStore {
+    cursors: Arc<Mutex<HashMap<String, String>>>,
+}
+
+impl MemoryCursorStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl CursorStore for MemoryCursorStore {
+    fn load(&self, key: &str) -> Result<Option<String>, SuiError> {
+        let cursors = self.cursors.lock().unwrap_or_else(|e| e.into_inner());
+        Ok(cursors.get(key).cloned())
+    }
+
+    fn save(&self, key: &str, cursor: &str) -> Result<(), SuiError> {
+        let mut cursors = self.cursors.lock().unwrap_or_else(|e| e.into_inner());
+        cursors.insert(key.to_string(), cursor.to_string());
+        Ok(())
+    }
+}
+
+/// # File cursor store
+///
+/// Cursors in a JSON file, rewritten through a temporary file on every save so a crash
+/// leaves either the old or the new cursors.
+///
+/// ## Example
+/// ```rust
+/// use sui_network_sdk::deposit::{CursorStore, FileCursorStore};
+///
+/// let path = std::env::temp_dir().join(format!("cursors-{}.json", std::process::id()));
+/// let store = FileCursorStore::new(&path);
+/// assert_eq!(store.load("0xa1").unwrap(), None);
+/// store.save("0xa1", "4xYbR1").unwrap();
+/// store.save("0xb0b", "9WzSXd").unwrap();
+/// let reopened = FileCursorStore::new(&path);
+/// assert_eq!(reopened.load("0xa1").unwrap().as_deref(), Some("4xYbR1"));
+/// std::fs::remove_file(path).unwrap();
+/// ```
+#[derive(Debug)]
+pub struct FileCursorStore {
+    path: PathBuf,
+    lock: Mutex<()>,
+}
+
+impl FileCursorStore {
+    /// # creates a store in a file, created on the first save
+    ///
+    /// ## Parameters
+    /// - path : path of the JSON file
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self {
+            path: path.into(),
+            lock: Mutex::new(()),
+        }
+    }
+
+    /// cursors in the file, none when it does not exist yet
+    fn read(&self) -> Result<BTreeMap<String, String>, SuiError> {
+        match std::fs::read(&self.path) {
+            Ok(bytes) => Ok(serde_json::from_slice(&bytes)?),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(BTreeMap::new()),
+            Err(e) => Err(e.into()),
+        }
+    }
+}
+
+impl CursorStore for FileCursorStore {
+    fn load(&self, key: &str) -> Result<Option<String>, SuiError> {
+        let _guard = self.lock.lock().unwrap_or_else(|e| e.into_inner());
+        Ok(self.read()?.remove(key))
+    }
+
+    fn save(&self, key: &str, cursor: &str) -> Result<(), SuiError> {
+        let _guard = self.lock.lock().unwrap_or_else(|e| e.into_inner());
+        let mut cursors = self.read()?;
+        cursors.insert(key.to_string(), cursor.to_string());
+        let temporary = self.path.with_extension("tmp");
+        std::fs::write(&temporary, serde_json::to_vec_pretty(&cursors)?)?;
+        std::fs::rename(&temporary, &self.path)?;
+        Ok(())
+    }
+}
+
+/// # Deposit source
+///
+/// What wakes a [`DepositScanner`] that found nothing.
+///
+/// # Variants
+/// - Polling : the poll interval only
+/// - Listener : transactions of the watched addresses on a shared listener, or the poll
+///   interval, whichever comes first
+pub enum DepositSource {
+    Polling,
+    Listener(SharedListener),
+}
+
+/// # Deposit scanner
+///
+/// Detects coins arriving at watched addresses. Each address is read with
+/// `suix_queryTransactionBlocks` filtered by `ToAddress`, oldest first, and its positive
+/// balance changes become [`Deposit`]s. A transaction is only emitted once its checkpoint
+/// is `min_confirmations_checkpoints` behind the latest checkpoint, and the transactions
+/// after it wait for it, so deposits of an address come in chain order however the node
+/// or the listener delivers them.
+///
+/// Checkpoints are final, a checkpointed deposit is never reverted and there are no reorgs
+/// to wait out. The confirmation depth is about node lag: behind a load balancer a read
+/// may land on a node a few checkpoints behind the one that reported the deposit, waiting
+/// `K` checkpoints lets every node serve it before it is credited.
+///
+/// The cursor of an address is saved through the [`CursorStore`] when the deposits before
+/// it were handed out and the next one is requested. After a restart the deposits handed
+/// out last may come again, credit them idempotently by digest and address. Within a
+/// scanner a transaction is emitted once per address even when a node serves it again.
+///
+/// ## Example
+/// ```rust
+/// use std::sync::Arc;
+/// use sui_network_sdk::SuiClient;
+/// use sui_network_sdk::deposit::{CursorStore, DepositScanner, DepositSource, MemoryCursorStore};
+/// use sui_network_sdk::transport::MockTransport;
+/// use sui_network_sdk::types::SuiClientConfig;
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let a = format!("0x{:0>64}", "a1");
+///     let usdc = "0xdba3::usdc::USDC";
+///     let tx = |digest: &str, checkpoint: Option<u64>, changes: &[(&str, &str, i64)]| serde_json::json!({
+///         "digest": digest,
+///         "checkpoint": checkpoint.map(|c| c.to_string()),
+///         "balanceChanges": changes
+///             .iter()
+///             .map(|(owner, coin_type, amount)| serde_json::json!({
+///                 "owner": { "AddressOwner": owner },
+///                 "coinType": coin_type,
+///                 "amount": amount.to_string()
+///             }))
+///             .collect::<Vec<_>>()
+///     });
+///     let page = |data: Vec<serde_json::Value>| serde_json::json!({
+///         "data": data, "nextCursor": null, "hasNextPage": false
+///     });
+///     let t1 = tx("T1", Some(7), &[(&a, "0x2::sui::SUI", 100), ("0xb0b", "0x2::sui::SUI", -100)]);
+///     let t2 = tx("T2", Some(9), &[(&a, usdc, 5)]);
+///     // the recipient paid gas in SUI and received USDC
+///     let t3 = tx("T3", Some(11), &[(&a, "0x2::sui::SUI", -2000), (&a, usdc, 7)]);
+///     let mock = Arc::new(
+///         MockTransport::new()
+///             .with_result("sui_getLatestCheckpointSequenceNumber", serde_json::json!("10"))
+///             .with_result("sui_getLatestCheckpointSequenceNumber", serde_json::json!("12"))
+///             .with_result("sui_getLatestCheckpointSequenceNumber", serde_json::json!("13"))
+///             // T3 is seen before it is checkpointed
+///             .with_result("suix_queryTransactionBlocks", page(vec![t1.clone(), t2.clone(), tx("T3", None, &[])]))
+///             // a lagging node serves T1 again
+///             .with_result("suix_queryTransactionBlocks", page(vec![t1, t2, t3.clone()]))
+///             .with_result("suix_queryTransactionBlocks", page(vec![t3])),
+///     );
+///     let client = SuiClient::new_with_transport(SuiClientConfig::default(), mock.clone());
+///     let store = MemoryCursorStore::new();
+///     let mut scanner = DepositScanner::new(&client, DepositSource::Polling, &["0xa1"], 2)
+///         .unwrap()
+///         .with_cursor_store(store.clone());
+///
+///     // at checkpoint 10 only T1 is 2 checkpoints deep
+///     let deposit = scanner.next_deposit().await.unwrap();
+///     assert_eq!((deposit.digest.as_str(), deposit.amount, deposit.checkpoint), ("T1", 100, 7));
+///     assert_eq!(deposit.address, a);
+///     let deposit = scanner.next_deposit().await.unwrap();
+///     assert_eq!((deposit.digest.as_str(), deposit.coin_type.as_str()), ("T2", usdc));
+///     let deposit = scanner.next_deposit().await.unwrap();
+///     assert_eq!((deposit.digest.as_str(), deposit.amount), ("T3", 7));
+///     // T3 was handed out but not acknowledged by asking for the next deposit
+///     assert_eq!(store.load(&a).unwrap().as_deref(), Some("T2"));
+///
+///     let requests = mock.requests();
+///     let queries: Vec<_> = requests.iter().filter(|r| r.method == "suix_queryTransactionBlocks").collect();
+///     assert_eq!(queries[0].params[0]["filter"]["ToAddress"], a.as_str());
+///     assert_eq!(queries[0].params[1], serde_json::Value::Null);
+///     assert_eq!(queries[1].params[1], "T1");
+///
+///     // a restarted scanner continues after the saved cursor and hands out T3 again
+///     let mut restarted = DepositScanner::new(&client, DepositSource::Polling, &["0xa1"], 2)
+///         .unwrap()
+///         .with_cursor_store(store);
+///     assert_eq!(restarted.next_deposit().await.unwrap().digest, "T3");
+///     assert_eq!(mock.requests().last().unwrap().params[1], "T2");
+/// }
+/// ```
+pub struct DepositScanner<'a> {
+    client: &'a SuiClient,
+    addresses: Vec<String>,
+    watch: Option<(WatchList, WatchStream)>,
+    min_confirmations: u64,
+    poll_interval: Duration,
+    store: Box<dyn CursorStore>,
+    cursors: HashMap<String, Option<String>>,
+    unsaved: HashSet<String>,
+    ready: VecDeque<Deposit>,
+    emitted: HashSet<(String, String)>,
+    emitted_order: VecDeque<(String, String)>,
+}
+
+impl<'a> DepositScanner<'a> {
+    /// # creates a deposit scanner
+    ///
+    /// ## Parameters
+    /// - client : client reading the transactions
+    /// - source : what wakes the scanner between scans
+    /// - addresses : watched addresses
+    /// - min_confirmations_checkpoints : checkpoints the latest checkpoint must be past a
+    ///   transaction's before its deposits are emitted, `0` emits them once checkpointed
+    ///
+    /// ## Returns
+    /// - Ok(DepositScanner) : scanner keeping its cursors in memory
+    /// - Err(SuiError) : an address is not hex
+    pub fn new(
+        client: &'a SuiClient,
+        source: DepositSource,
+        addresses: &[&str],
+        min_confirmations_checkpoints: u64,
+    ) -> Result<Self, SuiError> {
+        let mut scanner = Self {
+            client,
+            addresses: Vec::new(),
+            watch: match source {
+                DepositSource::Polling => None,
+                DepositSource::Listener(listener) => Some(listener.watch_list()),
+            },
+            min_confirmations: min_confirmations_checkpoints,
+            poll_interval: DEFAULT_POLL_INTERVAL,
+            store: Box::new(MemoryCursorStore::new()),
+            cursors: HashMap::new(),
+            unsaved: HashSet::new(),
+            ready: VecDeque::new(),
+            emitted: HashSet::new(),
+            emitted_order: VecDeque::new(),
+        };
+        for address in addresses {
+            scanner.add_address(address)?;
+        }
+        Ok(scanner)
+    }
+
+    /// # set cursor store
+    ///
+    /// ## Parameters
+    /// - store : store the cursors are loaded from and saved to
+    ///
+    /// ## Returns
+    /// scanner persisting its cursors
+    pub fn with_cursor_store(mut self, store: impl CursorStore + 'static) -> Self {
+        self.store = Box::new(store);
+        self.cursors.clear();
+        self
+    }
+
+    /// # set poll interval
+    ///
+    /// ## Parameters
+    /// - poll_interval : wait after a scan finding nothing, one second by default
+    ///
+    /// ## Returns
+    /// scanner with the interval
+    pub fn with_poll_interval(mut self, poll_interval: Duration) -> Self {
+        self.poll_interval = poll_interval;
+        self
+    }
+
+    /// # Add address
+    ///
+    /// ## Parameters
+    /// - address : address to watch
+    ///
+    /// ## Returns
+    /// - Ok(bool) : whether the address was added, `false` if it was watched already
+    /// - Err(SuiError) : not a hex address
+    pub fn add_address(&mut self, address: &str) -> Result<bool, SuiError> {
+        let address = address::normalize(address)
+            .ok_or_else(|| SuiError::Hex(format!("invalid address {}", address)))?;
+        if self.addresses.contains(&address) {
+            return Ok(false);
+        }
+        if let Some((watch, _)) = &self.watch {
+            watch.add_address(&address)?;
+        }
+        self.addresses.push(address);
+        Ok(true)
+    }
+
+    /// # Remove address
+    ///
+    /// ## Parameters
+    /// - address : watched address
+    ///
+    /// ## Returns
+    /// whether the address was watched
+    pub fn remove_address(&mut self, address: &str) -> bool {
+        let Some(address) = address::normalize(address) else {
+            return false;
+        };
+        let Some(index) = self.addresses.iter().position(|a| *a == address) else {
+            return false;
+        };
+        self.addresses.remove(index);
+        if let Some((watch, _)) = &self.watch {
+            watch.remove_address(&address);
+        }
+        true
+    }
+
+    /// watched addresses, in the order they were added
+    pub fn addresses(&self) -> &[String] {
+        &self.addresses
+    }
+
+    /// # Next deposit
+    ///
+    /// Saves the cursors of the deposits handed out so far, then scans until a deposit is
+    /// confirmed, waiting for the source between scans finding nothing.
+    ///
+    /// ## Returns
+    /// - Ok(Deposit) : next deposit
+    /// - Err(SuiError) : a read or a cursor save failed, the scan can be retried
+    pub async fn next_deposit(&mut self) -> Result<Deposit, SuiError> {
+        loop {
+            if let Some(deposit) = self.ready.pop_front() {
+                return Ok(deposit);
+            }
+            self.save_cursors()?;
+            let deposits = self.scan().await?;
+            if deposits.is_empty() {
+                self.wait().await;
+            }
+            self.ready.extend(deposits);
+        }
+    }
+
+    /// confirmed deposits of all addresses after their cursors, moving the cursors in memory
+    async fn scan(&mut self) -> Result<Vec<Deposit>, SuiError> {
+        let tip = self.client.get_latest_checkpoint_sequence_number().await?;
+        let mut deposits = Vec::new();
+        for address in self.addresses.clone() {
+            self.scan_address(&address, tip, &mut deposits).await?;
+        }
+        Ok(deposits)
+    }
+
+    /// confirmed deposits of an address, stopping at the first unconfirmed transaction
+    async fn scan_address(
+        &mut self,
+        address: &str,
+        tip: u64,
+        deposits: &mut Vec<Deposit>,
+    ) -> Result<(), SuiError> {
+        let filter = TransactionFilter::ToAddress(address.to_string());
+        let options = TransactionBlockResponseOptions {
+            show_balance_changes: true,
+            ..Default::default()
+        };
+        let mut cursor = match self.cursors.get(address) {
+            Some(cursor) => cursor.clone(),
+            None => self.store.load(address)?,
+        };
+        loop {
+            let page = self
+                .client
+                .query_transaction_blocks(
+                    filter.clone(),
+                    options,
+                    cursor.clone(),
+                    Some(DEPOSIT_PAGE_SIZE),
+                    false,
+                )
+                .await?;
+            for tx in &page.data {
+                // later transactions wait, deposits are emitted in chain order
+                let Some(checkpoint) = tx
+                    .checkpoint
+                    .filter(|c| c.saturating_add(self.min_confirmations) <= tip)
+                else {
+                    self.cursors.insert(address.to_string(), cursor);
+                    return Ok(());
+                };
+                if self.remember(address, &tx.digest) {
+                    deposits.extend(deposits_of(address, tx, checkpoint));
+                }
+                cursor = Some(tx.digest.clone());
+                self.unsaved.insert(address.to_string());
+            }
+            if !page.has_next_page || page.data.is_empty() {
+                self.cursors.insert(address.to_string(), cursor);
+                return Ok(());
+            }
+        }
+    }
+
+    /// whether the transaction is new for the address, remembering it
+    fn remember(&mut self, address: &str, digest: &str) -> bool {
+        let key = (address.to_string(), digest.to_string());
+        if !self.emitted.insert(key.clone()) {
+            return false;
+        }
+        self.emitted_order.push_back(key);
+        if self.emitted_order.len() > DEDUP_CAPACITY
+            && let Some(oldest) = self.emitted_order.pop_front()
+        {
+            self.emitted.remove(&oldest);
+        }
+        true
+    }
+
+    /// persist the cursors moved since the last save
+    fn save_cursors(&mut self) -> Result<(), SuiError> {
+        for address in std::mem::take(&mut self.unsaved) {
+            if let Some(Some(cursor)) = self.cursors.get(&address) {
+                self.store.save(&address, cursor)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// wait for a watched transaction or the poll interval
+    async fn wait(&mut self) {
+        match &mut self.watch {
+            Some((_, transactions)) => {
+                let _ = tokio::time::timeout(self.poll_interval, transactions.next()).await;
+            }
+            None => tokio::time::sleep(self.poll_interval).await,
+        }
+    }
+}
+
+/// positive balance changes of the address in a transaction
+fn deposits_of(address: &str, tx: &TransactionResponse, checkpoint: u64) -> Vec<Deposit> {
+    tx.balance_changes
+        .iter()
+        .flatten()
+        .filter(|change| matches!(&change.owner, Owner::AddressOwner(owner) if owner == address))
+        .filter_map(|change| {
+            Some(Deposit {
+                address: address.to_string(),
+                coin_type: change.coin_type.clone(),
+                amount: received(change.amount)?,
+                digest: tx.digest.clone(),
+                checkpoint,
+            })
+        })
+        .collect()
+}
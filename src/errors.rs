@@ -0,0 +1,296 @@
+use crate::types::{TransactionPlan, address};
+use std::fmt;
+
+/// # Failure kind
+///
+/// Known kind of an execution failure, decoded from the error string of the effects or
+/// the error of a rejected submission. Parts missing from the string are `None`.
+///
+/// # Variants
+/// - InsufficientGas : the gas budget ran out
+/// - InvalidGasObject : the gas payment can't pay for gas
+/// - ObjectTooBig : an object grew over the size limit
+/// - MoveAbort : a Move function aborted, with its location and abort code
+/// - CommandArgumentError : an argument of a command is invalid, by index
+/// - InsufficientCoinBalance : a coin holds less than the amount taken from it
+/// - ObjectVersionUnavailable : an input object changed since the transaction was built
+/// - Other : any other failure, the raw error
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FailureKind {
+    InsufficientGas,
+    InvalidGasObject,
+    ObjectTooBig {
+        size: Option<u64>,
+        max_size: Option<u64>,
+    },
+    MoveAbort {
+        package: Option<String>,
+        module: Option<String>,
+        function: Option<String>,
+        code: Option<u64>,
+    },
+    CommandArgumentError {
+        argument: Option<usize>,
+        kind: Option<String>,
+    },
+    InsufficientCoinBalance,
+    ObjectVersionUnavailable {
+        object_id: Option<String>,
+        version: Option<u64>,
+        current_version: Option<u64>,
+    },
+    Other(String),
+}
+
+impl fmt::Display for FailureKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FailureKind::InsufficientGas => {
+                f.write_str("the gas budget ran out before execution finished")
+            }
+            FailureKind::InvalidGasObject => {
+                f.write_str("the gas payment is not a SUI coin the gas owner can spend")
+            }
+            FailureKind::ObjectTooBig { size, max_size } => match (size, max_size) {
+                (Some(size), Some(max_size)) => write!(
+                    f,
+                    "an object grew to {} bytes, over the limit of {} bytes",
+                    size, max_size
+                ),
+                _ => f.write_str("an object grew over the size limit"),
+            },
+            FailureKind::MoveAbort {
+                module,
+                function,
+                code,
+                ..
+            } => {
+                match (module, function) {
+                    (Some(module), Some(function)) => write!(f, "{}::{}", module, function)?,
+                    (Some(module), None) => write!(f, "module {}", module)?,
+                    _ => f.write_str("a Move function")?,
+                }
+                match code {
+                    Some(code) => write!(f, " aborted with code {}", code),
+                    None => f.write_str(" aborted"),
+                }
+            }
+            FailureKind::CommandArgumentError { argument, kind } => {
+                match argument {
+                    Some(argument) => write!(f, "argument {} is invalid", argument)?,
+                    None => f.write_str("an argument is invalid")?,
+                }
+                match kind {
+                    Some(kind) => write!(f, " ({})", kind),
+                    None => Ok(()),
+                }
+            }
+            FailureKind::InsufficientCoinBalance => {
+                f.write_str("a coin holds less than the amount taken from it")
+            }
+            FailureKind::ObjectVersionUnavailable {
+                object_id,
+                version,
+                current_version,
+            } => {
+                match object_id {
+                    Some(object_id) => write!(f, "object {}", object_id)?,
+                    None => f.write_str("an input object")?,
+                }
+                if let Some(version) = version {
+                    write!(f, " was used at version {}", version)?;
+                }
+                match current_version {
+                    Some(current_version) => {
+                        write!(f, " but is at version {} now", current_version)
+                    }
+                    None => f.write_str(" but changed since"),
+                }
+            }
+            FailureKind::Other(message) => f.write_str(message),
+        }
+    }
+}
+
+/// # Failure explanation
+///
+/// Execution failure decoded into its kind and the command that failed, see [`explain`].
+/// Displays as one sentence, e.g. `command 2 (Split 1.5 SUI off the gas coin) failed: a
+/// coin holds less than the amount taken from it`.
+///
+/// # Fields
+/// - kind : kind of the failure
+/// - command : index of the failed command, when the error names one
+/// - step : description of the failed command in the transaction plan, when known
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FailureExplanation {
+    pub kind: FailureKind,
+    pub command: Option<usize>,
+    pub step: Option<String>,
+}
+
+impl FailureExplanation {
+    /// # With plan
+    ///
+    /// ## Parameters
+    /// - plan : plan of the failed transaction, see [`Trade::plan`]
+    ///
+    /// ## Returns
+    /// explanation naming the step of the failed command
+    ///
+    /// [`Trade::plan`]: crate::trade::Trade::plan
+    pub fn with_plan(mut self, plan: &TransactionPlan) -> Self {
+        self.step = self
+            .command
+            .and_then(|command| plan.commands.get(command))
+            .cloned();
+        self
+    }
+
+    /// whether the failure is of a known kind
+    pub fn is_known(&self) -> bool {
+        !matches!(self.kind, FailureKind::Other(_))
+    }
+}
+
+impl fmt::Display for FailureExplanation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (self.command, &self.step) {
+            (Some(command), Some(step)) => write!(f, "command {} ({}) failed: ", command, step)?,
+            (Some(command), None) => write!(f, "command {} failed: ", command)?,
+            _ => f.write_str("transaction failed: ")?,
+        }
+        write!(f, "{}", self.kind)
+    }
+}
+
+/// # Explain
+///
+/// Decodes an execution error, e.g. `InsufficientCoinBalance in command 2`, into its kind
+/// and the index of the failed command. Both the effects format and the messages of
+/// rejected submissions are read.
+///
+/// ## Parameters
+/// - error : error string
+///
+/// ## Returns
+/// explanation, of kind `Other` when the error is not a known failure
+///
+/// ## Example
+/// ```rust
+/// use sui_network_sdk::errors::{self, FailureKind};
+///
+/// // every failure format of the fixtures, with its explanation
+/// let fixtures: serde_json::Value = serde_json::from_str(include_str!(concat!(
+///     env!("CARGO_MANIFEST_DIR"),
+///     "/fixtures/execution_failures.json"
+/// )))
+/// .unwrap();
+/// for fixture in fixtures.as_array().unwrap() {
+///     let explanation = errors::explain(fixture["error"].as_str().unwrap());
+///     assert_eq!(explanation.to_string(), fixture["explanation"], "{}", fixture["error"]);
+///     assert_eq!(explanation.command, fixture["command"].as_u64().map(|c| c as usize));
+///     assert!(explanation.is_known());
+/// }
+///
+/// let abort = errors::explain(
+///     r#"MoveAbort(MoveLocation { module: ModuleId { address: 5a0b, name: Identifier("pool") }, function: 3, instruction: 12, function_name: Some("swap") }, 2) in command 1"#,
+/// );
+/// assert_eq!(abort.kind, FailureKind::MoveAbort {
+///     package: Some(format!("0x{:0>64}", "5a0b")),
+///     module: Some("pool".to_string()),
+///     function: Some("swap".to_string()),
+///     code: Some(2),
+/// });
+/// assert!(!errors::explain("SomethingNew").is_known());
+/// ```
+pub fn explain(error: &str) -> FailureExplanation {
+    let (failure, command) = match error.rsplit_once(" in command ") {
+        Some((failure, command)) => (failure, command.trim().parse().ok()),
+        None => (error, None),
+    };
+    FailureExplanation {
+        kind: failure_kind(failure),
+        command,
+        step: None,
+    }
+}
+
+/// kind of a failure without its command
+fn failure_kind(failure: &str) -> FailureKind {
+    let failure = failure.trim();
+    let name = failure
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .next()
+        .unwrap_or_default();
+    match name {
+        "InsufficientGas" => FailureKind::InsufficientGas,
+        "InvalidGasObject" => FailureKind::InvalidGasObject,
+        "InsufficientCoinBalance" => FailureKind::InsufficientCoinBalance,
+        "ObjectTooBig" => FailureKind::ObjectTooBig {
+            size: field(failure, "object_size: ").and_then(|size| size.parse().ok()),
+            max_size: field(failure, "max_object_size: ").and_then(|size| size.parse().ok()),
+        },
+        "MoveAbort" => FailureKind::MoveAbort {
+            package: field(failure, "address: ").and_then(address::normalize),
+            module: quoted(failure, "Identifier(\""),
+            function: quoted(failure, "function_name: Some(\""),
+            code: failure
+                .rsplit_once("},")
+                .and_then(|(_, rest)| rest.split(')').next())
+                .and_then(|code| code.trim().parse().ok()),
+        },
+        "CommandArgumentError" => FailureKind::CommandArgumentError {
+            argument: field(failure, "arg_idx: ").and_then(|index| index.parse().ok()),
+            kind: field(failure, "kind: ").map(str::to_string),
+        },
+        "ObjectVersionUnavailableForConsumption" => FailureKind::ObjectVersionUnavailable {
+            object_id: field(failure, "provided_obj_ref: (").and_then(address::normalize),
+            version: failure
+                .split("SequenceNumber(")
+                .nth(1)
+                .and_then(sequence_number),
+            current_version: field(failure, "current_version: SequenceNumber(")
+                .and_then(sequence_number),
+        },
+        // the message of a submission rejected for a stale object
+        _ if failure.contains("is not available for consumption") => {
+            FailureKind::ObjectVersionUnavailable {
+                object_id: field(failure, "Object ID ").and_then(address::normalize),
+                version: field(failure, "Version ").and_then(sequence_number),
+                current_version: field(failure, "current version: ").and_then(sequence_number),
+            }
+        }
+        _ => FailureKind::Other(failure.to_string()),
+    }
+}
+
+/// token following a key, up to the next separator
+fn field<'a>(s: &'a str, key: &str) -> Option<&'a str> {
+    let rest = &s[s.find(key)? + key.len()..];
+    let end = rest.find([',', ' ', '}', ')']).unwrap_or(rest.len());
+    Some(&rest[..end]).filter(|token| !token.is_empty())
+}
+
+/// string following an opening quote, up to the closing one
+fn quoted(s: &str, key: &str) -> Option<String> {
+    let rest = &s[s.find(key)? + key.len()..];
+    rest.split('"').next().map(str::to_string)
+}
+
+/// leading version of a token, decimal as `SequenceNumber(5)` prints it or hex as `0x5`
+fn sequence_number(token: &str) -> Option<u64> {
+    match token.strip_prefix("0x") {
+        Some(hex) => {
+            let end = hex
+                .find(|c: char| !c.is_ascii_hexdigit())
+                .unwrap_or(hex.len());
+            u64::from_str_radix(&hex[..end], 16).ok()
+        }
+        None => token
+            .split(|c: char| !c.is_ascii_digit())
+            .next()?
+            .parse()
+            .ok(),
+    }
+}
@@ -0,0 +1,258 @@
+use crate::SuiClient;
+use crate::listener::{ListenerEvent, SharedListener, SharedStream, SubscriptionFilter};
+use crate::types::{CheckpointId, Event, EventFilter, EventId, SuiError};
+use futures::StreamExt;
+use std::collections::{HashSet, VecDeque};
+use std::time::Duration;
+
+/// events per query page, the node's largest page
+const EVENT_PAGE_SIZE: usize = 50;
+/// wait before a closed live subscription is reopened
+const RESUBSCRIBE_DELAY: Duration = Duration::from_secs(1);
+
+/// # Event start
+///
+/// Where [`SuiClient::events_from`] starts reading.
+///
+/// # Variants
+/// - Beginning : the first event of the chain
+/// - Cursor : after an event, e.g. the id of the last event processed before a restart
+/// - Checkpoint : the events of transactions checkpointed at or after the checkpoint's
+///   timestamp, events of an earlier checkpoint with the same timestamp included
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EventStart {
+    Beginning,
+    Cursor(EventId),
+    Checkpoint(u64),
+}
+
+/// # Live events
+///
+/// How [`SuiClient::events_from`] follows new events once the history is drained.
+///
+/// # Variants
+/// - Polling : a query page from the last event every interval
+/// - Listener : an event subscription of a shared listener
+pub enum LiveEvents {
+    Polling(Duration),
+    Listener(SharedListener),
+}
+
+/// reading phase of an event feed
+enum Phase {
+    /// query pages until the last one
+    Backfill,
+    /// query pages from a cursor while the subscription buffers, until the last one
+    CatchUp,
+    /// subscription notifications, or query pages every interval when polling
+    Live,
+}
+
+/// state of [`SuiClient::events_from`]
+struct EventFeed<'a> {
+    client: &'a SuiClient,
+    filter: EventFilter,
+    live: LiveEvents,
+    start: Option<EventStart>,
+    // id of the last event emitted
+    cursor: Option<EventId>,
+    // cursor the catch-up reads after
+    catch_up_cursor: Option<EventId>,
+    phase: Phase,
+    ready: VecDeque<Event>,
+    subscription: Option<SharedStream>,
+    // ids of the last backfill page and of the catch-up, the live duplicates to drop
+    seen: HashSet<EventId>,
+}
+
+impl EventFeed<'_> {
+    /// next event of the feed
+    async fn next(&mut self) -> Result<Event, SuiError> {
+        if let Some(start) = self.start.take() {
+            self.cursor = self.client.event_cursor(start).await?;
+        }
+        loop {
+            if let Some(event) = self.ready.pop_front() {
+                self.cursor = Some(event.id.clone());
+                return Ok(event);
+            }
+            match self.phase {
+                Phase::Backfill => {
+                    let from = self.cursor.clone();
+                    let page = self.page(from.clone()).await?;
+                    // the catch-up reads the last page again, its events must not repeat
+                    self.seen = page.data.iter().map(|event| event.id.clone()).collect();
+                    self.ready.extend(page.data);
+                    if !page.has_next_page {
+                        self.catch_up_cursor = from;
+                        self.phase = self.after_history().await;
+                    }
+                }
+                Phase::CatchUp => {
+                    let page = self.page(self.catch_up_cursor.clone()).await?;
+                    self.catch_up_cursor = page
+                        .data
+                        .last()
+                        .map(|event| event.id.clone())
+                        .or(self.catch_up_cursor.take());
+                    for event in page.data {
+                        if self.seen.insert(event.id.clone()) {
+                            self.ready.push_back(event);
+                        }
+                    }
+                    if !page.has_next_page {
+                        self.phase = Phase::Live;
+                    }
+                }
+                Phase::Live => self.live().await?,
+            }
+        }
+    }
+
+    /// phase following the drained history, opens the live subscription
+    async fn after_history(&mut self) -> Phase {
+        match &self.live {
+            LiveEvents::Polling(_) => Phase::Live,
+            LiveEvents::Listener(listener) => {
+                let filter = SubscriptionFilter::Events(self.filter.clone());
+                self.subscription = Some(listener.subscribe(filter));
+                Phase::CatchUp
+            }
+        }
+    }
+
+    /// wait for live events and queue them
+    async fn live(&mut self) -> Result<(), SuiError> {
+        let LiveEvents::Listener(listener) = &self.live else {
+            let LiveEvents::Polling(interval) = self.live else {
+                unreachable!("live events poll or listen")
+            };
+            tokio::time::sleep(interval).await;
+            let page = self.page(self.cursor.clone()).await?;
+            self.ready.extend(page.data);
+            return Ok(());
+        };
+        let notification = match &mut self.subscription {
+            Some(subscription) => subscription.next().await,
+            None => None,
+        };
+        match notification {
+            Some(ListenerEvent::Message(message)) => {
+                let event: Event = serde_json::from_value(message)?;
+                // a live event already read by the catch-up
+                if !self.seen.remove(&event.id) {
+                    self.ready.push_back(event);
+                }
+            }
+            Some(ListenerEvent::Lagged(_)) | Some(ListenerEvent::WatchdogRestart { .. }) => {
+                // notifications were missed, read them from the last event
+                self.catch_up_cursor = self.cursor.clone();
+                self.phase = Phase::CatchUp;
+            }
+            Some(ListenerEvent::GapDetected { .. }) => {}
+            Some(ListenerEvent::Closed(_)) | Some(ListenerEvent::InternalError { .. }) | None => {
+                self.subscription = None;
+                tokio::time::sleep(RESUBSCRIBE_DELAY).await;
+                let filter = SubscriptionFilter::Events(self.filter.clone());
+                self.subscription = Some(listener.subscribe(filter));
+                self.catch_up_cursor = self.cursor.clone();
+                self.phase = Phase::CatchUp;
+            }
+        }
+        Ok(())
+    }
+
+    /// page of events after a cursor, oldest first
+    async fn page(
+        &self,
+        cursor: Option<EventId>,
+    ) -> Result<crate::types::Page<Event, EventId>, SuiError> {
+        self.client
+            .query_events(self.filter.clone(), cursor, Some(EVENT_PAGE_SIZE), false)
+            .await
+    }
+}
+
+impl SuiClient {
+    /// # Events from
+    ///
+    /// Streams the events matching a filter from a start, first the history page by page
+    /// with [`SuiClient::query_events`], then live events. The live subscription is opened
+    /// once the history is drained and the last page is read again while it buffers, so
+    /// events emitted during the backfill are neither missed nor repeated. Events are
+    /// deduplicated by [`EventId`] at the splice, and missed notifications (lag, watchdog
+    /// restarts, closed subscriptions) are read again from the last event emitted.
+    ///
+    /// ## Parameters
+    /// - filter : event filter, also the filter of the live subscription
+    /// - start : where to start reading
+    /// - live : how new events are followed
+    ///
+    /// ## Returns
+    /// stream of events oldest first, ending after the first error
+    ///
+    /// ## Example
+    /// ```no_run
+    /// use futures::StreamExt;
+    /// use sui_network_sdk::SuiClient;
+    /// use sui_network_sdk::events::{EventStart, LiveEvents};
+    /// use sui_network_sdk::global::mainnet;
+    /// use sui_network_sdk::listener::SharedListener;
+    /// use sui_network_sdk::types::EventFilter;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let client = SuiClient::new_by_rpc_url(mainnet::RPC_URL.to_string());
+    ///     let filter = EventFilter::MoveEventType("0xdee9::clob::OrderFilled".to_string());
+    ///     let live = LiveEvents::Listener(SharedListener::new(mainnet::WSS_URL.to_string()));
+    ///     let mut events = std::pin::pin!(client.events_from(filter, EventStart::Checkpoint(1_000_000), live));
+    ///     while let Some(event) = events.next().await {
+    ///         println!("{:?}", event.unwrap().id);
+    ///     }
+    /// }
+    /// ```
+    pub fn events_from(
+        &self,
+        filter: EventFilter,
+        start: EventStart,
+        live: LiveEvents,
+    ) -> impl futures::Stream<Item = Result<Event, SuiError>> + '_ {
+        let feed = EventFeed {
+            client: self,
+            filter,
+            live,
+            start: Some(start),
+            cursor: None,
+            catch_up_cursor: None,
+            phase: Phase::Backfill,
+            ready: VecDeque::new(),
+            subscription: None,
+            seen: HashSet::new(),
+        };
+        let events = futures::stream::unfold(feed, |mut feed| async move {
+            let event = feed.next().await;
+            Some((event, feed))
+        });
+        crate::until_error(events)
+    }
+
+    /// cursor the events of a start follow
+    async fn event_cursor(&self, start: EventStart) -> Result<Option<EventId>, SuiError> {
+        match start {
+            EventStart::Beginning => Ok(None),
+            EventStart::Cursor(cursor) => Ok(Some(cursor)),
+            EventStart::Checkpoint(sequence_number) => {
+                let checkpoint = self
+                    .get_checkpoint(CheckpointId::SequenceNumber(sequence_number))
+                    .await?;
+                // the last event before the checkpoint's timestamp
+                let filter = EventFilter::TimeRange {
+                    start_time: 0,
+                    end_time: checkpoint.timestamp_ms,
+                };
+                let page = self.query_events(filter, None, Some(1), true).await?;
+                Ok(page.data.into_iter().next().map(|event| event.id))
+            }
+        }
+    }
+}
@@ -0,0 +1,120 @@
+use crate::SuiClient;
+use crate::types::{SuiError, TransactionResponse};
+
+/// chain identifier of mainnet
+pub const MAINNET_CHAIN_ID: &str = "35834a8a";
+/// chain identifier of testnet
+pub const TESTNET_CHAIN_ID: &str = "4c78adac";
+
+/// # Explorer
+///
+/// Block explorer links are generated for.
+///
+/// # Variants
+/// - SuiScan : suiscan.xyz, the default
+/// - SuiVision : suivision.xyz
+/// - Custom : base url of an explorer serving `/tx/`, `/object/` and `/account/` paths
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum Explorer {
+    #[default]
+    SuiScan,
+    SuiVision,
+    Custom(String),
+}
+
+/// # Network
+///
+/// Network of a chain identifier, devnet and local networks get a new identifier on every
+/// reset and are not recognized.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Network {
+    Mainnet,
+    Testnet,
+    Unknown(String),
+}
+
+impl Network {
+    /// network of the chain identifier
+    pub fn from_chain_identifier(chain_identifier: &str) -> Self {
+        match chain_identifier {
+            MAINNET_CHAIN_ID => Network::Mainnet,
+            TESTNET_CHAIN_ID => Network::Testnet,
+            other => Network::Unknown(other.to_string()),
+        }
+    }
+}
+
+/// # Explorer links
+///
+/// Urls of transactions, objects and addresses on an explorer, for the network the client
+/// is connected to, see [`SuiClient::explorer_links`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExplorerLinks {
+    base_url: String,
+    tx_path: &'static str,
+}
+
+impl ExplorerLinks {
+    /// # creates links of an explorer on a network
+    ///
+    /// ## Parameters
+    /// - explorer : explorer
+    /// - network : network of the links
+    ///
+    /// ## Returns
+    /// - Ok(ExplorerLinks) : links
+    /// - Err(SuiError::Unsupported) : the explorer does not serve an unknown network
+    pub fn new(explorer: &Explorer, network: &Network) -> Result<Self, SuiError> {
+        let (base_url, tx_path) = match (explorer, network) {
+            (Explorer::Custom(base_url), _) => (base_url.trim_end_matches('/').to_string(), "tx"),
+            (_, Network::Unknown(chain_identifier)) => {
+                return Err(SuiError::Unsupported(format!(
+                    "{:?} has no network for chain {}",
+                    explorer, chain_identifier
+                )));
+            }
+            (Explorer::SuiScan, Network::Mainnet) => {
+                ("https://suiscan.xyz/mainnet".to_string(), "tx")
+            }
+            (Explorer::SuiScan, Network::Testnet) => {
+                ("https://suiscan.xyz/testnet".to_string(), "tx")
+            }
+            (Explorer::SuiVision, Network::Mainnet) => {
+                ("https://suivision.xyz".to_string(), "txblock")
+            }
+            (Explorer::SuiVision, Network::Testnet) => {
+                ("https://testnet.suivision.xyz".to_string(), "txblock")
+            }
+        };
+        Ok(Self { base_url, tx_path })
+    }
+
+    /// url of a transaction
+    pub fn tx_url(&self, digest: &str) -> String {
+        format!("{}/{}/{}", self.base_url, self.tx_path, digest)
+    }
+
+    /// url of an object
+    pub fn object_url(&self, object_id: &str) -> String {
+        format!("{}/object/{}", self.base_url, object_id)
+    }
+
+    /// url of an address
+    pub fn address_url(&self, address: &str) -> String {
+        format!("{}/account/{}", self.base_url, address)
+    }
+}
+
+impl TransactionResponse {
+    /// # Explorer url
+    ///
+    /// ## Parameters
+    /// - client : client the transaction was read or executed with
+    ///
+    /// ## Returns
+    /// - Ok(String) : url of the transaction on the client's explorer
+    /// - Err(SuiError) : error
+    pub async fn explorer_url(&self, client: &SuiClient) -> Result<String, SuiError> {
+        Ok(client.explorer_links().await?.tx_url(&self.digest))
+    }
+}
@@ -0,0 +1,430 @@
+use crate::types::{LedgerError, SuiError};
+use crate::wallet::{SignatureScheme, Signer};
+use base64::Engine;
+use base64::prelude::BASE64_STANDARD;
+use fastcrypto::hash::{HashFunction, Sha256};
+use futures::future::BoxFuture;
+use std::collections::{HashMap, VecDeque};
+use std::sync::Mutex;
+
+/// default Sui derivation path of the first account
+pub const DEFAULT_DERIVATION_PATH: &str = "m/44'/784'/0'/0'/0'";
+
+/// Sui app instruction class
+const CLA: u8 = 0x00;
+/// Sui app instruction deriving a public key
+const INS_GET_PUBLIC_KEY: u8 = 0x02;
+/// Sui app instruction signing an intent message
+const INS_SIGN_TRANSACTION: u8 = 0x03;
+/// payload bytes per block of the block protocol
+const BLOCK_DATA_SIZE: usize = 180;
+/// status word of a successful command
+const SW_OK: u16 = 0x9000;
+/// Ledger USB vendor id
+const LEDGER_VENDOR_ID: u16 = 0x2c97;
+/// HID usage page of the Ledger APDU interface
+const LEDGER_USAGE_PAGE: u16 = 0xffa0;
+/// HID report size
+const HID_PACKET_SIZE: usize = 64;
+/// HID channel id
+const HID_CHANNEL: u16 = 0x0101;
+/// HID APDU tag
+const HID_TAG_APDU: u8 = 0x05;
+/// device answer timeout, long enough for the user to confirm on the device
+const HID_TIMEOUT_MS: i32 = 120_000;
+
+/// host to device block protocol instructions
+const HOST_START: u8 = 0x00;
+const HOST_GET_CHUNK_SUCCESS: u8 = 0x01;
+const HOST_GET_CHUNK_FAILURE: u8 = 0x02;
+const HOST_PUT_CHUNK_RESPONSE: u8 = 0x03;
+const HOST_RESULT_ACCUMULATING_RESPONSE: u8 = 0x04;
+
+/// device to host block protocol instructions
+const DEVICE_RESULT_ACCUMULATING: u8 = 0x00;
+const DEVICE_RESULT_FINAL: u8 = 0x01;
+const DEVICE_GET_CHUNK: u8 = 0x02;
+const DEVICE_PUT_CHUNK: u8 = 0x03;
+
+/// # APDU transport
+///
+/// Exchanges APDU commands with a Ledger device.
+pub trait ApduTransport: Send + Sync {
+    /// send a command APDU, returns the response including its trailing status word
+    fn exchange(&self, apdu: &[u8]) -> Result<Vec<u8>, SuiError>;
+}
+
+/// # HID transport
+///
+/// USB HID connection to a Ledger device.
+pub struct HidTransport {
+    device: Mutex<hidapi::HidDevice>,
+}
+
+impl HidTransport {
+    /// # Open
+    ///
+    /// Opens the first connected Ledger device.
+    ///
+    /// ## Returns
+    /// - Ok(HidTransport) : device connection
+    /// - Err(SuiError) : `LedgerError::DeviceNotFound` without a device, or HID error
+    pub fn open() -> Result<Self, SuiError> {
+        let api = hidapi::HidApi::new().map_err(hid_error)?;
+        let info = api
+            .device_list()
+            .filter(|d| d.vendor_id() == LEDGER_VENDOR_ID)
+            // the usage page is unknown on linux, the APDU interface is interface 0 there
+            .find(|d| d.usage_page() == LEDGER_USAGE_PAGE || d.interface_number() == 0)
+            .ok_or(SuiError::Ledger(LedgerError::DeviceNotFound))?;
+        let device = info.open_device(&api).map_err(hid_error)?;
+        Ok(Self {
+            device: Mutex::new(device),
+        })
+    }
+}
+
+impl ApduTransport for HidTransport {
+    fn exchange(&self, apdu: &[u8]) -> Result<Vec<u8>, SuiError> {
+        let device = self.device.lock().unwrap_or_else(|e| e.into_inner());
+        let mut framed = Vec::with_capacity(apdu.len() + 2);
+        framed.extend_from_slice(&(apdu.len() as u16).to_be_bytes());
+        framed.extend_from_slice(apdu);
+        for (sequence, chunk) in framed.chunks(HID_PACKET_SIZE - 5).enumerate() {
+            // leading report id 0
+            let mut packet = vec![0u8];
+            packet.extend_from_slice(&HID_CHANNEL.to_be_bytes());
+            packet.push(HID_TAG_APDU);
+            packet.extend_from_slice(&(sequence as u16).to_be_bytes());
+            packet.extend_from_slice(chunk);
+            packet.resize(HID_PACKET_SIZE + 1, 0);
+            device.write(&packet).map_err(hid_error)?;
+        }
+        let mut response = Vec::new();
+        let mut expected = None;
+        let mut sequence = 0u16;
+        while expected.is_none_or(|length| response.len() < length) {
+            let mut packet = [0u8; HID_PACKET_SIZE];
+            let read = device
+                .read_timeout(&mut packet, HID_TIMEOUT_MS)
+                .map_err(hid_error)?;
+            if read == 0 {
+                return Err(SuiError::Ledger(LedgerError::Transport(
+                    "device timed out".to_string(),
+                )));
+            }
+            if packet[..3] != [0x01, 0x01, HID_TAG_APDU] || packet[3..5] != sequence.to_be_bytes() {
+                return Err(SuiError::Ledger(LedgerError::InvalidResponse(
+                    "unexpected HID packet header".to_string(),
+                )));
+            }
+            let data = match expected {
+                None => {
+                    expected = Some(u16::from_be_bytes([packet[5], packet[6]]) as usize);
+                    &packet[7..read]
+                }
+                Some(_) => &packet[5..read],
+            };
+            response.extend_from_slice(data);
+            sequence += 1;
+        }
+        response.truncate(expected.unwrap_or(0));
+        Ok(response)
+    }
+}
+
+/// map a HID error to a transport error
+fn hid_error(error: hidapi::HidError) -> SuiError {
+    SuiError::Ledger(LedgerError::Transport(error.to_string()))
+}
+
+/// # Mock APDU transport
+///
+/// Replays a transcript of expected commands and device responses, for tests without a device.
+#[derive(Default)]
+pub struct MockApduTransport {
+    transcript: Mutex<VecDeque<(Vec<u8>, Vec<u8>)>>,
+}
+
+impl MockApduTransport {
+    /// create an empty transcript
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// expect a command and answer it with a response, including its status word
+    pub fn with_exchange(self, command: Vec<u8>, response: Vec<u8>) -> Self {
+        self.lock_transcript().push_back((command, response));
+        self
+    }
+
+    /// number of exchanges not yet consumed
+    pub fn remaining(&self) -> usize {
+        self.lock_transcript().len()
+    }
+
+    fn lock_transcript(&self) -> std::sync::MutexGuard<'_, VecDeque<(Vec<u8>, Vec<u8>)>> {
+        self.transcript.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+impl ApduTransport for MockApduTransport {
+    fn exchange(&self, apdu: &[u8]) -> Result<Vec<u8>, SuiError> {
+        match self.lock_transcript().pop_front() {
+            Some((command, response)) if command == apdu => Ok(response),
+            Some((command, _)) => Err(SuiError::Ledger(LedgerError::Transport(format!(
+                "expected command {}, got {}",
+                hex::encode(command),
+                hex::encode(apdu)
+            )))),
+            None => Err(SuiError::Ledger(LedgerError::Transport(format!(
+                "unexpected command {}",
+                hex::encode(apdu)
+            )))),
+        }
+    }
+}
+
+/// # Ledger Signer
+///
+/// Signs with the Sui app of a Ledger device. The app receives the intent message and
+/// signs its digest after the user confirms on the device.
+///
+/// ## Example
+/// ```rust
+/// use fastcrypto::hash::{HashFunction, Sha256};
+/// use sui_network_sdk::ledger::{LedgerSigner, MockApduTransport};
+/// use sui_network_sdk::types::{LedgerError, SuiError};
+/// use sui_network_sdk::wallet::{Signer, Wallet};
+///
+/// # #[tokio::main]
+/// # async fn main() {
+/// // the device key, held by a wallet to produce the signatures the device would
+/// let device = Wallet::new().unwrap();
+/// let public_key = device.get_public_key_bytes_vec();
+/// let tx_bytes = vec![0x00, 0x01, 0x02];
+///
+/// // blocks of the block protocol: hash of the next block followed by up to 180 bytes
+/// let block = |data: &[u8]| [vec![0u8; 32], data.to_vec()].concat();
+/// let hash = |block: &[u8]| Sha256::digest(block).digest.to_vec();
+/// let apdu = |ins: u8, data: Vec<u8>| [vec![0x00, ins, 0x00, 0x00, data.len() as u8], data].concat();
+/// let path = block(&[5, 44, 0, 0, 0x80, 16, 3, 0, 0x80, 0, 0, 0, 0x80, 0, 0, 0, 0x80, 0, 0, 0, 0x80]);
+/// let tx = block(&[[6u8, 0, 0, 0, 0, 0, 0].as_slice(), &tx_bytes].concat()); // length, intent, bytes
+/// let ok = |payload: Vec<u8>| [payload, vec![0x90, 0x00]].concat();
+///
+/// let transport = MockApduTransport::new()
+///     // derive the public key: start, the device asks for the path block, then answers
+///     .with_exchange(apdu(0x02, [vec![0x00], hash(&path)].concat()), ok([vec![0x02], hash(&path)].concat()))
+///     .with_exchange(apdu(0x02, [vec![0x01], path.clone()].concat()), ok([vec![0x01, 32], public_key.clone()].concat()))
+///     // sign: the device asks for the transaction and the path blocks
+///     .with_exchange(apdu(0x03, [vec![0x00], hash(&tx), hash(&path)].concat()), ok([vec![0x02], hash(&tx)].concat()))
+///     .with_exchange(apdu(0x03, [vec![0x01], tx.clone()].concat()), ok([vec![0x02], hash(&path)].concat()))
+///     .with_exchange(apdu(0x03, [vec![0x01], path.clone()].concat()), ok([vec![0x01], device.sign_transaction(&tx_bytes)].concat()))
+///     // the user rejects the next request
+///     .with_exchange(apdu(0x03, [vec![0x00], hash(&tx), hash(&path)].concat()), vec![0x69, 0x85]);
+///
+/// let signer = LedgerSigner::new(transport, "m/44'/784'/0'/0'/0'").unwrap();
+/// assert_eq!(Signer::address(&signer), device.get_address());
+/// let signature = Signer::sign_transaction(&signer, &tx_bytes).await.unwrap();
+/// assert_eq!(signature, device.sign_transaction(&tx_bytes));
+/// assert!(matches!(
+///     Signer::sign_transaction(&signer, &tx_bytes).await,
+///     Err(SuiError::Ledger(LedgerError::UserRejected))
+/// ));
+/// # }
+/// ```
+pub struct LedgerSigner<T: ApduTransport = HidTransport> {
+    transport: T,
+    path: Vec<u8>, // BIP32 path payload
+    public_key: Vec<u8>,
+    address: String,
+}
+
+impl LedgerSigner<HidTransport> {
+    /// # Connect
+    ///
+    /// Opens the first connected Ledger device and derives the address of the path.
+    ///
+    /// ## Parameters
+    /// - path : BIP32 derivation path, e.g. [`DEFAULT_DERIVATION_PATH`]
+    ///
+    /// ## Returns
+    /// - Ok(LedgerSigner) : signer of the derived address
+    /// - Err(SuiError) : `SuiError::Ledger` naming the device error
+    ///
+    /// ## Example
+    /// ```no_run
+    /// use sui_network_sdk::ledger::{DEFAULT_DERIVATION_PATH, LedgerSigner};
+    /// use sui_network_sdk::wallet::Signer;
+    /// let signer = LedgerSigner::connect(DEFAULT_DERIVATION_PATH).unwrap();
+    /// println!("Ledger address: {}", signer.address());
+    /// ```
+    pub fn connect(path: &str) -> Result<Self, SuiError> {
+        Self::new(HidTransport::open()?, path)
+    }
+}
+
+impl<T: ApduTransport> LedgerSigner<T> {
+    /// # create ledger signer
+    ///
+    /// ## Parameters
+    /// - transport : APDU transport of the device
+    /// - path : BIP32 derivation path
+    ///
+    /// ## Returns
+    /// - Ok(LedgerSigner) : signer of the derived address
+    /// - Err(SuiError) : `SuiError::Ledger` naming the device error
+    pub fn new(transport: T, path: &str) -> Result<Self, SuiError> {
+        let path = bip32_path(path)?;
+        let mut signer = Self {
+            transport,
+            path: path.clone(),
+            public_key: Vec::new(),
+            address: String::new(),
+        };
+        let response = signer.send_blocks(INS_GET_PUBLIC_KEY, &[&path])?;
+        let public_key = response
+            .split_first()
+            .and_then(|(&size, rest)| rest.get(..size as usize))
+            .filter(|key| key.len() == 32)
+            .ok_or_else(|| invalid_response("public key"))?;
+        signer.address = SignatureScheme::Ed25519.address(public_key);
+        signer.public_key = public_key.to_vec();
+        Ok(signer)
+    }
+
+    /// sign an intent message on the device
+    fn sign_intent_message(&self, intent_message: &[u8]) -> Result<Vec<u8>, SuiError> {
+        let mut payload = (intent_message.len() as u32).to_le_bytes().to_vec();
+        payload.extend_from_slice(intent_message);
+        let response = self.send_blocks(INS_SIGN_TRANSACTION, &[&payload, &self.path])?;
+        response
+            .get(..64)
+            .map(<[u8]>::to_vec)
+            .ok_or_else(|| invalid_response("signature"))
+    }
+
+    /// run the block protocol: the payloads are sent as hash linked blocks the device
+    /// requests by hash, its answer may come in several parts
+    fn send_blocks(&self, ins: u8, payloads: &[&[u8]]) -> Result<Vec<u8>, SuiError> {
+        let mut blocks = HashMap::new();
+        let mut command = vec![HOST_START];
+        for payload in payloads {
+            // linked from the last block, each block holds the hash of the next
+            let mut next = [0u8; 32];
+            let chunks: Vec<&[u8]> = payload.chunks(BLOCK_DATA_SIZE).collect();
+            for chunk in chunks.iter().rev() {
+                let block = [next.as_slice(), chunk].concat();
+                next = Sha256::digest(&block).digest;
+                blocks.insert(next, block);
+            }
+            command.extend_from_slice(&next);
+        }
+        let mut result = Vec::new();
+        loop {
+            let response = self.exchange(ins, &command)?;
+            let (&instruction, data) = response
+                .split_first()
+                .ok_or_else(|| invalid_response("empty answer"))?;
+            command = match instruction {
+                DEVICE_RESULT_ACCUMULATING | DEVICE_RESULT_FINAL => {
+                    result.extend_from_slice(data);
+                    if instruction == DEVICE_RESULT_FINAL {
+                        return Ok(result);
+                    }
+                    vec![HOST_RESULT_ACCUMULATING_RESPONSE]
+                }
+                DEVICE_GET_CHUNK => {
+                    match <[u8; 32]>::try_from(data).ok().and_then(|h| blocks.get(&h)) {
+                        Some(block) => [&[HOST_GET_CHUNK_SUCCESS], block.as_slice()].concat(),
+                        None => vec![HOST_GET_CHUNK_FAILURE],
+                    }
+                }
+                DEVICE_PUT_CHUNK => {
+                    blocks.insert(Sha256::digest(data).digest, data.to_vec());
+                    vec![HOST_PUT_CHUNK_RESPONSE]
+                }
+                _ => return Err(invalid_response("unknown block protocol instruction")),
+            };
+        }
+    }
+
+    /// send one APDU, returns the response data without status word
+    fn exchange(&self, ins: u8, data: &[u8]) -> Result<Vec<u8>, SuiError> {
+        let mut apdu = vec![CLA, ins, 0, 0, data.len() as u8];
+        apdu.extend_from_slice(data);
+        let mut response = self.transport.exchange(&apdu)?;
+        if response.len() < 2 {
+            return Err(invalid_response("missing status word"));
+        }
+        let status = response.split_off(response.len() - 2);
+        match u16::from_be_bytes([status[0], status[1]]) {
+            SW_OK => Ok(response),
+            0x5515 => Err(SuiError::Ledger(LedgerError::Locked)),
+            0x6985 => Err(SuiError::Ledger(LedgerError::UserRejected)),
+            // dashboard or another app answers unknown class or instruction
+            0x6511 | 0x6d00 | 0x6e00 | 0x6e01 => Err(SuiError::Ledger(LedgerError::WrongApp)),
+            status => Err(SuiError::Ledger(LedgerError::Status(status))),
+        }
+    }
+}
+
+impl<T: ApduTransport> Signer for LedgerSigner<T> {
+    fn address(&self) -> &str {
+        &self.address
+    }
+    fn public_key(&self) -> &[u8] {
+        &self.public_key
+    }
+    // device exchanges block the polling thread until the user confirms
+    fn sign_transaction<'s>(
+        &'s self,
+        tx_bytes: &'s [u8],
+    ) -> BoxFuture<'s, Result<Vec<u8>, SuiError>> {
+        Box::pin(async move { self.sign_intent_message(&[&[0u8, 0, 0], tx_bytes].concat()) })
+    }
+    fn sign_personal_message<'s>(
+        &'s self,
+        message: &'s [u8],
+    ) -> BoxFuture<'s, Result<String, SuiError>> {
+        Box::pin(async move {
+            let intent_message = [&[3u8, 0, 0], bcs::to_bytes(message)?.as_slice()].concat();
+            let signature = self.sign_intent_message(&intent_message)?;
+            let mut serialized = vec![SignatureScheme::Ed25519.flag()];
+            serialized.extend_from_slice(&signature);
+            serialized.extend_from_slice(&self.public_key);
+            Ok(BASE64_STANDARD.encode(serialized))
+        })
+    }
+}
+
+/// BIP32 path payload: component count followed by little endian components
+fn bip32_path(path: &str) -> Result<Vec<u8>, SuiError> {
+    let invalid = || SuiError::Ledger(LedgerError::InvalidPath(path.to_string()));
+    let components: Vec<&str> = path
+        .strip_prefix("m/")
+        .ok_or_else(invalid)?
+        .split('/')
+        .collect();
+    if components.len() > 10 {
+        return Err(invalid());
+    }
+    let mut payload = vec![components.len() as u8];
+    for component in components {
+        let (index, hardened) = match component.strip_suffix('\'') {
+            Some(index) => (index, 0x8000_0000),
+            None => (component, 0),
+        };
+        let index: u32 = index.parse().map_err(|_| invalid())?;
+        if index >= 0x8000_0000 {
+            return Err(invalid());
+        }
+        payload.extend_from_slice(&(index | hardened).to_le_bytes());
+    }
+    Ok(payload)
+}
+
+/// malformed device answer
+fn invalid_response(what: &str) -> SuiError {
+    SuiError::Ledger(LedgerError::InvalidResponse(what.to_string()))
+}
@@ -1,305 +1,5692 @@
+/// Accounting rows of balance changes
+pub mod accounting;
+/// Request audit records
+pub mod audit;
+/// Coin decimals and amounts
+pub mod coin;
+/// Deposit detection
+pub mod deposit;
+/// Explanations of execution failures
+pub mod errors;
+/// Historical and live event streams
+pub mod events;
+/// Block explorer links
+pub mod explorer;
 /// Global configuration and state management
 pub mod global;
+/// Ledger hardware wallet signer
+#[cfg(feature = "ledger")]
+pub mod ledger;
 /// Event listeners
 pub mod listener;
+/// Local network for tests
+#[cfg(feature = "test-utils")]
+pub mod localnet;
+/// Checked amount arithmetic
+pub mod math;
+/// Programmable transactions
+pub mod ptb;
+/// Request priority queue
+pub mod queue;
+/// Session keys
+pub mod session;
+/// Sign in with Sui messages
+pub mod siws;
+/// Checkpoint pinned reads
+pub mod snapshot;
+/// Gas sponsorship
+pub mod sponsor;
+/// Request latency stats
+pub mod stats;
+/// One-line summaries of responses
+pub mod summary;
 /// Trade module
 pub mod trade;
+/// Rpc transports
+pub mod transport;
 /// Type module
 pub mod types;
 /// Wallet module
 pub mod wallet;
+/// Local zkLogin address derivation
+pub mod zklogin;
+use crate::audit::AuditHook;
+use crate::coin::CoinRegistry;
+use crate::explorer::{Explorer, ExplorerLinks, Network};
+use crate::queue::{Priority, QueueStats, RequestLimits, RequestOptions, RequestQueue};
+use crate::snapshot::SnapshotReader;
+use crate::stats::{LatencyStats, MethodStats};
+use crate::transport::{DnsOverrides, HttpTransport, Transport};
 use crate::types::SuiError;
 use crate::types::*;
+use crate::wallet::{SuiPublicKey, serialized_signature};
 use base64::Engine;
 use base64::prelude::BASE64_STANDARD;
-use reqwest::Client as HttpClient;
+use futures::StreamExt;
 use serde_json::Value;
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::result::Result;
+use std::sync::{Arc, Mutex};
+use tokio::sync::OnceCell;
+
+/// upper bound of requests spent searching for the earliest available checkpoint
+const MAX_PRUNING_PROBES: u32 = 20;
+/// re-fetch rounds before a consistent read gives up on changing objects
+const MAX_CONSISTENT_READ_ROUNDS: usize = 5;
+/// largest page size the node serves for paginated queries
+const MAX_QUERY_PAGE_SIZE: usize = 50;
+/// method prefixes nodes have served the same methods under
+const METHOD_PREFIXES: [&str; 3] = ["suix_", "sui_", "unsafe_"];
+/// JSON-RPC error code of an unknown method
+const METHOD_NOT_FOUND: i32 = -32601;
+/// default upper bound of requests spent on gas price statistics
+const DEFAULT_GAS_STATS_MAX_CALLS: usize = 20;
+/// parents walked at most when locating an object held by other objects
+const MAX_OWNERSHIP_DEPTH: usize = 16;
+/// addresses per JSON-RPC batch of a bulk lookup
+const BULK_BATCH_SIZE: usize = 100;
+/// batches of a bulk lookup in flight at once
+const BULK_CONCURRENCY: usize = 4;
+/// wait between reads of an object awaiting its new owner
+const OWNERSHIP_POLL_INTERVAL: std::time::Duration = std::time::Duration::from_millis(250);
+/// wait between reads of the system state once the known epoch should have ended
+const EPOCH_RECHECK_INTERVAL: std::time::Duration = std::time::Duration::from_secs(10);
+
+/// epoch and protocol version of the system state read last
+#[derive(Debug, Clone, Copy)]
+struct ProtocolState {
+    epoch: u64,
+    protocol_version: u64,
+    epoch_ends_at_ms: Option<u64>,
+    read_at: std::time::Instant,
+}
+
+/// protocol state and the number of changes seen, caches keep the count they were read at
+#[derive(Debug, Default)]
+struct EpochTracker {
+    state: Option<ProtocolState>,
+    changes: u64,
+}
 
 /// Sui network client.
 /// # Params
-/// - http_client : http client
+/// - transport : rpc transport
 /// - config : configuration
+/// - capabilities : node capabilities, probed once on first use
+/// - transaction_limits : transaction limits of the protocol config, read again after an
+///   epoch change
+/// - epoch_tracker : epoch and protocol version seen in system state reads
+/// - method_routes : method name the node answered, by method without prefix
+/// - coin_registry : coin decimals
+/// - audit_hook : called with a record of every request
+/// - redact_audit_signatures : redact signatures in audit records
+/// - explorer : explorer of generated links
+/// - stats : latency histograms by method
+/// - slow_call_threshold : latency from which requests are logged
+/// - gas_price_cache : last reference gas price read and when
 pub struct SuiClient {
-    http_client: HttpClient,
+    transport: Arc<dyn Transport>,
     config: SuiClientConfig,
+    capabilities: OnceCell<NodeCapabilities>,
+    transaction_limits: tokio::sync::Mutex<Option<(u64, TransactionLimits)>>,
+    epoch_tracker: Mutex<EpochTracker>,
+    chain_identifier: OnceCell<String>,
+    method_routes: Mutex<HashMap<String, String>>,
+    coin_registry: CoinRegistry,
+    audit_hook: Option<Arc<AuditHook>>,
+    redact_audit_signatures: bool,
+    explorer: Explorer,
+    stats: LatencyStats,
+    slow_call_threshold: Option<std::time::Duration>,
+    request_queue: Option<RequestQueue>,
+    dns_overrides: DnsOverrides,
+    gas_price_cache: tokio::sync::Mutex<Option<(std::time::Instant, u64)>>,
 }
 
-impl SuiClient {
-    /// # creates new client
+impl SuiClient {
+    /// # creates new client
+    ///
+    /// ## Parameters
+    /// - config : client config
+    ///
+    /// ## Returns
+    /// - client object
+    ///
+    /// ## Panics
+    /// When the http client can't be built, e.g. no TLS backend can be initialized. Use
+    /// [`SuiClient::try_new`] to handle that case.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use sui_network_sdk::SuiClient;
+    /// use sui_network_sdk::global::mainnet;
+    /// use sui_network_sdk::types::SuiClientConfig;
+    /// let config = SuiClientConfig {
+    ///     rpc_url: mainnet::RPC_URL.to_string(),
+    ///     ..Default::default()
+    /// };
+    /// let client = SuiClient::new(config);
+    /// ```
+    pub fn new(config: SuiClientConfig) -> Self {
+        Self::try_new(config).expect("failed to build the http client")
+    }
+
+    /// # creates new client, returning http client errors
+    ///
+    /// ## Parameters
+    /// - config : client config
+    ///
+    /// ## Returns
+    /// - Ok(SuiClient): client object
+    /// - Err(SuiError::HttpRequest): the http client can't be built
+    ///
+    /// ## Example
+    /// ```rust
+    /// use sui_network_sdk::SuiClient;
+    /// use sui_network_sdk::types::SuiClientConfig;
+    /// let client = SuiClient::try_new(SuiClientConfig::default()).unwrap();
+    /// ```
+    pub fn try_new(config: SuiClientConfig) -> Result<Self, SuiError> {
+        let http_client = http_client(&config, &DnsOverrides::default())?;
+        Ok(Self::new_with_http_client(config, http_client))
+    }
+
+    /// # creates new client with a transport
+    ///
+    /// ## Parameters
+    /// - config : client config
+    /// - transport : rpc transport, e.g. a `MockTransport` for tests
+    ///
+    /// ## Returns
+    /// - client object
+    pub fn new_with_transport(config: SuiClientConfig, transport: Arc<dyn Transport>) -> Self {
+        Self {
+            transport,
+            config,
+            capabilities: OnceCell::new(),
+            transaction_limits: tokio::sync::Mutex::new(None),
+            epoch_tracker: Mutex::new(EpochTracker::default()),
+            chain_identifier: OnceCell::new(),
+            method_routes: Mutex::new(HashMap::new()),
+            coin_registry: CoinRegistry::new(),
+            audit_hook: None,
+            redact_audit_signatures: true,
+            explorer: Explorer::default(),
+            stats: LatencyStats::default(),
+            slow_call_threshold: None,
+            request_queue: None,
+            dns_overrides: DnsOverrides::default(),
+            gas_price_cache: tokio::sync::Mutex::new(None),
+        }
+    }
+
+    /// # creates new client with an http client
+    ///
+    /// Reuses an application's tuned `reqwest` 0.11 client, e.g. with a custom resolver,
+    /// an mTLS identity or default headers, for the requests to `config.rpc_url`. The SDK
+    /// sets no timeout or header of its own on the client, only the JSON body of each
+    /// request. Retries of the [`RetryPolicy`] and the probing of method prefixes send
+    /// their requests through the same client.
+    ///
+    /// ## Parameters
+    /// - config : client config
+    /// - http_client : http client
+    ///
+    /// ## Returns
+    /// - client object
+    ///
+    /// ## Example
+    /// ```no_run
+    /// use std::time::Duration;
+    /// use sui_network_sdk::SuiClient;
+    /// use sui_network_sdk::global::mainnet;
+    /// use sui_network_sdk::types::SuiClientConfig;
+    ///
+    /// let mut headers = reqwest::header::HeaderMap::new();
+    /// headers.insert("x-api-key", "secret".parse().unwrap());
+    /// let http_client = reqwest::Client::builder()
+    ///     .default_headers(headers)
+    ///     .timeout(Duration::from_secs(5))
+    ///     .build()
+    ///     .unwrap();
+    /// let config = SuiClientConfig { rpc_url: mainnet::RPC_URL.to_string(), ..Default::default() };
+    /// let client = SuiClient::new_with_http_client(config, http_client);
+    /// ```
+    pub fn new_with_http_client(config: SuiClientConfig, http_client: reqwest::Client) -> Self {
+        let transport = Arc::new(HttpTransport::new_with_http_client(
+            config.rpc_url.clone(),
+            http_client,
+        ));
+        Self::new_with_transport(config, transport)
+    }
+
+    /// # set http client
+    ///
+    /// Sends the requests to `rpc_url` through another `reqwest` 0.11 client, see
+    /// [`SuiClient::new_with_http_client`]. Replaces the client's transport.
+    ///
+    /// ## Parameters
+    /// - http_client : http client
+    ///
+    /// ## Returns
+    /// client using the http client
+    pub fn with_http_client(mut self, http_client: reqwest::Client) -> Self {
+        self.transport = Arc::new(HttpTransport::new_with_http_client(
+            self.config.rpc_url.clone(),
+            http_client,
+        ));
+        self
+    }
+
+    /// # set retry policy
+    ///
+    /// ## Parameters
+    /// - retry_policy : retries of transient failures, replaces `config.retry_policy`
+    ///
+    /// ## Returns
+    /// client using the policy
+    ///
+    /// ## Example
+    /// ```no_run
+    /// use std::time::Duration;
+    /// use sui_network_sdk::SuiClient;
+    /// use sui_network_sdk::global::mainnet;
+    /// use sui_network_sdk::types::RetryPolicy;
+    ///
+    /// // throttled requests wait for the node's Retry-After
+    /// let retry_policy = RetryPolicy { base_delay: Duration::from_millis(200), ..Default::default() };
+    /// let client = SuiClient::new_by_rpc_url(mainnet::RPC_URL.to_string()).with_retry_policy(retry_policy);
+    /// ```
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.config.retry_policy = retry_policy;
+        self
+    }
+
+    /// # set coin registry
+    ///
+    /// ## Parameters
+    /// - coin_registry : registry with the overrides of coins without metadata
+    ///
+    /// ## Returns
+    /// client using the registry
+    pub fn with_coin_registry(mut self, coin_registry: CoinRegistry) -> Self {
+        self.coin_registry = coin_registry;
+        self
+    }
+
+    /// # set audit hook
+    ///
+    /// The hook gets an [`AuditRecord`] of every request, one per request of a batch,
+    /// with the method, canonical params JSON, endpoint and send time. Records of
+    /// `executeTransactionBlock` carry the decoded sender, gas budget and commands, and
+    /// their signatures are redacted unless [`SuiClient::redact_audit_signatures`] turns
+    /// that off. The hook runs on a separate task once the response arrived, a slow hook
+    /// does not delay requests.
+    ///
+    /// ## Parameters
+    /// - hook : called with every record
+    ///
+    /// ## Returns
+    /// client calling the hook
+    ///
+    /// ## Example
+    /// ```rust
+    /// use std::sync::Arc;
+    /// use sui_network_sdk::SuiClient;
+    /// use sui_network_sdk::ptb::{Argument, ProgrammableTransactionBuilder, TransactionData};
+    /// use sui_network_sdk::transport::MockTransport;
+    /// use sui_network_sdk::types::SuiClientConfig;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let mock = Arc::new(MockTransport::new().with_result(
+    ///         "sui_executeTransactionBlock",
+    ///         serde_json::json!({ "digest": "A1" }),
+    ///     ));
+    ///     let (records, mut received) = tokio::sync::mpsc::unbounded_channel();
+    ///     let client = SuiClient::new_with_transport(SuiClientConfig::default(), mock)
+    ///         .on_audit(Box::new(move |record| {
+    ///             let _ = records.send(record);
+    ///         }));
+    ///
+    ///     let mut builder = ProgrammableTransactionBuilder::new();
+    ///     let coins = builder.split_coins(Argument::GasCoin, &[1000]).unwrap();
+    ///     builder.transfer_objects(coins, "0x7d20").unwrap();
+    ///     let data = TransactionData::new_programmable("0xa1", vec![], builder.finish(), 5_000_000, 1000).unwrap();
+    ///     client
+    ///         .execute_transaction_signatures(data.to_bytes().unwrap(), vec!["c2lnbmF0dXJl".to_string()])
+    ///         .await
+    ///         .unwrap();
+    ///
+    ///     let record = received.recv().await.unwrap();
+    ///     assert_eq!(record.method, "sui_executeTransactionBlock");
+    ///     assert_eq!(record.endpoint, client.config().rpc_url);
+    ///     assert!(record.params.contains(r#"["<redacted>"],{"showEffects":true}"#));
+    ///     assert!(record.error.is_none());
+    ///     let transaction = record.transaction.unwrap();
+    ///     assert_eq!(transaction.sender, format!("0x{:0>64}", "a1"));
+    ///     assert_eq!(transaction.gas_budget, 5_000_000);
+    ///     assert_eq!(transaction.commands, ["SplitCoins", "TransferObjects"]);
+    /// }
+    /// ```
+    ///
+    /// [`AuditRecord`]: crate::audit::AuditRecord
+    pub fn on_audit(mut self, hook: AuditHook) -> Self {
+        self.audit_hook = Some(Arc::new(hook));
+        self
+    }
+
+    /// # set audit signature redaction
+    ///
+    /// ## Parameters
+    /// - redact : replace the signatures of audited executions with `<redacted>`, the default
+    ///
+    /// ## Returns
+    /// client with the redaction setting
+    pub fn redact_audit_signatures(mut self, redact: bool) -> Self {
+        self.redact_audit_signatures = redact;
+        self
+    }
+
+    /// # set explorer
+    ///
+    /// ## Parameters
+    /// - explorer : explorer of [`SuiClient::explorer_links`], SuiScan by default
+    ///
+    /// ## Returns
+    /// client linking to the explorer
+    pub fn with_explorer(mut self, explorer: Explorer) -> Self {
+        self.explorer = explorer;
+        self
+    }
+
+    /// # set slow call threshold
+    ///
+    /// ## Parameters
+    /// - threshold : requests taking at least this long, retries included, are logged as
+    ///   `tracing` warnings with their method, endpoint and elapsed time
+    ///
+    /// ## Returns
+    /// client logging slow calls
+    pub fn with_slow_call_threshold(mut self, threshold: std::time::Duration) -> Self {
+        self.slow_call_threshold = Some(threshold);
+        self
+    }
+
+    /// # resolve host
+    ///
+    /// Pins a hostname of the endpoints to an address, e.g. a fullnode reached through a
+    /// split-horizon DNS. Only the address is used, the port comes from the url. Rebuilds
+    /// the http transport from the dns overrides, replacing a transport or http client set
+    /// before. Listeners take the same overrides with their `with_dns_overrides`, see
+    /// [`SuiClient::dns_overrides`].
+    ///
+    /// ## Parameters
+    /// - host : hostname of `rpc_url`
+    /// - address : address the host resolves to
+    ///
+    /// ## Returns
+    /// - Ok(SuiClient): client resolving the host to the address
+    /// - Err(SuiError::HttpRequest): the http client can't be rebuilt
+    ///
+    /// ## Example
+    /// ```no_run
+    /// use sui_network_sdk::SuiClient;
+    /// use sui_network_sdk::types::SuiClientConfig;
+    ///
+    /// let config = SuiClientConfig {
+    ///     rpc_url: "https://fullnode.internal:9000".to_string(),
+    ///     ..Default::default()
+    /// };
+    /// let client = SuiClient::new(config)
+    ///     .resolve_host("fullnode.internal", "10.0.0.7:0".parse().unwrap())
+    ///     .unwrap();
+    /// ```
+    pub fn resolve_host(
+        mut self,
+        host: &str,
+        address: std::net::SocketAddr,
+    ) -> Result<Self, SuiError> {
+        self.dns_overrides = self.dns_overrides.resolve_host(host, address);
+        let http_client = http_client(&self.config, &self.dns_overrides)?;
+        Ok(self.with_http_client(http_client))
+    }
+
+    /// # prefer IPv4
+    ///
+    /// Connects to the endpoints over IPv4 only, e.g. where IPv6 routes exist but don't
+    /// work. Rebuilds the http transport like [`SuiClient::resolve_host`].
+    ///
+    /// ## Returns
+    /// - Ok(SuiClient): client connecting over IPv4
+    /// - Err(SuiError::HttpRequest): the http client can't be rebuilt
+    pub fn prefer_ipv4(mut self) -> Result<Self, SuiError> {
+        self.dns_overrides = self.dns_overrides.prefer_ipv4();
+        let http_client = http_client(&self.config, &self.dns_overrides)?;
+        Ok(self.with_http_client(http_client))
+    }
+
+    /// # Dns overrides
+    ///
+    /// Overrides set with [`SuiClient::resolve_host`] and [`SuiClient::prefer_ipv4`], for
+    /// the listeners of the same endpoints.
+    ///
+    /// ## Returns
+    /// dns overrides
+    ///
+    /// ## Example
+    /// ```no_run
+    /// use sui_network_sdk::SuiClient;
+    /// use sui_network_sdk::listener::SharedListener;
+    /// use sui_network_sdk::types::SuiClientConfig;
+    ///
+    /// let client = SuiClient::new(SuiClientConfig::default()).prefer_ipv4().unwrap();
+    /// let listener = SharedListener::new(client.config().wss_url.clone())
+    ///     .with_dns_overrides(client.dns_overrides().clone());
+    /// ```
+    pub fn dns_overrides(&self) -> &DnsOverrides {
+        &self.dns_overrides
+    }
+
+    /// # set request limits
+    ///
+    /// Sends the client's requests through a priority queue holding them within the
+    /// limits, e.g. the rate limit of a provider. The waiting requests of the highest
+    /// [`Priority`] are sent first, in order, so transaction submissions don't wait behind
+    /// bulk reads. A low priority request waiting longer than `low_aging` is sent before
+    /// the others. Each retry waits for a turn again. Without limits requests are sent
+    /// at once.
+    ///
+    /// ## Parameters
+    /// - limits : request limits
+    ///
+    /// ## Returns
+    /// client queueing its requests
+    ///
+    /// ## Example
+    /// ```rust
+    /// use std::sync::Arc;
+    /// use std::time::Duration;
+    /// use futures::future::BoxFuture;
+    /// use serde_json::Value;
+    /// use sui_network_sdk::SuiClient;
+    /// use sui_network_sdk::queue::{Priority, RequestLimits, RequestOptions};
+    /// use sui_network_sdk::transport::{MockTransport, Transport};
+    /// use sui_network_sdk::types::{SuiClientConfig, SuiError};
+    ///
+    /// // a node answering in 40 ms
+    /// struct Slow(Arc<MockTransport>);
+    ///
+    /// impl Transport for Slow {
+    ///     fn send(&self, body: Value) -> BoxFuture<'_, Result<Value, SuiError>> {
+    ///         Box::pin(async move {
+    ///             tokio::time::sleep(Duration::from_millis(40)).await;
+    ///             self.0.send(body).await
+    ///         })
+    ///     }
+    /// }
+    ///
+    /// // one request at a time, queues the others behind a first one
+    /// async fn saturate(low_aging: Duration, methods: &[(&str, Option<Priority>)]) -> (Arc<SuiClient>, Vec<String>) {
+    ///     let mut mock = MockTransport::new();
+    ///     for (method, _) in methods {
+    ///         mock = mock.with_result(method, serde_json::json!(null));
+    ///     }
+    ///     let mock = Arc::new(mock);
+    ///     let limits = RequestLimits { max_in_flight: 1, low_aging, ..Default::default() };
+    ///     let client = Arc::new(
+    ///         SuiClient::new_with_transport(SuiClientConfig::default(), Arc::new(Slow(mock.clone())))
+    ///             .with_request_limits(limits),
+    ///     );
+    ///     let mut calls = Vec::new();
+    ///     for (method, priority) in methods {
+    ///         let (client, method, priority) = (client.clone(), method.to_string(), *priority);
+    ///         calls.push(tokio::spawn(async move {
+    ///             let options = RequestOptions { priority, ..Default::default() };
+    ///             client.request_with_options::<Value>(&method, vec![], options).await.unwrap();
+    ///         }));
+    ///         tokio::time::sleep(Duration::from_millis(5)).await;
+    ///     }
+    ///     for call in calls {
+    ///         call.await.unwrap();
+    ///     }
+    ///     (client, mock.requests().into_iter().map(|r| r.method).collect())
+    /// }
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let (client, sent) = saturate(Duration::from_secs(10), &[
+    ///         ("sui_getChainIdentifier", None),
+    ///         ("suix_queryEvents", None),
+    ///         ("sui_getObject", None),
+    ///         ("suix_getReferenceGasPrice", Some(Priority::High)),
+    ///         ("sui_dryRunTransactionBlock", None),
+    ///     ])
+    ///     .await;
+    ///     assert_eq!(sent, [
+    ///         "sui_getChainIdentifier",
+    ///         "suix_getReferenceGasPrice",
+    ///         "sui_dryRunTransactionBlock",
+    ///         "sui_getObject",
+    ///         "suix_queryEvents",
+    ///     ]);
+    ///     let stats = client.queue_stats();
+    ///     assert_eq!(stats[&Priority::High].dispatched, 2);
+    ///     assert!(stats[&Priority::Low].max_wait >= Duration::from_millis(100));
+    ///     assert_eq!(stats[&Priority::Low].aged, 0);
+    ///
+    ///     // the bulk read waited past its aging, it goes before the later submission
+    ///     let (client, sent) = saturate(Duration::from_millis(20), &[
+    ///         ("sui_getChainIdentifier", None),
+    ///         ("suix_queryEvents", None),
+    ///         ("sui_dryRunTransactionBlock", None),
+    ///     ])
+    ///     .await;
+    ///     assert_eq!(sent, ["sui_getChainIdentifier", "suix_queryEvents", "sui_dryRunTransactionBlock"]);
+    ///     assert_eq!(client.queue_stats()[&Priority::Low].aged, 1);
+    /// }
+    /// ```
+    pub fn with_request_limits(mut self, limits: RequestLimits) -> Self {
+        self.request_queue = Some(RequestQueue::new(limits));
+        self
+    }
+
+    /// # Explorer links
+    ///
+    /// Links of the client's explorer for the network it is connected to. The network is
+    /// detected from the chain identifier cached by [`SuiClient::node_capabilities`], only
+    /// the first call sends requests.
+    ///
+    /// ## Returns
+    /// - Ok(ExplorerLinks) : links
+    /// - Err(SuiError::Unsupported) : the node's chain is unknown and the explorer is not custom
+    /// - Err(SuiError) : error
+    ///
+    /// ## Example
+    /// ```rust
+    /// use std::sync::Arc;
+    /// use sui_network_sdk::SuiClient;
+    /// use sui_network_sdk::explorer::Explorer;
+    /// use sui_network_sdk::transport::MockTransport;
+    /// use sui_network_sdk::types::{SuiClientConfig, SuiError, TransactionResponse};
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let mock = Arc::new(
+    ///         MockTransport::new()
+    ///             .with_result("sui_getChainIdentifier", serde_json::json!("4c78adac"))
+    ///             .with_result("sui_getCheckpoint", serde_json::json!({ "sequenceNumber": "0" })),
+    ///     );
+    ///     let client = SuiClient::new_with_transport(SuiClientConfig::default(), mock.clone());
+    ///     let links = client.explorer_links().await.unwrap();
+    ///     assert_eq!(links.tx_url("4xYbR1"), "https://suiscan.xyz/testnet/tx/4xYbR1");
+    ///     assert_eq!(links.object_url("0x5"), "https://suiscan.xyz/testnet/object/0x5");
+    ///     assert_eq!(links.address_url("0xa1"), "https://suiscan.xyz/testnet/account/0xa1");
+    ///
+    ///     let response: TransactionResponse =
+    ///         serde_json::from_value(serde_json::json!({ "digest": "9WzSXd" })).unwrap();
+    ///     for _ in 0..3 {
+    ///         let url = response.explorer_url(&client).await.unwrap();
+    ///         assert_eq!(url, "https://suiscan.xyz/testnet/tx/9WzSXd");
+    ///     }
+    ///     // the chain identifier is read once
+    ///     assert_eq!(mock.request_count("sui_getChainIdentifier"), 1);
+    ///
+    ///     let client = SuiClient::new_with_transport(SuiClientConfig::default(), mock.clone())
+    ///         .with_explorer(Explorer::SuiVision);
+    ///     let links = client.explorer_links().await.unwrap();
+    ///     assert_eq!(links.tx_url("9WzSXd"), "https://testnet.suivision.xyz/txblock/9WzSXd");
+    ///
+    ///     // a local network only links to a custom explorer
+    ///     let local = Arc::new(
+    ///         MockTransport::new()
+    ///             .with_result("sui_getChainIdentifier", serde_json::json!("1f2e3d4c"))
+    ///             .with_result("sui_getCheckpoint", serde_json::json!({ "sequenceNumber": "0" })),
+    ///     );
+    ///     let client = SuiClient::new_with_transport(SuiClientConfig::default(), local.clone());
+    ///     assert!(matches!(client.explorer_links().await, Err(SuiError::Unsupported(_))));
+    ///     let client = SuiClient::new_with_transport(SuiClientConfig::default(), local)
+    ///         .with_explorer(Explorer::Custom("http://localhost:3000/".to_string()));
+    ///     let links = client.explorer_links().await.unwrap();
+    ///     assert_eq!(links.object_url("0x5"), "http://localhost:3000/object/0x5");
+    /// }
+    /// ```
+    pub async fn explorer_links(&self) -> Result<ExplorerLinks, SuiError> {
+        let network = match self.node_capabilities().await?.chain_identifier {
+            Some(chain_identifier) => Network::from_chain_identifier(&chain_identifier),
+            None => Network::Unknown("?".to_string()),
+        };
+        ExplorerLinks::new(&self.explorer, &network)
+    }
+
+    /// # Coin registry
+    ///
+    /// ## Returns
+    /// decimals registry of the client, shared by its amount conversions
+    ///
+    /// ## Example
+    /// ```no_run
+    /// use sui_network_sdk::SuiClient;
+    /// use sui_network_sdk::global::mainnet;
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let client = SuiClient::new_by_rpc_url(mainnet::RPC_URL.to_string());
+    ///     let coin_type = "0xdba34672e30cb065b1f93e3ab55318768fd6fef66c15942c9f7cb846e2f900e7::usdc::USDC";
+    ///     let amount = client.coin_registry().parse_amount(&client, coin_type, "12.5").await.unwrap();
+    ///     println!("{} base units", amount);
+    /// }
+    /// ```
+    pub fn coin_registry(&self) -> &CoinRegistry {
+        &self.coin_registry
+    }
+
+    /// # create new client by rpc url
+    ///
+    /// ## Parameters
+    /// - url : rpc url
+    ///
+    /// ## Returns
+    /// sui network client
+    ///
+    /// ## Example
+    /// ```rust
+    /// use sui_network_sdk::SuiClient;
+    /// use sui_network_sdk::global::mainnet;
+    /// let client = SuiClient::new_by_rpc_url(mainnet::RPC_URL.to_string());
+    /// ```
+    pub fn new_by_rpc_url(url: String) -> Self {
+        let config = SuiClientConfig {
+            rpc_url: url,
+            ..Default::default()
+        };
+        Self::new(config)
+    }
+
+    /// # Get client config
+    ///
+    /// ## Returns
+    /// client configuration
+    pub fn config(&self) -> &SuiClientConfig {
+        &self.config
+    }
+
+    /// # send JSON request
+    ///
+    /// Methods with a `sui_`, `suix_` or `unsafe_` prefix are routed: when the node
+    /// does not know the method under the given prefix the other prefixes are tried,
+    /// and the name the node answered is used for later calls, see
+    /// [`SuiClient::method_routes`].
+    ///
+    /// ## Parameters
+    /// - method: rpc method name
+    /// - params: rpc param list
+    ///
+    /// ## Returns
+    /// - Ok(T): Response data
+    /// - Err(SuiError): rpc call error
+    ///
+    /// ## Errors
+    /// - SuiError::Rpc: rpc call failed.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use std::sync::Arc;
+    /// use sui_network_sdk::SuiClient;
+    /// use sui_network_sdk::transport::MockTransport;
+    /// use sui_network_sdk::types::SuiClientConfig;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     // a node serving only the old method name
+    ///     let mock = Arc::new(MockTransport::new().with_result(
+    ///         "sui_getBalance",
+    ///         serde_json::json!({ "totalBalance": "5" }),
+    ///     ));
+    ///     let client = SuiClient::new_with_transport(SuiClientConfig::default(), mock.clone());
+    ///     for _ in 0..3 {
+    ///         let _: serde_json::Value = client.request("suix_getBalance", vec![]).await.unwrap();
+    ///     }
+    ///     assert_eq!(mock.request_count("suix_getBalance"), 1);
+    ///     assert_eq!(mock.request_count("sui_getBalance"), 3);
+    ///     assert_eq!(client.method_routes()["getBalance"], "sui_getBalance");
+    ///     client.reset_method_routes();
+    ///     assert!(client.method_routes().is_empty());
+    /// }
+    /// ```
+    pub async fn request<T: serde::de::DeserializeOwned>(
+        &self,
+        method: &str,
+        params: Vec<Value>,
+    ) -> Result<T, SuiError> {
+        let Some((prefix, name)) = METHOD_PREFIXES
+            .iter()
+            .find_map(|prefix| method.strip_prefix(prefix).map(|name| (*prefix, name)))
+        else {
+            return self
+                .send_request(method, params)
+                .await?
+                .map_err(SuiError::from);
+        };
+        // the routed name first, then the given prefix, then the others
+        let routed = self.lock_method_routes().get(name).cloned();
+        let mut candidates: Vec<String> = routed.into_iter().collect();
+        for candidate in std::iter::once(prefix)
+            .chain(METHOD_PREFIXES)
+            .map(|prefix| format!("{}{}", prefix, name))
+        {
+            if !candidates.contains(&candidate) {
+                candidates.push(candidate);
+            }
+        }
+        let mut not_found = None;
+        for candidate in candidates {
+            match self.send_request(&candidate, params.clone()).await? {
+                Err(error) if error.code == METHOD_NOT_FOUND => not_found = Some(error),
+                result => {
+                    self.lock_method_routes()
+                        .insert(name.to_string(), candidate);
+                    return result.map_err(SuiError::from);
+                }
+            }
+        }
+        self.lock_method_routes().remove(name);
+        Err(not_found.map_or_else(
+            || SuiError::Rpc("Method not found".to_string()),
+            SuiError::from,
+        ))
+    }
+
+    /// # send JSON request with options
+    ///
+    /// Sends a request like [`SuiClient::request`] with request options, e.g. the
+    /// [`Priority`] of a bulk read. [`RequestOptions::scope`] applies options to every
+    /// request of a call.
+    ///
+    /// ## Parameters
+    /// - method: rpc method name
+    /// - params: rpc param list
+    /// - options: request options
+    ///
+    /// ## Returns
+    /// - Ok(T): Response data
+    /// - Err(SuiError): rpc call error
+    pub async fn request_with_options<T: serde::de::DeserializeOwned>(
+        &self,
+        method: &str,
+        params: Vec<Value>,
+        options: RequestOptions,
+    ) -> Result<T, SuiError> {
+        options.scope(self.request(method, params)).await
+    }
+
+    /// # send JSON request with a timeout
+    ///
+    /// Sends a request like [`SuiClient::request`] with its own deadline, replacing
+    /// `SuiClientConfig::timeout` for this call, e.g. a longer one for a dry run of a large
+    /// transaction. The deadline covers the whole call, retries of the [`RetryPolicy`] and
+    /// their backoff included, where `SuiClientConfig::timeout` bounds each attempt.
+    ///
+    /// ## Parameters
+    /// - method: rpc method name
+    /// - params: rpc param list
+    /// - timeout: deadline of the request
+    ///
+    /// ## Returns
+    /// - Ok(T): Response data
+    /// - Err(SuiError::Timeout): no response within the deadline
+    /// - Err(SuiError): rpc call error
+    ///
+    /// ## Example
+    /// ```no_run
+    /// use std::time::Duration;
+    /// use sui_network_sdk::SuiClient;
+    /// use sui_network_sdk::global::mainnet;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let client = SuiClient::new_by_rpc_url(mainnet::RPC_URL.to_string());
+    ///     let chain: String = client
+    ///         .request_with_timeout("sui_getChainIdentifier", vec![], Duration::from_secs(30))
+    ///         .await
+    ///         .unwrap();
+    /// }
+    /// ```
+    pub async fn request_with_timeout<T: serde::de::DeserializeOwned>(
+        &self,
+        method: &str,
+        params: Vec<Value>,
+        timeout: std::time::Duration,
+    ) -> Result<T, SuiError> {
+        let options = RequestOptions {
+            timeout: Some(timeout),
+            ..Default::default()
+        };
+        self.request_with_options(method, params, options).await
+    }
+
+    /// # send JSON-RPC batch
+    ///
+    /// Sends the calls as one JSON-RPC 2.0 batch, a single round trip. Each call gets its
+    /// own id and the responses, which may come in any order, are matched back by id. An
+    /// error response with a `null` id, which the node could not attribute to a call, fails
+    /// the calls left without a response. A node without batch support rejects the batch
+    /// as an invalid request or answers that batches are not supported, the calls are then
+    /// sent one by one with [`SuiClient::request`]. Batches skip the method routing, give
+    /// the method names the node serves.
+    ///
+    /// ## Parameters
+    /// - calls: method names and param lists
+    ///
+    /// ## Returns
+    /// - Ok(Vec<Result<Value, SuiError>>): per call in input order, the result or the node's error
+    /// - Err(SuiError): the batch could not be sent, the node rejected it for another reason
+    ///   or its response could not be read
+    ///
+    /// ## Example
+    /// ```rust
+    /// use std::sync::Arc;
+    /// use futures::future::BoxFuture;
+    /// use serde_json::{Value, json};
+    /// use sui_network_sdk::SuiClient;
+    /// use sui_network_sdk::transport::{MockTransport, Transport};
+    /// use sui_network_sdk::types::{SuiClientConfig, SuiError};
+    ///
+    /// // answers batches in reverse order, or rejects them like a node without batch support
+    /// struct Node {
+    ///     mock: Arc<MockTransport>,
+    ///     batches: bool,
+    /// }
+    ///
+    /// impl Transport for Node {
+    ///     fn send(&self, body: Value) -> BoxFuture<'_, Result<Value, SuiError>> {
+    ///         Box::pin(async move {
+    ///             match (body.is_array(), self.batches) {
+    ///                 (true, false) => Ok(json!({
+    ///                     "jsonrpc": "2.0",
+    ///                     "id": null,
+    ///                     "error": { "code": -32600, "message": "Batch requests are not supported" }
+    ///                 })),
+    ///                 (true, true) => {
+    ///                     let mut responses = self.mock.send(body).await?;
+    ///                     responses.as_array_mut().unwrap().reverse();
+    ///                     Ok(responses)
+    ///                 }
+    ///                 (false, _) => self.mock.send(body).await,
+    ///             }
+    ///         })
+    ///     }
+    /// }
+    ///
+    /// // answers every request with the same body
+    /// struct Fixed(Value);
+    ///
+    /// impl Transport for Fixed {
+    ///     fn send(&self, _: Value) -> BoxFuture<'_, Result<Value, SuiError>> {
+    ///         Box::pin(async move { Ok(self.0.clone()) })
+    ///     }
+    /// }
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let mock = Arc::new(
+    ///         MockTransport::new()
+    ///             .with_result("suix_getReferenceGasPrice", json!("750"))
+    ///             .with_error("sui_getObject", -32602, "Invalid object id"),
+    ///     );
+    ///     for batches in [true, false] {
+    ///         let node = Arc::new(Node { mock: mock.clone(), batches });
+    ///         let client = SuiClient::new_with_transport(SuiClientConfig::default(), node);
+    ///         let results = client
+    ///             .batch_request(vec![
+    ///                 ("suix_getReferenceGasPrice", vec![]),
+    ///                 ("sui_getObject", vec![json!("0x5")]),
+    ///             ])
+    ///             .await
+    ///             .unwrap();
+    ///         assert_eq!(results[0].as_ref().unwrap(), &json!("750"));
+    ///         assert!(results[1].as_ref().unwrap_err().to_string().contains("Invalid object id"));
+    ///     }
+    ///
+    ///     // an overloaded node rejects the batch, the calls are not sent one by one
+    ///     let mock = Arc::new(MockTransport::new());
+    ///     let overloaded = json!({
+    ///         "jsonrpc": "2.0", "id": null, "error": { "code": -32603, "message": "Server busy" }
+    ///     });
+    ///     let client = SuiClient::new_with_transport(SuiClientConfig::default(), Arc::new(Fixed(overloaded)));
+    ///     let error = client.batch_request(vec![("suix_getReferenceGasPrice", vec![])]).await.unwrap_err();
+    ///     assert!(error.to_string().contains("Server busy"), "{}", error);
+    ///
+    ///     // an error the node could not attribute fails the calls without a response
+    ///     let partial = json!([
+    ///         { "jsonrpc": "2.0", "id": 0, "result": "750" },
+    ///         { "jsonrpc": "2.0", "id": null, "error": { "code": -32700, "message": "Parse error" } }
+    ///     ]);
+    ///     let client = SuiClient::new_with_transport(SuiClientConfig::default(), Arc::new(Fixed(partial)));
+    ///     let results = client
+    ///         .batch_request(vec![("suix_getReferenceGasPrice", vec![]), ("sui_getObject", vec![json!("0x5")])])
+    ///         .await
+    ///         .unwrap();
+    ///     assert_eq!(results[0].as_ref().unwrap(), &json!("750"));
+    ///     assert!(results[1].as_ref().unwrap_err().to_string().contains("Parse error"));
+    ///
+    ///     // a call fails like a single request: a method the node serves under another prefix
+    ///     // is routed, a missing transaction is annotated with the node's pruned history
+    ///     let mock = Arc::new(
+    ///         MockTransport::new()
+    ///             .with_result("sui_getBalance", json!({ "totalBalance": "5" }))
+    ///             .with_error("sui_getTransactionBlock", -32602, "Could not find the referenced transaction")
+    ///             .with_error("sui_getCheckpoint", -32602, "Could not find checkpoint 0")
+    ///             .with_result("sui_getLatestCheckpointSequenceNumber", json!("1")),
+    ///     );
+    ///     let node = Arc::new(Node { mock: mock.clone(), batches: true });
+    ///     let client = SuiClient::new_with_transport(SuiClientConfig::default(), node);
+    ///     let results = client
+    ///         .batch_request(vec![("suix_getBalance", vec![]), ("sui_getTransactionBlock", vec![json!("TX1")])])
+    ///         .await
+    ///         .unwrap();
+    ///     assert_eq!(results[0].as_ref().unwrap()["totalBalance"], "5");
+    ///     let error = results[1].as_ref().unwrap_err();
+    ///     assert!(error.is_not_found());
+    ///     assert!(error.to_string().contains("node history starts at checkpoint 1"), "{}", error);
+    /// }
+    /// ```
+    pub async fn batch_request(
+        &self,
+        calls: Vec<(&str, Vec<Value>)>,
+    ) -> Result<Vec<Result<Value, SuiError>>, SuiError> {
+        if calls.is_empty() {
+            return Ok(Vec::new());
+        }
+        let requests: Vec<RpcRequest> = calls
+            .into_iter()
+            .enumerate()
+            .map(|(id, (method, params))| RpcRequest {
+                jsonrpc: "2.0".to_string(),
+                id: id as u64,
+                method: method.to_string(),
+                params,
+            })
+            .collect();
+        let body = self.send_body(serde_json::to_value(&requests)?).await?;
+        if !body.is_array() {
+            let response: RpcResponse<Value> = serde_json::from_value(body)?;
+            return match response.error {
+                // no batch support, the calls are sent one by one
+                Some(error) if batch_unsupported(&error) => {
+                    let mut results = Vec::with_capacity(requests.len());
+                    for request in requests {
+                        results.push(self.request(&request.method, request.params).await);
+                    }
+                    Ok(results)
+                }
+                Some(error) => Err(error.into()),
+                None => Err(SuiError::Rpc(
+                    "Batch answered with a single response".to_string(),
+                )),
+            };
+        }
+        // responses of a batch may come in any order
+        let responses: Vec<RpcResponse<Value>> = serde_json::from_value(body)?;
+        let mut results: Vec<Option<Result<Value, SuiError>>> =
+            requests.iter().map(|_| None).collect();
+        let mut unattributed = None;
+        for response in responses {
+            let request = response.id.and_then(|id| requests.get(id as usize));
+            // errors of a call are those of the same single request
+            let result = match (response.error, response.result, request) {
+                (Some(error), _, Some(request)) if error.code == METHOD_NOT_FOUND => {
+                    self.request(&request.method, request.params.clone()).await
+                }
+                (Some(error), ..) => Err(self.annotate_pruned(error.into()).await),
+                (None, Some(result), _) => Ok(result),
+                (None, None, _) => Err(SuiError::Rpc("No result in response".to_string())),
+            };
+            match response.id {
+                Some(id) => {
+                    if let Some(slot) = results.get_mut(id as usize) {
+                        *slot = Some(result);
+                    }
+                }
+                None => unattributed = result.err().or(unattributed),
+            }
+        }
+        Ok(results
+            .into_iter()
+            .map(|result| {
+                result.unwrap_or_else(|| match &unattributed {
+                    Some(error) => Err(error.clone()),
+                    None => Err(SuiError::Rpc("No response in batch".to_string())),
+                })
+            })
+            .collect())
+    }
+
+    /// # Method routes
+    ///
+    /// Snapshot of the routing table: the method name the node answered, by method
+    /// name without prefix (e.g. `getCoins` -> `suix_getCoins`). The table belongs to
+    /// this client and its endpoint.
+    ///
+    /// ## Returns
+    /// routing table
+    pub fn method_routes(&self) -> HashMap<String, String> {
+        self.lock_method_routes().clone()
+    }
+
+    /// # Reset method routes
+    ///
+    /// Forgets all routes, e.g. after the node was upgraded, the next calls probe again.
+    pub fn reset_method_routes(&self) {
+        self.lock_method_routes().clear();
+    }
+
+    /// # Stats
+    ///
+    /// Latency of the requests sent by this client, by method. A batch counts as one call.
+    ///
+    /// ## Returns
+    /// stats by method name as sent
+    ///
+    /// ## Example
+    /// ```rust
+    /// use std::sync::Arc;
+    /// use std::time::Duration;
+    /// use futures::future::BoxFuture;
+    /// use serde_json::Value;
+    /// use sui_network_sdk::SuiClient;
+    /// use sui_network_sdk::transport::{MockTransport, Transport};
+    /// use sui_network_sdk::types::{SuiClientConfig, SuiError};
+    ///
+    /// // answers the reference gas price in 12 ms and checkpoints in 120 ms
+    /// struct Latency(MockTransport);
+    ///
+    /// impl Transport for Latency {
+    ///     fn send(&self, body: Value) -> BoxFuture<'_, Result<Value, SuiError>> {
+    ///         Box::pin(async move {
+    ///             let ms = if body["method"] == "suix_getReferenceGasPrice" { 12 } else { 120 };
+    ///             tokio::time::sleep(Duration::from_millis(ms)).await;
+    ///             self.0.send(body).await
+    ///         })
+    ///     }
+    /// }
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let mock = MockTransport::new()
+    ///         .with_result("suix_getReferenceGasPrice", serde_json::json!("750"))
+    ///         .with_result("sui_getLatestCheckpointSequenceNumber", serde_json::json!("9"));
+    ///     let client = SuiClient::new_with_transport(SuiClientConfig::default(), Arc::new(Latency(mock)))
+    ///         .with_slow_call_threshold(Duration::from_millis(100));
+    ///     for _ in 0..19 {
+    ///         let _: Value = client.request("suix_getReferenceGasPrice", vec![]).await.unwrap();
+    ///     }
+    ///     let _: Value = client.request("sui_getLatestCheckpointSequenceNumber", vec![]).await.unwrap();
+    ///
+    ///     let stats = client.stats();
+    ///     let gas_price = &stats["suix_getReferenceGasPrice"];
+    ///     assert_eq!(gas_price.count, 19);
+    ///     // 12 ms calls fall in the 10-20 ms bucket
+    ///     assert_eq!(gas_price.buckets[4], 19);
+    ///     assert_eq!(gas_price.p50, Duration::from_millis(20).min(gas_price.max));
+    ///     assert!(gas_price.max >= Duration::from_millis(12));
+    ///     let checkpoint = &stats["sui_getLatestCheckpointSequenceNumber"];
+    ///     assert_eq!(checkpoint.count, 1);
+    ///     assert_eq!(checkpoint.p95, Duration::from_millis(200).min(checkpoint.max));
+    ///
+    ///     client.reset_stats();
+    ///     assert!(client.stats().is_empty());
+    /// }
+    /// ```
+    pub fn stats(&self) -> BTreeMap<String, MethodStats> {
+        self.stats.snapshot()
+    }
+
+    /// # Reset stats
+    ///
+    /// Forgets the latencies recorded so far.
+    pub fn reset_stats(&self) {
+        self.stats.reset();
+    }
+
+    /// # Queue stats
+    ///
+    /// Requests of each priority class, see [`SuiClient::with_request_limits`].
+    ///
+    /// ## Returns
+    /// stats by class, empty without request limits
+    pub fn queue_stats(&self) -> BTreeMap<Priority, QueueStats> {
+        self.request_queue
+            .as_ref()
+            .map(RequestQueue::snapshot)
+            .unwrap_or_default()
+    }
+
+    fn lock_method_routes(&self) -> std::sync::MutexGuard<'_, HashMap<String, String>> {
+        self.method_routes.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// send a single request, the inner result carries the node's JSON-RPC error
+    async fn send_request<T: serde::de::DeserializeOwned>(
+        &self,
+        method: &str,
+        params: Vec<Value>,
+    ) -> Result<Result<T, RpcError>, SuiError> {
+        let request = RpcRequest {
+            jsonrpc: "2.0".to_string(),
+            id: 1,
+            method: method.to_string(),
+            params,
+        };
+        let body = self.send_body(serde_json::to_value(&request)?).await?;
+        let response: RpcResponse<T> = serde_json::from_value(body)?;
+
+        if let Some(error) = response.error {
+            return Ok(Err(error));
+        }
+        response
+            .result
+            .map(Ok)
+            .ok_or_else(|| SuiError::Rpc("No result in response".to_string()))
+    }
+
+    /// send a request body, retrying transport errors the retry policy allows
+    async fn send_body(&self, body: Value) -> Result<Value, SuiError> {
+        if let Some(expected_chain) = &self.config.expected_chain
+            && !body_method(&body).ends_with("getChainIdentifier")
+        {
+            self.check_chain(expected_chain).await?;
+        }
+        let sent_at = self.audit_hook.as_ref().map(|_| audit::now_ms());
+        let started = std::time::Instant::now();
+        let priority = queue::current_priority(body_method(&body));
+        let idempotent = is_idempotent(&body);
+        let timeout = queue::current_timeout();
+        let mut attempt = 0;
+        let attempts = async {
+            loop {
+                let permit = match &self.request_queue {
+                    Some(queue) => Some(queue.acquire(priority).await),
+                    None => None,
+                };
+                let sent = match timeout {
+                    Some(timeout) => {
+                        self.transport
+                            .send_with_timeout(body.clone(), timeout)
+                            .await
+                    }
+                    None => self.transport.send(body.clone()).await,
+                };
+                drop(permit);
+                match sent {
+                    Err(error) => match self.config.retry_policy.delay(attempt, &error, idempotent)
+                    {
+                        Some(delay) => {
+                            tracing::debug!(
+                                method = body_method(&body),
+                                attempt = attempt + 1,
+                                delay_ms = delay.as_millis() as u64,
+                                %error,
+                                "retrying rpc request"
+                            );
+                            tokio::time::sleep(delay).await;
+                            attempt += 1;
+                        }
+                        None if attempt > 0 => {
+                            break Err(SuiError::Retried {
+                                attempts: attempt + 1,
+                                error: Box::new(error),
+                            });
+                        }
+                        None => break Err(error),
+                    },
+                    Ok(body) => break Ok(body),
+                }
+            }
+        };
+        // a deadline of the call covers its retries, backoff and queueing included
+        let result = match timeout {
+            Some(timeout) => tokio::time::timeout(timeout, attempts)
+                .await
+                .unwrap_or_else(|_| {
+                    Err(SuiError::Timeout(format!(
+                        "{} got no response within {} ms",
+                        body_method(&body),
+                        timeout.as_millis()
+                    )))
+                }),
+            None => attempts.await,
+        };
+        self.record_latency(&body, started.elapsed());
+        if let (Some(hook), Some(sent_at)) = (&self.audit_hook, sent_at) {
+            let outcome = match &result {
+                Ok(response) => Ok(response.clone()),
+                Err(error) => Err(error.to_string()),
+            };
+            audit::spawn_records(
+                hook.clone(),
+                self.redact_audit_signatures,
+                self.config.rpc_url.clone(),
+                sent_at,
+                body,
+                outcome,
+            );
+        }
+        result
+    }
+
+    /// add a request to the stats of its method, logging it when slow
+    fn record_latency(&self, body: &Value, elapsed: std::time::Duration) {
+        let method = body_method(body);
+        self.stats.record(method, elapsed);
+        if self
+            .slow_call_threshold
+            .is_some_and(|threshold| elapsed >= threshold)
+        {
+            tracing::warn!(
+                method,
+                endpoint = %self.config.rpc_url,
+                elapsed_ms = elapsed.as_millis() as u64,
+                "slow rpc call"
+            );
+        }
+    }
+
+    /// # Get object info
+    ///
+    /// Reads the object with its type, owner and content, see
+    /// [`SuiClient::get_object_with_options`] for other data.
+    ///
+    /// ## Parameters
+    /// - object_id : object id
+    ///
+    /// ## Returns
+    /// - Ok(Object) : object information
+    /// - Err(SuiError) : error
+    ///
+    /// ## Example
+    /// ```no_run
+    /// use sui_network_sdk::SuiClient;
+    /// use sui_network_sdk::global::mainnet;
+    /// #[tokio::main]
+    /// async fn main() {
+    ///    let client = SuiClient::new_by_rpc_url(mainnet::RPC_URL.to_string());
+    ///    let object = client.get_object("object id").await.unwrap();
+    ///    println!("Object owner: {:?}", object.owner);
+    /// }
+    /// ```
+    pub async fn get_object(&self, object_id: &str) -> Result<Object, SuiError> {
+        let options = ObjectDataOptions {
+            show_type: true,
+            show_owner: true,
+            show_content: true,
+            ..Default::default()
+        };
+        self.get_object_with_options(object_id, options).await
+    }
+
+    /// # Get object with options
+    ///
+    /// ## Parameters
+    /// - object_id : object id
+    /// - options : object data to include, fields of the object are `None` when their flag is off
+    ///
+    /// ## Returns
+    /// - Ok(Object) : object information
+    /// - Err(SuiError) : error, including the node's read error of deleted or missing objects
+    ///
+    /// ## Example
+    /// ```rust
+    /// use std::sync::Arc;
+    /// use sui_network_sdk::SuiClient;
+    /// use sui_network_sdk::transport::MockTransport;
+    /// use sui_network_sdk::types::{ObjectDataOptions, SuiClientConfig};
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let mock = Arc::new(
+    ///         MockTransport::new()
+    ///             .with_result("sui_getObject", serde_json::json!({
+    ///                 "data": {
+    ///                     "objectId": "0x5",
+    ///                     "version": "7",
+    ///                     "digest": "9WzSXdwbky8tNbH7juvyaui4QzMUYEjdCEKMrMgLhXHT",
+    ///                     "bcs": {
+    ///                         "dataType": "moveObject",
+    ///                         "type": "0x2::coin::Coin<0x2::sui::SUI>",
+    ///                         "hasPublicTransfer": true,
+    ///                         "version": "7",
+    ///                         "bcsBytes": "AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAUAypo7AAAAAA=="
+    ///                     }
+    ///                 }
+    ///             }))
+    ///             .with_result("sui_getObject", serde_json::json!({ "error": { "code": "notExists", "object_id": "0x6" } })),
+    ///     );
+    ///     let client = SuiClient::new_with_transport(SuiClientConfig::default(), mock.clone());
+    ///     let options = ObjectDataOptions { show_bcs: true, ..Default::default() };
+    ///     let object = client.get_object_with_options("0x5", options).await.unwrap();
+    ///     assert_eq!(object.version, 7);
+    ///     assert!(object.bcs.is_some());
+    ///     assert!(object.owner.is_none() && object.data.is_none());
+    ///     // unset options are left out
+    ///     assert_eq!(mock.requests()[0].params[1], serde_json::json!({ "showBcs": true }));
+    ///
+    ///     let missing = client.get_object("0x6").await.unwrap_err();
+    ///     assert!(missing.is_not_found());
+    ///     let options = &mock.requests()[1].params[1];
+    ///     assert!(options["showType"] == true && options["showOwner"] == true && options["showContent"] == true);
+    /// }
+    /// ```
+    pub async fn get_object_with_options(
+        &self,
+        object_id: &str,
+        options: ObjectDataOptions,
+    ) -> Result<Object, SuiError> {
+        let response: ObjectResponse = self
+            .request(
+                "sui_getObject",
+                vec![object_id.into(), serde_json::to_value(options)?],
+            )
+            .await?;
+        match (response.data, response.error) {
+            (Some(object), _) => Ok(object),
+            (None, error) => Err(SuiError::Rpc(format!(
+                "Object {} not readable: {}",
+                object_id,
+                error.unwrap_or_default()
+            ))),
+        }
+    }
+
+    /// # Wait for object owned by
+    ///
+    /// Reads of a full node may trail execution, right after a transfer the recipient's
+    /// node may not show the new owner yet. Polls the object until the address owns it.
+    ///
+    /// ## Parameters
+    /// - object_id : object id
+    /// - address : expected owner address
+    /// - timeout : longest wait
+    ///
+    /// ## Returns
+    /// - Ok(true) : the address owns the object
+    /// - Ok(false) : the address did not own the object within the timeout
+    /// - Err(SuiError) : a read failed
+    ///
+    /// ## Example
+    /// ```rust
+    /// use std::sync::Arc;
+    /// use std::time::Duration;
+    /// use sui_network_sdk::SuiClient;
+    /// use sui_network_sdk::transport::MockTransport;
+    /// use sui_network_sdk::types::SuiClientConfig;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let object = |owner: &str| serde_json::json!({ "data": {
+    ///         "objectId": "0x0b1",
+    ///         "version": "7",
+    ///         "digest": "9WzSXdwbky8tNbH7juvyaui4QzMUYEjdCEKMrMgLhXHT",
+    ///         "owner": { "AddressOwner": owner }
+    ///     }});
+    ///     // not found yet, still the sender's, then the recipient's
+    ///     let transport = Arc::new(MockTransport::new()
+    ///         .with_result("sui_getObject", serde_json::json!({ "error": { "code": "notExists", "object_id": "0x0b1" } }))
+    ///         .with_result("sui_getObject", object("0xa11ce"))
+    ///         .with_result("sui_getObject", object("0xb0b")));
+    ///     let client = SuiClient::new_with_transport(SuiClientConfig::default(), transport.clone());
+    ///     assert!(client.wait_for_object_owned_by("0x0b1", "0xB0B", Duration::from_secs(5)).await.unwrap());
+    ///     assert_eq!(transport.request_count("sui_getObject"), 3);
+    ///     assert!(!client.wait_for_object_owned_by("0x0b1", "0xa11ce", Duration::from_millis(100)).await.unwrap());
+    /// }
+    /// ```
+    pub async fn wait_for_object_owned_by(
+        &self,
+        object_id: &str,
+        address: &str,
+        timeout: std::time::Duration,
+    ) -> Result<bool, SuiError> {
+        let owner = Owner::AddressOwner(wallet::normalize_address(address));
+        let deadline = tokio::time::Instant::now() + timeout;
+        loop {
+            let response: ObjectResponse = self
+                .request(
+                    "sui_getObject",
+                    vec![object_id.into(), serde_json::json!({ "showOwner": true })],
+                )
+                .await?;
+            if response
+                .data
+                .is_some_and(|object| object.owner.as_ref() == Some(&owner))
+            {
+                return Ok(true);
+            }
+            if tokio::time::Instant::now() + OWNERSHIP_POLL_INTERVAL > deadline {
+                return Ok(false);
+            }
+            tokio::time::sleep(OWNERSHIP_POLL_INTERVAL).await;
+        }
+    }
+
+    /// # Get owned objects
+    ///
+    /// One page of the objects owned by an address.
+    ///
+    /// ## Parameters
+    /// - address : owner address
+    /// - filter : object filter, all objects when `None`
+    /// - options : object data to include, id, version and digest only when `None`
+    /// - cursor : cursor returned by the previous page
+    /// - limit : maximum number of objects in the page, at most 50
+    ///
+    /// ## Returns
+    /// - Ok(Page<Object>) : object page
+    /// - Err(SuiError) : error, or an entry of the page without data
+    ///
+    /// ## Example
+    /// ```rust
+    /// use std::sync::Arc;
+    /// use sui_network_sdk::SuiClient;
+    /// use sui_network_sdk::transport::MockTransport;
+    /// use sui_network_sdk::types::{ObjectDataOptions, ObjectFilter, SuiClientConfig};
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let transport = Arc::new(MockTransport::new().with_result("suix_getOwnedObjects", serde_json::json!({
+    ///         "data": [{ "data": {
+    ///             "objectId": "0xc01",
+    ///             "version": "8",
+    ///             "digest": "9WzSXdwbky8tNbH7juvyaui4QzMUYEjdCEKMrMgLhXHT",
+    ///             "type": "0x2::coin::Coin<0x2::sui::SUI>"
+    ///         }}],
+    ///         "nextCursor": "0xc01",
+    ///         "hasNextPage": false
+    ///     })));
+    ///     let client = SuiClient::new_with_transport(SuiClientConfig::default(), transport.clone());
+    ///
+    ///     // coins of every type
+    ///     let filter = ObjectFilter::StructType("0x2::coin::Coin".to_string());
+    ///     let options = ObjectDataOptions { show_type: true, ..Default::default() };
+    ///     let page = client
+    ///         .get_owned_objects("0xa11ce", Some(filter), Some(options), None, Some(50))
+    ///         .await
+    ///         .unwrap();
+    ///     assert!(page.data[0].parsed_type().unwrap().is_coin());
+    ///     assert_eq!(
+    ///         serde_json::Value::from(transport.requests()[0].params.clone()),
+    ///         serde_json::json!([
+    ///             "0xa11ce",
+    ///             {
+    ///                 "filter": { "StructType": "0x2::coin::Coin" },
+    ///                 "options": { "showType": true }
+    ///             },
+    ///             null,
+    ///             50
+    ///         ])
+    ///     );
+    ///     let filter = ObjectFilter::MoveModule { package: "0x2".to_string(), module: "coin".to_string() };
+    ///     assert_eq!(
+    ///         serde_json::to_value(filter).unwrap(),
+    ///         serde_json::json!({ "MoveModule": { "package": "0x2", "module": "coin" } })
+    ///     );
+    /// }
+    /// ```
+    pub async fn get_owned_objects(
+        &self,
+        address: &str,
+        filter: Option<ObjectFilter>,
+        options: Option<ObjectDataOptions>,
+        cursor: Option<String>,
+        limit: Option<usize>,
+    ) -> Result<Page<Object>, SuiError> {
+        let mut query = serde_json::json!({ "options": options.unwrap_or_default() });
+        if let Some(filter) = filter {
+            query["filter"] = serde_json::to_value(filter)?;
+        }
+        let page: Page<ObjectResponse> = self
+            .request(
+                "suix_getOwnedObjects",
+                vec![address.into(), query, cursor.into(), limit.into()],
+            )
+            .await?;
+        let data = page
+            .data
+            .into_iter()
+            .map(|response| {
+                response.data.ok_or_else(|| {
+                    SuiError::Rpc(format!(
+                        "Owned object of {} not readable: {}",
+                        address,
+                        response.error.unwrap_or_default()
+                    ))
+                })
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(Page {
+            data,
+            next_cursor: page.next_cursor,
+            has_next_page: page.has_next_page,
+        })
+    }
+
+    /// # Get objects owned by address
+    ///
+    /// Pages through all objects owned by the address, with their type and owner, see
+    /// [`SuiClient::get_owned_objects`] for filters and single pages.
+    ///
+    /// ## Parameters
+    /// - address : address
+    ///
+    /// ## Returns
+    /// - Ok(Vec<SuiObject>) : all objects owned by this address..
+    /// - Err(SuiError) : error
+    ///
+    /// ## Example
+    /// ```no_run
+    /// use sui_network_sdk::SuiClient;
+    /// use sui_network_sdk::global::mainnet;
+    /// #[tokio::main]
+    /// async fn main() {
+    ///    let client = SuiClient::new_by_rpc_url(mainnet::RPC_URL.to_string());
+    ///    let objects = client.get_objects_owned_by_address("0x123...").await.unwrap();
+    ///    for object in objects {
+    ///        println!("Object ID: {}", object.object_id);
+    ///    }
+    /// }
+    /// ```
+    pub async fn get_objects_owned_by_address(
+        &self,
+        address: &str,
+    ) -> Result<Vec<Object>, SuiError> {
+        let options = ObjectDataOptions {
+            show_type: true,
+            show_owner: true,
+            ..Default::default()
+        };
+        let mut objects = Vec::new();
+        let mut cursor = None;
+        loop {
+            let page = self
+                .get_owned_objects(
+                    address,
+                    None,
+                    Some(options),
+                    cursor,
+                    Some(MAX_QUERY_PAGE_SIZE),
+                )
+                .await?;
+            objects.extend(page.data);
+            match page.next_cursor {
+                Some(next) if page.has_next_page => cursor = Some(next),
+                _ => return Ok(objects),
+            }
+        }
+    }
+
+    /// # Get coin vec
+    ///
+    /// Pages through all coins of one type owned by the address, see
+    /// [`SuiClient::get_coins_page`] for a single page.
+    ///
+    /// ## Parameters
+    /// - address: address
+    /// - coin_type: coin type ("0x2::sui::SUI")
+    ///
+    /// ## Returns
+    /// - Ok(Vec<Coin>): coin list
+    /// - Err(SuiError): error
+    ///
+    /// ## Example
+    /// ```rust
+    /// use std::sync::Arc;
+    /// use sui_network_sdk::SuiClient;
+    /// use sui_network_sdk::transport::MockTransport;
+    /// use sui_network_sdk::types::SuiClientConfig;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let coin = |id: &str| serde_json::json!({
+    ///         "coinType": "0x2::sui::SUI",
+    ///         "coinObjectId": id,
+    ///         "version": "3",
+    ///         "digest": "9WzSXdwbky8tNbH7juvyaui4QzMUYEjdCEKMrMgLhXHT",
+    ///         "balance": "1000"
+    ///     });
+    ///     // the node may answer an empty page that still has a next page
+    ///     let transport = Arc::new(MockTransport::new()
+    ///         .with_result("suix_getCoins", serde_json::json!({ "data": [coin("0xc1")], "nextCursor": "0xc1", "hasNextPage": true }))
+    ///         .with_result("suix_getCoins", serde_json::json!({ "data": [], "nextCursor": "0xc1", "hasNextPage": true }))
+    ///         .with_result("suix_getCoins", serde_json::json!({ "data": [coin("0xc2")], "nextCursor": "0xc2", "hasNextPage": false })));
+    ///     let client = SuiClient::new_with_transport(SuiClientConfig::default(), transport.clone());
+    ///     let coins = client.get_coin_vec("0xa11ce", None).await.unwrap();
+    ///     assert_eq!(coins.len(), 2);
+    ///     assert_eq!(transport.request_count("suix_getCoins"), 3);
+    /// }
+    /// ```
+    pub async fn get_coin_vec(
+        &self,
+        address: &str,
+        coin_type: Option<&str>,
+    ) -> Result<Vec<Coin>, SuiError> {
+        let coin_type = coin_type.unwrap_or("0x2::sui::SUI");
+        self.all_coin_pages("suix_getCoins", vec![address.into(), coin_type.into()])
+            .await
+    }
+
+    /// # Get all coins for address
+    ///
+    /// Pages through the coins of every type owned by the address.
+    ///
+    /// ## Parameters
+    /// - address : owner address
+    ///
+    /// ## Returns
+    /// - Ok(Vec<Coin>) : coins of all types
+    /// - Err(SuiError) : error
+    ///
+    /// ## Example
+    /// ```no_run
+    /// use sui_network_sdk::SuiClient;
+    /// use sui_network_sdk::global::mainnet;
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let client = SuiClient::new_by_rpc_url(mainnet::RPC_URL.to_string());
+    ///     for coin in client.get_all_coins_for_address("0x123...").await.unwrap() {
+    ///         println!("{} {}", coin.coin_type, coin.balance);
+    ///     }
+    /// }
+    /// ```
+    pub async fn get_all_coins_for_address(&self, address: &str) -> Result<Vec<Coin>, SuiError> {
+        self.all_coin_pages("suix_getAllCoins", vec![address.into()])
+            .await
+    }
+
+    /// read coin pages until the last, params are followed by cursor and limit
+    async fn all_coin_pages(
+        &self,
+        method: &str,
+        params: Vec<Value>,
+    ) -> Result<Vec<Coin>, SuiError> {
+        let mut coins = Vec::new();
+        let mut cursor: Option<String> = None;
+        loop {
+            let mut page_params = params.clone();
+            page_params.extend([cursor.into(), Value::Null]);
+            let page: Page<Coin> = self.request(method, page_params).await?;
+            coins.extend(page.data);
+            // an empty page may still be followed by more pages
+            match page.next_cursor {
+                Some(next) if page.has_next_page => cursor = Some(next),
+                _ => return Ok(coins),
+            }
+        }
+    }
+
+    /// # Get balance
+    ///
+    /// ## Parameters
+    /// - address : address
+    /// - coin_type : coin type ("0x2::sui::SUI")
+    ///
+    /// ## Returns
+    /// - Ok(u64) : total balance, see [`SuiClient::get_balance_detail`] for balances over `u64`
+    /// - Err(SuiError) : error
+    ///
+    /// ## Example
+    /// ```no_run
+    /// use sui_network_sdk::SuiClient;
+    /// use sui_network_sdk::global::mainnet;
+    /// #[tokio::main]
+    /// async fn main() {
+    ///   let client = SuiClient::new_by_rpc_url(mainnet::RPC_URL.to_string());
+    ///   let balance = client.get_balance("0x123...", None).await.unwrap();
+    ///   println!("Balance: {} MIST", balance);
+    /// }
+    /// ```
+    pub async fn get_balance(
+        &self,
+        address: &str,
+        coin_type: Option<&str>,
+    ) -> Result<u64, SuiError> {
+        let balance = self.get_balance_detail(address, coin_type).await?;
+        u64::try_from(balance.total_balance).map_err(|_| {
+            SuiError::Rpc(format!(
+                "Balance {} of {} exceeds u64",
+                balance.total_balance, balance.coin_type
+            ))
+        })
+    }
+
+    /// # Get balance detail
+    ///
+    /// ## Parameters
+    /// - address : address
+    /// - coin_type : coin type ("0x2::sui::SUI")
+    ///
+    /// ## Returns
+    /// - Ok(Balance) : total and locked balance with the coin object count
+    /// - Err(SuiError) : error
+    ///
+    /// ## Example
+    /// ```rust
+    /// use std::sync::Arc;
+    /// use sui_network_sdk::SuiClient;
+    /// use sui_network_sdk::transport::MockTransport;
+    /// use sui_network_sdk::types::SuiClientConfig;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let mock = Arc::new(MockTransport::new().with_result(
+    ///         "sui_getBalance",
+    ///         serde_json::json!({
+    ///             "coinType": "0x2::sui::SUI",
+    ///             "coinObjectCount": 2,
+    ///             "totalBalance": "4990000000",
+    ///             "lockedBalance": {}
+    ///         }),
+    ///     ));
+    ///     let client = SuiClient::new_with_transport(SuiClientConfig::default(), mock);
+    ///     let balance = client.get_balance_detail("0xa1", None).await.unwrap();
+    ///     assert_eq!((balance.coin_object_count, balance.total_balance), (2, 4_990_000_000));
+    ///     assert!(balance.locked_balance.is_empty());
+    ///     // the total arrives as a string
+    ///     assert_eq!(client.get_balance("0xa1", None).await.unwrap(), 4_990_000_000);
+    /// }
+    /// ```
+    pub async fn get_balance_detail(
+        &self,
+        address: &str,
+        coin_type: Option<&str>,
+    ) -> Result<Balance, SuiError> {
+        let coin_type = coin_type.unwrap_or("0x2::sui::SUI");
+        self.request("sui_getBalance", vec![address.into(), coin_type.into()])
+            .await
+    }
+
+    /// # Get all balances
+    ///
+    /// ## Parameters
+    /// - address : address
+    ///
+    /// ## Returns
+    /// - Ok(Vec<Balance>) : balance of every coin type the address holds, empty without coins
+    /// - Err(SuiError) : error
+    ///
+    /// ## Example
+    /// ```rust
+    /// use std::sync::Arc;
+    /// use sui_network_sdk::SuiClient;
+    /// use sui_network_sdk::transport::MockTransport;
+    /// use sui_network_sdk::types::SuiClientConfig;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let lp = "0xd0e2::pool::LP<0xdba3::usdc::USDC, 0x2::sui::SUI, 0x9f1::curve::Stable<0x5d4b::coin::COIN>>";
+    ///     let mock = Arc::new(
+    ///         MockTransport::new()
+    ///             .with_result("suix_getAllBalances", serde_json::json!([
+    ///                 { "coinType": "0x2::sui::SUI", "coinObjectCount": 3, "totalBalance": "5000000000", "lockedBalance": {} },
+    ///                 { "coinType": lp, "coinObjectCount": 1, "totalBalance": "340282366920938463463374607431768211455" }
+    ///             ]))
+    ///             .with_result("suix_getAllBalances", serde_json::json!([])),
+    ///     );
+    ///     let client = SuiClient::new_with_transport(SuiClientConfig::default(), mock);
+    ///     let balances = client.get_all_balances("0xa1").await.unwrap();
+    ///     assert_eq!(balances.len(), 2);
+    ///     assert_eq!((balances[0].coin_object_count, balances[0].total_balance), (3, 5_000_000_000));
+    ///     assert_eq!(balances[1].coin_type, lp);
+    ///     assert_eq!(balances[1].total_balance, u128::MAX);
+    ///     // an address without coins
+    ///     assert!(client.get_all_balances("0xb0b").await.unwrap().is_empty());
+    /// }
+    /// ```
+    pub async fn get_all_balances(&self, address: &str) -> Result<Vec<Balance>, SuiError> {
+        self.request("suix_getAllBalances", vec![address.into()])
+            .await
+    }
+
+    /// # Execute transaction
+    ///
+    /// ## Parameters
+    /// -  trade_bytes : serialized transaction bytes
+    /// -  sign : transaction signature
+    /// -  public_key : public key of the signer, with its signature scheme
+    ///
+    /// ## Returns
+    /// -  Ok(TransactionResponse) : execution transaction result
+    /// -  Err(SuiError) : execution transaction error
+    ///
+    /// ## Example
+    /// ```no_run
+    /// use sui_network_sdk::SuiClient;
+    /// use sui_network_sdk::global::mainnet;
+    /// use sui_network_sdk::wallet::Wallet;
+    /// #[tokio::main]
+    /// async fn main() {
+    ///   let client = SuiClient::new_by_rpc_url(mainnet::RPC_URL.to_string());
+    ///   let wallet = Wallet::new().unwrap();
+    ///   let tx_bytes = vec![];
+    ///   let signature = wallet.sign_transaction(&tx_bytes);
+    ///   let response = client.exe_transaction(tx_bytes, signature, wallet.sui_public_key()).await.unwrap();
+    ///  println!("Transaction digest: {:?}", response.digest);
+    /// }
+    /// ```
+    pub async fn exe_transaction(
+        &self,
+        trade_bytes: Vec<u8>,
+        sign: Vec<u8>,
+        public_key: SuiPublicKey,
+    ) -> Result<TransactionResponse, SuiError> {
+        let signature = serialized_signature(public_key.scheme(), &sign, public_key.as_bytes());
+        self.execute_transaction_signatures(trade_bytes, vec![signature])
+            .await
+    }
+
+    /// # Execute transaction with signatures
+    ///
+    /// Executes a transaction carrying several signatures, e.g. the sender's and the gas
+    /// sponsor's.
+    ///
+    /// ## Parameters
+    /// - tx_bytes : serialized transaction bytes
+    /// - signatures : base64 serialized signatures (scheme flag, signature, public key)
+    ///
+    /// ## Returns
+    /// - Ok(TransactionResponse) : execution result
+    /// - Err(SuiError) : error
+    pub async fn execute_transaction_signatures(
+        &self,
+        tx_bytes: Vec<u8>,
+        signatures: Vec<String>,
+    ) -> Result<TransactionResponse, SuiError> {
+        let tx_bytes = BASE64_STANDARD.encode(tx_bytes);
+        let params = vec![
+            tx_bytes.into(),
+            signatures.into(),
+            // events can be large, they stay one call away with events_lazy
+            serde_json::to_value(TransactionBlockResponseOptions::default().with_effects())?,
+            serde_json::to_value(ExecuteTransactionRequestType::WaitForLocalExecution)?,
+        ];
+        self.request("sui_executeTransactionBlock", params).await
+    }
+
+    /// # Dry run transaction block
+    ///
+    /// Executes a transaction without committing it, e.g. to read its gas cost before
+    /// signing. A failing execution is reported in the effects status, not as an error.
+    ///
+    /// ## Parameters
+    /// - tx_bytes : BCS bytes of the transaction data
+    ///
+    /// ## Returns
+    /// - Ok(DryRunResult) : effects with the gas cost, events, object and balance changes
+    /// - Err(SuiError) : error, e.g. the node rejected the transaction
+    ///
+    /// ## Example
+    /// ```rust
+    /// use std::sync::Arc;
+    /// use sui_network_sdk::SuiClient;
+    /// use sui_network_sdk::transport::MockTransport;
+    /// use sui_network_sdk::types::SuiClientConfig;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let mock = Arc::new(MockTransport::new().with_result(
+    ///         "sui_dryRunTransactionBlock",
+    ///         serde_json::json!({
+    ///             "effects": {
+    ///                 "status": { "status": "success" },
+    ///                 "gasUsed": { "computationCost": "750000", "storageCost": "1976000", "storageRebate": "978120" },
+    ///                 "transactionDigest": "D1"
+    ///             },
+    ///             "events": [],
+    ///             "objectChanges": [],
+    ///             "balanceChanges": []
+    ///         }),
+    ///     ));
+    ///     let client = SuiClient::new_with_transport(SuiClientConfig::default(), mock.clone());
+    ///
+    ///     let dry_run = client.dry_run_transaction_block(&[1, 2, 3]).await.unwrap();
+    ///     assert!(dry_run.effects.status.is_success());
+    ///     assert_eq!(dry_run.effects.gas_used.gas_budget(0), 1_747_880);
+    ///     assert_eq!(mock.requests()[0].params[0], "AQID");
+    /// }
+    /// ```
+    pub async fn dry_run_transaction_block(
+        &self,
+        tx_bytes: &[u8],
+    ) -> Result<DryRunResult, SuiError> {
+        let params = vec![BASE64_STANDARD.encode(tx_bytes).into()];
+        self.request("sui_dryRunTransactionBlock", params).await
+    }
+
+    /// # Dev inspect
+    ///
+    /// Runs a transaction without gas or signatures and commits nothing, e.g. to call a
+    /// view function such as a DEX quote. Return values stay raw BCS bytes with their
+    /// Move types.
+    ///
+    /// ## Parameters
+    /// - sender : address the transaction runs as
+    /// - tx_bytes : BCS bytes of the transaction kind, see [`ProgrammableTransaction::kind_bytes`]
+    /// - gas_price : gas price of the run, the reference gas price when `None`
+    /// - epoch : epoch of the run, the current epoch when `None`
+    ///
+    /// ## Returns
+    /// - Ok(DevInspectResults) : effects, events, values of each command and execution error
+    /// - Err(SuiError) : error
+    ///
+    /// ## Example
+    /// ```rust
+    /// use std::sync::Arc;
+    /// use sui_network_sdk::SuiClient;
+    /// use sui_network_sdk::ptb::{Argument, ProgrammableTransactionBuilder};
+    /// use sui_network_sdk::transport::MockTransport;
+    /// use sui_network_sdk::types::SuiClientConfig;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let mock = Arc::new(MockTransport::new().with_result(
+    ///         "sui_devInspectTransactionBlock",
+    ///         serde_json::json!({
+    ///             "effects": {
+    ///                 "status": { "status": "success" },
+    ///                 "gasUsed": { "computationCost": "1000", "storageCost": "0", "storageRebate": "0" },
+    ///                 "transactionDigest": "D1"
+    ///             },
+    ///             "events": [],
+    ///             "results": [{
+    ///                 "mutableReferenceOutputs": [[{ "Input": 0 }, [1], "0x5a0b::pool::Pool"]],
+    ///                 "returnValues": [[[64, 66, 15, 0, 0, 0, 0, 0], "u64"]]
+    ///             }]
+    ///         }),
+    ///     ));
+    ///     let client = SuiClient::new_with_transport(SuiClientConfig::default(), mock.clone());
+    ///     let mut builder = ProgrammableTransactionBuilder::new();
+    ///     let pool = builder.pure(&7u64).unwrap();
+    ///     builder.move_call("0x5a0b", "pool", "quote", vec![], vec![pool]).unwrap();
+    ///     let kind = builder.finish().kind_bytes().unwrap();
+    ///
+    ///     let results = client.dev_inspect("0xa11ce", &kind, Some(1000), None).await.unwrap();
+    ///     assert!(results.error.is_none());
+    ///     let quote = &results.return_values(0)[0];
+    ///     assert_eq!(quote.type_tag, "u64");
+    ///     assert_eq!(bcs::from_bytes::<u64>(&quote.bcs).unwrap(), 1_000_000);
+    ///     assert_eq!(results.results[0].mutable_reference_outputs[0].argument, Argument::Input(0));
+    ///
+    ///     let params = &mock.requests()[0].params;
+    ///     assert_eq!(params[2], "1000");
+    ///     assert!(params[3].is_null());
+    /// }
+    /// ```
+    ///
+    /// [`ProgrammableTransaction::kind_bytes`]: crate::ptb::ProgrammableTransaction::kind_bytes
+    pub async fn dev_inspect(
+        &self,
+        sender: &str,
+        tx_bytes: &[u8],
+        gas_price: Option<u64>,
+        epoch: Option<u64>,
+    ) -> Result<DevInspectResults, SuiError> {
+        let params = vec![
+            sender.into(),
+            BASE64_STANDARD.encode(tx_bytes).into(),
+            gas_price.map(|price| price.to_string()).into(),
+            epoch.map(|epoch| epoch.to_string()).into(),
+        ];
+        self.request("sui_devInspectTransactionBlock", params).await
+    }
+
+    /// # Get trade info
+    ///
+    /// ## Parameters
+    /// - `digest`: trade hash
+    ///
+    /// ## Returns
+    /// - `Ok(TransactionResponse)`: trade details
+    /// - `Err(SuiError)`: Query error
+    ///
+    /// ## Example
+    /// ```no_run
+    /// use sui_network_sdk::SuiClient;
+    /// use sui_network_sdk::global::mainnet;
+    /// #[tokio::main]
+    /// async fn main() {
+    ///    let client = SuiClient::new_by_rpc_url(mainnet::RPC_URL.to_string());
+    ///    let tx_info = client.get_transaction_info("hash").await.unwrap();
+    ///    if let Some(effects) = tx_info.effects {
+    ///        println!("Transaction status: {:?}", effects.status);
+    ///    }
+    /// }
+    /// ```
+    pub async fn get_transaction_info(&self, hash: &str) -> Result<TransactionResponse, SuiError> {
+        let options = serde_json::json!({ "showEffects": true, "showEvents": true });
+        self.get_transaction_block(hash, options).await
+    }
+
+    /// # Get transaction block
+    ///
+    /// ## Parameters
+    /// - digest : transaction digest
+    /// - options : response options, e.g. `{ "showObjectChanges": true, "showInput": true }`
+    ///
+    /// ## Returns
+    /// - Ok(TransactionResponse) : transaction with the requested parts
+    /// - Err(SuiError) : error, annotated when the transaction may have been pruned
+    pub async fn get_transaction_block(
+        &self,
+        digest: &str,
+        options: Value,
+    ) -> Result<TransactionResponse, SuiError> {
+        match self
+            .request("sui_getTransactionBlock", vec![digest.into(), options])
+            .await
+        {
+            Ok(tr) => Ok(tr),
+            Err(e) => Err(self.annotate_pruned(e).await),
+        }
+    }
+
+    /// # Get node capabilities
+    ///
+    /// Probes the node once (`rpc.discover`, the chain identifier, checkpoint 0 and a bounded
+    /// binary search for the lowest available checkpoint), the result is cached on the client.
+    /// When the search runs out of probes the start of the history is only known to lie
+    /// between `earliest_checkpoint_lower_bound` and `earliest_checkpoint`.
+    ///
+    /// ## Returns
+    /// - Ok(NodeCapabilities) : node capabilities
+    /// - Err(SuiError) : error, other than a checkpoint not found
+    ///
+    /// ## Example
+    /// ```rust
+    /// use std::sync::Arc;
+    /// use sui_network_sdk::SuiClient;
+    /// use sui_network_sdk::transport::MockTransport;
+    /// use sui_network_sdk::types::SuiClientConfig;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     // checkpoint 0 is pruned, every later probe exists: the search can't converge
+    ///     let mock = Arc::new(
+    ///         MockTransport::new()
+    ///             .with_error("sui_getCheckpoint", -32602, "Could not find checkpoint 0")
+    ///             .with_result(
+    ///                 "sui_getLatestCheckpointSequenceNumber",
+    ///                 serde_json::json!(u64::pow(2, 40).to_string()),
+    ///             ),
+    ///     );
+    ///     mock.push_result("sui_getCheckpoint", serde_json::json!({}));
+    ///     let client = SuiClient::new_with_transport(SuiClientConfig::default(), mock);
+    ///     let capabilities = client.node_capabilities().await.unwrap();
+    ///     assert!(capabilities.is_pruned());
+    ///     assert!(!capabilities.is_earliest_checkpoint_exact());
+    ///     assert_eq!(capabilities.earliest_checkpoint_lower_bound, 1);
+    ///     assert_eq!(capabilities.earliest_checkpoint, 1 << 20);
+    ///
+    ///     // a failing node is an error, not a pruned checkpoint
+    ///     let mock = Arc::new(
+    ///         MockTransport::new().with_error("sui_getCheckpoint", -32603, "Internal error"),
+    ///     );
+    ///     let client = SuiClient::new_with_transport(SuiClientConfig::default(), mock);
+    ///     assert!(client.node_capabilities().await.is_err());
+    /// }
+    /// ```
+    pub async fn node_capabilities(&self) -> Result<NodeCapabilities, SuiError> {
+        self.capabilities
+            .get_or_try_init(|| self.probe_node_capabilities())
+            .await
+            .cloned()
+    }
+
+    /// # Get transaction limits
+    ///
+    /// Reads the size, input object, command and gas limits from `sui_getProtocolConfig`,
+    /// the result is cached on the client until a system state read shows a new epoch or
+    /// protocol version. Once the known epoch should have ended the system state is read
+    /// again, so the limits follow protocol upgrades without a
+    /// [`watch_protocol_version`](Self::watch_protocol_version) running. Nodes without the
+    /// method get `TransactionLimits::default()`.
+    ///
+    /// ## Returns
+    /// - Ok(TransactionLimits) : transaction limits
+    /// - Err(SuiError) : error
+    ///
+    /// ## Example
+    /// ```rust
+    /// use std::sync::Arc;
+    /// use sui_network_sdk::SuiClient;
+    /// use sui_network_sdk::transport::MockTransport;
+    /// use sui_network_sdk::types::{SuiClientConfig, TransactionLimits};
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let mock = Arc::new(MockTransport::new().with_result(
+    ///         "sui_getProtocolConfig",
+    ///         serde_json::json!({
+    ///             "protocolVersion": "70",
+    ///             "attributes": {
+    ///                 "max_tx_size_bytes": { "u64": "131072" },
+    ///                 "max_input_objects": { "u64": "2048" },
+    ///                 "max_programmable_tx_commands": { "u32": "512" },
+    ///                 "max_gas_payment_objects": { "u32": "256" }
+    ///             }
+    ///         }),
+    ///     ));
+    ///     let client = SuiClient::new_with_transport(SuiClientConfig::default(), mock.clone());
+    ///     let limits = client.transaction_limits().await.unwrap();
+    ///     assert_eq!(
+    ///         limits,
+    ///         TransactionLimits { max_tx_size_bytes: 131_072, max_input_objects: 2048, max_commands: 512, ..Default::default() }
+    ///     );
+    ///     client.transaction_limits().await.unwrap();
+    ///     assert_eq!(mock.request_count("sui_getProtocolConfig"), 1);
+    /// }
+    /// ```
+    pub async fn transaction_limits(&self) -> Result<TransactionLimits, SuiError> {
+        let mut cache = self.transaction_limits.lock().await;
+        self.recheck_ended_epoch().await;
+        let changes = self.epoch_tracker.lock().unwrap().changes;
+        if let Some((read_at, limits)) = *cache
+            && read_at == changes
+        {
+            return Ok(limits);
+        }
+        let limits = match self.request::<Value>("sui_getProtocolConfig", vec![]).await {
+            Ok(config) => TransactionLimits::from_protocol_config(&config),
+            Err(SuiError::Rpc(_)) => TransactionLimits::default(),
+            Err(e) => return Err(e),
+        };
+        *cache = Some((changes, limits));
+        Ok(limits)
+    }
+
+    /// # Watch protocol version
+    ///
+    /// Polls the system state and yields when the epoch or the protocol version changes.
+    /// The first read is the baseline, reads of a node behind the last one seen are
+    /// ignored. Failed reads are yielded and polling goes on. Every read also refreshes the
+    /// client's cached [`transaction_limits`](Self::transaction_limits) after a change.
+    ///
+    /// ## Parameters
+    /// - interval : wait between reads
+    ///
+    /// ## Returns
+    /// stream of changes with the old and new epoch and protocol version
+    ///
+    /// ## Example
+    /// ```rust
+    /// use std::sync::Arc;
+    /// use std::time::Duration;
+    /// use futures::StreamExt;
+    /// use sui_network_sdk::SuiClient;
+    /// use sui_network_sdk::transport::MockTransport;
+    /// use sui_network_sdk::types::{ProtocolVersionChange, SuiClientConfig};
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let state = |epoch: u64, version: u64| serde_json::json!({
+    ///         "epoch": epoch.to_string(),
+    ///         "protocolVersion": version.to_string(),
+    ///         "referenceGasPrice": "750",
+    ///         "totalStake": "0",
+    ///         "activeValidators": []
+    ///     });
+    ///     let config = |max_tx_gas: &str| serde_json::json!({
+    ///         "protocolVersion": "70",
+    ///         "attributes": { "max_tx_gas": { "u64": max_tx_gas } }
+    ///     });
+    ///     let mock = Arc::new(
+    ///         MockTransport::new()
+    ///             .with_result("suix_getLatestSuiSystemState", state(600, 70))
+    ///             .with_result("suix_getLatestSuiSystemState", state(600, 70))
+    ///             .with_result("suix_getLatestSuiSystemState", state(601, 71))
+    ///             .with_result("sui_getProtocolConfig", config("50000000000"))
+    ///             .with_result("sui_getProtocolConfig", config("60000000000")),
+    ///     );
+    ///     let client = SuiClient::new_with_transport(SuiClientConfig::default(), mock.clone());
+    ///     assert_eq!(client.transaction_limits().await.unwrap().max_tx_gas, 50_000_000_000);
+    ///
+    ///     let mut changes = client.watch_protocol_version(Duration::from_millis(1));
+    ///     let change = changes.next().await.unwrap().unwrap();
+    ///     assert_eq!(
+    ///         change,
+    ///         ProtocolVersionChange {
+    ///             old_epoch: 600,
+    ///             new_epoch: 601,
+    ///             old_protocol_version: 70,
+    ///             new_protocol_version: 71
+    ///         }
+    ///     );
+    ///     assert!(change.protocol_upgraded());
+    ///     assert_eq!(mock.request_count("suix_getLatestSuiSystemState"), 3);
+    ///
+    ///     // the limits of the new protocol version are read on next use
+    ///     assert_eq!(client.transaction_limits().await.unwrap().max_tx_gas, 60_000_000_000);
+    ///     assert_eq!(mock.request_count("sui_getProtocolConfig"), 2);
+    /// }
+    /// ```
+    pub fn watch_protocol_version(
+        &self,
+        interval: std::time::Duration,
+    ) -> futures::stream::BoxStream<'_, Result<ProtocolVersionChange, SuiError>> {
+        futures::stream::unfold(
+            (None::<(u64, u64)>, true),
+            move |(mut last, first)| async move {
+                if !first {
+                    tokio::time::sleep(interval).await;
+                }
+                loop {
+                    let summary = match self.get_latest_sui_system_state().await {
+                        Ok(summary) => summary,
+                        Err(e) => return Some((Err(e), (last, false))),
+                    };
+                    let seen = (summary.epoch, summary.protocol_version);
+                    match last {
+                        Some(previous) if seen > previous => {
+                            let change = ProtocolVersionChange {
+                                old_epoch: previous.0,
+                                new_epoch: seen.0,
+                                old_protocol_version: previous.1,
+                                new_protocol_version: seen.1,
+                            };
+                            return Some((Ok(change), (Some(seen), false)));
+                        }
+                        Some(_) => {}
+                        None => last = Some(seen),
+                    }
+                    tokio::time::sleep(interval).await;
+                }
+            },
+        )
+        .boxed()
+    }
+
+    /// record the epoch of a system state read, counting changes from the previous one
+    fn observe_protocol_state(&self, summary: &SuiSystemStateSummary) {
+        let next = ProtocolState {
+            epoch: summary.epoch,
+            protocol_version: summary.protocol_version,
+            epoch_ends_at_ms: (summary.epoch_duration_ms > 0)
+                .then(|| summary.epoch_start_timestamp_ms + summary.epoch_duration_ms),
+            read_at: std::time::Instant::now(),
+        };
+        let mut tracker = self.epoch_tracker.lock().unwrap();
+        match tracker.state {
+            Some(previous)
+                if (next.epoch, next.protocol_version)
+                    < (previous.epoch, previous.protocol_version) =>
+            {
+                // a node behind the one read before
+                return;
+            }
+            Some(previous)
+                if (next.epoch, next.protocol_version)
+                    > (previous.epoch, previous.protocol_version) =>
+            {
+                tracker.changes += 1;
+            }
+            _ => {}
+        }
+        tracker.state = Some(next);
+    }
+
+    /// read the system state again once the known epoch should have ended
+    async fn recheck_ended_epoch(&self) {
+        let ended = self
+            .epoch_tracker
+            .lock()
+            .unwrap()
+            .state
+            .is_some_and(|state| {
+                state
+                    .epoch_ends_at_ms
+                    .is_some_and(|ends_at| ends_at <= crate::audit::now_ms())
+                    && state.read_at.elapsed() >= EPOCH_RECHECK_INTERVAL
+            });
+        if ended {
+            // a failed read keeps the cached values until the next check
+            let _ = self.get_latest_sui_system_state().await;
+        }
+    }
+
+    /// # Get chain identifier
+    ///
+    /// ## Returns
+    /// - Ok(String) : identifier of the node's chain, the first four bytes of the genesis
+    ///   checkpoint digest in hex, e.g. `explorer::MAINNET_CHAIN_ID`
+    /// - Err(SuiError) : error
+    ///
+    /// ## Example
+    /// ```rust
+    /// use std::sync::Arc;
+    /// use sui_network_sdk::SuiClient;
+    /// use sui_network_sdk::explorer::{MAINNET_CHAIN_ID, TESTNET_CHAIN_ID};
+    /// use sui_network_sdk::transport::MockTransport;
+    /// use sui_network_sdk::types::{SuiClientConfig, SuiError};
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let mock = Arc::new(
+    ///         MockTransport::new()
+    ///             .with_result("sui_getChainIdentifier", serde_json::json!(TESTNET_CHAIN_ID))
+    ///             .with_result("suix_getReferenceGasPrice", serde_json::json!("750")),
+    ///     );
+    ///     let client = SuiClient::new_with_transport(SuiClientConfig::default(), mock.clone());
+    ///     assert_eq!(client.get_chain_identifier().await.unwrap(), TESTNET_CHAIN_ID);
+    ///
+    ///     // a mainnet bot pointed at testnet refuses to send anything
+    ///     let config = SuiClientConfig {
+    ///         expected_chain: Some(MAINNET_CHAIN_ID.to_string()),
+    ///         ..Default::default()
+    ///     };
+    ///     let client = SuiClient::new_with_transport(config, mock.clone());
+    ///     for _ in 0..2 {
+    ///         match client.get_reference_gas_price().await {
+    ///             Err(SuiError::Rpc(e)) => assert!(e.contains("expected 35834a8a (mainnet)"), "{}", e),
+    ///             other => panic!("unexpected {:?}", other),
+    ///         }
+    ///     }
+    ///     assert_eq!(mock.request_count("suix_getReferenceGasPrice"), 0);
+    ///     // the identifier is read once per client
+    ///     assert_eq!(mock.request_count("sui_getChainIdentifier"), 2);
+    ///
+    ///     let config = SuiClientConfig {
+    ///         expected_chain: Some(TESTNET_CHAIN_ID.to_string()),
+    ///         ..Default::default()
+    ///     };
+    ///     let client = SuiClient::new_with_transport(config, mock.clone());
+    ///     assert_eq!(client.get_reference_gas_price().await.unwrap(), 750);
+    /// }
+    /// ```
+    pub async fn get_chain_identifier(&self) -> Result<String, SuiError> {
+        self.request("sui_getChainIdentifier", vec![]).await
+    }
+
+    /// # Get protocol config
+    ///
+    /// ## Parameters
+    /// - version : protocol version, the node's current version when `None`
+    ///
+    /// ## Returns
+    /// - Ok(ProtocolConfig) : supported versions, feature flags and attributes
+    /// - Err(SuiError) : error, e.g. a version the node does not support
+    ///
+    /// ## Example
+    /// ```rust
+    /// use std::sync::Arc;
+    /// use sui_network_sdk::SuiClient;
+    /// use sui_network_sdk::transport::MockTransport;
+    /// use sui_network_sdk::types::SuiClientConfig;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let mock = Arc::new(MockTransport::new().with_result(
+    ///         "sui_getProtocolConfig",
+    ///         serde_json::json!({
+    ///             "minSupportedProtocolVersion": "1",
+    ///             "maxSupportedProtocolVersion": "70",
+    ///             "protocolVersion": "68",
+    ///             "featureFlags": {},
+    ///             "attributes": { "max_tx_gas": { "u64": "50000000000" } }
+    ///         }),
+    ///     ));
+    ///     let client = SuiClient::new_with_transport(SuiClientConfig::default(), mock.clone());
+    ///     let config = client.get_protocol_config(Some(68)).await.unwrap();
+    ///     assert_eq!(config.protocol_version, 68);
+    ///     assert_eq!(config.attribute_u64("max_tx_gas"), Some(50_000_000_000));
+    ///     assert_eq!(mock.requests()[0].params[0], "68");
+    /// }
+    /// ```
+    pub async fn get_protocol_config(
+        &self,
+        version: Option<u64>,
+    ) -> Result<ProtocolConfig, SuiError> {
+        let params = vec![version.map(|version| version.to_string()).into()];
+        self.request("sui_getProtocolConfig", params).await
+    }
+
+    /// fail unless the node is on the expected chain, the identifier is read once
+    async fn check_chain(&self, expected_chain: &str) -> Result<(), SuiError> {
+        let chain_identifier = self
+            .chain_identifier
+            .get_or_try_init(|| Box::pin(self.get_chain_identifier()))
+            .await?;
+        if chain_identifier == expected_chain {
+            return Ok(());
+        }
+        let network =
+            |chain_identifier: &str| match Network::from_chain_identifier(chain_identifier) {
+                Network::Mainnet => "mainnet",
+                Network::Testnet => "testnet",
+                Network::Unknown(_) => "unknown network",
+            };
+        Err(SuiError::Rpc(format!(
+            "chain check: {} is on chain {} ({}), expected {} ({})",
+            self.config.rpc_url,
+            chain_identifier,
+            network(chain_identifier),
+            expected_chain,
+            network(expected_chain)
+        )))
+    }
+
+    /// probe node capabilities
+    async fn probe_node_capabilities(&self) -> Result<NodeCapabilities, SuiError> {
+        let (api_version, has_subscriptions) =
+            match self.request::<Value>("rpc.discover", vec![]).await {
+                Ok(spec) => {
+                    let api_version = spec
+                        .get("info")
+                        .and_then(|i| i.get("version"))
+                        .and_then(|v| v.as_str())
+                        .map(|v| v.to_string());
+                    let has_subscriptions = spec
+                        .get("methods")
+                        .and_then(|m| m.as_array())
+                        .map(|methods| {
+                            methods.iter().any(|m| {
+                                m.get("name")
+                                    .and_then(|n| n.as_str())
+                                    .is_some_and(|n| n.contains("subscribe"))
+                            })
+                        })
+                        .unwrap_or(false);
+                    (api_version, has_subscriptions)
+                }
+                Err(_) => (None, false),
+            };
+        let chain_identifier = self.get_chain_identifier().await.ok();
+        let (earliest_checkpoint_lower_bound, earliest_checkpoint) =
+            if self.checkpoint_exists(0).await? {
+                (0, 0)
+            } else {
+                let latest: String = self
+                    .request("sui_getLatestCheckpointSequenceNumber", vec![])
+                    .await?;
+                let latest = latest.parse::<u64>().map_err(|e| {
+                    SuiError::Rpc(format!("Invalid checkpoint sequence number: {}", e))
+                })?;
+                // checkpoints below low are pruned, high is the lowest probed one known to exist
+                let (mut low, mut high) = (1, latest);
+                let mut probes = 0;
+                while low < high && probes < MAX_PRUNING_PROBES {
+                    let mid = low + (high - low) / 2;
+                    if self.checkpoint_exists(mid).await? {
+                        high = mid;
+                    } else {
+                        low = mid + 1;
+                    }
+                    probes += 1;
+                }
+                (low, high)
+            };
+        Ok(NodeCapabilities {
+            earliest_checkpoint,
+            earliest_checkpoint_lower_bound,
+            has_subscriptions,
+            api_version,
+            chain_identifier,
+        })
+    }
+
+    /// whether the node still serves the checkpoint
+    async fn checkpoint_exists(&self, sequence_number: u64) -> Result<bool, SuiError> {
+        match self
+            .request::<Value>(
+                "sui_getCheckpoint",
+                vec![sequence_number.to_string().into()],
+            )
+            .await
+        {
+            Ok(_) => Ok(true),
+            Err(e) if e.is_not_found() => Ok(false),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// annotate not-found errors with the node's pruning horizon
+    async fn annotate_pruned(&self, error: SuiError) -> SuiError {
+        if !error.is_not_found() {
+            return error;
+        }
+        match (error, self.node_capabilities().await) {
+            (SuiError::Rpc(e), Ok(capabilities))
+                if capabilities.is_pruned() && capabilities.is_earliest_checkpoint_exact() =>
+            {
+                SuiError::Rpc(format!(
+                    "{}; may be pruned; node history starts at checkpoint {}",
+                    e, capabilities.earliest_checkpoint
+                ))
+            }
+            (SuiError::Rpc(e), Ok(capabilities)) if capabilities.is_pruned() => {
+                SuiError::Rpc(format!(
+                    "{}; may be pruned; node history starts between checkpoints {} and {}",
+                    e,
+                    capabilities.earliest_checkpoint_lower_bound,
+                    capabilities.earliest_checkpoint
+                ))
+            }
+            (error, _) => error,
+        }
+    }
+
+    /// # Get objects consistent
+    ///
+    /// Fetches the objects, then re-fetches any object whose version changed while the batch
+    /// was read, until every object is seen at the same version twice in a row.
+    ///
+    /// ## Parameters
+    /// - object_ids : object ids
+    ///
+    /// ## Returns
+    /// - Ok(Vec<Object>) : objects forming a mutually consistent snapshot, in input order
+    /// - Err(SuiError::Contended) : ids of the objects still changing after the last round
+    /// - Err(SuiError) : error
+    ///
+    /// ## Example
+    /// ```no_run
+    /// use sui_network_sdk::SuiClient;
+    /// use sui_network_sdk::global::mainnet;
+    /// #[tokio::main]
+    /// async fn main() {
+    ///    let client = SuiClient::new_by_rpc_url(mainnet::RPC_URL.to_string());
+    ///    let objects = client.get_objects_consistent(&["0x1...", "0x2..."]).await.unwrap();
+    ///    for object in objects {
+    ///        println!("{} @ {}", object.object_id, object.version);
+    ///    }
+    /// }
+    /// ```
+    pub async fn get_objects_consistent(
+        &self,
+        object_ids: &[&str],
+    ) -> Result<Vec<Object>, SuiError> {
+        let mut objects = self.fetch_objects(object_ids).await?;
+        // indexes of objects not yet confirmed at a stable version
+        let mut pending: Vec<usize> = (0..objects.len()).collect();
+        for _ in 0..MAX_CONSISTENT_READ_ROUNDS {
+            let ids: Vec<&str> = pending.iter().map(|&i| object_ids[i]).collect();
+            let refetched = self.fetch_objects(&ids).await?;
+            let mut changed = Vec::new();
+            for (&i, object) in pending.iter().zip(refetched) {
+                if object.version != objects[i].version {
+                    changed.push(i);
+                }
+                objects[i] = object;
+            }
+            if changed.is_empty() {
+                return Ok(objects);
+            }
+            pending = changed;
+        }
+        Err(SuiError::Contended(
+            pending.iter().map(|&i| object_ids[i].to_string()).collect(),
+        ))
+    }
+
+    /// fetch objects in input order, failing if any object can't be read
+    async fn fetch_objects(&self, object_ids: &[&str]) -> Result<Vec<Object>, SuiError> {
+        let options = ObjectDataOptions {
+            show_type: true,
+            show_owner: true,
+            show_content: true,
+            ..Default::default()
+        };
+        self.fetch_objects_with(object_ids, options).await
+    }
+
+    /// fetch objects with show options in input order, failing if any object can't be read
+    async fn fetch_objects_with(
+        &self,
+        object_ids: &[&str],
+        options: ObjectDataOptions,
+    ) -> Result<Vec<Object>, SuiError> {
+        self.multi_get_objects(object_ids, options)
+            .await
+            .into_iter()
+            .collect()
+    }
+
+    /// # Multi get objects
+    ///
+    /// Reads many objects in `sui_multiGetObjects` requests, inputs over the configured
+    /// `multi_get_chunk_size` (50, the fullnode limit, by default) are split into chunks
+    /// issued concurrently. A failed chunk fails only its own items.
+    ///
+    /// ## Parameters
+    /// - object_ids : object ids
+    /// - options : object data to include
+    ///
+    /// ## Returns
+    /// per object in input order, the object or why it could not be read
+    ///
+    /// ## Example
+    /// ```rust
+    /// use std::sync::Arc;
+    /// use sui_network_sdk::SuiClient;
+    /// use sui_network_sdk::transport::MockTransport;
+    /// use sui_network_sdk::types::{ObjectDataOptions, SuiClientConfig};
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let object = |id: &str| serde_json::json!({ "data": {
+    ///         "objectId": id,
+    ///         "version": "1",
+    ///         "digest": "9WzSXdwbky8tNbH7juvyaui4QzMUYEjdCEKMrMgLhXHT"
+    ///     }});
+    ///     let mock = Arc::new(
+    ///         MockTransport::new()
+    ///             .with_result("sui_multiGetObjects", serde_json::json!([object("0x1"), object("0x2")]))
+    ///             .with_error("sui_multiGetObjects", -32000, "provider overloaded")
+    ///             .with_result("sui_multiGetObjects", serde_json::json!([{ "error": { "code": "notExists" } }])),
+    ///     );
+    ///     let config = SuiClientConfig { multi_get_chunk_size: 2, ..Default::default() };
+    ///     let client = SuiClient::new_with_transport(config, mock.clone());
+    ///
+    ///     let ids = ["0x1", "0x2", "0x3", "0x4", "0x5"];
+    ///     let options = ObjectDataOptions { show_owner: true, ..Default::default() };
+    ///     let objects = client.multi_get_objects(&ids, options).await;
+    ///     assert_eq!(mock.request_count("sui_multiGetObjects"), 3);
+    ///     let requests = mock.requests();
+    ///     assert_eq!(requests[0].params[0], serde_json::json!(["0x1", "0x2"]));
+    ///     assert_eq!(requests[0].params[1]["showOwner"], true);
+    ///     assert_eq!(objects.len(), 5);
+    ///     assert_eq!(objects[1].as_ref().unwrap().object_id, format!("0x{:0>64}", "2"));
+    ///     // the second chunk failed, the others still answer
+    ///     assert!(objects[2].is_err() && objects[3].is_err());
+    ///     assert!(objects[4].as_ref().unwrap_err().to_string().contains("not found"));
+    /// }
+    /// ```
+    pub async fn multi_get_objects(
+        &self,
+        object_ids: &[&str],
+        options: ObjectDataOptions,
+    ) -> Vec<Result<Object, SuiError>> {
+        let options = serde_json::json!(options);
+        self.multi_get::<ObjectResponse>("sui_multiGetObjects", object_ids, &options)
+            .await
+            .into_iter()
+            .zip(object_ids)
+            .map(|(response, id)| {
+                let response = response?;
+                response.data.ok_or_else(|| {
+                    SuiError::Rpc(format!(
+                        "Object {} not found: {}",
+                        id,
+                        response.error.unwrap_or_default()
+                    ))
+                })
+            })
+            .collect()
+    }
+
+    /// # Multi get transaction blocks
+    ///
+    /// Reads many transactions, inputs over the configured `multi_get_chunk_size` are split
+    /// into chunks issued concurrently. A failed chunk fails only its own items, each with
+    /// the chunk's error. The node rejects a whole chunk for one unknown digest, the digests
+    /// of a chunk the node rejected with a JSON-RPC error are read one by one so only the
+    /// unknown ones fail. Chunks that failed in transport, e.g. timed out, are not re-read.
+    ///
+    /// ## Parameters
+    /// - digests : transaction digests
+    /// - options : response options
+    ///
+    /// ## Returns
+    /// per digest in input order, the transaction or why it could not be read
+    ///
+    /// ## Example
+    /// ```rust
+    /// use std::sync::Arc;
+    /// use futures::future::BoxFuture;
+    /// use sui_network_sdk::SuiClient;
+    /// use sui_network_sdk::transport::{MockTransport, Transport};
+    /// use sui_network_sdk::types::{
+    ///     RetryPolicy, SuiClientConfig, SuiError, TransactionBlockResponseOptions,
+    /// };
+    ///
+    /// // a node that never answers in time
+    /// struct Unresponsive;
+    ///
+    /// impl Transport for Unresponsive {
+    ///     fn send(&self, _: serde_json::Value) -> BoxFuture<'_, Result<serde_json::Value, SuiError>> {
+    ///         Box::pin(async { Err(SuiError::Timeout("no response within 100 ms".into())) })
+    ///     }
+    /// }
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let tx = |digest: &str| serde_json::json!({ "digest": digest, "checkpoint": "9" });
+    ///     let unknown = "Could not find the referenced transaction [TransactionDigest(C3)]";
+    ///     let mock = Arc::new(
+    ///         MockTransport::new()
+    ///             .with_result("sui_multiGetTransactionBlocks", serde_json::json!([tx("A1"), tx("B2")]))
+    ///             .with_error("sui_multiGetTransactionBlocks", -32602, unknown)
+    ///             .with_result("sui_getTransactionBlock", tx("D4"))
+    ///             .with_error("sui_getTransactionBlock", -32602, unknown),
+    ///     );
+    ///     let config = SuiClientConfig { multi_get_chunk_size: 2, ..Default::default() };
+    ///     let client = SuiClient::new_with_transport(config, mock.clone());
+    ///     let options = TransactionBlockResponseOptions::default().with_effects();
+    ///     let transactions = client
+    ///         .multi_get_transaction_blocks(&["A1", "B2", "D4", "C3"], options)
+    ///         .await;
+    ///     assert_eq!(mock.requests()[0].params[1], serde_json::json!({ "showEffects": true }));
+    ///     assert_eq!(transactions[1].as_ref().unwrap().digest, "B2");
+    ///     // the chunk with the unknown digest was read one by one
+    ///     assert_eq!(transactions[2].as_ref().unwrap().digest, "D4");
+    ///     assert!(transactions[3].as_ref().unwrap_err().is_not_found());
+    ///     assert_eq!(mock.request_count("sui_getTransactionBlock"), 2);
+    ///
+    ///     // each item of a timed out chunk fails with the timeout
+    ///     let config = SuiClientConfig { retry_policy: RetryPolicy::none(), ..Default::default() };
+    ///     let client = SuiClient::new_with_transport(config, Arc::new(Unresponsive));
+    ///     let options = TransactionBlockResponseOptions::default();
+    ///     let transactions = client.multi_get_transaction_blocks(&["A1", "B2"], options).await;
+    ///     assert!(transactions.iter().all(|tx| matches!(tx, Err(SuiError::Timeout(_)))));
+    /// }
+    /// ```
+    pub async fn multi_get_transaction_blocks(
+        &self,
+        digests: &[&str],
+        options: TransactionBlockResponseOptions,
+    ) -> Vec<Result<TransactionResponse, SuiError>> {
+        let options = serde_json::json!(options);
+        let mut transactions: Vec<Result<TransactionResponse, SuiError>> = self
+            .multi_get("sui_multiGetTransactionBlocks", digests, &options)
+            .await;
+        let unknown: Vec<usize> = transactions
+            .iter()
+            .enumerate()
+            .filter(|(_, tx)| matches!(tx, Err(SuiError::Rpc(_))))
+            .map(|(i, _)| i)
+            .collect();
+        let reads: Vec<_> = futures::stream::iter(&unknown)
+            .map(|&i| self.get_transaction_block(digests[i], options.clone()))
+            .buffered(BULK_CONCURRENCY)
+            .collect()
+            .await;
+        for (i, read) in unknown.into_iter().zip(reads) {
+            transactions[i] = read;
+        }
+        transactions
+    }
+
+    /// issue a multi get method in chunks of the configured size, results in input order
+    async fn multi_get<T: serde::de::DeserializeOwned>(
+        &self,
+        method: &str,
+        keys: &[impl serde::Serialize + Sync],
+        options: &Value,
+    ) -> Vec<Result<T, SuiError>> {
+        let chunk_size = self.config.multi_get_chunk_size.max(1);
+        let chunks: Vec<_> = futures::stream::iter(keys.chunks(chunk_size))
+            .map(|chunk| async move {
+                let result = self
+                    .request::<Vec<T>>(method, vec![serde_json::json!(chunk), options.clone()])
+                    .await;
+                (chunk.len(), result)
+            })
+            .buffered(BULK_CONCURRENCY)
+            .collect()
+            .await;
+        let mut items = Vec::with_capacity(keys.len());
+        for (len, result) in chunks {
+            match result {
+                Ok(values) if values.len() == len => items.extend(values.into_iter().map(Ok)),
+                Ok(values) => {
+                    let message = format!("{} results for {} items", values.len(), len);
+                    items.extend(
+                        (0..len).map(|_| Err(SuiError::Rpc(format!("Chunk failed: {}", message)))),
+                    );
+                }
+                // every item fails with the chunk's own error, e.g. a timeout stays a timeout
+                Err(e) => items.extend((0..len).map(|_| Err(e.clone()))),
+            }
+        }
+        items
+    }
+
+    /// # Get coins page
+    ///
+    /// ## Parameters
+    /// - address : owner address
+    /// - coin_type : coin type, defaults to "0x2::sui::SUI"
+    /// - cursor : cursor returned by the previous page
+    /// - limit : maximum number of coins in the page
+    ///
+    /// ## Returns
+    /// - Ok(Page<Coin>) : coin page
+    /// - Err(SuiError) : error
+    ///
+    /// ## Example
+    /// ```no_run
+    /// use sui_network_sdk::SuiClient;
+    /// use sui_network_sdk::global::mainnet;
+    /// #[tokio::main]
+    /// async fn main() {
+    ///    let client = SuiClient::new_by_rpc_url(mainnet::RPC_URL.to_string());
+    ///    let page = client.get_coins_page("0x123...", None, None, Some(50)).await.unwrap();
+    ///    println!("{} coins, more: {}", page.data.len(), page.has_next_page);
+    /// }
+    /// ```
+    pub async fn get_coins_page(
+        &self,
+        address: &str,
+        coin_type: Option<&str>,
+        cursor: Option<String>,
+        limit: Option<usize>,
+    ) -> Result<Page<Coin>, SuiError> {
+        let coin_type = coin_type.unwrap_or("0x2::sui::SUI");
+        self.request(
+            "suix_getCoins",
+            vec![
+                address.into(),
+                coin_type.into(),
+                cursor.into(),
+                limit.into(),
+            ],
+        )
+        .await
+    }
+
+    /// # Find gas coin
+    ///
+    /// Pages through the address's SUI coins and stops at the first coin holding at least
+    /// `min_balance` that is not excluded.
+    ///
+    /// ## Parameters
+    /// - address : owner address
+    /// - min_balance : minimum coin balance in MIST
+    /// - exclude : coin object ids that must not be used
+    ///
+    /// ## Returns
+    /// - Ok(Coin) : gas coin
+    /// - Err(SuiError::Gas) : no coin qualifies, the message carries the total available balance
+    /// - Err(SuiError) : error
+    ///
+    /// ## Example
+    /// ```no_run
+    /// use sui_network_sdk::SuiClient;
+    /// use sui_network_sdk::global::mainnet;
+    /// #[tokio::main]
+    /// async fn main() {
+    ///    let client = SuiClient::new_by_rpc_url(mainnet::RPC_URL.to_string());
+    ///    let coin = client.find_gas_coin("0x123...", 10_000_000, &[]).await.unwrap();
+    ///    println!("gas coin: {}", coin.coin_object_id);
+    /// }
+    /// ```
+    pub async fn find_gas_coin(
+        &self,
+        address: &str,
+        min_balance: u64,
+        exclude: &[&str],
+    ) -> Result<Coin, SuiError> {
+        let mut cursor = None;
+        let mut available: u128 = 0;
+        loop {
+            let page = self.get_coins_page(address, None, cursor, None).await?;
+            for coin in page.data {
+                if exclude.contains(&coin.coin_object_id.as_str()) {
+                    continue;
+                }
+                if coin.balance >= min_balance {
+                    return Ok(coin);
+                }
+                available += coin.balance as u128;
+            }
+            // an empty page may still be followed by more pages
+            match page.next_cursor {
+                Some(next) if page.has_next_page => cursor = Some(next),
+                _ => break,
+            }
+        }
+        Err(SuiError::Gas(format!(
+            "No gas coin of {} holds at least {} MIST, total available balance {} MIST",
+            address, min_balance, available
+        )))
+    }
+
+    /// # Get object BCS only
+    ///
+    /// Reads only the object's type and BCS contents, skipping the JSON content
+    /// entirely, for hot paths decoding objects locally.
+    ///
+    /// ## Parameters
+    /// - object_id : object id
+    ///
+    /// ## Returns
+    /// - Ok(BcsObject) : object type tag and raw BCS bytes
+    /// - Err(SuiError) : error
+    ///
+    /// ## Example
+    /// ```no_run
+    /// use sui_network_sdk::SuiClient;
+    /// use sui_network_sdk::global::mainnet;
+    /// #[tokio::main]
+    /// async fn main() {
+    ///    let client = SuiClient::new_by_rpc_url(mainnet::RPC_URL.to_string());
+    ///    let object = client.get_object_bcs_only("0x5").await.unwrap();
+    ///    println!("{}: {} bytes", object.type_tag, object.bcs_bytes.len());
+    /// }
+    /// ```
+    pub async fn get_object_bcs_only(&self, object_id: &str) -> Result<BcsObject, SuiError> {
+        self.multi_get_objects_bcs(&[object_id])
+            .await?
+            .pop()
+            .ok_or_else(|| SuiError::Rpc(format!("Object {} not found", object_id)))
+    }
+
+    /// # Multi get objects BCS
+    ///
+    /// Reads only the type and BCS contents of many objects in one request.
+    ///
+    /// ## Parameters
+    /// - object_ids : object ids
+    ///
+    /// ## Returns
+    /// - Ok(Vec<BcsObject>) : objects in input order
+    /// - Err(SuiError) : error, including any object that is missing or not a move object
+    pub async fn multi_get_objects_bcs(
+        &self,
+        object_ids: &[&str],
+    ) -> Result<Vec<BcsObject>, SuiError> {
+        let options = ObjectDataOptions {
+            show_type: true,
+            show_bcs: true,
+            ..Default::default()
+        };
+        self.fetch_objects_with(object_ids, options)
+            .await?
+            .into_iter()
+            .map(|object| {
+                let raw = object.bcs.ok_or_else(|| {
+                    SuiError::Rpc(format!("No bcs data for object {}", object.object_id))
+                })?;
+                let bcs_bytes = raw.bcs_bytes.ok_or_else(|| {
+                    SuiError::Rpc(format!("Object {} is not a move object", object.object_id))
+                })?;
+                Ok(BcsObject {
+                    type_tag: raw.object_type.or(object.object_type).unwrap_or_default(),
+                    bcs_bytes: BASE64_STANDARD.decode(bcs_bytes)?,
+                    object_id: object.object_id,
+                    version: object.version,
+                    digest: object.digest,
+                })
+            })
+            .collect()
+    }
+
+    /// # Resolve name service address
+    ///
+    /// Looks up the address a SuiNS name, e.g. `example.sui`, points at.
+    ///
+    /// ## Parameters
+    /// - name : SuiNS name
+    ///
+    /// ## Returns
+    /// - Ok(Some(String)) : address of the name
+    /// - Ok(None) : the name is not registered or points at no address
+    /// - Err(SuiError) : error
+    ///
+    /// ## Example
+    /// ```rust
+    /// use std::sync::Arc;
+    /// use sui_network_sdk::SuiClient;
+    /// use sui_network_sdk::transport::MockTransport;
+    /// use sui_network_sdk::types::SuiClientConfig;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let mock = MockTransport::new()
+    ///         .with_result("suix_resolveNameServiceAddress", serde_json::json!("0xa11ce"))
+    ///         .with_result("suix_resolveNameServiceAddress", serde_json::json!(null))
+    ///         .with_error("suix_resolveNameServiceAddress", -32000, "Name not found: ghost.sui");
+    ///     let client = SuiClient::new_with_transport(SuiClientConfig::default(), Arc::new(mock));
+    ///     let address = client.resolve_name_service_address("example.sui").await.unwrap();
+    ///     assert_eq!(address, Some(format!("0x{:0>64}", "a11ce")));
+    ///     assert_eq!(client.resolve_name_service_address("unset.sui").await.unwrap(), None);
+    ///     assert_eq!(client.resolve_name_service_address("ghost.sui").await.unwrap(), None);
+    /// }
+    /// ```
+    pub async fn resolve_name_service_address(
+        &self,
+        name: &str,
+    ) -> Result<Option<String>, SuiError> {
+        let address: Option<SuiAddress> = match self
+            .request("suix_resolveNameServiceAddress", vec![name.into()])
+            .await
+        {
+            Err(e) if e.is_not_found() => None,
+            result => result?,
+        };
+        Ok(address.map(|address| address.to_string()))
+    }
+
+    /// # Resolve name service names
+    ///
+    /// Reverse lookup of the SuiNS names pointing at an address, an address can have several.
+    ///
+    /// ## Parameters
+    /// - address : address
+    /// - cursor : cursor of the page, `None` for the first page
+    /// - limit : names per page, the node's default when `None`
+    ///
+    /// ## Returns
+    /// - Ok(Page<String>) : page of names
+    /// - Err(SuiError) : error
+    ///
+    /// ## Example
+    /// ```no_run
+    /// use sui_network_sdk::SuiClient;
+    /// use sui_network_sdk::global::mainnet;
+    /// #[tokio::main]
+    /// async fn main() {
+    ///    let client = SuiClient::new_by_rpc_url(mainnet::RPC_URL.to_string());
+    ///    let names = client.resolve_name_service_names("address", None, Some(10)).await.unwrap();
+    ///    for name in names.data {
+    ///        println!("{}", name);
+    ///    }
+    /// }
+    /// ```
+    pub async fn resolve_name_service_names(
+        &self,
+        address: &str,
+        cursor: Option<String>,
+        limit: Option<usize>,
+    ) -> Result<Page<String>, SuiError> {
+        self.request(
+            "suix_resolveNameServiceNames",
+            vec![address.into(), cursor.into(), limit.into()],
+        )
+        .await
+    }
+
+    /// # Get package
+    ///
+    /// Reads a published Move package with its module bytecode and linkage table.
+    ///
+    /// ## Parameters
+    /// - package_id : package id
+    ///
+    /// ## Returns
+    /// - Ok(MovePackage) : package
+    /// - Err(SuiError) : error, including an object that is not a package
+    ///
+    /// ## Example
+    /// ```no_run
+    /// use sui_network_sdk::SuiClient;
+    /// use sui_network_sdk::global::mainnet;
+    /// #[tokio::main]
+    /// async fn main() {
+    ///    let client = SuiClient::new_by_rpc_url(mainnet::RPC_URL.to_string());
+    ///    let package = client.get_package("0x2").await.unwrap();
+    ///    for name in package.module_names() {
+    ///        println!("{}: {} bytes", name, package.module_bytecode(name).unwrap().len());
+    ///    }
+    /// }
+    /// ```
+    pub async fn get_package(&self, package_id: &str) -> Result<MovePackage, SuiError> {
+        let options = ObjectDataOptions {
+            show_type: true,
+            show_bcs: true,
+            ..Default::default()
+        };
+        let object = self
+            .fetch_objects_with(&[package_id], options)
+            .await?
+            .pop()
+            .ok_or_else(|| SuiError::Rpc(format!("Package {} not found", package_id)))?;
+        let raw = object
+            .bcs
+            .ok_or_else(|| SuiError::Rpc(format!("No bcs data for object {}", object.object_id)))?;
+        match raw.module_map {
+            Some(module_map) if raw.data_type == "package" => Ok(MovePackage {
+                package_id: object.object_id,
+                version: object.version,
+                module_map,
+                linkage_table: raw.linkage_table.unwrap_or_default(),
+            }),
+            _ => Err(SuiError::Rpc(format!(
+                "Object {} is not a package",
+                object.object_id
+            ))),
+        }
+    }
+
+    /// # Verify package bytecode
+    ///
+    /// Compares a package's on-chain module bytecode with locally compiled modules,
+    /// module by module.
+    ///
+    /// ## Parameters
+    /// - package_id : package id
+    /// - local_modules : locally compiled bytecode by module name
+    ///
+    /// ## Returns
+    /// - Ok(PackageVerification) : match or mismatch per module, on either side
+    /// - Err(SuiError) : error
+    ///
+    /// ## Example
+    /// ```rust
+    /// use std::collections::BTreeMap;
+    /// use std::sync::Arc;
+    /// use sui_network_sdk::SuiClient;
+    /// use sui_network_sdk::transport::MockTransport;
+    /// use sui_network_sdk::types::{ModuleVerification, SuiClientConfig};
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let mock = MockTransport::new().with_result(
+    ///         "sui_multiGetObjects",
+    ///         serde_json::json!([{ "data": {
+    ///             "objectId": "0xabc",
+    ///             "version": "3",
+    ///             "digest": "9WzSXdwbky8tNbH7juvyaui4QzMUYEjdCEKMrMgLhXHT",
+    ///             "type": "package",
+    ///             "bcs": {
+    ///                 "dataType": "package",
+    ///                 "moduleMap": { "nft": "oRzrCwY=", "market": "oRzrCwc=" },
+    ///                 "linkageTable": {}
+    ///             }
+    ///         }}]),
+    ///     );
+    ///     let client = SuiClient::new_with_transport(SuiClientConfig::default(), Arc::new(mock));
+    ///     let local = BTreeMap::from([
+    ///         ("nft".to_string(), vec![0xa1, 0x1c, 0xeb, 0x0b, 0x06]),
+    ///         ("market".to_string(), vec![0xa1, 0x1c, 0xeb, 0x0b, 0x06]),
+    ///     ]);
+    ///     let report = client.verify_package_bytecode("0xabc", &local).await.unwrap();
+    ///     assert_eq!(report.modules["nft"], ModuleVerification::Match);
+    ///     assert_eq!(report.modules["market"], ModuleVerification::Mismatch);
+    ///     assert!(!report.is_verified());
+    /// }
+    /// ```
+    pub async fn verify_package_bytecode(
+        &self,
+        package_id: &str,
+        local_modules: &std::collections::BTreeMap<String, Vec<u8>>,
+    ) -> Result<PackageVerification, SuiError> {
+        let package = self.get_package(package_id).await?;
+        let mut modules = std::collections::BTreeMap::new();
+        for name in package.module_names() {
+            let status = match local_modules.get(name) {
+                Some(local) if *local == package.module_bytecode(name)? => {
+                    ModuleVerification::Match
+                }
+                Some(_) => ModuleVerification::Mismatch,
+                None => ModuleVerification::MissingLocally,
+            };
+            modules.insert(name.to_string(), status);
+        }
+        for name in local_modules.keys() {
+            modules
+                .entry(name.clone())
+                .or_insert(ModuleVerification::MissingOnChain);
+        }
+        Ok(PackageVerification {
+            package_id: package.package_id,
+            version: package.version,
+            modules,
+        })
+    }
+
+    /// # Get normalized Move module
+    ///
+    /// Reads the structs and exposed functions of a module, e.g. to list the entry
+    /// functions of a package before calling them.
+    ///
+    /// ## Parameters
+    /// - package_id : package id
+    /// - module : module name
+    ///
+    /// ## Returns
+    /// - Ok(NormalizedMoveModule) : module
+    /// - Err(SuiError) : error, including an unknown package or module
+    ///
+    /// ## Example
+    /// ```rust
+    /// use std::sync::Arc;
+    /// use sui_network_sdk::SuiClient;
+    /// use sui_network_sdk::transport::MockTransport;
+    /// use sui_network_sdk::types::{MoveAbility, NormalizedMoveType, SuiClientConfig};
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let uid = serde_json::json!({ "Struct": { "address": "0x2", "module": "object", "name": "UID", "typeArguments": [] } });
+    ///     let mock = MockTransport::new().with_result(
+    ///         "sui_getNormalizedMoveModule",
+    ///         serde_json::json!({
+    ///             "fileFormatVersion": 6,
+    ///             "address": "0xabc",
+    ///             "name": "market",
+    ///             "friends": [{ "address": "0xabc", "name": "admin" }],
+    ///             "structs": {
+    ///                 "Listing": {
+    ///                     "abilities": { "abilities": ["Store", "Key"] },
+    ///                     "typeParameters": [{ "constraints": { "abilities": ["Store"] }, "isPhantom": true }],
+    ///                     "fields": [{ "name": "id", "type": uid }, { "name": "price", "type": "U64" }]
+    ///                 }
+    ///             },
+    ///             "exposedFunctions": {
+    ///                 "buy": { "visibility": "Public", "isEntry": true, "typeParameters": [], "parameters": [], "return": [] },
+    ///                 "price": { "visibility": "Public", "isEntry": false, "typeParameters": [], "parameters": [], "return": ["U64"] }
+    ///             }
+    ///         }),
+    ///     );
+    ///     let client = SuiClient::new_with_transport(SuiClientConfig::default(), Arc::new(mock));
+    ///     let module = client.get_normalized_move_module("0xabc", "market").await.unwrap();
+    ///     assert_eq!(module.entry_functions(), ["buy"]);
+    ///     assert_eq!(module.exposed_functions["price"].return_types, [NormalizedMoveType::U64]);
+    ///     let listing = &module.structs["Listing"];
+    ///     assert!(listing.abilities.has(MoveAbility::Key));
+    ///     assert!(listing.type_parameters[0].is_phantom);
+    ///     assert_eq!(listing.fields[0].field_type.to_string(), format!("0x{:0>64}::object::UID", "2"));
+    /// }
+    /// ```
+    pub async fn get_normalized_move_module(
+        &self,
+        package_id: &str,
+        module: &str,
+    ) -> Result<NormalizedMoveModule, SuiError> {
+        self.request(
+            "sui_getNormalizedMoveModule",
+            vec![package_id.into(), module.into()],
+        )
+        .await
+    }
+
+    /// # Get normalized Move function
+    ///
+    /// Reads the signature of an exposed function, its visibility, type parameters,
+    /// parameter and return types.
+    ///
+    /// ## Parameters
+    /// - package_id : package id
+    /// - module : module name
+    /// - function : function name
+    ///
+    /// ## Returns
+    /// - Ok(NormalizedMoveFunction) : function signature
+    /// - Err(SuiError) : error, including an unknown or private function
+    ///
+    /// ## Example
+    /// ```rust
+    /// use std::sync::Arc;
+    /// use sui_network_sdk::SuiClient;
+    /// use sui_network_sdk::transport::MockTransport;
+    /// use sui_network_sdk::types::{MoveVisibility, NormalizedMoveType, SuiClientConfig};
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     // public fun split<T>(self: &mut Coin<T>, split_amount: u64, ctx: &mut TxContext): Coin<T>
+    ///     let coin = serde_json::json!({ "Struct": {
+    ///         "address": "0x2", "module": "coin", "name": "Coin", "typeArguments": [{ "TypeParameter": 0 }]
+    ///     }});
+    ///     let context = serde_json::json!({ "MutableReference": { "Struct": {
+    ///         "address": "0x2", "module": "tx_context", "name": "TxContext", "typeArguments": []
+    ///     }}});
+    ///     let mock = MockTransport::new().with_result(
+    ///         "sui_getNormalizedMoveFunction",
+    ///         serde_json::json!({
+    ///             "visibility": "Public",
+    ///             "isEntry": false,
+    ///             "typeParameters": [{ "abilities": [] }],
+    ///             "parameters": [{ "MutableReference": coin }, "U64", context],
+    ///             "return": [coin]
+    ///         }),
+    ///     );
+    ///     let client = SuiClient::new_with_transport(SuiClientConfig::default(), Arc::new(mock));
+    ///     let split = client.get_normalized_move_function("0x2", "coin", "split").await.unwrap();
+    ///     assert_eq!(split.visibility, MoveVisibility::Public);
+    ///     assert!(!split.is_entry);
+    ///     let parameters: Vec<String> = split.call_parameters().iter().map(ToString::to_string).collect();
+    ///     assert_eq!(parameters, [format!("&mut 0x{:0>64}::coin::Coin<T0>", "2"), "u64".to_string()]);
+    ///     assert!(matches!(&split.return_types[0], NormalizedMoveType::Struct { name, .. } if name == "Coin"));
+    /// }
+    /// ```
+    pub async fn get_normalized_move_function(
+        &self,
+        package_id: &str,
+        module: &str,
+        function: &str,
+    ) -> Result<NormalizedMoveFunction, SuiError> {
+        self.request(
+            "sui_getNormalizedMoveFunction",
+            vec![package_id.into(), module.into(), function.into()],
+        )
+        .await
+    }
+
+    /// # Get Move function argument types
+    ///
+    /// Reads whether each parameter of a function takes a pure value or an object, and
+    /// how it takes the object, the `TxContext` excluded.
+    ///
+    /// ## Parameters
+    /// - package_id : package id
+    /// - module : module name
+    /// - function : function name
+    ///
+    /// ## Returns
+    /// - Ok(Vec<MoveFunctionArgType>) : argument kinds in parameter order
+    /// - Err(SuiError) : error
+    ///
+    /// ## Example
+    /// ```rust
+    /// use std::sync::Arc;
+    /// use sui_network_sdk::SuiClient;
+    /// use sui_network_sdk::transport::MockTransport;
+    /// use sui_network_sdk::types::{MoveFunctionArgType, ObjectValueKind, SuiClientConfig};
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let mock = MockTransport::new().with_result(
+    ///         "sui_getMoveFunctionArgTypes",
+    ///         serde_json::json!([{ "Object": "ByMutableReference" }, "Pure"]),
+    ///     );
+    ///     let client = SuiClient::new_with_transport(SuiClientConfig::default(), Arc::new(mock));
+    ///     let arguments = client.get_move_function_arg_types("0x2", "coin", "split").await.unwrap();
+    ///     assert_eq!(arguments, [
+    ///         MoveFunctionArgType::Object(ObjectValueKind::ByMutableReference),
+    ///         MoveFunctionArgType::Pure,
+    ///     ]);
+    /// }
+    /// ```
+    pub async fn get_move_function_arg_types(
+        &self,
+        package_id: &str,
+        module: &str,
+        function: &str,
+    ) -> Result<Vec<MoveFunctionArgType>, SuiError> {
+        self.request(
+            "sui_getMoveFunctionArgTypes",
+            vec![package_id.into(), module.into(), function.into()],
+        )
+        .await
+    }
+
+    /// # Get events by transaction
+    ///
+    /// All events a transaction emitted, also when its response was fetched without them.
+    ///
+    /// ## Parameters
+    /// - digest : transaction digest
+    ///
+    /// ## Returns
+    /// - Ok(Vec<Event>) : events emitted by the transaction, empty when it emitted none
+    /// - Err(SuiError::Rpc) : the node's message, e.g. for an unknown digest
+    /// - Err(SuiError) : error
+    ///
+    /// ## Example
+    /// ```rust
+    /// use std::sync::Arc;
+    /// use sui_network_sdk::SuiClient;
+    /// use sui_network_sdk::transport::MockTransport;
+    /// use sui_network_sdk::types::{SuiClientConfig, SuiError};
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let event = serde_json::json!({
+    ///         "id": { "txDigest": "D1", "eventSeq": "0" },
+    ///         "packageId": "0x3",
+    ///         "transactionModule": "sui_system",
+    ///         "sender": "0xa11ce",
+    ///         "type": "0x3::validator::StakingRequestEvent",
+    ///         "parsedJson": { "amount": "1000000000" },
+    ///         "bcsEncoding": "base64",
+    ///         "bcs": "AQID",
+    ///         "timestampMs": "1700000000000"
+    ///     });
+    ///     let mock = MockTransport::new()
+    ///         .with_result("sui_getEvents", serde_json::json!([event]))
+    ///         .with_result("sui_getEvents", serde_json::json!([]))
+    ///         .with_error("sui_getEvents", -32602, "Could not find the referenced transaction");
+    ///     let client = SuiClient::new_with_transport(SuiClientConfig::default(), Arc::new(mock));
+    ///
+    ///     let events = client.get_events_by_transaction("D1").await.unwrap();
+    ///     assert_eq!(events[0].sender, format!("0x{:0>64}", "a11ce"));
+    ///     assert_eq!(events[0].transaction_module, "sui_system");
+    ///     assert_eq!(events[0].bcs.as_deref(), Some("AQID"));
+    ///     assert!(client.get_events_by_transaction("D2").await.unwrap().is_empty());
+    ///     match client.get_events_by_transaction("D3").await {
+    ///         Err(SuiError::Rpc(message)) => assert!(message.contains("Could not find")),
+    ///         other => panic!("unexpected {:?}", other),
+    ///     }
+    /// }
+    /// ```
+    pub async fn get_events_by_transaction(&self, digest: &str) -> Result<Vec<Event>, SuiError> {
+        self.request("sui_getEvents", vec![digest.into()]).await
+    }
+
+    /// # Get loaded child objects
+    ///
+    /// ## Parameters
+    /// - digest : transaction digest
+    ///
+    /// ## Returns
+    /// - Ok(Vec<LoadedChildObject>) : child objects the transaction loaded, with the versions read
+    /// - Err(SuiError) : error
+    pub async fn get_loaded_child_objects(
+        &self,
+        digest: &str,
+    ) -> Result<Vec<LoadedChildObject>, SuiError> {
+        let result: Value = self
+            .request("sui_getLoadedChildObjects", vec![digest.into()])
+            .await?;
+        let children = result
+            .get("loadedChildObjects")
+            .cloned()
+            .ok_or_else(|| SuiError::Rpc("No loadedChildObjects in response".to_string()))?;
+        Ok(serde_json::from_value(children)?)
+    }
+
+    /// # Query transaction blocks
+    ///
+    /// One page of the transactions matching a filter, e.g. the history of an address by
+    /// querying `FromAddress` and `ToAddress`.
+    ///
+    /// ## Parameters
+    /// - filter : transaction filter
+    /// - options : transaction data to include, the digest only by default
+    /// - cursor : cursor returned by the previous page
+    /// - limit : maximum number of transactions in the page, at most 50
+    /// - descending : newest first when true, to walk history backwards
+    ///
+    /// ## Returns
+    /// - Ok(Page<TransactionResponse>) : page of transactions
+    /// - Err(SuiError) : error
+    ///
+    /// ## Example
+    /// ```rust
+    /// use std::sync::Arc;
+    /// use sui_network_sdk::SuiClient;
+    /// use sui_network_sdk::transport::MockTransport;
+    /// use sui_network_sdk::types::{SuiClientConfig, TransactionBlockResponseOptions, TransactionFilter};
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let transport = Arc::new(MockTransport::new().with_result("suix_queryTransactionBlocks", serde_json::json!({
+    ///         "data": [{ "digest": "D2", "checkpoint": "12" }, { "digest": "D1", "checkpoint": "9" }],
+    ///         "nextCursor": "D1",
+    ///         "hasNextPage": true
+    ///     })));
+    ///     let client = SuiClient::new_with_transport(SuiClientConfig::default(), transport.clone());
+    ///
+    ///     let filter = TransactionFilter::ToAddress("0xa11ce".to_string());
+    ///     let options = TransactionBlockResponseOptions { show_balance_changes: true, ..Default::default() };
+    ///     let page = client
+    ///         .query_transaction_blocks(filter, options, None, Some(2), true)
+    ///         .await
+    ///         .unwrap();
+    ///     assert_eq!(page.data[0].digest, "D2");
+    ///     assert_eq!(page.next_cursor.as_deref(), Some("D1"));
+    ///     assert!(page.has_next_page);
+    ///
+    ///     let params = &transport.requests()[0].params;
+    ///     assert_eq!(params[0]["filter"], serde_json::json!({ "ToAddress": "0xa11ce" }));
+    ///     assert_eq!(params[0]["options"]["showBalanceChanges"], true);
+    ///     assert_eq!(params[1..], [serde_json::Value::Null, 2.into(), true.into()]);
+    ///
+    ///     let filter = TransactionFilter::MoveFunction {
+    ///         package: "0x2".to_string(),
+    ///         module: Some("coin".to_string()),
+    ///         function: None,
+    ///     };
+    ///     assert_eq!(
+    ///         serde_json::to_value(filter).unwrap(),
+    ///         serde_json::json!({ "MoveFunction": { "package": "0x2", "module": "coin", "function": null } })
+    ///     );
+    /// }
+    /// ```
+    pub async fn query_transaction_blocks(
+        &self,
+        filter: TransactionFilter,
+        options: TransactionBlockResponseOptions,
+        cursor: Option<String>,
+        limit: Option<usize>,
+        descending: bool,
+    ) -> Result<Page<TransactionResponse>, SuiError> {
+        let query = serde_json::json!({ "filter": filter, "options": options });
+        self.request(
+            "suix_queryTransactionBlocks",
+            vec![query, cursor.into(), limit.into(), descending.into()],
+        )
+        .await
+    }
+
+    /// # Query events
+    ///
+    /// One page of the events matching a filter. The cursor is the id of the last event of
+    /// the previous page, it can be persisted to resume indexing later.
+    ///
+    /// ## Parameters
+    /// - filter : event filter
+    /// - cursor : cursor returned by the previous page
+    /// - limit : maximum number of events in the page, at most 50
+    /// - descending : newest first when true
+    ///
+    /// ## Returns
+    /// - Ok(Page<Event, EventId>) : page of events
+    /// - Err(SuiError) : error
+    ///
+    /// ## Example
+    /// ```rust
+    /// use std::sync::Arc;
+    /// use sui_network_sdk::SuiClient;
+    /// use sui_network_sdk::transport::MockTransport;
+    /// use sui_network_sdk::types::{EventFilter, EventId, SuiClientConfig};
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let transport = Arc::new(MockTransport::new().with_result("suix_queryEvents", serde_json::json!({
+    ///         "data": [{
+    ///             "id": { "txDigest": "D1", "eventSeq": "0" },
+    ///             "type": "0x2::coin::Mint",
+    ///             "parsedJson": {}
+    ///         }],
+    ///         "nextCursor": { "txDigest": "D1", "eventSeq": "0" },
+    ///         "hasNextPage": true
+    ///     })));
+    ///     let client = SuiClient::new_with_transport(SuiClientConfig::default(), transport.clone());
+    ///
+    ///     let filter = EventFilter::TimeRange { start_time: 1_700_000_000_000, end_time: 1_700_000_060_000 };
+    ///     let cursor = EventId { tx_digest: "D0".to_string(), event_seq: 3 };
+    ///     let page = client.query_events(filter, Some(cursor), Some(50), false).await.unwrap();
+    ///     assert_eq!(page.data[0].event_type, "0x2::coin::Mint");
+    ///     assert_eq!(page.next_cursor, Some(EventId { tx_digest: "D1".to_string(), event_seq: 0 }));
+    ///
+    ///     assert_eq!(
+    ///         serde_json::Value::from(transport.requests()[0].params.clone()),
+    ///         serde_json::json!([
+    ///             { "TimeRange": { "startTime": "1700000000000", "endTime": "1700000060000" } },
+    ///             { "txDigest": "D0", "eventSeq": "3" },
+    ///             50,
+    ///             false
+    ///         ])
+    ///     );
+    ///     let filter = EventFilter::MoveModule { package: "0x3".to_string(), module: "validator".to_string() };
+    ///     assert_eq!(
+    ///         serde_json::to_value(filter).unwrap(),
+    ///         serde_json::json!({ "MoveModule": { "package": "0x3", "module": "validator" } })
+    ///     );
+    /// }
+    /// ```
+    pub async fn query_events(
+        &self,
+        filter: EventFilter,
+        cursor: Option<EventId>,
+        limit: Option<usize>,
+        descending: bool,
+    ) -> Result<Page<Event, EventId>, SuiError> {
+        self.request(
+            "suix_queryEvents",
+            vec![
+                serde_json::to_value(filter)?,
+                serde_json::to_value(cursor)?,
+                limit.into(),
+                descending.into(),
+            ],
+        )
+        .await
+    }
+
+    /// # Query transactions with prefetching
+    ///
+    /// Streams the transactions matching a filter, oldest first, in the same order as paging
+    /// [`SuiClient::query_transaction_blocks`] with the same options. Pages are listed with
+    /// digests only, which is fast, and each page is enriched with the requested options
+    /// through `sui_multiGetTransactionBlocks`. Up to `prefetch_pages` enrichments run at
+    /// once ahead of the consumer, the listing of the next page does not wait for them.
+    ///
+    /// The listing itself stays serial, the gain comes from digest-only pages being cheaper to
+    /// serve than full pages. With 20 pages of 50 transactions, digest pages answered in 20 ms
+    /// and pages with effects and events in 150 ms, the serial loop took 3.0 s and prefetching
+    /// 8 pages 0.58 s, see the `query_prefetch_bench` example.
+    ///
+    /// ## Parameters
+    /// - filter : transaction filter
+    /// - options : transaction data to include
+    /// - prefetch_pages : pages enriched concurrently, at least 1
+    ///
+    /// ## Returns
+    /// stream of transactions, ending after the first error
+    ///
+    /// ## Example
+    /// ```rust
+    /// use std::sync::Arc;
+    /// use futures::StreamExt;
+    /// use sui_network_sdk::SuiClient;
+    /// use sui_network_sdk::transport::MockTransport;
+    /// use sui_network_sdk::types::{SuiClientConfig, TransactionBlockResponseOptions, TransactionFilter};
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let page = |digests: &[&str], next: Option<&str>| serde_json::json!({
+    ///         "data": digests.iter().map(|d| serde_json::json!({ "digest": d })).collect::<Vec<_>>(),
+    ///         "nextCursor": next,
+    ///         "hasNextPage": next.is_some()
+    ///     });
+    ///     let enriched = |digests: &[&str]| serde_json::json!(digests
+    ///         .iter()
+    ///         .map(|d| serde_json::json!({ "digest": d, "checkpoint": "7" }))
+    ///         .collect::<Vec<_>>());
+    ///     let mock = Arc::new(
+    ///         MockTransport::new()
+    ///             .with_result("suix_queryTransactionBlocks", page(&["A", "B"], Some("B")))
+    ///             .with_result("suix_queryTransactionBlocks", page(&["C"], None))
+    ///             .with_result("sui_multiGetTransactionBlocks", enriched(&["A", "B"]))
+    ///             .with_result("sui_multiGetTransactionBlocks", enriched(&["C"])),
+    ///     );
+    ///     let client = SuiClient::new_with_transport(SuiClientConfig::default(), mock.clone());
+    ///
+    ///     let filter = TransactionFilter::FromAddress("0xa1".to_string());
+    ///     let options = TransactionBlockResponseOptions { show_effects: true, ..Default::default() };
+    ///     let transactions: Vec<_> = client
+    ///         .query_transactions_prefetch(filter, options, 4)
+    ///         .collect()
+    ///         .await;
+    ///     let transactions: Vec<_> = transactions.into_iter().map(Result::unwrap).collect();
+    ///     let digests: Vec<&str> = transactions.iter().map(|tx| tx.digest.as_str()).collect();
+    ///     assert_eq!(digests, ["A", "B", "C"]);
+    ///     assert_eq!(transactions[2].checkpoint, Some(7));
+    ///
+    ///     // the listing asks for digests only, the options go to the enrichment
+    ///     let requests = mock.requests();
+    ///     let listing = requests.iter().find(|r| r.method == "suix_queryTransactionBlocks").unwrap();
+    ///     assert_eq!(listing.params[0]["options"], serde_json::json!({}));
+    ///     let enrichment = requests.iter().find(|r| r.method == "sui_multiGetTransactionBlocks").unwrap();
+    ///     assert_eq!(enrichment.params[1]["showEffects"], true);
+    /// }
+    /// ```
+    pub fn query_transactions_prefetch(
+        &self,
+        filter: TransactionFilter,
+        options: TransactionBlockResponseOptions,
+        prefetch_pages: usize,
+    ) -> impl futures::Stream<Item = Result<TransactionResponse, SuiError>> + '_ {
+        let pages = futures::stream::try_unfold(Some(None), move |cursor| {
+            let filter = filter.clone();
+            async move {
+                let Some(cursor) = cursor else {
+                    return Ok::<_, SuiError>(None);
+                };
+                let page = self
+                    .query_transaction_blocks(
+                        filter,
+                        TransactionBlockResponseOptions::default(),
+                        cursor,
+                        Some(MAX_QUERY_PAGE_SIZE),
+                        false,
+                    )
+                    .await?;
+                let next = match page.next_cursor {
+                    Some(cursor) if page.has_next_page => Some(Some(cursor)),
+                    _ => None,
+                };
+                Ok(Some((page.data, next)))
+            }
+        });
+        let enriched = pages
+            .map(move |page| async move {
+                let digests: Vec<String> = page?.into_iter().map(|tx| tx.digest).collect();
+                let digests: Vec<&str> = digests.iter().map(String::as_str).collect();
+                Ok(self.multi_get_transaction_blocks(&digests, options).await)
+            })
+            .buffered(prefetch_pages.max(1))
+            .flat_map(|transactions| {
+                futures::stream::iter(match transactions {
+                    Ok(transactions) => transactions,
+                    Err(e) => vec![Err(e)],
+                })
+            });
+        until_error(enriched)
+    }
+
+    /// # Address summary
+    ///
+    /// Profile of an address: its first and last transaction, transaction counts in
+    /// both directions, owned object count and SUI balance. All requests are issued
+    /// concurrently, counts page through at most `max_count` items each.
+    ///
+    /// ## Parameters
+    /// - address : address
+    /// - max_count : upper bound of each count
+    ///
+    /// ## Returns
+    /// - Ok(AddressSummary) : summary, `truncated` when a count hit the bound
+    /// - Err(SuiError) : error
+    ///
+    /// ## Example
+    /// ```no_run
+    /// use sui_network_sdk::SuiClient;
+    /// use sui_network_sdk::global::mainnet;
+    /// #[tokio::main]
+    /// async fn main() {
+    ///    let client = SuiClient::new_by_rpc_url(mainnet::RPC_URL.to_string());
+    ///    let summary = client.address_summary("0x123...", 10_000).await.unwrap();
+    ///    println!("{} sent, first seen {:?}", summary.total_txs_from, summary.first_tx);
+    /// }
+    /// ```
+    pub async fn address_summary(
+        &self,
+        address: &str,
+        max_count: u64,
+    ) -> Result<AddressSummary, SuiError> {
+        let from = TransactionFilter::FromAddress(address.to_string());
+        let to = TransactionFilter::ToAddress(address.to_string());
+        let (first_from, first_to, last_from, last_to, txs_from, txs_to, objects, balance) = tokio::try_join!(
+            self.edge_transaction(from.clone(), false),
+            self.edge_transaction(to.clone(), false),
+            self.edge_transaction(from.clone(), true),
+            self.edge_transaction(to.clone(), true),
+            self.count_transactions(from, max_count),
+            self.count_transactions(to, max_count),
+            self.count_owned_objects(address, max_count),
+            self.request::<Balance>("suix_getBalance", vec![address.into()]),
+        )?;
+        let sui_balance = balance.total_balance;
+        Ok(AddressSummary {
+            first_tx: first_from.into_iter().chain(first_to).min_by_key(|tx| tx.1),
+            last_tx: last_from.into_iter().chain(last_to).max_by_key(|tx| tx.1),
+            total_txs_from: txs_from.0,
+            total_txs_to: txs_to.0,
+            owned_object_count: objects.0,
+            sui_balance,
+            truncated: txs_from.1 || txs_to.1 || objects.1,
+        })
+    }
+
+    /// earliest or latest checkpointed transaction matching a filter, as (digest, timestamp)
+    async fn edge_transaction(
+        &self,
+        filter: TransactionFilter,
+        descending: bool,
+    ) -> Result<Option<(String, u64)>, SuiError> {
+        let page = self
+            .query_transaction_blocks(filter, Default::default(), None, Some(1), descending)
+            .await?;
+        Ok(page
+            .data
+            .into_iter()
+            .next()
+            .map(|tx| (tx.digest, tx.timestamp_ms.unwrap_or_default())))
+    }
+
+    /// count transactions matching a filter up to a bound, returns (count, truncated)
+    async fn count_transactions(
+        &self,
+        filter: TransactionFilter,
+        max_count: u64,
+    ) -> Result<(u64, bool), SuiError> {
+        let mut count = 0u64;
+        let mut cursor = None;
+        loop {
+            let page = self
+                .query_transaction_blocks(
+                    filter.clone(),
+                    Default::default(),
+                    cursor,
+                    Some(MAX_QUERY_PAGE_SIZE),
+                    false,
+                )
+                .await?;
+            count += page.data.len() as u64;
+            if !page.has_next_page {
+                return Ok((count, false));
+            }
+            if count >= max_count {
+                return Ok((max_count, true));
+            }
+            cursor = page.next_cursor;
+        }
+    }
+
+    /// count objects owned by an address up to a bound, returns (count, truncated)
+    async fn count_owned_objects(
+        &self,
+        address: &str,
+        max_count: u64,
+    ) -> Result<(u64, bool), SuiError> {
+        let mut count = 0u64;
+        let mut cursor: Option<String> = None;
+        loop {
+            let page: Page<Value> = self
+                .request(
+                    "suix_getOwnedObjects",
+                    vec![
+                        address.into(),
+                        serde_json::json!({ "options": {} }),
+                        cursor.into(),
+                        MAX_QUERY_PAGE_SIZE.into(),
+                    ],
+                )
+                .await?;
+            count += page.data.len() as u64;
+            if !page.has_next_page {
+                return Ok((count, false));
+            }
+            if count >= max_count {
+                return Ok((max_count, true));
+            }
+            cursor = page.next_cursor;
+        }
+    }
+
+    /// # Recent gas prices
+    ///
+    /// Gas price statistics of transactions in the latest checkpoints. Requests are
+    /// capped at `max_rpc_calls`: the reference price, the latest checkpoint number, one
+    /// request per checkpoint and batches of up to 50 transactions. When the cap is
+    /// tight, fewer transactions are sampled per checkpoint, then fewer checkpoints.
+    ///
+    /// ## Parameters
+    /// - num_checkpoints : number of latest checkpoints to sample
+    /// - max_rpc_calls : upper bound of requests, at least 4, 20 when `None`
+    ///
+    /// ## Returns
+    /// - Ok(GasPriceStats) : min, p50, p90, max and the reference gas price
+    /// - Err(SuiError) : error
+    ///
+    /// ## Example
+    /// ```no_run
+    /// use sui_network_sdk::SuiClient;
+    /// use sui_network_sdk::global::mainnet;
+    /// #[tokio::main]
+    /// async fn main() {
+    ///    let client = SuiClient::new_by_rpc_url(mainnet::RPC_URL.to_string());
+    ///    let stats = client.recent_gas_prices(10, None).await.unwrap();
+    ///    println!("p50 {} p90 {} (reference {})", stats.p50, stats.p90, stats.reference_gas_price);
+    /// }
+    /// ```
+    pub async fn recent_gas_prices(
+        &self,
+        num_checkpoints: u64,
+        max_rpc_calls: Option<usize>,
+    ) -> Result<GasPriceStats, SuiError> {
+        let max_rpc_calls = max_rpc_calls.unwrap_or(DEFAULT_GAS_STATS_MAX_CALLS).max(4);
+        let (reference_gas_price, latest) = tokio::try_join!(
+            self.get_reference_gas_price(),
+            self.get_latest_checkpoint_sequence_number(),
+        )?;
+        // every checkpoint needs a request of its own plus at least one transaction batch
+        let budget = max_rpc_calls - 2;
+        let num_checkpoints = num_checkpoints
+            .min(latest + 1)
+            .min((budget - 1) as u64)
+            .max(1);
+        let checkpoints = futures::future::try_join_all(
+            (latest + 1 - num_checkpoints..=latest)
+                .map(|seq| self.get_checkpoint(CheckpointId::SequenceNumber(seq))),
+        )
+        .await?;
+        let batches = budget - checkpoints.len();
+        let per_checkpoint = (batches * MAX_QUERY_PAGE_SIZE / checkpoints.len()).max(1);
+        let digests: Vec<String> = checkpoints
+            .iter()
+            .flat_map(|checkpoint| checkpoint.transactions.iter().take(per_checkpoint).cloned())
+            .take(batches * MAX_QUERY_PAGE_SIZE)
+            .collect();
+        let options = serde_json::json!({ "showInput": true });
+        let responses =
+            futures::future::try_join_all(digests.chunks(MAX_QUERY_PAGE_SIZE).map(|chunk| {
+                self.request::<Vec<TransactionResponse>>(
+                    "sui_multiGetTransactionBlocks",
+                    vec![chunk.into(), options.clone()],
+                )
+            }))
+            .await?;
+        let prices = responses
+            .into_iter()
+            .flatten()
+            .filter_map(|tx| Some(tx.transaction?.data.gas_data?.price))
+            .collect();
+        Ok(GasPriceStats::from_prices(
+            prices,
+            reference_gas_price,
+            checkpoints.len(),
+        ))
+    }
+
+    /// # Get reference gas price
+    ///
+    /// ## Returns
+    /// - Ok(u64) : reference gas price of the current epoch in MIST per unit
+    /// - Err(SuiError) : error
+    pub async fn get_reference_gas_price(&self) -> Result<u64, SuiError> {
+        let price: Value = self.request("suix_getReferenceGasPrice", vec![]).await?;
+        match &price {
+            Value::String(s) => s.parse::<u64>().ok(),
+            v => v.as_u64(),
+        }
+        .ok_or_else(|| SuiError::Rpc("Failed to parse reference gas price".to_string()))
+    }
+
+    /// # Get latest Sui system state
+    ///
+    /// ## Returns
+    /// - Ok(SuiSystemStateSummary) : system state of the current epoch with its active validators
+    /// - Err(SuiError) : error
+    ///
+    /// ## Example
+    /// ```rust
+    /// use std::sync::Arc;
+    /// use sui_network_sdk::SuiClient;
+    /// use sui_network_sdk::transport::MockTransport;
+    /// use sui_network_sdk::types::SuiClientConfig;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let mock = MockTransport::new().with_result("suix_getLatestSuiSystemState", serde_json::json!({
+    ///         "epoch": "412",
+    ///         "protocolVersion": "70",
+    ///         "systemStateVersion": "2",
+    ///         "referenceGasPrice": "750",
+    ///         "totalStake": "7800000000000000000",
+    ///         "safeMode": false,
+    ///         "safeModeStorageRewards": "0",
+    ///         "epochStartTimestampMs": "1700000000000",
+    ///         "epochDurationMs": "86400000",
+    ///         "stakeSubsidyBalance": "1000",
+    ///         "activeValidators": [{
+    ///             "suiAddress": "0x4a",
+    ///             "name": "Validator A",
+    ///             "imageUrl": "https://a.example/logo.png",
+    ///             "projectUrl": "https://a.example",
+    ///             "votingPower": "120",
+    ///             "gasPrice": "750",
+    ///             "commissionRate": "200",
+    ///             "stakingPoolSuiBalance": "95000000000000000",
+    ///             "nextEpochStake": "96000000000000000",
+    ///             "nextEpochCommissionRate": "500",
+    ///             "stakingPoolActivationEpoch": "0",
+    ///             "stakingPoolDeactivationEpoch": null
+    ///         }]
+    ///     }));
+    ///     let client = SuiClient::new_with_transport(SuiClientConfig::default(), Arc::new(mock));
+    ///
+    ///     let state = client.get_latest_sui_system_state().await.unwrap();
+    ///     assert_eq!((state.epoch, state.protocol_version, state.reference_gas_price), (412, 70, 750));
+    ///     assert_eq!(state.total_stake, 7_800_000_000_000_000_000);
+    ///     assert!(!state.safe_mode);
+    ///     assert_eq!(state.other["stakeSubsidyBalance"], "1000");
+    ///     let validator = &state.active_validators[0];
+    ///     assert_eq!(validator.sui_address, format!("0x{:0>64}", "4a"));
+    ///     assert_eq!((validator.voting_power, validator.commission_rate), (120, 200));
+    ///     assert_eq!(validator.next_epoch_stake, 96_000_000_000_000_000);
+    ///     assert_eq!(validator.staking_pool_activation_epoch, Some(0));
+    ///     assert_eq!(validator.staking_pool_deactivation_epoch, None);
+    /// }
+    /// ```
+    pub async fn get_latest_sui_system_state(&self) -> Result<SuiSystemStateSummary, SuiError> {
+        let summary: SuiSystemStateSummary =
+            self.request("suix_getLatestSuiSystemState", vec![]).await?;
+        self.observe_protocol_state(&summary);
+        Ok(summary)
+    }
+
+    /// # Get stakes
+    ///
+    /// ## Parameters
+    /// - owner : staker address
+    ///
+    /// ## Returns
+    /// - Ok(Vec<DelegatedStake>) : stakes by validator, empty when the address never staked
+    /// - Err(SuiError) : error
+    ///
+    /// ## Example
+    /// ```rust
+    /// use std::sync::Arc;
+    /// use sui_network_sdk::SuiClient;
+    /// use sui_network_sdk::transport::MockTransport;
+    /// use sui_network_sdk::types::{StakeStatus, SuiClientConfig};
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let mock = MockTransport::new()
+    ///         .with_result("suix_getStakes", serde_json::json!([{
+    ///             "validatorAddress": "0x4a",
+    ///             "stakingPool": "0x9f",
+    ///             "stakes": [{
+    ///                 "stakedSuiId": "0x5a",
+    ///                 "stakeRequestEpoch": "410",
+    ///                 "stakeActiveEpoch": "411",
+    ///                 "principal": "1000000000000",
+    ///                 "status": "Active",
+    ///                 "estimatedReward": "2500000000"
+    ///             }]
+    ///         }]))
+    ///         .with_result("suix_getStakes", serde_json::json!([]));
+    ///     let client = SuiClient::new_with_transport(SuiClientConfig::default(), Arc::new(mock));
+    ///
+    ///     let stakes = client.get_stakes("0x123").await.unwrap();
+    ///     assert_eq!(stakes[0].validator_address, format!("0x{:0>64}", "4a"));
+    ///     assert_eq!(stakes[0].stakes[0].principal, 1_000_000_000_000);
+    ///     assert_eq!(stakes[0].stakes[0].status, StakeStatus::Active { estimated_reward: 2_500_000_000 });
+    ///     assert!(client.get_stakes("0x456").await.unwrap().is_empty());
+    /// }
+    /// ```
+    pub async fn get_stakes(&self, owner: &str) -> Result<Vec<DelegatedStake>, SuiError> {
+        self.request("suix_getStakes", vec![owner.into()]).await
+    }
+
+    /// # Get stakes by ids
+    ///
+    /// ## Parameters
+    /// - ids : ids of `StakedSui` objects, withdrawn ones included
+    ///
+    /// ## Returns
+    /// - Ok(Vec<DelegatedStake>) : stakes by validator
+    /// - Err(SuiError) : error
+    ///
+    /// ## Example
+    /// ```rust
+    /// use std::sync::Arc;
+    /// use sui_network_sdk::SuiClient;
+    /// use sui_network_sdk::transport::MockTransport;
+    /// use sui_network_sdk::types::{StakeStatus, SuiClientConfig};
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let mock = Arc::new(MockTransport::new().with_result("suix_getStakesByIds", serde_json::json!([{
+    ///         "validatorAddress": "0x4a",
+    ///         "stakingPool": "0x9f",
+    ///         "stakes": [{
+    ///             "stakedSuiId": "0x5b",
+    ///             "stakeRequestEpoch": "300",
+    ///             "stakeActiveEpoch": "301",
+    ///             "principal": "5000000000",
+    ///             "status": "Unstaked"
+    ///         }]
+    ///     }])));
+    ///     let client = SuiClient::new_with_transport(SuiClientConfig::default(), mock.clone());
+    ///
+    ///     let stakes = client.get_stakes_by_ids(&["0x5b"]).await.unwrap();
+    ///     assert_eq!(stakes[0].stakes[0].status, StakeStatus::Unstaked);
+    ///     assert_eq!(mock.requests()[0].params, [serde_json::json!(["0x5b"])]);
+    /// }
+    /// ```
+    pub async fn get_stakes_by_ids(&self, ids: &[&str]) -> Result<Vec<DelegatedStake>, SuiError> {
+        self.request("suix_getStakesByIds", vec![ids.into()]).await
+    }
+
+    /// # Get validators APY
+    ///
+    /// ## Returns
+    /// - Ok(ValidatorsApy) : yields of the active validators, see [`ValidatorsApy::apy_for`]
+    /// - Err(SuiError) : error
+    ///
+    /// ## Example
+    /// ```rust
+    /// use std::sync::Arc;
+    /// use sui_network_sdk::SuiClient;
+    /// use sui_network_sdk::transport::MockTransport;
+    /// use sui_network_sdk::types::SuiClientConfig;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let mock = MockTransport::new().with_result("suix_getValidatorsApy", serde_json::json!({
+    ///         "apys": [
+    ///             { "address": "0x4a", "apy": 0.0312 },
+    ///             { "address": "0x4b", "apy": 0.0287 }
+    ///         ],
+    ///         "epoch": "412"
+    ///     }));
+    ///     let client = SuiClient::new_with_transport(SuiClientConfig::default(), Arc::new(mock));
+    ///
+    ///     let apys = client.get_validators_apy().await.unwrap();
+    ///     assert_eq!(apys.epoch, 412);
+    ///     assert_eq!(apys.apy_for("0x4b"), Some(0.0287));
+    ///     assert_eq!(apys.apy_for("0x4c"), None);
+    /// }
+    /// ```
+    pub async fn get_validators_apy(&self) -> Result<ValidatorsApy, SuiError> {
+        self.request("suix_getValidatorsApy", vec![]).await
+    }
+
+    /// # Reference gas price cached
+    ///
+    /// The reference gas price only changes at epoch boundaries, the price read last is
+    /// reused until it is older than the ttl. Concurrent callers of an expired price wait
+    /// for a single request.
+    ///
+    /// ## Parameters
+    /// - ttl : age from which the price is read again
+    ///
+    /// ## Returns
+    /// - Ok(u64) : reference gas price in MIST per unit
+    /// - Err(SuiError) : error, the cached price is kept
+    ///
+    /// ## Example
+    /// ```rust
+    /// use std::sync::Arc;
+    /// use std::time::Duration;
+    /// use sui_network_sdk::SuiClient;
+    /// use sui_network_sdk::transport::MockTransport;
+    /// use sui_network_sdk::types::SuiClientConfig;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let mock = Arc::new(
+    ///         MockTransport::new()
+    ///             .with_result("suix_getReferenceGasPrice", serde_json::json!("750"))
+    ///             .with_result("suix_getReferenceGasPrice", serde_json::json!("780")),
+    ///     );
+    ///     let client = SuiClient::new_with_transport(SuiClientConfig::default(), mock.clone());
+    ///     let ttl = Duration::from_secs(60);
+    ///     let (a, b) = tokio::join!(
+    ///         client.reference_gas_price_cached(ttl),
+    ///         client.reference_gas_price_cached(ttl)
+    ///     );
+    ///     assert_eq!((a.unwrap(), b.unwrap()), (750, 750));
+    ///     assert_eq!(mock.request_count("suix_getReferenceGasPrice"), 1);
+    ///
+    ///     // an expired price is read again
+    ///     assert_eq!(client.reference_gas_price_cached(Duration::ZERO).await.unwrap(), 780);
+    ///     assert_eq!(client.reference_gas_price_cached(ttl).await.unwrap(), 780);
+    ///     assert_eq!(mock.request_count("suix_getReferenceGasPrice"), 2);
+    /// }
+    /// ```
+    pub async fn reference_gas_price_cached(
+        &self,
+        ttl: std::time::Duration,
+    ) -> Result<u64, SuiError> {
+        let mut cache = self.gas_price_cache.lock().await;
+        if let Some((read_at, price)) = *cache
+            && read_at.elapsed() < ttl
+        {
+            return Ok(price);
+        }
+        let price = self.get_reference_gas_price().await?;
+        *cache = Some((std::time::Instant::now(), price));
+        Ok(price)
+    }
+
+    /// # Locate object
+    ///
+    /// Classifies where an object is held. Objects held by other objects (dynamic fields,
+    /// kiosk items, objects transferred to an object) don't show in address listings, their
+    /// parent chain is walked up to the outermost owner. Objects transferred to an object
+    /// are address owned by the receiving object, address owners are therefore checked for
+    /// being objects themselves.
+    ///
+    /// ## Parameters
+    /// - object_id : object id
+    ///
+    /// ## Returns
+    /// - Ok(LocatedObject) : location of the object and the ownership chain walked
+    /// - Err(SuiError) : the object never existed, or RPC error
+    ///
+    /// ## Example
+    /// ```rust
+    /// use std::sync::Arc;
+    /// use sui_network_sdk::SuiClient;
+    /// use sui_network_sdk::transport::MockTransport;
+    /// use sui_network_sdk::types::{ObjectLocation, SuiClientConfig};
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let object = |id: &str, owner: serde_json::Value| serde_json::json!({ "data": {
+    ///         "objectId": id,
+    ///         "version": "1",
+    ///         "digest": "9WzSXdwbky8tNbH7juvyaui4QzMUYEjdCEKMrMgLhXHT",
+    ///         "owner": owner
+    ///     }});
+    ///     // an NFT placed in a kiosk: item -> dynamic field -> shared kiosk
+    ///     let mock = MockTransport::new()
+    ///         .with_result("sui_getObject", object("0xa", serde_json::json!({ "ObjectOwner": "0xf" })))
+    ///         .with_result("sui_getObject", object("0xf", serde_json::json!({ "ObjectOwner": "0xc" })))
+    ///         .with_result("sui_getObject", object("0xc", serde_json::json!({ "Shared": { "initial_shared_version": "5" } })));
+    ///     let client = SuiClient::new_with_transport(SuiClientConfig::default(), Arc::new(mock));
+    ///     let located = client.locate_object("0xa").await.unwrap();
+    ///     let ObjectLocation::InObject { parent_id, top_level_owner } = located.location else { panic!() };
+    ///     assert!(parent_id.ends_with('f'));
+    ///     assert_eq!(top_level_owner, Some(Box::new(ObjectLocation::Shared)));
+    ///     assert_eq!(located.chain.len(), 3);
+    ///
+    ///     // objects owning each other are reported unresolved instead of looping
+    ///     let mock = MockTransport::new()
+    ///         .with_result("sui_getObject", object("0xa", serde_json::json!({ "ObjectOwner": "0xb" })))
+    ///         .with_result("sui_getObject", object("0xb", serde_json::json!({ "ObjectOwner": "0xa" })));
+    ///     let client = SuiClient::new_with_transport(SuiClientConfig::default(), Arc::new(mock));
+    ///     let located = client.locate_object("0xa").await.unwrap();
+    ///     assert!(matches!(located.location, ObjectLocation::InObject { top_level_owner: None, .. }));
+    ///
+    ///     // an address owner that is not an object is the owning account
+    ///     let mock = MockTransport::new()
+    ///         .with_result("sui_getObject", object("0xa", serde_json::json!({ "AddressOwner": "0xd" })))
+    ///         .with_result("sui_getObject", serde_json::json!({ "error": { "code": "notExists", "object_id": "0xd" } }));
+    ///     let client = SuiClient::new_with_transport(SuiClientConfig::default(), Arc::new(mock));
+    ///     let located = client.locate_object("0xa").await.unwrap();
+    ///     assert!(matches!(located.location, ObjectLocation::DirectlyOwned(owner) if owner.ends_with('d')));
+    /// }
+    /// ```
+    pub async fn locate_object(&self, object_id: &str) -> Result<LocatedObject, SuiError> {
+        let object_id = address::normalize(object_id).unwrap_or_else(|| object_id.to_string());
+        let owner = match self.read_owner(&object_id).await? {
+            Ok(owner) => owner,
+            Err(code) if code == "deleted" => {
+                return Ok(LocatedObject {
+                    object_id,
+                    location: ObjectLocation::Deleted,
+                    chain: Vec::new(),
+                });
+            }
+            Err(code) => {
+                return Err(SuiError::Rpc(format!(
+                    "Object {} not found: {}",
+                    object_id, code
+                )));
+            }
+        };
+        let mut chain = vec![OwnershipLink {
+            object_id: object_id.clone(),
+            owner,
+        }];
+        let mut visited = HashSet::from([object_id.clone()]);
+        let mut parent_id = None;
+        // location of the outermost parent, None when unresolved
+        let top_level = loop {
+            let owner = &chain[chain.len() - 1].owner;
+            let (next, read) = match owner {
+                Owner::Shared(_) => break Some(ObjectLocation::Shared),
+                Owner::Immutable => break Some(ObjectLocation::Immutable),
+                Owner::ConsensusAddressOwner(owner) => {
+                    break Some(ObjectLocation::DirectlyOwned(owner.owner.clone()));
+                }
+                Owner::Unknown(_) => break None,
+                Owner::AddressOwner(address) => {
+                    if visited.contains(address) || chain.len() > MAX_OWNERSHIP_DEPTH {
+                        parent_id.get_or_insert_with(|| address.clone());
+                        break None;
+                    }
+                    match self.read_owner(address).await? {
+                        Ok(owner) => (address.clone(), Ok(owner)),
+                        // not an object, the owning account
+                        Err(_) => break Some(ObjectLocation::DirectlyOwned(address.clone())),
+                    }
+                }
+                Owner::ObjectOwner(parent) => {
+                    if visited.contains(parent) || chain.len() > MAX_OWNERSHIP_DEPTH {
+                        parent_id.get_or_insert_with(|| parent.clone());
+                        break None;
+                    }
+                    (parent.clone(), self.read_owner(parent).await?)
+                }
+            };
+            parent_id.get_or_insert_with(|| next.clone());
+            visited.insert(next.clone());
+            match read {
+                Ok(owner) => chain.push(OwnershipLink {
+                    object_id: next,
+                    owner,
+                }),
+                Err(code) if code == "deleted" => break Some(ObjectLocation::Deleted),
+                Err(_) => break None,
+            }
+        };
+        let location = match parent_id {
+            Some(parent_id) => ObjectLocation::InObject {
+                parent_id,
+                top_level_owner: top_level.map(Box::new),
+            },
+            None => top_level.unwrap_or(ObjectLocation::Deleted),
+        };
+        Ok(LocatedObject {
+            object_id,
+            location,
+            chain,
+        })
+    }
+
+    /// owner of an object, or the read error code such as `deleted` or `notExists`
+    async fn read_owner(&self, object_id: &str) -> Result<Result<Owner, String>, SuiError> {
+        let options = serde_json::json!({ "showOwner": true });
+        let response: ObjectResponse = self
+            .request("sui_getObject", vec![object_id.into(), options])
+            .await?;
+        if let Some(owner) = response.data.and_then(|object| object.owner) {
+            return Ok(Ok(owner));
+        }
+        let code = response
+            .error
+            .as_ref()
+            .and_then(|e| e.get("code"))
+            .and_then(|c| c.as_str())
+            .unwrap_or("unknown");
+        Ok(Err(code.to_string()))
+    }
+
+    /// # Get latest checkpoint sequence number
+    ///
+    /// ## Returns
+    /// - Ok(u64) : sequence number of the latest executed checkpoint
+    /// - Err(SuiError) : error
+    pub async fn get_latest_checkpoint_sequence_number(&self) -> Result<u64, SuiError> {
+        let latest: Value = self
+            .request("sui_getLatestCheckpointSequenceNumber", vec![])
+            .await?;
+        match &latest {
+            Value::String(s) => s.parse::<u64>().ok(),
+            v => v.as_u64(),
+        }
+        .ok_or_else(|| SuiError::Rpc("Failed to parse latest checkpoint".to_string()))
+    }
+
+    /// # Get checkpoint
+    ///
+    /// ## Parameters
+    /// - id : sequence number or digest of the checkpoint
+    ///
+    /// ## Returns
+    /// - Ok(Checkpoint) : checkpoint with the digests of its transactions
+    /// - Err(SuiError) : error
+    ///
+    /// ## Example
+    /// ```rust
+    /// use std::sync::Arc;
+    /// use sui_network_sdk::SuiClient;
+    /// use sui_network_sdk::transport::MockTransport;
+    /// use sui_network_sdk::types::{CheckpointId, SuiClientConfig};
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let mock = Arc::new(MockTransport::new().with_result("sui_getCheckpoint", serde_json::json!({
+    ///         "epoch": "412",
+    ///         "sequenceNumber": "1024",
+    ///         "digest": "C1024",
+    ///         "previousDigest": "C1023",
+    ///         "timestampMs": "1700000000123",
+    ///         "networkTotalTransactions": "3200000000",
+    ///         "epochRollingGasCostSummary": {
+    ///             "computationCost": "75000", "storageCost": "98000",
+    ///             "storageRebate": "40000", "nonRefundableStorageFee": "400"
+    ///         },
+    ///         "transactions": ["T1", "T2"],
+    ///         "checkpointCommitments": [],
+    ///         "validatorSignature": "AAA="
+    ///     })));
+    ///     let client = SuiClient::new_with_transport(SuiClientConfig::default(), mock.clone());
+    ///
+    ///     let checkpoint = client.get_checkpoint(CheckpointId::Digest("C1024".to_string())).await.unwrap();
+    ///     assert_eq!(checkpoint.epoch, 412);
+    ///     assert_eq!(checkpoint.network_total_transactions, 3_200_000_000);
+    ///     assert_eq!(checkpoint.epoch_rolling_gas_cost_summary.unwrap().storage_rebate, 40_000);
+    ///     assert_eq!(checkpoint.transactions, ["T1", "T2"]);
+    ///
+    ///     client.get_checkpoint(CheckpointId::SequenceNumber(1024)).await.unwrap();
+    ///     let requests = mock.requests();
+    ///     assert_eq!(requests[0].params, [serde_json::json!("C1024")]);
+    ///     assert_eq!(requests[1].params, [serde_json::json!("1024")]);
+    /// }
+    /// ```
+    pub async fn get_checkpoint(&self, id: CheckpointId) -> Result<Checkpoint, SuiError> {
+        self.request("sui_getCheckpoint", vec![id.to_string().into()])
+            .await
+    }
+
+    /// # Get checkpoints
+    ///
+    /// One page of checkpoints, e.g. to index the chain in order.
+    ///
+    /// ## Parameters
+    /// - cursor : sequence number to continue after, the first or latest checkpoint when `None`
+    /// - limit : maximum number of checkpoints in the page
+    /// - descending : latest first when true
+    ///
+    /// ## Returns
+    /// - Ok(Page<Checkpoint, u64>) : checkpoints, the cursor is a sequence number
+    /// - Err(SuiError) : error
+    ///
+    /// ## Example
+    /// ```rust
+    /// use std::sync::Arc;
+    /// use sui_network_sdk::SuiClient;
+    /// use sui_network_sdk::transport::MockTransport;
+    /// use sui_network_sdk::types::SuiClientConfig;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let checkpoint = |seq: u64| serde_json::json!({
+    ///         "sequenceNumber": seq.to_string(),
+    ///         "digest": format!("C{}", seq),
+    ///         "timestampMs": "1700000000000",
+    ///         "transactions": [format!("T{}", seq)]
+    ///     });
+    ///     let mock = Arc::new(MockTransport::new().with_result("sui_getCheckpoints", serde_json::json!({
+    ///         "data": [checkpoint(11), checkpoint(12)],
+    ///         "nextCursor": "12",
+    ///         "hasNextPage": true
+    ///     })));
+    ///     let client = SuiClient::new_with_transport(SuiClientConfig::default(), mock.clone());
+    ///
+    ///     let page = client.get_checkpoints(Some(10), Some(2), false).await.unwrap();
+    ///     assert_eq!(page.data[1].transactions, ["T12"]);
+    ///     assert_eq!(page.next_cursor, Some(12));
+    ///     assert_eq!(
+    ///         mock.requests()[0].params,
+    ///         [serde_json::json!("10"), serde_json::json!(2), serde_json::json!(false)]
+    ///     );
+    /// }
+    /// ```
+    pub async fn get_checkpoints(
+        &self,
+        cursor: Option<u64>,
+        limit: Option<usize>,
+        descending: bool,
+    ) -> Result<Page<Checkpoint, u64>, SuiError> {
+        let page: CheckpointPage = self
+            .request(
+                "sui_getCheckpoints",
+                vec![
+                    cursor.map(|c| c.to_string()).into(),
+                    limit.into(),
+                    descending.into(),
+                ],
+            )
+            .await?;
+        Ok(Page {
+            data: page.data,
+            next_cursor: page.next_cursor,
+            has_next_page: page.has_next_page,
+        })
+    }
+
+    /// # Get total transaction blocks
+    ///
+    /// ## Returns
+    /// - Ok(u64) : transactions executed by the network since genesis
+    /// - Err(SuiError) : error
+    ///
+    /// ## Example
+    /// ```no_run
+    /// use sui_network_sdk::SuiClient;
+    /// use sui_network_sdk::global::mainnet;
+    /// #[tokio::main]
+    /// async fn main() {
+    ///    let client = SuiClient::new_by_rpc_url(mainnet::RPC_URL.to_string());
+    ///    println!("{} transactions", client.get_total_transaction_blocks().await.unwrap());
+    /// }
+    /// ```
+    pub async fn get_total_transaction_blocks(&self) -> Result<u64, SuiError> {
+        let total: Value = self
+            .request("sui_getTotalTransactionBlocks", vec![])
+            .await?;
+        match &total {
+            Value::String(s) => s.parse::<u64>().ok(),
+            v => v.as_u64(),
+        }
+        .ok_or_else(|| SuiError::Rpc("Failed to parse total transaction blocks".to_string()))
+    }
+
+    /// # Get epochs
+    ///
+    /// One page of epochs with their validators and, for ended epochs, their totals.
+    ///
+    /// ## Parameters
+    /// - cursor : epoch to continue after, the first or latest epoch when `None`
+    /// - limit : maximum number of epochs in the page
+    /// - descending : latest first when true
+    ///
+    /// ## Returns
+    /// - Ok(Page<EpochInfo, u64>) : epochs, the cursor is an epoch number
+    /// - Err(SuiError) : error
+    ///
+    /// ## Example
+    /// ```rust
+    /// use std::sync::Arc;
+    /// use sui_network_sdk::SuiClient;
+    /// use sui_network_sdk::transport::MockTransport;
+    /// use sui_network_sdk::types::SuiClientConfig;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let ended = serde_json::json!({
+    ///         "epoch": "599",
+    ///         "validators": [],
+    ///         "epochTotalTransactions": "1250000",
+    ///         "firstCheckpointId": "170900000",
+    ///         "epochStartTimestamp": "1749913600000",
+    ///         "endOfEpochInfo": {
+    ///             "lastCheckpointId": "170999999",
+    ///             "epochEndTimestamp": "1750000000000",
+    ///             "protocolVersion": "70",
+    ///             "referenceGasPrice": "750",
+    ///             "totalStake": "7600000000000000000",
+    ///             "storageCharge": "12",
+    ///             "newTotalAddedLater": "1"
+    ///         }
+    ///     });
+    ///     let mock = Arc::new(
+    ///         MockTransport::new()
+    ///             .with_result("suix_getEpochs", serde_json::json!({
+    ///                 "data": [ended],
+    ///                 "nextCursor": "599",
+    ///                 "hasNextPage": true
+    ///             }))
+    ///             .with_result("sui_getTotalTransactionBlocks", serde_json::json!("3500000000")),
+    ///     );
+    ///     let client = SuiClient::new_with_transport(SuiClientConfig::default(), mock.clone());
+    ///
+    ///     let page = client.get_epochs(Some(600), Some(1), true).await.unwrap();
+    ///     let end = page.data[0].end_of_epoch_info.as_ref().unwrap();
+    ///     assert_eq!((end.protocol_version, end.reference_gas_price), (70, 750));
+    ///     assert_eq!(page.data[0].total_stake(), 7_600_000_000_000_000_000);
+    ///     assert_eq!(page.next_cursor, Some(599));
+    ///     assert_eq!(
+    ///         mock.requests()[0].params,
+    ///         [serde_json::json!("600"), serde_json::json!(1), serde_json::json!(true)]
+    ///     );
+    ///     assert_eq!(client.get_total_transaction_blocks().await.unwrap(), 3_500_000_000);
+    /// }
+    /// ```
+    pub async fn get_epochs(
+        &self,
+        cursor: Option<u64>,
+        limit: Option<usize>,
+        descending: bool,
+    ) -> Result<Page<EpochInfo, u64>, SuiError> {
+        let page: EpochPage = self
+            .request(
+                "suix_getEpochs",
+                vec![
+                    cursor.map(|c| c.to_string()).into(),
+                    limit.into(),
+                    descending.into(),
+                ],
+            )
+            .await?;
+        Ok(Page {
+            data: page.data,
+            next_cursor: page.next_cursor,
+            has_next_page: page.has_next_page,
+        })
+    }
+
+    /// # Get current epoch
+    ///
+    /// ## Returns
+    /// - Ok(EpochInfo) : the running epoch, without end of epoch info
+    /// - Err(SuiError) : error
+    ///
+    /// ## Example
+    /// ```no_run
+    /// use sui_network_sdk::SuiClient;
+    /// use sui_network_sdk::global::mainnet;
+    /// #[tokio::main]
+    /// async fn main() {
+    ///    let client = SuiClient::new_by_rpc_url(mainnet::RPC_URL.to_string());
+    ///    let epoch = client.get_current_epoch().await.unwrap();
+    ///    println!("epoch {} with {} validators", epoch.epoch, epoch.validator_count());
+    /// }
+    /// ```
+    pub async fn get_current_epoch(&self) -> Result<EpochInfo, SuiError> {
+        self.request("suix_getCurrentEpoch", vec![]).await
+    }
+
+    /// # Get past object
+    ///
+    /// Reads an object at a version. With `ReadConsistency::AtCheckpoint` the version must
+    /// have been written at or before the checkpoint, it is checked through the checkpoint
+    /// of the transaction that wrote it.
+    ///
+    /// ## Parameters
+    /// - object_id : object id
+    /// - version : object version
+    /// - consistency : checkpoint pin of the read
+    ///
+    /// ## Returns
+    /// - Ok(Object) : the object at the version
+    /// - Err(SuiError) : the version doesn't exist, or didn't at the pinned checkpoint,
+    ///   annotated when it may have been pruned
+    ///
+    /// ## Example
+    /// ```rust
+    /// use std::sync::Arc;
+    /// use sui_network_sdk::SuiClient;
+    /// use sui_network_sdk::transport::MockTransport;
+    /// use sui_network_sdk::types::{ReadConsistency, SuiClientConfig};
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     // the node no longer serves checkpoint 0, its history starts at checkpoint 1
+    ///     let mock = Arc::new(
+    ///         MockTransport::new()
+    ///             .with_result(
+    ///                 "sui_tryGetPastObject",
+    ///                 serde_json::json!({ "status": "VersionNotFound", "details": ["0x5", "42"] }),
+    ///             )
+    ///             .with_error("sui_getCheckpoint", -32602, "Could not find checkpoint 0")
+    ///             .with_result("sui_getLatestCheckpointSequenceNumber", serde_json::json!("1")),
+    ///     );
+    ///     let client = SuiClient::new_with_transport(SuiClientConfig::default(), mock);
+    ///     let error = client
+    ///         .get_past_object("0x5", 42, ReadConsistency::Latest)
+    ///         .await
+    ///         .unwrap_err();
+    ///     assert!(error.is_not_found());
+    ///     assert!(error.to_string().contains("node history starts at checkpoint 1"), "{}", error);
+    /// }
+    /// ```
+    pub async fn get_past_object(
+        &self,
+        object_id: &str,
+        version: u64,
+        consistency: ReadConsistency,
+    ) -> Result<Object, SuiError> {
+        let options = ObjectDataOptions::default()
+            .with_type()
+            .with_owner()
+            .with_content()
+            .with_previous_transaction();
+        let object = match self
+            .try_get_past_object(object_id, version, options)
+            .await?
+        {
+            PastObjectResponse::VersionFound(object) => *object,
+            response => {
+                let error = SuiError::Rpc(format!(
+                    "Object {} version {} not found: {}",
+                    object_id,
+                    version,
+                    response.status()
+                ));
+                return Err(self.annotate_pruned(error).await);
+            }
+        };
+        if let ReadConsistency::AtCheckpoint(pin) = consistency {
+            let digest = object.previous_transaction.as_deref().ok_or_else(|| {
+                SuiError::Rpc("No previous transaction in past object response".to_string())
+            })?;
+            let written = self
+                .get_transaction_block(digest, serde_json::json!({}))
+                .await?
+                .checkpoint;
+            if written.is_none_or(|checkpoint| checkpoint > pin) {
+                return Err(SuiError::Rpc(format!(
+                    "Object {} version {} not found at checkpoint {}",
+                    object_id, version, pin
+                )));
+            }
+        }
+        Ok(object)
+    }
+
+    /// # Try get past object
+    ///
+    /// Reads an object at a version, e.g. the version a transaction read, with the status of
+    /// the read when the version is not available.
     ///
     /// ## Parameters
-    /// - config : client config
+    /// - object_id : object id
+    /// - version : object version
+    /// - options : object data to include
     ///
     /// ## Returns
-    /// - client object
+    /// - Ok(PastObjectResponse) : the object, or why the version is not available
+    /// - Err(SuiError) : error, annotated when the object may have been pruned
     ///
     /// ## Example
     /// ```rust
-    /// let config = SuiClientConfig {
-    ///     rpc_url: mainnet::RPC_URL.to_string(),
-    ///     ..Default::default(),
-    /// };
-    /// let client = SuiClient::new(config);
+    /// use std::sync::Arc;
+    /// use sui_network_sdk::SuiClient;
+    /// use sui_network_sdk::transport::MockTransport;
+    /// use sui_network_sdk::types::{ObjectDataOptions, PastObjectResponse, SuiClientConfig};
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let mock = Arc::new(MockTransport::new().with_result(
+    ///         "sui_tryGetPastObject",
+    ///         serde_json::json!({
+    ///             "status": "ObjectDeleted",
+    ///             "details": { "objectId": "0x5", "version": "8", "digest": "d" }
+    ///         }),
+    ///     ));
+    ///     let client = SuiClient::new_with_transport(SuiClientConfig::default(), mock.clone());
+    ///     let options = ObjectDataOptions::default().with_owner();
+    ///     match client.try_get_past_object("0x5", 7, options).await.unwrap() {
+    ///         PastObjectResponse::ObjectDeleted(deleted) => assert_eq!(deleted.version, 8),
+    ///         other => panic!("unexpected {}", other.status()),
+    ///     }
+    ///     let params = &mock.requests()[0].params;
+    ///     assert_eq!(params[1], 7);
+    ///     assert_eq!(params[2], serde_json::json!({ "showOwner": true }));
+    /// }
     /// ```
-    pub fn new(config: SuiClientConfig) -> Self {
-        Self {
-            http_client: HttpClient::new(),
-            config,
+    pub async fn try_get_past_object(
+        &self,
+        object_id: &str,
+        version: u64,
+        options: ObjectDataOptions,
+    ) -> Result<PastObjectResponse, SuiError> {
+        match self
+            .request(
+                "sui_tryGetPastObject",
+                vec![
+                    object_id.into(),
+                    version.into(),
+                    serde_json::to_value(options)?,
+                ],
+            )
+            .await
+        {
+            Ok(response) => Ok(response),
+            Err(e) => Err(self.annotate_pruned(e).await),
         }
     }
 
-    /// # create new client by rpc url
+    /// # Try multi get past objects
+    ///
+    /// Reads many objects at versions, inputs over the configured `multi_get_chunk_size` are
+    /// split into chunks issued concurrently. A failed chunk fails only its own items.
     ///
     /// ## Parameters
-    /// - url : rpc url
+    /// - requests : object ids and versions
+    /// - options : object data to include
     ///
     /// ## Returns
-    /// sui network client
+    /// per request in input order, the read status or why the chunk failed, not-found errors
+    /// annotated when the objects may have been pruned
     ///
     /// ## Example
     /// ```rust
-    /// let client = SuiClient::new_by_rpc_url(mainnet::RPC_URL.to_string());
+    /// use std::sync::Arc;
+    /// use sui_network_sdk::SuiClient;
+    /// use sui_network_sdk::transport::MockTransport;
+    /// use sui_network_sdk::types::{ObjectDataOptions, PastObjectResponse, SuiClientConfig};
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let mock = Arc::new(MockTransport::new().with_result(
+    ///         "sui_tryMultiGetPastObjects",
+    ///         serde_json::json!([
+    ///             { "status": "VersionFound", "details": { "objectId": "0x1", "version": "3", "digest": "d" } },
+    ///             { "status": "ObjectNotExists", "details": "0x2" }
+    ///         ]),
+    ///     ));
+    ///     let client = SuiClient::new_with_transport(SuiClientConfig::default(), mock.clone());
+    ///     let results = client
+    ///         .try_multi_get_past_objects(&[("0x1", 3), ("0x2", 1)], ObjectDataOptions::default())
+    ///         .await;
+    ///     assert_eq!(results[0].as_ref().unwrap().object().unwrap().version, 3);
+    ///     assert!(matches!(results[1], Ok(PastObjectResponse::ObjectNotExists(_))));
+    ///     assert_eq!(
+    ///         mock.requests()[0].params[0],
+    ///         serde_json::json!([
+    ///             { "objectId": "0x1", "version": "3" },
+    ///             { "objectId": "0x2", "version": "1" }
+    ///         ])
+    ///     );
+    /// }
     /// ```
-    pub fn new_by_rpc_url(url: String) -> Self {
-        let config = SuiClientConfig {
-            rpc_url: url,
-            ..Default::default()
-        };
-        Self::new(config)
+    pub async fn try_multi_get_past_objects(
+        &self,
+        requests: &[(&str, u64)],
+        options: ObjectDataOptions,
+    ) -> Vec<Result<PastObjectResponse, SuiError>> {
+        let requests: Vec<PastObjectRequest> = requests
+            .iter()
+            .map(|&(object_id, version)| PastObjectRequest {
+                object_id: object_id.to_string(),
+                version,
+            })
+            .collect();
+        let options = serde_json::json!(options);
+        let results = self
+            .multi_get("sui_tryMultiGetPastObjects", &requests, &options)
+            .await;
+        let mut annotated = Vec::with_capacity(results.len());
+        for result in results {
+            annotated.push(match result {
+                Ok(response) => Ok(response),
+                Err(e) => Err(self.annotate_pruned(e).await),
+            });
+        }
+        annotated
     }
 
-    /// # send JSON request
+    /// # Snapshot at latest
+    ///
+    /// Records the latest checkpoint and returns a reader pinning its reads to it.
+    ///
+    /// ## Returns
+    /// - Ok(SnapshotReader) : reader pinned to the latest checkpoint
+    /// - Err(SuiError) : error
+    pub async fn snapshot_at_latest(&self) -> Result<SnapshotReader<'_>, SuiError> {
+        let checkpoint = self.get_latest_checkpoint_sequence_number().await?;
+        Ok(SnapshotReader::new(self, checkpoint))
+    }
+
+    /// # Batch get balances
+    ///
+    /// Balances of a few addresses in one JSON-RPC batch, see [`SuiClient::batch_request`].
+    /// Large address lists go through [`SuiClient::get_balances_bulk`].
     ///
     /// ## Parameters
-    /// - method: rpc method name
-    /// - params: rpc param list
+    /// - addresses : addresses
+    /// - coin_type : coin type, defaults to "0x2::sui::SUI"
     ///
     /// ## Returns
-    /// - Ok(T): Response data
-    /// - Err(SuiError): rpc call error
+    /// - Ok(Vec<Result<u64, SuiError>>) : per address in input order, the balance or the node's error
+    /// - Err(SuiError) : the batch failed as a whole
     ///
-    /// ## Errors
-    /// - SuiError::Rpc: rpc call failed.
-    pub async fn request<T: serde::de::DeserializeOwned>(
+    /// ## Example
+    /// ```rust
+    /// use std::sync::Arc;
+    /// use sui_network_sdk::SuiClient;
+    /// use sui_network_sdk::transport::MockTransport;
+    /// use sui_network_sdk::types::SuiClientConfig;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let mock = Arc::new(MockTransport::new());
+    ///     mock.push_result("suix_getBalance", serde_json::json!({ "totalBalance": "5" }));
+    ///     mock.push_error("suix_getBalance", -32602, "Invalid address");
+    ///     mock.push_result("suix_getBalance", serde_json::json!({ "totalBalance": "7" }));
+    ///     let client = SuiClient::new_with_transport(SuiClientConfig::default(), mock.clone());
+    ///     let balances = client.batch_get_balances(&["0xa", "0xb", "0xc"], None).await.unwrap();
+    ///     assert_eq!(balances[0].as_ref().unwrap(), &5);
+    ///     assert!(balances[1].is_err());
+    ///     assert_eq!(balances[2].as_ref().unwrap(), &7);
+    ///     assert_eq!(mock.requests()[2].params[1], "0x2::sui::SUI");
+    /// }
+    /// ```
+    pub async fn batch_get_balances(
         &self,
-        method: &str,
-        params: Vec<Value>,
-    ) -> Result<T, SuiError> {
-        let request = RpcRequest {
-            jsonrpc: "2.0".to_string(),
-            id: 1,
-            method: method.to_string(),
-            params,
-        };
-        let response: RpcResponse<T> = self
-            .http_client
-            .post(&self.config.rpc_url)
-            .json(&request)
-            .send()
+        addresses: &[&str],
+        coin_type: Option<&str>,
+    ) -> Result<Vec<Result<u64, SuiError>>, SuiError> {
+        let coin_type = coin_type.unwrap_or("0x2::sui::SUI");
+        // batches skip the method routing, use the route single requests found
+        let method = self
+            .lock_method_routes()
+            .get("getBalance")
+            .cloned()
+            .unwrap_or_else(|| "suix_getBalance".to_string());
+        let calls = addresses
+            .iter()
+            .map(|&address| (method.as_str(), vec![address.into(), coin_type.into()]))
+            .collect();
+        Ok(self
+            .batch_request(calls)
             .await?
-            .json()
-            .await?;
+            .into_iter()
+            .map(|result| {
+                let balance = result?;
+                match balance.get("totalBalance") {
+                    Some(Value::String(s)) => s.parse().ok(),
+                    Some(v) => v.as_u64(),
+                    None => None,
+                }
+                .ok_or_else(|| SuiError::Rpc("Failed to parse balance".to_string()))
+            })
+            .collect())
+    }
 
-        if let Some(error) = response.error {
-            return Err(SuiError::Rpc(error.message));
+    /// # Get balances bulk
+    ///
+    /// Balances of many addresses, sent as JSON-RPC batches of 100 addresses with a few
+    /// batches in flight. Failures are reported per address: malformed addresses, node
+    /// errors for one address, and every address of a batch that failed as a whole.
+    ///
+    /// ## Parameters
+    /// - addresses : addresses
+    /// - coin_type : coin type, defaults to "0x2::sui::SUI"
+    /// - progress : called with the addresses done and the total after each batch
+    ///
+    /// ## Returns
+    /// balances by normalized address, and the failed addresses
+    ///
+    /// ## Example
+    /// ```rust
+    /// use std::sync::Arc;
+    /// use sui_network_sdk::SuiClient;
+    /// use sui_network_sdk::transport::MockTransport;
+    /// use sui_network_sdk::types::SuiClientConfig;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     // every 100th address is malformed, 990 go to the node in 10 batches
+    ///     let addresses: Vec<String> = (1..=1000)
+    ///         .map(|i| if i % 100 == 0 { format!("deposit-{}", i) } else { format!("0x{:x}", i) })
+    ///         .collect();
+    ///     let addresses: Vec<&str> = addresses.iter().map(String::as_str).collect();
+    ///     // the node answers 980 lookups, then fails the remaining 10
+    ///     let mock = Arc::new(MockTransport::new());
+    ///     for _ in 0..980 {
+    ///         mock.push_result("suix_getBalance", serde_json::json!({ "totalBalance": "7" }));
+    ///     }
+    ///     mock.push_error("suix_getBalance", -32000, "overloaded");
+    ///     let client = SuiClient::new_with_transport(SuiClientConfig::default(), mock.clone());
+    ///
+    ///     let mut updates = Vec::new();
+    ///     let mut progress = |done: usize, total: usize| updates.push((done, total));
+    ///     let report = client.get_balances_bulk(&addresses, None, Some(&mut progress)).await;
+    ///     assert_eq!(report.balances.len(), 980);
+    ///     assert!(report.balances.values().all(|&balance| balance == 7));
+    ///     assert_eq!(report.failures.len(), 20);
+    ///     assert_eq!(mock.request_count("suix_getBalance"), 990);
+    ///     assert_eq!(updates.len(), 10);
+    ///     assert_eq!(updates.last(), Some(&(1000, 1000)));
+    /// }
+    /// ```
+    pub async fn get_balances_bulk(
+        &self,
+        addresses: &[&str],
+        coin_type: Option<&str>,
+        mut progress: Option<&mut dyn FnMut(usize, usize)>,
+    ) -> BulkBalances {
+        let mut report = BulkBalances::default();
+        let mut valid = Vec::with_capacity(addresses.len());
+        for &raw in addresses {
+            match address::normalize(raw) {
+                Some(normalized) => valid.push(normalized),
+                None => report.failures.push((
+                    raw.to_string(),
+                    SuiError::Rpc(format!("Invalid address {}", raw)),
+                )),
+            }
         }
-        response
-            .result
-            .ok_or_else(|| SuiError::Rpc("No result in response".to_string()))
+        let mut done = report.failures.len();
+        let mut batches = futures::stream::iter(valid.chunks(BULK_BATCH_SIZE))
+            .map(|chunk| async move {
+                let addresses: Vec<&str> = chunk.iter().map(String::as_str).collect();
+                (chunk, self.batch_get_balances(&addresses, coin_type).await)
+            })
+            .buffer_unordered(BULK_CONCURRENCY);
+        while let Some((chunk, results)) = batches.next().await {
+            match results {
+                Ok(results) => {
+                    for (address, balance) in chunk.iter().zip(results) {
+                        match balance {
+                            Ok(balance) => {
+                                report.balances.insert(address.clone(), balance);
+                            }
+                            Err(e) => report.failures.push((address.clone(), e)),
+                        }
+                    }
+                }
+                Err(e) => {
+                    let message = e.to_string();
+                    report.failures.extend(chunk.iter().map(|address| {
+                        (
+                            address.clone(),
+                            SuiError::Rpc(format!("Batch failed: {}", message)),
+                        )
+                    }));
+                }
+            }
+            done += chunk.len();
+            if let Some(progress) = progress.as_mut() {
+                progress(done, addresses.len());
+            }
+        }
+        report
     }
 
-    /// # Get object info
+    /// # Get coin metadata
     ///
     /// ## Parameters
-    /// - object_id : object id
+    /// - coin_type : coin type
     ///
     /// ## Returns
-    /// - Ok(Object) : object information
+    /// - Ok(Some(CoinMetadata)) : metadata of the coin type
+    /// - Ok(None) : the coin type has no metadata object
     /// - Err(SuiError) : error
     ///
     /// ## Example
-    /// ```rust
-    /// use sui_client::SuiClient;
+    /// ```no_run
+    /// use sui_network_sdk::SuiClient;
+    /// use sui_network_sdk::global::mainnet;
     /// #[tokio::main]
     /// async fn main() {
-    ///    let client = SuiClient::new_by_rpc_url(mainnet::RPC_URL.to_string());
-    ///    let object = client.get_object("object id").await.unwrap();
-    ///    println!("Object owner: {:?}", object.owner);
+    ///     let client = SuiClient::new_by_rpc_url(mainnet::RPC_URL.to_string());
+    ///     let metadata = client.get_coin_metadata("0x2::sui::SUI").await.unwrap();
+    ///     println!("{:?}", metadata.map(|metadata| metadata.decimals));
     /// }
     /// ```
-    pub async fn get_object(&self, object_id: &str) -> Result<Object, SuiError> {
-        match self
-            .request::<Object>("sui_getObject", vec![object_id.into()])
+    pub async fn get_coin_metadata(
+        &self,
+        coin_type: &str,
+    ) -> Result<Option<CoinMetadata>, SuiError> {
+        // a coin without metadata has a null result
+        self.request("suix_getCoinMetadata", vec![coin_type.into()])
             .await
-        {
-            Ok(o) => Ok(o),
-            Err(e) => Err(e),
-        }
     }
 
-    /// # Get objects owned by address
+    /// # Get total supply
     ///
     /// ## Parameters
-    /// - address : address
+    /// - coin_type : coin type
     ///
     /// ## Returns
-    /// - Ok(Vec<SuiObject>) : all objects owned by this address..
+    /// - Ok(Supply) : total supply of the coin type
     /// - Err(SuiError) : error
     ///
     /// ## Example
     /// ```rust
-    /// use sui_client::SuiClient;
+    /// use std::sync::Arc;
+    /// use sui_network_sdk::SuiClient;
+    /// use sui_network_sdk::transport::MockTransport;
+    /// use sui_network_sdk::types::SuiClientConfig;
+    ///
     /// #[tokio::main]
     /// async fn main() {
-    ///    let client = SuiClient::new_by_rpc_url(mainnet::RPC_URL.to_string());
-    ///    let objects = client.get_objects_owned_by_address("0x123...").await.unwrap();
-    ///    for object in objects {
-    ///        println!("Object ID: {}", object.object_id);
-    ///    }
+    ///     let mock = Arc::new(
+    ///         MockTransport::new()
+    ///             .with_result("suix_getTotalSupply", serde_json::json!({ "value": "1000000000000000000000" }))
+    ///             .with_result("suix_getCoinMetadata", serde_json::Value::Null),
+    ///     );
+    ///     let client = SuiClient::new_with_transport(SuiClientConfig::default(), mock);
+    ///     let supply = client.get_total_supply("0xfeed::meme::MEME").await.unwrap();
+    ///     assert_eq!(supply.value, 1_000_000_000_000_000_000_000);
+    ///     // a coin type without metadata is not an error
+    ///     assert!(client.get_coin_metadata("0xfeed::meme::MEME").await.unwrap().is_none());
     /// }
     /// ```
-    pub async fn get_objects_owned_by_address(
-        &self,
-        address: &str,
-    ) -> Result<Vec<Object>, SuiError> {
-        self.request("sui_getObjectsOwnedByAddress", vec![address.into()])
+    pub async fn get_total_supply(&self, coin_type: &str) -> Result<Supply, SuiError> {
+        self.request("suix_getTotalSupply", vec![coin_type.into()])
             .await
     }
 
-    /// # Get coin vec
+    /// # Get dynamic fields
     ///
     /// ## Parameters
-    /// - address: address
-    /// - coin_type: coin type ("0x2::sui::SUI")
+    /// - parent_id : object holding the dynamic fields, e.g. the id of a `Table` or `Bag`
+    /// - cursor : cursor returned by the previous page
+    /// - limit : maximum number of fields in the page
     ///
     /// ## Returns
-    /// - Ok(Vec<Coin>): coin list
-    /// - Err(SuiError): error
+    /// - Ok(Page<DynamicFieldInfo>) : field page
+    /// - Err(SuiError) : error
     ///
     /// ## Example
     /// ```rust
-    /// use sui_client::SuiClient;
+    /// use std::sync::Arc;
+    /// use sui_network_sdk::SuiClient;
+    /// use sui_network_sdk::transport::MockTransport;
+    /// use sui_network_sdk::types::{DynamicFieldType, SuiClientConfig};
+    ///
     /// #[tokio::main]
     /// async fn main() {
-    ///     let client = SuiClient::new_by_rpc_url(mainnet::RPC_URL.to_string());
-    ///     // Get SUI coins
-    ///     let sui_coins = client.get_coin_vec("0x123...", None).await.unwrap();
-    ///     // Get custom coin type
-    ///     let custom_coins = client.get_coin_vec("0x123...", Some("0x2::usdc::USDC")).await.unwrap();
+    ///     // a kiosk item, named by a struct
+    ///     let name = serde_json::json!({ "type": "0x2::kiosk::Item", "value": { "id": "0xc0ffee" } });
+    ///     let mock = Arc::new(MockTransport::new()
+    ///         .with_result("suix_getDynamicFields", serde_json::json!({
+    ///             "data": [{
+    ///                 "name": name,
+    ///                 "bcsName": "2ZR5C3HKoAsWAuhcnqZy3JW6STWWMCnYaH4xT5L5pZnH",
+    ///                 "type": "DynamicObject",
+    ///                 "objectType": "0xa11::sword::Sword",
+    ///                 "objectId": "0xc0ffee",
+    ///                 "version": "12",
+    ///                 "digest": "9WzSXdwbky8tNbH7juvyaui4QzMUYEjdCEKMrMgLhXHT"
+    ///             }],
+    ///             "nextCursor": "0xc0ffee",
+    ///             "hasNextPage": false
+    ///         }))
+    ///         .with_result("suix_getDynamicFieldObject", serde_json::json!({ "data": {
+    ///             "objectId": "0xc0ffee", "version": "12", "digest": "9WzSXdwbky8tNbH7juvyaui4QzMUYEjdCEKMrMgLhXHT"
+    ///         }})));
+    ///     let client = SuiClient::new_with_transport(SuiClientConfig::default(), mock.clone());
+    ///
+    ///     let page = client.get_dynamic_fields("0xk105c", None, Some(50)).await.unwrap();
+    ///     let field = &page.data[0];
+    ///     assert_eq!(field.field_type, DynamicFieldType::DynamicObject);
+    ///     assert_eq!(field.bcs_name, "2ZR5C3HKoAsWAuhcnqZy3JW6STWWMCnYaH4xT5L5pZnH");
+    ///
+    ///     // the struct name is sent back as it was listed
+    ///     let object = client.get_dynamic_field_object("0xk105c", &field.name).await.unwrap();
+    ///     assert_eq!(object.unwrap().object_id, field.object_id);
+    ///     assert_eq!(mock.requests()[1].params[1], name);
     /// }
     /// ```
-    pub async fn get_coin_vec(
+    pub async fn get_dynamic_fields(
         &self,
-        address: &str,
-        coin_type: Option<&str>,
-    ) -> Result<Vec<Coin>, SuiError> {
-        let coin_type = coin_type.unwrap_or("0x2::sui::SUI");
-        self.request("sui_getCoins", vec![address.into(), coin_type.into()])
-            .await
+        parent_id: &str,
+        cursor: Option<String>,
+        limit: Option<usize>,
+    ) -> Result<Page<DynamicFieldInfo>, SuiError> {
+        self.request(
+            "suix_getDynamicFields",
+            vec![parent_id.into(), cursor.into(), limit.into()],
+        )
+        .await
     }
 
-    /// # Get balance
+    /// # Dynamic fields
+    ///
+    /// Streams all dynamic fields of an object, listing the next page once the fields of
+    /// the previous one were taken.
     ///
     /// ## Parameters
-    /// - address : address
-    /// - coin_type : coin type ("0x2::sui::SUI")
+    /// - parent_id : object holding the dynamic fields
+    ///
+    /// ## Returns
+    /// stream of fields, ending after the first error
+    ///
+    /// ## Example
+    /// ```no_run
+    /// use futures::StreamExt;
+    /// use sui_network_sdk::SuiClient;
+    /// use sui_network_sdk::global::mainnet;
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let client = SuiClient::new_by_rpc_url(mainnet::RPC_URL.to_string());
+    ///     let fields = client.dynamic_fields("0x123...");
+    ///     let mut fields = std::pin::pin!(fields);
+    ///     while let Some(field) = fields.next().await {
+    ///         let field = field.unwrap();
+    ///         println!("{} {}: {}", field.name.name_type, field.name.value, field.object_type);
+    ///     }
+    /// }
+    /// ```
+    pub fn dynamic_fields<'a>(
+        &'a self,
+        parent_id: &'a str,
+    ) -> impl futures::Stream<Item = Result<DynamicFieldInfo, SuiError>> + 'a {
+        let fields = self
+            .dynamic_field_pages(parent_id, MAX_QUERY_PAGE_SIZE)
+            .flat_map(|page| {
+                futures::stream::iter(match page {
+                    Ok(fields) => fields.into_iter().map(Ok).collect(),
+                    Err(e) => vec![Err(e)],
+                })
+            });
+        until_error(fields)
+    }
+
+    /// pages of the dynamic fields of an object, ending after the last page or an error
+    fn dynamic_field_pages<'a>(
+        &'a self,
+        parent_id: &'a str,
+        page_size: usize,
+    ) -> impl futures::Stream<Item = Result<Vec<DynamicFieldInfo>, SuiError>> + 'a {
+        futures::stream::try_unfold(Some(None), move |cursor| async move {
+            let Some(cursor) = cursor else {
+                return Ok::<_, SuiError>(None);
+            };
+            let page = self
+                .get_dynamic_fields(parent_id, cursor, Some(page_size))
+                .await?;
+            let next = match page.next_cursor {
+                Some(cursor) if page.has_next_page => Some(Some(cursor)),
+                _ => None,
+            };
+            Ok(Some((page.data, next)))
+        })
+    }
+
+    /// # Get dynamic field object
+    ///
+    /// Reads the object of a dynamic field by its name, for a dynamic object field the
+    /// object stored under the name.
+    ///
+    /// ## Parameters
+    /// - parent_id : object holding the dynamic field
+    /// - name : field name, see the constructors of [`DynamicFieldName`]
     ///
     /// ## Returns
-    /// - Ok(u64) : balance
+    /// - Ok(Some(Object)) : field object with its type and content
+    /// - Ok(None) : the parent has no field of that name
     /// - Err(SuiError) : error
     ///
     /// ## Example
     /// ```rust
-    /// use sui_client::SuiClient;
+    /// use std::sync::Arc;
+    /// use sui_network_sdk::SuiClient;
+    /// use sui_network_sdk::transport::MockTransport;
+    /// use sui_network_sdk::types::{DynamicFieldName, SuiClientConfig};
+    ///
     /// #[tokio::main]
     /// async fn main() {
-    ///   let client = SuiClient::new_by_rpc_url(mainnet::RPC_URL.to_string());
-    ///   let balance = client.get_balance("0x123...", None).await.unwrap();
-    ///   println!("Balance: {} MIST", balance);
+    ///     let transport = Arc::new(MockTransport::new().with_result(
+    ///         "suix_getDynamicFieldObject",
+    ///         serde_json::json!({ "error": { "code": "dynamicFieldNotFound", "parent_object_id": "0x7ab1e" } }),
+    ///     ));
+    ///     let client = SuiClient::new_with_transport(SuiClientConfig::default(), transport.clone());
+    ///     let item = serde_json::json!({ "id": "0xc0ffee" });
+    ///     let names = [
+    ///         DynamicFieldName::u64(42),
+    ///         DynamicFieldName::address("0xA11CE").unwrap(),
+    ///         DynamicFieldName::string("alice"),
+    ///         DynamicFieldName::struct_(&"0x2::kiosk::Item".parse().unwrap(), item),
+    ///     ];
+    ///     for name in &names {
+    ///         assert!(client.get_dynamic_field_object("0x7ab1e", name).await.unwrap().is_none());
+    ///     }
+    ///     assert!(DynamicFieldName::address("alice").is_err());
+    ///
+    ///     // captured params, integers as strings and addresses in long form
+    ///     let fixtures = [
+    ///         r#"["0x7ab1e",{"type":"u64","value":"42"}]"#,
+    ///         r#"["0x7ab1e",{"type":"address","value":"0x00000000000000000000000000000000000000000000000000000000000a11ce"}]"#,
+    ///         r#"["0x7ab1e",{"type":"0x1::string::String","value":"alice"}]"#,
+    ///         r#"["0x7ab1e",{"type":"0x0000000000000000000000000000000000000000000000000000000000000002::kiosk::Item","value":{"id":"0xc0ffee"}}]"#,
+    ///     ];
+    ///     for (request, fixture) in transport.requests().iter().zip(fixtures) {
+    ///         assert_eq!(request.method, "suix_getDynamicFieldObject");
+    ///         assert_eq!(serde_json::Value::from(request.params.clone()).to_string(), fixture);
+    ///     }
     /// }
     /// ```
-    pub async fn get_balance(
+    pub async fn get_dynamic_field_object(
         &self,
-        address: &str,
-        coin_type: Option<&str>,
-    ) -> Result<u64, SuiError> {
-        let coin_type = coin_type.unwrap_or("0x2::sui::SUI");
-        let result: HashMap<String, Value> = self
-            .request("sui_getBalance", vec![address.into(), coin_type.into()])
+        parent_id: &str,
+        name: &DynamicFieldName,
+    ) -> Result<Option<Object>, SuiError> {
+        let response: ObjectResponse = self
+            .request(
+                "suix_getDynamicFieldObject",
+                vec![parent_id.into(), serde_json::to_value(name)?],
+            )
             .await?;
-        result
-            .get("totalBalance")
-            .and_then(|v| v.as_u64())
-            .ok_or_else(|| SuiError::Rpc("Failed to parse balance".to_string()))
+        match (response.data, response.error) {
+            (Some(object), _) => Ok(Some(object)),
+            (None, Some(error)) if error["code"] == "dynamicFieldNotFound" => Ok(None),
+            (None, error) => Err(SuiError::Rpc(format!(
+                "Dynamic field {} of {} not readable: {}",
+                name.value,
+                parent_id,
+                error.unwrap_or_default()
+            ))),
+        }
     }
 
-    /// # Execute transaction
+    /// # Table entries
+    ///
+    /// Streams the entries of a `Table`, `Bag`, `ObjectTable` or `ObjectBag` page by page,
+    /// only the pages in flight are held in memory. Keys decode from the JSON of the field
+    /// names, values from the `value` field of the field objects, or from the whole value
+    /// object of object tables. Integers the node sends as strings decode into integer
+    /// types. Up to `concurrency` pages fetch their values at once while listing continues.
     ///
     /// ## Parameters
-    /// -  trade_bytes : serialized transaction bytes
-    /// -  sign : transaction signature
-    /// -  pub_key : public key
+    /// - table_id : object id of the table, the `id` field of the `Table` struct
+    /// - options : page size, concurrency and key type filter, `max_entries` is not applied
     ///
     /// ## Returns
-    /// -  Ok(TransactionResponse) : execution transaction result
-    /// -  Err(SuiError) : execution transaction error
+    /// stream of entries, ending after the first error; decoding errors are
+    /// `TableError::Key` or `TableError::Value` naming the key
+    ///
+    /// ## Example
+    /// ```no_run
+    /// use futures::StreamExt;
+    /// use sui_network_sdk::SuiClient;
+    /// use sui_network_sdk::global::mainnet;
+    /// use sui_network_sdk::types::TableReadOptions;
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let client = SuiClient::new_by_rpc_url(mainnet::RPC_URL.to_string());
+    ///     let entries = client.table_entries::<String, u64>("0x123...", TableReadOptions::default());
+    ///     let mut entries = std::pin::pin!(entries);
+    ///     while let Some(entry) = entries.next().await {
+    ///         let (key, value) = entry.unwrap();
+    ///         println!("{} = {}", key, value);
+    ///     }
+    /// }
+    /// ```
+    pub fn table_entries<'a, K, V>(
+        &'a self,
+        table_id: &'a str,
+        options: TableReadOptions,
+    ) -> impl futures::Stream<Item = Result<(K, V), SuiError>> + 'a
+    where
+        K: serde::de::DeserializeOwned + 'a,
+        V: serde::de::DeserializeOwned + 'a,
+    {
+        let page_size = options.page_size.max(1);
+        let key_type = options.key_type;
+        let entries = self
+            .dynamic_field_pages(table_id, page_size)
+            .map(move |fields| {
+                let key_type = key_type.clone();
+                async move { self.table_page::<K, V>(fields?, key_type.as_deref()).await }
+            })
+            .buffered(options.concurrency.max(1))
+            .flat_map(|entries| {
+                futures::stream::iter(match entries {
+                    Ok(entries) => entries.into_iter().map(Ok).collect(),
+                    Err(e) => vec![Err(e)],
+                })
+            });
+        until_error(entries)
+    }
+
+    /// # Read table
+    ///
+    /// Reads a whole table into a map, see [`SuiClient::table_entries`] for the decoding and
+    /// for tables too large to hold in memory.
+    ///
+    /// ## Parameters
+    /// - table_id : object id of the table
+    /// - options : limits of the read
+    ///
+    /// ## Returns
+    /// - Ok(HashMap<K, V>) : table contents
+    /// - Err(SuiError) : `TableError::TooLarge` past `max_entries`, `TableError::Key` or
+    ///   `TableError::Value` naming the key that failed to decode, or a request error
     ///
     /// ## Example
     /// ```rust
-    /// use sui_client::SuiClient;
+    /// use std::sync::Arc;
+    /// use sui_network_sdk::SuiClient;
+    /// use sui_network_sdk::transport::MockTransport;
+    /// use sui_network_sdk::types::{SuiClientConfig, SuiError, TableError, TableReadOptions};
+    ///
     /// #[tokio::main]
     /// async fn main() {
-    ///   let client = SuiClient::new_by_rpc_url(mainnet::RPC_URL.to_string());
-    ///   let tx_bytes = vec![];
-    ///   let signature = vec![];
-    ///   let pub_key = vec![];
-    ///   let response = client.exe_transaction(tx_bytes, signature, pub_key).await.unwrap();
-    ///  println!("Transaction digest: {:?}", response.digest);
+    ///     // a Table<u64, String> listed in two pages
+    ///     let field = |id: &str, key: &str| serde_json::json!({
+    ///         "name": { "type": "u64", "value": key },
+    ///         "type": "DynamicField",
+    ///         "objectType": "0x1::string::String",
+    ///         "objectId": id,
+    ///         "version": "4",
+    ///         "digest": "9WzSXdwbky8tNbH7juvyaui4QzMUYEjdCEKMrMgLhXHT"
+    ///     });
+    ///     let object = |id: &str, value: serde_json::Value| serde_json::json!({ "data": {
+    ///         "objectId": id,
+    ///         "version": "4",
+    ///         "digest": "9WzSXdwbky8tNbH7juvyaui4QzMUYEjdCEKMrMgLhXHT",
+    ///         "content": {
+    ///             "dataType": "moveObject",
+    ///             "fields": { "id": { "id": id }, "name": "1", "value": value }
+    ///         }
+    ///     }});
+    ///     let mock = || {
+    ///         MockTransport::new()
+    ///             .with_result("suix_getDynamicFields", serde_json::json!({
+    ///                 "data": [field("0xf1", "1"), field("0xf2", "2")],
+    ///                 "nextCursor": "0xf2",
+    ///                 "hasNextPage": true
+    ///             }))
+    ///             .with_result("suix_getDynamicFields", serde_json::json!({
+    ///                 "data": [field("0xf3", "3")],
+    ///                 "nextCursor": null,
+    ///                 "hasNextPage": false
+    ///             }))
+    ///             .with_result("sui_multiGetObjects", serde_json::json!([
+    ///                 object("0xf1", "alice".into()),
+    ///                 object("0xf2", "bob".into())
+    ///             ]))
+    ///             .with_result("sui_multiGetObjects", serde_json::json!([object("0xf3", "carol".into())]))
+    ///     };
+    ///     let transport = Arc::new(mock());
+    ///     let client = SuiClient::new_with_transport(SuiClientConfig::default(), transport.clone());
+    ///
+    ///     let table = client
+    ///         .read_table::<u64, String>("0x7ab1e", TableReadOptions::default())
+    ///         .await
+    ///         .unwrap();
+    ///     assert_eq!(table.len(), 3);
+    ///     assert_eq!(table[&2], "bob");
+    ///     assert_eq!(transport.request_count("suix_getDynamicFields"), 2);
+    ///
+    ///     // past the limit
+    ///     let client = SuiClient::new_with_transport(SuiClientConfig::default(), Arc::new(mock()));
+    ///     let options = TableReadOptions { max_entries: 2, ..Default::default() };
+    ///     assert!(matches!(
+    ///         client.read_table::<u64, String>("0x7ab1e", options).await,
+    ///         Err(SuiError::Table(TableError::TooLarge { limit: 2 }))
+    ///     ));
+    ///
+    ///     // the error names the key whose value does not decode
+    ///     let client = SuiClient::new_with_transport(SuiClientConfig::default(), Arc::new(mock()));
+    ///     let error = client.read_table::<u64, u64>("0x7ab1e", TableReadOptions::default()).await;
+    ///     assert!(matches!(
+    ///         error,
+    ///         Err(SuiError::Table(TableError::Value { key, .. })) if key == "\"1\""
+    ///     ));
     /// }
     /// ```
-    pub async fn exe_transaction(
+    pub async fn read_table<K, V>(
         &self,
-        trade_bytes: Vec<u8>,
-        sign: Vec<u8>,
-        pub_key: Vec<u8>,
-    ) -> Result<TransactionResponse, SuiError> {
-        let tx_bytes = BASE64_STANDARD.encode(trade_bytes);
-        let sig_bytes = BASE64_STANDARD.encode(sign);
-        let pub_key_bytes = BASE64_STANDARD.encode(pub_key);
-        let params = vec![
-            tx_bytes.into(),
-            "Ed25519".into(),
-            sig_bytes.into(),
-            pub_key_bytes.into(),
-        ];
-        self.request("sui_executeTransactionBlock", params).await
+        table_id: &str,
+        options: TableReadOptions,
+    ) -> Result<HashMap<K, V>, SuiError>
+    where
+        K: serde::de::DeserializeOwned + Eq + std::hash::Hash,
+        V: serde::de::DeserializeOwned,
+    {
+        let max_entries = options.max_entries;
+        let entries = self.table_entries::<K, V>(table_id, options);
+        let mut entries = std::pin::pin!(entries);
+        let mut table = HashMap::new();
+        while let Some(entry) = entries.next().await {
+            if table.len() == max_entries {
+                return Err(SuiError::Table(TableError::TooLarge { limit: max_entries }));
+            }
+            let (key, value) = entry?;
+            table.insert(key, value);
+        }
+        Ok(table)
     }
 
-    /// # Get trade info
+    /// # Read table value
+    ///
+    /// Reads the value of one key of a `Table`, `Bag`, `ObjectTable` or `ObjectBag`
+    /// without listing the table.
     ///
     /// ## Parameters
-    /// - `digest`: trade hash
+    /// - table_id : object id of the table
+    /// - key : key as a dynamic field name, e.g. `DynamicFieldName::u64(7)`
     ///
     /// ## Returns
-    /// - `Ok(TransactionResponse)`: trade details
-    /// - `Err(SuiError)`: Query error
+    /// - Ok(Some(V)) : value of the key
+    /// - Ok(None) : the table has no such key
+    /// - Err(SuiError) : `TableError::Value` if the value does not decode, or a request error
     ///
     /// ## Example
-    /// ```no_run
-    /// use sui_client::SuiClient;
+    /// ```rust
+    /// use std::sync::Arc;
+    /// use sui_network_sdk::SuiClient;
+    /// use sui_network_sdk::transport::MockTransport;
+    /// use sui_network_sdk::types::{DynamicFieldName, SuiClientConfig};
+    ///
     /// #[tokio::main]
     /// async fn main() {
-    ///    let client = SuiClient::new_by_rpc_url(mainnet::RPC_URL.to_string());
-    ///    let tx_info = client.get_transaction_info("hash").await.unwrap();
-    ///    println!("Transaction status: {:?}", tx_info.effects.status);
+    ///     let transport = Arc::new(MockTransport::new().with_result("suix_getDynamicFieldObject", serde_json::json!({ "data": {
+    ///         "objectId": "0xf1",
+    ///         "version": "4",
+    ///         "digest": "9WzSXdwbky8tNbH7juvyaui4QzMUYEjdCEKMrMgLhXHT",
+    ///         "type": "0x2::dynamic_field::Field<address, u64>",
+    ///         "content": {
+    ///             "dataType": "moveObject",
+    ///             "fields": { "id": { "id": "0xf1" }, "name": "0xa11ce", "value": "1500" }
+    ///         }
+    ///     }})));
+    ///     let client = SuiClient::new_with_transport(SuiClientConfig::default(), transport.clone());
+    ///     let key = DynamicFieldName::address("0xa11ce").unwrap();
+    ///     let balance: Option<u64> = client.read_table_value("0x7ab1e", &key).await.unwrap();
+    ///     assert_eq!(balance, Some(1500));
     /// }
     /// ```
-    pub async fn get_transaction_info(&self, hash: &str) -> Result<TransactionResponse, SuiError> {
-        match self.request("sui_getTransaction", vec![hash.into()]).await {
-            Ok(tr) => Ok(tr),
-            Err(e) => Err(e),
+    pub async fn read_table_value<V>(
+        &self,
+        table_id: &str,
+        key: &DynamicFieldName,
+    ) -> Result<Option<V>, SuiError>
+    where
+        V: serde::de::DeserializeOwned,
+    {
+        let Some(object) = self.get_dynamic_field_object(table_id, key).await? else {
+            return Ok(None);
+        };
+        let value_error = |error: String| {
+            SuiError::Table(TableError::Value {
+                key: key.value.to_string(),
+                error,
+            })
+        };
+        let is_field = object
+            .parsed_type()
+            .is_some_and(ObjectType::is_dynamic_field);
+        let content = object
+            .data
+            .ok_or_else(|| value_error("object has no content".to_string()))?
+            .fields;
+        // object tables return the stored object itself
+        let value = if is_field {
+            &content["value"]
+        } else {
+            &content
+        };
+        decode_json(value).map(Some).map_err(value_error)
+    }
+
+    /// decode the keys of a page of dynamic fields and fetch their values
+    async fn table_page<K, V>(
+        &self,
+        fields: Vec<DynamicFieldInfo>,
+        key_type: Option<&str>,
+    ) -> Result<Vec<(K, V)>, SuiError>
+    where
+        K: serde::de::DeserializeOwned,
+        V: serde::de::DeserializeOwned,
+    {
+        let fields: Vec<DynamicFieldInfo> = fields
+            .into_iter()
+            .filter(|field| {
+                key_type.is_none_or(|key_type| same_type(&field.name.name_type, key_type))
+            })
+            .collect();
+        let ids: Vec<&str> = fields
+            .iter()
+            .map(|field| field.object_id.as_str())
+            .collect();
+        let objects = self
+            .multi_get_objects(
+                &ids,
+                ObjectDataOptions {
+                    show_content: true,
+                    ..Default::default()
+                },
+            )
+            .await;
+        fields
+            .iter()
+            .zip(objects)
+            .map(|(field, object)| {
+                let key_json = field.name.value.to_string();
+                let key = decode_json(&field.name.value).map_err(|error| {
+                    SuiError::Table(TableError::Key {
+                        key: key_json.clone(),
+                        error,
+                    })
+                })?;
+                let value_error = |error: String| {
+                    SuiError::Table(TableError::Value {
+                        key: key_json.clone(),
+                        error,
+                    })
+                };
+                let content = object
+                    .map_err(|e| value_error(e.to_string()))?
+                    .data
+                    .ok_or_else(|| value_error("object has no content".to_string()))?
+                    .fields;
+                let value = match field.field_type {
+                    DynamicFieldType::DynamicField => &content["value"],
+                    DynamicFieldType::DynamicObject => &content,
+                    DynamicFieldType::Unknown => {
+                        return Err(value_error("unknown dynamic field kind".to_string()));
+                    }
+                };
+                Ok((key, decode_json(value).map_err(value_error)?))
+            })
+            .collect()
+    }
+}
+
+/// decode JSON, integers sent as strings also decode into integer types
+fn decode_json<T: serde::de::DeserializeOwned>(value: &Value) -> Result<T, String> {
+    T::deserialize(value).or_else(|error| match value {
+        Value::String(s) if !s.is_empty() && s.bytes().all(|b| b.is_ascii_digit()) => {
+            serde_json::from_str(s).map_err(|_| error.to_string())
         }
+        _ => Err(error.to_string()),
+    })
+}
+
+/// method of a request body, a batch holds calls of one method
+fn body_method(body: &Value) -> &str {
+    match body {
+        Value::Array(requests) => requests.first().and_then(|r| r["method"].as_str()),
+        request => request["method"].as_str(),
+    }
+    .unwrap_or_default()
+}
+
+/// whether the node rejected a batch for not supporting batches, an invalid request or
+/// an error naming batches, rather than failing it for another reason
+fn batch_unsupported(error: &RpcError) -> bool {
+    error.code == -32600 || error.message.to_lowercase().contains("batch")
+}
+
+/// http client of the config's timeouts, resolving hosts with the overrides
+fn http_client(
+    config: &SuiClientConfig,
+    dns_overrides: &DnsOverrides,
+) -> Result<reqwest::Client, SuiError> {
+    let mut builder = dns_overrides.http_client_builder();
+    if let Some(timeout) = config.timeout {
+        builder = builder.timeout(timeout);
+    }
+    if let Some(connect_timeout) = config.connect_timeout {
+        builder = builder.connect_timeout(connect_timeout);
+    }
+    Ok(builder.build()?)
+}
+
+/// whether resending a request body can't change chain state, false when it executes a
+/// transaction
+fn is_idempotent(body: &Value) -> bool {
+    let executes = |request: &Value| {
+        request["method"]
+            .as_str()
+            .is_some_and(|method| method.ends_with("executeTransactionBlock"))
+    };
+    match body {
+        Value::Array(requests) => !requests.iter().any(executes),
+        request => !executes(request),
+    }
+}
+
+/// whether two Move types are the same, with addresses normalized
+fn same_type(a: &str, b: &str) -> bool {
+    match (a.parse::<ptb::TypeTag>(), b.parse::<ptb::TypeTag>()) {
+        (Ok(a), Ok(b)) => a == b,
+        _ => a == b,
     }
 }
+
+/// stream ending after its first error
+fn until_error<T>(
+    stream: impl futures::Stream<Item = Result<T, SuiError>>,
+) -> impl futures::Stream<Item = Result<T, SuiError>> {
+    stream.scan(false, |failed, item| {
+        let item = (!*failed).then_some(item);
+        *failed = item.as_ref().is_some_and(Result::is_err);
+        futures::future::ready(item)
+    })
+}
+
+/// page of `suix_getEpochs`, the cursor is an epoch sent as a string
+#[derive(serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct EpochPage {
+    data: Vec<EpochInfo>,
+    #[serde(default, with = "option_string_number")]
+    next_cursor: Option<u64>,
+    has_next_page: bool,
+}
+
+/// page of `sui_getCheckpoints`, the cursor is a sequence number sent as a string
+#[derive(serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CheckpointPage {
+    data: Vec<Checkpoint>,
+    #[serde(default, with = "option_string_number")]
+    next_cursor: Option<u64>,
+    has_next_page: bool,
+}
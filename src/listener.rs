@@ -1,15 +1,34 @@
-use crate::types::SuiError;
-use futures::{SinkExt, StreamExt};
+use crate::SuiClient;
+use crate::coin::normalize_coin_type;
+use crate::transport::DnsOverrides;
+use crate::types::{
+    EventFilter, ObjectChange, PublishedPackage, SuiError, TransactionEffects, TransactionFilter,
+    TransactionResponse, address,
+};
+use futures::{FutureExt, SinkExt, Stream, StreamExt};
+use serde::{Deserialize, Serialize};
 use serde_json::Value;
-use tokio_tungstenite::{connect_async, tungstenite::Message};
+use std::collections::{BTreeMap, HashMap};
+use std::fs::File;
+use std::io::{BufRead, BufReader, LineWriter, Write};
+use std::path::Path;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, Weak};
+use std::task::{Context, Poll, Waker};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+use tokio::sync::broadcast;
+use tokio::task::{AbortHandle, JoinHandle};
+use tokio_tungstenite::tungstenite::Message;
 
 /// # Sui Network Listener
 ///
 /// Use WebSocket real-time monitoring capabilities for Sui blockchain events, Supports transaction tracking, event monitoring, and address-specific notifications.
 ///
 /// ## Example
-/// ```rust
-/// use sui_client::listener::Listener;
+/// ```no_run
+/// use sui_network_sdk::listener::Listener;
+/// use sui_network_sdk::global::mainnet;
 ///
 /// #[tokio::main]
 /// async fn main() {
@@ -21,6 +40,9 @@ use tokio_tungstenite::{connect_async, tungstenite::Message};
 /// ```
 pub struct Listener {
     pub url: String, // websocket url
+    config: ListenerConfig,
+    replay: Option<Arc<Vec<RawFrame>>>, // recorded frames replayed instead of the websocket
+    dns: DnsOverrides,
 }
 
 impl Listener {
@@ -34,11 +56,151 @@ impl Listener {
     ///
     /// ## Example
     /// ```no_run
-    /// use sui_client::listener::Listener;
+    /// use sui_network_sdk::listener::Listener;
+    /// use sui_network_sdk::global::mainnet;
     /// let listener = Listener::new(mainnet::WSS_URL.to_string());
     /// ```
     pub fn new(url: String) -> Self {
-        Self { url }
+        Self {
+            url,
+            config: ListenerConfig::default(),
+            replay: None,
+            dns: DnsOverrides::default(),
+        }
+    }
+
+    /// # Set the listener config
+    ///
+    /// ## Parameters
+    /// - config : listener config
+    ///
+    /// ## Example
+    /// ```no_run
+    /// use sui_network_sdk::listener::{Listener, ListenerConfig};
+    /// use sui_network_sdk::global::mainnet;
+    /// let config = ListenerConfig::default().record_to_file("frames.ndjson").unwrap();
+    /// let listener = Listener::new(mainnet::WSS_URL.to_string()).with_config(config);
+    /// ```
+    pub fn with_config(mut self, config: ListenerConfig) -> Self {
+        self.config = config;
+        self
+    }
+
+    /// # Set dns overrides
+    ///
+    /// Resolves the websocket host like the client's http transport, see
+    /// [`SuiClient::dns_overrides`].
+    ///
+    /// ## Parameters
+    /// - overrides : dns overrides
+    ///
+    /// [`SuiClient::dns_overrides`]: crate::SuiClient::dns_overrides
+    pub fn with_dns_overrides(mut self, overrides: DnsOverrides) -> Self {
+        self.dns = overrides;
+        self
+    }
+
+    /// # Replay from file
+    ///
+    /// Creates a listener reading the inbound frames of an NDJSON recording, see
+    /// [`ListenerConfig::record_to_file`], instead of a websocket. Frames go through the same
+    /// parsing and dispatch as live ones, the listen methods return once the recording ends.
+    ///
+    /// ## Parameters
+    /// - path : NDJSON recording
+    ///
+    /// ## Returns
+    /// - Ok(Listener) : replaying listener
+    /// - Err(SuiError) : the file can't be read or a line is not a frame
+    ///
+    /// ## Example
+    /// ```rust
+    /// use std::sync::{Arc, Mutex};
+    /// use sui_network_sdk::listener::{FrameDirection, Listener, ListenerConfig, RawFrame};
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let path = std::env::temp_dir().join("sui-network-sdk-replay.ndjson");
+    ///     let frames = [
+    ///         RawFrame::new(FrameDirection::Outbound, r#"{"method":"sui_subscribeTransaction"}"#),
+    ///         RawFrame::new(FrameDirection::Inbound, r#"{"jsonrpc":"2.0","result":7}"#),
+    ///         RawFrame::new(FrameDirection::Inbound, r#"{"params":{"result":{"digest":"A1"}}}"#),
+    ///         RawFrame::new(FrameDirection::Inbound, r#"{"params":{"result":{"digest":"B2"}}}"#),
+    ///     ];
+    ///     let lines: Vec<String> = frames.iter().map(|f| serde_json::to_string(f).unwrap()).collect();
+    ///     std::fs::write(&path, lines.join("\n")).unwrap();
+    ///
+    ///     // the tap sees every inbound frame, typed delivery only the notifications
+    ///     let tapped = Arc::new(Mutex::new(Vec::new()));
+    ///     let tap = tapped.clone();
+    ///     let config = ListenerConfig::default()
+    ///         .raw_tap(move |frame: &RawFrame| tap.lock().unwrap().push(frame.text.clone()));
+    ///     let listener = Listener::replay_from_file(&path).unwrap().with_config(config);
+    ///     let mut digests = Vec::new();
+    ///     listener.listen_transactions(|digest| digests.push(digest)).await.unwrap();
+    ///     assert_eq!(digests, vec!["A1", "B2"]);
+    ///     assert_eq!(tapped.lock().unwrap().len(), 3);
+    ///     std::fs::remove_file(&path).unwrap();
+    /// }
+    /// ```
+    pub fn replay_from_file(path: impl AsRef<Path>) -> Result<Self, SuiError> {
+        let path = path.as_ref();
+        let mut frames = Vec::new();
+        for line in BufReader::new(File::open(path)?).lines() {
+            let line = line?;
+            if !line.trim().is_empty() {
+                frames.push(serde_json::from_str::<RawFrame>(&line)?);
+            }
+        }
+        Ok(Self {
+            url: path.display().to_string(),
+            config: ListenerConfig::default(),
+            replay: Some(Arc::new(frames)),
+            dns: DnsOverrides::default(),
+        })
+    }
+
+    /// open the subscription, returns the inbound text frames
+    async fn open(&self, subscribe: Value) -> Result<FrameStream, SuiError> {
+        let tap = self.config.raw_tap.clone();
+        if let Some(frames) = &self.replay {
+            let inbound: Vec<RawFrame> = frames
+                .iter()
+                .filter(|frame| frame.direction == FrameDirection::Inbound)
+                .cloned()
+                .collect();
+            return Ok(Box::pin(futures::stream::iter(inbound).map(move |frame| {
+                if let Some(tap) = &tap {
+                    tap.frame(&frame);
+                }
+                Ok(frame.text)
+            })));
+        }
+        let ws_stream = self.dns.connect_websocket(&self.url).await?;
+        let (mut write, read) = ws_stream.split();
+        let subscribe = subscribe.to_string();
+        if let Some(tap) = &tap {
+            tap.frame(&RawFrame::new(FrameDirection::Outbound, &subscribe));
+        }
+        write.send(Message::Text(subscribe.into())).await?;
+        Ok(Box::pin(
+            read.take_while(|message| {
+                futures::future::ready(!matches!(message, Ok(Message::Close(_))))
+            })
+            .filter_map(move |message| {
+                let frame = match message {
+                    Ok(Message::Text(text)) => {
+                        if let Some(tap) = &tap {
+                            tap.frame(&RawFrame::new(FrameDirection::Inbound, &text));
+                        }
+                        Some(Ok(text.to_string()))
+                    }
+                    Err(e) => Some(Err(SuiError::WebSocket(e.to_string()))),
+                    _ => None,
+                };
+                futures::future::ready(frame)
+            }),
+        ))
     }
 
     /// # Listen transactions
@@ -51,8 +213,9 @@ impl Listener {
     /// - Err(SuiError) : WebSocket error
     ///
     /// ## Example
-    /// ```rust
-    /// use sui_client::listener::Listener;
+    /// ```no_run
+    /// use sui_network_sdk::listener::Listener;
+    /// use sui_network_sdk::global::mainnet;
     ///
     /// #[tokio::main]
     /// async fn main() {
@@ -66,37 +229,24 @@ impl Listener {
     where
         F: FnMut(String),
     {
-        let (ws_stream, _) = connect_async(&self.url).await?;
-        let (mut write, mut read) = ws_stream.split();
         let msg = serde_json::json!({
             "jsonrpc": "2.0",
             "id": 1,
             "method": "sui_subscribeTransaction",
             "params": [{"All": []}]
         });
-        write.send(Message::Text(msg.to_string().into())).await?;
-        while let Some(message) = read.next().await {
-            match message {
-                Ok(Message::Text(text)) => {
-                    if let Ok(event) = serde_json::from_str::<Value>(&text) {
-                        if let Some(tx_digest) = event
-                            .get("params")
-                            .and_then(|p| p.get("result"))
-                            .and_then(|r| r.get("digest"))
-                            .and_then(|d| d.as_str())
-                        {
-                            // new transactions
-                            callback(tx_digest.to_string());
-                        }
-                    }
-                }
-                Ok(Message::Close(_)) => {
-                    break;
-                }
-                Err(e) => {
-                    return Err(SuiError::WebSocket(e.to_string()));
-                }
-                _ => {}
+        let mut frames = self.open(msg).await?;
+        while let Some(text) = frames.next().await {
+            let text = text?;
+            if let Ok(event) = serde_json::from_str::<Value>(&text)
+                && let Some(tx_digest) = event
+                    .get("params")
+                    .and_then(|p| p.get("result"))
+                    .and_then(|r| r.get("digest"))
+                    .and_then(|d| d.as_str())
+            {
+                // new transactions
+                callback(tx_digest.to_string());
             }
         }
         Ok(())
@@ -112,8 +262,9 @@ impl Listener {
     /// - Err(SuiError) : WebSocket Error.
     ///
     /// ## Example
-    /// ```rust
-    /// use sui_client::listener::Listener;
+    /// ```no_run
+    /// use sui_network_sdk::listener::Listener;
+    /// use sui_network_sdk::global::mainnet;
     /// #[tokio::main]
     /// async fn main() {
     /// let listener = Listener::new(mainnet::WSS_URL.to_string());
@@ -126,30 +277,18 @@ impl Listener {
     where
         F: FnMut(Value),
     {
-        let (ws_stream, _) = connect_async(&self.url).await?;
-        let (mut write, mut read) = ws_stream.split();
         let msg = serde_json::json!({
             "jsonrpc": "2.0",
             "id": 1,
             "method": "sui_subscribeEvent",
             "params": [{"All": []}]
         });
-        write.send(Message::Text(msg.to_string().into())).await?;
-        while let Some(message) = read.next().await {
-            match message {
-                Ok(Message::Text(text)) => {
-                    if let Ok(event) = serde_json::from_str::<Value>(&text) {
-                        // new event
-                        callback(event);
-                    }
-                }
-                Ok(Message::Close(_)) => {
-                    break;
-                }
-                Err(e) => {
-                    return Err(SuiError::WebSocket(e.to_string()));
-                }
-                _ => {}
+        let mut frames = self.open(msg).await?;
+        while let Some(text) = frames.next().await {
+            let text = text?;
+            if let Ok(event) = serde_json::from_str::<Value>(&text) {
+                // new event
+                callback(event);
             }
         }
         Ok(())
@@ -169,8 +308,9 @@ impl Listener {
     /// This method does not care whether the address is a sender or a receiver.
     ///
     /// ## Example
-    /// ```rust
-    /// # use sui_client::listener::Listener;
+    /// ```no_run
+    /// # use sui_network_sdk::listener::Listener;
+    /// # use sui_network_sdk::global::mainnet;
     /// # #[tokio::main]
     /// # async fn main() {
     /// # let listener = Listener::new(mainnet::WSS_URL.to_string());
@@ -189,38 +329,1927 @@ impl Listener {
     where
         F: FnMut(String),
     {
-        let (ws_stream, _) = connect_async(&self.url).await?;
-        let (mut write, mut read) = ws_stream.split();
         let msg = serde_json::json!({
             "jsonrpc": "2.0",
             "id": 1,
             "method": "sui_subscribeTransaction",
             "params": [{"ToOrFromAddress": {"addr": address}}]
         });
-        write.send(Message::Text(msg.to_string().into())).await?;
-        while let Some(message) = read.next().await {
-            match message {
-                Ok(Message::Text(text)) => {
-                    if let Ok(event) = serde_json::from_str::<Value>(&text) {
-                        if let Some(tx_digest) = event
-                            .get("params")
-                            .and_then(|p| p.get("result"))
-                            .and_then(|r| r.get("digest"))
-                            .and_then(|d| d.as_str())
-                        {
-                            callback(tx_digest.to_string());
-                        }
+        let mut frames = self.open(msg).await?;
+        while let Some(text) = frames.next().await {
+            let text = text?;
+            if let Ok(event) = serde_json::from_str::<Value>(&text)
+                && let Some(tx_digest) = event
+                    .get("params")
+                    .and_then(|p| p.get("result"))
+                    .and_then(|r| r.get("digest"))
+                    .and_then(|d| d.as_str())
+            {
+                callback(tx_digest.to_string());
+            }
+        }
+        Ok(())
+    }
+
+    /// # Listen package publishes
+    ///
+    /// Watches transactions for published packages, optionally only those sent by one
+    /// address. Notifications without object changes are enriched through the client,
+    /// only for transactions creating immutable objects since packages are created
+    /// immutable.
+    ///
+    /// ## Parameters
+    /// - client : client used to fetch object changes missing from notifications
+    /// - sender : publisher address, all publishers when `None`
+    /// - callback : callback function
+    ///
+    /// ## Returns
+    /// - Ok(()) : Listening Successfully.
+    /// - Err(SuiError) : WebSocket or RPC Error.
+    ///
+    /// ## Example
+    /// ```no_run
+    /// use sui_network_sdk::SuiClient;
+    /// use sui_network_sdk::listener::Listener;
+    /// use sui_network_sdk::global::mainnet;
+    /// #[tokio::main]
+    /// async fn main() {
+    /// let client = SuiClient::new_by_rpc_url(mainnet::RPC_URL.to_string());
+    /// let listener = Listener::new(mainnet::WSS_URL.to_string());
+    /// listener.listen_publishes(&client, Some("0x7d20dcdb2bca4f508ea9613994683eb4e76e9c4ed371169677c1be02aaf0b58e"), |package| {
+    ///       println!("{} published {:?}", package.package_id, package.modules);
+    /// }).await.unwrap();
+    /// }
+    /// ```
+    pub async fn listen_publishes<F>(
+        &self,
+        client: &SuiClient,
+        sender: Option<&str>,
+        mut callback: F,
+    ) -> Result<(), SuiError>
+    where
+        F: FnMut(PublishedPackage),
+    {
+        let filter = match sender {
+            Some(sender) => serde_json::json!({"FromAddress": sender}),
+            None => serde_json::json!({"All": []}),
+        };
+        let msg = serde_json::json!({
+            "jsonrpc": "2.0",
+            "id": 1,
+            "method": "sui_subscribeTransaction",
+            "params": [filter]
+        });
+        let mut frames = self.open(msg).await?;
+        while let Some(text) = frames.next().await {
+            let text = text?;
+            let Some(result) = serde_json::from_str::<Value>(&text)
+                .ok()
+                .and_then(|event| event.get("params")?.get("result").cloned())
+            else {
+                continue;
+            };
+            let packages = match serde_json::from_value::<TransactionResponse>(result.clone())
+                .ok()
+                .as_ref()
+                .and_then(PublishedPackage::from_response)
+            {
+                Some(packages) => packages,
+                None => {
+                    let Some(digest) = result
+                        .get("digest")
+                        .or_else(|| result.get("transactionDigest"))
+                        .and_then(|d| d.as_str())
+                    else {
+                        continue;
+                    };
+                    if !may_publish(&result) {
+                        continue;
                     }
+                    let options =
+                        serde_json::json!({ "showObjectChanges": true, "showInput": true });
+                    let response = client.get_transaction_block(digest, options).await?;
+                    PublishedPackage::from_response(&response).unwrap_or_default()
                 }
-                Ok(Message::Close(_)) => {
-                    break;
-                }
-                Err(e) => {
-                    return Err(SuiError::WebSocket(e.to_string()));
+            };
+            for package in packages {
+                callback(package);
+            }
+        }
+        Ok(())
+    }
+    /// # Listen events in order
+    ///
+    /// Delivers the events of each transaction in `eventSeq` order without gaps. Events
+    /// arriving ahead of a missing one are held back until the missing ones arrive, for at
+    /// most `max_wait`. When the wait expires, or the subscription ends, the listener
+    /// raises `ListenerEvent::GapDetected` with the missing sequence numbers. With a
+    /// client, the transaction's full event list is then fetched to fill the gap,
+    /// otherwise the held back events follow the gap notice. Events missing after the
+    /// last one received can't be detected. Missing events arriving after their gap was
+    /// reported are dropped, like duplicates.
+    ///
+    /// ## Parameters
+    /// - max_wait : longest time events are held back for a missing one
+    /// - client : client fetching the events of transactions with gaps, `None` to skip
+    /// - callback : receives `ListenerEvent::Message` with event payloads and
+    ///   `ListenerEvent::GapDetected`
+    ///
+    /// ## Returns
+    /// - Ok(()) : Listening Successfully.
+    /// - Err(SuiError) : WebSocket Error.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use std::sync::Arc;
+    /// use std::time::Duration;
+    /// use sui_network_sdk::SuiClient;
+    /// use sui_network_sdk::listener::{FrameDirection, Listener, ListenerEvent, RawFrame};
+    /// use sui_network_sdk::transport::MockTransport;
+    /// use sui_network_sdk::types::SuiClientConfig;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let event = |digest: &str, seq: u64| RawFrame::new(
+    ///         FrameDirection::Inbound,
+    ///         &serde_json::json!({ "params": { "result": {
+    ///             "id": { "txDigest": digest, "eventSeq": seq.to_string() }
+    ///         }}}).to_string(),
+    ///     );
+    ///     // A arrives out of order, B never receives its event 1
+    ///     let frames = [event("A", 1), event("B", 0), event("A", 0), event("B", 2), event("A", 2)];
+    ///     let path = std::env::temp_dir().join("sui-network-sdk-ordered.ndjson");
+    ///     let lines: Vec<String> = frames.iter().map(|f| serde_json::to_string(f).unwrap()).collect();
+    ///     std::fs::write(&path, lines.join("\n")).unwrap();
+    ///
+    ///     let listener = Listener::replay_from_file(&path).unwrap();
+    ///     let mut delivered = Vec::new();
+    ///     listener.listen_events_ordered(Duration::from_secs(5), None, |event| match event {
+    ///         ListenerEvent::Message(event) => delivered.push(format!(
+    ///             "{}{}",
+    ///             event["id"]["txDigest"].as_str().unwrap(),
+    ///             event["id"]["eventSeq"].as_str().unwrap()
+    ///         )),
+    ///         ListenerEvent::GapDetected { digest, missing } => {
+    ///             delivered.push(format!("gap {} {:?}", digest, missing))
+    ///         }
+    ///         _ => {}
+    ///     }).await.unwrap();
+    ///     assert_eq!(delivered, vec!["B0", "A0", "A1", "A2", "gap B [1]", "B2"]);
+    ///
+    ///     // with a client the gap is filled from the transaction's event list
+    ///     let ids = |seqs: &[u64]| serde_json::Value::Array(seqs.iter().map(|seq| {
+    ///         serde_json::json!({ "id": { "txDigest": "B", "eventSeq": seq.to_string() } })
+    ///     }).collect());
+    ///     let mock = MockTransport::new().with_result("sui_getEvents", ids(&[0, 1, 2]));
+    ///     let client = SuiClient::new_with_transport(SuiClientConfig::default(), Arc::new(mock));
+    ///     let mut filled = Vec::new();
+    ///     listener.listen_events_ordered(Duration::from_secs(5), Some(&client), |event| {
+    ///         if let ListenerEvent::Message(event) = event {
+    ///             filled.push(event["id"]["eventSeq"].as_str().unwrap().to_string());
+    ///         }
+    ///     }).await.unwrap();
+    ///     assert_eq!(filled, vec!["0", "0", "1", "2", "1", "2"]);
+    ///     std::fs::remove_file(&path).unwrap();
+    /// }
+    /// ```
+    pub async fn listen_events_ordered<F>(
+        &self,
+        max_wait: Duration,
+        client: Option<&SuiClient>,
+        mut callback: F,
+    ) -> Result<(), SuiError>
+    where
+        F: FnMut(ListenerEvent),
+    {
+        let msg = serde_json::json!({
+            "jsonrpc": "2.0",
+            "id": 1,
+            "method": "sui_subscribeEvent",
+            "params": [{"All": []}]
+        });
+        let mut frames = self.open(msg).await?;
+        let mut sequencer = EventSequencer::new(max_wait);
+        loop {
+            let frame = match sequencer.next_deadline() {
+                Some(deadline) => tokio::select! {
+                    frame = frames.next() => frame,
+                    _ = tokio::time::sleep_until(deadline) => {
+                        let gaps = sequencer.expire(Some(Instant::now()));
+                        for (digest, next) in fill_gaps(gaps, client, &mut callback).await {
+                            sequencer.skip_to(&digest, next);
+                        }
+                        continue;
+                    }
+                },
+                None => frames.next().await,
+            };
+            let Some(text) = frame else {
+                break;
+            };
+            if let Some(event) = serde_json::from_str::<Value>(&text?)
+                .ok()
+                .and_then(|event| event.get("params")?.get("result").cloned())
+            {
+                for event in sequencer.push(event, Instant::now()) {
+                    callback(ListenerEvent::Message(event));
                 }
-                _ => {}
             }
         }
+        // the subscription ended, held back events won't be completed anymore
+        fill_gaps(sequencer.expire(None), client, &mut callback).await;
         Ok(())
     }
 }
+
+/// whether a transaction notification may contain a publish, packages are created
+/// immutable, notifications without effects may contain anything
+fn may_publish(result: &Value) -> bool {
+    let effects = result.get("effects").unwrap_or(result);
+    match effects.get("created").and_then(|c| c.as_array()) {
+        Some(created) => created
+            .iter()
+            .any(|object| object.get("owner").and_then(|o| o.as_str()) == Some("Immutable")),
+        None => true,
+    }
+}
+
+/// how long a transaction whose gap was reported drops its late events
+const GAP_TOMBSTONE_TTL: Duration = Duration::from_secs(600);
+
+/// events of one transaction not yet delivered
+struct PendingEvents {
+    next: u64,                  // sequence number delivered next
+    held: BTreeMap<u64, Value>, // events ahead of a missing one
+    deadline: Option<Instant>,  // when the missing events are given up
+    last_seen: Instant,
+    gap_reported: bool, // kept as a tombstone for late events
+}
+
+/// gap of a transaction: digest, missing sequence numbers, held back events
+type EventGap = (String, Vec<u64>, Vec<Value>);
+
+/// orders the events of each transaction by sequence number
+struct EventSequencer {
+    max_wait: Duration,
+    pending: HashMap<String, PendingEvents>,
+    next_prune: Instant,
+}
+
+impl EventSequencer {
+    fn new(max_wait: Duration) -> Self {
+        Self {
+            max_wait,
+            pending: HashMap::new(),
+            next_prune: Instant::now() + max_wait,
+        }
+    }
+
+    /// add an event, returns the events now deliverable in order
+    fn push(&mut self, event: Value, now: Instant) -> Vec<Value> {
+        let digest = event
+            .get("id")
+            .and_then(|id| id.get("txDigest"))
+            .and_then(|d| d.as_str())
+            .map(str::to_string);
+        let seq = event_seq(&event);
+        // events without an id can't be ordered
+        let (Some(digest), Some(seq)) = (digest, seq) else {
+            return vec![event];
+        };
+        // positions of complete transactions are kept for a window to drop duplicates
+        if now >= self.next_prune {
+            let max_wait = self.max_wait;
+            self.pending.retain(|_, pending| {
+                let keep = match pending.gap_reported {
+                    true => GAP_TOMBSTONE_TTL.max(max_wait),
+                    false => max_wait,
+                };
+                !pending.held.is_empty() || now.duration_since(pending.last_seen) < keep
+            });
+            self.next_prune = now + max_wait;
+        }
+        let pending = self.pending.entry(digest).or_insert_with(|| PendingEvents {
+            next: 0,
+            held: BTreeMap::new(),
+            deadline: None,
+            last_seen: now,
+            gap_reported: false,
+        });
+        pending.last_seen = now;
+        if seq < pending.next {
+            // already delivered
+            return Vec::new();
+        }
+        pending.held.insert(seq, event);
+        let mut ready = Vec::new();
+        while let Some(event) = pending.held.remove(&pending.next) {
+            ready.push(event);
+            pending.next += 1;
+        }
+        pending.deadline = match pending.held.is_empty() {
+            true => None,
+            false => pending.deadline.or(Some(now + self.max_wait)),
+        };
+        ready
+    }
+
+    /// earliest deadline of held back events
+    fn next_deadline(&self) -> Option<tokio::time::Instant> {
+        self.pending
+            .values()
+            .filter_map(|pending| pending.deadline)
+            .min()
+            .map(tokio::time::Instant::from_std)
+    }
+
+    /// give up on missing events whose deadline passed, all of them without `now`; the
+    /// transaction stays as a tombstone past its held events so late ones are dropped
+    fn expire(&mut self, now: Option<Instant>) -> Vec<EventGap> {
+        let expired: Vec<String> = self
+            .pending
+            .iter()
+            .filter(|(_, pending)| {
+                pending
+                    .deadline
+                    .is_some_and(|deadline| now.is_none_or(|now| deadline <= now))
+            })
+            .map(|(digest, _)| digest.clone())
+            .collect();
+        let mut gaps = Vec::with_capacity(expired.len());
+        for digest in expired {
+            if let Some(pending) = self.pending.get_mut(&digest) {
+                let last = pending
+                    .held
+                    .keys()
+                    .next_back()
+                    .copied()
+                    .unwrap_or(pending.next);
+                let missing = (pending.next..last)
+                    .filter(|seq| !pending.held.contains_key(seq))
+                    .collect();
+                let held = std::mem::take(&mut pending.held);
+                pending.next = pending.next.max(last + 1);
+                pending.deadline = None;
+                pending.last_seen = now.unwrap_or_else(Instant::now);
+                pending.gap_reported = true;
+                gaps.push((digest, missing, held.into_values().collect()));
+            }
+        }
+        gaps.sort_by(|a, b| a.0.cmp(&b.0));
+        gaps
+    }
+
+    /// mark the events of a transaction before `next` delivered, e.g. fetched for a gap
+    fn skip_to(&mut self, digest: &str, next: u64) {
+        if let Some(pending) = self.pending.get_mut(digest)
+            && pending.held.is_empty()
+        {
+            pending.next = pending.next.max(next);
+        }
+    }
+}
+
+/// report gaps, then deliver the fetched events from the first missing one on, or the
+/// held back events when there is no client or the fetch fails; returns per transaction
+/// the sequence number after the last event delivered
+async fn fill_gaps<F>(
+    gaps: Vec<EventGap>,
+    client: Option<&SuiClient>,
+    callback: &mut F,
+) -> Vec<(String, u64)>
+where
+    F: FnMut(ListenerEvent),
+{
+    let mut delivered = Vec::with_capacity(gaps.len());
+    for (digest, missing, held) in gaps {
+        let first = missing.first().copied().unwrap_or(0);
+        callback(ListenerEvent::GapDetected {
+            digest: digest.clone(),
+            missing,
+        });
+        let fetched = match client {
+            Some(client) => client
+                .request::<Vec<Value>>("sui_getEvents", vec![digest.as_str().into()])
+                .await
+                .ok(),
+            None => None,
+        };
+        let events = match fetched {
+            Some(events) => events
+                .into_iter()
+                .filter(|event| event_seq(event).is_some_and(|seq| seq >= first))
+                .collect(),
+            None => held,
+        };
+        let mut next = first;
+        for event in events {
+            if let Some(seq) = event_seq(&event) {
+                next = next.max(seq + 1);
+            }
+            callback(ListenerEvent::Message(event));
+        }
+        delivered.push((digest, next));
+    }
+    delivered
+}
+
+/// sequence number of an event within its transaction
+fn event_seq(event: &Value) -> Option<u64> {
+    event
+        .get("id")
+        .and_then(|id| id.get("eventSeq"))
+        .and_then(|seq| match seq {
+            Value::String(s) => s.parse::<u64>().ok(),
+            seq => seq.as_u64(),
+        })
+}
+
+/// inbound text frames of a subscription
+type FrameStream = Pin<Box<dyn Stream<Item = Result<String, SuiError>> + Send>>;
+
+/// # Frame direction
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum FrameDirection {
+    /// sent by the node
+    Inbound,
+    /// sent to the node
+    Outbound,
+}
+
+/// # Raw frame
+///
+/// Websocket text frame as exchanged with the node, one NDJSON line of a recording.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RawFrame {
+    pub direction: FrameDirection,
+    pub timestamp_ms: u64, // unix time the frame was sent or received
+    pub text: String,
+}
+
+impl RawFrame {
+    /// frame sent or received now
+    pub fn new(direction: FrameDirection, text: &str) -> Self {
+        let timestamp_ms = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_millis() as u64)
+            .unwrap_or(0);
+        Self {
+            direction,
+            timestamp_ms,
+            text: text.to_string(),
+        }
+    }
+}
+
+/// # Raw tap
+///
+/// Receives every raw frame of a listener, independently of typed delivery. Implemented
+/// for callbacks and for channel senders.
+pub trait RawTap: Send + Sync {
+    fn frame(&self, frame: &RawFrame);
+}
+
+impl<F> RawTap for F
+where
+    F: Fn(&RawFrame) + Send + Sync,
+{
+    fn frame(&self, frame: &RawFrame) {
+        self(frame)
+    }
+}
+
+impl RawTap for tokio::sync::mpsc::UnboundedSender<RawFrame> {
+    fn frame(&self, frame: &RawFrame) {
+        // a dropped receiver only stops the tap
+        let _ = self.send(frame.clone());
+    }
+}
+
+impl RawTap for std::sync::mpsc::Sender<RawFrame> {
+    fn frame(&self, frame: &RawFrame) {
+        let _ = self.send(frame.clone());
+    }
+}
+
+/// writes frames to a file as NDJSON
+struct FileRecorder(Mutex<LineWriter<File>>);
+
+impl RawTap for FileRecorder {
+    fn frame(&self, frame: &RawFrame) {
+        if let Ok(line) = serde_json::to_string(frame) {
+            let mut file = self.0.lock().unwrap_or_else(|e| e.into_inner());
+            // recording is best effort, it never interrupts listening
+            let _ = writeln!(file, "{}", line);
+        }
+    }
+}
+
+/// # Listener config
+///
+/// ## Example
+/// ```rust
+/// use sui_network_sdk::listener::{ListenerConfig, RawFrame};
+///
+/// let (sender, receiver) = tokio::sync::mpsc::unbounded_channel::<RawFrame>();
+/// let config = ListenerConfig::default().raw_tap(sender);
+/// ```
+#[derive(Clone, Default)]
+pub struct ListenerConfig {
+    raw_tap: Option<Arc<dyn RawTap>>,
+}
+
+impl ListenerConfig {
+    /// # Raw tap
+    ///
+    /// Delivers every raw text frame, with its direction and timestamp, to a callback or
+    /// channel sender. Replaces a previous tap or recording.
+    ///
+    /// ## Parameters
+    /// - tap : callback `Fn(&RawFrame)` or channel sender
+    pub fn raw_tap(mut self, tap: impl RawTap + 'static) -> Self {
+        self.raw_tap = Some(Arc::new(tap));
+        self
+    }
+
+    /// # Record to file
+    ///
+    /// Appends every raw frame to a file as NDJSON, replayable with
+    /// [`Listener::replay_from_file`]. Replaces a previous tap.
+    ///
+    /// ## Parameters
+    /// - path : recording file, created if missing
+    ///
+    /// ## Returns
+    /// - Ok(ListenerConfig) : config recording frames
+    /// - Err(SuiError) : the file can't be opened
+    pub fn record_to_file(self, path: impl AsRef<Path>) -> Result<Self, SuiError> {
+        let file = std::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)?;
+        Ok(self.raw_tap(FileRecorder(Mutex::new(LineWriter::new(file)))))
+    }
+}
+
+/// default buffer size of a shared subscription's broadcast channel
+const SHARED_CHANNEL_CAPACITY: usize = 1024;
+/// interval of the pings keeping a shared subscription's connection open
+const KEEPALIVE_INTERVAL: Duration = Duration::from_secs(30);
+/// wait before a watch list resubscribes an address whose subscription closed
+const WATCH_RESUBSCRIBE_DELAY: Duration = Duration::from_secs(1);
+
+/// # Subscription filter
+///
+/// Identifies an upstream websocket subscription, subscriptions with equal filters are shared.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum SubscriptionFilter {
+    /// all transactions
+    AllTransactions,
+    /// transactions sent or received by an address
+    AddressTransactions(String),
+    /// all events
+    AllEvents,
+    /// events matching a filter
+    Events(EventFilter),
+    /// transactions matching a filter
+    Transactions(TransactionFilter),
+}
+
+impl SubscriptionFilter {
+    /// subscribe request message of the filter
+    fn subscribe_message(&self) -> Value {
+        let (method, params) = match self {
+            SubscriptionFilter::AllTransactions => {
+                ("sui_subscribeTransaction", serde_json::json!([{"All": []}]))
+            }
+            SubscriptionFilter::AddressTransactions(address) => (
+                "sui_subscribeTransaction",
+                serde_json::json!([{"ToOrFromAddress": {"addr": address}}]),
+            ),
+            SubscriptionFilter::AllEvents => {
+                ("sui_subscribeEvent", serde_json::json!([{"All": []}]))
+            }
+            SubscriptionFilter::Events(filter) => {
+                ("sui_subscribeEvent", serde_json::json!([filter]))
+            }
+            SubscriptionFilter::Transactions(filter) => {
+                ("sui_subscribeTransaction", serde_json::json!([filter]))
+            }
+        };
+        serde_json::json!({
+            "jsonrpc": "2.0",
+            "id": 1,
+            "method": method,
+            "params": params
+        })
+    }
+}
+
+/// # Listener event
+///
+/// Item delivered to shared subscription consumers and ordered event listeners.
+#[derive(Debug, Clone)]
+pub enum ListenerEvent {
+    /// subscription notification payload (`params.result`)
+    Message(Value),
+    /// the consumer fell behind and `n` messages were skipped
+    Lagged(u64),
+    /// the upstream subscription failed or was closed by the node
+    Closed(Option<String>),
+    /// events of a transaction were still missing when the ordering window expired,
+    /// see [`Listener::listen_events_ordered`]
+    GapDetected { digest: String, missing: Vec<u64> },
+    /// the upstream subscription was silent for `silent_for` and was reconnected,
+    /// see [`SharedListener::with_watchdog`]
+    WatchdogRestart { silent_for: Duration },
+    /// a background task panicked, its siblings were stopped, see [`TaskSupervisor`]
+    InternalError { task: String, message: String },
+}
+
+/// # Watchdog
+///
+/// Silence handling of a shared subscription.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Watchdog {
+    /// never restart, for feeds that are legitimately quiet
+    Disabled,
+    /// restart after this long without any frame, set it well above the expected time
+    /// between messages of the feed
+    MaxSilence(Duration),
+}
+
+/// how an upstream subscription ended without error
+enum UpstreamEnd {
+    /// closed by the node
+    Closed,
+    /// silent for longer than the watchdog allows
+    Silent(Duration),
+}
+
+/// # Task supervisor
+///
+/// Owner of a group of background tasks. Tasks are named `{group}/{task}`, the name shows
+/// in tokio-console when built with `--cfg tokio_unstable` and in the tracing span of the
+/// task. A panicking task is reported as `ListenerEvent::InternalError` and stops the
+/// rest of the group, dropping the supervisor aborts every task. Each upstream subscription
+/// of a [`SharedListener`] is such a group, see [`SharedListener::with_config`].
+///
+/// ## Example
+/// ```rust
+/// use std::time::Duration;
+/// use sui_network_sdk::listener::{ListenerEvent, TaskSupervisor};
+/// use tokio::sync::{broadcast, oneshot};
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let (events, mut receiver) = broadcast::channel(16);
+///     let supervisor = TaskSupervisor::new("feed", events);
+///     // the keepalive holds the sender until it is stopped
+///     let (alive, stopped) = oneshot::channel::<()>();
+///     supervisor.spawn("keepalive", async move {
+///         let _alive = alive;
+///         std::future::pending::<()>().await
+///     });
+///     supervisor.spawn("read-loop", async { panic!("frame decoder out of sync") });
+///
+///     match receiver.recv().await.unwrap() {
+///         ListenerEvent::InternalError { task, message } => {
+///             assert_eq!(task, "feed/read-loop");
+///             assert_eq!(message, "frame decoder out of sync");
+///         }
+///         other => panic!("unexpected {:?}", other),
+///     }
+///     tokio::time::timeout(Duration::from_secs(1), stopped).await.unwrap().unwrap_err();
+///
+///     // dropping the supervisor stops its tasks
+///     let (events, _receiver) = broadcast::channel(16);
+///     let supervisor = TaskSupervisor::new("feed", events);
+///     let (alive, stopped) = oneshot::channel::<()>();
+///     supervisor.spawn("watchdog", async move {
+///         let _alive = alive;
+///         std::future::pending::<()>().await
+///     });
+///     assert_eq!(supervisor.task_names(), ["feed/watchdog"]);
+///     drop(supervisor);
+///     tokio::time::timeout(Duration::from_secs(1), stopped).await.unwrap().unwrap_err();
+/// }
+/// ```
+pub struct TaskSupervisor {
+    spawner: TaskSpawner,
+    tasks: SupervisedTasks,
+}
+
+/// named handles of a supervisor's tasks, `None` once the supervisor dropped
+type TaskList = Mutex<Option<Vec<(String, JoinHandle<()>)>>>;
+type SupervisedTasks = Arc<TaskList>;
+
+impl TaskSupervisor {
+    /// # create task supervisor
+    ///
+    /// ## Parameters
+    /// - group : prefix of the task names
+    /// - events : channel receiving `ListenerEvent::InternalError` when a task panics
+    pub fn new(group: impl Into<String>, events: broadcast::Sender<ListenerEvent>) -> Self {
+        let tasks = Arc::new(Mutex::new(Some(Vec::new())));
+        Self {
+            spawner: TaskSpawner {
+                group: group.into(),
+                events,
+                tasks: Arc::downgrade(&tasks),
+            },
+            tasks,
+        }
+    }
+
+    /// # Spawn
+    ///
+    /// Spawns a named task of the group on the current tokio runtime.
+    ///
+    /// ## Parameters
+    /// - task : task name within the group, e.g. `read-loop`
+    /// - future : task body
+    ///
+    /// ## Returns
+    /// handle stopping the task alone
+    pub fn spawn<F>(&self, task: &str, future: F) -> AbortHandle
+    where
+        F: Future<Output = ()> + Send + 'static,
+    {
+        self.spawner.spawn_into(&self.tasks, task, future)
+    }
+
+    /// names of the tasks still running
+    pub fn task_names(&self) -> Vec<String> {
+        lock(&self.tasks)
+            .iter()
+            .flatten()
+            .filter(|(_, handle)| !handle.is_finished())
+            .map(|(name, _)| name.clone())
+            .collect()
+    }
+
+    /// whether every task of the group ended
+    pub fn is_finished(&self) -> bool {
+        lock(&self.tasks)
+            .iter()
+            .flatten()
+            .all(|(_, handle)| handle.is_finished())
+    }
+
+    /// stop every task of the group
+    pub fn abort_all(&self) {
+        for (_, handle) in lock(&self.tasks).iter().flatten() {
+            handle.abort();
+        }
+    }
+
+    /// spawning side of the supervisor, for tasks of the group that spawn siblings
+    fn spawner(&self) -> TaskSpawner {
+        self.spawner.clone()
+    }
+}
+
+impl Drop for TaskSupervisor {
+    fn drop(&mut self) {
+        // closing the list makes tasks spawned from now on abort at once
+        if let Some(tasks) = lock(&self.tasks).take() {
+            for (_, handle) in tasks {
+                handle.abort();
+            }
+        }
+    }
+}
+
+impl std::fmt::Debug for TaskSupervisor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TaskSupervisor")
+            .field("group", &self.spawner.group)
+            .field("tasks", &self.task_names())
+            .finish()
+    }
+}
+
+/// spawns tasks into a supervisor's group without keeping the supervisor alive
+#[derive(Clone)]
+struct TaskSpawner {
+    group: String,
+    events: broadcast::Sender<ListenerEvent>,
+    tasks: Weak<TaskList>,
+}
+
+impl TaskSpawner {
+    /// spawn a task of the group, none once the supervisor dropped
+    fn spawn<F>(&self, task: &str, future: F) -> Option<AbortHandle>
+    where
+        F: Future<Output = ()> + Send + 'static,
+    {
+        let tasks = self.tasks.upgrade()?;
+        Some(self.spawn_into(&tasks, task, future))
+    }
+
+    /// spawn a task into the task list, reporting its panic and then stopping the group
+    fn spawn_into<F>(&self, tasks: &SupervisedTasks, task: &str, future: F) -> AbortHandle
+    where
+        F: Future<Output = ()> + Send + 'static,
+    {
+        let name = format!("{}/{}", self.group, task);
+        let events = self.events.clone();
+        let siblings = self.tasks.clone();
+        let task_name = name.clone();
+        let supervised = async move {
+            let Err(panic) = std::panic::AssertUnwindSafe(future).catch_unwind().await else {
+                return;
+            };
+            let message = panic
+                .downcast_ref::<&str>()
+                .map(|message| message.to_string())
+                .or_else(|| panic.downcast_ref::<String>().cloned())
+                .unwrap_or_else(|| "task panicked".to_string());
+            tracing::error!(task = %task_name, %message, "listener task panicked");
+            let _ = events.send(ListenerEvent::InternalError {
+                task: task_name,
+                message,
+            });
+            // the group is broken, stop the other tasks
+            if let Some(siblings) = siblings.upgrade() {
+                for (_, handle) in lock(&siblings).iter().flatten() {
+                    handle.abort();
+                }
+            }
+        };
+        let handle = spawn_named(&name, supervised);
+        let abort = handle.abort_handle();
+        match lock(tasks).as_mut() {
+            Some(tasks) => {
+                tasks.retain(|(_, handle)| !handle.is_finished());
+                tasks.push((name, handle));
+            }
+            None => handle.abort(),
+        }
+        abort
+    }
+}
+
+/// spawn a task on the current runtime under a name, shown by tokio-console in builds
+/// with `--cfg tokio_unstable` and recorded in the task's tracing span
+pub(crate) fn spawn_named<F>(name: &str, future: F) -> JoinHandle<()>
+where
+    F: Future<Output = ()> + Send + 'static,
+{
+    let future = tracing::Instrument::instrument(future, tracing::info_span!("task", task = %name));
+    #[cfg(tokio_unstable)]
+    {
+        tokio::task::Builder::new()
+            .name(name)
+            .spawn(future)
+            // fails where tokio::spawn panics, outside a runtime
+            .unwrap_or_else(|e| panic!("failed to spawn {}: {}", name, e))
+    }
+    #[cfg(not(tokio_unstable))]
+    {
+        tokio::spawn(future)
+    }
+}
+
+/// lock a mutex, recovering it from a panicked holder
+fn lock<T>(mutex: &Mutex<T>) -> std::sync::MutexGuard<'_, T> {
+    mutex.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+/// upstream subscription shared between consumers
+struct SharedUpstream {
+    id: u64,
+    sender: broadcast::Sender<ListenerEvent>,
+    tasks: TaskSupervisor,
+}
+
+type SharedUpstreams = Arc<Mutex<HashMap<SubscriptionFilter, SharedUpstream>>>;
+
+/// # Shared Listener
+///
+/// Holds one upstream websocket subscription per unique filter and fans its messages out to
+/// any number of consumers. The upstream subscription is closed when its last consumer drops,
+/// slow consumers never block others and receive `ListenerEvent::Lagged` instead.
+///
+/// ## Example
+/// ```no_run
+/// use futures::StreamExt;
+/// use sui_network_sdk::global::mainnet;
+/// use sui_network_sdk::listener::{ListenerEvent, SharedListener, SubscriptionFilter};
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let listener = SharedListener::new(mainnet::WSS_URL.to_string());
+///     let filter = SubscriptionFilter::AddressTransactions("0x123...".to_string());
+///     let mut a = listener.subscribe(filter.clone());
+///     let mut b = listener.subscribe(filter); // reuses the same upstream subscription
+///     while let Some(event) = a.next().await {
+///         if let ListenerEvent::Lagged(n) = event {
+///             println!("skipped {} messages", n);
+///         }
+///     }
+/// }
+/// ```
+#[derive(Clone)]
+pub struct SharedListener {
+    url: String,
+    capacity: usize,
+    upstreams: SharedUpstreams,
+    next_id: Arc<AtomicU64>,
+    watchdog: Watchdog,
+    filter_watchdogs: HashMap<SubscriptionFilter, Watchdog>,
+    dns: Arc<DnsOverrides>,
+    config: ListenerConfig,
+}
+
+impl SharedListener {
+    /// # create shared listener
+    ///
+    /// ## Parameters
+    /// - url : websocket url
+    pub fn new(url: String) -> Self {
+        Self::with_capacity(url, SHARED_CHANNEL_CAPACITY)
+    }
+
+    /// # create shared listener with a channel capacity
+    ///
+    /// ## Parameters
+    /// - url : websocket url
+    /// - capacity : messages buffered per subscription before slow consumers lag
+    pub fn with_capacity(url: String, capacity: usize) -> Self {
+        Self {
+            url,
+            capacity,
+            upstreams: Arc::new(Mutex::new(HashMap::new())),
+            next_id: Arc::new(AtomicU64::new(0)),
+            watchdog: Watchdog::Disabled,
+            filter_watchdogs: HashMap::new(),
+            dns: Arc::new(DnsOverrides::default()),
+            config: ListenerConfig::default(),
+        }
+    }
+
+    /// # With watchdog
+    ///
+    /// Restarts upstream subscriptions that stop delivering while their socket stays open.
+    /// Once a subscription delivered a notification, a silence of `max_silence` without any
+    /// frame, pings included, tears it down and reconnects it, consumers receive
+    /// `ListenerEvent::WatchdogRestart` and keep their stream. Quiet feeds need their own
+    /// threshold or none, see [`SharedListener::with_filter_watchdog`].
+    ///
+    /// ## Parameters
+    /// - max_silence : longest silence of a subscription that delivered before
+    ///
+    /// ## Example
+    /// ```no_run
+    /// use futures::StreamExt;
+    /// use std::time::Duration;
+    /// use sui_network_sdk::global::mainnet;
+    /// use sui_network_sdk::listener::{ListenerEvent, SharedListener, SubscriptionFilter};
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let listener = SharedListener::new(mainnet::WSS_URL.to_string())
+    ///         .with_watchdog(Duration::from_secs(30));
+    ///     let mut stream = listener.subscribe(SubscriptionFilter::AllTransactions);
+    ///     while let Some(event) = stream.next().await {
+    ///         if let ListenerEvent::WatchdogRestart { silent_for } = event {
+    ///             println!("restarted after {:?} of silence", silent_for);
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    pub fn with_watchdog(mut self, max_silence: Duration) -> Self {
+        self.watchdog = Watchdog::MaxSilence(max_silence);
+        self
+    }
+
+    /// # With filter watchdog
+    ///
+    /// Overrides the watchdog of one subscription, e.g. disables it for an address feed
+    /// that may stay quiet for hours, or sets a threshold matching the feed's expected
+    /// activity. Applies to subscriptions opened afterwards.
+    ///
+    /// ## Parameters
+    /// - filter : subscription filter
+    /// - watchdog : watchdog of the subscription
+    ///
+    /// ## Example
+    /// ```no_run
+    /// use std::time::Duration;
+    /// use sui_network_sdk::global::mainnet;
+    /// use sui_network_sdk::listener::{SharedListener, SubscriptionFilter, Watchdog};
+    /// let listener = SharedListener::new(mainnet::WSS_URL.to_string())
+    ///     .with_watchdog(Duration::from_secs(30))
+    ///     .with_filter_watchdog(
+    ///         SubscriptionFilter::AddressTransactions("0x123...".to_string()),
+    ///         Watchdog::Disabled,
+    ///     );
+    /// ```
+    pub fn with_filter_watchdog(mut self, filter: SubscriptionFilter, watchdog: Watchdog) -> Self {
+        self.filter_watchdogs.insert(filter, watchdog);
+        self
+    }
+
+    /// # With dns overrides
+    ///
+    /// Resolves the websocket host like the client's http transport, see
+    /// [`SuiClient::dns_overrides`]. Applies to subscriptions opened afterwards.
+    ///
+    /// ## Parameters
+    /// - overrides : dns overrides
+    ///
+    /// ## Example
+    /// ```no_run
+    /// use sui_network_sdk::listener::SharedListener;
+    /// use sui_network_sdk::transport::DnsOverrides;
+    ///
+    /// let overrides = DnsOverrides::new().resolve_host("fullnode.internal", "10.0.0.7:0".parse().unwrap());
+    /// let listener = SharedListener::new("wss://fullnode.internal:9001".to_string())
+    ///     .with_dns_overrides(overrides);
+    /// ```
+    pub fn with_dns_overrides(mut self, overrides: DnsOverrides) -> Self {
+        self.dns = Arc::new(overrides);
+        self
+    }
+
+    /// # With config
+    ///
+    /// Sets the listener config, e.g. a raw tap seeing the frames of every upstream
+    /// subscription. Applies to subscriptions opened afterwards.
+    ///
+    /// Each upstream subscription runs as a [`TaskSupervisor`] group `sui-listener/{id}` of
+    /// a `reconnect` task and, per connection, a `read-loop`, a `keepalive` and, with a
+    /// watchdog, a `watchdog` task. The tap runs in the read loop: a panicking tap is
+    /// reported to the consumers as `ListenerEvent::InternalError` and stops the group.
+    ///
+    /// ## Parameters
+    /// - config : listener config
+    ///
+    /// ## Example
+    /// ```no_run
+    /// use sui_network_sdk::global::mainnet;
+    /// use sui_network_sdk::listener::{ListenerConfig, RawFrame, SharedListener};
+    ///
+    /// let config = ListenerConfig::default()
+    ///     .raw_tap(|frame: &RawFrame| println!("{:?} {}", frame.direction, frame.text));
+    /// let listener = SharedListener::new(mainnet::WSS_URL.to_string()).with_config(config);
+    /// ```
+    pub fn with_config(mut self, config: ListenerConfig) -> Self {
+        self.config = config;
+        self
+    }
+
+    /// # Subscribe
+    ///
+    /// Joins the upstream subscription of the filter, opening it if no consumer holds it yet.
+    ///
+    /// ## Parameters
+    /// - filter : subscription filter
+    ///
+    /// ## Returns
+    /// stream of listener events
+    pub fn subscribe(&self, filter: SubscriptionFilter) -> SharedStream {
+        let mut upstreams = self.upstreams.lock().unwrap_or_else(|e| e.into_inner());
+        // an upstream whose task died is replaced instead of joined
+        let live = upstreams
+            .get(&filter)
+            .filter(|upstream| !upstream.tasks.is_finished());
+        let receiver = match live {
+            Some(upstream) => upstream.sender.subscribe(),
+            None => {
+                let (sender, receiver) = broadcast::channel(self.capacity);
+                let id = self.next_id.fetch_add(1, Ordering::Relaxed);
+                let watchdog = self
+                    .filter_watchdogs
+                    .get(&filter)
+                    .copied()
+                    .unwrap_or(self.watchdog);
+                let tasks = TaskSupervisor::new(format!("sui-listener/{}", id), sender.clone());
+                let context = UpstreamContext {
+                    dns: self.dns.clone(),
+                    url: self.url.clone(),
+                    filter: filter.clone(),
+                    sender: sender.clone(),
+                    watchdog,
+                    raw_tap: self.config.raw_tap.clone(),
+                    spawner: tasks.spawner(),
+                };
+                tasks.spawn(
+                    "reconnect",
+                    Self::run_upstream(context, id, self.upstreams.clone()),
+                );
+                let replaced =
+                    upstreams.insert(filter.clone(), SharedUpstream { id, sender, tasks });
+                drop(upstreams);
+                drop(replaced);
+                receiver
+            }
+        };
+        let inner = futures::stream::unfold(receiver, |mut receiver| async move {
+            match receiver.recv().await {
+                Ok(event) => Some((event, receiver)),
+                Err(broadcast::error::RecvError::Lagged(n)) => {
+                    Some((ListenerEvent::Lagged(n), receiver))
+                }
+                Err(broadcast::error::RecvError::Closed) => None,
+            }
+        });
+        SharedStream {
+            inner: Box::pin(inner),
+            _guard: SharedStreamGuard {
+                filter,
+                upstreams: self.upstreams.clone(),
+            },
+        }
+    }
+
+    /// number of upstream subscriptions currently open
+    pub fn upstream_count(&self) -> usize {
+        self.upstreams
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .len()
+    }
+
+    /// names of the running background tasks of all upstream subscriptions
+    pub fn task_names(&self) -> Vec<String> {
+        self.upstreams
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .values()
+            .flat_map(|upstream| upstream.tasks.task_names())
+            .collect()
+    }
+
+    /// # Watch list
+    ///
+    /// Address watch list delivering the transactions of all its addresses on one stream,
+    /// see [`WatchList`].
+    ///
+    /// ## Returns
+    /// handle to add and remove addresses, and the stream of their transactions
+    pub fn watch_list(&self) -> (WatchList, WatchStream) {
+        let state = Arc::new(Mutex::new(WatchState::default()));
+        (
+            WatchList {
+                listener: self.clone(),
+                state: state.clone(),
+            },
+            WatchStream {
+                listener: self.clone(),
+                state,
+            },
+        )
+    }
+
+    /// connect the upstream subscription, reconnecting it when the watchdog finds it silent
+    async fn run_upstream(context: UpstreamContext, id: u64, upstreams: SharedUpstreams) {
+        // removes the upstream however the task ends, panics and aborts included
+        let _cleanup = UpstreamCleanup {
+            upstreams,
+            filter: context.filter.clone(),
+            id,
+        };
+        let delivered = Arc::new(AtomicBool::new(false));
+        let reason = loop {
+            match Self::connect_upstream(&context, &delivered).await {
+                Ok(UpstreamEnd::Silent(silent_for)) => {
+                    let _ = context
+                        .sender
+                        .send(ListenerEvent::WatchdogRestart { silent_for });
+                }
+                Ok(UpstreamEnd::Closed) => break None,
+                Err(e) => break Some(e.to_string()),
+            }
+        };
+        let _ = context.sender.send(ListenerEvent::Closed(reason));
+    }
+
+    /// open one connection of the upstream subscription and run its tasks until one of
+    /// them ends it, `delivered` tells whether the subscription ever delivered a
+    /// notification, which arms the watchdog
+    async fn connect_upstream(
+        context: &UpstreamContext,
+        delivered: &Arc<AtomicBool>,
+    ) -> Result<UpstreamEnd, SuiError> {
+        let ws_stream = context.dns.connect_websocket(&context.url).await?;
+        let (mut write, read) = ws_stream.split();
+        let subscribe = context.filter.subscribe_message().to_string();
+        if let Some(tap) = &context.raw_tap {
+            tap.frame(&RawFrame::new(FrameDirection::Outbound, &subscribe));
+        }
+        write.send(Message::Text(subscribe.into())).await?;
+        let (end, mut ended) = tokio::sync::mpsc::channel(1);
+        let last_frame = Arc::new(Mutex::new(Instant::now()));
+        let spawner = &context.spawner;
+        let mut tasks = Vec::new();
+        tasks.extend(spawner.spawn(
+            "read-loop",
+            Self::read_loop(
+                read,
+                context.clone(),
+                last_frame.clone(),
+                delivered.clone(),
+                end.clone(),
+            ),
+        ));
+        tasks.extend(spawner.spawn("keepalive", Self::keepalive(write, end.clone())));
+        if let Watchdog::MaxSilence(max_silence) = context.watchdog {
+            tasks.extend(spawner.spawn(
+                "watchdog",
+                Self::watch(max_silence, last_frame, delivered.clone(), end.clone()),
+            ));
+        }
+        drop(end);
+        let result = match ended.recv().await {
+            Some(result) => result,
+            // a task ended without reporting, it panicked and the supervisor stops this
+            // task as well
+            None => std::future::pending().await,
+        };
+        // dropping both halves of the socket tears the connection down
+        for task in tasks {
+            task.abort();
+        }
+        result
+    }
+
+    /// read the frames of a connection and broadcast its notifications
+    async fn read_loop(
+        mut read: impl Stream<Item = Result<Message, tokio_tungstenite::tungstenite::Error>> + Unpin,
+        context: UpstreamContext,
+        last_frame: Arc<Mutex<Instant>>,
+        delivered: Arc<AtomicBool>,
+        end: tokio::sync::mpsc::Sender<Result<UpstreamEnd, SuiError>>,
+    ) {
+        let result = loop {
+            let Some(message) = read.next().await else {
+                break Ok(UpstreamEnd::Closed);
+            };
+            // pongs answer the keepalive, they don't show the subscription is alive
+            if !matches!(message, Ok(Message::Pong(_))) {
+                *lock(&last_frame) = Instant::now();
+            }
+            match message {
+                Ok(Message::Text(text)) => {
+                    if let Some(tap) = &context.raw_tap {
+                        tap.frame(&RawFrame::new(FrameDirection::Inbound, &text));
+                    }
+                    if let Ok(event) = serde_json::from_str::<Value>(&text)
+                        && let Some(result) = event.get("params").and_then(|p| p.get("result"))
+                    {
+                        delivered.store(true, Ordering::Relaxed);
+                        // no receivers is fine, the guard closes the upstream shortly
+                        let _ = context.sender.send(ListenerEvent::Message(result.clone()));
+                    }
+                }
+                Ok(Message::Close(_)) => break Ok(UpstreamEnd::Closed),
+                Err(e) => break Err(SuiError::WebSocket(e.to_string())),
+                _ => {}
+            }
+        };
+        let _ = end.send(result).await;
+    }
+
+    /// ping the node so idle connections aren't dropped on the way
+    async fn keepalive(
+        mut write: impl futures::Sink<Message, Error = tokio_tungstenite::tungstenite::Error> + Unpin,
+        end: tokio::sync::mpsc::Sender<Result<UpstreamEnd, SuiError>>,
+    ) {
+        let start = tokio::time::Instant::now() + KEEPALIVE_INTERVAL;
+        let mut interval = tokio::time::interval_at(start, KEEPALIVE_INTERVAL);
+        loop {
+            interval.tick().await;
+            if let Err(e) = write.send(Message::Ping(Default::default())).await {
+                let _ = end.send(Err(SuiError::WebSocket(e.to_string()))).await;
+                return;
+            }
+        }
+    }
+
+    /// end the connection once the subscription delivered and then stayed without any
+    /// frame for max_silence
+    async fn watch(
+        max_silence: Duration,
+        last_frame: Arc<Mutex<Instant>>,
+        delivered: Arc<AtomicBool>,
+        end: tokio::sync::mpsc::Sender<Result<UpstreamEnd, SuiError>>,
+    ) {
+        loop {
+            let deadline = *lock(&last_frame) + max_silence;
+            tokio::time::sleep_until(deadline.into()).await;
+            if delivered.load(Ordering::Relaxed) && lock(&last_frame).elapsed() >= max_silence {
+                let _ = end.send(Ok(UpstreamEnd::Silent(max_silence))).await;
+                return;
+            }
+        }
+    }
+}
+
+/// what the tasks of an upstream subscription share
+#[derive(Clone)]
+struct UpstreamContext {
+    dns: Arc<DnsOverrides>,
+    url: String,
+    filter: SubscriptionFilter,
+    sender: broadcast::Sender<ListenerEvent>,
+    watchdog: Watchdog,
+    raw_tap: Option<Arc<dyn RawTap>>,
+    spawner: TaskSpawner,
+}
+
+/// # Shared stream
+///
+/// Consumer side of a shared subscription, dropping it releases the consumer's reference.
+pub struct SharedStream {
+    inner: Pin<Box<dyn Stream<Item = ListenerEvent> + Send>>,
+    _guard: SharedStreamGuard,
+}
+
+impl Stream for SharedStream {
+    type Item = ListenerEvent;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.inner.as_mut().poll_next(cx)
+    }
+}
+
+/// closes the upstream subscription when the last consumer drops,
+/// declared after the receiver so it runs once the receiver is gone
+struct SharedStreamGuard {
+    filter: SubscriptionFilter,
+    upstreams: SharedUpstreams,
+}
+
+impl Drop for SharedStreamGuard {
+    fn drop(&mut self) {
+        let mut upstreams = self.upstreams.lock().unwrap_or_else(|e| e.into_inner());
+        let unused = upstreams
+            .get(&self.filter)
+            .is_some_and(|u| u.sender.receiver_count() == 0);
+        let removed = if unused {
+            upstreams.remove(&self.filter)
+        } else {
+            None
+        };
+        // the supervisor aborts the upstream task once the lock is released
+        drop(upstreams);
+        drop(removed);
+    }
+}
+
+/// removes an upstream subscription from the shared listener when its task ends, so the
+/// next subscriber reconnects instead of joining a dead upstream
+struct UpstreamCleanup {
+    upstreams: SharedUpstreams,
+    filter: SubscriptionFilter,
+    id: u64,
+}
+
+impl Drop for UpstreamCleanup {
+    fn drop(&mut self) {
+        let mut upstreams = self.upstreams.lock().unwrap_or_else(|e| e.into_inner());
+        let removed = if upstreams.get(&self.filter).is_some_and(|u| u.id == self.id) {
+            upstreams.remove(&self.filter)
+        } else {
+            None
+        };
+        drop(upstreams);
+        drop(removed);
+    }
+}
+
+/// subscription of a watched address
+enum WatchEntry {
+    Subscribed(SharedStream),
+    /// the subscription closed, resubscribe once the delay passed
+    Resubscribing(Pin<Box<tokio::time::Sleep>>),
+}
+
+/// addresses of a watch list, polled in turn
+#[derive(Default)]
+struct WatchState {
+    entries: Vec<(String, WatchEntry)>,
+    next: usize,
+    waker: Option<Waker>,
+}
+
+/// # Watch list
+///
+/// Addresses watched through a [`SharedListener`], each by its own `ToOrFromAddress`
+/// subscription. Addresses are added and removed at runtime without disturbing the
+/// others, a removed address is unsubscribed at once. The transactions of all addresses
+/// arrive on the [`WatchStream`] as `(address, effects)`. The handle is cheap to clone.
+///
+/// Subscriptions closed by the node are reopened after a second, messages skipped by a
+/// lagging stream are not delivered.
+///
+/// ## Example
+/// ```no_run
+/// use futures::StreamExt;
+/// use sui_network_sdk::global::mainnet;
+/// use sui_network_sdk::listener::SharedListener;
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let listener = SharedListener::new(mainnet::WSS_URL.to_string());
+///     let (watch, mut transactions) = listener.watch_list();
+///     watch.add_address("0xa11ce").unwrap();
+///     watch.add_address("0xb0b").unwrap();
+///     while let Some((address, effects)) = transactions.next().await {
+///         println!("{} {}", address, effects.transaction_digest);
+///     }
+/// }
+/// ```
+#[derive(Clone)]
+pub struct WatchList {
+    listener: SharedListener,
+    state: Arc<Mutex<WatchState>>,
+}
+
+impl WatchList {
+    /// # Add address
+    ///
+    /// ## Parameters
+    /// - address : address to watch
+    ///
+    /// ## Returns
+    /// - Ok(bool) : whether the address was added, `false` if it was watched already
+    /// - Err(SuiError) : not a hex address
+    pub fn add_address(&self, address: &str) -> Result<bool, SuiError> {
+        let address = address::normalize(address)
+            .ok_or_else(|| SuiError::Hex(format!("invalid address {}", address)))?;
+        let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        if state.entries.iter().any(|(watched, _)| *watched == address) {
+            return Ok(false);
+        }
+        let stream = self
+            .listener
+            .subscribe(SubscriptionFilter::AddressTransactions(address.clone()));
+        state
+            .entries
+            .push((address, WatchEntry::Subscribed(stream)));
+        if let Some(waker) = state.waker.take() {
+            waker.wake();
+        }
+        Ok(true)
+    }
+
+    /// # Remove address
+    ///
+    /// Unsubscribes the address, unless another consumer of the listener shares its
+    /// subscription.
+    ///
+    /// ## Parameters
+    /// - address : watched address
+    ///
+    /// ## Returns
+    /// whether the address was watched
+    pub fn remove_address(&self, address: &str) -> bool {
+        let Some(address) = address::normalize(address) else {
+            return false;
+        };
+        let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        match state
+            .entries
+            .iter()
+            .position(|(watched, _)| *watched == address)
+        {
+            Some(index) => {
+                // dropping the stream releases the subscription
+                state.entries.remove(index);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// watched addresses, in the order they were added
+    pub fn addresses(&self) -> Vec<String> {
+        let state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        state
+            .entries
+            .iter()
+            .map(|(address, _)| address.clone())
+            .collect()
+    }
+}
+
+/// # Watch stream
+///
+/// Transactions of the addresses of a [`WatchList`] as `(address, effects)`, never ends
+/// on its own. Addresses are polled in turn so a busy one can't starve the others.
+pub struct WatchStream {
+    listener: SharedListener,
+    state: Arc<Mutex<WatchState>>,
+}
+
+impl Stream for WatchStream {
+    type Item = (String, TransactionEffects);
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        state.waker = Some(cx.waker().clone());
+        let len = state.entries.len();
+        for offset in 0..len {
+            let index = (state.next + offset) % len;
+            let (address, entry) = &mut state.entries[index];
+            if let Some(effects) = poll_watch_entry(&self.listener, address, entry, cx) {
+                let address = address.clone();
+                state.next = index + 1;
+                return Poll::Ready(Some((address, effects)));
+            }
+        }
+        Poll::Pending
+    }
+}
+
+/// poll one watched address until it yields effects or is pending
+fn poll_watch_entry(
+    listener: &SharedListener,
+    address: &str,
+    entry: &mut WatchEntry,
+    cx: &mut Context<'_>,
+) -> Option<TransactionEffects> {
+    loop {
+        match entry {
+            WatchEntry::Subscribed(stream) => match stream.poll_next_unpin(cx) {
+                Poll::Ready(Some(ListenerEvent::Message(result))) => {
+                    if let Ok(effects) = serde_json::from_value(result) {
+                        return Some(effects);
+                    }
+                }
+                Poll::Ready(Some(_)) => {}
+                Poll::Ready(None) => {
+                    *entry = WatchEntry::Resubscribing(Box::pin(tokio::time::sleep(
+                        WATCH_RESUBSCRIBE_DELAY,
+                    )));
+                }
+                Poll::Pending => return None,
+            },
+            WatchEntry::Resubscribing(delay) => match delay.poll_unpin(cx) {
+                Poll::Ready(()) => {
+                    *entry =
+                        WatchEntry::Subscribed(listener.subscribe(
+                            SubscriptionFilter::AddressTransactions(address.to_string()),
+                        ));
+                }
+                Poll::Pending => return None,
+            },
+        }
+    }
+}
+
+/// transactions per page of a package poll
+const PACKAGE_POLL_PAGE_SIZE: usize = 50;
+/// type of the capability authorizing package upgrades
+const UPGRADE_CAP_TYPE: &str = "0x2::package::UpgradeCap";
+
+/// # Package source
+///
+/// How [`SuiClient::listen_package_transactions`] learns about new transactions.
+///
+/// # Variants
+/// - Polling : a query per package every interval, queries can't combine packages
+/// - Listener : a subscription per package on a shared listener
+pub enum PackageSource {
+    Polling(Duration),
+    Listener(SharedListener),
+}
+
+/// # Package transaction
+///
+/// Transaction calling a watched package, see [`SuiClient::listen_package_transactions`].
+///
+/// # Fields
+/// - package_id : watched package the transaction called
+/// - digest : transaction digest
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PackageTransaction {
+    pub package_id: String,
+    pub digest: String,
+}
+
+impl SuiClient {
+    /// # Listen package transactions
+    ///
+    /// Watches the transactions calling any of a set of packages, e.g. every version of a
+    /// protocol, tagged with the package that matched. A transaction calling several
+    /// watched packages is delivered once per package. With upgrade following, the
+    /// `UpgradeCap` of each package is found through its publish transaction and read
+    /// every interval, a package the cap points at is added to the watch set; versions
+    /// published and upgraded again within one interval are not seen.
+    ///
+    /// Polling starts after the latest transaction of each package, a package found by an
+    /// upgrade is read from its first transaction. Subscriptions closed by the node are
+    /// reopened after a second, transactions in between are missed.
+    ///
+    /// ## Parameters
+    /// - source : polling or a shared listener
+    /// - package_ids : packages to watch
+    /// - upgrade_check : interval between reads of the upgrade caps, `None` to watch only
+    ///   the given packages
+    /// - callback : callback function
+    ///
+    /// ## Returns
+    /// - Ok(()) : every subscription ended
+    /// - Err(SuiError) : an id is not hex, or RPC error
+    ///
+    /// ## Example
+    /// ```rust
+    /// use std::sync::Arc;
+    /// use std::time::Duration;
+    /// use sui_network_sdk::SuiClient;
+    /// use sui_network_sdk::listener::{PackageSource, PackageTransaction};
+    /// use sui_network_sdk::transport::MockTransport;
+    /// use sui_network_sdk::types::SuiClientConfig;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let (v1, v2) = (format!("0x{:0>64}", "a1"), format!("0x{:0>64}", "a2"));
+    ///     let page = |digests: &[&str]| serde_json::json!({
+    ///         "data": digests.iter().map(|d| serde_json::json!({ "digest": d })).collect::<Vec<_>>(),
+    ///         "nextCursor": digests.last(),
+    ///         "hasNextPage": false
+    ///     });
+    ///     let mock = Arc::new(
+    ///         MockTransport::new()
+    ///             // the package was published with its upgrade cap
+    ///             .with_result("sui_getObject", serde_json::json!({ "data": {
+    ///                 "objectId": v1, "version": "1", "digest": "D1", "previousTransaction": "PUBLISH"
+    ///             }}))
+    ///             .with_result("sui_getTransactionBlock", serde_json::json!({
+    ///                 "digest": "PUBLISH",
+    ///                 "objectChanges": [{
+    ///                     "type": "created", "sender": "0xb0b", "owner": { "AddressOwner": "0xb0b" },
+    ///                     "objectType": "0x2::package::UpgradeCap", "objectId": "0xcab",
+    ///                     "version": "2", "digest": "D2"
+    ///                 }]
+    ///             }))
+    ///             // the cap points at v2 once read again
+    ///             .with_result("sui_getObject", serde_json::json!({ "data": {
+    ///                 "objectId": "0xcab", "version": "3", "digest": "D3",
+    ///                 "content": { "dataType": "moveObject", "fields": { "package": "0xa2", "version": "2" } }
+    ///             }}))
+    ///             // the latest transaction of v1, then new ones of v1 and v2
+    ///             .with_result("suix_queryTransactionBlocks", page(&["T0"]))
+    ///             .with_result("suix_queryTransactionBlocks", page(&["T1"]))
+    ///             .with_result("suix_queryTransactionBlocks", page(&[]))
+    ///             .with_result("suix_queryTransactionBlocks", page(&["T2"]))
+    ///             .with_result("suix_queryTransactionBlocks", page(&[])),
+    ///     );
+    ///     let client = SuiClient::new_with_transport(SuiClientConfig::default(), mock.clone());
+    ///
+    ///     let mut seen = Vec::new();
+    ///     let source = PackageSource::Polling(Duration::from_millis(10));
+    ///     let listen = client.listen_package_transactions(source, &["0xa1"], Some(Duration::ZERO), |tx| seen.push(tx));
+    ///     let _ = tokio::time::timeout(Duration::from_millis(300), listen).await;
+    ///     let tx = |package_id: &str, digest: &str| PackageTransaction {
+    ///         package_id: package_id.to_string(),
+    ///         digest: digest.to_string(),
+    ///     };
+    ///     assert_eq!(seen, [tx(&v1, "T1"), tx(&v2, "T2")]);
+    ///
+    ///     // v2 is read from its first transaction
+    ///     let queries: Vec<_> = mock.requests().into_iter().filter(|r| r.method == "suix_queryTransactionBlocks").collect();
+    ///     assert_eq!(queries[3].params[0]["filter"]["MoveFunction"]["package"], v2);
+    ///     assert!(queries[3].params[1].is_null());
+    /// }
+    /// ```
+    pub async fn listen_package_transactions<F>(
+        &self,
+        source: PackageSource,
+        package_ids: &[&str],
+        upgrade_check: Option<Duration>,
+        mut callback: F,
+    ) -> Result<(), SuiError>
+    where
+        F: FnMut(PackageTransaction),
+    {
+        let mut packages: Vec<String> = Vec::new();
+        for package_id in package_ids {
+            let package_id = address::normalize(package_id)
+                .ok_or_else(|| SuiError::Hex(format!("invalid package id {}", package_id)))?;
+            if !packages.contains(&package_id) {
+                packages.push(package_id);
+            }
+        }
+        // upgrade caps with the package they pointed at last
+        let mut caps: Vec<(String, String)> = Vec::new();
+        if upgrade_check.is_some() {
+            for package in &packages {
+                if let Some(cap) = self.upgrade_cap(package).await?
+                    && !caps.iter().any(|(id, _)| *id == cap)
+                {
+                    caps.push((cap, package.clone()));
+                }
+            }
+        }
+        let mut next_check = upgrade_check.map(|interval| tokio::time::Instant::now() + interval);
+
+        match source {
+            PackageSource::Polling(interval) => {
+                let mut cursors: HashMap<String, Option<String>> = HashMap::new();
+                for package in &packages {
+                    let latest = self
+                        .query_transaction_blocks(
+                            package_filter(package),
+                            Default::default(),
+                            None,
+                            Some(1),
+                            true,
+                        )
+                        .await?;
+                    let cursor = latest.data.first().map(|tx| tx.digest.clone());
+                    cursors.insert(package.clone(), cursor);
+                }
+                loop {
+                    for package in &packages {
+                        loop {
+                            let page = self
+                                .query_transaction_blocks(
+                                    package_filter(package),
+                                    Default::default(),
+                                    cursors.get(package).cloned().flatten(),
+                                    Some(PACKAGE_POLL_PAGE_SIZE),
+                                    false,
+                                )
+                                .await?;
+                            for tx in &page.data {
+                                callback(PackageTransaction {
+                                    package_id: package.clone(),
+                                    digest: tx.digest.clone(),
+                                });
+                            }
+                            if let Some(last) = page.data.last() {
+                                cursors.insert(package.clone(), Some(last.digest.clone()));
+                            }
+                            if !page.has_next_page || page.data.is_empty() {
+                                break;
+                            }
+                        }
+                    }
+                    tokio::time::sleep(interval).await;
+                    if let (Some(check), Some(interval)) = (next_check, upgrade_check)
+                        && tokio::time::Instant::now() >= check
+                    {
+                        next_check = Some(tokio::time::Instant::now() + interval);
+                        for package in self.discover_upgrades(&mut caps).await? {
+                            if !packages.contains(&package) {
+                                cursors.insert(package.clone(), None);
+                                packages.push(package);
+                            }
+                        }
+                    }
+                }
+            }
+            PackageSource::Listener(listener) => {
+                let mut streams = futures::stream::SelectAll::new();
+                for package in &packages {
+                    streams.push(package_stream(listener.clone(), package.clone()));
+                }
+                loop {
+                    let check = async {
+                        match next_check {
+                            Some(check) => tokio::time::sleep_until(check).await,
+                            None => futures::future::pending().await,
+                        }
+                    };
+                    tokio::select! {
+                        item = streams.next() => match item {
+                            Some((package_id, ListenerEvent::Message(result))) => {
+                                if let Some(digest) = notification_digest(&result) {
+                                    callback(PackageTransaction { package_id, digest });
+                                }
+                            }
+                            Some(_) => {}
+                            None => return Ok(()),
+                        },
+                        _ = check => {
+                            next_check = upgrade_check.map(|interval| tokio::time::Instant::now() + interval);
+                            for package in self.discover_upgrades(&mut caps).await? {
+                                if !packages.contains(&package) {
+                                    streams.push(package_stream(listener.clone(), package.clone()));
+                                    packages.push(package);
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    /// upgrade cap of a package, created by its publish or changed by the upgrade creating it
+    async fn upgrade_cap(&self, package_id: &str) -> Result<Option<String>, SuiError> {
+        let options = crate::types::ObjectDataOptions {
+            show_previous_transaction: true,
+            ..Default::default()
+        };
+        let package = self.get_object_with_options(package_id, options).await?;
+        let Some(digest) = package.previous_transaction else {
+            return Ok(None);
+        };
+        let options = serde_json::json!({ "showObjectChanges": true });
+        let transaction = self.get_transaction_block(&digest, options).await?;
+        let upgrade_cap = normalize_coin_type(UPGRADE_CAP_TYPE);
+        Ok(transaction
+            .object_changes
+            .unwrap_or_default()
+            .into_iter()
+            .find_map(|change| match change {
+                ObjectChange::Created {
+                    object_type,
+                    object_id,
+                    ..
+                }
+                | ObjectChange::Mutated {
+                    object_type,
+                    object_id,
+                    ..
+                } if normalize_coin_type(&object_type) == upgrade_cap => Some(object_id),
+                _ => None,
+            }))
+    }
+
+    /// packages the upgrade caps point at that were not seen before, caps that were
+    /// destroyed to make their package immutable are dropped
+    async fn discover_upgrades(
+        &self,
+        caps: &mut Vec<(String, String)>,
+    ) -> Result<Vec<String>, SuiError> {
+        let mut upgraded = Vec::new();
+        let mut destroyed = Vec::new();
+        for (cap, latest) in caps.iter_mut() {
+            let object = match self.get_object(cap).await {
+                Ok(object) => object,
+                Err(e) if e.is_not_found() => {
+                    destroyed.push(cap.clone());
+                    continue;
+                }
+                Err(e) => return Err(e),
+            };
+            let package = object
+                .data
+                .as_ref()
+                .and_then(|data| data.fields["package"].as_str())
+                .and_then(address::normalize);
+            if let Some(package) = package
+                && package != *latest
+            {
+                *latest = package.clone();
+                upgraded.push(package);
+            }
+        }
+        caps.retain(|(cap, _)| !destroyed.contains(cap));
+        Ok(upgraded)
+    }
+}
+
+/// filter of the transactions calling a package
+fn package_filter(package_id: &str) -> TransactionFilter {
+    TransactionFilter::MoveFunction {
+        package: package_id.to_string(),
+        module: None,
+        function: None,
+    }
+}
+
+/// transactions of a package on a shared listener, resubscribing when the subscription ends
+fn package_stream(
+    listener: SharedListener,
+    package_id: String,
+) -> futures::stream::BoxStream<'static, (String, ListenerEvent)> {
+    let filter = SubscriptionFilter::Transactions(package_filter(&package_id));
+    futures::stream::unfold(None, move |subscription: Option<SharedStream>| {
+        let listener = listener.clone();
+        let filter = filter.clone();
+        let package_id = package_id.clone();
+        async move {
+            let mut subscription = subscription;
+            loop {
+                let stream = subscription.get_or_insert_with(|| listener.subscribe(filter.clone()));
+                match stream.next().await {
+                    Some(event) => return Some(((package_id, event), subscription)),
+                    None => {
+                        subscription = None;
+                        tokio::time::sleep(WATCH_RESUBSCRIBE_DELAY).await;
+                    }
+                }
+            }
+        }
+    })
+    .boxed()
+}
+
+/// digest of a transaction notification, a transaction response or bare effects
+fn notification_digest(result: &Value) -> Option<String> {
+    result
+        .get("digest")
+        .or_else(|| result.get("transactionDigest"))
+        .and_then(|digest| digest.as_str())
+        .map(str::to_string)
+}
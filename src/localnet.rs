@@ -0,0 +1,299 @@
+use crate::SuiClient;
+use crate::types::{
+    FaucetCoinInfo, FaucetError, FaucetResponse, LocalnetError, SuiClientConfig, SuiError,
+};
+use crate::wallet::Wallet;
+use std::fs::{self, File};
+use std::net::{Ipv4Addr, TcpListener, TcpStream};
+use std::path::PathBuf;
+use std::process::{Child, Command, Stdio};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+/// wait between readiness probes of a starting network
+const PROBE_INTERVAL: Duration = Duration::from_millis(250);
+/// bytes of the process log kept in an exit error
+const LOG_TAIL_BYTES: u64 = 2048;
+
+/// # Localnet config
+///
+/// # Fields
+/// - sui_binary : path of the `sui` binary, `SUI_BINARY` or `sui` on the `PATH` when `None`
+/// - rpc_port : fullnode rpc port, a free port when `None`
+/// - faucet_port : faucet port, a free port when `None`
+/// - startup_timeout : longest wait for the rpc and the faucet to answer
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LocalnetConfig {
+    pub sui_binary: Option<PathBuf>,
+    pub rpc_port: Option<u16>,
+    pub faucet_port: Option<u16>,
+    pub startup_timeout: Duration,
+}
+
+impl Default for LocalnetConfig {
+    fn default() -> Self {
+        Self {
+            sui_binary: None,
+            rpc_port: None,
+            faucet_port: None,
+            startup_timeout: Duration::from_secs(120),
+        }
+    }
+}
+
+/// # Localnet guard
+///
+/// Local network started with `sui start` for integration tests: a fresh genesis in a
+/// temporary config dir, a fullnode and a faucet. The process is killed and the config dir
+/// removed when the guard drops.
+///
+/// ## Example
+/// ```no_run
+/// use sui_network_sdk::localnet::{LocalnetConfig, LocalnetGuard};
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let localnet = LocalnetGuard::start(LocalnetConfig::default()).await.unwrap();
+///     let wallet = localnet.funded_wallet().await.unwrap();
+///     let coins = localnet.client().get_coin_vec(wallet.get_address(), None).await.unwrap();
+///     assert!(!coins.is_empty());
+/// }
+/// ```
+pub struct LocalnetGuard {
+    process: Child,
+    config_dir: PathBuf,
+    client: SuiClient,
+    faucet_url: String,
+    startup_timeout: Duration,
+}
+
+impl LocalnetGuard {
+    /// # Start
+    ///
+    /// Launches `sui start --force-regenesis` with a faucet and waits until the rpc answers
+    /// `sui_getChainIdentifier` and the faucet accepts connections.
+    ///
+    /// ## Parameters
+    /// - config : binary, ports and startup timeout
+    ///
+    /// ## Returns
+    /// - Ok(LocalnetGuard) : running network
+    /// - Err(SuiError::Localnet) : `BinaryNotFound`, `PortInUse`, `Exited` with the end of
+    ///   the process log, or `Timeout`
+    ///
+    /// ## Example
+    /// ```rust
+    /// use std::net::TcpListener;
+    /// use sui_network_sdk::localnet::{LocalnetConfig, LocalnetGuard};
+    /// use sui_network_sdk::types::{LocalnetError, SuiError};
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let missing = LocalnetConfig {
+    ///         sui_binary: Some("/nonexistent/sui".into()),
+    ///         ..Default::default()
+    ///     };
+    ///     assert!(matches!(
+    ///         LocalnetGuard::start(missing).await,
+    ///         Err(SuiError::Localnet(LocalnetError::BinaryNotFound(_)))
+    ///     ));
+    ///
+    ///     let taken = TcpListener::bind("127.0.0.1:0").unwrap();
+    ///     let port = taken.local_addr().unwrap().port();
+    ///     let collision = LocalnetConfig { rpc_port: Some(port), ..Default::default() };
+    ///     match LocalnetGuard::start(collision).await {
+    ///         Err(SuiError::Localnet(LocalnetError::PortInUse(p))) => assert_eq!(p, port),
+    ///         other => panic!("unexpected {:?}", other.err()),
+    ///     }
+    ///
+    ///     # #[cfg(unix)]
+    ///     # {
+    ///     // a binary that exits at once is reported with its status
+    ///     let exits = LocalnetConfig { sui_binary: Some("false".into()), ..Default::default() };
+    ///     assert!(matches!(
+    ///         LocalnetGuard::start(exits).await,
+    ///         Err(SuiError::Localnet(LocalnetError::Exited { .. }))
+    ///     ));
+    ///     # }
+    /// }
+    /// ```
+    pub async fn start(config: LocalnetConfig) -> Result<Self, SuiError> {
+        let rpc_port = port(config.rpc_port)?;
+        let faucet_port = port(config.faucet_port)?;
+        let sui_binary = config
+            .sui_binary
+            .or_else(|| std::env::var_os("SUI_BINARY").map(PathBuf::from))
+            .unwrap_or_else(|| PathBuf::from("sui"));
+        let config_dir = std::env::temp_dir().join(format!(
+            "sui-localnet-{}-{}",
+            std::process::id(),
+            SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map_or(0, |d| d.as_nanos())
+        ));
+        fs::create_dir_all(&config_dir)?;
+        let log = File::create(config_dir.join("sui.log"))?;
+        let spawned = Command::new(&sui_binary)
+            .args(["start", "--force-regenesis"])
+            .arg(format!("--with-faucet=127.0.0.1:{}", faucet_port))
+            .arg(format!("--fullnode-rpc-port={}", rpc_port))
+            .env("SUI_CONFIG_DIR", &config_dir)
+            .stdin(Stdio::null())
+            .stdout(log.try_clone()?)
+            .stderr(log)
+            .spawn();
+        let process = match spawned {
+            Ok(process) => process,
+            Err(e) => {
+                let _ = fs::remove_dir_all(&config_dir);
+                return Err(match e.kind() {
+                    std::io::ErrorKind::NotFound => SuiError::Localnet(
+                        LocalnetError::BinaryNotFound(sui_binary.display().to_string()),
+                    ),
+                    _ => e.into(),
+                });
+            }
+        };
+        let client = SuiClient::new(SuiClientConfig {
+            rpc_url: format!("http://127.0.0.1:{}", rpc_port),
+            wss_url: format!("ws://127.0.0.1:{}", rpc_port),
+            faucet_url: format!("http://127.0.0.1:{}/gas", faucet_port),
+            ..Default::default()
+        });
+        // dropped on an error, which stops the process and removes the dir
+        let mut guard = Self {
+            process,
+            config_dir,
+            faucet_url: client.config().faucet_url.clone(),
+            client,
+            startup_timeout: config.startup_timeout,
+        };
+        guard.wait_ready(faucet_port).await?;
+        Ok(guard)
+    }
+
+    /// client of the fullnode
+    pub fn client(&self) -> &SuiClient {
+        &self.client
+    }
+
+    /// url of the faucet's `/gas` endpoint
+    pub fn faucet_url(&self) -> &str {
+        &self.faucet_url
+    }
+
+    /// # Fund
+    ///
+    /// ## Parameters
+    /// - address : recipient of the faucet coins
+    ///
+    /// ## Returns
+    /// - Ok(Vec<FaucetCoinInfo>) : coins sent
+    /// - Err(SuiError) : the faucet refused, see `FaucetError`
+    pub async fn fund(&self, address: &str) -> Result<Vec<FaucetCoinInfo>, SuiError> {
+        let body = serde_json::json!({ "FixedAmountRequest": { "recipient": address } });
+        let response = reqwest::Client::new()
+            .post(&self.faucet_url)
+            .json(&body)
+            .send()
+            .await?;
+        let status = response.status();
+        let text = response.text().await?;
+        if !status.is_success() {
+            return Err(SuiError::Faucet(FaucetError::classify(
+                Some(status.as_u16()),
+                &text,
+                None,
+            )));
+        }
+        serde_json::from_str::<FaucetResponse>(&text)?
+            .into_result()
+            .map_err(SuiError::Faucet)
+    }
+
+    /// # Funded wallet
+    ///
+    /// ## Returns
+    /// - Ok(Wallet) : new wallet whose faucet coins are readable from the fullnode
+    /// - Err(SuiError) : the faucet refused, or the coins did not show up in time
+    pub async fn funded_wallet(&self) -> Result<Wallet, SuiError> {
+        let wallet = Wallet::new()?;
+        self.fund(wallet.get_address()).await?;
+        let started = Instant::now();
+        while self
+            .client
+            .get_coin_vec(wallet.get_address(), None)
+            .await?
+            .is_empty()
+        {
+            if started.elapsed() >= self.startup_timeout {
+                return Err(SuiError::Localnet(LocalnetError::Timeout {
+                    waited: started.elapsed(),
+                }));
+            }
+            tokio::time::sleep(PROBE_INTERVAL).await;
+        }
+        Ok(wallet)
+    }
+
+    /// wait for the rpc and the faucet, failing early when the process exits
+    async fn wait_ready(&mut self, faucet_port: u16) -> Result<(), SuiError> {
+        let started = Instant::now();
+        loop {
+            if let Some(status) = self.process.try_wait()? {
+                return Err(SuiError::Localnet(LocalnetError::Exited {
+                    status: status.to_string(),
+                    log: self.log_tail(),
+                }));
+            }
+            let rpc_ready = self
+                .client
+                .request::<String>("sui_getChainIdentifier", vec![])
+                .await
+                .is_ok();
+            if rpc_ready && TcpStream::connect((Ipv4Addr::LOCALHOST, faucet_port)).is_ok() {
+                return Ok(());
+            }
+            if started.elapsed() >= self.startup_timeout {
+                return Err(SuiError::Localnet(LocalnetError::Timeout {
+                    waited: started.elapsed(),
+                }));
+            }
+            tokio::time::sleep(PROBE_INTERVAL).await;
+        }
+    }
+
+    /// end of the process log
+    fn log_tail(&self) -> String {
+        let log = fs::read(self.config_dir.join("sui.log")).unwrap_or_default();
+        let start = log.len().saturating_sub(LOG_TAIL_BYTES as usize);
+        String::from_utf8_lossy(&log[start..]).trim().to_string()
+    }
+}
+
+impl Drop for LocalnetGuard {
+    fn drop(&mut self) {
+        let _ = self.process.kill();
+        let _ = self.process.wait();
+        let _ = fs::remove_dir_all(&self.config_dir);
+    }
+}
+
+impl std::fmt::Debug for LocalnetGuard {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("LocalnetGuard")
+            .field("pid", &self.process.id())
+            .field("config_dir", &self.config_dir)
+            .field("faucet_url", &self.faucet_url)
+            .finish()
+    }
+}
+
+/// the requested port when free, a free port otherwise
+fn port(requested: Option<u16>) -> Result<u16, SuiError> {
+    let listener = TcpListener::bind((Ipv4Addr::LOCALHOST, requested.unwrap_or(0)));
+    match (listener, requested) {
+        (Ok(listener), _) => Ok(listener.local_addr()?.port()),
+        (Err(_), Some(port)) => Err(SuiError::Localnet(LocalnetError::PortInUse(port))),
+        (Err(e), None) => Err(e.into()),
+    }
+}
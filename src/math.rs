@@ -0,0 +1,291 @@
+use crate::coin::SUI_DECIMALS;
+use crate::types::{CoinAmountError, SuiError};
+
+/// # Checked add delta
+///
+/// Applies a signed balance change to an unsigned balance.
+///
+/// ## Parameters
+/// - balance : balance in base units
+/// - delta : signed change in base units, as in a `BalanceChange`
+///
+/// ## Returns
+/// - Ok(u64) : new balance
+/// - Err(SuiError::CoinAmount) : `Underflow` when the balance would drop below zero,
+///   `Overflow` when it would not fit in a u64
+///
+/// ## Example
+/// ```rust
+/// use sui_network_sdk::math::checked_add_delta;
+/// use sui_network_sdk::types::{CoinAmountError, SuiError};
+///
+/// assert_eq!(checked_add_delta(100, -40).unwrap(), 60);
+/// assert_eq!(checked_add_delta(100, 40).unwrap(), 140);
+/// assert_eq!(checked_add_delta(100, -100).unwrap(), 0);
+/// assert_eq!(checked_add_delta(u64::MAX - 1, 1).unwrap(), u64::MAX);
+/// assert!(matches!(
+///     checked_add_delta(100, -101),
+///     Err(SuiError::CoinAmount(CoinAmountError::Underflow))
+/// ));
+/// assert!(matches!(
+///     checked_add_delta(u64::MAX, 1),
+///     Err(SuiError::CoinAmount(CoinAmountError::Overflow))
+/// ));
+///
+/// // every boundary around zero and u64::MAX, with the extremes of i128
+/// let balances = [0, 1, u64::MAX / 2, u64::MAX - 1, u64::MAX];
+/// let deltas = [i128::MIN, -i128::from(u64::MAX) - 1, -1, 0, 1, i128::from(u64::MAX), i128::MAX];
+/// for balance in balances {
+///     for delta in deltas {
+///         let exact = i128::from(balance).checked_add(delta);
+///         match checked_add_delta(balance, delta) {
+///             Ok(sum) => assert_eq!(Some(i128::from(sum)), exact),
+///             Err(SuiError::CoinAmount(CoinAmountError::Underflow)) => {
+///                 assert!(exact.is_some_and(|e| e < 0))
+///             }
+///             Err(SuiError::CoinAmount(CoinAmountError::Overflow)) => {
+///                 assert!(exact.is_none_or(|e| e > i128::from(u64::MAX)))
+///             }
+///             Err(e) => panic!("unexpected {}", e),
+///         }
+///     }
+/// }
+/// ```
+pub fn checked_add_delta(balance: u64, delta: i128) -> Result<u64, SuiError> {
+    match i128::from(balance).checked_add(delta) {
+        Some(sum) if sum < 0 => Err(SuiError::CoinAmount(CoinAmountError::Underflow)),
+        Some(sum) => {
+            u64::try_from(sum).map_err(|_| SuiError::CoinAmount(CoinAmountError::Overflow))
+        }
+        None => Err(SuiError::CoinAmount(CoinAmountError::Overflow)),
+    }
+}
+
+/// # Saturating add delta
+///
+/// Applies a signed balance change to an unsigned balance, stopping at zero and
+/// `u64::MAX`.
+///
+/// ## Example
+/// ```rust
+/// use sui_network_sdk::math::saturating_add_delta;
+///
+/// assert_eq!(saturating_add_delta(100, -40), 60);
+/// assert_eq!(saturating_add_delta(100, -101), 0);
+/// assert_eq!(saturating_add_delta(u64::MAX, 1), u64::MAX);
+/// assert_eq!(saturating_add_delta(0, i128::MIN), 0);
+/// assert_eq!(saturating_add_delta(u64::MAX, i128::MAX), u64::MAX);
+/// ```
+pub fn saturating_add_delta(balance: u64, delta: i128) -> u64 {
+    let sum = i128::from(balance).saturating_add(delta);
+    u64::try_from(sum).unwrap_or(if sum < 0 { 0 } else { u64::MAX })
+}
+
+/// # Balance delta
+///
+/// ## Parameters
+/// - before : balance before, in base units
+/// - after : balance after, in base units
+///
+/// ## Returns
+/// signed change, any two u64 balances differ by an amount an i128 holds
+///
+/// ## Example
+/// ```rust
+/// use sui_network_sdk::math::balance_delta;
+///
+/// assert_eq!(balance_delta(100, 60), -40);
+/// assert_eq!(balance_delta(0, u64::MAX), i128::from(u64::MAX));
+/// assert_eq!(balance_delta(u64::MAX, 0), -i128::from(u64::MAX));
+/// ```
+pub fn balance_delta(before: u64, after: u64) -> i128 {
+    i128::from(after) - i128::from(before)
+}
+
+/// # Received
+///
+/// ## Parameters
+/// - delta : signed balance change
+///
+/// ## Returns
+/// amount received, `None` for a debit or no change
+///
+/// ## Example
+/// ```rust
+/// use sui_network_sdk::math::received;
+///
+/// assert_eq!(received(25), Some(25));
+/// assert_eq!(received(i128::MAX), Some(i128::MAX as u128));
+/// assert_eq!(received(0), None);
+/// assert_eq!(received(-25), None);
+/// ```
+pub fn received(delta: i128) -> Option<u128> {
+    u128::try_from(delta).ok().filter(|amount| *amount > 0)
+}
+
+/// # Format signed amount
+///
+/// ## Parameters
+/// - delta : signed amount in base units
+/// - decimals : decimals of the coin
+///
+/// ## Returns
+/// display amount without trailing zeros, with a `-` when negative
+///
+/// ## Example
+/// ```rust
+/// use sui_network_sdk::math::format_signed_amount;
+///
+/// assert_eq!(format_signed_amount(-250_000_000, 9), "-0.25");
+/// assert_eq!(format_signed_amount(1_500_000, 6), "1.5");
+/// assert_eq!(format_signed_amount(0, 9), "0");
+/// assert_eq!(
+///     format_signed_amount(i128::MIN, 0),
+///     "-170141183460469231731687303715884105728"
+/// );
+/// ```
+pub fn format_signed_amount(delta: i128, decimals: u8) -> String {
+    let sign = if delta < 0 { "-" } else { "" };
+    format!("{}{}", sign, format_units(delta.unsigned_abs(), decimals))
+}
+
+/// # Format SUI delta
+///
+/// ## Parameters
+/// - delta : signed amount in MIST
+///
+/// ## Returns
+/// amount in SUI with the unit, e.g. `-0.25 SUI`
+///
+/// ## Example
+/// ```rust
+/// use sui_network_sdk::math::format_sui_delta;
+///
+/// assert_eq!(format_sui_delta(-250_000_000), "-0.25 SUI");
+/// assert_eq!(format_sui_delta(2_100_000), "0.0021 SUI");
+/// ```
+pub fn format_sui_delta(delta: i128) -> String {
+    format!("{} SUI", format_signed_amount(delta, SUI_DECIMALS))
+}
+
+/// # Parse signed amount
+///
+/// Inverse of [`format_signed_amount`], amounts of at most `decimals` fractional digits
+/// round-trip exactly.
+///
+/// ## Parameters
+/// - amount : display amount, digits with an optional decimal point and sign
+/// - decimals : decimals of the coin
+///
+/// ## Returns
+/// - Ok(i128) : signed amount in base units
+/// - Err(CoinAmountError) : invalid amount, more decimal places than the coin, or an
+///   amount out of the i128 range
+///
+/// ## Example
+/// ```rust
+/// use sui_network_sdk::math::{format_signed_amount, parse_signed_amount};
+/// use sui_network_sdk::types::CoinAmountError;
+///
+/// assert_eq!(parse_signed_amount("-0.25", 9), Ok(-250_000_000));
+/// assert_eq!(parse_signed_amount("+1.5", 6), Ok(1_500_000));
+/// assert_eq!(parse_signed_amount("-0", 9), Ok(0));
+/// assert_eq!(parse_signed_amount("-170141183460469231731687303715884105728", 0), Ok(i128::MIN));
+/// assert_eq!(
+///     parse_signed_amount("170141183460469231731687303715884105728", 0),
+///     Err(CoinAmountError::Overflow)
+/// );
+/// assert!(matches!(parse_signed_amount("--1", 9), Err(CoinAmountError::Invalid(_))));
+/// assert!(matches!(parse_signed_amount("-", 9), Err(CoinAmountError::Invalid(_))));
+/// assert!(matches!(parse_signed_amount("- 1", 9), Err(CoinAmountError::Invalid(_))));
+///
+/// // deterministic fuzzing: arbitrary input never panics
+/// let mut seed = 0x2545_f491_4f6c_dd1du64;
+/// let mut next = move || {
+///     seed ^= seed << 13;
+///     seed ^= seed >> 7;
+///     seed ^= seed << 17;
+///     seed
+/// };
+/// let alphabet = b"0123456789.-+ e\xc3\xa9";
+/// for _ in 0..20_000 {
+///     let len = (next() % 48) as usize;
+///     let bytes: Vec<u8> = (0..len).map(|_| alphabet[(next() % alphabet.len() as u64) as usize]).collect();
+///     let input = String::from_utf8_lossy(&bytes);
+///     let _ = parse_signed_amount(&input, (next() % 40) as u8);
+/// }
+///
+/// // amounts of up to 9 fractional digits round-trip exactly
+/// for _ in 0..20_000 {
+///     let decimals = (next() % 10) as u8;
+///     let raw = (i128::from(next()) << 64 | i128::from(next())) >> (next() % 127);
+///     let text = format_signed_amount(raw, decimals);
+///     assert_eq!(parse_signed_amount(&text, decimals), Ok(raw), "{}", text);
+///
+///     let whole = next() % 1_000_000_000_000;
+///     let fraction = format!("{:09}", next() % 1_000_000_000);
+///     let fraction = &fraction[..(next() % 10) as usize];
+///     let text = format!("-{}.{}", whole, fraction);
+///     let parsed = parse_signed_amount(&text, 9).unwrap();
+///     assert_eq!(parse_signed_amount(&format_signed_amount(parsed, 9), 9), Ok(parsed));
+/// }
+/// ```
+pub fn parse_signed_amount(amount: &str, decimals: u8) -> Result<i128, CoinAmountError> {
+    let trimmed = amount.trim();
+    let (negative, digits) = match trimmed.as_bytes().first() {
+        Some(b'-') => (true, &trimmed[1..]),
+        Some(b'+') => (false, &trimmed[1..]),
+        _ => (false, trimmed),
+    };
+    if digits.starts_with(|c: char| !c.is_ascii_digit() && c != '.') {
+        return Err(CoinAmountError::Invalid(amount.to_string()));
+    }
+    let magnitude = parse_units(digits, decimals).map_err(|e| match e {
+        CoinAmountError::Invalid(_) => CoinAmountError::Invalid(amount.to_string()),
+        e => e,
+    })?;
+    let signed = if negative {
+        0i128.checked_sub_unsigned(magnitude)
+    } else {
+        i128::try_from(magnitude).ok()
+    };
+    signed.ok_or(CoinAmountError::Overflow)
+}
+
+/// display amount of base units, without trailing zeros
+pub(crate) fn format_units(raw: u128, decimals: u8) -> String {
+    let decimals = decimals as usize;
+    let digits = format!("{:0>width$}", raw, width = decimals + 1);
+    let (whole, fraction) = digits.split_at(digits.len() - decimals);
+    let fraction = fraction.trim_end_matches('0');
+    if fraction.is_empty() {
+        whole.to_string()
+    } else {
+        format!("{}.{}", whole, fraction)
+    }
+}
+
+/// base units of an unsigned display amount
+pub(crate) fn parse_units(amount: &str, decimals: u8) -> Result<u128, CoinAmountError> {
+    let invalid = || CoinAmountError::Invalid(amount.to_string());
+    let trimmed = amount.trim();
+    let (whole, fraction) = trimmed.split_once('.').unwrap_or((trimmed, ""));
+    if whole.is_empty() && fraction.is_empty()
+        || !whole
+            .bytes()
+            .chain(fraction.bytes())
+            .all(|b| b.is_ascii_digit())
+    {
+        return Err(invalid());
+    }
+    let fraction = fraction.trim_end_matches('0');
+    if fraction.len() > decimals as usize {
+        return Err(CoinAmountError::TooManyDecimals { decimals });
+    }
+    let digits = format!("{}{:0<width$}", whole, fraction, width = decimals as usize);
+    let digits = digits.trim_start_matches('0');
+    if digits.is_empty() {
+        return Ok(0);
+    }
+    digits.parse().map_err(|_| CoinAmountError::Overflow)
+}
@@ -0,0 +1,930 @@
+use crate::coin::{SUI_DECIMALS, format_amount};
+use crate::types::{Object, ObjectRef, Owner, SuiError, address};
+use fastcrypto::encoding::{Base58, Encoding};
+use fastcrypto::hash::{Blake2b256, HashFunction};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt;
+use std::str::FromStr;
+
+/// # Argument
+///
+/// Argument of a programmable transaction command.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Argument {
+    /// the gas coin
+    GasCoin,
+    /// transaction input by index
+    Input(u16),
+    /// result of a command by index
+    Result(u16),
+    /// element of a command result tuple, e.g. one coin of a split
+    NestedResult(u16, u16),
+}
+
+/// # Object digest
+///
+/// 32 byte object digest, base58 in JSON, length prefixed bytes in BCS.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ObjectDigest(pub [u8; 32]);
+
+impl Serialize for ObjectDigest {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_bytes(&self.0)
+    }
+}
+
+impl<'de> Deserialize<'de> for ObjectDigest {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let bytes = Vec::<u8>::deserialize(deserializer)?;
+        bytes
+            .try_into()
+            .map(ObjectDigest)
+            .map_err(|_| serde::de::Error::custom("digest is not 32 bytes"))
+    }
+}
+
+impl fmt::Display for ObjectDigest {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&Base58::encode(self.0))
+    }
+}
+
+impl FromStr for ObjectDigest {
+    type Err = SuiError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let bytes = Base58::decode(s).map_err(|e| SuiError::Bcs(format!("digest {}: {}", s, e)))?;
+        bytes
+            .try_into()
+            .map(ObjectDigest)
+            .map_err(|_| SuiError::Bcs(format!("digest {} is not 32 bytes", s)))
+    }
+}
+
+/// object id, version and digest in BCS form
+pub type ObjectReference = ([u8; 32], u64, ObjectDigest);
+
+/// # Address bytes
+///
+/// ## Parameters
+/// - address : hex address or object id
+///
+/// ## Returns
+/// - Ok([u8; 32]) : address bytes
+/// - Err(SuiError) : not a hex address
+pub fn address_bytes(address: &str) -> Result<[u8; 32], SuiError> {
+    let canonical = address::normalize(address)
+        .ok_or_else(|| SuiError::Hex(format!("invalid address {}", address)))?;
+    let mut bytes = [0u8; 32];
+    hex::decode_to_slice(&canonical[2..], &mut bytes)?;
+    Ok(bytes)
+}
+
+/// # Object reference
+///
+/// ## Parameters
+/// - object_ref : object reference of a response
+///
+/// ## Returns
+/// - Ok(ObjectReference) : reference in BCS form
+/// - Err(SuiError) : malformed id or digest
+pub fn object_reference(object_ref: &ObjectRef) -> Result<ObjectReference, SuiError> {
+    Ok((
+        address_bytes(&object_ref.object_id)?,
+        object_ref.version,
+        object_ref.digest.parse()?,
+    ))
+}
+
+/// # Transaction digest
+///
+/// Digest of a transaction as reported by the node, base58 of BLAKE2b-256 over
+/// `TransactionData::` followed by the transaction bytes.
+///
+/// ## Parameters
+/// - tx_bytes : BCS bytes of the transaction data
+///
+/// ## Returns
+/// base58 transaction digest
+pub fn transaction_digest(tx_bytes: &[u8]) -> String {
+    let mut hasher = Blake2b256::default();
+    hasher.update(b"TransactionData::");
+    hasher.update(tx_bytes);
+    Base58::encode(hasher.finalize().digest)
+}
+
+/// # Call argument
+///
+/// Transaction input.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum CallArg {
+    /// BCS encoded pure value
+    Pure(Vec<u8>),
+    /// object
+    Object(ObjectArg),
+}
+
+/// # Object argument
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ObjectArg {
+    /// owned or immutable object
+    ImmOrOwnedObject(ObjectReference),
+    /// shared object
+    SharedObject {
+        id: [u8; 32],
+        initial_shared_version: u64,
+        mutable: bool,
+    },
+    /// object sent to an object, to be received
+    Receiving(ObjectReference),
+}
+
+impl ObjectArg {
+    /// # Object argument of an object
+    ///
+    /// ## Parameters
+    /// - object : object read with its owner
+    /// - mutable : whether a shared object is used mutably
+    ///
+    /// ## Returns
+    /// - Ok(ObjectArg) : shared or owned object argument
+    /// - Err(SuiError) : malformed id or digest
+    pub fn from_object(object: &Object, mutable: bool) -> Result<Self, SuiError> {
+        match &object.owner {
+            Some(Owner::Shared(shared)) => Ok(ObjectArg::SharedObject {
+                id: address_bytes(&object.object_id)?,
+                initial_shared_version: shared.initial_shared_version,
+                mutable,
+            }),
+            _ => Ok(ObjectArg::ImmOrOwnedObject((
+                address_bytes(&object.object_id)?,
+                object.version,
+                object.digest.parse()?,
+            ))),
+        }
+    }
+}
+
+/// # Type tag
+///
+/// Move type, parsed from its string form.
+///
+/// ## Example
+/// ```rust
+/// use sui_network_sdk::ptb::TypeTag;
+/// let tag: TypeTag = "0x2::coin::Coin<0x2::sui::SUI>".parse().unwrap();
+/// assert_eq!(
+///     tag.to_string(),
+///     format!("0x{:0>64}::coin::Coin<0x{:0>64}::sui::SUI>", "2", "2")
+/// );
+/// assert!("vector<u8>".parse::<TypeTag>().is_ok());
+/// assert!("0x2::coin".parse::<TypeTag>().is_err());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum TypeTag {
+    Bool,
+    U8,
+    U64,
+    U128,
+    Address,
+    Signer,
+    Vector(Box<TypeTag>),
+    Struct(Box<StructTag>),
+    U16,
+    U32,
+    U256,
+}
+
+/// # Struct tag
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct StructTag {
+    pub address: [u8; 32],
+    pub module: String,
+    pub name: String,
+    pub type_params: Vec<TypeTag>,
+}
+
+impl FromStr for StructTag {
+    type Err = SuiError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.parse::<TypeTag>()? {
+            TypeTag::Struct(tag) => Ok(*tag),
+            _ => Err(invalid_type(s)),
+        }
+    }
+}
+
+impl FromStr for TypeTag {
+    type Err = SuiError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (tag, rest) = parse_type_tag(s.trim())?;
+        if !rest.trim().is_empty() {
+            return Err(invalid_type(s));
+        }
+        Ok(tag)
+    }
+}
+
+impl fmt::Display for TypeTag {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TypeTag::Bool => write!(f, "bool"),
+            TypeTag::U8 => write!(f, "u8"),
+            TypeTag::U16 => write!(f, "u16"),
+            TypeTag::U32 => write!(f, "u32"),
+            TypeTag::U64 => write!(f, "u64"),
+            TypeTag::U128 => write!(f, "u128"),
+            TypeTag::U256 => write!(f, "u256"),
+            TypeTag::Address => write!(f, "address"),
+            TypeTag::Signer => write!(f, "signer"),
+            TypeTag::Vector(inner) => write!(f, "vector<{}>", inner),
+            TypeTag::Struct(tag) => {
+                write!(
+                    f,
+                    "0x{}::{}::{}",
+                    hex::encode(tag.address),
+                    tag.module,
+                    tag.name
+                )?;
+                if !tag.type_params.is_empty() {
+                    let params: Vec<String> =
+                        tag.type_params.iter().map(|t| t.to_string()).collect();
+                    write!(f, "<{}>", params.join(", "))?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+/// invalid type tag error
+fn invalid_type(s: &str) -> SuiError {
+    SuiError::Transaction(format!("invalid type tag {}", s))
+}
+
+/// parse one type tag off the front of the input, returns the tag and the rest
+fn parse_type_tag(input: &str) -> Result<(TypeTag, &str), SuiError> {
+    let input = input.trim_start();
+    let end = input.find(['<', '>', ',']).unwrap_or(input.len());
+    let (head, rest) = input.split_at(end);
+    let head = head.trim();
+    let primitive = match head {
+        "bool" => Some(TypeTag::Bool),
+        "u8" => Some(TypeTag::U8),
+        "u16" => Some(TypeTag::U16),
+        "u32" => Some(TypeTag::U32),
+        "u64" => Some(TypeTag::U64),
+        "u128" => Some(TypeTag::U128),
+        "u256" => Some(TypeTag::U256),
+        "address" => Some(TypeTag::Address),
+        "signer" => Some(TypeTag::Signer),
+        _ => None,
+    };
+    if let Some(primitive) = primitive {
+        return Ok((primitive, rest));
+    }
+    let (params, rest) = match rest.strip_prefix('<') {
+        Some(mut rest) => {
+            let mut params = Vec::new();
+            loop {
+                let (param, after) = parse_type_tag(rest)?;
+                params.push(param);
+                let after = after.trim_start();
+                if let Some(after) = after.strip_prefix(',') {
+                    rest = after;
+                } else if let Some(after) = after.strip_prefix('>') {
+                    break (params, after);
+                } else {
+                    return Err(invalid_type(input));
+                }
+            }
+        }
+        None => (Vec::new(), rest),
+    };
+    if head == "vector" {
+        let mut params = params;
+        return match (params.pop(), params.is_empty()) {
+            (Some(inner), true) => Ok((TypeTag::Vector(Box::new(inner)), rest)),
+            _ => Err(invalid_type(input)),
+        };
+    }
+    let mut parts = head.split("::");
+    match (parts.next(), parts.next(), parts.next(), parts.next()) {
+        (Some(address), Some(module), Some(name), None)
+            if !module.is_empty() && !name.is_empty() =>
+        {
+            Ok((
+                TypeTag::Struct(Box::new(StructTag {
+                    address: address_bytes(address)?,
+                    module: module.to_string(),
+                    name: name.to_string(),
+                    type_params: params,
+                })),
+                rest,
+            ))
+        }
+        _ => Err(invalid_type(input)),
+    }
+}
+
+/// # Move call command
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct ProgrammableMoveCall {
+    pub package: [u8; 32],
+    pub module: String,
+    pub function: String,
+    pub type_arguments: Vec<TypeTag>,
+    pub arguments: Vec<Argument>,
+}
+
+/// # Command
+///
+/// Command of a programmable transaction.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Command {
+    /// call a Move function
+    MoveCall(Box<ProgrammableMoveCall>),
+    /// transfer objects to an address
+    TransferObjects(Vec<Argument>, Argument),
+    /// split amounts off a coin
+    SplitCoins(Argument, Vec<Argument>),
+    /// merge coins into the first
+    MergeCoins(Argument, Vec<Argument>),
+    /// publish modules with dependencies
+    Publish(Vec<Vec<u8>>, Vec<[u8; 32]>),
+    /// build a vector of arguments
+    MakeMoveVec(Option<TypeTag>, Vec<Argument>),
+    /// upgrade a package
+    Upgrade(Vec<Vec<u8>>, Vec<[u8; 32]>, [u8; 32], Argument),
+}
+
+impl Command {
+    /// # Command summary
+    ///
+    /// ## Returns
+    /// command name, with the function of a Move call
+    ///
+    /// ## Example
+    /// ```rust
+    /// use sui_network_sdk::ptb::{Argument, Command, ProgrammableTransactionBuilder};
+    ///
+    /// let mut builder = ProgrammableTransactionBuilder::new();
+    /// builder.move_call("0x2", "clock", "timestamp_ms", vec![], vec![]).unwrap();
+    /// let pt = builder.finish();
+    /// assert_eq!(
+    ///     pt.commands[0].summary(),
+    ///     "MoveCall 0x0000000000000000000000000000000000000000000000000000000000000002::clock::timestamp_ms"
+    /// );
+    /// assert_eq!(Command::MergeCoins(Argument::GasCoin, vec![]).summary(), "MergeCoins");
+    /// ```
+    pub fn summary(&self) -> String {
+        match self {
+            Command::MoveCall(call) => format!(
+                "MoveCall 0x{}::{}::{}",
+                hex::encode(call.package),
+                call.module,
+                call.function
+            ),
+            Command::TransferObjects(..) => "TransferObjects".to_string(),
+            Command::SplitCoins(..) => "SplitCoins".to_string(),
+            Command::MergeCoins(..) => "MergeCoins".to_string(),
+            Command::Publish(..) => "Publish".to_string(),
+            Command::MakeMoveVec(..) => "MakeMoveVec".to_string(),
+            Command::Upgrade(..) => "Upgrade".to_string(),
+        }
+    }
+}
+
+/// # Programmable transaction
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct ProgrammableTransaction {
+    pub inputs: Vec<CallArg>,
+    pub commands: Vec<Command>,
+}
+
+impl ProgrammableTransaction {
+    /// BCS bytes of the transaction kind, the transaction without sender and gas
+    pub fn kind_bytes(&self) -> Result<Vec<u8>, SuiError> {
+        Ok(bcs::to_bytes(&TransactionKind::ProgrammableTransaction(
+            self.clone(),
+        ))?)
+    }
+
+    /// recipients of the transfer commands given as address inputs
+    pub fn transfer_recipients(&self) -> Vec<String> {
+        let mut recipients: Vec<String> = self
+            .commands
+            .iter()
+            .filter_map(|command| match command {
+                Command::TransferObjects(_, Argument::Input(index)) => {
+                    match self.inputs.get(*index as usize) {
+                        Some(CallArg::Pure(bytes)) if bytes.len() == 32 => {
+                            Some(format!("0x{}", hex::encode(bytes)))
+                        }
+                        _ => None,
+                    }
+                }
+                _ => None,
+            })
+            .collect();
+        recipients.sort();
+        recipients.dedup();
+        recipients
+    }
+
+    /// # Describe
+    ///
+    /// Human-readable description of each command, inputs resolved to addresses, object
+    /// ids and amounts. Amounts split off the gas coin are in SUI, other amounts in base
+    /// units.
+    ///
+    /// ## Returns
+    /// one description per command
+    ///
+    /// ## Example
+    /// ```rust
+    /// use sui_network_sdk::ptb::{Argument, ProgrammableTransactionBuilder};
+    ///
+    /// let mut builder = ProgrammableTransactionBuilder::new();
+    /// let coins = builder.split_coins(Argument::GasCoin, &[1_500_000_000]).unwrap();
+    /// builder.transfer_objects(coins.clone(), "0xabc").unwrap();
+    /// let amount = builder.pure(&3u64).unwrap();
+    /// builder.move_call("0x5a0b", "market", "buy", vec![], vec![coins[0], amount, amount]).unwrap();
+    /// let descriptions = builder.finish().describe();
+    /// assert_eq!(descriptions[0], "Split 1.5 SUI off the gas coin");
+    /// assert_eq!(descriptions[1], format!("Transfer 1.5 SUI to 0x{:0>64}", "abc"));
+    /// assert_eq!(descriptions[2], format!("Call 0x{:0>64}::market::buy with 3 args", "5a0b"));
+    /// ```
+    pub fn describe(&self) -> Vec<String> {
+        self.commands
+            .iter()
+            .map(|command| self.describe_command(command))
+            .collect()
+    }
+
+    fn describe_command(&self, command: &Command) -> String {
+        match command {
+            Command::MoveCall(call) => {
+                let type_arguments = if call.type_arguments.is_empty() {
+                    String::new()
+                } else {
+                    let tags: Vec<String> = call
+                        .type_arguments
+                        .iter()
+                        .map(ToString::to_string)
+                        .collect();
+                    format!("<{}>", tags.join(", "))
+                };
+                format!(
+                    "Call 0x{}::{}::{}{} with {}",
+                    hex::encode(call.package),
+                    call.module,
+                    call.function,
+                    type_arguments,
+                    count(call.arguments.len(), "arg")
+                )
+            }
+            Command::TransferObjects(objects, recipient) => format!(
+                "Transfer {} to {}",
+                self.describe_arguments(objects),
+                self.describe_address(recipient)
+            ),
+            Command::SplitCoins(coin, amounts) => {
+                let amounts: Vec<String> = amounts
+                    .iter()
+                    .map(|amount| self.describe_amount(coin, amount))
+                    .collect();
+                format!(
+                    "Split {} off {}",
+                    amounts.join(", "),
+                    self.describe_argument(coin)
+                )
+            }
+            Command::MergeCoins(target, coins) => format!(
+                "Merge {} into {}",
+                self.describe_arguments(coins),
+                self.describe_argument(target)
+            ),
+            Command::Publish(modules, dependencies) => format!(
+                "Publish {} with {}",
+                count(modules.len(), "module"),
+                count(dependencies.len(), "dependency")
+            ),
+            Command::MakeMoveVec(type_tag, elements) => match type_tag {
+                Some(type_tag) => format!(
+                    "Make a vector<{}> of {}",
+                    type_tag,
+                    count(elements.len(), "element")
+                ),
+                None => format!("Make a vector of {}", count(elements.len(), "element")),
+            },
+            Command::Upgrade(modules, _, package, _) => format!(
+                "Upgrade package 0x{} with {}",
+                hex::encode(package),
+                count(modules.len(), "module")
+            ),
+        }
+    }
+
+    /// arguments joined with commas
+    fn describe_arguments(&self, arguments: &[Argument]) -> String {
+        let arguments: Vec<String> = arguments
+            .iter()
+            .map(|argument| self.describe_argument(argument))
+            .collect();
+        arguments.join(", ")
+    }
+
+    /// objects by id, coins split off the gas coin by amount
+    fn describe_argument(&self, argument: &Argument) -> String {
+        let split = |command: u16, coin: usize| match self.commands.get(command as usize) {
+            Some(Command::SplitCoins(source @ Argument::GasCoin, amounts)) => amounts
+                .get(coin)
+                .map(|amount| self.describe_amount(source, amount)),
+            _ => None,
+        };
+        match argument {
+            Argument::GasCoin => "the gas coin".to_string(),
+            Argument::Input(index) => match self.inputs.get(*index as usize) {
+                Some(CallArg::Object(
+                    ObjectArg::ImmOrOwnedObject((id, ..)) | ObjectArg::Receiving((id, ..)),
+                ))
+                | Some(CallArg::Object(ObjectArg::SharedObject { id, .. })) => {
+                    format!("object 0x{}", hex::encode(id))
+                }
+                _ => format!("input {}", index),
+            },
+            Argument::Result(command) => {
+                split(*command, 0).unwrap_or_else(|| format!("result of command {}", command))
+            }
+            Argument::NestedResult(command, coin) => split(*command, *coin as usize)
+                .unwrap_or_else(|| format!("result {} of command {}", coin, command)),
+        }
+    }
+
+    /// pure address input as an address
+    fn describe_address(&self, argument: &Argument) -> String {
+        match argument {
+            Argument::Input(index) => match self.inputs.get(*index as usize) {
+                Some(CallArg::Pure(bytes)) if bytes.len() == 32 => {
+                    format!("0x{}", hex::encode(bytes))
+                }
+                _ => self.describe_argument(argument),
+            },
+            _ => self.describe_argument(argument),
+        }
+    }
+
+    /// pure u64 input as an amount of the coin it is split off
+    fn describe_amount(&self, coin: &Argument, amount: &Argument) -> String {
+        let raw = match amount {
+            Argument::Input(index) => match self.inputs.get(*index as usize) {
+                Some(CallArg::Pure(bytes)) => <[u8; 8]>::try_from(bytes.as_slice())
+                    .ok()
+                    .map(u64::from_le_bytes),
+                _ => None,
+            },
+            _ => None,
+        };
+        match (raw, coin) {
+            (Some(raw), Argument::GasCoin) => {
+                format!("{} SUI", format_amount(raw, SUI_DECIMALS))
+            }
+            (Some(raw), _) => raw.to_string(),
+            (None, _) => self.describe_argument(amount),
+        }
+    }
+}
+
+/// count with a noun, plural unless one
+fn count(n: usize, noun: &str) -> String {
+    match (n, noun.strip_suffix('y')) {
+        (1, _) => format!("1 {}", noun),
+        (n, Some(stem)) => format!("{} {}ies", n, stem),
+        (n, None) => format!("{} {}s", n, noun),
+    }
+}
+
+/// # Programmable transaction builder
+///
+/// Collects inputs and commands, identical inputs are shared.
+///
+/// ## Example
+/// ```rust
+/// use sui_network_sdk::ptb::{Argument, Command, ProgrammableTransactionBuilder};
+///
+/// let mut builder = ProgrammableTransactionBuilder::new();
+/// let coins = builder.split_coins(Argument::GasCoin, &[1000, 2000]).unwrap();
+/// builder.transfer_objects(coins, "0x7d20").unwrap();
+/// let pt = builder.finish();
+/// assert_eq!(pt.inputs.len(), 3);
+/// assert_eq!(
+///     pt.commands[1],
+///     Command::TransferObjects(
+///         vec![Argument::NestedResult(0, 0), Argument::NestedResult(0, 1)],
+///         Argument::Input(2)
+///     )
+/// );
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct ProgrammableTransactionBuilder {
+    inputs: Vec<CallArg>,
+    commands: Vec<Command>,
+}
+
+impl ProgrammableTransactionBuilder {
+    /// create an empty builder
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// add an input, returns the existing input when an identical one was added before
+    pub fn input(&mut self, arg: CallArg) -> Argument {
+        let index = match self.inputs.iter().position(|input| *input == arg) {
+            Some(index) => index,
+            None => {
+                self.inputs.push(arg);
+                self.inputs.len() - 1
+            }
+        };
+        Argument::Input(index as u16)
+    }
+
+    /// add a BCS encoded pure input
+    pub fn pure<T: Serialize>(&mut self, value: &T) -> Result<Argument, SuiError> {
+        Ok(self.input(CallArg::Pure(bcs::to_bytes(value)?)))
+    }
+
+    /// add an object input
+    pub fn object(&mut self, arg: ObjectArg) -> Argument {
+        self.input(CallArg::Object(arg))
+    }
+
+    /// add a command, returns its result
+    pub fn command(&mut self, command: Command) -> Argument {
+        self.commands.push(command);
+        Argument::Result((self.commands.len() - 1) as u16)
+    }
+
+    /// call a Move function
+    pub fn move_call(
+        &mut self,
+        package: &str,
+        module: &str,
+        function: &str,
+        type_arguments: Vec<TypeTag>,
+        arguments: Vec<Argument>,
+    ) -> Result<Argument, SuiError> {
+        Ok(
+            self.command(Command::MoveCall(Box::new(ProgrammableMoveCall {
+                package: address_bytes(package)?,
+                module: module.to_string(),
+                function: function.to_string(),
+                type_arguments,
+                arguments,
+            }))),
+        )
+    }
+
+    /// transfer objects to a recipient
+    pub fn transfer_objects(
+        &mut self,
+        objects: Vec<Argument>,
+        recipient: &str,
+    ) -> Result<Argument, SuiError> {
+        let recipient = self.pure(&address_bytes(recipient)?)?;
+        Ok(self.command(Command::TransferObjects(objects, recipient)))
+    }
+
+    /// split amounts off a coin, returns one coin per amount
+    pub fn split_coins(
+        &mut self,
+        coin: Argument,
+        amounts: &[u64],
+    ) -> Result<Vec<Argument>, SuiError> {
+        let amounts = amounts
+            .iter()
+            .map(|amount| self.pure(amount))
+            .collect::<Result<Vec<_>, _>>()?;
+        let count = amounts.len() as u16;
+        let Argument::Result(index) = self.command(Command::SplitCoins(coin, amounts)) else {
+            unreachable!("commands return results")
+        };
+        Ok((0..count)
+            .map(|i| Argument::NestedResult(index, i))
+            .collect())
+    }
+
+    /// merge coins into the target coin
+    pub fn merge_coins(&mut self, target: Argument, coins: Vec<Argument>) -> Argument {
+        self.command(Command::MergeCoins(target, coins))
+    }
+
+    /// number of commands so far
+    pub fn command_count(&self) -> usize {
+        self.commands.len()
+    }
+
+    /// number of inputs so far
+    pub fn input_count(&self) -> usize {
+        self.inputs.len()
+    }
+
+    /// finish the programmable transaction
+    pub fn finish(self) -> ProgrammableTransaction {
+        ProgrammableTransaction {
+            inputs: self.inputs,
+            commands: self.commands,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+enum TransactionKind {
+    ProgrammableTransaction(ProgrammableTransaction),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+struct GasConfig {
+    payment: Vec<ObjectReference>,
+    owner: [u8; 32],
+    price: u64,
+    budget: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+enum TransactionExpiration {
+    None,
+    Epoch(u64),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+struct TransactionDataV1 {
+    kind: TransactionKind,
+    sender: [u8; 32],
+    gas_data: GasConfig,
+    expiration: TransactionExpiration,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+enum VersionedTransactionData {
+    V1(TransactionDataV1),
+}
+
+/// # Transaction data
+///
+/// Unsigned transaction, its BCS bytes are what a wallet signs.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct TransactionData(VersionedTransactionData);
+
+impl TransactionData {
+    /// # Programmable transaction data
+    ///
+    /// ## Parameters
+    /// - sender : sender address, also the gas owner
+    /// - gas_payment : gas coins
+    /// - pt : programmable transaction
+    /// - gas_budget : gas budget in MIST
+    /// - gas_price : gas price in MIST per unit
+    ///
+    /// ## Returns
+    /// - Ok(TransactionData) : transaction data
+    /// - Err(SuiError) : malformed sender
+    pub fn new_programmable(
+        sender: &str,
+        gas_payment: Vec<ObjectReference>,
+        pt: ProgrammableTransaction,
+        gas_budget: u64,
+        gas_price: u64,
+    ) -> Result<Self, SuiError> {
+        Self::new_programmable_sponsored(sender, sender, gas_payment, pt, gas_budget, gas_price)
+    }
+
+    /// # Sponsored programmable transaction data
+    ///
+    /// ## Parameters
+    /// - sender : sender address
+    /// - gas_owner : sponsor address, owner of the gas coins
+    /// - gas_payment : gas coins
+    /// - pt : programmable transaction
+    /// - gas_budget : gas budget in MIST
+    /// - gas_price : gas price in MIST per unit
+    ///
+    /// ## Returns
+    /// - Ok(TransactionData) : transaction data
+    /// - Err(SuiError) : malformed sender or gas owner
+    pub fn new_programmable_sponsored(
+        sender: &str,
+        gas_owner: &str,
+        gas_payment: Vec<ObjectReference>,
+        pt: ProgrammableTransaction,
+        gas_budget: u64,
+        gas_price: u64,
+    ) -> Result<Self, SuiError> {
+        let sender = address_bytes(sender)?;
+        let gas_owner = address_bytes(gas_owner)?;
+        Ok(TransactionData(VersionedTransactionData::V1(
+            TransactionDataV1 {
+                kind: TransactionKind::ProgrammableTransaction(pt),
+                sender,
+                gas_data: GasConfig {
+                    payment: gas_payment,
+                    owner: gas_owner,
+                    price: gas_price,
+                    budget: gas_budget,
+                },
+                expiration: TransactionExpiration::None,
+            },
+        )))
+    }
+
+    /// BCS bytes of the transaction data
+    pub fn to_bytes(&self) -> Result<Vec<u8>, SuiError> {
+        Ok(bcs::to_bytes(self)?)
+    }
+
+    /// # Transaction data from bytes
+    ///
+    /// ## Parameters
+    /// - tx_bytes : BCS bytes of a programmable transaction, as signed by wallets
+    ///
+    /// ## Returns
+    /// - Ok(TransactionData) : decoded transaction data
+    /// - Err(SuiError) : not the BCS of a programmable transaction
+    ///
+    /// ## Example
+    /// ```rust
+    /// use sui_network_sdk::ptb::{Argument, ProgrammableTransactionBuilder, TransactionData};
+    ///
+    /// let mut builder = ProgrammableTransactionBuilder::new();
+    /// let coins = builder.split_coins(Argument::GasCoin, &[1000]).unwrap();
+    /// builder.transfer_objects(coins, "0x7d20").unwrap();
+    /// let data = TransactionData::new_programmable("0xa1", vec![], builder.finish(), 5_000_000, 1000).unwrap();
+    ///
+    /// let decoded = TransactionData::from_bytes(&data.to_bytes().unwrap()).unwrap();
+    /// assert_eq!(decoded, data);
+    /// assert_eq!(decoded.sender(), format!("0x{:0>64}", "a1"));
+    /// assert_eq!(decoded.gas_budget(), 5_000_000);
+    /// assert_eq!(decoded.programmable_transaction().commands.len(), 2);
+    /// assert!(TransactionData::from_bytes(&[1, 2, 3]).is_err());
+    /// ```
+    pub fn from_bytes(tx_bytes: &[u8]) -> Result<Self, SuiError> {
+        Ok(bcs::from_bytes(tx_bytes)?)
+    }
+
+    /// sender address
+    pub fn sender(&self) -> String {
+        format!("0x{}", hex::encode(self.v1().sender))
+    }
+
+    /// gas owner address, the sender unless sponsored
+    pub fn gas_owner(&self) -> String {
+        format!("0x{}", hex::encode(self.v1().gas_data.owner))
+    }
+
+    /// gas budget in MIST
+    pub fn gas_budget(&self) -> u64 {
+        self.v1().gas_data.budget
+    }
+
+    /// gas price in MIST per unit
+    pub fn gas_price(&self) -> u64 {
+        self.v1().gas_data.price
+    }
+
+    /// gas coins
+    pub fn gas_payment(&self) -> Vec<ObjectRef> {
+        self.v1()
+            .gas_data
+            .payment
+            .iter()
+            .map(|(id, version, digest)| ObjectRef {
+                object_id: format!("0x{}", hex::encode(id)),
+                version: *version,
+                digest: digest.to_string(),
+            })
+            .collect()
+    }
+
+    /// last epoch the transaction may execute in, `None` if it does not expire
+    pub fn expiration_epoch(&self) -> Option<u64> {
+        match self.v1().expiration {
+            TransactionExpiration::None => None,
+            TransactionExpiration::Epoch(epoch) => Some(epoch),
+        }
+    }
+
+    /// inputs and commands
+    pub fn programmable_transaction(&self) -> &ProgrammableTransaction {
+        let TransactionKind::ProgrammableTransaction(pt) = &self.v1().kind;
+        pt
+    }
+
+    fn v1(&self) -> &TransactionDataV1 {
+        let VersionedTransactionData::V1(v1) = &self.0;
+        v1
+    }
+}
@@ -0,0 +1,359 @@
+use std::collections::{BTreeMap, VecDeque};
+use std::future::Future;
+use std::sync::Mutex;
+use std::time::Duration;
+use tokio::sync::Notify;
+use tokio::time::Instant;
+
+/// methods submitting or simulating transactions, sent before reads
+const HIGH_PRIORITY_METHODS: [&str; 3] = [
+    "executeTransactionBlock",
+    "dryRunTransactionBlock",
+    "devInspectTransactionBlock",
+];
+/// methods of bulk reads, sent after interactive reads
+const LOW_PRIORITY_METHODS: [&str; 5] = [
+    "queryEvents",
+    "queryTransactionBlocks",
+    "getCheckpoints",
+    "multiGetObjects",
+    "multiGetTransactionBlocks",
+];
+/// priority classes, the index of a class is its priority as usize
+const CLASSES: [Priority; 3] = [Priority::High, Priority::Normal, Priority::Low];
+
+tokio::task_local! {
+    /// priority set by [`RequestOptions::scope`]
+    static PRIORITY: Priority;
+    /// request deadline set by [`RequestOptions::scope`]
+    static TIMEOUT: Duration;
+}
+
+/// # Priority
+///
+/// Class of a request in the queue of [`SuiClient::with_request_limits`], higher classes
+/// are sent first. Each method has a default class, see [`Priority::for_method`].
+///
+/// # Variants
+/// - High : transaction execution, dry runs and dev inspects
+/// - Normal : interactive reads
+/// - Low : bulk reads and backfills, sent ahead of the others once aged
+///
+/// [`SuiClient::with_request_limits`]: crate::SuiClient::with_request_limits
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Priority {
+    High,
+    Normal,
+    Low,
+}
+
+impl Priority {
+    /// # Priority of a method
+    ///
+    /// ## Parameters
+    /// - method : rpc method name, with or without prefix
+    ///
+    /// ## Returns
+    /// default class of the method
+    ///
+    /// ## Example
+    /// ```rust
+    /// use sui_network_sdk::queue::Priority;
+    ///
+    /// assert_eq!(Priority::for_method("sui_executeTransactionBlock"), Priority::High);
+    /// assert_eq!(Priority::for_method("sui_getObject"), Priority::Normal);
+    /// assert_eq!(Priority::for_method("suix_queryEvents"), Priority::Low);
+    /// ```
+    pub fn for_method(method: &str) -> Self {
+        let name = method.rsplit('_').next().unwrap_or(method);
+        if HIGH_PRIORITY_METHODS.contains(&name) {
+            Priority::High
+        } else if LOW_PRIORITY_METHODS.contains(&name) {
+            Priority::Low
+        } else {
+            Priority::Normal
+        }
+    }
+}
+
+/// # Request options
+///
+/// Options of the requests sent by a call, see [`SuiClient::request_with_options`].
+///
+/// # Fields
+/// - priority : class of the requests, the class of each method when `None`
+/// - timeout : deadline of each request, replacing the client's `SuiClientConfig::timeout`
+///   when set
+///
+/// [`SuiClient::request_with_options`]: crate::SuiClient::request_with_options
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct RequestOptions {
+    pub priority: Option<Priority>,
+    pub timeout: Option<Duration>,
+}
+
+impl RequestOptions {
+    /// # Scope
+    ///
+    /// Runs a future with the options, every request it sends, e.g. the pages of a
+    /// backfill, takes them. Tasks spawned by the future do not.
+    ///
+    /// ## Parameters
+    /// - future : future sending requests
+    ///
+    /// ## Returns
+    /// output of the future
+    pub async fn scope<F: Future>(self, future: F) -> F::Output {
+        let future = async move {
+            match self.timeout {
+                Some(timeout) => TIMEOUT.scope(timeout, future).await,
+                None => future.await,
+            }
+        };
+        match self.priority {
+            Some(priority) => PRIORITY.scope(priority, future).await,
+            None => future.await,
+        }
+    }
+}
+
+/// class of a request, the scoped priority or the method's default
+pub(crate) fn current_priority(method: &str) -> Priority {
+    PRIORITY
+        .try_with(|priority| *priority)
+        .unwrap_or_else(|_| Priority::for_method(method))
+}
+
+/// request deadline scoped by the caller, `None` for the transport's own
+pub(crate) fn current_timeout() -> Option<Duration> {
+    TIMEOUT.try_with(|timeout| *timeout).ok()
+}
+
+/// # Request limits
+///
+/// Limits of the requests a client sends, see [`SuiClient::with_request_limits`].
+///
+/// # Fields
+/// - max_in_flight : requests awaiting their response at once
+/// - min_interval : time between two requests sent, e.g. a second over the provider's
+///   rate limit
+/// - low_aging : wait after which a low priority request is sent before the others
+///
+/// [`SuiClient::with_request_limits`]: crate::SuiClient::with_request_limits
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RequestLimits {
+    pub max_in_flight: usize,
+    pub min_interval: Duration,
+    pub low_aging: Duration,
+}
+
+impl Default for RequestLimits {
+    fn default() -> Self {
+        Self {
+            max_in_flight: 16,
+            min_interval: Duration::ZERO,
+            low_aging: Duration::from_secs(2),
+        }
+    }
+}
+
+/// # Queue stats
+///
+/// Requests of one priority class since the client was created.
+///
+/// # Fields
+/// - queued : requests waiting now
+/// - dispatched : requests sent, each retry counts
+/// - aged : low priority requests sent before higher classes after their aging
+/// - total_wait : time spent in the queue by the requests sent
+/// - max_wait : longest time a request sent spent in the queue
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct QueueStats {
+    pub queued: usize,
+    pub dispatched: u64,
+    pub aged: u64,
+    pub total_wait: Duration,
+    pub max_wait: Duration,
+}
+
+/// request waiting for its turn
+struct Waiter {
+    id: u64,
+    enqueued: Instant,
+}
+
+/// state of a request queue
+struct QueueState {
+    next_id: u64,
+    waiters: [VecDeque<Waiter>; 3],
+    in_flight: usize,
+    next_slot: Instant,
+    stats: [QueueStats; 3],
+}
+
+impl QueueState {
+    /// class sent next and whether it is an aged low priority request
+    fn next_class(&self, now: Instant, low_aging: Duration) -> Option<(usize, bool)> {
+        let low = Priority::Low as usize;
+        if self.waiters[low]
+            .front()
+            .is_some_and(|waiter| now.duration_since(waiter.enqueued) >= low_aging)
+        {
+            return Some((low, true));
+        }
+        let class = self
+            .waiters
+            .iter()
+            .position(|waiters| !waiters.is_empty())?;
+        Some((class, false))
+    }
+}
+
+/// priority queue in front of the transport, limiting requests in flight and their rate
+pub(crate) struct RequestQueue {
+    limits: RequestLimits,
+    state: Mutex<QueueState>,
+    notify: Notify,
+}
+
+/// waiting request, leaves the queue when dropped before its turn
+struct Enqueued<'a> {
+    queue: &'a RequestQueue,
+    class: usize,
+    id: u64,
+    dispatched: bool,
+}
+
+impl Drop for Enqueued<'_> {
+    fn drop(&mut self) {
+        if !self.dispatched {
+            let id = self.id;
+            self.queue.lock().waiters[self.class].retain(|waiter| waiter.id != id);
+            self.queue.notify.notify_waiters();
+        }
+    }
+}
+
+/// turn of a request, frees its slot when dropped
+pub(crate) struct QueuePermit<'a> {
+    queue: &'a RequestQueue,
+}
+
+impl Drop for QueuePermit<'_> {
+    fn drop(&mut self) {
+        self.queue.lock().in_flight -= 1;
+        self.queue.notify.notify_waiters();
+    }
+}
+
+impl RequestQueue {
+    pub(crate) fn new(limits: RequestLimits) -> Self {
+        Self {
+            limits,
+            state: Mutex::new(QueueState {
+                next_id: 0,
+                waiters: Default::default(),
+                in_flight: 0,
+                next_slot: Instant::now(),
+                stats: Default::default(),
+            }),
+            notify: Notify::new(),
+        }
+    }
+
+    /// wait for the turn of a request
+    pub(crate) async fn acquire(&self, priority: Priority) -> QueuePermit<'_> {
+        let class = priority as usize;
+        let id = {
+            let mut state = self.lock();
+            state.next_id += 1;
+            let id = state.next_id;
+            state.waiters[class].push_back(Waiter {
+                id,
+                enqueued: Instant::now(),
+            });
+            id
+        };
+        let mut enqueued = Enqueued {
+            queue: self,
+            class,
+            id,
+            dispatched: false,
+        };
+        loop {
+            // registered before the state is read, a release in between still wakes it
+            let notified = self.notify.notified();
+            tokio::pin!(notified);
+            notified.as_mut().enable();
+            let wake_at = {
+                let mut state = self.lock();
+                let now = Instant::now();
+                let first = state.waiters[class].front().map(|waiter| waiter.id) == Some(id);
+                match state.next_class(now, self.limits.low_aging) {
+                    Some((next, aged)) if next == class && first => {
+                        if state.in_flight < self.limits.max_in_flight.max(1)
+                            && now >= state.next_slot
+                        {
+                            let waiter = state.waiters[class].pop_front();
+                            let waited = waiter.map_or(Duration::ZERO, |w| now - w.enqueued);
+                            state.in_flight += 1;
+                            state.next_slot = now + self.limits.min_interval;
+                            let stats = &mut state.stats[class];
+                            stats.dispatched += 1;
+                            stats.aged += u64::from(aged);
+                            stats.total_wait += waited;
+                            stats.max_wait = stats.max_wait.max(waited);
+                            None
+                        } else if state.in_flight < self.limits.max_in_flight.max(1) {
+                            Some(Some(state.next_slot))
+                        } else {
+                            Some(None)
+                        }
+                    }
+                    // the oldest low priority request may take the turn once aged
+                    _ => Some(
+                        state.waiters[Priority::Low as usize]
+                            .front()
+                            .map(|waiter| waiter.enqueued + self.limits.low_aging)
+                            .filter(|at| *at > now),
+                    ),
+                }
+            };
+            match wake_at {
+                None => {
+                    enqueued.dispatched = true;
+                    // the next request may be sent too
+                    self.notify.notify_waiters();
+                    return QueuePermit { queue: self };
+                }
+                Some(Some(at)) => {
+                    tokio::select! {
+                        _ = notified => {}
+                        _ = tokio::time::sleep_until(at) => {}
+                    }
+                }
+                Some(None) => notified.await,
+            }
+        }
+    }
+
+    /// stats by class
+    pub(crate) fn snapshot(&self) -> BTreeMap<Priority, QueueStats> {
+        let state = self.lock();
+        CLASSES
+            .iter()
+            .map(|priority| {
+                let class = *priority as usize;
+                let stats = QueueStats {
+                    queued: state.waiters[class].len(),
+                    ..state.stats[class].clone()
+                };
+                (*priority, stats)
+            })
+            .collect()
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, QueueState> {
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
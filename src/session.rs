@@ -0,0 +1,466 @@
+use crate::ptb::address_bytes;
+use crate::types::{SessionError, SuiError};
+use crate::wallet::{SignatureScheme, Signer, Wallet, verify_personal_message};
+use base64::Engine;
+use base64::prelude::BASE64_STANDARD;
+use futures::future::{self, BoxFuture};
+use serde::{Deserialize, Serialize};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// current delegation format version
+pub const DELEGATION_VERSION: u8 = 1;
+/// domain separating delegations from other personal messages of the parent
+pub const DELEGATION_DOMAIN: &str = "sui-session-delegation";
+
+/// # Delegation statement
+///
+/// What the parent wallet signs, as a personal message, to hand a session key the right to
+/// act for it until `valid_until_ms`. The message bytes are the BCS encoding of the fields
+/// in order:
+///
+/// | field | BCS |
+/// |---|---|
+/// | version | `u8`, [`DELEGATION_VERSION`] |
+/// | domain | ULEB128 length and UTF-8 bytes of [`DELEGATION_DOMAIN`] |
+/// | parent | 32 address bytes |
+/// | session_scheme | `u8` signature scheme flag of the session key |
+/// | session_public_key | ULEB128 length and key bytes |
+/// | valid_until_ms | `u64` little endian, unix time in milliseconds |
+///
+/// A contract verifies the statement like any personal message signature: the parent
+/// signature covers BLAKE2b-256 of the intent `[3, 0, 0]` followed by the BCS encoded
+/// message bytes, see [`Wallet::sign_personal_message`].
+///
+/// # Fields
+/// - version : format version
+/// - domain : delegation domain
+/// - parent : parent address bytes
+/// - session_scheme : scheme flag of the session key
+/// - session_public_key : session public key bytes
+/// - valid_until_ms : expiry, unix time in milliseconds
+///
+/// ## Example
+/// ```rust
+/// use sui_network_sdk::session::DelegationStatement;
+/// use sui_network_sdk::wallet::Wallet;
+///
+/// // test vector
+/// let parent = Wallet::from_private_key(&[1u8; 32]).unwrap();
+/// let session = Wallet::from_private_key(&[2u8; 32]).unwrap();
+/// let statement = DelegationStatement::new(parent.get_address(), &session, 1_700_000_000_000).unwrap();
+/// assert_eq!(
+///     hex::encode(statement.to_bytes().unwrap()),
+///     concat!(
+///         "01",
+///         "167375692d73657373696f6e2d64656c65676174696f6e",
+///         "29dfbf688abce7ab43bb8e70cae158ae961196e721440f515482f8ba1684390f",
+///         "00",
+///         "20",
+///         "8139770ea87d175f56a35466c34c7ecccb8d8a91b4ee37a25df60f5b8fc9b394",
+///         "0068e5cf8b010000",
+///     )
+/// );
+/// assert_eq!(DelegationStatement::from_bytes(&statement.to_bytes().unwrap()).unwrap(), statement);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DelegationStatement {
+    pub version: u8,
+    pub domain: String,
+    pub parent: [u8; 32],
+    pub session_scheme: u8,
+    pub session_public_key: Vec<u8>,
+    pub valid_until_ms: u64,
+}
+
+impl DelegationStatement {
+    /// # create statement
+    ///
+    /// ## Parameters
+    /// - parent : parent address
+    /// - session : session key
+    /// - valid_until_ms : expiry, unix time in milliseconds
+    ///
+    /// ## Returns
+    /// - Ok(DelegationStatement) : statement of the current version
+    /// - Err(SuiError) : malformed parent address
+    pub fn new(parent: &str, session: &Wallet, valid_until_ms: u64) -> Result<Self, SuiError> {
+        Ok(Self {
+            version: DELEGATION_VERSION,
+            domain: DELEGATION_DOMAIN.to_string(),
+            parent: address_bytes(parent)?,
+            session_scheme: SignatureScheme::Ed25519.flag(),
+            session_public_key: session.get_public_key_bytes_vec(),
+            valid_until_ms,
+        })
+    }
+
+    /// BCS bytes, the personal message the parent signs
+    pub fn to_bytes(&self) -> Result<Vec<u8>, SuiError> {
+        Ok(bcs::to_bytes(self)?)
+    }
+
+    /// # Statement from bytes
+    ///
+    /// ## Parameters
+    /// - bytes : BCS bytes of a statement
+    ///
+    /// ## Returns
+    /// - Ok(DelegationStatement) : decoded statement
+    /// - Err(SuiError) : `SessionError::UnsupportedVersion` for other versions, malformed bytes,
+    ///   or `SessionError::InvalidDelegation` for an unknown session scheme flag
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, SuiError> {
+        check_version(bytes)?;
+        let statement: Self = bcs::from_bytes(bytes)?;
+        session_scheme(statement.session_scheme)?;
+        Ok(statement)
+    }
+
+    /// parent address
+    pub fn parent_address(&self) -> String {
+        format!("0x{}", hex::encode(self.parent))
+    }
+
+    /// session key address, an error for an unknown session scheme flag
+    pub fn session_address(&self) -> Result<String, SuiError> {
+        Ok(session_scheme(self.session_scheme)?.address(&self.session_public_key))
+    }
+}
+
+/// # Session delegation
+///
+/// Delegation statement with the parent signature, the blob handed to contracts that verify
+/// session keys. Its bytes are the BCS encoding of the statement followed by the serialized
+/// parent signature (scheme flag, signature, public key) with a ULEB128 length.
+///
+/// # Fields
+/// - statement : what the parent delegates
+/// - parent_signature : serialized personal message signature of the parent over the statement
+///
+/// ## Example
+/// ```rust
+/// use std::time::{Duration, UNIX_EPOCH};
+/// use base64::Engine;
+/// use sui_network_sdk::session::{DelegationStatement, SessionDelegation};
+/// use sui_network_sdk::wallet::Wallet;
+///
+/// // test vector, the `DelegationStatement` test vector signed by its parent
+/// let parent = Wallet::from_private_key(&[1u8; 32]).unwrap();
+/// let session = Wallet::from_private_key(&[2u8; 32]).unwrap();
+/// let statement = DelegationStatement::new(parent.get_address(), &session, 1_700_000_000_000).unwrap();
+/// let signature = parent.sign_personal_message(&statement.to_bytes().unwrap());
+/// let delegation = SessionDelegation {
+///     statement,
+///     parent_signature: base64::prelude::BASE64_STANDARD.decode(signature).unwrap(),
+/// };
+/// let bytes = hex::encode(delegation.to_bytes().unwrap());
+/// assert_eq!(bytes.len(), 2 * (98 + 1 + 97));
+/// // statement, signature length, Ed25519 flag, signature, parent public key
+/// assert_eq!(&bytes[196..200], "6100");
+/// assert_eq!(&bytes[200..328], "6cb3f66e843a2838a0ce981f3460c5c9d099f93a5c4e18a50726c7863c00dac0e4a94df82acc55143c5068ae57623f90f0ebe16771400a4cdae2e3c79697a20e");
+/// assert_eq!(&bytes[328..], hex::encode(parent.get_public_key_bytes()));
+///
+/// let valid_until = UNIX_EPOCH + Duration::from_millis(1_700_000_000_000);
+/// assert!(delegation.verify(valid_until - Duration::from_secs(1)).is_ok());
+/// assert!(delegation.verify(valid_until).is_err());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SessionDelegation {
+    pub statement: DelegationStatement,
+    pub parent_signature: Vec<u8>,
+}
+
+impl SessionDelegation {
+    /// BCS bytes of the delegation
+    pub fn to_bytes(&self) -> Result<Vec<u8>, SuiError> {
+        Ok(bcs::to_bytes(self)?)
+    }
+
+    /// # Delegation from bytes
+    ///
+    /// ## Parameters
+    /// - bytes : BCS bytes of a delegation
+    ///
+    /// ## Returns
+    /// - Ok(SessionDelegation) : decoded delegation, not yet verified
+    /// - Err(SuiError) : `SessionError::UnsupportedVersion` for other versions, malformed bytes,
+    ///   or `SessionError::InvalidDelegation` for an unknown session scheme flag
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, SuiError> {
+        check_version(bytes)?;
+        let delegation: Self = bcs::from_bytes(bytes)?;
+        session_scheme(delegation.statement.session_scheme)?;
+        Ok(delegation)
+    }
+
+    /// # Verify delegation
+    ///
+    /// Checks the format version, domain and session scheme, that the parent signed the
+    /// statement, and the expiry.
+    ///
+    /// ## Parameters
+    /// - now : time the delegation must be valid at
+    ///
+    /// ## Returns
+    /// - Ok(()) : valid delegation
+    /// - Err(SuiError) : `SessionError` of the failed check
+    pub fn verify(&self, now: SystemTime) -> Result<(), SuiError> {
+        let statement = &self.statement;
+        if statement.version != DELEGATION_VERSION {
+            return Err(SuiError::Session(SessionError::UnsupportedVersion(
+                statement.version,
+            )));
+        }
+        if statement.domain != DELEGATION_DOMAIN {
+            return Err(SuiError::Session(SessionError::InvalidDelegation(format!(
+                "domain {:?}",
+                statement.domain
+            ))));
+        }
+        session_scheme(statement.session_scheme)?;
+        verify_personal_message(
+            &statement.to_bytes()?,
+            &BASE64_STANDARD.encode(&self.parent_signature),
+            &statement.parent_address(),
+        )
+        .map_err(|e| SuiError::Session(SessionError::InvalidDelegation(e.to_string())))?;
+        check_expiry(statement.valid_until_ms, now)
+    }
+}
+
+/// # Session wallet
+///
+/// Ephemeral key acting for a parent wallet until the delegation expires, created with
+/// [`Wallet::create_session_key`]. Transactions are signed by the session key, so they are
+/// sent from the session address, and signing fails once the session expired. The parent
+/// key is only needed to create the session.
+///
+/// ## Example
+/// ```rust
+/// use std::time::{Duration, SystemTime};
+/// use sui_network_sdk::session::SessionDelegation;
+/// use sui_network_sdk::types::{SessionError, SuiError};
+/// use sui_network_sdk::wallet::Wallet;
+///
+/// let parent = Wallet::new().unwrap();
+/// let session = parent
+///     .create_session_key(SystemTime::now() + Duration::from_secs(3600))
+///     .unwrap();
+/// assert_eq!(session.parent_address(), parent.get_address());
+/// assert_ne!(session.address(), parent.get_address());
+/// assert!(session.sign_transaction(b"tx bytes").is_ok());
+///
+/// // the delegation travels as bytes and verifies against the parent
+/// let bytes = session.delegation().to_bytes().unwrap();
+/// let delegation = SessionDelegation::from_bytes(&bytes).unwrap();
+/// assert!(delegation.verify(SystemTime::now()).is_ok());
+/// assert!(matches!(
+///     delegation.verify(SystemTime::now() + Duration::from_secs(7200)),
+///     Err(SuiError::Session(SessionError::Expired { .. }))
+/// ));
+///
+/// // a tampered expiry breaks the parent signature
+/// let mut extended = delegation.clone();
+/// extended.statement.valid_until_ms += 1;
+/// assert!(matches!(
+///     extended.verify(SystemTime::now()),
+///     Err(SuiError::Session(SessionError::InvalidDelegation(_)))
+/// ));
+///
+/// // unknown versions are rejected before decoding
+/// let mut future = bytes.clone();
+/// future[0] = 2;
+/// assert!(matches!(
+///     SessionDelegation::from_bytes(&future),
+///     Err(SuiError::Session(SessionError::UnsupportedVersion(2)))
+/// ));
+///
+/// // unknown session scheme flags are rejected, not read as Ed25519
+/// assert_eq!(
+///     delegation.statement.session_address().unwrap(),
+///     session.address()
+/// );
+/// let mut unknown = delegation.clone();
+/// unknown.statement.session_scheme = 5;
+/// let rejected = |result: Result<(), SuiError>| {
+///     matches!(result, Err(SuiError::Session(SessionError::InvalidDelegation(e))) if e.contains("scheme flag 5"))
+/// };
+/// assert!(rejected(unknown.statement.session_address().map(|_| ())));
+/// assert!(rejected(unknown.verify(SystemTime::now())));
+/// assert!(rejected(SessionDelegation::from_bytes(&unknown.to_bytes().unwrap()).map(|_| ())));
+///
+/// assert!(matches!(
+///     parent.create_session_key(SystemTime::now() - Duration::from_secs(1)),
+///     Err(SuiError::Session(SessionError::Expired { .. }))
+/// ));
+/// ```
+#[derive(Debug, Clone)]
+pub struct SessionWallet {
+    session: Wallet,
+    delegation: SessionDelegation,
+}
+
+impl SessionWallet {
+    /// # Restore session wallet
+    ///
+    /// ## Parameters
+    /// - session : session key
+    /// - delegation : delegation of the session key
+    ///
+    /// ## Returns
+    /// - Ok(SessionWallet) : session wallet
+    /// - Err(SuiError) : the delegation is invalid, expired, or delegates another key
+    pub fn from_parts(session: Wallet, delegation: SessionDelegation) -> Result<Self, SuiError> {
+        delegation.verify(SystemTime::now())?;
+        if delegation.statement.session_public_key != session.get_public_key_bytes() {
+            return Err(SuiError::Session(SessionError::InvalidDelegation(
+                "delegation is for another session key".to_string(),
+            )));
+        }
+        Ok(Self {
+            session,
+            delegation,
+        })
+    }
+
+    /// session address, the sender of transactions signed by this wallet
+    pub fn address(&self) -> &str {
+        self.session.get_address()
+    }
+
+    /// parent address
+    pub fn parent_address(&self) -> String {
+        self.delegation.statement.parent_address()
+    }
+
+    /// delegation for contracts verifying the session
+    pub fn delegation(&self) -> &SessionDelegation {
+        &self.delegation
+    }
+
+    /// expiry of the session
+    pub fn valid_until(&self) -> SystemTime {
+        UNIX_EPOCH + Duration::from_millis(self.delegation.statement.valid_until_ms)
+    }
+
+    /// whether the session expired
+    pub fn is_expired(&self) -> bool {
+        check_expiry(self.delegation.statement.valid_until_ms, SystemTime::now()).is_err()
+    }
+
+    /// # Sign transaction
+    ///
+    /// ## Parameters
+    /// - tx_bytes : transaction bytes
+    ///
+    /// ## Returns
+    /// - Ok(Vec<u8>) : session key signature, see [`Wallet::sign_transaction`]
+    /// - Err(SuiError) : `SessionError::Expired` after the expiry
+    pub fn sign_transaction(&self, tx_bytes: &[u8]) -> Result<Vec<u8>, SuiError> {
+        check_expiry(self.delegation.statement.valid_until_ms, SystemTime::now())?;
+        Ok(self.session.sign_transaction(tx_bytes))
+    }
+
+    /// # Sign personal message
+    ///
+    /// ## Parameters
+    /// - message : message bytes
+    ///
+    /// ## Returns
+    /// - Ok(String) : session key signature, see [`Wallet::sign_personal_message`]
+    /// - Err(SuiError) : `SessionError::Expired` after the expiry
+    pub fn sign_personal_message(&self, message: &[u8]) -> Result<String, SuiError> {
+        check_expiry(self.delegation.statement.valid_until_ms, SystemTime::now())?;
+        Ok(self.session.sign_personal_message(message))
+    }
+}
+
+impl Signer for SessionWallet {
+    fn address(&self) -> &str {
+        self.session.get_address()
+    }
+    fn public_key(&self) -> &[u8] {
+        self.session.get_public_key_bytes()
+    }
+    fn sign_transaction<'s>(
+        &'s self,
+        tx_bytes: &'s [u8],
+    ) -> BoxFuture<'s, Result<Vec<u8>, SuiError>> {
+        Box::pin(future::ready(SessionWallet::sign_transaction(
+            self, tx_bytes,
+        )))
+    }
+    fn sign_personal_message<'s>(
+        &'s self,
+        message: &'s [u8],
+    ) -> BoxFuture<'s, Result<String, SuiError>> {
+        Box::pin(future::ready(SessionWallet::sign_personal_message(
+            self, message,
+        )))
+    }
+}
+
+impl Wallet {
+    /// # Create session key
+    ///
+    /// Generates an ephemeral key and signs a [`DelegationStatement`] binding it to this
+    /// wallet until `valid_until`, see [`SessionWallet`].
+    ///
+    /// ## Parameters
+    /// - valid_until : expiry of the session
+    ///
+    /// ## Returns
+    /// - Ok(SessionWallet) : session wallet
+    /// - Err(SuiError) : `SessionError::Expired` when `valid_until` already passed
+    pub fn create_session_key(&self, valid_until: SystemTime) -> Result<SessionWallet, SuiError> {
+        let valid_until_ms = unix_ms(valid_until);
+        check_expiry(valid_until_ms, SystemTime::now())?;
+        let session = Wallet::new()?;
+        let statement = DelegationStatement::new(self.get_address(), &session, valid_until_ms)?;
+        let parent_signature =
+            BASE64_STANDARD.decode(self.sign_personal_message(&statement.to_bytes()?))?;
+        Ok(SessionWallet {
+            session,
+            delegation: SessionDelegation {
+                statement,
+                parent_signature,
+            },
+        })
+    }
+}
+
+/// the version byte leads the encoding, other versions are rejected before decoding
+fn check_version(bytes: &[u8]) -> Result<(), SuiError> {
+    match bytes.first() {
+        Some(&DELEGATION_VERSION) => Ok(()),
+        Some(&version) => Err(SuiError::Session(SessionError::UnsupportedVersion(version))),
+        None => Err(SuiError::Session(SessionError::InvalidDelegation(
+            "empty delegation".to_string(),
+        ))),
+    }
+}
+
+/// signature scheme of a session scheme flag, unknown flags are rejected
+fn session_scheme(flag: u8) -> Result<SignatureScheme, SuiError> {
+    [SignatureScheme::Ed25519, SignatureScheme::Secp256k1]
+        .into_iter()
+        .find(|scheme| scheme.flag() == flag)
+        .ok_or_else(|| {
+            SuiError::Session(SessionError::InvalidDelegation(format!(
+                "unknown session scheme flag {}",
+                flag
+            )))
+        })
+}
+
+/// unix time in milliseconds, 0 before the epoch
+fn unix_ms(time: SystemTime) -> u64 {
+    time.duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_millis() as u64)
+}
+
+/// expired once `now` reaches the expiry
+fn check_expiry(valid_until_ms: u64, now: SystemTime) -> Result<(), SuiError> {
+    if unix_ms(now) >= valid_until_ms {
+        return Err(SuiError::Session(SessionError::Expired { valid_until_ms }));
+    }
+    Ok(())
+}
@@ -0,0 +1,318 @@
+use crate::types::{SiwsError, SuiError};
+use crate::wallet::{Wallet, verify_personal_message};
+use rand::Rng;
+use rand::rng;
+use std::collections::HashSet;
+use std::fmt;
+use std::sync::Mutex;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// first line suffix of a sign-in message
+const HEADER_SUFFIX: &str = " wants you to sign in with your Sui account:";
+
+/// # Sign in with Sui message
+///
+/// Login message signed by a wallet, the canonical text is the `Display` output.
+///
+/// # Fields
+/// - domain : domain requesting the sign-in
+/// - address : address signing in
+/// - statement : optional human readable statement
+/// - nonce : single use nonce issued by the server
+/// - issued_at : issue time, unix seconds
+/// - expiration_time : expiration time, unix seconds
+///
+/// ## Example
+/// ```rust
+/// use std::time::Duration;
+/// use sui_network_sdk::siws::{MemoryNonceStore, SiwsMessage, SiwsVerifier};
+/// use sui_network_sdk::types::{SiwsError, SuiError};
+/// use sui_network_sdk::wallet::Wallet;
+///
+/// let verifier = SiwsVerifier::new("example.com", MemoryNonceStore::new());
+/// let wallet = Wallet::new().unwrap();
+///
+/// // client side
+/// let nonce = verifier.nonce_store().issue();
+/// let message = SiwsMessage::new("example.com", wallet.get_address(), &nonce, Duration::from_secs(300));
+/// let text = message.to_string();
+/// let signature = message.sign(&wallet);
+///
+/// // server side
+/// assert_eq!(verifier.verify(&text, &signature).unwrap(), message);
+///
+/// // replayed nonce
+/// assert!(matches!(
+///     verifier.verify(&text, &signature),
+///     Err(SuiError::Siws(SiwsError::NonceRejected))
+/// ));
+///
+/// // altered domain
+/// let nonce = verifier.nonce_store().issue();
+/// let message = SiwsMessage::new("example.com", wallet.get_address(), &nonce, Duration::from_secs(300));
+/// let signature = message.sign(&wallet);
+/// let tampered = message.to_string().replace("example.com", "evil.com");
+/// assert!(matches!(
+///     verifier.verify(&tampered, &signature),
+///     Err(SuiError::Siws(SiwsError::DomainMismatch { .. }))
+/// ));
+/// let other = SiwsVerifier::new("evil.com", MemoryNonceStore::new());
+/// assert!(matches!(
+///     other.verify(&tampered, &signature),
+///     Err(SuiError::Siws(SiwsError::InvalidSignature(_)))
+/// ));
+///
+/// // expired
+/// let expired_at = message.expiration_time + 1;
+/// assert!(matches!(
+///     verifier.verify_at(&message.to_string(), &signature, expired_at),
+///     Err(SuiError::Siws(SiwsError::Expired))
+/// ));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SiwsMessage {
+    pub domain: String,
+    pub address: String,
+    pub statement: Option<String>,
+    pub nonce: String,
+    pub issued_at: u64,
+    pub expiration_time: u64,
+}
+
+impl SiwsMessage {
+    /// # create sign-in message
+    ///
+    /// ## Parameters
+    /// - domain : domain requesting the sign-in
+    /// - address : address signing in
+    /// - nonce : nonce issued by the server
+    /// - lifetime : time until the message expires
+    ///
+    /// ## Returns
+    /// message issued now
+    pub fn new(domain: &str, address: &str, nonce: &str, lifetime: Duration) -> Self {
+        let issued_at = unix_now();
+        Self {
+            domain: domain.to_string(),
+            address: address.to_string(),
+            statement: None,
+            nonce: nonce.to_string(),
+            issued_at,
+            expiration_time: issued_at.saturating_add(lifetime.as_secs()),
+        }
+    }
+
+    /// set the statement
+    pub fn with_statement(mut self, statement: &str) -> Self {
+        self.statement = Some(statement.to_string());
+        self
+    }
+
+    /// sign the canonical text as a personal message, returns the base64 serialized signature
+    pub fn sign(&self, wallet: &Wallet) -> String {
+        wallet.sign_personal_message(self.to_string().as_bytes())
+    }
+
+    /// # Parse sign-in message
+    ///
+    /// ## Parameters
+    /// - text : canonical message text
+    ///
+    /// ## Returns
+    /// - Ok(SiwsMessage) : message
+    /// - Err(SuiError) : `SiwsError::Malformed` when the text is not canonical
+    pub fn parse(text: &str) -> Result<Self, SuiError> {
+        let malformed = |e: &str| SuiError::Siws(SiwsError::Malformed(e.to_string()));
+        let mut lines = text.split('\n');
+        let domain = lines
+            .next()
+            .and_then(|line| line.strip_suffix(HEADER_SUFFIX))
+            .filter(|domain| !domain.is_empty())
+            .ok_or_else(|| malformed("missing header"))?;
+        let address = lines
+            .next()
+            .filter(|address| !address.is_empty())
+            .ok_or_else(|| malformed("missing address"))?;
+        if lines.next() != Some("") {
+            return Err(malformed("missing blank line after address"));
+        }
+        let mut next = lines.next().ok_or_else(|| malformed("missing nonce"))?;
+        let mut statement = None;
+        if !next.starts_with("Nonce: ") {
+            statement = Some(next.to_string());
+            if lines.next() != Some("") {
+                return Err(malformed("missing blank line after statement"));
+            }
+            next = lines.next().ok_or_else(|| malformed("missing nonce"))?;
+        }
+        let nonce = next
+            .strip_prefix("Nonce: ")
+            .filter(|nonce| !nonce.is_empty())
+            .ok_or_else(|| malformed("missing nonce"))?;
+        let mut timestamp = |prefix: &str| {
+            lines
+                .next()
+                .and_then(|line| line.strip_prefix(prefix))
+                .and_then(|t| t.parse::<u64>().ok())
+                .ok_or_else(|| malformed(&format!("missing {}", prefix.trim_end_matches(": "))))
+        };
+        let issued_at = timestamp("Issued At: ")?;
+        let expiration_time = timestamp("Expiration Time: ")?;
+        if lines.next().is_some() {
+            return Err(malformed("trailing lines"));
+        }
+        let message = Self {
+            domain: domain.to_string(),
+            address: address.to_string(),
+            statement,
+            nonce: nonce.to_string(),
+            issued_at,
+            expiration_time,
+        };
+        // only canonical text is accepted, so the signed text and the parsed message agree
+        if message.to_string() != text {
+            return Err(malformed("not in canonical form"));
+        }
+        Ok(message)
+    }
+}
+
+impl fmt::Display for SiwsMessage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{}{}", self.domain, HEADER_SUFFIX)?;
+        writeln!(f, "{}", self.address)?;
+        writeln!(f)?;
+        if let Some(statement) = &self.statement {
+            writeln!(f, "{}", statement)?;
+            writeln!(f)?;
+        }
+        writeln!(f, "Nonce: {}", self.nonce)?;
+        writeln!(f, "Issued At: {}", self.issued_at)?;
+        write!(f, "Expiration Time: {}", self.expiration_time)
+    }
+}
+
+/// # Nonce store
+///
+/// Issues nonces and accepts each one once, plug in a shared store (database, cache)
+/// when several servers verify sign-ins.
+pub trait NonceStore: Send + Sync {
+    /// consume a nonce, true when it was issued and not used before
+    fn consume(&self, nonce: &str) -> bool;
+}
+
+/// # Memory nonce store
+///
+/// Nonce store for a single process.
+#[derive(Debug, Default)]
+pub struct MemoryNonceStore {
+    issued: Mutex<HashSet<String>>,
+}
+
+impl MemoryNonceStore {
+    /// create an empty store
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// issue a random nonce
+    pub fn issue(&self) -> String {
+        let mut bytes = [0u8; 16];
+        rng().fill(&mut bytes);
+        let nonce = hex::encode(bytes);
+        self.issued
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .insert(nonce.clone());
+        nonce
+    }
+}
+
+impl NonceStore for MemoryNonceStore {
+    fn consume(&self, nonce: &str) -> bool {
+        self.issued
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .remove(nonce)
+    }
+}
+
+/// # Sign-in verifier
+///
+/// Server side verification of sign-in messages for one domain.
+pub struct SiwsVerifier<S: NonceStore> {
+    domain: String,
+    nonce_store: S,
+}
+
+impl<S: NonceStore> SiwsVerifier<S> {
+    /// # create verifier
+    ///
+    /// ## Parameters
+    /// - domain : domain messages must be issued for
+    /// - nonce_store : store of issued nonces
+    pub fn new(domain: &str, nonce_store: S) -> Self {
+        Self {
+            domain: domain.to_string(),
+            nonce_store,
+        }
+    }
+
+    /// nonce store of the verifier
+    pub fn nonce_store(&self) -> &S {
+        &self.nonce_store
+    }
+
+    /// # Verify sign-in
+    ///
+    /// Parses the message, checks domain and validity window, verifies the signature
+    /// binds to the message's address and finally consumes the nonce.
+    ///
+    /// ## Parameters
+    /// - text : signed message text
+    /// - signature : base64 serialized signature
+    ///
+    /// ## Returns
+    /// - Ok(SiwsMessage) : verified message
+    /// - Err(SuiError) : `SuiError::Siws` naming the failed check
+    pub fn verify(&self, text: &str, signature: &str) -> Result<SiwsMessage, SuiError> {
+        self.verify_at(text, signature, unix_now())
+    }
+
+    /// verify a sign-in at a given time, unix seconds
+    pub fn verify_at(
+        &self,
+        text: &str,
+        signature: &str,
+        now: u64,
+    ) -> Result<SiwsMessage, SuiError> {
+        let message = SiwsMessage::parse(text)?;
+        if message.domain != self.domain {
+            return Err(SuiError::Siws(SiwsError::DomainMismatch {
+                expected: self.domain.clone(),
+                found: message.domain,
+            }));
+        }
+        if now >= message.expiration_time {
+            return Err(SuiError::Siws(SiwsError::Expired));
+        }
+        if now < message.issued_at {
+            return Err(SuiError::Siws(SiwsError::NotYetValid));
+        }
+        verify_personal_message(text.as_bytes(), signature, &message.address)
+            .map_err(|e| SuiError::Siws(SiwsError::InvalidSignature(e.to_string())))?;
+        // consumed last, so a rejected message does not burn the nonce
+        if !self.nonce_store.consume(&message.nonce) {
+            return Err(SuiError::Siws(SiwsError::NonceRejected));
+        }
+        Ok(message)
+    }
+}
+
+/// current unix time in seconds
+fn unix_now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or_default()
+}
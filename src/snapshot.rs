@@ -0,0 +1,189 @@
+use crate::SuiClient;
+use crate::types::{
+    Checkpoint, CheckpointId, Coin, Event, Object, Page, ReadConsistency, SuiError,
+    TransactionResponse,
+};
+use serde_json::Value;
+
+/// # Snapshot Reader
+///
+/// Reads pinned to one checkpoint, all of them reflect the chain state as of that checkpoint.
+/// Pinnable reads are of immutable data whose checkpoint can be checked: checkpoints,
+/// transactions, their events and past object versions. Reads of live state fail with
+/// `SuiError::Unsupported` since JSON-RPC can't serve them at a past checkpoint.
+///
+/// | method | pinned |
+/// |---|---|
+/// | `get_checkpoint` | yes |
+/// | `get_transaction_block` | yes, later transactions are not found |
+/// | `get_events` | yes, through their transaction |
+/// | `get_past_object` | yes, later versions are not found |
+/// | `get_object` | no, `Unsupported` |
+/// | `get_coins_page` | no, `Unsupported` |
+/// | `get_balance` | no, `Unsupported` |
+///
+/// ## Example
+/// ```rust
+/// use std::sync::Arc;
+/// use sui_network_sdk::SuiClient;
+/// use sui_network_sdk::transport::MockTransport;
+/// use sui_network_sdk::types::{SuiClientConfig, SuiError};
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let mock = MockTransport::new()
+///         .with_result("sui_getLatestCheckpointSequenceNumber", serde_json::json!("100"))
+///         .with_result("sui_getTransactionBlock", serde_json::json!({ "digest": "A1", "checkpoint": "99" }))
+///         .with_result("sui_getTransactionBlock", serde_json::json!({ "digest": "B2", "checkpoint": "101" }))
+///         .with_result("sui_tryGetPastObject", serde_json::json!({ "status": "VersionFound", "details": {
+///             "objectId": "0x5",
+///             "version": "7",
+///             "digest": "9WzSXdwbky8tNbH7juvyaui4QzMUYEjdCEKMrMgLhXHT",
+///             "previousTransaction": "B2"
+///         }}));
+///     let client = SuiClient::new_with_transport(SuiClientConfig::default(), Arc::new(mock));
+///     let snapshot = client.snapshot_at_latest().await.unwrap();
+///     assert_eq!(snapshot.checkpoint(), 100);
+///
+///     // executed before the pin
+///     assert!(snapshot.get_transaction_block("A1", serde_json::json!({})).await.is_ok());
+///     // executed after the pin
+///     let error = snapshot.get_transaction_block("B2", serde_json::json!({})).await.unwrap_err();
+///     assert!(error.is_not_found());
+///     // written by a transaction after the pin
+///     let error = snapshot.get_past_object("0x5", 7).await.unwrap_err();
+///     assert!(error.is_not_found());
+///     // live state can't be pinned
+///     assert!(matches!(snapshot.get_object("0x5").await, Err(SuiError::Unsupported(_))));
+///     assert!(matches!(snapshot.get_balance("0x1", None).await, Err(SuiError::Unsupported(_))));
+///     assert!(matches!(
+///         snapshot.get_coins_page("0x1", None, None, None).await,
+///         Err(SuiError::Unsupported(_))
+///     ));
+/// }
+/// ```
+#[derive(Clone, Copy)]
+pub struct SnapshotReader<'a> {
+    client: &'a SuiClient,
+    checkpoint: u64,
+}
+
+impl<'a> SnapshotReader<'a> {
+    /// # create snapshot reader
+    ///
+    /// ## Parameters
+    /// - client : sui client
+    /// - checkpoint : sequence number of the pinned checkpoint
+    pub fn new(client: &'a SuiClient, checkpoint: u64) -> Self {
+        Self { client, checkpoint }
+    }
+
+    /// sequence number of the pinned checkpoint
+    pub fn checkpoint(&self) -> u64 {
+        self.checkpoint
+    }
+
+    /// read consistency of the snapshot
+    pub fn consistency(&self) -> ReadConsistency {
+        ReadConsistency::AtCheckpoint(self.checkpoint)
+    }
+
+    /// # Get checkpoint
+    ///
+    /// Pinned, the pinned checkpoint itself.
+    pub async fn get_checkpoint(&self) -> Result<Checkpoint, SuiError> {
+        self.client
+            .get_checkpoint(CheckpointId::SequenceNumber(self.checkpoint))
+            .await
+    }
+
+    /// # Get transaction block
+    ///
+    /// Pinned, transactions not checkpointed at the pinned checkpoint are not found.
+    ///
+    /// ## Parameters
+    /// - digest : transaction digest
+    /// - options : raw response options
+    pub async fn get_transaction_block(
+        &self,
+        digest: &str,
+        options: Value,
+    ) -> Result<TransactionResponse, SuiError> {
+        let response = self.client.get_transaction_block(digest, options).await?;
+        if response
+            .checkpoint
+            .is_none_or(|checkpoint| checkpoint > self.checkpoint)
+        {
+            return Err(SuiError::Rpc(format!(
+                "Transaction {} not found at checkpoint {}",
+                digest, self.checkpoint
+            )));
+        }
+        Ok(response)
+    }
+
+    /// # Get events
+    ///
+    /// Pinned, events of transactions not checkpointed at the pinned checkpoint are not found.
+    ///
+    /// ## Parameters
+    /// - digest : transaction digest
+    pub async fn get_events(&self, digest: &str) -> Result<Vec<Event>, SuiError> {
+        let response = self
+            .get_transaction_block(digest, serde_json::json!({ "showEvents": true }))
+            .await?;
+        Ok(response.events.unwrap_or_default())
+    }
+
+    /// # Get past object
+    ///
+    /// Pinned, versions written after the pinned checkpoint are not found.
+    ///
+    /// ## Parameters
+    /// - object_id : object id
+    /// - version : object version
+    pub async fn get_past_object(&self, object_id: &str, version: u64) -> Result<Object, SuiError> {
+        self.client
+            .get_past_object(object_id, version, self.consistency())
+            .await
+    }
+
+    /// # Get object
+    ///
+    /// Not pinnable, JSON-RPC only serves the live version, use `get_past_object`.
+    pub async fn get_object(&self, object_id: &str) -> Result<Object, SuiError> {
+        Err(self.unsupported("sui_getObject", object_id))
+    }
+
+    /// # Get coins page
+    ///
+    /// Not pinnable, JSON-RPC only serves live coin ownership.
+    pub async fn get_coins_page(
+        &self,
+        address: &str,
+        _coin_type: Option<&str>,
+        _cursor: Option<String>,
+        _limit: Option<usize>,
+    ) -> Result<Page<Coin>, SuiError> {
+        Err(self.unsupported("suix_getCoins", address))
+    }
+
+    /// # Get balance
+    ///
+    /// Not pinnable, JSON-RPC only serves live balances.
+    pub async fn get_balance(
+        &self,
+        address: &str,
+        _coin_type: Option<&str>,
+    ) -> Result<u64, SuiError> {
+        Err(self.unsupported("suix_getBalance", address))
+    }
+
+    /// error of a read that can't honor the pin
+    fn unsupported(&self, method: &str, target: &str) -> SuiError {
+        SuiError::Unsupported(format!(
+            "{} of {} can't be pinned to checkpoint {}",
+            method, target, self.checkpoint
+        ))
+    }
+}
@@ -0,0 +1,291 @@
+use crate::ptb::{ProgrammableTransaction, TransactionData, object_reference};
+use crate::transport::BODY_SNIPPET_LEN;
+use crate::types::{GasData, ObjectRef, SuiError};
+use crate::wallet::{SignatureScheme, Wallet, serialized_signature};
+use base64::Engine;
+use base64::prelude::BASE64_STANDARD;
+use futures::future::{self, BoxFuture};
+use reqwest::Client as HttpClient;
+use serde::{Deserialize, Serialize};
+use std::sync::Mutex;
+
+/// # Unsigned transaction
+///
+/// Transaction a sponsor is asked to pay gas for, without gas data.
+///
+/// # Fields
+/// - sender : sender address
+/// - kind : programmable transaction
+/// - gas_budget : gas budget the sender asks for, in MIST
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnsignedTransaction {
+    pub sender: String,
+    pub kind: ProgrammableTransaction,
+    pub gas_budget: u64,
+}
+
+impl UnsignedTransaction {
+    /// # With gas
+    ///
+    /// ## Parameters
+    /// - gas_data : gas chosen by the sponsor
+    ///
+    /// ## Returns
+    /// - Ok(TransactionData) : the transaction both the sender and the sponsor sign
+    /// - Err(SuiError) : malformed address or gas coin reference
+    pub fn with_gas(&self, gas_data: &GasData) -> Result<TransactionData, SuiError> {
+        let payment = gas_data
+            .payment
+            .iter()
+            .map(object_reference)
+            .collect::<Result<Vec<_>, _>>()?;
+        TransactionData::new_programmable_sponsored(
+            &self.sender,
+            &gas_data.owner,
+            payment,
+            self.kind.clone(),
+            gas_data.budget,
+            gas_data.price,
+        )
+    }
+}
+
+/// Gas paid by a sponsor.
+///
+/// # Fields
+/// - gas_data : gas coins, owner, price and budget chosen by the sponsor
+/// - sponsor_signature : base64 serialized sponsor signature over the transaction with this gas
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SponsoredGas {
+    pub gas_data: GasData,
+    pub sponsor_signature: String,
+}
+
+/// # Gas sponsor
+///
+/// Gas station paying for transactions, see [`Trade::with_gas_sponsor`].
+///
+/// [`Trade::with_gas_sponsor`]: crate::trade::Trade::with_gas_sponsor
+pub trait GasSponsor: Send + Sync {
+    /// choose gas for the transaction and sign the transaction with that gas
+    fn sponsor<'s>(
+        &'s self,
+        tx: &'s UnsignedTransaction,
+    ) -> BoxFuture<'s, Result<SponsoredGas, SuiError>>;
+}
+
+/// # HTTP gas sponsor
+///
+/// Gas station reached over HTTP. The transaction is posted as JSON
+///
+/// ```json
+/// { "sender": "0x..", "transactionKind": "<base64 BCS>", "gasBudget": "10000000" }
+/// ```
+///
+/// and the station answers with a [`SponsoredGas`]
+///
+/// ```json
+/// {
+///   "gasData": {
+///     "payment": [{ "objectId": "0x..", "version": "7", "digest": "..." }],
+///     "owner": "0x..", "price": "1000", "budget": "10000000"
+///   },
+///   "sponsorSignature": "<base64 serialized signature>"
+/// }
+/// ```
+///
+/// ## Example
+/// ```no_run
+/// use sui_network_sdk::SuiClient;
+/// use sui_network_sdk::global::mainnet;
+/// use sui_network_sdk::sponsor::HttpGasSponsor;
+/// use sui_network_sdk::trade::{BatchArg, Trade};
+/// use sui_network_sdk::wallet::Wallet;
+/// #[tokio::main]
+/// async fn main() {
+///     let client = SuiClient::new_by_rpc_url(mainnet::RPC_URL.to_string());
+///     let wallet = Wallet::new().unwrap();
+///     let sponsor = HttpGasSponsor::new("https://gas.example.com/sponsor".to_string())
+///         .with_header("x-api-key", "secret");
+///     let trade = Trade::new(&client, &wallet)
+///         .with_gas_budget(10_000_000)
+///         .with_gas_sponsor(sponsor);
+///     let mut batch = trade.batch();
+///     batch.move_call("0x2", "clock", "timestamp_ms", vec![], vec![BatchArg::object("0x6")]);
+///     println!("{:?}", batch.execute().await.unwrap().digest);
+/// }
+/// ```
+pub struct HttpGasSponsor {
+    http_client: HttpClient,
+    endpoint: String,
+    headers: Vec<(String, String)>,
+}
+
+impl HttpGasSponsor {
+    /// # create http gas sponsor
+    ///
+    /// ## Parameters
+    /// - endpoint : url the transactions are posted to
+    pub fn new(endpoint: String) -> Self {
+        Self {
+            http_client: HttpClient::new(),
+            endpoint,
+            headers: Vec::new(),
+        }
+    }
+    /// add a header to every request, e.g. an api key
+    pub fn with_header(mut self, name: &str, value: &str) -> Self {
+        self.headers.push((name.to_string(), value.to_string()));
+        self
+    }
+}
+
+impl GasSponsor for HttpGasSponsor {
+    fn sponsor<'s>(
+        &'s self,
+        tx: &'s UnsignedTransaction,
+    ) -> BoxFuture<'s, Result<SponsoredGas, SuiError>> {
+        Box::pin(async move {
+            let body = serde_json::json!({
+                "sender": tx.sender,
+                "transactionKind": BASE64_STANDARD.encode(tx.kind.kind_bytes()?),
+                "gasBudget": tx.gas_budget.to_string(),
+            });
+            let mut request = self.http_client.post(&self.endpoint).json(&body);
+            for (name, value) in &self.headers {
+                request = request.header(name, value);
+            }
+            let response = request.send().await?;
+            let status = response.status();
+            if !status.is_success() {
+                let body = response.text().await.unwrap_or_default();
+                return Err(SuiError::HttpStatus {
+                    status: status.as_u16(),
+                    retry_after: None,
+                    body_snippet: body.chars().take(BODY_SNIPPET_LEN).collect(),
+                });
+            }
+            Ok(response.json().await?)
+        })
+    }
+}
+
+/// # Mock gas sponsor
+///
+/// Sponsor paying with fixed gas coins of a local wallet, for examples and tests without a
+/// gas station. Every sponsored transaction is recorded.
+///
+/// ## Example
+/// ```rust
+/// use std::sync::Arc;
+/// use sui_network_sdk::SuiClient;
+/// use sui_network_sdk::sponsor::MockGasSponsor;
+/// use sui_network_sdk::trade::Trade;
+/// use sui_network_sdk::transport::MockTransport;
+/// use sui_network_sdk::types::{ObjectRef, SuiClientConfig};
+/// use sui_network_sdk::wallet::Wallet;
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let mock = Arc::new(MockTransport::new().with_result(
+///         "sui_executeTransactionBlock",
+///         serde_json::json!({ "digest": "A1" }),
+///     ));
+///     let client = SuiClient::new_with_transport(SuiClientConfig::default(), mock.clone());
+///     let sender = Wallet::new().unwrap();
+///     let gas_coin = ObjectRef {
+///         object_id: "0x9".to_string(),
+///         version: 3,
+///         digest: "9WzSXdwbky8tNbH7juvyaui4QzMUYEjdCEKMrMgLhXHT".to_string(),
+///     };
+///     let sponsor = MockGasSponsor::new(Wallet::new().unwrap(), vec![gas_coin], 1000);
+///
+///     let trade = Trade::new(&client, &sender).with_gas_budget(5_000_000);
+///     let trade = trade.with_gas_sponsor(&sponsor);
+///     let mut batch = trade.batch();
+///     batch.move_call("0x2", "tx_context", "epoch", vec![], vec![]);
+///     let response = batch.execute().await.unwrap();
+///     assert_eq!(response.digest, "A1");
+///     assert_eq!(sponsor.sponsored().len(), 1);
+///     assert_eq!(sponsor.sponsored()[0].gas_budget, 5_000_000);
+///     // executed with the sender and the sponsor signatures
+///     let requests = mock.requests();
+///     let execution = requests.iter().find(|r| r.method == "sui_executeTransactionBlock").unwrap();
+///     assert_eq!(execution.params[1].as_array().unwrap().len(), 2);
+///     // no gas coin lookup or gas price read, the sponsor chose the gas
+///     assert_eq!(mock.request_count("suix_getCoins"), 0);
+///     assert_eq!(mock.request_count("suix_getReferenceGasPrice"), 0);
+/// }
+/// ```
+pub struct MockGasSponsor {
+    wallet: Wallet,
+    payment: Vec<ObjectRef>,
+    price: u64,
+    sponsored: Mutex<Vec<UnsignedTransaction>>,
+}
+
+impl MockGasSponsor {
+    /// # create mock gas sponsor
+    ///
+    /// ## Parameters
+    /// - wallet : sponsor wallet, owner of the gas coins
+    /// - payment : gas coins
+    /// - price : gas price in MIST per unit
+    pub fn new(wallet: Wallet, payment: Vec<ObjectRef>, price: u64) -> Self {
+        Self {
+            wallet,
+            payment,
+            price,
+            sponsored: Mutex::new(Vec::new()),
+        }
+    }
+    /// transactions sponsored so far
+    pub fn sponsored(&self) -> Vec<UnsignedTransaction> {
+        self.sponsored
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .clone()
+    }
+    /// choose the gas and sign
+    fn sign(&self, tx: &UnsignedTransaction) -> Result<SponsoredGas, SuiError> {
+        let gas_data = GasData {
+            payment: self.payment.clone(),
+            owner: self.wallet.get_address().to_string(),
+            price: self.price,
+            budget: tx.gas_budget,
+        };
+        let tx_bytes = tx.with_gas(&gas_data)?.to_bytes()?;
+        let signature = self.wallet.sign_transaction(&tx_bytes);
+        self.sponsored
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .push(tx.clone());
+        Ok(SponsoredGas {
+            gas_data,
+            sponsor_signature: serialized_signature(
+                SignatureScheme::Ed25519,
+                &signature,
+                self.wallet.get_public_key_bytes(),
+            ),
+        })
+    }
+}
+
+impl GasSponsor for MockGasSponsor {
+    fn sponsor<'s>(
+        &'s self,
+        tx: &'s UnsignedTransaction,
+    ) -> BoxFuture<'s, Result<SponsoredGas, SuiError>> {
+        Box::pin(future::ready(self.sign(tx)))
+    }
+}
+
+impl<S: GasSponsor + ?Sized> GasSponsor for &S {
+    fn sponsor<'s>(
+        &'s self,
+        tx: &'s UnsignedTransaction,
+    ) -> BoxFuture<'s, Result<SponsoredGas, SuiError>> {
+        (**self).sponsor(tx)
+    }
+}
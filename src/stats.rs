@@ -0,0 +1,119 @@
+use std::collections::{BTreeMap, HashMap};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+/// upper bounds of the latency buckets in milliseconds, slower calls fall in a last bucket
+pub const LATENCY_BUCKETS_MS: [u64; 14] = [
+    1, 2, 5, 10, 20, 50, 100, 200, 500, 1_000, 2_000, 5_000, 10_000, 30_000,
+];
+
+/// # Method stats
+///
+/// Latencies of one rpc method since the client was created or its stats were reset.
+/// Percentiles are the upper bound of the bucket they fall in, calls slower than the last
+/// bucket report the maximum.
+///
+/// # Fields
+/// - count : calls, retries of a call included in its latency
+/// - p50 : median latency
+/// - p95 : 95th percentile latency
+/// - max : slowest call
+/// - buckets : calls per bucket of [`LATENCY_BUCKETS_MS`], then slower calls
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MethodStats {
+    pub count: u64,
+    pub p50: Duration,
+    pub p95: Duration,
+    pub max: Duration,
+    pub buckets: Vec<u64>,
+}
+
+/// latency histogram of one method
+#[derive(Default)]
+struct Histogram {
+    buckets: [AtomicU64; LATENCY_BUCKETS_MS.len() + 1],
+    max_us: AtomicU64,
+}
+
+impl Histogram {
+    fn record(&self, elapsed: Duration) {
+        let bucket = LATENCY_BUCKETS_MS
+            .iter()
+            .position(|bound| elapsed <= Duration::from_millis(*bound))
+            .unwrap_or(LATENCY_BUCKETS_MS.len());
+        self.buckets[bucket].fetch_add(1, Ordering::Relaxed);
+        self.max_us.fetch_max(
+            u64::try_from(elapsed.as_micros()).unwrap_or(u64::MAX),
+            Ordering::Relaxed,
+        );
+    }
+
+    fn snapshot(&self) -> MethodStats {
+        let buckets: Vec<u64> = self
+            .buckets
+            .iter()
+            .map(|b| b.load(Ordering::Relaxed))
+            .collect();
+        let count: u64 = buckets.iter().sum();
+        let max = Duration::from_micros(self.max_us.load(Ordering::Relaxed));
+        let percentile = |p: u64| {
+            // rank of the percentile among the calls, 1-based
+            let rank = (count * p).div_ceil(100).max(1);
+            let mut seen = 0;
+            for (i, calls) in buckets.iter().enumerate() {
+                seen += calls;
+                if seen >= rank {
+                    return LATENCY_BUCKETS_MS
+                        .get(i)
+                        .map_or(max, |bound| Duration::from_millis(*bound).min(max));
+                }
+            }
+            max
+        };
+        MethodStats {
+            count,
+            p50: percentile(50),
+            p95: percentile(95),
+            max,
+            buckets,
+        }
+    }
+}
+
+/// latency histograms by method, shared by the requests of a client
+#[derive(Default)]
+pub(crate) struct LatencyStats {
+    methods: Mutex<HashMap<String, Arc<Histogram>>>,
+}
+
+impl LatencyStats {
+    /// record a call of a method
+    pub(crate) fn record(&self, method: &str, elapsed: Duration) {
+        let histogram = {
+            let mut methods = self.lock();
+            match methods.get(method) {
+                Some(histogram) => histogram.clone(),
+                None => methods.entry(method.to_string()).or_default().clone(),
+            }
+        };
+        histogram.record(elapsed);
+    }
+
+    /// stats of every method called
+    pub(crate) fn snapshot(&self) -> BTreeMap<String, MethodStats> {
+        self.lock()
+            .iter()
+            .map(|(method, histogram)| (method.clone(), histogram.snapshot()))
+            .collect()
+    }
+
+    /// forget all calls
+    pub(crate) fn reset(&self) {
+        self.lock().clear();
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, HashMap<String, Arc<Histogram>>> {
+        self.methods.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
@@ -0,0 +1,243 @@
+use crate::math::format_sui_delta;
+use crate::ptb::TypeTag;
+use crate::types::{Coin, Object, Owner, TransactionEffects, TransactionResponse};
+use std::fmt;
+
+/// characters of a digest kept in a summary
+const DIGEST_PREFIX_LEN: usize = 6;
+
+/// # Summary
+///
+/// One-line summary of a response for logs, instead of the kilobytes `{:?}` prints. The
+/// formats are stable, fields are `key=value` pairs in a fixed order and absent values
+/// print as `?`.
+///
+/// ## Example
+/// ```rust
+/// use sui_network_sdk::summary::Summary;
+/// use sui_network_sdk::types::{Coin, Object, TransactionResponse};
+///
+/// let response: TransactionResponse = serde_json::from_value(serde_json::json!({
+///     "digest": "4xYbR1mC7a8S2AyGa6d5TqXu4LDhZx1wPfT5BKQ9hUjE",
+///     "effects": {
+///         "status": { "status": "success" },
+///         "gasUsed": { "computationCost": "1000000", "storageCost": "2976000", "storageRebate": "1876000" },
+///         "transactionDigest": "4xYbR1mC7a8S2AyGa6d5TqXu4LDhZx1wPfT5BKQ9hUjE",
+///         "created": [
+///             { "owner": { "AddressOwner": "0xb0b" }, "reference": { "objectId": "0xc1", "version": "9", "digest": "d" } },
+///             { "owner": { "AddressOwner": "0xb0b" }, "reference": { "objectId": "0xc2", "version": "9", "digest": "d" } }
+///         ]
+///     }
+/// }))
+/// .unwrap();
+/// assert_eq!(
+///     response.summary(),
+///     "tx 4xYbR1… status=Success gas=0.0021 SUI created=2 mutated=0 deleted=0"
+/// );
+/// assert_eq!(
+///     format!("{}", response.effects.as_ref().unwrap().compact()),
+///     "effects 4xYbR1… status=Success gas=0.0021 SUI created=2 mutated=0 deleted=0"
+/// );
+///
+/// let failed: TransactionResponse = serde_json::from_value(serde_json::json!({
+///     "digest": "9WzSXdwbky8tNbH7juvyaui4QzMUYEjdCEKMrMgLhXHT",
+///     "effects": {
+///         "status": { "status": "failure", "error": "InsufficientGas" },
+///         "gasUsed": { "computationCost": "1000", "storageCost": "0", "storageRebate": "3000" },
+///         "transactionDigest": "9WzSXdwbky8tNbH7juvyaui4QzMUYEjdCEKMrMgLhXHT"
+///     }
+/// }))
+/// .unwrap();
+/// assert_eq!(
+///     failed.summary(),
+///     "tx 9WzSXd… status=Failure gas=-0.000002 SUI created=0 mutated=0 deleted=0"
+/// );
+/// let pending: TransactionResponse =
+///     serde_json::from_value(serde_json::json!({ "digest": "AbC" })).unwrap();
+/// assert_eq!(pending.summary(), "tx AbC status=? gas=? created=? mutated=? deleted=?");
+///
+/// let object: Object = serde_json::from_value(serde_json::json!({
+///     "objectId": "0xabc",
+///     "version": "42",
+///     "digest": "9WzSXdwbky8tNbH7juvyaui4QzMUYEjdCEKMrMgLhXHT",
+///     "type": "0x2::coin::Coin<0x2::sui::SUI>",
+///     "owner": { "AddressOwner": "0xdef" }
+/// }))
+/// .unwrap();
+/// assert_eq!(object.summary(), "object 0xabc type=Coin<SUI> v=42 owner=0xdef");
+/// let shared: Object = serde_json::from_value(serde_json::json!({
+///     "objectId": "0x5",
+///     "version": "1",
+///     "digest": "9WzSXdwbky8tNbH7juvyaui4QzMUYEjdCEKMrMgLhXHT",
+///     "type": "0xdee9::pool::Pool<0x2::sui::SUI, vector<u8>>",
+///     "owner": { "Shared": { "initial_shared_version": "1" } }
+/// }))
+/// .unwrap();
+/// assert_eq!(shared.summary(), "object 0x5 type=Pool<SUI, vector<u8>> v=1 owner=shared");
+///
+/// let coin: Coin = serde_json::from_value(serde_json::json!({
+///     "coinType": "0x5d4b::coin::COIN",
+///     "coinObjectId": "0xc01",
+///     "version": "7",
+///     "digest": "9WzSXdwbky8tNbH7juvyaui4QzMUYEjdCEKMrMgLhXHT",
+///     "balance": "1500"
+/// }))
+/// .unwrap();
+/// assert_eq!(coin.summary(), "coin 0xc01 type=COIN balance=1500 v=7");
+/// ```
+pub trait Summary {
+    /// write the one-line summary
+    fn fmt_compact(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result;
+
+    /// one-line summary
+    fn summary(&self) -> String {
+        self.compact().to_string()
+    }
+
+    /// displays as the one-line summary, for `format!` and logging without a copy
+    fn compact(&self) -> Compact<'_, Self> {
+        Compact(self)
+    }
+}
+
+/// # Compact
+///
+/// Display of a [`Summary`].
+pub struct Compact<'a, T: ?Sized>(&'a T);
+
+impl<T: Summary + ?Sized> fmt::Display for Compact<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt_compact(f)
+    }
+}
+
+impl Summary for TransactionResponse {
+    fn fmt_compact(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "tx {} ", ShortDigest(&self.digest))?;
+        match &self.effects {
+            Some(effects) => fmt_effects(effects, f)?,
+            None => write!(f, "status=? gas=? created=? mutated=? deleted=?")?,
+        }
+        match self.known_loaded_child_objects() {
+            Some(children) => write!(f, " children={}", children.len()),
+            None => Ok(()),
+        }
+    }
+}
+
+impl Summary for TransactionEffects {
+    fn fmt_compact(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "effects {} ", ShortDigest(&self.transaction_digest))?;
+        fmt_effects(self, f)
+    }
+}
+
+impl Summary for Object {
+    fn fmt_compact(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "object {} type=", ShortAddress(&self.object_id))?;
+        match self.parsed_type() {
+            Some(object_type) => fmt_type_name(
+                &TypeTag::Struct(Box::new(object_type.struct_tag().clone())),
+                f,
+            )?,
+            None => write!(f, "{}", self.object_type.as_deref().unwrap_or("?"))?,
+        }
+        write!(f, " v={} owner=", self.version)?;
+        match &self.owner {
+            Some(Owner::AddressOwner(address)) => write!(f, "{}", ShortAddress(address)),
+            Some(Owner::ObjectOwner(parent)) => write!(f, "object:{}", ShortAddress(parent)),
+            Some(Owner::Shared(_)) => write!(f, "shared"),
+            Some(Owner::Immutable) => write!(f, "immutable"),
+            Some(Owner::ConsensusAddressOwner(owner)) => {
+                write!(f, "consensus:{}", ShortAddress(&owner.owner))
+            }
+            Some(Owner::Unknown(_)) | None => write!(f, "?"),
+        }
+    }
+}
+
+impl Summary for Coin {
+    fn fmt_compact(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "coin {} type=", ShortAddress(&self.coin_object_id))?;
+        match self.coin_type.parse::<TypeTag>() {
+            Ok(coin_type) => fmt_type_name(&coin_type, f)?,
+            Err(_) if self.coin_type.is_empty() => write!(f, "?")?,
+            Err(_) => write!(f, "{}", self.coin_type)?,
+        }
+        write!(f, " balance={} v={}", self.balance, self.version)
+    }
+}
+
+/// status, net gas and object counts of effects
+fn fmt_effects(effects: &TransactionEffects, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    let status = if effects.status.is_success() {
+        "Success"
+    } else {
+        "Failure"
+    };
+    write!(
+        f,
+        "status={} gas={} created={} mutated={} deleted={}",
+        status,
+        format_sui_delta(effects.gas_used.net_cost()),
+        effects.created.len(),
+        effects.mutated.len(),
+        effects.deleted.len()
+    )?;
+    if !effects.unwrapped_then_deleted.is_empty() {
+        write!(
+            f,
+            " unwrapped_then_deleted={}",
+            effects.unwrapped_then_deleted.len()
+        )?;
+    }
+    Ok(())
+}
+
+/// type without addresses and modules, e.g. `Coin<SUI>`
+fn fmt_type_name(type_tag: &TypeTag, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match type_tag {
+        TypeTag::Struct(tag) => {
+            write!(f, "{}", tag.name)?;
+            if let Some((first, rest)) = tag.type_params.split_first() {
+                write!(f, "<")?;
+                fmt_type_name(first, f)?;
+                for param in rest {
+                    write!(f, ", ")?;
+                    fmt_type_name(param, f)?;
+                }
+                write!(f, ">")?;
+            }
+            Ok(())
+        }
+        TypeTag::Vector(inner) => {
+            write!(f, "vector<")?;
+            fmt_type_name(inner, f)?;
+            write!(f, ">")
+        }
+        primitive => write!(f, "{}", primitive),
+    }
+}
+
+/// digest cut to its first characters
+struct ShortDigest<'a>(&'a str);
+
+impl fmt::Display for ShortDigest<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.0.char_indices().nth(DIGEST_PREFIX_LEN) {
+            Some((end, _)) => write!(f, "{}…", &self.0[..end]),
+            None => write!(f, "{}", self.0),
+        }
+    }
+}
+
+/// address without leading zeros
+struct ShortAddress<'a>(&'a str);
+
+impl fmt::Display for ShortAddress<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let hex = self.0.strip_prefix("0x").unwrap_or(self.0);
+        let trimmed = hex.trim_start_matches('0');
+        write!(f, "0x{}", if trimmed.is_empty() { "0" } else { trimmed })
+    }
+}
@@ -1,24 +1,58 @@
 use crate::SuiClient;
-use crate::types::SuiError;
-use crate::wallet::Wallet;
+use crate::ptb::{
+    Argument, CallArg, ObjectArg, ProgrammableTransaction, ProgrammableTransactionBuilder,
+    TransactionData, TypeTag, address_bytes, object_reference, transaction_digest,
+};
+use crate::sponsor::{GasSponsor, UnsignedTransaction};
+use crate::types::{
+    Coin, ConsistencyReport, DestroyZeroReport, DevInspectResults, GasPlan, Object, ObjectRef,
+    ObjectType, Owner, RetryPolicy, SuiError, TransactionLimits, TransactionPlan,
+    TransactionResponse, TransferredObject,
+};
+use crate::wallet::{
+    Signer, normalize_address, serialized_signature, verify_transaction_signatures,
+};
 use base64::Engine;
 use base64::prelude::BASE64_STANDARD;
+use rand::{Rng, rng};
 use serde_json::Value;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::Duration;
+
+/// minimum principal of a StakedSui object (1 SUI), `MIN_STAKING_THRESHOLD` of
+/// `0x3::staking_pool`, a Move constant the system state does not report
+pub const MIN_STAKING_THRESHOLD: u64 = 1_000_000_000;
+/// wait between lookups of a transaction whose submission gave no answer
+const SUBMISSION_POLL_INTERVAL: Duration = Duration::from_millis(500);
+/// safety margin of estimated gas budgets unless one is set, in percent
+const DEFAULT_GAS_MARGIN_PERCENT: u64 = 20;
 
 pub struct Trade<'a> {
     client: &'a SuiClient,
-    wallet: &'a Wallet,
+    signer: &'a dyn Signer,
     gas_payment: Option<String>,
     gas_budget: u64,
+    allow_self_transfer: bool,
+    allow_zero_amount: bool,
+    gas_sponsor: Option<Box<dyn GasSponsor + 'a>>,
+    gas_price_ttl: Option<Duration>,
+    gas_margin_percent: Option<u64>,
+    verify_before_submit: bool,
 }
 
 impl<'a> Trade<'a> {
-    pub fn new(client: &'a SuiClient, wallet: &'a Wallet) -> Self {
+    pub fn new(client: &'a SuiClient, signer: &'a dyn Signer) -> Self {
         Self {
             client,
-            wallet,
+            signer,
             gas_payment: None,
             gas_budget: 1000,
+            allow_self_transfer: false,
+            allow_zero_amount: false,
+            gas_sponsor: None,
+            gas_price_ttl: None,
+            gas_margin_percent: None,
+            verify_before_submit: false,
         }
     }
     pub fn with_gas_payment(mut self, gas_payment: String) -> Self {
@@ -29,24 +63,283 @@ impl<'a> Trade<'a> {
         self.gas_budget = gas_budget;
         self
     }
-    /// transfer by sui
+    /// # With auto gas budget
+    ///
+    /// Budgets each built transaction from a dry run instead of the fixed gas budget, see
+    /// [`Trade::estimate_gas_budget`]. The transaction is built once to be dry run at the
+    /// gas coin's balance and rebuilt with the estimate, which replaces the fixed budget.
+    ///
+    /// ## Parameters
+    /// - margin_percent : safety margin over the dry run cost, in percent
+    ///
+    /// ## Returns
+    /// trade estimating its gas budgets
+    ///
+    /// ## Example
+    /// ```rust
+    /// use std::sync::Arc;
+    /// use sui_network_sdk::SuiClient;
+    /// use sui_network_sdk::trade::Trade;
+    /// use sui_network_sdk::transport::MockTransport;
+    /// use sui_network_sdk::types::SuiClientConfig;
+    /// use sui_network_sdk::wallet::Wallet;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let mock = Arc::new(MockTransport::new()
+    ///         .with_result("sui_multiGetObjects", serde_json::json!([{ "data": {
+    ///             "objectId": "0x9", "version": "3", "digest": "G1", "type": "0x2::coin::Coin<0x2::sui::SUI>",
+    ///             "content": { "dataType": "moveObject", "fields": { "balance": "900000000" } }
+    ///         } }]))
+    ///         .with_result("unsafe_splitCoin", serde_json::json!({ "txBytes": "AAA=" }))
+    ///         .with_result("sui_dryRunTransactionBlock", serde_json::json!({ "effects": {
+    ///             "status": { "status": "success" },
+    ///             "gasUsed": { "computationCost": "1000000", "storageCost": "2000000", "storageRebate": "1000000" },
+    ///             "transactionDigest": "D1"
+    ///         } })));
+    ///     let client = SuiClient::new_with_transport(SuiClientConfig::default(), mock.clone());
+    ///     let wallet = Wallet::new().unwrap();
+    ///     let trade = Trade::new(&client, &wallet)
+    ///         .with_gas_payment("0x9".to_string())
+    ///         .with_auto_gas_budget(10);
+    ///
+    ///     trade.split_coin("0x5", vec![1_000]).await.unwrap();
+    ///     let builds: Vec<_> = mock.requests().into_iter().filter(|r| r.method == "unsafe_splitCoin").collect();
+    ///     // dry run at the gas coin's balance, rebuilt with the cost and the margin
+    ///     assert_eq!(builds[0].params[4], "900000000");
+    ///     assert_eq!(builds[1].params[4], "2200000");
+    /// }
+    /// ```
+    pub fn with_auto_gas_budget(mut self, margin_percent: u64) -> Self {
+        self.gas_margin_percent = Some(margin_percent);
+        self
+    }
+    /// reuse the reference gas price of built transactions for the ttl, see
+    /// [`SuiClient::reference_gas_price_cached`]
+    pub fn with_gas_price_ttl(mut self, ttl: Duration) -> Self {
+        self.gas_price_ttl = Some(ttl);
+        self
+    }
+    /// allow transfers whose recipient is the sender
+    pub fn allow_self_transfer(mut self) -> Self {
+        self.allow_self_transfer = true;
+        self
+    }
+    /// allow transfers of a zero amount
+    pub fn allow_zero_amount(mut self) -> Self {
+        self.allow_zero_amount = true;
+        self
+    }
+    /// # Verify before submit
+    ///
+    /// Checks the signatures of every transaction this trade executes before it is sent,
+    /// see [`verify_transaction_signatures`]: a signing bug or a sponsor signing for
+    /// another gas owner fails with `SuiError::Sign` instead of reaching the node.
+    ///
+    /// ## Parameters
+    /// - verify : whether to verify, off by default
+    ///
+    /// ## Returns
+    /// trade verifying its submissions
+    ///
+    /// ## Example
+    /// ```rust
+    /// use std::sync::Arc;
+    /// use base64::Engine;
+    /// use base64::prelude::BASE64_STANDARD;
+    /// use futures::future::{self, BoxFuture};
+    /// use sui_network_sdk::SuiClient;
+    /// use sui_network_sdk::ptb::ObjectDigest;
+    /// use sui_network_sdk::sponsor::{GasSponsor, SponsoredGas, UnsignedTransaction};
+    /// use sui_network_sdk::trade::Trade;
+    /// use sui_network_sdk::transport::MockTransport;
+    /// use sui_network_sdk::types::{GasData, ObjectRef, SuiClientConfig, SuiError};
+    /// use sui_network_sdk::wallet::{Signer, Wallet};
+    ///
+    /// // a signer whose signatures come out corrupted
+    /// struct Corrupting(Wallet);
+    ///
+    /// impl Signer for Corrupting {
+    ///     fn address(&self) -> &str {
+    ///         self.0.get_address()
+    ///     }
+    ///     fn public_key(&self) -> &[u8] {
+    ///         self.0.get_public_key_bytes()
+    ///     }
+    ///     fn sign_transaction<'s>(&'s self, tx_bytes: &'s [u8]) -> BoxFuture<'s, Result<Vec<u8>, SuiError>> {
+    ///         let mut signature = self.0.sign_transaction(tx_bytes);
+    ///         signature[10] ^= 1;
+    ///         Box::pin(future::ready(Ok(signature)))
+    ///     }
+    ///     fn sign_personal_message<'s>(&'s self, message: &'s [u8]) -> BoxFuture<'s, Result<String, SuiError>> {
+    ///         Box::pin(future::ready(Ok(self.0.sign_personal_message(message))))
+    ///     }
+    /// }
+    ///
+    /// // a sponsor naming one gas owner and signing with another key
+    /// struct Mismatched { owner: Wallet, key: Wallet }
+    ///
+    /// impl GasSponsor for Mismatched {
+    ///     fn sponsor<'s>(&'s self, tx: &'s UnsignedTransaction) -> BoxFuture<'s, Result<SponsoredGas, SuiError>> {
+    ///         let payment = vec![ObjectRef { object_id: "0x9a5".into(), version: 3, digest: ObjectDigest([7; 32]).to_string() }];
+    ///         let gas_data = GasData { payment, owner: self.owner.get_address().into(), price: 750, budget: tx.gas_budget };
+    ///         let tx_bytes = tx.with_gas(&gas_data).unwrap().to_bytes().unwrap();
+    ///         let signature = [&[0u8][..], &self.key.sign_transaction(&tx_bytes), self.key.get_public_key_bytes()].concat();
+    ///         let sponsor_signature = BASE64_STANDARD.encode(signature);
+    ///         Box::pin(future::ready(Ok(SponsoredGas { gas_data, sponsor_signature })))
+    ///     }
+    /// }
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let digest = ObjectDigest([7; 32]).to_string();
+    ///     let mock = Arc::new(MockTransport::new()
+    ///         .with_result("suix_getCoins", serde_json::json!({
+    ///             "data": [{ "coinObjectId": "0x9a5", "version": "7", "digest": digest, "balance": "5000000000" }],
+    ///             "nextCursor": null,
+    ///             "hasNextPage": false
+    ///         }))
+    ///         .with_result("suix_getReferenceGasPrice", serde_json::json!("750")));
+    ///     let client = SuiClient::new_with_transport(SuiClientConfig::default(), mock.clone());
+    ///
+    ///     let signer = Corrupting(Wallet::new().unwrap());
+    ///     let trade = Trade::new(&client, &signer).verify_before_submit(true);
+    ///     let mut batch = trade.batch();
+    ///     batch.transfer_sui("0xb0b", 1_000);
+    ///     match batch.execute().await {
+    ///         Err(SuiError::Sign(e)) => assert!(e.contains("invalid Ed25519 signature"), "{}", e),
+    ///         other => panic!("unexpected {:?}", other),
+    ///     }
+    ///
+    ///     let wallet = Wallet::new().unwrap();
+    ///     let sponsor = Mismatched { owner: Wallet::new().unwrap(), key: Wallet::new().unwrap() };
+    ///     let trade = Trade::new(&client, &wallet)
+    ///         .with_gas_sponsor(sponsor)
+    ///         .verify_before_submit(true);
+    ///     let mut batch = trade.batch();
+    ///     batch.transfer_object(sui_network_sdk::trade::BatchArg::Gas, "0xb0b");
+    ///     match batch.execute().await {
+    ///         Err(SuiError::Sign(e)) => assert!(e.contains("signs for neither"), "{}", e),
+    ///         other => panic!("unexpected {:?}", other),
+    ///     }
+    ///     // nothing reached the node
+    ///     assert_eq!(mock.request_count("sui_executeTransactionBlock"), 0);
+    /// }
+    /// ```
+    pub fn verify_before_submit(mut self, verify: bool) -> Self {
+        self.verify_before_submit = verify;
+        self
+    }
+    /// # With gas sponsor
+    ///
+    /// Lets a gas station pay for the transactions. Batches built with [`Trade::batch`]
+    /// leave gas selection to the sponsor and execute with its signature next to the
+    /// sender's. Transactions built by the node (`unsafe_*` builders) can't carry sponsor
+    /// gas and fail with `SuiError::Unsupported`.
+    ///
+    /// ## Parameters
+    /// - gas_sponsor : gas station, e.g. an `HttpGasSponsor`
+    ///
+    /// ## Returns
+    /// trade paying gas through the sponsor
+    pub fn with_gas_sponsor(mut self, gas_sponsor: impl GasSponsor + 'a) -> Self {
+        self.gas_sponsor = Some(Box::new(gas_sponsor));
+        self
+    }
+    /// # Transfer by sui
+    ///
+    /// Builds and signs a SUI transfer. The recipient is an address or a SuiNS name such
+    /// as `example.sui`, resolved with [`SuiClient::resolve_name_service_address`]. The
+    /// amount is split off the gas coin, the configured gas payment or else the largest SUI
+    /// coin; a gas coin short of the amount and the gas budget fails with `SuiError::Gas`
+    /// before the transaction is built, see [`Trade::max_sendable_sui`].
+    ///
+    /// ## Example
+    /// ```rust
+    /// use std::sync::Arc;
+    /// use sui_network_sdk::SuiClient;
+    /// use sui_network_sdk::trade::Trade;
+    /// use sui_network_sdk::transport::MockTransport;
+    /// use sui_network_sdk::types::SuiClientConfig;
+    /// use sui_network_sdk::wallet::Wallet;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let mock = Arc::new(MockTransport::new()
+    ///         .with_result("suix_getCoins", serde_json::json!({
+    ///             "data": [{ "coinObjectId": "0x9", "version": "3", "digest": "G1", "balance": "5000000" }],
+    ///             "nextCursor": null,
+    ///             "hasNextPage": false
+    ///         }))
+    ///         .with_result("suix_resolveNameServiceAddress", serde_json::json!("0xb0b"))
+    ///         .with_result("suix_resolveNameServiceAddress", serde_json::json!(null))
+    ///         .with_result("unsafe_transferSui", serde_json::json!({ "txBytes": "AAA=" })));
+    ///     let client = SuiClient::new_with_transport(SuiClientConfig::default(), mock.clone());
+    ///     let wallet = Wallet::new().unwrap();
+    ///     let trade = Trade::new(&client, &wallet).with_gas_payment("0x9".to_string());
+    ///
+    ///     trade.transfer_by_sui("bob.sui", 1_000).await.unwrap();
+    ///     let transfer = mock.requests().into_iter().find(|r| r.method == "unsafe_transferSui").unwrap();
+    ///     assert_eq!(transfer.params[3], format!("0x{:0>64}", "b0b"));
+    ///
+    ///     let error = trade.transfer_by_sui("nobody.sui", 1_000).await.unwrap_err();
+    ///     assert!(error.to_string().contains("nobody.sui is not registered"));
+    ///     assert_eq!(mock.request_count("unsafe_transferSui"), 1);
+    ///
+    ///     // a zero amount fails before the name is looked up
+    ///     let error = trade.transfer_by_sui("bob.sui", 0).await.unwrap_err();
+    ///     assert!(error.to_string().contains("zero amount check"));
+    ///     assert_eq!(mock.request_count("suix_resolveNameServiceAddress"), 2);
+    /// }
+    /// ```
     pub async fn transfer_by_sui(
         &self,
         recipient: &str,
         amount: u64,
     ) -> Result<(Vec<u8>, Vec<u8>), SuiError> {
-        let gas_payment = self
-            .get_gas_payment()
-            .await
-            .ok_or_else(|| SuiError::Transaction("No gas payment available".to_string()))?;
-        let params = vec![
-            self.wallet.address.clone().into(),
-            gas_payment.into(),
-            amount.to_string().into(),
-            recipient.into(),
-            self.gas_budget.to_string().into(),
-        ];
-        let transaction_data: Value = self.client.request("unsafe_transferObject", params).await?;
+        // the amount is checked before the name is resolved, the recipient after
+        self.check_amount(amount)?;
+        let recipient = self.resolve_recipient(recipient).await?;
+        let recipient = recipient.as_str();
+        self.check_recipient(recipient)?;
+        // the amount is split off the gas coin, which also pays for gas
+        let gas_payment = self.check_sui_balance(amount, &[]).await?.coin_object_id;
+        let transaction_data = self
+            .build_transaction("unsafe_transferSui", &gas_payment, |gas_budget| {
+                vec![
+                    self.signer.address().into(),
+                    gas_payment.as_str().into(),
+                    gas_budget.to_string().into(),
+                    recipient.into(),
+                    amount.to_string().into(),
+                ]
+            })
+            .await?;
+        self.sign_transaction(transaction_data).await
+    }
+    /// publish a move package
+    pub async fn publish_package(
+        &self,
+        compiled_modules: Vec<Vec<u8>>,
+        dependencies: Vec<&str>,
+    ) -> Result<(Vec<u8>, Vec<u8>), SuiError> {
+        let gas_payment = self.get_gas_payment().await?;
+        let modules: Vec<Value> = compiled_modules
+            .iter()
+            .map(|module| BASE64_STANDARD.encode(module).into())
+            .collect();
+        let dependencies: Vec<Value> = dependencies.into_iter().map(Value::from).collect();
+        let transaction_data = self
+            .build_transaction("unsafe_publish", &gas_payment, |gas_budget| {
+                vec![
+                    self.signer.address().into(),
+                    modules.clone().into(),
+                    dependencies.clone().into(),
+                    gas_payment.as_str().into(),
+                    gas_budget.to_string().into(),
+                ]
+            })
+            .await?;
         self.sign_transaction(transaction_data).await
     }
     // call contract function
@@ -58,12 +351,85 @@ impl<'a> Trade<'a> {
         type_arguments: Vec<&str>,
         arguments: Vec<Value>,
     ) -> Result<(Vec<u8>, Vec<u8>), SuiError> {
-        let gas_payment = self
-            .get_gas_payment()
-            .await
-            .ok_or_else(|| SuiError::CallContract("No gas payment available".to_string()))?;
+        let gas_payment = self.get_gas_payment().await?;
+        let transaction_data = self
+            .build_transaction("unsafe_moveCall", &gas_payment, |gas_budget| {
+                vec![
+                    self.signer.address().into(),
+                    package_object_id.into(),
+                    module.into(),
+                    function.into(),
+                    type_arguments
+                        .iter()
+                        .copied()
+                        .map(Value::from)
+                        .collect::<Vec<_>>()
+                        .into(),
+                    arguments.clone().into(),
+                    gas_payment.as_str().into(),
+                    gas_budget.to_string().into(),
+                ]
+            })
+            .await?;
+        self.sign_transaction(transaction_data).await
+    }
+    /// # Dev inspect call
+    ///
+    /// Builds a Move call like [`Trade::call_contract_function`] and runs it with
+    /// [`SuiClient::dev_inspect`] instead of signing, e.g. to read a view function. The
+    /// node picks a gas coin for the build unless one is set, nothing is paid.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use std::sync::Arc;
+    /// use base64::Engine;
+    /// use base64::prelude::BASE64_STANDARD;
+    /// use sui_network_sdk::SuiClient;
+    /// use sui_network_sdk::ptb::{ProgrammableTransactionBuilder, TransactionData};
+    /// use sui_network_sdk::trade::Trade;
+    /// use sui_network_sdk::transport::MockTransport;
+    /// use sui_network_sdk::types::SuiClientConfig;
+    /// use sui_network_sdk::wallet::{Signer, Wallet};
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let wallet = Wallet::new().unwrap();
+    ///     // the transaction the node builds for the call
+    ///     let mut builder = ProgrammableTransactionBuilder::new();
+    ///     builder.move_call("0x5a0b", "pool", "quote", vec![], vec![]).unwrap();
+    ///     let pt = builder.finish();
+    ///     let data = TransactionData::new_programmable(wallet.address(), vec![], pt.clone(), 1000, 1000).unwrap();
+    ///     let mock = Arc::new(MockTransport::new()
+    ///         .with_result("unsafe_moveCall", serde_json::json!({ "txBytes": BASE64_STANDARD.encode(data.to_bytes().unwrap()) }))
+    ///         .with_result("sui_devInspectTransactionBlock", serde_json::json!({
+    ///             "effects": {
+    ///                 "status": { "status": "success" },
+    ///                 "gasUsed": { "computationCost": "1000", "storageCost": "0", "storageRebate": "0" },
+    ///                 "transactionDigest": "D1"
+    ///             },
+    ///             "results": [{ "returnValues": [[[42, 0, 0, 0, 0, 0, 0, 0], "u64"]] }]
+    ///         })));
+    ///     let client = SuiClient::new_with_transport(SuiClientConfig::default(), mock.clone());
+    ///     let trade = Trade::new(&client, &wallet);
+    ///
+    ///     let results = trade.dev_inspect_call("0x5a0b", "pool", "quote", vec![], vec![]).await.unwrap();
+    ///     assert_eq!(results.return_values(0)[0].bcs, [42, 0, 0, 0, 0, 0, 0, 0]);
+    ///     // the node received the transaction kind, without sender and gas
+    ///     let inspect = mock.requests().into_iter().find(|r| r.method == "sui_devInspectTransactionBlock").unwrap();
+    ///     assert_eq!(inspect.params[1], BASE64_STANDARD.encode(pt.kind_bytes().unwrap()));
+    ///     assert_eq!(mock.request_count("sui_executeTransactionBlock"), 0);
+    /// }
+    /// ```
+    pub async fn dev_inspect_call(
+        &self,
+        package_object_id: &str,
+        module: &str,
+        function: &str,
+        type_arguments: Vec<&str>,
+        arguments: Vec<Value>,
+    ) -> Result<DevInspectResults, SuiError> {
         let params = vec![
-            self.wallet.address.clone().into(),
+            self.signer.address().into(),
             package_object_id.into(),
             module.into(),
             function.into(),
@@ -73,11 +439,82 @@ impl<'a> Trade<'a> {
                 .collect::<Vec<_>>()
                 .into(),
             arguments.into(),
-            gas_payment.into(),
+            self.gas_payment.clone().into(),
             self.gas_budget.to_string().into(),
         ];
         let transaction_data: Value = self.client.request("unsafe_moveCall", params).await?;
-        self.sign_transaction(transaction_data).await
+        let tx_bytes = Self::decode_tx_bytes(&transaction_data)?;
+        let kind = TransactionData::from_bytes(&tx_bytes)?
+            .programmable_transaction()
+            .kind_bytes()?;
+        self.client
+            .dev_inspect(self.signer.address(), &kind, None, None)
+            .await
+    }
+    /// # Estimate gas budget
+    ///
+    /// Dry runs a built transaction and budgets its cost, computation plus storage minus
+    /// the rebate, with the safety margin of [`Trade::with_auto_gas_budget`] (20% unless
+    /// set), see [`GasCostSummary::gas_budget`].
+    ///
+    /// ## Parameters
+    /// - tx_bytes : BCS bytes of the transaction data
+    ///
+    /// ## Returns
+    /// - Ok(u64) : gas budget in MIST
+    /// - Err(SuiError) : the node rejected the dry run, or `SuiError::Transaction` with the
+    ///   execution error of a failed dry run, decoded by [`SuiError::explain`]
+    ///
+    /// ## Example
+    /// ```rust
+    /// use std::sync::Arc;
+    /// use sui_network_sdk::SuiClient;
+    /// use sui_network_sdk::errors::FailureKind;
+    /// use sui_network_sdk::trade::Trade;
+    /// use sui_network_sdk::transport::MockTransport;
+    /// use sui_network_sdk::types::SuiClientConfig;
+    /// use sui_network_sdk::wallet::Wallet;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let dry_run = |status: serde_json::Value| serde_json::json!({ "effects": {
+    ///         "status": status,
+    ///         "gasUsed": { "computationCost": "1000000", "storageCost": "2000000", "storageRebate": "500000" },
+    ///         "transactionDigest": "D1"
+    ///     } });
+    ///     let abort = r#"MoveAbort(MoveLocation { module: ModuleId { address: 5a0b, name: Identifier("pool") }, function: 3, instruction: 12, function_name: Some("swap") }, 2) in command 0"#;
+    ///     let mock = Arc::new(MockTransport::new()
+    ///         .with_result("sui_dryRunTransactionBlock", dry_run(serde_json::json!({ "status": "success" })))
+    ///         .with_result("sui_dryRunTransactionBlock", dry_run(serde_json::json!({ "status": "failure", "error": abort }))));
+    ///     let client = SuiClient::new_with_transport(SuiClientConfig::default(), mock.clone());
+    ///     let wallet = Wallet::new().unwrap();
+    ///     let trade = Trade::new(&client, &wallet);
+    ///
+    ///     assert_eq!(trade.estimate_gas_budget(b"tx bytes").await.unwrap(), 3_000_000);
+    ///
+    ///     let error = trade.estimate_gas_budget(b"tx bytes").await.unwrap_err();
+    ///     let explanation = error.explain().unwrap();
+    ///     assert_eq!(explanation.command, Some(0));
+    ///     assert!(matches!(explanation.kind, FailureKind::MoveAbort { code: Some(2), .. }));
+    /// }
+    /// ```
+    ///
+    /// [`GasCostSummary::gas_budget`]: crate::types::GasCostSummary::gas_budget
+    pub async fn estimate_gas_budget(&self, tx_bytes: &[u8]) -> Result<u64, SuiError> {
+        let effects = self
+            .client
+            .dry_run_transaction_block(tx_bytes)
+            .await?
+            .effects;
+        if !effects.status.is_success() {
+            return Err(SuiError::Transaction(
+                effects.status.error.unwrap_or_default(),
+            ));
+        }
+        let margin_percent = self
+            .gas_margin_percent
+            .unwrap_or(DEFAULT_GAS_MARGIN_PERCENT);
+        Ok(effects.gas_used.gas_budget(margin_percent))
     }
     // merge coins
     pub async fn merge_coins(
@@ -85,18 +522,20 @@ impl<'a> Trade<'a> {
         primary_coin: &str,
         coin_to_merge: &str,
     ) -> Result<(Vec<u8>, Vec<u8>), SuiError> {
-        let gas_payment = self
-            .get_gas_payment()
-            .await
-            .ok_or_else(|| SuiError::CallContract("No gas payment available".to_string()))?;
-        let params = vec![
-            self.wallet.address.clone().into(),
-            primary_coin.into(),
-            coin_to_merge.into(),
-            gas_payment.into(),
-            self.gas_budget.to_string().into(),
-        ];
-        let transaction_data: Value = self.client.request("unsafe_mergeCoins", params).await?;
+        self.resolve_owned_objects(&[primary_coin, coin_to_merge])
+            .await?;
+        let gas_payment = self.get_gas_payment().await?;
+        let transaction_data = self
+            .build_transaction("unsafe_mergeCoins", &gas_payment, |gas_budget| {
+                vec![
+                    self.signer.address().into(),
+                    primary_coin.into(),
+                    coin_to_merge.into(),
+                    gas_payment.as_str().into(),
+                    gas_budget.to_string().into(),
+                ]
+            })
+            .await?;
         self.sign_transaction(transaction_data).await
     }
     // split coin
@@ -105,48 +544,1775 @@ impl<'a> Trade<'a> {
         coin_object_id: &str,
         split_amounts: Vec<u64>,
     ) -> Result<(Vec<u8>, Vec<u8>), SuiError> {
-        let gas_payment = self
-            .get_gas_payment()
-            .await
-            .ok_or_else(|| SuiError::CallContract("No gas payment available".to_string()))?;
+        let gas_payment = self.get_gas_payment().await?;
         let amounts: Vec<Value> = split_amounts
             .into_iter()
             .map(|amount| amount.to_string().into())
             .collect();
-        let params = vec![
-            self.wallet.address.clone().into(),
-            coin_object_id.into(),
-            amounts.into(),
-            gas_payment.into(),
-            self.gas_budget.to_string().into(),
-        ];
-        let transaction_data: Value = self.client.request("unsafe_splitCoin", params).await?;
+        let transaction_data = self
+            .build_transaction("unsafe_splitCoin", &gas_payment, |gas_budget| {
+                vec![
+                    self.signer.address().into(),
+                    coin_object_id.into(),
+                    amounts.clone().into(),
+                    gas_payment.as_str().into(),
+                    gas_budget.to_string().into(),
+                ]
+            })
+            .await?;
         self.sign_transaction(transaction_data).await
     }
-    /// get gas payment
-    async fn get_gas_payment(&self) -> Option<String> {
+    /// # Split stake
+    ///
+    /// Splits `amount` MIST of principal off a StakedSui object into a new StakedSui
+    /// object owned by the wallet (`0x3::staking_pool::split_staked_sui`), see
+    /// [`TransactionResponse::created_staked_sui`](crate::types::TransactionResponse::created_staked_sui)
+    /// for the id of the new object. Both halves must keep at least [`MIN_STAKING_THRESHOLD`].
+    ///
+    /// ## Parameters
+    /// - staked_sui_id : StakedSui object id
+    /// - amount : principal to split off in MIST
+    ///
+    /// ## Returns
+    /// - Ok((Vec<u8>, Vec<u8>)) : transaction bytes and signature
+    /// - Err(SuiError) : error, including an object that is not an owned StakedSui or
+    ///   a split leaving less than the minimum stake on either half
+    pub async fn split_stake(
+        &self,
+        staked_sui_id: &str,
+        amount: u64,
+    ) -> Result<(Vec<u8>, Vec<u8>), SuiError> {
+        let principal = self.get_stake_principal(staked_sui_id).await?;
+        if amount < MIN_STAKING_THRESHOLD
+            || principal.saturating_sub(amount) < MIN_STAKING_THRESHOLD
+        {
+            return Err(SuiError::Transaction(format!(
+                "minimum stake check: splitting {} MIST off a stake of {} MIST leaves a half below {} MIST",
+                amount, principal, MIN_STAKING_THRESHOLD
+            )));
+        }
+        self.call_contract_function(
+            "0x3",
+            "staking_pool",
+            "split_staked_sui",
+            vec![],
+            vec![staked_sui_id.into(), amount.to_string().into()],
+        )
+        .await
+    }
+    /// # Transfer stake
+    ///
+    /// Transfers a StakedSui object to another address.
+    ///
+    /// ## Parameters
+    /// - staked_sui_id : StakedSui object id
+    /// - recipient : recipient address
+    ///
+    /// ## Returns
+    /// - Ok((Vec<u8>, Vec<u8>)) : transaction bytes and signature
+    /// - Err(SuiError) : error, including an object that is not an owned StakedSui
+    pub async fn transfer_stake(
+        &self,
+        staked_sui_id: &str,
+        recipient: &str,
+    ) -> Result<(Vec<u8>, Vec<u8>), SuiError> {
+        self.check_recipient(recipient)?;
+        self.get_stake_principal(staked_sui_id).await?;
+        let gas_payment = self.get_gas_payment().await?;
+        let transaction_data = self
+            .build_transaction("unsafe_transferObject", &gas_payment, |gas_budget| {
+                vec![
+                    self.signer.address().into(),
+                    staked_sui_id.into(),
+                    gas_payment.as_str().into(),
+                    gas_budget.to_string().into(),
+                    recipient.into(),
+                ]
+            })
+            .await?;
+        self.sign_transaction(transaction_data).await
+    }
+    /// # Destroy zero coins
+    ///
+    /// Destroys all zero balance coins of a coin type for their storage rebate. Each
+    /// transaction is a batch calling `0x2::coin::destroy_zero` once per coin, coins are
+    /// chunked so no transaction exceeds the node's transaction limits.
+    ///
+    /// ## Parameters
+    /// - coin_type : coin type, e.g. `0x2::sui::SUI`
+    ///
+    /// ## Returns
+    /// - Ok(DestroyZeroReport) : the destroyed coin ids, the total storage rebate reported
+    ///   by the effects and the transaction digests
+    /// - Err(SuiError) : error, including a failed transaction; the coins of the transactions
+    ///   executed before it are destroyed
+    ///
+    /// ## Example
+    /// ```rust
+    /// use std::sync::Arc;
+    /// use base64::Engine;
+    /// use base64::prelude::BASE64_STANDARD;
+    /// use sui_network_sdk::SuiClient;
+    /// use sui_network_sdk::ptb::{ObjectDigest, TransactionData};
+    /// use sui_network_sdk::trade::Trade;
+    /// use sui_network_sdk::transport::MockTransport;
+    /// use sui_network_sdk::types::SuiClientConfig;
+    /// use sui_network_sdk::wallet::Wallet;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let digest = ObjectDigest([7; 32]).to_string();
+    ///     let coin = |id: &str, balance: &str| serde_json::json!({
+    ///         "coinObjectId": id, "version": "3", "digest": digest, "balance": balance
+    ///     });
+    ///     let object = |id: &str| serde_json::json!({ "data": {
+    ///         "objectId": id, "version": "3", "digest": digest
+    ///     }});
+    ///     let executed = |digest: &str, deleted: &[&str], rebate: &str| serde_json::json!({
+    ///         "digest": digest,
+    ///         "effects": {
+    ///             "status": { "status": "success" },
+    ///             "gasUsed": { "computationCost": "1", "storageCost": "1", "storageRebate": rebate },
+    ///             "transactionDigest": digest,
+    ///             "deleted": deleted.iter().map(|id| serde_json::json!({
+    ///                 "objectId": id, "version": "4", "digest": ObjectDigest([7; 32]).to_string()
+    ///             })).collect::<Vec<_>>()
+    ///         }
+    ///     });
+    ///     // two commands per transaction, so the three zero coins take two transactions
+    ///     let mock = Arc::new(MockTransport::new()
+    ///         .with_result("sui_getProtocolConfig", serde_json::json!({
+    ///             "attributes": { "max_programmable_tx_commands": { "u64": "2" } }
+    ///         }))
+    ///         .with_result("suix_getCoins", serde_json::json!({
+    ///             "data": [coin("0xa1", "0"), coin("0xa2", "0"), coin("0xa3", "0"), coin("0x9a5", "5000000000")],
+    ///             "nextCursor": null,
+    ///             "hasNextPage": false
+    ///         }))
+    ///         .with_result("suix_getReferenceGasPrice", serde_json::json!("750")));
+    ///     // each chunk is read twice for a consistent snapshot
+    ///     for chunk in [vec![object("0xa1"), object("0xa2")], vec![object("0xa3")]] {
+    ///         mock.push_result("sui_multiGetObjects", serde_json::json!(chunk.clone()));
+    ///         mock.push_result("sui_multiGetObjects", serde_json::json!(chunk));
+    ///     }
+    ///     mock.push_result("sui_executeTransactionBlock", executed("TX1", &["0xa1", "0xa2"], "1976000"));
+    ///     mock.push_result("sui_executeTransactionBlock", executed("TX2", &["0xa3"], "988000"));
+    ///     let client = SuiClient::new_with_transport(SuiClientConfig::default(), mock.clone());
+    ///     let wallet = Wallet::new().unwrap();
+    ///     let trade = Trade::new(&client, &wallet);
+    ///
+    ///     let report = trade.destroy_zero_coins("0x2::sui::SUI").await.unwrap();
+    ///     assert_eq!(report.coin_ids.len(), 3);
+    ///     assert_eq!(report.storage_rebate, 2_964_000);
+    ///     assert_eq!(report.digests, vec!["TX1", "TX2"]);
+    ///     // the chunks are programmable transactions paid by the non-zero coin
+    ///     let commands: Vec<usize> = mock
+    ///         .requests()
+    ///         .iter()
+    ///         .filter(|r| r.method == "sui_executeTransactionBlock")
+    ///         .map(|r| {
+    ///             let tx_bytes = BASE64_STANDARD.decode(r.params[0].as_str().unwrap()).unwrap();
+    ///             let data = TransactionData::from_bytes(&tx_bytes).unwrap();
+    ///             assert!(data.gas_payment()[0].object_id.ends_with("9a5"));
+    ///             data.programmable_transaction().commands.len()
+    ///         })
+    ///         .collect();
+    ///     assert_eq!(commands, vec![2, 1]);
+    /// }
+    /// ```
+    pub async fn destroy_zero_coins(&self, coin_type: &str) -> Result<DestroyZeroReport, SuiError> {
+        let coins = self.get_zero_balance_coins(coin_type).await?;
+        let limits = self.client.transaction_limits().await?;
+        let mut report = DestroyZeroReport::default();
+        let mut remaining = coins.as_slice();
+        while !remaining.is_empty() {
+            let count = self.destroy_zero_chunk_len(coin_type, remaining, &limits)?;
+            let (chunk, rest) = remaining.split_at(count);
+            remaining = rest;
+            let prepared = self.prepare_destroy_zero(coin_type, chunk).await?;
+            let response = self.execute_prepared(prepared).await?;
+            let effects = response
+                .effects
+                .ok_or_else(|| SuiError::Transaction("No effects in response".to_string()))?;
+            if !effects.status.is_success() {
+                return Err(SuiError::Transaction(format!(
+                    "destroy_zero failed: {}",
+                    effects.status.error.unwrap_or_default()
+                )));
+            }
+            report
+                .coin_ids
+                .extend(effects.deleted.into_iter().map(|o| o.object_id));
+            report.storage_rebate += effects.gas_used.storage_rebate;
+            report.digests.push(response.digest);
+        }
+        Ok(report)
+    }
+    /// # Destroy zero coins dry run
+    ///
+    /// Dry runs the transactions of [`Trade::destroy_zero_coins`] without executing them.
+    ///
+    /// ## Parameters
+    /// - coin_type : coin type, e.g. `0x2::sui::SUI`
+    ///
+    /// ## Returns
+    /// - Ok(DestroyZeroReport) : the coin ids that would be destroyed and the expected
+    ///   storage rebate, no digests
+    /// - Err(SuiError) : error, including a dry run that fails
+    ///
+    /// ## Example
+    /// ```rust
+    /// use std::sync::Arc;
+    /// use sui_network_sdk::SuiClient;
+    /// use sui_network_sdk::ptb::ObjectDigest;
+    /// use sui_network_sdk::trade::Trade;
+    /// use sui_network_sdk::transport::MockTransport;
+    /// use sui_network_sdk::types::SuiClientConfig;
+    /// use sui_network_sdk::wallet::Wallet;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let digest = ObjectDigest([7; 32]).to_string();
+    ///     let dry_run = |status: &str, rebate: &str| serde_json::json!({ "effects": {
+    ///         "status": { "status": status, "error": "InsufficientGas" },
+    ///         "gasUsed": { "computationCost": "1", "storageCost": "1", "storageRebate": rebate },
+    ///         "transactionDigest": "DRY"
+    ///     }});
+    ///     let mock = Arc::new(MockTransport::new()
+    ///         .with_result("suix_getCoins", serde_json::json!({
+    ///             "data": [
+    ///                 { "coinObjectId": "0xa1", "version": "3", "digest": digest, "balance": "0" },
+    ///                 { "coinObjectId": "0x9a5", "version": "3", "digest": digest, "balance": "5000000000" }
+    ///             ],
+    ///             "nextCursor": null,
+    ///             "hasNextPage": false
+    ///         }))
+    ///         .with_result("sui_multiGetObjects", serde_json::json!([{ "data": {
+    ///             "objectId": "0xa1", "version": "3", "digest": digest
+    ///         }}]))
+    ///         .with_result("suix_getReferenceGasPrice", serde_json::json!("750")));
+    ///     mock.push_result("sui_dryRunTransactionBlock", dry_run("success", "988000"));
+    ///     mock.push_result("sui_dryRunTransactionBlock", dry_run("failure", "0"));
+    ///     let client = SuiClient::new_with_transport(SuiClientConfig::default(), mock.clone());
+    ///     let wallet = Wallet::new().unwrap();
+    ///     let trade = Trade::new(&client, &wallet);
+    ///
+    ///     let report = trade.destroy_zero_coins_dry_run("0x2::sui::SUI").await.unwrap();
+    ///     assert_eq!(report.coin_ids.len(), 1);
+    ///     assert_eq!(report.storage_rebate, 988_000);
+    ///     assert!(report.digests.is_empty());
+    ///     assert_eq!(mock.request_count("sui_executeTransactionBlock"), 0);
+    ///
+    ///     // a failing dry run is an error rather than a rebate
+    ///     let error = trade.destroy_zero_coins_dry_run("0x2::sui::SUI").await.unwrap_err();
+    ///     assert!(error.to_string().contains("InsufficientGas"));
+    /// }
+    /// ```
+    pub async fn destroy_zero_coins_dry_run(
+        &self,
+        coin_type: &str,
+    ) -> Result<DestroyZeroReport, SuiError> {
+        let coins = self.get_zero_balance_coins(coin_type).await?;
+        let limits = self.client.transaction_limits().await?;
+        let mut report = DestroyZeroReport::default();
+        let mut remaining = coins.as_slice();
+        while !remaining.is_empty() {
+            let count = self.destroy_zero_chunk_len(coin_type, remaining, &limits)?;
+            let (chunk, rest) = remaining.split_at(count);
+            remaining = rest;
+            let prepared = self.prepare_destroy_zero(coin_type, chunk).await?;
+            let effects = self
+                .client
+                .dry_run_transaction_block(prepared.tx_bytes())
+                .await?
+                .effects;
+            if !effects.status.is_success() {
+                return Err(SuiError::Transaction(format!(
+                    "destroy_zero dry run failed: {}",
+                    effects.status.error.unwrap_or_default()
+                )));
+            }
+            report
+                .coin_ids
+                .extend(chunk.iter().map(|coin| coin.coin_object_id.clone()));
+            report.storage_rebate += effects.gas_used.storage_rebate;
+        }
+        Ok(report)
+    }
+    /// get zero balance coins of a coin type
+    async fn get_zero_balance_coins(&self, coin_type: &str) -> Result<Vec<Coin>, SuiError> {
+        let coins = self
+            .client
+            .get_coin_vec(self.signer.address(), Some(coin_type))
+            .await?;
+        Ok(coins.into_iter().filter(|coin| coin.balance == 0).collect())
+    }
+    /// length of the longest prefix of the coins one destroy_zero transaction takes
+    /// within the limits, measured on the transaction the batch builds
+    fn destroy_zero_chunk_len(
+        &self,
+        coin_type: &str,
+        coins: &[Coin],
+        limits: &TransactionLimits,
+    ) -> Result<usize, SuiError> {
+        let type_tag: TypeTag = coin_type.parse()?;
+        // one command and one input object per coin, next to the gas coin
+        let mut count = coins
+            .len()
+            .min(limits.max_commands as usize)
+            .min(limits.max_input_objects.saturating_sub(1) as usize)
+            .max(1);
+        loop {
+            let mut builder = ProgrammableTransactionBuilder::new();
+            for coin in &coins[..count] {
+                let coin_ref = ObjectRef {
+                    object_id: coin.coin_object_id.clone(),
+                    version: coin.version,
+                    digest: coin.digest.clone(),
+                };
+                let coin =
+                    builder.object(ObjectArg::ImmOrOwnedObject(object_reference(&coin_ref)?));
+                builder.move_call(
+                    "0x2",
+                    "coin",
+                    "destroy_zero",
+                    vec![type_tag.clone()],
+                    vec![coin],
+                )?;
+            }
+            // object references and gas numbers have a fixed size, any coin stands in for the gas coin
+            let gas_coin = ObjectRef {
+                object_id: coins[0].coin_object_id.clone(),
+                version: coins[0].version,
+                digest: coins[0].digest.clone(),
+            };
+            let size = TransactionData::new_programmable(
+                self.signer.address(),
+                vec![object_reference(&gas_coin)?],
+                builder.finish(),
+                self.gas_budget,
+                0,
+            )?
+            .to_bytes()?
+            .len();
+            if size as u64 <= limits.max_tx_size_bytes || count == 1 {
+                return Ok(count);
+            }
+            let fitting = count as u64 * limits.max_tx_size_bytes / size as u64;
+            count = (fitting as usize).clamp(1, count - 1);
+        }
+    }
+    /// prepare a batch calling `0x2::coin::destroy_zero` for each coin
+    async fn prepare_destroy_zero(
+        &self,
+        coin_type: &str,
+        coins: &[Coin],
+    ) -> Result<PreparedTransaction, SuiError> {
+        let mut batch = self.batch();
+        for coin in coins {
+            batch.move_call(
+                "0x2",
+                "coin",
+                "destroy_zero",
+                vec![coin_type],
+                vec![BatchArg::object(&coin.coin_object_id)],
+            );
+        }
+        batch.prepare().await
+    }
+    /// resolve the input objects from a mutually consistent snapshot,
+    /// checking every object is owned by the wallet
+    async fn resolve_owned_objects(&self, object_ids: &[&str]) -> Result<Vec<Object>, SuiError> {
+        let objects = self.client.get_objects_consistent(object_ids).await?;
+        for object in &objects {
+            match &object.owner {
+                Some(Owner::AddressOwner(owner)) if owner == self.signer.address() => {}
+                owner => {
+                    return Err(SuiError::Transaction(format!(
+                        "Object {} is not owned by {} (owner: {:?})",
+                        object.object_id,
+                        self.signer.address(),
+                        owner
+                    )));
+                }
+            }
+        }
+        Ok(objects)
+    }
+    /// # Max sendable SUI
+    ///
+    /// Largest amount a SUI transfer can send. The amount is split off the single gas coin,
+    /// so this is the balance of that coin, the configured gas payment or else the largest
+    /// SUI coin, less the gas budget the transfer reserves: the fixed budget or, with auto
+    /// gas budget, the estimate of a sample transfer. Merge coins to send more.
+    ///
+    /// ## Returns
+    /// - Ok(u64) : amount in MIST, 0 when the gas coin doesn't cover the gas budget
+    /// - Err(SuiError) : error, `SuiError::Unsupported` with a gas sponsor, whose gas coin
+    ///   the amount would be split off
+    ///
+    /// ## Example
+    /// ```rust
+    /// use std::sync::Arc;
+    /// use sui_network_sdk::SuiClient;
+    /// use sui_network_sdk::trade::Trade;
+    /// use sui_network_sdk::transport::MockTransport;
+    /// use sui_network_sdk::types::{SuiClientConfig, SuiError};
+    /// use sui_network_sdk::wallet::Wallet;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let coin = |id: &str, balance: &str| serde_json::json!({
+    ///         "coinObjectId": id, "version": "3", "digest": "G1", "balance": balance
+    ///     });
+    ///     let mock = Arc::new(MockTransport::new().with_result("suix_getCoins", serde_json::json!({
+    ///         "data": [coin("0x9", "600000000"), coin("0xa", "400000000")],
+    ///         "nextCursor": null,
+    ///         "hasNextPage": false
+    ///     })));
+    ///     let client = SuiClient::new_with_transport(SuiClientConfig::default(), mock.clone());
+    ///     let wallet = Wallet::new().unwrap();
+    ///     let trade = Trade::new(&client, &wallet)
+    ///         .with_gas_payment("0x9".to_string())
+    ///         .with_gas_budget(3_000_000);
+    ///     // only the gas coin 0x9 is spent, not the 1 SUI of both coins
+    ///     assert_eq!(trade.max_sendable_sui().await.unwrap(), 597_000_000);
+    ///
+    ///     // more than the gas coin holds fails before anything is built
+    ///     match trade.transfer_by_sui("0xb0b", 700_000_000).await {
+    ///         Err(SuiError::Gas(e)) => assert!(
+    ///             e.contains(&format!("600000000 MIST available in gas coin 0x{:0>64}, 703000000 required", "9"))
+    ///                 && e.contains("at most 597000000"),
+    ///             "{}",
+    ///             e
+    ///         ),
+    ///         other => panic!("unexpected {:?}", other.err()),
+    ///     }
+    ///     assert_eq!(mock.request_count("unsafe_transferSui"), 0);
+    ///
+    ///     // without a gas payment the largest coin pays
+    ///     let trade = Trade::new(&client, &wallet).with_gas_budget(3_000_000);
+    ///     assert_eq!(trade.max_sendable_sui().await.unwrap(), 597_000_000);
+    ///     let mut batch = trade.batch();
+    ///     batch.transfer_sui("0xb0b", 597_000_000);
+    ///     batch.transfer_sui("0xca7", 1);
+    ///     assert!(matches!(batch.prepare().await, Err(SuiError::Gas(_))));
+    /// }
+    /// ```
+    pub async fn max_sendable_sui(&self) -> Result<u64, SuiError> {
+        let available = self.sui_gas_coin(&[]).await?.balance;
+        Ok(available.saturating_sub(self.sui_transfer_gas_budget().await?))
+    }
+    /// gas coin SUI transfers split off, the configured gas payment or the largest SUI
+    /// coin outside exclude
+    async fn sui_gas_coin(&self, exclude: &[&str]) -> Result<Coin, SuiError> {
+        if self.gas_sponsor.is_some() {
+            return Err(SuiError::Unsupported(
+                "SUI transfers split off the gas coin, which a gas sponsor owns".to_string(),
+            ));
+        }
+        let coins = self
+            .client
+            .get_coin_vec(self.signer.address(), None)
+            .await?;
+        let coin = match &self.gas_payment {
+            Some(gas_payment) => {
+                let gas_payment = normalize_address(gas_payment);
+                coins
+                    .into_iter()
+                    .find(|coin| normalize_address(&coin.coin_object_id) == gas_payment)
+            }
+            None => coins
+                .into_iter()
+                .filter(|coin| !exclude.contains(&normalize_address(&coin.coin_object_id).as_str()))
+                .max_by_key(|coin| coin.balance),
+        };
+        coin.ok_or_else(|| {
+            SuiError::Gas(format!(
+                "balance check: no SUI gas coin of {}{}",
+                self.signer.address(),
+                self.gas_payment
+                    .as_ref()
+                    .map(|id| format!(" with id {}", id))
+                    .unwrap_or_default()
+            ))
+        })
+    }
+    /// gas budget a SUI transfer reserves, with auto gas budget the estimate of a sample
+    /// transfer to the sender
+    async fn sui_transfer_gas_budget(&self) -> Result<u64, SuiError> {
+        if self.gas_sponsor.is_some() {
+            return Ok(0);
+        }
+        if self.gas_margin_percent.is_none() {
+            return Ok(self.gas_budget);
+        }
+        let mut sample = self.batch();
+        let coin = sample.builder.split_coins(Argument::GasCoin, &[1])?;
+        sample
+            .builder
+            .transfer_objects(coin, self.signer.address())?;
+        let prepared = Box::pin(sample.prepare()).await?;
+        Ok(TransactionData::from_bytes(prepared.tx_bytes())?.gas_budget())
+    }
+    /// gas coin for a transfer of amount, failing when it can't pay the amount and the gas
+    /// budget, run before signing
+    async fn check_sui_balance(&self, amount: u64, exclude: &[&str]) -> Result<Coin, SuiError> {
+        let coin = self.sui_gas_coin(exclude).await?;
+        let gas_budget = self.sui_transfer_gas_budget().await?;
+        let required = amount.saturating_add(gas_budget);
+        if coin.balance < required {
+            return Err(SuiError::Gas(format!(
+                "balance check: {} MIST available in gas coin {}, {} required to send {} with a gas budget of {}; at most {} can be sent, merge coins to send more",
+                coin.balance,
+                coin.coin_object_id,
+                required,
+                amount,
+                gas_budget,
+                coin.balance.saturating_sub(gas_budget)
+            )));
+        }
+        Ok(coin)
+    }
+    /// sanity checks on a transfer, run before any rpc
+    fn check_transfer(&self, recipient: &str, amount: u64) -> Result<(), SuiError> {
+        self.check_amount(amount)?;
+        self.check_recipient(recipient)
+    }
+    /// sanity checks on a transfer amount, run before any rpc
+    fn check_amount(&self, amount: u64) -> Result<(), SuiError> {
+        if amount == 0 && !self.allow_zero_amount {
+            return Err(SuiError::Transaction(
+                "zero amount check: transfer amount is 0, use allow_zero_amount() to permit it"
+                    .to_string(),
+            ));
+        }
+        Ok(())
+    }
+    /// address of a recipient given as an address or a SuiNS name
+    async fn resolve_recipient(&self, recipient: &str) -> Result<String, SuiError> {
+        if !recipient.ends_with(".sui") {
+            return Ok(recipient.to_string());
+        }
+        self.client
+            .resolve_name_service_address(recipient)
+            .await?
+            .ok_or_else(|| {
+                SuiError::Transaction(format!(
+                    "name check: {} is not registered or points at no address",
+                    recipient
+                ))
+            })
+    }
+    /// sanity checks on a recipient, run before any rpc
+    fn check_recipient(&self, recipient: &str) -> Result<(), SuiError> {
+        let recipient = normalize_address(recipient);
+        if recipient
+            .trim_start_matches("0x")
+            .bytes()
+            .all(|b| b == b'0')
+        {
+            return Err(SuiError::Transaction(format!(
+                "burn address check: recipient {} is the all zero address",
+                recipient
+            )));
+        }
+        if recipient == normalize_address(self.signer.address()) && !self.allow_self_transfer {
+            return Err(SuiError::Transaction(format!(
+                "self transfer check: recipient {} is the sender, use allow_self_transfer() to permit it",
+                recipient
+            )));
+        }
+        Ok(())
+    }
+    /// principal of a StakedSui object owned by the wallet
+    async fn get_stake_principal(&self, staked_sui_id: &str) -> Result<u64, SuiError> {
+        let object = self
+            .resolve_owned_objects(&[staked_sui_id])
+            .await?
+            .pop()
+            .ok_or_else(|| SuiError::Rpc(format!("Object {} not found", staked_sui_id)))?;
+        if !object.parsed_type().is_some_and(ObjectType::is_staked_sui) {
+            return Err(SuiError::Transaction(format!(
+                "Object {} is not a StakedSui (type: {:?})",
+                staked_sui_id, object.object_type
+            )));
+        }
+        u64_field(&object, "principal").ok_or_else(|| {
+            SuiError::Transaction(format!("No principal in StakedSui {}", staked_sui_id))
+        })
+    }
+    /// get gas payment, a coin covering the gas budget unless one was configured
+    async fn get_gas_payment(&self) -> Result<String, SuiError> {
+        if self.gas_sponsor.is_some() {
+            return Err(SuiError::Unsupported(
+                "gas sponsorship needs a locally built transaction, use Trade::batch".to_string(),
+            ));
+        }
         if let Some(ref gas_payment) = self.gas_payment {
-            return Some(gas_payment.clone());
+            return Ok(gas_payment.clone());
         }
-        match self.client.get_coin_vec(&self.wallet.address, None).await {
-            Ok(coins) => coins.first().map(|coin| coin.coin_object_id.clone()),
-            Err(_) => None,
+        self.client
+            .find_gas_coin(self.signer.address(), self.gas_budget, &[])
+            .await
+            .map(|coin| coin.coin_object_id)
+    }
+    /// build a transaction with an unsafe_ builder, at the fixed gas budget or, with auto
+    /// gas budget, at the estimate of a dry run
+    async fn build_transaction(
+        &self,
+        method: &str,
+        gas_payment: &str,
+        params: impl Fn(u64) -> Vec<Value>,
+    ) -> Result<Value, SuiError> {
+        if self.gas_margin_percent.is_none() {
+            return self.client.request(method, params(self.gas_budget)).await;
         }
+        let object = self.client.fetch_objects(&[gas_payment]).await?.remove(0);
+        let dry_run_budget = self.dry_run_budget(u64_field(&object, "balance")).await?;
+        let transaction_data: Value = self.client.request(method, params(dry_run_budget)).await?;
+        let gas_budget = self
+            .estimate_gas_budget(&Self::decode_tx_bytes(&transaction_data)?)
+            .await?;
+        self.client.request(method, params(gas_budget)).await
+    }
+    /// gas budget of a dry run, all the gas coin can pay up to the protocol limit
+    async fn dry_run_budget(&self, gas_coin_balance: Option<u64>) -> Result<u64, SuiError> {
+        let max_tx_gas = self.client.transaction_limits().await?.max_tx_gas;
+        Ok(gas_coin_balance.unwrap_or(self.gas_budget).min(max_tx_gas))
+    }
+    /// decode the transaction bytes of an unsafe_ builder response
+    fn decode_tx_bytes(transaction_data: &Value) -> Result<Vec<u8>, SuiError> {
+        let tx_bytes_str = transaction_data
+            .get("txBytes")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| SuiError::Transaction("No txBytes in response".to_string()))?;
+        Ok(BASE64_STANDARD.decode(tx_bytes_str)?)
     }
     /// sign transaction
     async fn sign_transaction(
         &self,
         transaction_data: Value,
     ) -> Result<(Vec<u8>, Vec<u8>), SuiError> {
-        let tx_bytes_str = transaction_data
-            .get("txBytes")
-            .and_then(|v| v.as_str())
-            .ok_or_else(|| SuiError::Transaction("No txBytes in response".to_string()))?;
-        let tx_bytes = BASE64_STANDARD
-            .decode(tx_bytes_str)
-            .map_err(|e| SuiError::Sign(format!("Failed to decode txBytes: {}", e)))?;
+        let tx_bytes = Self::decode_tx_bytes(&transaction_data)?;
+        self.client.transaction_limits().await?.check(&tx_bytes)?;
         // sign transaction
-        let signature = self.wallet.sign(&tx_bytes);
+        let signature = self.signer.sign_transaction(&tx_bytes).await?;
         Ok((tx_bytes, signature))
     }
 }
+
+/// number field of an object's content, a string or a JSON number
+fn u64_field(object: &Object, field: &str) -> Option<u64> {
+    match object.data.as_ref()?.fields.get(field)? {
+        Value::String(s) => s.parse().ok(),
+        value => value.as_u64(),
+    }
+}
+
+/// # Contract call
+///
+/// Move call rebuilt on each attempt of [`Trade::call_with_contention_retry`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct ContractCall<'c> {
+    pub package: &'c str,
+    pub module: &'c str,
+    pub function: &'c str,
+    pub type_arguments: Vec<&'c str>,
+    pub arguments: Vec<Value>,
+}
+
+/// # Contention retry outcome
+///
+/// # Fields
+/// - response : response of the last executed attempt
+/// - attempts : executed attempts, the first one included
+#[derive(Debug, Clone)]
+pub struct ContentionRetryOutcome {
+    pub response: TransactionResponse,
+    pub attempts: u32,
+}
+
+impl<'a> Trade<'a> {
+    /// # Call with contention retry
+    ///
+    /// Builds, signs and executes a Move call, retrying while it fails from contention on
+    /// a shared object: shared object congestion, or expiry before finality. Each retry
+    /// waits a jittered exponential backoff, then rebuilds the call so shared object and
+    /// gas coin versions are resolved again. Deterministic failures such as Move aborts
+    /// are returned after the first attempt.
+    ///
+    /// ## Parameters
+    /// - call : Move call
+    /// - policy : retries and backoff, see [`RetryPolicy::backoff`]
+    ///
+    /// ## Returns
+    /// - Ok(ContentionRetryOutcome) : last response, successful or not, and the attempt count
+    /// - Err(SuiError) : error of the last submission
+    ///
+    /// ## Example
+    /// ```rust
+    /// use std::sync::Arc;
+    /// use std::time::Duration;
+    /// use sui_network_sdk::SuiClient;
+    /// use sui_network_sdk::trade::{ContractCall, Trade};
+    /// use sui_network_sdk::transport::MockTransport;
+    /// use sui_network_sdk::types::{RetryPolicy, SuiClientConfig};
+    /// use sui_network_sdk::wallet::Wallet;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let effects = |status: &str, error: &str| serde_json::json!({ "digest": "A1", "effects": {
+    ///         "status": { "status": status, "error": error },
+    ///         "gasUsed": { "computationCost": "1", "storageCost": "1", "storageRebate": "0" },
+    ///         "transactionDigest": "A1"
+    ///     }});
+    ///     let abort = r#"MoveAbort(MoveLocation { module: ModuleId { address: 5a0b, name: Identifier("pool") }, function: 3, instruction: 12, function_name: Some("swap") }, 2) in command 0"#;
+    ///     let mock = Arc::new(MockTransport::new()
+    ///         .with_result("unsafe_moveCall", serde_json::json!({ "txBytes": "AAA=" }))
+    ///         .with_result("sui_executeTransactionBlock", effects("failure", "ExecutionCancelledDueToSharedObjectCongestion"))
+    ///         .with_result("sui_executeTransactionBlock", effects("success", ""))
+    ///         .with_result("sui_executeTransactionBlock", effects("failure", abort)));
+    ///     let client = SuiClient::new_with_transport(SuiClientConfig::default(), mock.clone());
+    ///     let wallet = Wallet::new().unwrap();
+    ///     let trade = Trade::new(&client, &wallet).with_gas_payment("0x9".to_string());
+    ///     let policy = RetryPolicy { max_retries: 3, base_delay: Duration::from_millis(1), max_delay: Duration::from_millis(10), jitter: Duration::ZERO };
+    ///     let call = ContractCall {
+    ///         package: "0x5a0b",
+    ///         module: "pool",
+    ///         function: "swap",
+    ///         type_arguments: vec![],
+    ///         arguments: vec!["0xb001".into()],
+    ///     };
+    ///
+    ///     // congestion is retried with a rebuilt transaction
+    ///     let outcome = trade.call_with_contention_retry(call.clone(), policy).await.unwrap();
+    ///     assert_eq!(outcome.attempts, 2);
+    ///     assert!(outcome.response.effects.unwrap().status.is_success());
+    ///     assert_eq!(mock.request_count("unsafe_moveCall"), 2);
+    ///
+    ///     // a Move abort is never retried
+    ///     let outcome = trade.call_with_contention_retry(call, policy).await.unwrap();
+    ///     assert_eq!(outcome.attempts, 1);
+    ///     assert_eq!(mock.request_count("unsafe_moveCall"), 3);
+    /// }
+    /// ```
+    pub async fn call_with_contention_retry(
+        &self,
+        call: ContractCall<'_>,
+        policy: RetryPolicy,
+    ) -> Result<ContentionRetryOutcome, SuiError> {
+        let mut attempt = 0;
+        loop {
+            let result = self.execute_contract_call(&call).await;
+            let class = match &result {
+                Ok(response) => response
+                    .effects
+                    .as_ref()
+                    .and_then(|effects| effects.status.failure_class()),
+                Err(e) => e.failure_class(),
+            };
+            if attempt >= policy.max_retries || !class.is_some_and(|c| c.is_contention()) {
+                return result.map(|response| ContentionRetryOutcome {
+                    response,
+                    attempts: attempt + 1,
+                });
+            }
+            // jitter keeps competing senders from retrying in lockstep
+            let backoff = policy.backoff(attempt);
+            let jitter = rng().random_range(0.5..=1.0);
+            tokio::time::sleep(backoff.mul_f64(jitter)).await;
+            attempt += 1;
+        }
+    }
+    /// build, sign and execute a Move call
+    async fn execute_contract_call(
+        &self,
+        call: &ContractCall<'_>,
+    ) -> Result<TransactionResponse, SuiError> {
+        let (tx_bytes, signature) = self
+            .call_contract_function(
+                call.package,
+                call.module,
+                call.function,
+                call.type_arguments.clone(),
+                call.arguments.clone(),
+            )
+            .await?;
+        self.submit(tx_bytes, signature).await
+    }
+}
+
+/// source of unique batch ids
+static NEXT_BATCH_ID: AtomicU64 = AtomicU64::new(1);
+/// shared system objects validators accept by immutable reference only, Clock and Random
+const IMMUTABLE_SHARED_OBJECTS: [&str; 2] = ["0x6", "0x8"];
+
+/// # Batch result
+///
+/// Handle of a command result in an [`AtomicBatch`], valid only in that batch.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct BatchResult {
+    batch: u64,
+    argument: Argument,
+}
+
+/// # Batch argument
+///
+/// Argument of an [`AtomicBatch`] command. Shared objects are passed mutably, except the
+/// Clock `0x6` and Random `0x8` objects validators accept by immutable reference only, and
+/// objects given as `ReadOnlyObject` so transactions only reading them aren't serialized
+/// behind their writers.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BatchArg {
+    /// the gas coin
+    Gas,
+    /// object by id, resolved when the batch is built
+    Object(String),
+    /// object by id, resolved when the batch is built, passed immutably when shared; an
+    /// object also given as `Object` in the batch is passed mutably
+    ReadOnlyObject(String),
+    /// BCS encoded pure value
+    Pure(Vec<u8>),
+    /// result of an earlier command of the batch
+    Result(BatchResult),
+}
+
+impl BatchArg {
+    /// BCS encoded pure value
+    pub fn pure<T: serde::Serialize>(value: &T) -> Result<Self, SuiError> {
+        Ok(BatchArg::Pure(bcs::to_bytes(value)?))
+    }
+    /// address value
+    pub fn address(address: &str) -> Result<Self, SuiError> {
+        Ok(BatchArg::Pure(address_bytes(address)?.to_vec()))
+    }
+    /// object by id
+    pub fn object(object_id: &str) -> Self {
+        BatchArg::Object(object_id.to_string())
+    }
+    /// object by id, passed immutably when shared
+    pub fn read_only_object(object_id: &str) -> Self {
+        BatchArg::ReadOnlyObject(object_id.to_string())
+    }
+}
+
+impl From<BatchResult> for BatchArg {
+    fn from(result: BatchResult) -> Self {
+        BatchArg::Result(result)
+    }
+}
+
+/// # Atomic batch
+///
+/// Independent operations composed into one programmable transaction, they succeed or
+/// fail together. Commands returning a [`BatchResult`] can feed later commands.
+/// Errors of individual commands are reported when the batch is built.
+///
+/// ## Example
+/// ```rust
+/// use std::sync::Arc;
+/// use sui_network_sdk::SuiClient;
+/// use sui_network_sdk::ptb::{Argument, Command};
+/// use sui_network_sdk::trade::{BatchArg, Trade};
+/// use sui_network_sdk::transport::MockTransport;
+/// use sui_network_sdk::types::SuiClientConfig;
+/// use sui_network_sdk::wallet::Wallet;
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let client = SuiClient::new_with_transport(SuiClientConfig::default(), Arc::new(MockTransport::new()));
+///     let wallet = Wallet::new().unwrap();
+///     let trade = Trade::new(&client, &wallet);
+///
+///     // split a coin off gas, pass it to a contract, then pay a vendor
+///     let mut batch = trade.batch();
+///     let coin = batch.split_coin(BatchArg::Gas, 1_000);
+///     let receipt = batch.move_call("0x5a0b", "shop", "buy", vec![], vec![coin.into()]);
+///     batch.transfer_object(receipt.into(), "0xa11ce").transfer_sui("0xb0b", 2_000);
+///     let pt = batch.programmable_transaction().await.unwrap();
+///     assert_eq!(pt.commands.len(), 5);
+///     assert_eq!(pt.commands[0], Command::SplitCoins(Argument::GasCoin, vec![Argument::Input(0)]));
+///     let Command::MoveCall(call) = &pt.commands[1] else { panic!() };
+///     assert_eq!(call.arguments, vec![Argument::NestedResult(0, 0)]);
+///     assert_eq!(pt.commands[2], Command::TransferObjects(vec![Argument::Result(1)], Argument::Input(1)));
+///
+///     // handles belong to their batch
+///     let mut other = trade.batch();
+///     other.transfer_object(coin.into(), "0xa11ce");
+///     assert!(other.programmable_transaction().await.is_err());
+///
+///     // at most 1024 commands, the limit when the node reports no protocol config
+///     let mut large = trade.batch();
+///     for _ in 0..1025 {
+///         large.split_coin(BatchArg::Gas, 1);
+///     }
+///     let error = large.programmable_transaction().await.unwrap_err().to_string();
+///     assert!(error.contains("1025 commands, at most 1024 are allowed; split off at least 1 commands"));
+/// }
+/// ```
+pub struct AtomicBatch<'t, 'a> {
+    trade: &'t Trade<'a>,
+    id: u64,
+    builder: ProgrammableTransactionBuilder,
+    objects: Vec<(String, Argument, bool)>, // id, input, passed mutably when shared
+    sui_amount: u64,
+    error: Option<SuiError>,
+}
+
+impl<'a> Trade<'a> {
+    /// start an atomic batch of operations
+    pub fn batch(&self) -> AtomicBatch<'_, 'a> {
+        AtomicBatch {
+            trade: self,
+            id: NEXT_BATCH_ID.fetch_add(1, Ordering::Relaxed),
+            builder: ProgrammableTransactionBuilder::new(),
+            objects: Vec::new(),
+            sui_amount: 0,
+            error: None,
+        }
+    }
+}
+
+impl<'t, 'a> AtomicBatch<'t, 'a> {
+    /// transfer SUI split off the gas coin, the gas coin is checked to cover the transfers
+    /// and the gas budget when the batch is prepared
+    pub fn transfer_sui(&mut self, recipient: &str, amount: u64) -> &mut Self {
+        if let Err(e) = self.trade.check_transfer(recipient, amount) {
+            self.fail(e);
+            return self;
+        }
+        self.sui_amount = self.sui_amount.saturating_add(amount);
+        let coin = self.split_coin(BatchArg::Gas, amount);
+        self.transfer_object(coin.into(), recipient)
+    }
+    /// split an amount off a coin, returns the new coin
+    pub fn split_coin(&mut self, coin: BatchArg, amount: u64) -> BatchResult {
+        let coin = self.argument(coin);
+        let coins = self.builder.split_coins(coin, &[amount]);
+        self.result(coins.map(|coins| coins[0]))
+    }
+    /// merge coins into the target coin
+    pub fn merge_coins(&mut self, target: BatchArg, coins: Vec<BatchArg>) -> &mut Self {
+        let target = self.argument(target);
+        let coins = coins.into_iter().map(|coin| self.argument(coin)).collect();
+        self.builder.merge_coins(target, coins);
+        self
+    }
+    /// call a Move function, returns its result
+    pub fn move_call(
+        &mut self,
+        package: &str,
+        module: &str,
+        function: &str,
+        type_arguments: Vec<&str>,
+        arguments: Vec<BatchArg>,
+    ) -> BatchResult {
+        let arguments = arguments
+            .into_iter()
+            .map(|argument| self.argument(argument))
+            .collect();
+        let result = type_arguments
+            .into_iter()
+            .map(str::parse)
+            .collect::<Result<Vec<TypeTag>, _>>()
+            .and_then(|type_arguments| {
+                self.builder
+                    .move_call(package, module, function, type_arguments, arguments)
+            });
+        self.result(result)
+    }
+    /// transfer an object to a recipient
+    pub fn transfer_object(&mut self, object: BatchArg, recipient: &str) -> &mut Self {
+        if let Err(e) = self.trade.check_recipient(recipient) {
+            self.fail(e);
+            return self;
+        }
+        let object = self.argument(object);
+        if let Err(e) = self.builder.transfer_objects(vec![object], recipient) {
+            self.fail(e);
+        }
+        self
+    }
+    /// number of commands so far
+    pub fn command_count(&self) -> usize {
+        self.builder.command_count()
+    }
+    /// # Programmable transaction
+    ///
+    /// Validates the batch and resolves its object inputs, read as a consistent snapshot
+    /// with [`SuiClient::get_objects_consistent`].
+    ///
+    /// ## Returns
+    /// - Ok(ProgrammableTransaction) : the batch as one programmable transaction
+    /// - Err(SuiError) : the first command error, a foreign result handle, more commands
+    ///   than the node's limit, an object that can't be read or keeps changing
+    ///
+    /// ## Example
+    /// ```rust
+    /// use std::sync::Arc;
+    /// use sui_network_sdk::SuiClient;
+    /// use sui_network_sdk::ptb::{CallArg, ObjectArg, ObjectDigest};
+    /// use sui_network_sdk::trade::{BatchArg, Trade};
+    /// use sui_network_sdk::transport::MockTransport;
+    /// use sui_network_sdk::types::SuiClientConfig;
+    /// use sui_network_sdk::wallet::Wallet;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let digest = ObjectDigest([7; 32]).to_string();
+    ///     let object = |id: &str, version: &str| serde_json::json!({ "data": {
+    ///         "objectId": id, "version": version, "digest": digest
+    ///     }});
+    ///     // 0xa is written between the reads of the batch, the second read of it agrees
+    ///     let mock = Arc::new(MockTransport::new());
+    ///     mock.push_result("sui_multiGetObjects", serde_json::json!([object("0xa", "3"), object("0xb", "5")]));
+    ///     mock.push_result("sui_multiGetObjects", serde_json::json!([object("0xa", "4"), object("0xb", "5")]));
+    ///     mock.push_result("sui_multiGetObjects", serde_json::json!([object("0xa", "4")]));
+    ///     let client = SuiClient::new_with_transport(SuiClientConfig::default(), mock.clone());
+    ///     let wallet = Wallet::new().unwrap();
+    ///     let trade = Trade::new(&client, &wallet);
+    ///     let mut batch = trade.batch();
+    ///     let a = BatchArg::Object("0xa".to_string());
+    ///     let b = BatchArg::Object("0xb".to_string());
+    ///     batch.move_call("0x5a0b", "pool", "swap", vec![], vec![a, b]);
+    ///     let pt = batch.programmable_transaction().await.unwrap();
+    ///     let versions: Vec<u64> = pt
+    ///         .inputs
+    ///         .iter()
+    ///         .map(|input| match input {
+    ///             CallArg::Object(ObjectArg::ImmOrOwnedObject((_, version, _))) => *version,
+    ///             other => panic!("unexpected {:?}", other),
+    ///         })
+    ///         .collect();
+    ///     assert_eq!(versions, vec![4, 5]);
+    ///     assert_eq!(mock.request_count("sui_multiGetObjects"), 3);
+    ///
+    ///     // the Clock and objects given read-only are passed immutably
+    ///     let shared = |id: &str| serde_json::json!({ "data": {
+    ///         "objectId": id, "version": "9", "digest": digest,
+    ///         "owner": { "Shared": { "initial_shared_version": 1 } }
+    ///     }});
+    ///     let mock = Arc::new(MockTransport::new().with_result(
+    ///         "sui_multiGetObjects",
+    ///         serde_json::json!([shared("0x6"), shared("0x0a"), shared("0x0b")]),
+    ///     ));
+    ///     let client = SuiClient::new_with_transport(SuiClientConfig::default(), mock);
+    ///     let trade = Trade::new(&client, &wallet);
+    ///     let mut batch = trade.batch();
+    ///     let args = vec![
+    ///         BatchArg::object("0x6"),
+    ///         BatchArg::read_only_object("0x0a"),
+    ///         BatchArg::object("0x0b"),
+    ///     ];
+    ///     batch.move_call("0x5a0b", "pool", "swap", vec![], args);
+    ///     let pt = batch.programmable_transaction().await.unwrap();
+    ///     let mutability: Vec<bool> = pt
+    ///         .inputs
+    ///         .iter()
+    ///         .map(|input| match input {
+    ///             CallArg::Object(ObjectArg::SharedObject { mutable, .. }) => *mutable,
+    ///             other => panic!("unexpected {:?}", other),
+    ///         })
+    ///         .collect();
+    ///     assert_eq!(mutability, vec![false, false, true]);
+    /// }
+    /// ```
+    pub async fn programmable_transaction(self) -> Result<ProgrammableTransaction, SuiError> {
+        if let Some(error) = self.error {
+            return Err(error);
+        }
+        let max_commands = self.trade.client.transaction_limits().await?.max_commands;
+        let commands = self.builder.command_count() as u64;
+        if commands > max_commands {
+            return Err(SuiError::Transaction(format!(
+                "command limit check: batch has {} commands, at most {} are allowed; split off at least {} commands",
+                commands,
+                max_commands,
+                commands - max_commands
+            )));
+        }
+        let mut pt = self.builder.finish();
+        if !self.objects.is_empty() {
+            let ids: Vec<&str> = self.objects.iter().map(|(id, ..)| id.as_str()).collect();
+            let objects = self.trade.client.get_objects_consistent(&ids).await?;
+            for ((_, input, mutable), object) in self.objects.iter().zip(&objects) {
+                if let Argument::Input(index) = input {
+                    pt.inputs[*index as usize] =
+                        CallArg::Object(ObjectArg::from_object(object, *mutable)?);
+                }
+            }
+        }
+        Ok(pt)
+    }
+    /// # Build
+    ///
+    /// With a gas sponsor the sponsor signature is attached by [`AtomicBatch::execute`]
+    /// only.
+    ///
+    /// ## Returns
+    /// - Ok((Vec<u8>, Vec<u8>)) : transaction bytes and signature
+    /// - Err(SuiError) : error
+    pub async fn build(self) -> Result<(Vec<u8>, Vec<u8>), SuiError> {
+        let signer = self.trade.signer;
+        let prepared = self.prepare().await?;
+        let signature = signer.sign_transaction(&prepared.tx_bytes).await?;
+        Ok((prepared.tx_bytes, signature))
+    }
+    /// # Prepare
+    ///
+    /// Builds the batch without signing it, for review of its [`PreparedTransaction::plan`]
+    /// before [`Trade::execute_prepared`]. A gas sponsor signs at this point.
+    ///
+    /// ## Returns
+    /// - Ok(PreparedTransaction) : the transaction bytes to be signed
+    /// - Err(SuiError) : error
+    pub async fn prepare(self) -> Result<PreparedTransaction, SuiError> {
+        let trade = self.trade;
+        let object_ids: Vec<String> = self.objects.iter().map(|(id, ..)| id.clone()).collect();
+        let sui_amount = self.sui_amount;
+        let pt = self.programmable_transaction().await?;
+        if let Some(gas_sponsor) = &trade.gas_sponsor {
+            let unsigned = UnsignedTransaction {
+                sender: trade.signer.address().to_string(),
+                kind: pt,
+                gas_budget: trade.gas_budget,
+            };
+            let sponsored = gas_sponsor.sponsor(&unsigned).await?;
+            let tx_bytes = unsigned.with_gas(&sponsored.gas_data)?.to_bytes()?;
+            trade.client.transaction_limits().await?.check(&tx_bytes)?;
+            return Ok(PreparedTransaction {
+                tx_bytes,
+                sponsor_signature: Some(sponsored.sponsor_signature),
+            });
+        }
+        let exclude: Vec<&str> = object_ids.iter().map(String::as_str).collect();
+        let (gas_coin, gas_coin_balance) = match &trade.gas_payment {
+            // the transfers are split off the gas coin, which must cover them and the gas
+            _ if sui_amount > 0 => {
+                let coin = trade.check_sui_balance(sui_amount, &exclude).await?;
+                let gas_coin = ObjectRef {
+                    object_id: coin.coin_object_id,
+                    version: coin.version,
+                    digest: coin.digest,
+                };
+                (gas_coin, Some(coin.balance))
+            }
+            Some(gas_payment) => {
+                let object = trade
+                    .client
+                    .fetch_objects(&[gas_payment.as_str()])
+                    .await?
+                    .remove(0);
+                let balance = u64_field(&object, "balance");
+                let gas_coin = ObjectRef {
+                    object_id: object.object_id,
+                    version: object.version,
+                    digest: object.digest,
+                };
+                (gas_coin, balance)
+            }
+            None => {
+                let coin = trade
+                    .client
+                    .find_gas_coin(trade.signer.address(), trade.gas_budget, &exclude)
+                    .await?;
+                let gas_coin = ObjectRef {
+                    object_id: coin.coin_object_id,
+                    version: coin.version,
+                    digest: coin.digest,
+                };
+                (gas_coin, Some(coin.balance))
+            }
+        };
+        let gas_price = trade.reference_gas_price().await?;
+        let build = |gas_budget| -> Result<Vec<u8>, SuiError> {
+            TransactionData::new_programmable(
+                trade.signer.address(),
+                vec![object_reference(&gas_coin)?],
+                pt.clone(),
+                gas_budget,
+                gas_price,
+            )?
+            .to_bytes()
+        };
+        let gas_budget = match trade.gas_margin_percent {
+            Some(_) => {
+                let dry_run_budget = trade.dry_run_budget(gas_coin_balance).await?;
+                trade.estimate_gas_budget(&build(dry_run_budget)?).await?
+            }
+            None => trade.gas_budget,
+        };
+        let tx_bytes = build(gas_budget)?;
+        trade.client.transaction_limits().await?.check(&tx_bytes)?;
+        Ok(PreparedTransaction::new(tx_bytes))
+    }
+    /// # Execute
+    ///
+    /// Builds, signs and executes the batch.
+    ///
+    /// ## Returns
+    /// - Ok(TransactionResponse) : execution result
+    /// - Err(SuiError) : error
+    pub async fn execute(self) -> Result<TransactionResponse, SuiError> {
+        let trade = self.trade;
+        trade.execute_prepared(self.prepare().await?).await
+    }
+    /// keep the first error
+    fn fail(&mut self, error: SuiError) {
+        self.error.get_or_insert(error);
+    }
+    /// handle of a command result
+    fn result(&mut self, argument: Result<Argument, SuiError>) -> BatchResult {
+        let argument = argument.unwrap_or_else(|e| {
+            self.fail(e);
+            Argument::GasCoin
+        });
+        BatchResult {
+            batch: self.id,
+            argument,
+        }
+    }
+    /// input of an object, added once per batch, mutable when any use is
+    fn object_input(&mut self, id: String, mutable: bool) -> Argument {
+        if let Some((_, input, object_mutable)) =
+            self.objects.iter_mut().find(|(object, ..)| *object == id)
+        {
+            *object_mutable |= mutable;
+            return *input;
+        }
+        // placeholder input until the object is resolved
+        let index = self.builder.input_count();
+        self.builder
+            .input(CallArg::Pure(format!("object {}", id).into_bytes()));
+        let input = Argument::Input(index as u16);
+        self.objects.push((id, input, mutable));
+        input
+    }
+    /// builder argument of a batch argument
+    fn argument(&mut self, argument: BatchArg) -> Argument {
+        match argument {
+            BatchArg::Gas => Argument::GasCoin,
+            BatchArg::Pure(bytes) => self.builder.input(CallArg::Pure(bytes)),
+            BatchArg::Object(id) => {
+                let id = normalize_address(&id);
+                let mutable = !IMMUTABLE_SHARED_OBJECTS
+                    .iter()
+                    .any(|object| normalize_address(object) == id);
+                self.object_input(id, mutable)
+            }
+            BatchArg::ReadOnlyObject(id) => self.object_input(normalize_address(&id), false),
+            BatchArg::Result(result) if result.batch != self.id => {
+                self.fail(SuiError::Transaction(format!(
+                    "result handle of batch {} used in batch {}",
+                    result.batch, self.id
+                )));
+                Argument::GasCoin
+            }
+            BatchArg::Result(result) => result.argument,
+        }
+    }
+}
+
+/// # Prepared transaction
+///
+/// Transaction bytes awaiting approval. The plan is decoded from the bytes themselves,
+/// not from the builder inputs, so a reviewer sees exactly what
+/// [`Trade::execute_prepared`] signs.
+///
+/// ## Example
+/// ```rust
+/// use std::sync::Arc;
+/// use sui_network_sdk::SuiClient;
+/// use sui_network_sdk::ptb::{ObjectDigest, transaction_digest};
+/// use sui_network_sdk::trade::{BatchArg, PreparedTransaction, Trade};
+/// use sui_network_sdk::transport::MockTransport;
+/// use sui_network_sdk::types::SuiClientConfig;
+/// use sui_network_sdk::wallet::Wallet;
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let gas_digest = ObjectDigest([7; 32]).to_string();
+///     let mock = MockTransport::new()
+///         .with_result("suix_getCoins", serde_json::json!({
+///             "data": [{ "coinObjectId": "0x9a5", "version": "12", "digest": gas_digest, "balance": "5000000000" }],
+///             "nextCursor": null,
+///             "hasNextPage": false
+///         }))
+///         .with_result("suix_getReferenceGasPrice", serde_json::json!("750"));
+///     let client = SuiClient::new_with_transport(SuiClientConfig::default(), Arc::new(mock));
+///     let wallet = Wallet::new().unwrap();
+///     let trade = Trade::new(&client, &wallet);
+///
+///     let mut batch = trade.batch();
+///     batch.transfer_sui("0xabc", 1_500_000_000);
+///     let amount = BatchArg::pure(&3u64).unwrap();
+///     batch.move_call("0x5a0b", "market", "buy", vec![], vec![BatchArg::Gas, amount.clone(), amount]);
+///     let prepared = batch.prepare().await.unwrap();
+///
+///     let plan = prepared.plan().unwrap();
+///     assert_eq!(plan.sender, wallet.address);
+///     assert_eq!(plan.gas.payment[0].object_id, format!("0x{:0>64}", "9a5"));
+///     assert_eq!(plan.gas.payment[0].digest, gas_digest);
+///     assert_eq!((plan.gas.budget, plan.gas.price), (1000, 750));
+///     assert_eq!(plan.expiration, None);
+///     assert_eq!(plan.commands, vec![
+///         "Split 1.5 SUI off the gas coin".to_string(),
+///         format!("Transfer 1.5 SUI to 0x{:0>64}", "abc"),
+///         format!("Call 0x{:0>64}::market::buy with 3 args", "5a0b"),
+///     ]);
+///     assert_eq!(plan.digest, transaction_digest(prepared.tx_bytes()));
+///
+///     // bytes of any origin, e.g. an `unsafe_` builder, are planned the same way
+///     let same = PreparedTransaction::new(prepared.tx_bytes().to_vec());
+///     assert_eq!(same.plan().unwrap(), plan);
+///     assert!(PreparedTransaction::new(vec![1, 2, 3]).plan().is_err());
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PreparedTransaction {
+    tx_bytes: Vec<u8>,
+    sponsor_signature: Option<String>,
+}
+
+impl PreparedTransaction {
+    /// prepared transaction of BCS transaction bytes
+    pub fn new(tx_bytes: Vec<u8>) -> Self {
+        Self {
+            tx_bytes,
+            sponsor_signature: None,
+        }
+    }
+    /// the bytes to be signed
+    pub fn tx_bytes(&self) -> &[u8] {
+        &self.tx_bytes
+    }
+    /// # Plan
+    ///
+    /// ## Returns
+    /// - Ok(TransactionPlan) : sender, gas, expiration, command descriptions and digest
+    /// - Err(SuiError) : the bytes are not a programmable transaction
+    pub fn plan(&self) -> Result<TransactionPlan, SuiError> {
+        let data = TransactionData::from_bytes(&self.tx_bytes)?;
+        Ok(TransactionPlan {
+            digest: transaction_digest(&self.tx_bytes),
+            sender: data.sender(),
+            gas: GasPlan {
+                payment: data.gas_payment(),
+                owner: data.gas_owner(),
+                budget: data.gas_budget(),
+                price: data.gas_price(),
+            },
+            expiration: data.expiration_epoch(),
+            commands: data.programmable_transaction().describe(),
+        })
+    }
+}
+
+impl<'a> Trade<'a> {
+    /// # Execute prepared
+    ///
+    /// Signs and executes a prepared transaction, unchanged since its plan was read.
+    ///
+    /// ## Parameters
+    /// - prepared : transaction of this trade's signer
+    ///
+    /// ## Returns
+    /// - Ok(TransactionResponse) : execution result
+    /// - Err(SuiError) : the transaction has another sender, or signing or execution failed
+    pub async fn execute_prepared(
+        &self,
+        prepared: PreparedTransaction,
+    ) -> Result<TransactionResponse, SuiError> {
+        let sender = TransactionData::from_bytes(&prepared.tx_bytes)?.sender();
+        if sender != normalize_address(self.signer.address()) {
+            return Err(SuiError::Transaction(format!(
+                "prepared transaction of {} can't be signed by {}",
+                sender,
+                self.signer.address()
+            )));
+        }
+        let signature = self.signer.sign_transaction(&prepared.tx_bytes).await?;
+        let signatures = std::iter::once(serialized_signature(
+            self.signer.scheme(),
+            &signature,
+            self.signer.public_key(),
+        ))
+        .chain(prepared.sponsor_signature)
+        .collect();
+        self.submit_signatures(prepared.tx_bytes, signatures).await
+    }
+
+    /// execute a transaction signed by the signer alone
+    async fn submit(
+        &self,
+        tx_bytes: Vec<u8>,
+        signature: Vec<u8>,
+    ) -> Result<TransactionResponse, SuiError> {
+        let signature =
+            serialized_signature(self.signer.scheme(), &signature, self.signer.public_key());
+        self.submit_signatures(tx_bytes, vec![signature]).await
+    }
+
+    /// execute a signed transaction, verifying the signatures first when asked to
+    async fn submit_signatures(
+        &self,
+        tx_bytes: Vec<u8>,
+        signatures: Vec<String>,
+    ) -> Result<TransactionResponse, SuiError> {
+        if self.verify_before_submit {
+            verify_transaction_signatures(&tx_bytes, &signatures)?;
+        }
+        self.client
+            .execute_transaction_signatures(tx_bytes, signatures)
+            .await
+    }
+
+    /// # Execute and confirm
+    ///
+    /// Executes a prepared transaction, then optionally waits until the node shows the
+    /// objects the effects transferred to the transaction's recipients owned by them, see
+    /// [`SuiClient::wait_for_object_owned_by`]. Recipients are read from the transfer
+    /// commands of the signed bytes.
+    ///
+    /// ## Parameters
+    /// - prepared : transaction of this trade's signer
+    /// - ownership_timeout : longest wait for the transferred objects, no check when `None`
+    ///
+    /// ## Returns
+    /// - Ok((TransactionResponse, ConsistencyReport)) : execution result and the transferred
+    ///   objects the node confirmed or not, an empty report without a check or on failure
+    /// - Err(SuiError) : error
+    ///
+    /// ## Example
+    /// ```rust
+    /// use std::sync::Arc;
+    /// use std::time::Duration;
+    /// use sui_network_sdk::SuiClient;
+    /// use sui_network_sdk::ptb::ObjectDigest;
+    /// use sui_network_sdk::trade::Trade;
+    /// use sui_network_sdk::transport::MockTransport;
+    /// use sui_network_sdk::types::SuiClientConfig;
+    /// use sui_network_sdk::wallet::Wallet;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let wallet = Wallet::new().unwrap();
+    ///     let digest = ObjectDigest([7; 32]).to_string();
+    ///     let owned = |id: &str, owner: &str| serde_json::json!({
+    ///         "owner": { "AddressOwner": owner },
+    ///         "reference": { "objectId": id, "version": "8", "digest": digest }
+    ///     });
+    ///     let mock = MockTransport::new()
+    ///         .with_result("suix_getCoins", serde_json::json!({
+    ///             "data": [{ "coinObjectId": "0x9a5", "version": "7", "digest": digest, "balance": "5000000000" }],
+    ///             "nextCursor": null,
+    ///             "hasNextPage": false
+    ///         }))
+    ///         .with_result("suix_getReferenceGasPrice", serde_json::json!("750"))
+    ///         .with_result("sui_executeTransactionBlock", serde_json::json!({
+    ///             "digest": "TX1",
+    ///             "effects": {
+    ///                 "status": { "status": "success" },
+    ///                 "gasUsed": { "computationCost": "1", "storageCost": "1", "storageRebate": "0" },
+    ///                 "transactionDigest": "TX1",
+    ///                 "created": [owned("0xc01", "0xb0b")],
+    ///                 "mutated": [owned("0x9a5", &wallet.address)]
+    ///             }
+    ///         }))
+    ///         // the recipient's node trails execution by one read
+    ///         .with_result("sui_getObject", serde_json::json!({ "error": { "code": "notExists" } }))
+    ///         .with_result("sui_getObject", serde_json::json!({ "data": {
+    ///             "objectId": "0xc01", "version": "8", "digest": digest, "owner": { "AddressOwner": "0xb0b" }
+    ///         }}));
+    ///     let client = SuiClient::new_with_transport(SuiClientConfig::default(), Arc::new(mock));
+    ///     let trade = Trade::new(&client, &wallet);
+    ///
+    ///     let mut batch = trade.batch();
+    ///     batch.transfer_sui("0xb0b", 1_000);
+    ///     let prepared = batch.prepare().await.unwrap();
+    ///     let (response, report) = trade
+    ///         .execute_and_confirm(prepared, Some(Duration::from_secs(5)))
+    ///         .await
+    ///         .unwrap();
+    ///     assert_eq!(response.digest, "TX1");
+    ///     assert!(report.is_consistent());
+    ///     // the gas coin stays with the sender and is not checked
+    ///     assert_eq!(report.confirmed.len(), 1);
+    ///     assert_eq!(report.confirmed[0].recipient, format!("0x{:0>64}", "b0b"));
+    /// }
+    /// ```
+    pub async fn execute_and_confirm(
+        &self,
+        prepared: PreparedTransaction,
+        ownership_timeout: Option<Duration>,
+    ) -> Result<(TransactionResponse, ConsistencyReport), SuiError> {
+        let recipients = TransactionData::from_bytes(&prepared.tx_bytes)?
+            .programmable_transaction()
+            .transfer_recipients();
+        let response = self.execute_prepared(prepared).await?;
+        let mut report = ConsistencyReport::default();
+        let (Some(timeout), Some(effects)) = (ownership_timeout, &response.effects) else {
+            return Ok((response, report));
+        };
+        if !effects.status.is_success() {
+            return Ok((response, report));
+        }
+        let transferred: Vec<TransferredObject> = effects
+            .created
+            .iter()
+            .chain(&effects.mutated)
+            .filter_map(|object| match &object.owner {
+                Owner::AddressOwner(owner) if recipients.contains(owner) => {
+                    Some(TransferredObject {
+                        object_id: object.reference.object_id.clone(),
+                        recipient: owner.clone(),
+                    })
+                }
+                _ => None,
+            })
+            .collect();
+        let owned = futures::future::try_join_all(transferred.iter().map(|object| {
+            self.client
+                .wait_for_object_owned_by(&object.object_id, &object.recipient, timeout)
+        }))
+        .await?;
+        for (object, owned) in transferred.into_iter().zip(owned) {
+            if owned {
+                report.confirmed.push(object);
+            } else {
+                report.unconfirmed.push(object);
+            }
+        }
+        Ok((response, report))
+    }
+}
+
+/// # Submission outcome
+///
+/// What became of a transaction whose submission gave no answer, see
+/// [`Trade::resolve_ambiguous_submission`].
+#[derive(Debug, Clone)]
+pub enum SubmissionOutcome {
+    /// the transaction executed
+    Landed(Box<TransactionResponse>),
+    /// the transaction did not execute within the window, a self-transfer of its gas coins
+    /// executed instead and consumed their versions, so it never will
+    Superseded { replacement_digest: String },
+    /// a gas coin of the transaction was changed or deleted by another transaction, so it
+    /// never will execute
+    Invalidated { gas_coin: String },
+}
+
+impl<'a> Trade<'a> {
+    /// # Resolve ambiguous submission
+    ///
+    /// Settles a transaction whose execution request timed out or failed without an answer.
+    /// The digest is looked up until the window ends. If the transaction is still unknown
+    /// and its gas coins are at the versions it pays with, a transaction transferring the
+    /// gas coins back to the sender is executed at the same versions, which makes the
+    /// original bytes invalid. When the replacement fails, the original may have executed
+    /// in the meantime and is looked up once more.
+    ///
+    /// Submitting the replacement while validators still hold the original can lock the gas
+    /// coins until the end of the epoch, pick a window longer than the node takes to settle
+    /// a transaction.
+    ///
+    /// ## Parameters
+    /// - prepared : transaction of this trade's signer, paying its own gas
+    /// - window : how long the digest is looked up before the transaction is superseded
+    ///
+    /// ## Returns
+    /// - Ok(SubmissionOutcome) : the transaction landed, was superseded or was invalidated
+    /// - Err(SuiError::Unsupported) : the transaction is sponsored, its gas can't be spent
+    /// - Err(SuiError) : error, e.g. of the replacement when the original did not land
+    ///
+    /// ## Example
+    /// ```rust
+    /// use std::sync::Arc;
+    /// use std::time::Duration;
+    /// use sui_network_sdk::SuiClient;
+    /// use sui_network_sdk::ptb::{Argument, Command, ObjectDigest, TransactionData, transaction_digest};
+    /// use sui_network_sdk::trade::{SubmissionOutcome, Trade};
+    /// use sui_network_sdk::transport::MockTransport;
+    /// use sui_network_sdk::types::SuiClientConfig;
+    /// use sui_network_sdk::wallet::Wallet;
+    /// use base64::Engine;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let wallet = Wallet::new().unwrap();
+    ///     let digest = ObjectDigest([7; 32]).to_string();
+    ///     let gas = serde_json::json!({ "coinObjectId": "0x9a5", "version": "7", "digest": digest, "balance": "5000000000" });
+    ///     let prepare = |mock: MockTransport| {
+    ///         let mock = mock
+    ///             .with_result("suix_getCoins", serde_json::json!({ "data": [gas], "nextCursor": null, "hasNextPage": false }))
+    ///             .with_result("suix_getReferenceGasPrice", serde_json::json!("750"));
+    ///         Arc::new(mock)
+    ///     };
+    ///     let not_found = |mock: MockTransport| {
+    ///         mock.with_error("sui_getTransactionBlock", -32602, "Could not find the referenced transaction")
+    ///     };
+    ///
+    ///     // the transaction shows up on the second lookup
+    ///     let mock = prepare(not_found(MockTransport::new())
+    ///         .with_result("sui_getTransactionBlock", serde_json::json!({ "digest": "TX1" })));
+    ///     let client = SuiClient::new_with_transport(SuiClientConfig::default(), mock.clone());
+    ///     let trade = Trade::new(&client, &wallet);
+    ///     let mut batch = trade.batch();
+    ///     batch.transfer_sui("0xb0b", 1_000);
+    ///     let prepared = batch.prepare().await.unwrap();
+    ///     match trade.resolve_ambiguous_submission(&prepared, Duration::from_secs(5)).await.unwrap() {
+    ///         SubmissionOutcome::Landed(response) => assert_eq!(response.digest, "TX1"),
+    ///         other => panic!("unexpected {:?}", other),
+    ///     }
+    ///     assert_eq!(mock.request_count("sui_executeTransactionBlock"), 0);
+    ///
+    ///     // the transaction never shows up, its gas coin is still at version 7
+    ///     let mock = prepare(not_found(MockTransport::new())
+    ///         .with_result("sui_multiGetObjects", serde_json::json!([{ "data": {
+    ///             "objectId": "0x9a5", "version": "7", "digest": digest
+    ///         }}]))
+    ///         .with_result("sui_executeTransactionBlock", serde_json::json!({ "digest": "TX2" })));
+    ///     let client = SuiClient::new_with_transport(SuiClientConfig::default(), mock.clone());
+    ///     let trade = Trade::new(&client, &wallet);
+    ///     let mut batch = trade.batch();
+    ///     batch.transfer_sui("0xb0b", 1_000);
+    ///     let prepared = batch.prepare().await.unwrap();
+    ///     match trade.resolve_ambiguous_submission(&prepared, Duration::from_millis(50)).await.unwrap() {
+    ///         SubmissionOutcome::Superseded { replacement_digest } => assert_eq!(replacement_digest, "TX2"),
+    ///         other => panic!("unexpected {:?}", other),
+    ///     }
+    ///     let requests = mock.requests();
+    ///     let lookup = requests.iter().find(|r| r.method == "sui_getTransactionBlock").unwrap();
+    ///     assert_eq!(lookup.params[0], transaction_digest(prepared.tx_bytes()));
+    ///     let execution = requests.iter().find(|r| r.method == "sui_executeTransactionBlock").unwrap();
+    ///     let bytes = base64::prelude::BASE64_STANDARD
+    ///         .decode(execution.params[0].as_str().unwrap())
+    ///         .unwrap();
+    ///     let replacement = TransactionData::from_bytes(&bytes).unwrap();
+    ///     let original = TransactionData::from_bytes(prepared.tx_bytes()).unwrap();
+    ///     assert_eq!(replacement.gas_payment(), original.gas_payment());
+    ///     assert!(matches!(
+    ///         &replacement.programmable_transaction().commands[..],
+    ///         [Command::TransferObjects(objects, _)] if objects == &[Argument::GasCoin]
+    ///     ));
+    ///
+    ///     // the gas coin moved on to version 8 without the transaction
+    ///     let mock = prepare(not_found(MockTransport::new())
+    ///         .with_result("sui_multiGetObjects", serde_json::json!([{ "data": {
+    ///             "objectId": "0x9a5", "version": "8", "digest": digest
+    ///         }}])));
+    ///     let client = SuiClient::new_with_transport(SuiClientConfig::default(), mock.clone());
+    ///     let trade = Trade::new(&client, &wallet);
+    ///     let mut batch = trade.batch();
+    ///     batch.transfer_sui("0xb0b", 1_000);
+    ///     let prepared = batch.prepare().await.unwrap();
+    ///     assert!(matches!(
+    ///         trade.resolve_ambiguous_submission(&prepared, Duration::ZERO).await.unwrap(),
+    ///         SubmissionOutcome::Invalidated { .. }
+    ///     ));
+    ///     assert_eq!(mock.request_count("sui_executeTransactionBlock"), 0);
+    /// }
+    /// ```
+    pub async fn resolve_ambiguous_submission(
+        &self,
+        prepared: &PreparedTransaction,
+        window: Duration,
+    ) -> Result<SubmissionOutcome, SuiError> {
+        let data = TransactionData::from_bytes(&prepared.tx_bytes)?;
+        let sender = data.sender();
+        if sender != normalize_address(self.signer.address()) {
+            return Err(SuiError::Transaction(format!(
+                "prepared transaction of {} can't be resolved by {}",
+                sender,
+                self.signer.address()
+            )));
+        }
+        if data.gas_owner() != sender {
+            return Err(SuiError::Unsupported(
+                "sponsored transactions can't be superseded by the sender".to_string(),
+            ));
+        }
+        let digest = transaction_digest(&prepared.tx_bytes);
+        let deadline = tokio::time::Instant::now() + window;
+        loop {
+            if let Some(response) = self.find_transaction(&digest).await? {
+                return Ok(SubmissionOutcome::Landed(Box::new(response)));
+            }
+            let now = tokio::time::Instant::now();
+            if now >= deadline {
+                break;
+            }
+            tokio::time::sleep((deadline - now).min(SUBMISSION_POLL_INTERVAL)).await;
+        }
+        let payment = data.gas_payment();
+        let ids: Vec<&str> = payment.iter().map(|coin| coin.object_id.as_str()).collect();
+        let current = self
+            .client
+            .multi_get_objects(&ids, Default::default())
+            .await;
+        for (coin, object) in payment.iter().zip(current) {
+            let moved = match object {
+                Ok(object) => object.version != coin.version,
+                Err(e) if e.is_not_found() => true,
+                Err(e) => return Err(e),
+            };
+            if moved {
+                // the transaction itself may have moved it and only now be visible
+                return Ok(match self.find_transaction(&digest).await? {
+                    Some(response) => SubmissionOutcome::Landed(Box::new(response)),
+                    None => SubmissionOutcome::Invalidated {
+                        gas_coin: coin.object_id.clone(),
+                    },
+                });
+            }
+        }
+        let mut builder = ProgrammableTransactionBuilder::new();
+        builder.transfer_objects(vec![Argument::GasCoin], &sender)?;
+        let gas_price = data.gas_price().max(self.reference_gas_price().await?);
+        let replacement = TransactionData::new_programmable(
+            &sender,
+            payment
+                .iter()
+                .map(object_reference)
+                .collect::<Result<_, _>>()?,
+            builder.finish(),
+            data.gas_budget(),
+            gas_price,
+        )?
+        .to_bytes()?;
+        match self
+            .execute_prepared(PreparedTransaction::new(replacement))
+            .await
+        {
+            Ok(response) => Ok(SubmissionOutcome::Superseded {
+                replacement_digest: response.digest,
+            }),
+            Err(e) => match self.find_transaction(&digest).await? {
+                Some(response) => Ok(SubmissionOutcome::Landed(Box::new(response))),
+                None => Err(e),
+            },
+        }
+    }
+
+    /// reference gas price, cached when the trade has a ttl
+    async fn reference_gas_price(&self) -> Result<u64, SuiError> {
+        match self.gas_price_ttl {
+            Some(ttl) => self.client.reference_gas_price_cached(ttl).await,
+            None => self.client.get_reference_gas_price().await,
+        }
+    }
+
+    /// the transaction with effects, none while the node does not know it
+    async fn find_transaction(
+        &self,
+        digest: &str,
+    ) -> Result<Option<TransactionResponse>, SuiError> {
+        match self.client.get_transaction_info(digest).await {
+            Ok(response) => Ok(Some(response)),
+            Err(e) if e.is_not_found() => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+}
@@ -0,0 +1,393 @@
+use crate::types::{RpcRequest, SuiError};
+use futures::future::BoxFuture;
+use reqwest::Client as HttpClient;
+use serde_json::Value;
+use std::collections::{HashMap, VecDeque};
+use std::net::{IpAddr, Ipv4Addr, SocketAddr};
+use std::sync::Mutex;
+use std::time::Duration;
+
+/// characters of an error response body kept in `SuiError::HttpStatus`
+pub(crate) const BODY_SNIPPET_LEN: usize = 256;
+
+/// # Transport
+///
+/// Carries JSON-RPC request bodies to a node and returns the raw response bodies.
+pub trait Transport: Send + Sync {
+    /// send a request body, returns the response body
+    fn send(&self, body: Value) -> BoxFuture<'_, Result<Value, SuiError>>;
+
+    /// send a request body with a deadline replacing the transport's own timeout, fails
+    /// with `SuiError::Timeout` when it passes
+    fn send_with_timeout(
+        &self,
+        body: Value,
+        timeout: Duration,
+    ) -> BoxFuture<'_, Result<Value, SuiError>> {
+        Box::pin(async move {
+            tokio::time::timeout(timeout, self.send(body))
+                .await
+                .unwrap_or_else(|_| {
+                    Err(SuiError::Timeout(format!(
+                        "no response within {} ms",
+                        timeout.as_millis()
+                    )))
+                })
+        })
+    }
+}
+
+/// # Http transport
+///
+/// Default transport posting requests to a fullnode rpc url.
+pub struct HttpTransport {
+    http_client: HttpClient,
+    url: String,
+}
+
+impl HttpTransport {
+    /// # create http transport
+    ///
+    /// ## Parameters
+    /// - url : rpc url
+    pub fn new(url: String) -> Self {
+        Self::new_with_http_client(url, HttpClient::new())
+    }
+
+    /// # create http transport with an http client
+    ///
+    /// Requests are built on the client, its timeouts, default headers, proxy, TLS identity
+    /// and resolver apply as configured. The transport only sets the JSON body and its
+    /// content type on each request, and the deadline of a request sent with
+    /// [`RequestOptions::timeout`](crate::queue::RequestOptions), which replaces the
+    /// client's timeout.
+    ///
+    /// ## Parameters
+    /// - url : rpc url
+    /// - http_client : reqwest 0.11 client
+    pub fn new_with_http_client(url: String, http_client: HttpClient) -> Self {
+        Self { http_client, url }
+    }
+
+    /// post a request body, with a deadline replacing the client's timeout when set
+    async fn post(&self, body: Value, timeout: Option<Duration>) -> Result<Value, SuiError> {
+        let mut request = self.http_client.post(&self.url).json(&body);
+        if let Some(timeout) = timeout {
+            request = request.timeout(timeout);
+        }
+        let response = request.send().await.map_err(request_error)?;
+        let status = response.status();
+        if !status.is_success() {
+            // delay-seconds form only, HTTP-date values fall back to the retry backoff
+            let retry_after = response
+                .headers()
+                .get(reqwest::header::RETRY_AFTER)
+                .and_then(|v| v.to_str().ok())
+                .and_then(|v| v.trim().parse::<u64>().ok())
+                .map(Duration::from_secs);
+            let body = response.text().await.unwrap_or_default();
+            return Err(SuiError::HttpStatus {
+                status: status.as_u16(),
+                retry_after,
+                body_snippet: body.chars().take(BODY_SNIPPET_LEN).collect(),
+            });
+        }
+        response.json().await.map_err(|e| match e.is_decode() {
+            true => SuiError::Json(e.to_string()),
+            false => request_error(e),
+        })
+    }
+}
+
+impl Transport for HttpTransport {
+    fn send(&self, body: Value) -> BoxFuture<'_, Result<Value, SuiError>> {
+        Box::pin(self.post(body, None))
+    }
+
+    fn send_with_timeout(
+        &self,
+        body: Value,
+        timeout: Duration,
+    ) -> BoxFuture<'_, Result<Value, SuiError>> {
+        Box::pin(self.post(body, Some(timeout)))
+    }
+}
+
+/// error of a request, timeouts and failed connections apart from other failures
+fn request_error(error: reqwest::Error) -> SuiError {
+    if error.is_timeout() {
+        SuiError::Timeout(error.to_string())
+    } else if error.is_connect() {
+        // nothing was sent, resending can't duplicate the request
+        SuiError::HttpConnect(error.to_string())
+    } else {
+        error.into()
+    }
+}
+
+/// websocket stream dialed by [`DnsOverrides::connect_websocket`]
+pub(crate) type WebSocket =
+    tokio_tungstenite::WebSocketStream<tokio_tungstenite::MaybeTlsStream<tokio::net::TcpStream>>;
+
+/// # Dns overrides
+///
+/// Name resolution of the fullnode endpoints, shared by the http transport and the
+/// websocket listeners, e.g. hostnames pinned to the addresses of a split-horizon DNS.
+/// Only the address of an override is used, the port comes from the url as with
+/// `reqwest`'s `resolve`.
+///
+/// ## Example
+/// ```rust
+/// use sui_network_sdk::transport::DnsOverrides;
+///
+/// let overrides = DnsOverrides::new()
+///     .resolve_host("fullnode.internal", "10.0.0.7:443".parse().unwrap())
+///     .prefer_ipv4();
+/// assert_eq!(overrides.host("fullnode.internal"), Some("10.0.0.7:443".parse().unwrap()));
+/// assert!(overrides.is_ipv4_preferred());
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DnsOverrides {
+    hosts: HashMap<String, SocketAddr>,
+    prefer_ipv4: bool,
+}
+
+impl DnsOverrides {
+    /// overrides resolving every host through the system resolver
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// # Resolve host
+    ///
+    /// ## Parameters
+    /// - host : hostname of an endpoint url
+    /// - address : address the host resolves to, replaces a previous override
+    ///
+    /// ## Returns
+    /// overrides resolving the host to the address
+    pub fn resolve_host(mut self, host: &str, address: SocketAddr) -> Self {
+        self.hosts.insert(host.to_ascii_lowercase(), address);
+        self
+    }
+
+    /// # Prefer IPv4
+    ///
+    /// Connects over IPv4 only, e.g. where IPv6 routes exist but don't work. Overrides
+    /// with an IPv6 address are still dialed.
+    ///
+    /// ## Returns
+    /// overrides connecting over IPv4
+    pub fn prefer_ipv4(mut self) -> Self {
+        self.prefer_ipv4 = true;
+        self
+    }
+
+    /// address a host is pinned to
+    pub fn host(&self, host: &str) -> Option<SocketAddr> {
+        self.hosts.get(&host.to_ascii_lowercase()).copied()
+    }
+
+    /// whether connections use IPv4 only
+    pub fn is_ipv4_preferred(&self) -> bool {
+        self.prefer_ipv4
+    }
+
+    /// http client resolving with the overrides
+    pub(crate) fn http_client_builder(&self) -> reqwest::ClientBuilder {
+        let mut builder = HttpClient::builder();
+        for (host, address) in &self.hosts {
+            builder = builder.resolve(host, *address);
+        }
+        if self.prefer_ipv4 {
+            // a socket bound to an IPv4 address only reaches IPv4 peers
+            builder = builder.local_address(IpAddr::V4(Ipv4Addr::UNSPECIFIED));
+        }
+        builder
+    }
+
+    /// dial a websocket url, resolving its host with the overrides
+    pub(crate) async fn connect_websocket(&self, url: &str) -> Result<WebSocket, SuiError> {
+        if self.hosts.is_empty() && !self.prefer_ipv4 {
+            let (ws_stream, _) = tokio_tungstenite::connect_async(url).await?;
+            return Ok(ws_stream);
+        }
+        let parsed =
+            url::Url::parse(url).map_err(|e| SuiError::WebSocket(format!("{}: {}", url, e)))?;
+        // the crate dials websockets without TLS, as `connect_async` does
+        if parsed.scheme() == "wss" {
+            return Err(tokio_tungstenite::tungstenite::Error::Url(
+                tokio_tungstenite::tungstenite::error::UrlError::TlsFeatureNotEnabled,
+            )
+            .into());
+        }
+        let host = parsed
+            .host_str()
+            .ok_or_else(|| SuiError::WebSocket(format!("no host in {}", url)))?;
+        let port = parsed
+            .port_or_known_default()
+            .ok_or_else(|| SuiError::WebSocket(format!("no port in {}", url)))?;
+        // bracketed IPv6 literals parse without the brackets
+        let host = host.trim_start_matches('[').trim_end_matches(']');
+        let addresses: Vec<SocketAddr> = match self.host(host) {
+            Some(address) => vec![SocketAddr::new(address.ip(), port)],
+            None => tokio::net::lookup_host((host, port))
+                .await?
+                .filter(|address| !self.prefer_ipv4 || address.is_ipv4())
+                .collect(),
+        };
+        let mut last_error = None;
+        for address in addresses {
+            match tokio::net::TcpStream::connect(address).await {
+                Ok(stream) => {
+                    let stream = tokio_tungstenite::MaybeTlsStream::Plain(stream);
+                    let (ws_stream, _) = tokio_tungstenite::client_async(url, stream).await?;
+                    return Ok(ws_stream);
+                }
+                Err(e) => last_error = Some(e),
+            }
+        }
+        Err(SuiError::WebSocket(match last_error {
+            Some(e) => format!("{}: {}", url, e),
+            None => format!("{}: no address to connect to", url),
+        }))
+    }
+}
+
+/// canned results per method
+type MockResults = HashMap<String, VecDeque<Result<Value, (i32, String)>>>;
+
+/// # Mock transport
+///
+/// Answers requests from canned results per method, for examples and tests without a node.
+/// Queued results are returned in order and the last one repeats, methods without a result
+/// answer with a JSON-RPC "method not found" error. Batches are answered request by request.
+/// Every request is recorded.
+///
+/// ## Example
+/// ```rust
+/// use std::sync::Arc;
+/// use sui_network_sdk::SuiClient;
+/// use sui_network_sdk::transport::MockTransport;
+/// use sui_network_sdk::types::SuiClientConfig;
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let mock = Arc::new(
+///         MockTransport::new().with_result("sui_getChainIdentifier", serde_json::json!("4c78adac")),
+///     );
+///     let client = SuiClient::new_with_transport(SuiClientConfig::default(), mock.clone());
+///     let chain: String = client.request("sui_getChainIdentifier", vec![]).await.unwrap();
+///     assert_eq!(chain, "4c78adac");
+///     assert_eq!(mock.request_count("sui_getChainIdentifier"), 1);
+/// }
+/// ```
+#[derive(Default)]
+pub struct MockTransport {
+    results: Mutex<MockResults>,
+    requests: Mutex<Vec<RpcRequest>>,
+}
+
+impl MockTransport {
+    /// create an empty mock transport
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// queue a result for a method
+    pub fn with_result(self, method: &str, result: Value) -> Self {
+        self.push_result(method, result);
+        self
+    }
+
+    /// queue a JSON-RPC error for a method
+    pub fn with_error(self, method: &str, code: i32, message: &str) -> Self {
+        self.push_error(method, code, message);
+        self
+    }
+
+    /// queue a result for a method
+    pub fn push_result(&self, method: &str, result: Value) {
+        self.lock_results()
+            .entry(method.to_string())
+            .or_default()
+            .push_back(Ok(result));
+    }
+
+    /// queue a JSON-RPC error for a method
+    pub fn push_error(&self, method: &str, code: i32, message: &str) {
+        self.lock_results()
+            .entry(method.to_string())
+            .or_default()
+            .push_back(Err((code, message.to_string())));
+    }
+
+    /// requests received so far
+    pub fn requests(&self) -> Vec<RpcRequest> {
+        self.requests
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .clone()
+    }
+
+    /// number of requests received for a method
+    pub fn request_count(&self, method: &str) -> usize {
+        self.requests
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .iter()
+            .filter(|r| r.method == method)
+            .count()
+    }
+
+    fn lock_results(&self) -> std::sync::MutexGuard<'_, MockResults> {
+        self.results.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// answer a single request
+    fn answer(&self, request: RpcRequest) -> Value {
+        let result = {
+            let mut results = self.lock_results();
+            match results.get_mut(&request.method) {
+                Some(queue) if queue.len() > 1 => queue.pop_front(),
+                Some(queue) => queue.front().cloned(),
+                None => None,
+            }
+        };
+        let id = request.id;
+        self.requests
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .push(request);
+        match result {
+            Some(Ok(result)) => serde_json::json!({ "jsonrpc": "2.0", "id": id, "result": result }),
+            Some(Err((code, message))) => serde_json::json!({
+                "jsonrpc": "2.0",
+                "id": id,
+                "error": { "code": code, "message": message }
+            }),
+            None => serde_json::json!({
+                "jsonrpc": "2.0",
+                "id": id,
+                "error": { "code": -32601, "message": "Method not found" }
+            }),
+        }
+    }
+}
+
+impl Transport for MockTransport {
+    fn send(&self, body: Value) -> BoxFuture<'_, Result<Value, SuiError>> {
+        Box::pin(async move {
+            if let Value::Array(requests) = body {
+                // batch, answered in order
+                return requests
+                    .into_iter()
+                    .map(|request| Ok(self.answer(serde_json::from_value(request)?)))
+                    .collect::<Result<Vec<Value>, SuiError>>()
+                    .map(Value::Array);
+            }
+            let request: RpcRequest = serde_json::from_value(body)?;
+            Ok(self.answer(request))
+        })
+    }
+}
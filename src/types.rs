@@ -1,144 +1,3849 @@
 use serde::{Deserialize, Serialize};
 use std::fmt;
 
+use crate::errors::FailureExplanation;
 use crate::global::devnet;
+use crate::ptb::{Argument, StructTag, TypeTag};
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+/// serde helpers for integers the node encodes as strings,
+/// accepts both string and number encodings and serializes as a string.
+pub mod string_number {
+    use serde::{Deserialize, Deserializer, Serializer};
+    use std::fmt::Display;
+    use std::str::FromStr;
+
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum StringOrNumber {
+        String(String),
+        Number(serde_json::Number),
+    }
+
+    pub fn serialize<T: Display, S: Serializer>(
+        value: &T,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(value)
+    }
+
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+    where
+        T: FromStr,
+        T::Err: Display,
+        D: Deserializer<'de>,
+    {
+        let raw = match StringOrNumber::deserialize(deserializer)? {
+            StringOrNumber::String(s) => s,
+            StringOrNumber::Number(n) => n.to_string(),
+        };
+        raw.parse::<T>().map_err(serde::de::Error::custom)
+    }
+}
+
+/// serde helpers for optional integers the node encodes as strings, `null` and
+/// missing fields (with `#[serde(default)]`) deserialize as `None`.
+pub mod option_string_number {
+    use serde::{Deserialize, Deserializer, Serializer};
+    use std::fmt::Display;
+    use std::str::FromStr;
+
+    #[derive(Deserialize)]
+    #[serde(transparent)]
+    struct Wrapper<T: FromStr>(#[serde(with = "super::string_number")] T)
+    where
+        T::Err: Display;
+
+    pub fn serialize<T: Display, S: Serializer>(
+        value: &Option<T>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        match value {
+            Some(value) => serializer.collect_str(value),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<Option<T>, D::Error>
+    where
+        T: FromStr,
+        T::Err: Display,
+        D: Deserializer<'de>,
+    {
+        Ok(Option::<Wrapper<T>>::deserialize(deserializer)?.map(|w| w.0))
+    }
+}
+
+/// serde helpers for maps whose integer values the node encodes as strings.
+pub mod string_number_map {
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+    use std::collections::BTreeMap;
+    use std::fmt::Display;
+    use std::str::FromStr;
+
+    #[derive(Deserialize)]
+    #[serde(transparent)]
+    struct Wrapper<T: FromStr>(#[serde(with = "super::string_number")] T)
+    where
+        T::Err: Display;
+
+    pub fn serialize<K: Serialize, T: Display, S: Serializer>(
+        map: &BTreeMap<K, T>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.collect_map(map.iter().map(|(key, value)| (key, value.to_string())))
+    }
+
+    pub fn deserialize<'de, K, T, D>(deserializer: D) -> Result<BTreeMap<K, T>, D::Error>
+    where
+        K: Deserialize<'de> + Ord,
+        T: FromStr,
+        T::Err: Display,
+        D: Deserializer<'de>,
+    {
+        Ok(BTreeMap::<K, Wrapper<T>>::deserialize(deserializer)?
+            .into_iter()
+            .map(|(key, value)| (key, value.0))
+            .collect())
+    }
+}
+
+/// serde helpers for address typed fields (addresses and object ids), deserialization
+/// normalizes `0x2`, `0x0000...0002` and mixed case to the canonical lowercase, 0x
+/// prefixed, 64 hex char form.
+///
+/// ## Example
+/// ```rust
+/// use sui_network_sdk::types::{Object, Owner, TransactionResponse};
+///
+/// let object: Object = serde_json::from_value(serde_json::json!({
+///     "objectId": "0x5",
+///     "version": "1",
+///     "digest": "9WzSXdwb",
+///     "owner": { "AddressOwner": "0x7D20DCDB2BCA4F508EA9613994683EB4E76E9C4ED371169677C1BE02AAF0B58E" }
+/// }))
+/// .unwrap();
+/// assert_eq!(object.object_id, format!("0x{:0>64}", "5"));
+/// assert_eq!(
+///     object.owner,
+///     Some(Owner::AddressOwner(
+///         "0x7d20dcdb2bca4f508ea9613994683eb4e76e9c4ed371169677c1be02aaf0b58e".to_string()
+///     ))
+/// );
+///
+/// let response: TransactionResponse = serde_json::from_value(serde_json::json!({
+///     "digest": "3Fc1yDqA",
+///     "transaction": { "data": { "sender": "0x0000000000000000000000000000000000000000000000000000000000000002" } },
+///     "objectChanges": [{ "type": "published", "packageId": "0xABC", "version": "1",
+///                         "digest": "9WzSXdwb", "modules": [] }]
+/// }))
+/// .unwrap();
+/// assert_eq!(response.transaction.unwrap().data.sender, format!("0x{:0>64}", "2"));
+///
+/// assert!(serde_json::from_value::<Object>(serde_json::json!({
+///     "objectId": "0xnothex", "version": "1", "digest": "9WzSXdwb"
+/// }))
+/// .is_err());
+/// ```
+pub mod address {
+    use serde::{Deserialize, Deserializer};
+
+    /// canonical form of an address, `None` when it is not a hex address of at most 32 bytes
+    pub fn normalize(address: &str) -> Option<String> {
+        let trimmed = address.trim();
+        let hex = trimmed
+            .strip_prefix("0x")
+            .or_else(|| trimmed.strip_prefix("0X"))
+            .unwrap_or(trimmed);
+        if hex.is_empty() || hex.len() > 64 || !hex.bytes().all(|b| b.is_ascii_hexdigit()) {
+            return None;
+        }
+        Some(format!("0x{:0>64}", hex.to_ascii_lowercase()))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<String, D::Error> {
+        let raw = String::deserialize(deserializer)?;
+        normalize(&raw).ok_or_else(|| serde::de::Error::custom(format!("invalid address {}", raw)))
+    }
+}
+
+/// Sui address in canonical form, parsing and deserialization normalize the input.
+///
+/// ## Example
+/// ```rust
+/// use sui_network_sdk::types::SuiAddress;
+///
+/// let short: SuiAddress = "0x2".parse().unwrap();
+/// let long: SuiAddress = serde_json::from_str(
+///     "\"0x0000000000000000000000000000000000000000000000000000000000000002\"",
+/// )
+/// .unwrap();
+/// assert_eq!(short, long);
+/// assert!("0xzz".parse::<SuiAddress>().is_err());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct SuiAddress(#[serde(deserialize_with = "address::deserialize")] String);
+
+impl SuiAddress {
+    /// canonical address string
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl std::str::FromStr for SuiAddress {
+    type Err = SuiError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        address::normalize(s)
+            .map(SuiAddress)
+            .ok_or_else(|| SuiError::Hex(format!("invalid address {}", s)))
+    }
+}
+
+impl fmt::Display for SuiAddress {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl AsRef<str> for SuiAddress {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct SuiClientConfig {
     pub rpc_url: String,
     pub wss_url: String,
     pub faucet_url: String,
+    /// items per `sui_multiGet*` request, nodes reject more than 50, some providers allow more
+    #[serde(default = "default_multi_get_chunk_size")]
+    pub multi_get_chunk_size: usize,
+    /// chain identifier the node must be on, e.g. `explorer::MAINNET_CHAIN_ID`, checked
+    /// once before the first request
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expected_chain: Option<String>,
+    /// retries of throttled, unavailable or unreachable nodes
+    #[serde(default)]
+    pub retry_policy: RetryPolicy,
+    /// deadline of a request, from connecting to the end of the response, `None` waits
+    /// forever. Applies to the http client of `SuiClient::new`, see
+    /// `SuiClient::request_with_timeout` for a longer deadline of one call
+    #[serde(default = "default_timeout")]
+    pub timeout: Option<std::time::Duration>,
+    /// deadline of connecting to the node, `None` waits forever
+    #[serde(default = "default_connect_timeout")]
+    pub connect_timeout: Option<std::time::Duration>,
+}
+
+/// whether a flag is unset, false flags are left out of requests
+fn is_false(flag: &bool) -> bool {
+    !*flag
+}
+
+/// item limit of `sui_multiGet*` requests on public nodes
+fn default_multi_get_chunk_size() -> usize {
+    50
+}
+
+/// request deadline, long enough for a dry run on a busy node
+fn default_timeout() -> Option<std::time::Duration> {
+    Some(std::time::Duration::from_secs(30))
+}
+
+/// connect deadline
+fn default_connect_timeout() -> Option<std::time::Duration> {
+    Some(std::time::Duration::from_secs(10))
+}
+
+impl Default for SuiClientConfig {
+    fn default() -> Self {
+        Self {
+            rpc_url: devnet::RPC_URL.to_string(),
+            wss_url: devnet::WSS_URL.to_string(),
+            faucet_url: devnet::FAUCET_URL.to_string(),
+            multi_get_chunk_size: default_multi_get_chunk_size(),
+            expected_chain: None,
+            retry_policy: RetryPolicy::default(),
+            timeout: default_timeout(),
+            connect_timeout: default_connect_timeout(),
+        }
+    }
+}
+
+/// Object data, fields are absent when the matching show option was off.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Object {
+    #[serde(deserialize_with = "address::deserialize")]
+    pub object_id: String,
+    #[serde(with = "string_number")]
+    pub version: u64,
+    pub digest: String,
+    #[serde(rename = "type")]
+    pub object_type: Option<String>,
+    pub owner: Option<Owner>,
+    pub previous_transaction: Option<String>,
+    #[serde(rename = "content")]
+    pub data: Option<ObjectData>,
+    pub bcs: Option<RawObjectData>,
+    #[serde(skip)]
+    parsed_type: TypeCache,
+}
+
+impl Object {
+    /// # Parsed type
+    ///
+    /// ## Returns
+    /// type of the object parsed on first use, `None` when the type was not requested,
+    /// the object is a package, or the type does not parse
+    ///
+    /// ## Example
+    /// ```rust
+    /// use sui_network_sdk::types::Object;
+    ///
+    /// let object: Object = serde_json::from_value(serde_json::json!({
+    ///     "objectId": "0x5",
+    ///     "version": "1",
+    ///     "digest": "9WzSXdwbky8tNbH7juvyaui4QzMUYEjdCEKMrMgLhXHT",
+    ///     "type": "0x2::coin::Coin<0x2::sui::SUI>"
+    /// }))
+    /// .unwrap();
+    /// let object_type = object.parsed_type().unwrap();
+    /// assert!(object_type.is_coin());
+    /// assert_eq!(object_type.coin_type_param().unwrap().to_string(), format!("0x{:0>64}::sui::SUI", "2"));
+    /// ```
+    pub fn parsed_type(&self) -> Option<&ObjectType> {
+        self.parsed_type
+            .0
+            .get_or_init(|| self.object_type.as_deref()?.parse().ok())
+            .as_ref()
+    }
+}
+
+/// parsed object type, cached and ignored when comparing objects
+#[derive(Debug, Clone, Default)]
+struct TypeCache(std::sync::OnceLock<Option<ObjectType>>);
+
+impl PartialEq for TypeCache {
+    fn eq(&self, _: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for TypeCache {}
+
+/// # Object type
+///
+/// Struct type of an object, compared with addresses normalized, so
+/// `0x2::coin::Coin<0x2::sui::SUI>` and its long form are the same type.
+///
+/// ## Example
+/// ```rust
+/// use sui_network_sdk::ptb::StructTag;
+/// use sui_network_sdk::types::ObjectType;
+///
+/// let short: ObjectType = "0x2::coin::Coin<0x2::sui::SUI>".parse().unwrap();
+/// let long: ObjectType = format!("0x{:0>64}::coin::Coin<0x{:0>64}::sui::SUI>", "2", "2")
+///     .parse()
+///     .unwrap();
+/// assert_eq!(short, long);
+/// assert!(long.matches(&"0x2::coin::Coin<0x0002::sui::SUI>".parse().unwrap()));
+/// // a tag without type parameters matches every instantiation
+/// assert!(long.matches(&"0x2::coin::Coin".parse().unwrap()));
+/// assert!(!long.matches(&"0x2::coin::Coin<0xa1::usdc::USDC>".parse().unwrap()));
+/// assert!(!long.matches(&"0x2::balance::Balance".parse().unwrap()));
+///
+/// // nested generic types
+/// let pool: ObjectType = "0xdee9::pool::Pool<0x2::sui::SUI, 0x5d4b::coin::COIN>".parse().unwrap();
+/// let tag: StructTag = format!("0x{:0>64}::pool::Pool<0x2::sui::SUI, 0x{:0>64}::coin::COIN>", "dee9", "5d4b")
+///     .parse()
+///     .unwrap();
+/// assert!(pool.matches(&tag));
+/// let lp: ObjectType = "0x2::coin::Coin<0xabc::lp::LP<0x2::sui::SUI, 0x5d4b::coin::COIN>>".parse().unwrap();
+/// assert!(lp.is_coin());
+/// assert!(lp.matches(&"0x2::coin::Coin<0x0abc::lp::LP<0x02::sui::SUI, 0x5d4b::coin::COIN>>".parse().unwrap()));
+/// assert!(!lp.matches(&"0x2::coin::Coin<0xabc::lp::LP<0x2::sui::SUI, 0x2::sui::SUI>>".parse().unwrap()));
+///
+/// assert!("0x3::staking_pool::StakedSui".parse::<ObjectType>().unwrap().is_staked_sui());
+/// assert!("u64".parse::<ObjectType>().is_err());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ObjectType(StructTag);
+
+impl ObjectType {
+    /// struct tag of the type
+    pub fn struct_tag(&self) -> &StructTag {
+        &self.0
+    }
+
+    /// # Matches
+    ///
+    /// ## Parameters
+    /// - tag : struct type, without type parameters it matches every instantiation
+    ///
+    /// ## Returns
+    /// whether the object has the type
+    pub fn matches(&self, tag: &StructTag) -> bool {
+        self.0.address == tag.address
+            && self.0.module == tag.module
+            && self.0.name == tag.name
+            && (tag.type_params.is_empty() || self.0.type_params == tag.type_params)
+    }
+
+    /// whether the object is a `0x2::coin::Coin`
+    pub fn is_coin(&self) -> bool {
+        self.is(SUI_FRAMEWORK_ADDRESS, "coin", "Coin")
+    }
+
+    /// coin type `T` of a `0x2::coin::Coin<T>`
+    pub fn coin_type_param(&self) -> Option<&TypeTag> {
+        match self.0.type_params.as_slice() {
+            [coin_type] if self.is_coin() => Some(coin_type),
+            _ => None,
+        }
+    }
+
+    /// whether the object is a `0x2::dynamic_field::Field`, the object of a dynamic field
+    pub fn is_dynamic_field(&self) -> bool {
+        self.is(SUI_FRAMEWORK_ADDRESS, "dynamic_field", "Field")
+    }
+
+    /// whether the object is a `0x3::staking_pool::StakedSui`
+    pub fn is_staked_sui(&self) -> bool {
+        self.is(SUI_SYSTEM_ADDRESS, "staking_pool", "StakedSui")
+    }
+
+    fn is(&self, address: u8, module: &str, name: &str) -> bool {
+        self.0.address[..31].iter().all(|b| *b == 0)
+            && self.0.address[31] == address
+            && self.0.module == module
+            && self.0.name == name
+    }
+}
+
+impl std::str::FromStr for ObjectType {
+    type Err = SuiError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.parse().map(ObjectType)
+    }
+}
+
+impl fmt::Display for ObjectType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", TypeTag::Struct(Box::new(self.0.clone())))
+    }
+}
+
+/// last byte of the `0x2` framework address
+const SUI_FRAMEWORK_ADDRESS: u8 = 2;
+/// last byte of the `0x3` system address
+const SUI_SYSTEM_ADDRESS: u8 = 3;
+
+/// Raw BCS object data (`showBcs`), move objects carry `bcs_bytes`, packages `module_map`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RawObjectData {
+    pub data_type: String,
+    #[serde(rename = "type")]
+    pub object_type: Option<String>,
+    pub has_public_transfer: Option<bool>,
+    pub bcs_bytes: Option<String>,
+    pub module_map: Option<std::collections::BTreeMap<String, String>>,
+    pub linkage_table: Option<std::collections::BTreeMap<String, UpgradeInfo>>,
+}
+
+/// Linkage table entry of a package, the version a dependency is linked against.
+///
+/// # Fields
+/// - upgraded_id : id of the linked package
+/// - upgraded_version : version of the linked package
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UpgradeInfo {
+    #[serde(deserialize_with = "address::deserialize")]
+    pub upgraded_id: String,
+    #[serde(with = "string_number")]
+    pub upgraded_version: u64,
+}
+
+/// Published Move package.
+///
+/// # Fields
+/// - package_id : package id
+/// - version : package version, bumped on every upgrade
+/// - module_map : base64 encoded bytecode by module name
+/// - linkage_table : linked dependency versions by original package id
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MovePackage {
+    #[serde(deserialize_with = "address::deserialize")]
+    pub package_id: String,
+    pub version: u64,
+    pub module_map: std::collections::BTreeMap<String, String>,
+    pub linkage_table: std::collections::BTreeMap<String, UpgradeInfo>,
+}
+
+impl MovePackage {
+    /// module names in lexicographic order
+    pub fn module_names(&self) -> Vec<&str> {
+        self.module_map.keys().map(String::as_str).collect()
+    }
+
+    /// # Module bytecode
+    ///
+    /// ## Parameters
+    /// - name : module name
+    ///
+    /// ## Returns
+    /// - Ok(Vec<u8>) : decoded module bytecode
+    /// - Err(SuiError) : the module does not exist or its bytecode is not valid base64
+    ///
+    /// ## Example
+    /// ```rust
+    /// use std::collections::BTreeMap;
+    /// use sui_network_sdk::types::MovePackage;
+    ///
+    /// let package = MovePackage {
+    ///     package_id: "0x2".to_string(),
+    ///     version: 1,
+    ///     module_map: BTreeMap::from([("coin".to_string(), "oRzrCwY=".to_string())]),
+    ///     linkage_table: BTreeMap::new(),
+    /// };
+    /// assert_eq!(package.module_names(), vec!["coin"]);
+    /// assert_eq!(package.module_bytecode("coin").unwrap(), vec![0xa1, 0x1c, 0xeb, 0x0b, 0x06]);
+    /// assert!(package.module_bytecode("balance").is_err());
+    /// ```
+    pub fn module_bytecode(&self, name: &str) -> Result<Vec<u8>, SuiError> {
+        use base64::Engine;
+        let encoded = self.module_map.get(name).ok_or_else(|| {
+            SuiError::Rpc(format!(
+                "Module {} not found in package {}",
+                name, self.package_id
+            ))
+        })?;
+        Ok(base64::prelude::BASE64_STANDARD.decode(encoded)?)
+    }
+}
+
+/// Verification result of a single module.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ModuleVerification {
+    /// on-chain and local bytecode are identical
+    Match,
+    /// on-chain and local bytecode differ
+    Mismatch,
+    /// the module exists locally but not on chain
+    MissingOnChain,
+    /// the module exists on chain but not locally
+    MissingLocally,
+}
+
+/// Result of comparing a package's on-chain bytecode with locally compiled modules.
+///
+/// # Fields
+/// - package_id : package id
+/// - version : on-chain package version
+/// - modules : verification result by module name
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PackageVerification {
+    #[serde(deserialize_with = "address::deserialize")]
+    pub package_id: String,
+    pub version: u64,
+    pub modules: std::collections::BTreeMap<String, ModuleVerification>,
+}
+
+impl PackageVerification {
+    /// true when every module matches on both sides
+    pub fn is_verified(&self) -> bool {
+        !self.modules.is_empty()
+            && self
+                .modules
+                .values()
+                .all(|m| *m == ModuleVerification::Match)
+    }
+}
+
+/// Ability of a Move type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum MoveAbility {
+    Copy,
+    Drop,
+    Store,
+    Key,
+}
+
+/// Abilities of a Move struct, or the constraints of a type parameter.
+///
+/// # Fields
+/// - abilities : abilities
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MoveAbilitySet {
+    pub abilities: Vec<MoveAbility>,
+}
+
+impl MoveAbilitySet {
+    /// true when the set holds the ability
+    pub fn has(&self, ability: MoveAbility) -> bool {
+        self.abilities.contains(&ability)
+    }
+}
+
+/// Visibility of a Move function.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum MoveVisibility {
+    Private,
+    Public,
+    Friend,
+}
+
+/// Move type of a normalized module, externally tagged as on the wire
+/// (`"U64"`, `{"Vector": ..}`, `{"Struct": {..}}`).
+///
+/// # Variants
+/// - Bool, U8 .. U256, Address, Signer : primitive types
+/// - Struct : struct type with its type arguments
+/// - Vector : vector of a type
+/// - TypeParameter : type parameter of the function or struct, by index
+/// - Reference : immutable reference
+/// - MutableReference : mutable reference
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum NormalizedMoveType {
+    Bool,
+    U8,
+    U16,
+    U32,
+    U64,
+    U128,
+    U256,
+    Address,
+    Signer,
+    #[serde(rename_all = "camelCase")]
+    Struct {
+        #[serde(deserialize_with = "address::deserialize")]
+        address: String,
+        module: String,
+        name: String,
+        type_arguments: Vec<NormalizedMoveType>,
+    },
+    Vector(Box<NormalizedMoveType>),
+    TypeParameter(u16),
+    Reference(Box<NormalizedMoveType>),
+    MutableReference(Box<NormalizedMoveType>),
+}
+
+impl NormalizedMoveType {
+    /// true for `TxContext` and references to it, the parameter the runtime passes
+    pub fn is_tx_context(&self) -> bool {
+        match self {
+            NormalizedMoveType::Reference(inner) | NormalizedMoveType::MutableReference(inner) => {
+                inner.is_tx_context()
+            }
+            NormalizedMoveType::Struct {
+                address,
+                module,
+                name,
+                ..
+            } => {
+                address::normalize("0x2").as_deref() == Some(address.as_str())
+                    && module == "tx_context"
+                    && name == "TxContext"
+            }
+            _ => false,
+        }
+    }
+}
+
+impl fmt::Display for NormalizedMoveType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            NormalizedMoveType::Bool => f.write_str("bool"),
+            NormalizedMoveType::U8 => f.write_str("u8"),
+            NormalizedMoveType::U16 => f.write_str("u16"),
+            NormalizedMoveType::U32 => f.write_str("u32"),
+            NormalizedMoveType::U64 => f.write_str("u64"),
+            NormalizedMoveType::U128 => f.write_str("u128"),
+            NormalizedMoveType::U256 => f.write_str("u256"),
+            NormalizedMoveType::Address => f.write_str("address"),
+            NormalizedMoveType::Signer => f.write_str("signer"),
+            NormalizedMoveType::Struct {
+                address,
+                module,
+                name,
+                type_arguments,
+            } => {
+                write!(f, "{}::{}::{}", address, module, name)?;
+                if !type_arguments.is_empty() {
+                    let arguments: Vec<String> =
+                        type_arguments.iter().map(ToString::to_string).collect();
+                    write!(f, "<{}>", arguments.join(", "))?;
+                }
+                Ok(())
+            }
+            NormalizedMoveType::Vector(inner) => write!(f, "vector<{}>", inner),
+            NormalizedMoveType::TypeParameter(index) => write!(f, "T{}", index),
+            NormalizedMoveType::Reference(inner) => write!(f, "&{}", inner),
+            NormalizedMoveType::MutableReference(inner) => write!(f, "&mut {}", inner),
+        }
+    }
+}
+
+/// Signature of a Move function.
+///
+/// # Fields
+/// - visibility : visibility
+/// - is_entry : callable as a transaction command even when not public
+/// - type_parameters : constraints of each type parameter
+/// - parameters : parameter types
+/// - return_types : return types
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NormalizedMoveFunction {
+    pub visibility: MoveVisibility,
+    pub is_entry: bool,
+    pub type_parameters: Vec<MoveAbilitySet>,
+    pub parameters: Vec<NormalizedMoveType>,
+    #[serde(rename = "return")]
+    pub return_types: Vec<NormalizedMoveType>,
+}
+
+impl NormalizedMoveFunction {
+    /// parameters a move call passes, without the trailing `TxContext`
+    pub fn call_parameters(&self) -> &[NormalizedMoveType] {
+        match self.parameters.split_last() {
+            Some((last, rest)) if last.is_tx_context() => rest,
+            _ => &self.parameters,
+        }
+    }
+}
+
+/// Type parameter of a Move struct.
+///
+/// # Fields
+/// - constraints : abilities the type argument must have
+/// - is_phantom : the parameter appears in no field
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MoveStructTypeParameter {
+    pub constraints: MoveAbilitySet,
+    pub is_phantom: bool,
+}
+
+/// Field of a Move struct.
+///
+/// # Fields
+/// - name : field name
+/// - field_type : field type
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NormalizedMoveField {
+    pub name: String,
+    #[serde(rename = "type")]
+    pub field_type: NormalizedMoveType,
+}
+
+/// Declaration of a Move struct.
+///
+/// # Fields
+/// - abilities : abilities of the struct
+/// - type_parameters : type parameters
+/// - fields : fields in declaration order
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NormalizedMoveStruct {
+    pub abilities: MoveAbilitySet,
+    pub type_parameters: Vec<MoveStructTypeParameter>,
+    pub fields: Vec<NormalizedMoveField>,
+}
+
+/// Module a Move module declares as friend.
+///
+/// # Fields
+/// - address : package id
+/// - name : module name
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct MoveModuleId {
+    #[serde(deserialize_with = "address::deserialize")]
+    pub address: String,
+    pub name: String,
+}
+
+/// Normalized Move module, its structs and the functions callable from outside.
+///
+/// # Fields
+/// - file_format_version : bytecode version
+/// - address : package id
+/// - name : module name
+/// - friends : friend modules
+/// - structs : structs by name
+/// - exposed_functions : public, friend and entry functions by name
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NormalizedMoveModule {
+    pub file_format_version: u32,
+    #[serde(deserialize_with = "address::deserialize")]
+    pub address: String,
+    pub name: String,
+    pub friends: Vec<MoveModuleId>,
+    pub structs: std::collections::BTreeMap<String, NormalizedMoveStruct>,
+    pub exposed_functions: std::collections::BTreeMap<String, NormalizedMoveFunction>,
+}
+
+impl NormalizedMoveModule {
+    /// names of the entry functions, callable as transaction commands
+    pub fn entry_functions(&self) -> Vec<&str> {
+        self.exposed_functions
+            .iter()
+            .filter(|(_, function)| function.is_entry)
+            .map(|(name, _)| name.as_str())
+            .collect()
+    }
+}
+
+/// How a Move function takes an object argument.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ObjectValueKind {
+    ByImmutableReference,
+    ByMutableReference,
+    ByValue,
+}
+
+/// Kind of a Move function argument, `"Pure"` or `{"Object": "ByValue"}` on the wire.
+///
+/// # Variants
+/// - Pure : BCS encoded value
+/// - Object : object, with how the function takes it
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum MoveFunctionArgType {
+    Pure,
+    Object(ObjectValueKind),
+}
+
+/// Object read with BCS contents only.
+///
+/// # Fields
+/// - object_id : object id
+/// - version : object version
+/// - digest : object digest
+/// - type_tag : move type of the object
+/// - bcs_bytes : BCS encoded move object contents
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BcsObject {
+    #[serde(deserialize_with = "address::deserialize")]
+    pub object_id: String,
+    pub version: u64,
+    pub digest: String,
+    pub type_tag: String,
+    pub bcs_bytes: Vec<u8>,
+}
+
+/// Object read response envelope, holds either the object data or the read error.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ObjectResponse {
+    pub data: Option<Object>,
+    pub error: Option<serde_json::Value>,
+}
+
+/// Object read at a version, tagged by `status` with the payload in `details` as on the wire.
+///
+/// # Variants
+/// - VersionFound : the object at the version
+/// - ObjectNotExists : no object ever had the id
+/// - ObjectDeleted : the object was deleted, reference of the deletion
+/// - VersionNotFound : the id exists but never had the version
+/// - VersionTooHigh : the version is above the latest the node knows
+///
+/// ## Example
+/// ```rust
+/// use sui_network_sdk::types::PastObjectResponse;
+///
+/// let found: PastObjectResponse = serde_json::from_value(serde_json::json!({
+///     "status": "VersionFound",
+///     "details": { "objectId": "0x5", "version": "7", "digest": "d" }
+/// }))
+/// .unwrap();
+/// assert_eq!(found.object().unwrap().version, 7);
+///
+/// let too_high: PastObjectResponse = serde_json::from_value(serde_json::json!({
+///     "status": "VersionTooHigh",
+///     "details": { "object_id": "0x5", "asked_version": "9", "latest_version": "7" }
+/// }))
+/// .unwrap();
+/// assert!(matches!(too_high, PastObjectResponse::VersionTooHigh { latest_version: 7, .. }));
+/// assert_eq!(too_high.status(), "VersionTooHigh");
+/// assert!(too_high.into_object().is_none());
+///
+/// let missing: PastObjectResponse = serde_json::from_value(serde_json::json!({
+///     "status": "VersionNotFound",
+///     "details": ["0x5", 3]
+/// }))
+/// .unwrap();
+/// assert_eq!(missing, PastObjectResponse::VersionNotFound(format!("0x{:0>64}", "5"), 3));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "status", content = "details")]
+pub enum PastObjectResponse {
+    VersionFound(Box<Object>),
+    ObjectNotExists(#[serde(deserialize_with = "address::deserialize")] String),
+    ObjectDeleted(ObjectRef),
+    VersionNotFound(
+        #[serde(deserialize_with = "address::deserialize")] String,
+        #[serde(with = "string_number")] u64,
+    ),
+    VersionTooHigh {
+        #[serde(deserialize_with = "address::deserialize")]
+        object_id: String,
+        #[serde(with = "string_number")]
+        asked_version: u64,
+        #[serde(with = "string_number")]
+        latest_version: u64,
+    },
+}
+
+impl PastObjectResponse {
+    /// wire name of the status
+    pub fn status(&self) -> &'static str {
+        match self {
+            Self::VersionFound(_) => "VersionFound",
+            Self::ObjectNotExists(_) => "ObjectNotExists",
+            Self::ObjectDeleted(_) => "ObjectDeleted",
+            Self::VersionNotFound(..) => "VersionNotFound",
+            Self::VersionTooHigh { .. } => "VersionTooHigh",
+        }
+    }
+
+    /// the object when the version was found
+    pub fn object(&self) -> Option<&Object> {
+        match self {
+            Self::VersionFound(object) => Some(object),
+            _ => None,
+        }
+    }
+
+    /// the object when the version was found
+    pub fn into_object(self) -> Option<Object> {
+        match self {
+            Self::VersionFound(object) => Some(*object),
+            _ => None,
+        }
+    }
+}
+
+/// Object and version of a `sui_tryMultiGetPastObjects` request.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PastObjectRequest {
+    pub object_id: String,
+    #[serde(with = "string_number")]
+    pub version: u64,
+}
+
+/// Object owner, externally tagged as on the wire
+/// (`{"AddressOwner": "0x.."}`, `{"Shared": {..}}`, `"Immutable"`).
+/// Owners added by later protocol versions are kept as `Unknown` with their JSON, a
+/// malformed owner of a known kind is an error.
+///
+/// # Example
+/// ```rust
+/// use std::collections::HashSet;
+/// use sui_network_sdk::types::Owner;
+///
+/// let party: Owner = serde_json::from_str(r#"{"PartyOwner": {"parties": ["0xb0b"]}}"#).unwrap();
+/// assert!(matches!(party, Owner::Unknown(_)));
+/// assert!(serde_json::from_str::<Owner>(r#"{"AddressOwner": 5}"#).is_err());
+/// assert!(serde_json::from_str::<Owner>(r#"{"Shared": {}}"#).is_err());
+///
+/// // owners hash by value, unknown ones by their JSON
+/// let same_party: Owner = serde_json::from_str(r#"{ "PartyOwner": { "parties": [ "0xb0b" ] } }"#).unwrap();
+/// let owners: HashSet<Owner> = [party, same_party, Owner::Immutable].into_iter().collect();
+/// assert_eq!(owners.len(), 2);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Owner {
+    AddressOwner(#[serde(deserialize_with = "address::deserialize")] String),
+    ObjectOwner(#[serde(deserialize_with = "address::deserialize")] String),
+    Shared(SharedOwner),
+    Immutable,
+    /// owned by an address, versioned through consensus like a shared object
+    ConsensusAddressOwner(ConsensusAddressOwner),
+    #[serde(untagged, deserialize_with = "unknown_owner")]
+    Unknown(serde_json::Value),
+}
+
+/// tags of the owners the crate knows
+const OWNER_TAGS: [&str; 5] = [
+    "AddressOwner",
+    "ObjectOwner",
+    "Shared",
+    "Immutable",
+    "ConsensusAddressOwner",
+];
+
+/// owner of a kind the crate doesn't know, a known kind reaching this was malformed
+fn unknown_owner<'de, D>(deserializer: D) -> Result<serde_json::Value, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let value = serde_json::Value::deserialize(deserializer)?;
+    let tag = match &value {
+        serde_json::Value::String(tag) => Some(tag.as_str()),
+        serde_json::Value::Object(map) if map.len() == 1 => map.keys().next().map(String::as_str),
+        _ => None,
+    };
+    if let Some(tag) = tag
+        && OWNER_TAGS.contains(&tag)
+    {
+        return Err(serde::de::Error::custom(format!(
+            "malformed {} owner: {}",
+            tag, value
+        )));
+    }
+    Ok(value)
+}
+
+impl std::hash::Hash for Owner {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::mem::discriminant(self).hash(state);
+        match self {
+            Owner::AddressOwner(address) | Owner::ObjectOwner(address) => address.hash(state),
+            Owner::Shared(shared) => shared.hash(state),
+            Owner::Immutable => {}
+            Owner::ConsensusAddressOwner(owner) => owner.hash(state),
+            // object keys are sorted, so equal values print the same
+            Owner::Unknown(value) => value.to_string().hash(state),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct SharedOwner {
+    #[serde(with = "string_number")]
+    pub initial_shared_version: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct ConsensusAddressOwner {
+    #[serde(with = "string_number")]
+    pub start_version: u64,
+    #[serde(deserialize_with = "address::deserialize")]
+    pub owner: String,
+}
+
+/// # Object location
+///
+/// Where an object is held, see `SuiClient::locate_object`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ObjectLocation {
+    /// owned by an account address
+    DirectlyOwned(String),
+    /// held by another object, as a dynamic field, in a kiosk or transferred to the object;
+    /// `top_level_owner` is the location of the outermost parent, `None` when the parent
+    /// chain is cyclic, too deep, ends at a missing object or at an owner the crate does not know
+    InObject {
+        parent_id: String,
+        top_level_owner: Option<Box<ObjectLocation>>,
+    },
+    /// shared object
+    Shared,
+    /// immutable object
+    Immutable,
+    /// the object was deleted or wrapped
+    Deleted,
+}
+
+/// # Ownership link
+///
+/// One object of a walked ownership chain and its owner.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OwnershipLink {
+    pub object_id: String,
+    pub owner: Owner,
+}
+
+/// # Located object
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LocatedObject {
+    pub object_id: String,
+    pub location: ObjectLocation,
+    pub chain: Vec<OwnershipLink>, // the object then each parent walked, outermost last
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ObjectData {
+    pub data_type: String,
+    #[serde(default)]
+    pub fields: serde_json::Value,
+    #[serde(default)]
+    pub has_public_transfer: bool,
+}
+
+/// Transaction response.
+///
+/// # Fields
+/// - digest : transaction digest
+/// - effects : transaction effects, when requested
+/// - events : transaction events, `None` when the response was fetched without events,
+///   see [`TransactionResponse::events_lazy`]
+/// - object_changes : object changes, when requested
+/// - balance_changes : balance changes, when requested
+/// - loaded_child_objects : child objects the transaction loaded, when present, see
+///   [`TransactionResponse::loaded_child_objects`]
+/// - transaction : signed transaction, when requested
+/// - timestamp_ms : checkpoint timestamp in milliseconds, once checkpointed
+/// - checkpoint : sequence number of the checkpoint, once checkpointed
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransactionResponse {
+    pub digest: String,
+    pub effects: Option<TransactionEffects>,
+    #[serde(default)]
+    pub events: Option<Vec<Event>>,
+    #[serde(default)]
+    pub object_changes: Option<Vec<ObjectChange>>,
+    #[serde(default)]
+    pub balance_changes: Option<Vec<BalanceChange>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub loaded_child_objects: Option<Vec<LoadedChildObject>>,
+    #[serde(default)]
+    pub transaction: Option<TransactionBlock>,
+    #[serde(default, with = "option_string_number")]
+    pub timestamp_ms: Option<u64>,
+    #[serde(default, with = "option_string_number")]
+    pub checkpoint: Option<u64>,
+    #[serde(skip)]
+    lazy_events: LazyCache<Vec<Event>>,
+    #[serde(skip)]
+    lazy_child_objects: LazyCache<Vec<LoadedChildObject>>,
+}
+
+/// Coin balance of an address.
+///
+/// # Fields
+/// - coin_type : coin type
+/// - coin_object_count : number of coin objects
+/// - total_balance : total balance in the coin's smallest unit
+/// - locked_balance : locked balance by the epoch it unlocks at
+///
+/// ## Example
+/// ```rust
+/// use sui_network_sdk::types::Balance;
+///
+/// let balance: Balance = serde_json::from_value(serde_json::json!({
+///     "coinType": "0x2::sui::SUI",
+///     "coinObjectCount": 3,
+///     "totalBalance": "18446744073709551616",
+///     "lockedBalance": { "812": "5000" }
+/// }))
+/// .unwrap();
+/// assert_eq!(balance.total_balance, u64::MAX as u128 + 1);
+/// assert_eq!(balance.locked_balance[&812], 5000);
+/// assert_eq!(serde_json::to_value(&balance).unwrap()["totalBalance"], "18446744073709551616");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Balance {
+    pub coin_type: String,
+    pub coin_object_count: u64,
+    #[serde(with = "string_number")]
+    pub total_balance: u128,
+    #[serde(default, with = "string_number_map")]
+    pub locked_balance: std::collections::BTreeMap<u64, u128>,
+}
+
+/// Balance change of a transaction (`showBalanceChanges`).
+///
+/// # Fields
+/// - owner : owner of the balance
+/// - coin_type : coin type
+/// - amount : change in the coin's smallest unit, negative when the balance decreased
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BalanceChange {
+    pub owner: Owner,
+    pub coin_type: String,
+    #[serde(with = "string_number")]
+    pub amount: i128,
+}
+
+/// Child object a transaction loaded, e.g. a dynamic field (`sui_getLoadedChildObjects`).
+///
+/// # Fields
+/// - object_id : child object id
+/// - sequence_number : version of the child the transaction read
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LoadedChildObject {
+    #[serde(deserialize_with = "address::deserialize")]
+    pub object_id: String,
+    #[serde(with = "string_number")]
+    pub sequence_number: u64,
+}
+
+/// Signed transaction of a response (`showInput`).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TransactionBlock {
+    pub data: TransactionBlockData,
+}
+
+/// Transaction data, only the fields the crate reads.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransactionBlockData {
+    #[serde(deserialize_with = "address::deserialize")]
+    pub sender: String,
+    pub gas_data: Option<GasData>,
+}
+
+/// Gas configuration of a transaction.
+///
+/// # Fields
+/// - payment : gas coins
+/// - owner : gas owner address
+/// - price : gas price in MIST per unit
+/// - budget : gas budget in MIST
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct GasData {
+    #[serde(default)]
+    pub payment: Vec<ObjectRef>,
+    #[serde(deserialize_with = "address::deserialize")]
+    pub owner: String,
+    #[serde(with = "string_number")]
+    pub price: u64,
+    #[serde(with = "string_number")]
+    pub budget: u64,
+}
+
+/// Checkpoint summary.
+///
+/// # Fields
+/// - epoch : epoch of the checkpoint
+/// - sequence_number : checkpoint sequence number
+/// - digest : checkpoint digest
+/// - previous_digest : digest of the previous checkpoint, none for the genesis checkpoint
+/// - timestamp_ms : checkpoint timestamp in milliseconds
+/// - network_total_transactions : transactions of the network up to this checkpoint included
+/// - epoch_rolling_gas_cost_summary : gas cost of the epoch up to this checkpoint included
+/// - transactions : digests of the transactions in the checkpoint, in execution order
+///
+/// ## Example
+/// ```rust
+/// use sui_network_sdk::types::Checkpoint;
+/// // mainnet encodes integers as strings, older devnet nodes as numbers
+/// let mainnet: Checkpoint = serde_json::from_value(serde_json::json!({
+///     "sequenceNumber": "5", "digest": "C1", "timestampMs": "1700000000123"
+/// }))
+/// .unwrap();
+/// let devnet: Checkpoint = serde_json::from_value(serde_json::json!({
+///     "sequenceNumber": 5, "digest": "C1", "timestampMs": 1700000000123u64
+/// }))
+/// .unwrap();
+/// assert_eq!(mainnet, devnet);
+/// assert_eq!(devnet.timestamp_ms, 1_700_000_000_123);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Checkpoint {
+    #[serde(default, with = "string_number")]
+    pub epoch: u64,
+    #[serde(with = "string_number")]
+    pub sequence_number: u64,
+    pub digest: String,
+    #[serde(default)]
+    pub previous_digest: Option<String>,
+    #[serde(with = "string_number")]
+    pub timestamp_ms: u64,
+    #[serde(default, with = "string_number")]
+    pub network_total_transactions: u64,
+    #[serde(default)]
+    pub epoch_rolling_gas_cost_summary: Option<GasCostSummary>,
+    #[serde(default)]
+    pub transactions: Vec<String>,
+}
+
+/// Checkpoint of [`SuiClient::get_checkpoint`](crate::SuiClient::get_checkpoint), by
+/// sequence number or digest.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum CheckpointId {
+    SequenceNumber(u64),
+    Digest(String),
+}
+
+impl fmt::Display for CheckpointId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CheckpointId::SequenceNumber(sequence_number) => write!(f, "{}", sequence_number),
+            CheckpointId::Digest(digest) => write!(f, "{}", digest),
+        }
+    }
+}
+
+/// System state of the current epoch, see
+/// [`SuiClient::get_latest_sui_system_state`](crate::SuiClient::get_latest_sui_system_state).
+/// Amounts are in MIST.
+///
+/// # Fields
+/// - epoch : current epoch
+/// - protocol_version : protocol version of the epoch
+/// - system_state_version : version of the system state object
+/// - reference_gas_price : reference gas price of the epoch
+/// - total_stake : stake of the active validators
+/// - epoch_start_timestamp_ms : start of the epoch in milliseconds
+/// - epoch_duration_ms : target duration of an epoch in milliseconds
+/// - safe_mode : whether the network runs in safe mode, the epoch change failed
+/// - safe_mode_storage_rewards : storage rewards accumulated in safe mode
+/// - safe_mode_computation_rewards : computation rewards accumulated in safe mode
+/// - safe_mode_storage_rebates : storage rebates accumulated in safe mode
+/// - safe_mode_non_refundable_storage_fee : non-refundable storage fees accumulated in safe mode
+/// - active_validators : validators of the epoch
+/// - other : remaining fields of the summary, e.g. stake subsidy and validator set tables
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SuiSystemStateSummary {
+    #[serde(with = "string_number")]
+    pub epoch: u64,
+    #[serde(with = "string_number")]
+    pub protocol_version: u64,
+    #[serde(default, with = "string_number")]
+    pub system_state_version: u64,
+    #[serde(with = "string_number")]
+    pub reference_gas_price: u64,
+    #[serde(with = "string_number")]
+    pub total_stake: u64,
+    #[serde(default, with = "string_number")]
+    pub epoch_start_timestamp_ms: u64,
+    #[serde(default, with = "string_number")]
+    pub epoch_duration_ms: u64,
+    #[serde(default)]
+    pub safe_mode: bool,
+    #[serde(default, with = "string_number")]
+    pub safe_mode_storage_rewards: u64,
+    #[serde(default, with = "string_number")]
+    pub safe_mode_computation_rewards: u64,
+    #[serde(default, with = "string_number")]
+    pub safe_mode_storage_rebates: u64,
+    #[serde(default, with = "string_number")]
+    pub safe_mode_non_refundable_storage_fee: u64,
+    pub active_validators: Vec<ValidatorSummary>,
+    #[serde(flatten)]
+    pub other: serde_json::Map<String, serde_json::Value>,
+}
+
+/// Epoch or protocol version change seen by
+/// [`SuiClient::watch_protocol_version`](crate::SuiClient::watch_protocol_version).
+///
+/// # Fields
+/// - old_epoch : epoch before the change
+/// - new_epoch : epoch after the change
+/// - old_protocol_version : protocol version before the change
+/// - new_protocol_version : protocol version after the change
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProtocolVersionChange {
+    pub old_epoch: u64,
+    pub new_epoch: u64,
+    pub old_protocol_version: u64,
+    pub new_protocol_version: u64,
+}
+
+impl ProtocolVersionChange {
+    /// whether the protocol version changed, not only the epoch
+    pub fn protocol_upgraded(&self) -> bool {
+        self.old_protocol_version != self.new_protocol_version
+    }
+}
+
+/// Active validator of [`SuiSystemStateSummary`]. Amounts are in MIST, rates in basis
+/// points, keys and proofs base64 and addresses multiaddrs.
+///
+/// # Fields
+/// - sui_address : validator address
+/// - name : validator name
+/// - description : validator description
+/// - image_url : logo url
+/// - project_url : website url
+/// - voting_power : voting power, out of 10000 for the whole committee
+/// - gas_price : gas price quote of the epoch
+/// - commission_rate : commission of the epoch
+/// - staking_pool_sui_balance : stake of the epoch
+/// - next_epoch_stake : stake of the next epoch, pending stakes and withdrawals applied
+/// - next_epoch_gas_price : gas price quote of the next epoch
+/// - next_epoch_commission_rate : commission of the next epoch
+/// - staking_pool_id : id of the staking pool
+/// - staking_pool_activation_epoch : epoch the pool became active
+/// - staking_pool_deactivation_epoch : epoch the pool was deactivated
+/// - rewards_pool : undistributed rewards of the pool
+/// - pool_token_balance : pool tokens issued
+/// - pending_stake : stake added this epoch
+/// - pending_total_sui_withdraw : stake withdrawn this epoch
+/// - pending_pool_token_withdraw : pool tokens withdrawn this epoch
+/// - exchange_rates_id : id of the pool's exchange rate table
+/// - exchange_rates_size : entries of the exchange rate table
+/// - operation_cap_id : id of the validator's operation capability
+/// - protocol_pubkey_bytes : BLS protocol key
+/// - network_pubkey_bytes : network key
+/// - worker_pubkey_bytes : worker key
+/// - proof_of_possession_bytes : proof of possession of the protocol key
+/// - net_address : network address
+/// - p2p_address : peer to peer address
+/// - primary_address : primary address
+/// - worker_address : worker address
+/// - next_epoch_protocol_pubkey_bytes : protocol key of the next epoch, if it changes
+/// - next_epoch_proof_of_possession : proof of possession of the next epoch, if it changes
+/// - next_epoch_network_pubkey_bytes : network key of the next epoch, if it changes
+/// - next_epoch_worker_pubkey_bytes : worker key of the next epoch, if it changes
+/// - next_epoch_net_address : network address of the next epoch, if it changes
+/// - next_epoch_p2p_address : peer to peer address of the next epoch, if it changes
+/// - next_epoch_primary_address : primary address of the next epoch, if it changes
+/// - next_epoch_worker_address : worker address of the next epoch, if it changes
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ValidatorSummary {
+    #[serde(deserialize_with = "address::deserialize")]
+    pub sui_address: String,
+    pub name: String,
+    #[serde(default)]
+    pub description: String,
+    #[serde(default)]
+    pub image_url: String,
+    #[serde(default)]
+    pub project_url: String,
+    #[serde(with = "string_number")]
+    pub voting_power: u64,
+    #[serde(with = "string_number")]
+    pub gas_price: u64,
+    #[serde(with = "string_number")]
+    pub commission_rate: u64,
+    #[serde(with = "string_number")]
+    pub staking_pool_sui_balance: u64,
+    #[serde(with = "string_number")]
+    pub next_epoch_stake: u64,
+    #[serde(default, with = "string_number")]
+    pub next_epoch_gas_price: u64,
+    #[serde(default, with = "string_number")]
+    pub next_epoch_commission_rate: u64,
+    #[serde(default)]
+    pub staking_pool_id: String,
+    #[serde(default, with = "option_string_number")]
+    pub staking_pool_activation_epoch: Option<u64>,
+    #[serde(default, with = "option_string_number")]
+    pub staking_pool_deactivation_epoch: Option<u64>,
+    #[serde(default, with = "string_number")]
+    pub rewards_pool: u64,
+    #[serde(default, with = "string_number")]
+    pub pool_token_balance: u64,
+    #[serde(default, with = "string_number")]
+    pub pending_stake: u64,
+    #[serde(default, with = "string_number")]
+    pub pending_total_sui_withdraw: u64,
+    #[serde(default, with = "string_number")]
+    pub pending_pool_token_withdraw: u64,
+    #[serde(default)]
+    pub exchange_rates_id: String,
+    #[serde(default, with = "string_number")]
+    pub exchange_rates_size: u64,
+    #[serde(default)]
+    pub operation_cap_id: String,
+    #[serde(default)]
+    pub protocol_pubkey_bytes: String,
+    #[serde(default)]
+    pub network_pubkey_bytes: String,
+    #[serde(default)]
+    pub worker_pubkey_bytes: String,
+    #[serde(default)]
+    pub proof_of_possession_bytes: String,
+    #[serde(default)]
+    pub net_address: String,
+    #[serde(default)]
+    pub p2p_address: String,
+    #[serde(default)]
+    pub primary_address: String,
+    #[serde(default)]
+    pub worker_address: String,
+    #[serde(default)]
+    pub next_epoch_protocol_pubkey_bytes: Option<String>,
+    #[serde(default)]
+    pub next_epoch_proof_of_possession: Option<String>,
+    #[serde(default)]
+    pub next_epoch_network_pubkey_bytes: Option<String>,
+    #[serde(default)]
+    pub next_epoch_worker_pubkey_bytes: Option<String>,
+    #[serde(default)]
+    pub next_epoch_net_address: Option<String>,
+    #[serde(default)]
+    pub next_epoch_p2p_address: Option<String>,
+    #[serde(default)]
+    pub next_epoch_primary_address: Option<String>,
+    #[serde(default)]
+    pub next_epoch_worker_address: Option<String>,
+}
+
+/// Epoch of [`SuiClient::get_epochs`](crate::SuiClient::get_epochs). Fields added by later
+/// protocol versions are ignored.
+///
+/// # Fields
+/// - epoch : epoch number
+/// - validators : validators of the epoch
+/// - epoch_total_transactions : transactions of the epoch, 0 while it runs
+/// - first_checkpoint_id : first checkpoint of the epoch
+/// - epoch_start_timestamp : start of the epoch in milliseconds
+/// - reference_gas_price : reference gas price of the epoch, when the node reports it
+/// - end_of_epoch_info : totals of the epoch, `None` while it runs
+///
+/// ## Example
+/// ```rust
+/// use sui_network_sdk::types::EpochInfo;
+///
+/// let epoch: EpochInfo = serde_json::from_value(serde_json::json!({
+///     "epoch": "600",
+///     "validators": [],
+///     "epochTotalTransactions": "0",
+///     "firstCheckpointId": "171000000",
+///     "epochStartTimestamp": "1750000000000",
+///     "referenceGasPrice": 750,
+///     "endOfEpochInfo": null,
+///     "addedLater": { "any": "shape" }
+/// }))
+/// .unwrap();
+/// assert_eq!(epoch.reference_gas_price, Some(750));
+/// assert_eq!((epoch.validator_count(), epoch.total_stake()), (0, 0));
+/// assert!(epoch.end_of_epoch_info.is_none());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EpochInfo {
+    #[serde(with = "string_number")]
+    pub epoch: u64,
+    #[serde(default)]
+    pub validators: Vec<ValidatorSummary>,
+    #[serde(default, with = "string_number")]
+    pub epoch_total_transactions: u64,
+    #[serde(default, with = "string_number")]
+    pub first_checkpoint_id: u64,
+    #[serde(default, with = "string_number")]
+    pub epoch_start_timestamp: u64,
+    #[serde(default, with = "option_string_number")]
+    pub reference_gas_price: Option<u64>,
+    #[serde(default)]
+    pub end_of_epoch_info: Option<EndOfEpochInfo>,
+}
+
+impl EpochInfo {
+    /// number of validators of the epoch
+    pub fn validator_count(&self) -> usize {
+        self.validators.len()
+    }
+
+    /// stake of the epoch's validators, the reported total once the epoch ended
+    pub fn total_stake(&self) -> u64 {
+        match &self.end_of_epoch_info {
+            Some(end) => end.total_stake,
+            None => self
+                .validators
+                .iter()
+                .map(|validator| validator.staking_pool_sui_balance)
+                .sum(),
+        }
+    }
+}
+
+/// Totals of an ended epoch, amounts in MIST.
+///
+/// # Fields
+/// - last_checkpoint_id : last checkpoint of the epoch
+/// - epoch_end_timestamp : end of the epoch in milliseconds
+/// - protocol_version : protocol version of the epoch
+/// - reference_gas_price : reference gas price of the epoch
+/// - total_stake : stake of the validators
+/// - storage_fund_reinvestment : storage fund rewards reinvested into the fund
+/// - storage_charge : storage fees charged
+/// - storage_rebate : storage rebates paid
+/// - storage_fund_balance : storage fund balance at the end of the epoch
+/// - stake_subsidy_amount : stake subsidy paid out
+/// - total_gas_fees : gas fees collected
+/// - total_stake_rewards_distributed : rewards distributed to stakers
+/// - leftover_storage_fund_inflow : storage fund inflow not distributed
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EndOfEpochInfo {
+    #[serde(with = "string_number")]
+    pub last_checkpoint_id: u64,
+    #[serde(with = "string_number")]
+    pub epoch_end_timestamp: u64,
+    #[serde(with = "string_number")]
+    pub protocol_version: u64,
+    #[serde(with = "string_number")]
+    pub reference_gas_price: u64,
+    #[serde(with = "string_number")]
+    pub total_stake: u64,
+    #[serde(default, with = "string_number")]
+    pub storage_fund_reinvestment: u64,
+    #[serde(default, with = "string_number")]
+    pub storage_charge: u64,
+    #[serde(default, with = "string_number")]
+    pub storage_rebate: u64,
+    #[serde(default, with = "string_number")]
+    pub storage_fund_balance: u64,
+    #[serde(default, with = "string_number")]
+    pub stake_subsidy_amount: u64,
+    #[serde(default, with = "string_number")]
+    pub total_gas_fees: u64,
+    #[serde(default, with = "string_number")]
+    pub total_stake_rewards_distributed: u64,
+    #[serde(default, with = "string_number")]
+    pub leftover_storage_fund_inflow: u64,
+}
+
+/// Stakes of an address with one validator, see
+/// [`SuiClient::get_stakes`](crate::SuiClient::get_stakes).
+///
+/// # Fields
+/// - validator_address : validator staked with
+/// - staking_pool : id of the validator's staking pool
+/// - stakes : staked SUI objects
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DelegatedStake {
+    #[serde(deserialize_with = "address::deserialize")]
+    pub validator_address: String,
+    pub staking_pool: String,
+    pub stakes: Vec<StakeObject>,
+}
+
+/// Staked SUI object of a [`DelegatedStake`]. Amounts are in MIST.
+///
+/// # Fields
+/// - staked_sui_id : id of the `StakedSui` object
+/// - stake_request_epoch : epoch the stake was requested
+/// - stake_active_epoch : epoch the stake starts earning
+/// - principal : staked amount
+/// - status : status, flattened into the object as on the wire
+///
+/// ## Example
+/// ```rust
+/// use sui_network_sdk::types::{StakeObject, StakeStatus};
+///
+/// let stake: StakeObject = serde_json::from_value(serde_json::json!({
+///     "stakedSuiId": "0x5a",
+///     "stakeRequestEpoch": "410",
+///     "stakeActiveEpoch": "411",
+///     "principal": "1000000000000",
+///     "status": "Active",
+///     "estimatedReward": "2500000000"
+/// }))
+/// .unwrap();
+/// assert_eq!(stake.status, StakeStatus::Active { estimated_reward: 2_500_000_000 });
+/// let pending: StakeObject = serde_json::from_value(serde_json::json!({
+///     "stakedSuiId": "0x5b",
+///     "stakeRequestEpoch": "412",
+///     "stakeActiveEpoch": "413",
+///     "principal": "1000000000",
+///     "status": "Pending"
+/// }))
+/// .unwrap();
+/// assert_eq!(pending.status, StakeStatus::Pending);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StakeObject {
+    pub staked_sui_id: String,
+    #[serde(with = "string_number")]
+    pub stake_request_epoch: u64,
+    #[serde(with = "string_number")]
+    pub stake_active_epoch: u64,
+    #[serde(with = "string_number")]
+    pub principal: u64,
+    #[serde(flatten)]
+    pub status: StakeStatus,
+}
+
+/// Status of a [`StakeObject`], tagged by its `status` field.
+///
+/// # Variants
+/// - Pending : requested, earning from the active epoch
+/// - Active : earning, with the reward estimated at the current exchange rate
+/// - Unstaked : withdrawn, only returned by a lookup by id
+/// - Unknown : a status added by a newer node
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "status")]
+pub enum StakeStatus {
+    Pending,
+    #[serde(rename_all = "camelCase")]
+    Active {
+        #[serde(with = "string_number")]
+        estimated_reward: u64,
+    },
+    Unstaked,
+    #[serde(other)]
+    Unknown,
+}
+
+/// Annual percentage yields of the active validators, see
+/// [`SuiClient::get_validators_apy`](crate::SuiClient::get_validators_apy).
+///
+/// # Fields
+/// - apys : yield by validator
+/// - epoch : epoch the yields were computed for
+///
+/// ## Example
+/// ```rust
+/// use sui_network_sdk::types::ValidatorsApy;
+///
+/// let apys: ValidatorsApy = serde_json::from_value(serde_json::json!({
+///     "apys": [{ "address": "0x4a", "apy": 0.0312 }],
+///     "epoch": "412"
+/// }))
+/// .unwrap();
+/// assert_eq!(apys.apy_for("0x000000000000000000000000000000000000000000000000000000000000004a"), Some(0.0312));
+/// // a validator that joined this epoch has no yield yet
+/// assert_eq!(apys.apy_for("0x5b"), None);
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ValidatorsApy {
+    pub apys: Vec<ValidatorApy>,
+    #[serde(with = "string_number")]
+    pub epoch: u64,
+}
+
+impl ValidatorsApy {
+    /// yield of a validator, `None` when it is not in the list, e.g. joined this epoch
+    pub fn apy_for(&self, address: &str) -> Option<f64> {
+        let address = address::normalize(address)?;
+        self.apys
+            .iter()
+            .find(|apy| apy.address == address)
+            .map(|apy| apy.apy)
+    }
+}
+
+/// Yield of a validator.
+///
+/// # Fields
+/// - address : validator address
+/// - apy : annual percentage yield as a fraction, `0.03` for 3%
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ValidatorApy {
+    #[serde(deserialize_with = "address::deserialize")]
+    pub address: String,
+    pub apy: f64,
+}
+
+#[cfg(feature = "chrono")]
+impl Checkpoint {
+    /// # Timestamp
+    ///
+    /// ## Returns
+    /// checkpoint time, `None` when out of the datetime range
+    ///
+    /// ## Example
+    /// ```rust
+    /// use sui_network_sdk::types::Checkpoint;
+    /// let checkpoint: Checkpoint = serde_json::from_value(serde_json::json!({
+    ///     "sequenceNumber": "5",
+    ///     "digest": "C1",
+    ///     "timestampMs": "1700000000123"
+    /// }))
+    /// .unwrap();
+    /// assert_eq!(checkpoint.timestamp().unwrap().timestamp_millis(), 1_700_000_000_123);
+    /// ```
+    pub fn timestamp(&self) -> Option<chrono::DateTime<chrono::Utc>> {
+        datetime(self.timestamp_ms)
+    }
+}
+
+/// datetime of a millisecond timestamp
+#[cfg(feature = "chrono")]
+fn datetime(timestamp_ms: u64) -> Option<chrono::DateTime<chrono::Utc>> {
+    chrono::DateTime::from_timestamp_millis(i64::try_from(timestamp_ms).ok()?)
+}
+
+/// # Read consistency
+///
+/// State a read reflects.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum ReadConsistency {
+    /// whatever state the node serves
+    #[default]
+    Latest,
+    /// state as of the checkpoint with the sequence number, reads that can't honor the pin
+    /// fail instead of returning later state
+    AtCheckpoint(u64),
+}
+
+/// Object change of a transaction (`showObjectChanges`).
+///
+/// Kinds added by later protocol versions are kept as `Unknown` with their JSON, and fields
+/// the crate does not know are ignored, so responses of upgraded nodes keep parsing.
+///
+/// ## Example
+/// ```rust
+/// use sui_network_sdk::types::{ObjectChange, Owner, TransactionResponse};
+///
+/// let response: TransactionResponse = serde_json::from_value(serde_json::json!({
+///     "digest": "D1",
+///     "rawTransaction": "AAA=",
+///     "objectChanges": [
+///         {
+///             "type": "created",
+///             "sender": "0xa11ce",
+///             "owner": { "ConsensusAddressOwner": { "start_version": "7", "owner": "0xb0b" } },
+///             "objectType": "0x2::coin::Coin<0x2::sui::SUI>",
+///             "objectId": "0xc01",
+///             "version": "7",
+///             "digest": "9WzSXdwbky8tNbH7juvyaui4QzMUYEjdCEKMrMgLhXHT",
+///             "previousOwner": "0xa11ce"
+///         },
+///         {
+///             "type": "mutated",
+///             "sender": "0xa11ce",
+///             "owner": { "PartyOwner": { "parties": ["0xb0b"] } },
+///             "objectType": "0x2::clock::Clock",
+///             "objectId": "0x6",
+///             "version": "8",
+///             "previousVersion": "7",
+///             "digest": "9WzSXdwbky8tNbH7juvyaui4QzMUYEjdCEKMrMgLhXHT"
+///         },
+///         { "type": "frozen", "objectId": "0xc02" }
+///     ]
+/// }))
+/// .unwrap();
+///
+/// let changes = response.object_changes.unwrap();
+/// let ObjectChange::Created { owner: Owner::ConsensusAddressOwner(owner), .. } = &changes[0] else {
+///     panic!("created object of a consensus owner expected");
+/// };
+/// assert_eq!(owner.start_version, 7);
+/// let ObjectChange::Mutated { owner: Owner::Unknown(owner), .. } = &changes[1] else {
+///     panic!("unknown owner expected");
+/// };
+/// assert_eq!(owner["PartyOwner"]["parties"][0], "0xb0b");
+/// let ObjectChange::Unknown(change) = &changes[2] else {
+///     panic!("unknown change expected");
+/// };
+/// assert_eq!(change["type"], "frozen");
+///
+/// // a known change missing its fields is an error, not an unknown change
+/// let malformed = serde_json::json!({ "type": "created", "objectId": "0xc01" });
+/// assert!(serde_json::from_value::<ObjectChange>(malformed).is_err());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum ObjectChange {
+    #[serde(rename_all = "camelCase")]
+    Published {
+        #[serde(deserialize_with = "address::deserialize")]
+        package_id: String,
+        #[serde(with = "string_number")]
+        version: u64,
+        digest: String,
+        modules: Vec<String>,
+    },
+    #[serde(rename_all = "camelCase")]
+    Transferred {
+        #[serde(deserialize_with = "address::deserialize")]
+        sender: String,
+        recipient: Owner,
+        object_type: String,
+        #[serde(deserialize_with = "address::deserialize")]
+        object_id: String,
+        #[serde(with = "string_number")]
+        version: u64,
+        digest: String,
+    },
+    #[serde(rename_all = "camelCase")]
+    Mutated {
+        #[serde(deserialize_with = "address::deserialize")]
+        sender: String,
+        owner: Owner,
+        object_type: String,
+        #[serde(deserialize_with = "address::deserialize")]
+        object_id: String,
+        #[serde(with = "string_number")]
+        version: u64,
+        #[serde(with = "string_number")]
+        previous_version: u64,
+        digest: String,
+    },
+    #[serde(rename_all = "camelCase")]
+    Deleted {
+        #[serde(deserialize_with = "address::deserialize")]
+        sender: String,
+        object_type: String,
+        #[serde(deserialize_with = "address::deserialize")]
+        object_id: String,
+        #[serde(with = "string_number")]
+        version: u64,
+    },
+    #[serde(rename_all = "camelCase")]
+    Wrapped {
+        #[serde(deserialize_with = "address::deserialize")]
+        sender: String,
+        object_type: String,
+        #[serde(deserialize_with = "address::deserialize")]
+        object_id: String,
+        #[serde(with = "string_number")]
+        version: u64,
+    },
+    #[serde(rename_all = "camelCase")]
+    Created {
+        #[serde(deserialize_with = "address::deserialize")]
+        sender: String,
+        owner: Owner,
+        object_type: String,
+        #[serde(deserialize_with = "address::deserialize")]
+        object_id: String,
+        #[serde(with = "string_number")]
+        version: u64,
+        digest: String,
+    },
+    #[serde(untagged, deserialize_with = "unknown_object_change")]
+    Unknown(serde_json::Value),
+}
+
+/// types of the object changes the crate knows
+const OBJECT_CHANGE_TYPES: [&str; 6] = [
+    "published",
+    "transferred",
+    "mutated",
+    "deleted",
+    "wrapped",
+    "created",
+];
+
+/// object change of a type the crate doesn't know, a known type reaching this was malformed
+fn unknown_object_change<'de, D>(deserializer: D) -> Result<serde_json::Value, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let value = serde_json::Value::deserialize(deserializer)?;
+    if let Some(change_type) = value["type"].as_str()
+        && OBJECT_CHANGE_TYPES.contains(&change_type)
+    {
+        return Err(serde::de::Error::custom(format!(
+            "malformed {} object change: {}",
+            change_type, value
+        )));
+    }
+    Ok(value)
+}
+
+/// Package published by a transaction.
+///
+/// # Fields
+/// - package_id : package id
+/// - version : package version
+/// - modules : module names
+/// - sender : publisher address
+/// - digest : transaction digest
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PublishedPackage {
+    #[serde(deserialize_with = "address::deserialize")]
+    pub package_id: String,
+    pub version: u64,
+    pub modules: Vec<String>,
+    #[serde(deserialize_with = "address::deserialize")]
+    pub sender: String,
+    pub digest: String,
+}
+
+impl PublishedPackage {
+    /// # From response
+    ///
+    /// Packages published by a transaction, read from its object changes.
+    ///
+    /// ## Parameters
+    /// - response : transaction response fetched with `showObjectChanges` and `showInput`
+    ///
+    /// ## Returns
+    /// published packages, or `None` when the response lacks object changes or input
+    ///
+    /// ## Example
+    /// ```rust
+    /// use sui_network_sdk::types::{PublishedPackage, TransactionResponse};
+    ///
+    /// let response: TransactionResponse = serde_json::from_value(serde_json::json!({
+    ///     "digest": "3Fc1yDqA",
+    ///     "transaction": { "data": { "sender": "0x7d20" } },
+    ///     "objectChanges": [
+    ///         { "type": "published", "packageId": "0xabc", "version": "1",
+    ///           "digest": "9WzSXdwb", "modules": ["nft"] },
+    ///         { "type": "created", "sender": "0x7d20", "owner": { "AddressOwner": "0x7d20" },
+    ///           "objectType": "0x2::package::UpgradeCap", "objectId": "0xdef",
+    ///           "version": "1", "digest": "4Hn2sDk5" }
+    ///     ]
+    /// }))
+    /// .unwrap();
+    /// let published = PublishedPackage::from_response(&response).unwrap();
+    /// assert_eq!(published.len(), 1);
+    /// assert_eq!(published[0].package_id, format!("0x{:0>64}", "abc"));
+    /// assert_eq!(published[0].sender, format!("0x{:0>64}", "7d20"));
+    /// ```
+    pub fn from_response(response: &TransactionResponse) -> Option<Vec<Self>> {
+        let sender = &response.transaction.as_ref()?.data.sender;
+        let packages = response
+            .object_changes
+            .as_ref()?
+            .iter()
+            .filter_map(|change| match change {
+                ObjectChange::Published {
+                    package_id,
+                    version,
+                    modules,
+                    ..
+                } => Some(Self {
+                    package_id: package_id.clone(),
+                    version: *version,
+                    modules: modules.clone(),
+                    sender: sender.clone(),
+                    digest: response.digest.clone(),
+                }),
+                _ => None,
+            })
+            .collect();
+        Some(packages)
+    }
+}
+
+impl TransactionResponse {
+    /// # Timestamp
+    ///
+    /// ## Returns
+    /// checkpoint time of the transaction, `None` until checkpointed
+    ///
+    /// ## Example
+    /// ```rust
+    /// use sui_network_sdk::types::TransactionResponse;
+    /// let response: TransactionResponse = serde_json::from_value(serde_json::json!({
+    ///     "digest": "A1",
+    ///     "timestampMs": "1700000000123",
+    ///     "checkpoint": "5"
+    /// }))
+    /// .unwrap();
+    /// let timestamp = response.timestamp().unwrap();
+    /// assert_eq!(timestamp.timestamp_millis(), 1_700_000_000_123);
+    /// assert_eq!(timestamp.to_rfc3339(), "2023-11-14T22:13:20.123+00:00");
+    /// ```
+    #[cfg(feature = "chrono")]
+    pub fn timestamp(&self) -> Option<chrono::DateTime<chrono::Utc>> {
+        self.timestamp_ms.and_then(datetime)
+    }
+    /// # Events lazy
+    ///
+    /// Events of the transaction, fetched with `sui_getEvents` on first use when the
+    /// response was fetched without events. The fetched events are cached in the
+    /// response, concurrent callers share a single request.
+    ///
+    /// ## Parameters
+    /// - client : client used to fetch the events
+    ///
+    /// ## Returns
+    /// - Ok(&[Event]) : transaction events
+    /// - Err(SuiError) : error
+    ///
+    /// ## Example
+    /// ```rust
+    /// use std::sync::Arc;
+    /// use sui_network_sdk::SuiClient;
+    /// use sui_network_sdk::transport::MockTransport;
+    /// use sui_network_sdk::types::{SuiClientConfig, TransactionResponse};
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let mock = Arc::new(MockTransport::new().with_result(
+    ///         "sui_getEvents",
+    ///         serde_json::json!([{
+    ///             "id": { "txDigest": "3Fc1yDqA", "eventSeq": "0" },
+    ///             "type": "0x2::coin::Minted",
+    ///             "parsedJson": { "amount": "5" }
+    ///         }]),
+    ///     ));
+    ///     let client = SuiClient::new_with_transport(SuiClientConfig::default(), mock.clone());
+    ///     let response: TransactionResponse =
+    ///         serde_json::from_value(serde_json::json!({ "digest": "3Fc1yDqA" })).unwrap();
+    ///     assert!(response.events.is_none());
+    ///     let (first, second) =
+    ///         tokio::join!(response.events_lazy(&client), response.events_lazy(&client));
+    ///     assert_eq!(first.unwrap().len(), 1);
+    ///     assert_eq!(second.unwrap()[0].event_type, "0x2::coin::Minted");
+    ///     response.events_lazy(&client).await.unwrap();
+    ///     assert_eq!(mock.request_count("sui_getEvents"), 1);
+    /// }
+    /// ```
+    pub async fn events_lazy(&self, client: &crate::SuiClient) -> Result<&[Event], SuiError> {
+        if let Some(events) = &self.events {
+            return Ok(events);
+        }
+        self.lazy_events
+            .0
+            .get_or_try_init(|| client.get_events_by_transaction(&self.digest))
+            .await
+            .map(Vec::as_slice)
+    }
+
+    /// # Loaded child objects
+    ///
+    /// Child objects the transaction read or wrote through dynamic fields, which the
+    /// effects only list when they changed. Taken from the response when present, otherwise
+    /// fetched with `sui_getLoadedChildObjects` on first use and cached.
+    ///
+    /// ## Parameters
+    /// - client : client to fetch the child objects with
+    ///
+    /// ## Returns
+    /// - Ok(&[LoadedChildObject]) : loaded child objects
+    /// - Err(SuiError) : error
+    ///
+    /// ## Example
+    /// ```rust
+    /// use std::sync::Arc;
+    /// use sui_network_sdk::SuiClient;
+    /// use sui_network_sdk::summary::Summary;
+    /// use sui_network_sdk::transport::MockTransport;
+    /// use sui_network_sdk::types::{SuiClientConfig, TransactionResponse};
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     // `table::remove` of one entry and `table::borrow_mut` of another
+    ///     let response: TransactionResponse = serde_json::from_value(serde_json::json!({
+    ///         "digest": "8vHTUw1cTRc6tfv6f5BWq9vJjGmvBu8mX6x2Z1t8s5vd",
+    ///         "effects": {
+    ///             "status": { "status": "success" },
+    ///             "gasUsed": { "computationCost": "1000000", "storageCost": "2000000", "storageRebate": "3000000" },
+    ///             "transactionDigest": "8vHTUw1cTRc6tfv6f5BWq9vJjGmvBu8mX6x2Z1t8s5vd",
+    ///             "mutated": [
+    ///                 { "owner": { "Shared": { "initial_shared_version": 3 } }, "reference": { "objectId": "0x7ab1e", "version": "12", "digest": "d1" } },
+    ///                 { "owner": { "ObjectOwner": "0xf1e1d" }, "reference": { "objectId": "0xe2", "version": "12", "digest": "d2" } }
+    ///             ],
+    ///             "deleted": [{ "objectId": "0xe1", "version": "12", "digest": "d3" }],
+    ///             "unwrappedThenDeleted": [{ "objectId": "0x5ad", "version": "12", "digest": "d4" }]
+    ///         }
+    ///     }))
+    ///     .unwrap();
+    ///     let effects = response.effects.as_ref().unwrap();
+    ///     assert_eq!(effects.unwrapped_then_deleted[0].object_id, format!("0x{:0>64}", "5ad"));
+    ///     assert!(response.loaded_child_objects.is_none());
+    ///
+    ///     let mock = Arc::new(MockTransport::new().with_result(
+    ///         "sui_getLoadedChildObjects",
+    ///         serde_json::json!({ "loadedChildObjects": [
+    ///             { "objectId": "0xe1", "sequenceNumber": "11" },
+    ///             { "objectId": "0xe2", "sequenceNumber": "11" },
+    ///             { "objectId": "0xe3", "sequenceNumber": "4" }
+    ///         ]}),
+    ///     ));
+    ///     let client = SuiClient::new_with_transport(SuiClientConfig::default(), mock.clone());
+    ///     assert_eq!(response.summary(), "tx 8vHTUw… status=Success gas=0 SUI created=0 mutated=2 deleted=1 unwrapped_then_deleted=1");
+    ///     let children = response.loaded_child_objects(&client).await.unwrap();
+    ///     // 0xe3 was only read, the effects don't list it
+    ///     assert_eq!(children.len(), 3);
+    ///     assert_eq!((children[2].object_id.as_str(), children[2].sequence_number), (format!("0x{:0>64}", "e3").as_str(), 4));
+    ///     response.loaded_child_objects(&client).await.unwrap();
+    ///     assert_eq!(mock.request_count("sui_getLoadedChildObjects"), 1);
+    ///     assert!(response.summary().ends_with(" children=3"));
+    /// }
+    /// ```
+    pub async fn loaded_child_objects(
+        &self,
+        client: &crate::SuiClient,
+    ) -> Result<&[LoadedChildObject], SuiError> {
+        if let Some(children) = &self.loaded_child_objects {
+            return Ok(children);
+        }
+        self.lazy_child_objects
+            .0
+            .get_or_try_init(|| client.get_loaded_child_objects(&self.digest))
+            .await
+            .map(Vec::as_slice)
+    }
+
+    /// loaded child objects already known, without fetching them
+    pub(crate) fn known_loaded_child_objects(&self) -> Option<&[LoadedChildObject]> {
+        self.loaded_child_objects
+            .as_deref()
+            .or_else(|| self.lazy_child_objects.0.get().map(Vec::as_slice))
+    }
+
+    /// # Created StakedSui
+    ///
+    /// The StakedSui object created by a stake split, read from the object changes
+    /// when the response has them, otherwise from the effects, where a split creates
+    /// exactly one object.
+    ///
+    /// ## Returns
+    /// reference of the new StakedSui object, `None` when the transaction created none
+    pub fn created_staked_sui(&self) -> Option<ObjectRef> {
+        if let Some(changes) = &self.object_changes {
+            return changes.iter().find_map(|change| match change {
+                ObjectChange::Created {
+                    object_type,
+                    object_id,
+                    version,
+                    digest,
+                    ..
+                } if object_type
+                    .parse::<ObjectType>()
+                    .is_ok_and(|t| t.is_staked_sui()) =>
+                {
+                    Some(ObjectRef {
+                        object_id: object_id.clone(),
+                        version: *version,
+                        digest: digest.clone(),
+                    })
+                }
+                _ => None,
+            });
+        }
+        match self.effects.as_ref()?.created.as_slice() {
+            [created] => Some(created.reference.clone()),
+            _ => None,
+        }
+    }
+}
+
+/// data fetched after the response, not part of its value
+#[derive(Debug, Clone)]
+struct LazyCache<T>(tokio::sync::OnceCell<T>);
+
+impl<T> Default for LazyCache<T> {
+    fn default() -> Self {
+        Self(tokio::sync::OnceCell::new())
+    }
+}
+
+impl<T> PartialEq for LazyCache<T> {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl<T> Eq for LazyCache<T> {}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransactionEffects {
+    pub status: ExecutionStatus,
+    pub gas_used: GasCostSummary,
+    pub transaction_digest: String,
+    #[serde(default)]
+    pub created: Vec<OwnedObjectRef>,
+    #[serde(default)]
+    pub mutated: Vec<OwnedObjectRef>,
+    #[serde(default)]
+    pub deleted: Vec<ObjectRef>,
+    #[serde(default)]
+    pub unwrapped_then_deleted: Vec<ObjectRef>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ExecutionStatus {
+    pub status: String,
+    pub error: Option<String>,
+}
+
+impl ExecutionStatus {
+    /// whether the transaction executed successfully
+    pub fn is_success(&self) -> bool {
+        self.status == "success"
+    }
+    /// class of the failure, `None` on success
+    pub fn failure_class(&self) -> Option<FailureClass> {
+        if self.is_success() {
+            return None;
+        }
+        Some(FailureClass::classify(
+            self.error.as_deref().unwrap_or_default(),
+        ))
+    }
+    /// # Explain
+    ///
+    /// Decodes the execution error, see [`errors::explain`].
+    ///
+    /// ## Parameters
+    /// - plan : plan of the transaction, names the failed step when given
+    ///
+    /// ## Returns
+    /// explanation of the failure, `None` on success
+    ///
+    /// ## Example
+    /// ```rust
+    /// use sui_network_sdk::types::{ExecutionStatus, GasPlan, TransactionPlan};
+    ///
+    /// let status = ExecutionStatus {
+    ///     status: "failure".to_string(),
+    ///     error: Some("InsufficientCoinBalance in command 1".to_string()),
+    /// };
+    /// let plan = TransactionPlan {
+    ///     digest: "D1".to_string(),
+    ///     sender: "0xa11ce".to_string(),
+    ///     gas: GasPlan { payment: vec![], owner: "0xa11ce".to_string(), budget: 5_000_000, price: 1000 },
+    ///     expiration: None,
+    ///     commands: vec!["Split 1.5 SUI off the gas coin".to_string(), "Split 900 SUI off input 0".to_string()],
+    /// };
+    /// let explanation = status.explain(Some(&plan)).unwrap();
+    /// assert_eq!(explanation.step.as_deref(), Some("Split 900 SUI off input 0"));
+    /// assert_eq!(
+    ///     explanation.to_string(),
+    ///     "command 1 (Split 900 SUI off input 0) failed: a coin holds less than the amount taken from it"
+    /// );
+    /// ```
+    ///
+    /// [`errors::explain`]: crate::errors::explain
+    pub fn explain(&self, plan: Option<&TransactionPlan>) -> Option<FailureExplanation> {
+        if self.is_success() {
+            return None;
+        }
+        let explanation = crate::errors::explain(self.error.as_deref().unwrap_or_default());
+        Some(match plan {
+            Some(plan) => explanation.with_plan(plan),
+            None => explanation,
+        })
+    }
+}
+
+/// # Failure class
+///
+/// Class of a transaction failure, from the execution error of the effects or the error of
+/// a rejected submission.
+///
+/// ## Example
+/// ```rust
+/// use sui_network_sdk::types::FailureClass;
+///
+/// let abort = FailureClass::classify(
+///     r#"MoveAbort(MoveLocation { module: ModuleId { address: 5a0b, name: Identifier("pool") }, function: 3, instruction: 12, function_name: Some("swap") }, 2) in command 0"#,
+/// );
+/// assert_eq!(abort, FailureClass::MoveAbort { module: Some("pool".to_string()), code: Some(2) });
+/// assert!(!abort.is_contention());
+///
+/// let congested = FailureClass::classify("ExecutionCancelledDueToSharedObjectCongestion");
+/// assert_eq!(congested, FailureClass::SharedObjectCongestion);
+/// assert!(congested.is_contention());
+/// assert!(FailureClass::classify("Transaction timed out before reaching finality").is_contention());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FailureClass {
+    /// a Move abort, deterministic, the same transaction aborts again
+    MoveAbort {
+        module: Option<String>,
+        code: Option<u64>,
+    },
+    /// cancelled or rejected because a shared object is congested
+    SharedObjectCongestion,
+    /// the transaction expired or timed out before finality
+    Expired,
+    /// the gas budget did not cover execution
+    InsufficientGas,
+    /// any other failure
+    Other(String),
+}
+
+impl FailureClass {
+    /// classify a failure message
+    pub fn classify(message: &str) -> Self {
+        let lowercase = message.to_lowercase();
+        if message.contains("MoveAbort") {
+            // MoveAbort(MoveLocation { module: ModuleId { .., name: Identifier("m") }, .. }, code)
+            let module = message
+                .split("Identifier(\"")
+                .nth(1)
+                .and_then(|rest| rest.split('"').next())
+                .map(str::to_string);
+            let code = message
+                .rsplit_once("},")
+                .and_then(|(_, rest)| rest.split(')').next())
+                .and_then(|code| code.trim().parse().ok());
+            FailureClass::MoveAbort { module, code }
+        } else if lowercase.contains("sharedobjectcongestion")
+            || lowercase.contains("shared object congestion")
+            || lowercase.contains("toomanytransactionspendingonobject")
+            || lowercase.contains("too many transactions pending on object")
+        {
+            FailureClass::SharedObjectCongestion
+        } else if lowercase.contains("expired") || lowercase.contains("timed out") {
+            FailureClass::Expired
+        } else if lowercase.contains("insufficientgas") || lowercase.contains("insufficient gas") {
+            FailureClass::InsufficientGas
+        } else {
+            FailureClass::Other(message.to_string())
+        }
+    }
+    /// whether the failure comes from contention and the transaction may succeed when rebuilt
+    pub fn is_contention(&self) -> bool {
+        matches!(
+            self,
+            FailureClass::SharedObjectCongestion | FailureClass::Expired
+        )
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GasCostSummary {
+    #[serde(with = "string_number")]
+    pub computation_cost: u64,
+    #[serde(with = "string_number")]
+    pub storage_cost: u64,
+    #[serde(with = "string_number")]
+    pub storage_rebate: u64,
+}
+
+impl GasCostSummary {
+    /// # Gas budget
+    ///
+    /// Budget covering a transaction of this cost: the net cost, computation plus storage
+    /// minus the rebate, and at least the computation cost the rebate can't offset, raised
+    /// by the safety margin.
+    ///
+    /// ## Parameters
+    /// - margin_percent : safety margin in percent of the cost
+    ///
+    /// ## Returns
+    /// gas budget in MIST
+    ///
+    /// ## Example
+    /// ```rust
+    /// use sui_network_sdk::types::GasCostSummary;
+    ///
+    /// let cost = GasCostSummary { computation_cost: 1_000_000, storage_cost: 2_000_000, storage_rebate: 500_000 };
+    /// assert_eq!(cost.gas_budget(0), 2_500_000);
+    /// assert_eq!(cost.gas_budget(20), 3_000_000);
+    /// // a rebate over the storage cost still leaves the computation to pay
+    /// let refund = GasCostSummary { computation_cost: 1_000_000, storage_cost: 0, storage_rebate: 4_000_000 };
+    /// assert_eq!(refund.gas_budget(10), 1_100_000);
+    /// ```
+    pub fn gas_budget(&self, margin_percent: u64) -> u64 {
+        let net_cost = (self.computation_cost + self.storage_cost)
+            .saturating_sub(self.storage_rebate)
+            .max(self.computation_cost);
+        net_cost.saturating_add(net_cost.saturating_mul(margin_percent) / 100)
+    }
+
+    /// # Net cost
+    ///
+    /// ## Returns
+    /// computation plus storage minus the rebate in MIST, negative when the rebate is larger
+    ///
+    /// ## Example
+    /// ```rust
+    /// use sui_network_sdk::types::GasCostSummary;
+    ///
+    /// let refund = GasCostSummary { computation_cost: 1_000_000, storage_cost: 0, storage_rebate: 4_000_000 };
+    /// assert_eq!(refund.net_cost(), -3_000_000);
+    /// let max = GasCostSummary { computation_cost: u64::MAX, storage_cost: u64::MAX, storage_rebate: 0 };
+    /// assert_eq!(max.net_cost(), 2 * i128::from(u64::MAX));
+    /// ```
+    pub fn net_cost(&self) -> i128 {
+        i128::from(self.computation_cost) + i128::from(self.storage_cost)
+            - i128::from(self.storage_rebate)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct OwnedObjectRef {
+    pub owner: Owner,
+    pub reference: ObjectRef,
+}
+
+/// Object reference.
+///
+/// ## Example
+/// ```rust
+/// use std::collections::HashSet;
+/// use sui_network_sdk::types::ObjectRef;
+/// let object_ref = ObjectRef {
+///     object_id: "0x5".to_string(),
+///     version: 42,
+///     digest: "digest".to_string(),
+/// };
+/// let refs: HashSet<ObjectRef> = vec![object_ref.clone(), object_ref].into_iter().collect();
+/// assert_eq!(refs.len(), 1);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ObjectRef {
+    #[serde(deserialize_with = "address::deserialize")]
+    pub object_id: String,
+    #[serde(with = "string_number")]
+    pub version: u64,
+    pub digest: String,
+}
+
+/// Event emitted by a transaction.
+///
+/// # Fields
+/// - id : transaction digest and sequence number of the event
+/// - package_id : package of the module the transaction called
+/// - transaction_module : module the transaction called
+/// - sender : sender of the transaction
+/// - event_type : Move type of the event
+/// - parsed_json : event fields as JSON
+/// - bcs : BCS bytes of the event, base64 on current nodes
+/// - timestamp_ms : checkpoint time of the transaction, when the node reported it
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Event {
+    pub id: EventId,
+    #[serde(default, deserialize_with = "address::deserialize")]
+    pub package_id: String,
+    #[serde(default)]
+    pub transaction_module: String,
+    #[serde(default, deserialize_with = "address::deserialize")]
+    pub sender: String,
+    #[serde(rename = "type")]
+    pub event_type: String,
+    pub parsed_json: serde_json::Value,
+    #[serde(default)]
+    pub bcs: Option<String>,
+    #[serde(default, with = "option_string_number")]
+    pub timestamp_ms: Option<u64>,
+}
+
+#[cfg(feature = "chrono")]
+impl Event {
+    /// # Timestamp
+    ///
+    /// ## Returns
+    /// checkpoint time of the emitting transaction, when the node reported it
+    ///
+    /// ## Example
+    /// ```rust
+    /// use sui_network_sdk::types::Event;
+    /// let event: Event = serde_json::from_value(serde_json::json!({
+    ///     "id": { "txDigest": "A1", "eventSeq": "0" },
+    ///     "type": "0x2::coin::Mint",
+    ///     "parsedJson": {},
+    ///     "timestampMs": 1700000000123u64
+    /// }))
+    /// .unwrap();
+    /// assert_eq!(event.timestamp().unwrap().timestamp_millis(), 1_700_000_000_123);
+    /// ```
+    pub fn timestamp(&self) -> Option<chrono::DateTime<chrono::Utc>> {
+        self.timestamp_ms.and_then(datetime)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EventId {
+    pub tx_digest: String,
+    #[serde(with = "string_number")]
+    pub event_seq: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Coin {
+    #[serde(default)]
+    pub coin_type: String,
+    #[serde(deserialize_with = "address::deserialize")]
+    pub coin_object_id: String,
+    #[serde(with = "string_number")]
+    pub version: u64,
+    pub digest: String,
+    #[serde(with = "string_number")]
+    pub balance: u64,
+}
+
+/// coins are identified by object id and version
+impl PartialEq for Coin {
+    fn eq(&self, other: &Self) -> bool {
+        self.coin_object_id == other.coin_object_id && self.version == other.version
+    }
+}
+
+impl Eq for Coin {}
+
+impl std::hash::Hash for Coin {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.coin_object_id.hash(state);
+        self.version.hash(state);
+    }
+}
+
+/// Paginated rpc result.
+///
+/// # Fields
+/// - data : page items
+/// - next_cursor : cursor of the next page
+/// - has_next_page : whether more pages follow
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Page<T, C = String> {
+    pub data: Vec<T>,
+    pub next_cursor: Option<C>,
+    pub has_next_page: bool,
+}
+
+/// Name of a dynamic field.
+///
+/// # Fields
+/// - name_type : Move type of the name, e.g. `u64` or `0x2::object::ID`
+/// - value : name value as JSON, integers over 32 bits are strings
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DynamicFieldName {
+    #[serde(rename = "type")]
+    pub name_type: String,
+    pub value: serde_json::Value,
+}
+
+impl DynamicFieldName {
+    /// `u64` name, the value is sent as a string
+    pub fn u64(value: u64) -> Self {
+        Self {
+            name_type: "u64".to_string(),
+            value: value.to_string().into(),
+        }
+    }
+
+    /// # Address name
+    ///
+    /// ## Parameters
+    /// - address : hex address, sent normalized
+    ///
+    /// ## Returns
+    /// - Ok(DynamicFieldName) : `address` name
+    /// - Err(SuiError) : not a hex address
+    pub fn address(address: &str) -> Result<Self, SuiError> {
+        let value = address::normalize(address)
+            .ok_or_else(|| SuiError::Hex(format!("invalid address {}", address)))?;
+        Ok(Self {
+            name_type: "address".to_string(),
+            value: value.into(),
+        })
+    }
+
+    /// `0x1::string::String` name
+    pub fn string(value: &str) -> Self {
+        Self {
+            name_type: "0x1::string::String".to_string(),
+            value: value.into(),
+        }
+    }
+
+    /// # Struct name
+    ///
+    /// ## Parameters
+    /// - tag : struct type of the name
+    /// - value : the struct as JSON, fields by name with integers over 32 bits as strings
+    ///
+    /// ## Returns
+    /// name of the struct type
+    pub fn struct_(tag: &StructTag, value: serde_json::Value) -> Self {
+        Self {
+            name_type: TypeTag::Struct(Box::new(tag.clone())).to_string(),
+            value,
+        }
+    }
+}
+
+/// Kind of a dynamic field.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DynamicFieldType {
+    /// value stored in the field object, as in `Table` and `Bag`
+    DynamicField,
+    /// object stored under the field, as in `ObjectTable` and `ObjectBag`
+    DynamicObject,
+    /// kind added by a later protocol version
+    #[serde(other)]
+    Unknown,
+}
+
+/// Dynamic field of an object, an entry of `suix_getDynamicFields`.
+///
+/// # Fields
+/// - name : field name
+/// - bcs_name : BCS bytes of the name in base58, empty when the node omits them
+/// - field_type : whether the value is stored in the field or is an object
+/// - object_type : type of the object holding the value
+/// - object_id : id of the field object, or of the value object of a dynamic object field
+/// - version : object version
+/// - digest : object digest
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DynamicFieldInfo {
+    pub name: DynamicFieldName,
+    #[serde(default)]
+    pub bcs_name: String,
+    #[serde(rename = "type")]
+    pub field_type: DynamicFieldType,
+    pub object_type: String,
+    #[serde(deserialize_with = "address::deserialize")]
+    pub object_id: String,
+    #[serde(with = "string_number")]
+    pub version: u64,
+    pub digest: String,
+}
+
+/// Filter of owned objects, see [`SuiClient::get_owned_objects`](crate::SuiClient::get_owned_objects),
+/// externally tagged as on the wire (`{"StructType": "0x2::coin::Coin"}`).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ObjectFilter {
+    /// objects of a struct type, without type parameters every instantiation,
+    /// e.g. `0x2::coin::Coin` for coins of all types
+    StructType(String),
+    /// objects of types defined in a package
+    Package(String),
+    /// objects of types defined in a module
+    MoveModule { package: String, module: String },
+    /// one object
+    ObjectId(String),
+    /// objects matching every filter
+    MatchAll(Vec<ObjectFilter>),
+    /// objects matching any filter
+    MatchAny(Vec<ObjectFilter>),
+    /// objects matching no filter
+    MatchNone(Vec<ObjectFilter>),
+}
+
+/// Object data to include in object responses, only id, version and digest by default.
+/// Fields left false are omitted from requests, the node reads them as false.
+///
+/// # Fields
+/// - show_type : object type
+/// - show_owner : owner
+/// - show_previous_transaction : digest of the transaction that last wrote the object
+/// - show_display : display metadata
+/// - show_content : Move fields as JSON
+/// - show_bcs : BCS bytes
+/// - show_storage_rebate : storage rebate
+///
+/// ## Example
+/// ```rust
+/// use sui_network_sdk::types::ObjectDataOptions;
+///
+/// let options = ObjectDataOptions::default().with_type().with_content();
+/// assert_eq!(serde_json::to_string(&options).unwrap(), r#"{"showType":true,"showContent":true}"#);
+/// assert_eq!(serde_json::to_string(&ObjectDataOptions::default()).unwrap(), "{}");
+/// assert_eq!(
+///     serde_json::to_string(&ObjectDataOptions::full()).unwrap(),
+///     r#"{"showType":true,"showOwner":true,"showPreviousTransaction":true,"showDisplay":true,"showContent":true,"showBcs":true,"showStorageRebate":true}"#
+/// );
+/// assert_eq!(serde_json::to_string(&ObjectDataOptions::bcs_only()).unwrap(), r#"{"showBcs":true}"#);
+/// // responses of nodes sending every field still read back
+/// let sent: ObjectDataOptions = serde_json::from_str(r#"{"showType":true,"showBcs":false}"#).unwrap();
+/// assert_eq!(sent, ObjectDataOptions::default().with_type());
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ObjectDataOptions {
+    #[serde(default, skip_serializing_if = "is_false")]
+    pub show_type: bool,
+    #[serde(default, skip_serializing_if = "is_false")]
+    pub show_owner: bool,
+    #[serde(default, skip_serializing_if = "is_false")]
+    pub show_previous_transaction: bool,
+    #[serde(default, skip_serializing_if = "is_false")]
+    pub show_display: bool,
+    #[serde(default, skip_serializing_if = "is_false")]
+    pub show_content: bool,
+    #[serde(default, skip_serializing_if = "is_false")]
+    pub show_bcs: bool,
+    #[serde(default, skip_serializing_if = "is_false")]
+    pub show_storage_rebate: bool,
+}
+
+impl ObjectDataOptions {
+    /// every object field
+    pub fn full() -> Self {
+        Self {
+            show_type: true,
+            show_owner: true,
+            show_previous_transaction: true,
+            show_display: true,
+            show_content: true,
+            show_bcs: true,
+            show_storage_rebate: true,
+        }
+    }
+
+    /// BCS bytes only, for local decoding
+    pub fn bcs_only() -> Self {
+        Self::default().with_bcs()
+    }
+
+    /// include the object type
+    pub fn with_type(mut self) -> Self {
+        self.show_type = true;
+        self
+    }
+
+    /// include the owner
+    pub fn with_owner(mut self) -> Self {
+        self.show_owner = true;
+        self
+    }
+
+    /// include the digest of the transaction that last wrote the object
+    pub fn with_previous_transaction(mut self) -> Self {
+        self.show_previous_transaction = true;
+        self
+    }
+
+    /// include display metadata
+    pub fn with_display(mut self) -> Self {
+        self.show_display = true;
+        self
+    }
+
+    /// include Move fields as JSON
+    pub fn with_content(mut self) -> Self {
+        self.show_content = true;
+        self
+    }
+
+    /// include BCS bytes
+    pub fn with_bcs(mut self) -> Self {
+        self.show_bcs = true;
+        self
+    }
+
+    /// include the storage rebate
+    pub fn with_storage_rebate(mut self) -> Self {
+        self.show_storage_rebate = true;
+        self
+    }
+}
+
+/// Filter of transactions, see
+/// [`SuiClient::query_transaction_blocks`](crate::SuiClient::query_transaction_blocks),
+/// externally tagged as on the wire (`{"FromAddress": "0x..."}`).
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum TransactionFilter {
+    /// transactions sent by an address
+    FromAddress(String),
+    /// transactions that sent objects or coins to an address
+    ToAddress(String),
+    /// transactions taking an object as input
+    InputObject(String),
+    /// transactions that created, mutated, wrapped or deleted an object
+    ChangedObject(String),
+    /// transactions calling a Move function, every function of the package or module when
+    /// module or function is none
+    MoveFunction {
+        package: String,
+        module: Option<String>,
+        function: Option<String>,
+    },
+}
+
+/// Filter of events, see [`SuiClient::query_events`](crate::SuiClient::query_events),
+/// externally tagged as on the wire (`{"Sender": "0x..."}`).
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum EventFilter {
+    /// events of transactions sent by an address
+    Sender(String),
+    /// events of one transaction
+    Transaction(String),
+    /// events of a Move struct type, e.g. `0x3::validator::StakingRequestEvent`
+    MoveEventType(String),
+    /// events emitted by functions of a module
+    MoveModule { package: String, module: String },
+    /// events of transactions checkpointed in a time range, milliseconds since the epoch,
+    /// start inclusive and end exclusive
+    #[serde(rename_all = "camelCase")]
+    TimeRange {
+        #[serde(with = "string_number")]
+        start_time: u64,
+        #[serde(with = "string_number")]
+        end_time: u64,
+    },
+}
+
+/// Transaction data to include in transaction responses, only the digest by default.
+/// Fields left false are omitted from requests, the node reads them as false.
+///
+/// # Fields
+/// - show_input : signed transaction data
+/// - show_raw_input : BCS bytes of the signed transaction
+/// - show_effects : effects
+/// - show_events : events
+/// - show_object_changes : object changes
+/// - show_balance_changes : balance changes
+/// - show_raw_effects : BCS bytes of the effects
+///
+/// ## Example
+/// ```rust
+/// use sui_network_sdk::types::TransactionBlockResponseOptions;
+///
+/// let options = TransactionBlockResponseOptions::default().with_effects().with_events();
+/// assert_eq!(serde_json::to_string(&options).unwrap(), r#"{"showEffects":true,"showEvents":true}"#);
+/// assert_eq!(serde_json::to_string(&TransactionBlockResponseOptions::default()).unwrap(), "{}");
+/// assert_eq!(
+///     serde_json::to_string(&TransactionBlockResponseOptions::for_indexing()).unwrap(),
+///     r#"{"showInput":true,"showEffects":true,"showEvents":true,"showObjectChanges":true,"showBalanceChanges":true}"#
+/// );
+/// assert_eq!(
+///     serde_json::to_string(&TransactionBlockResponseOptions::full()).unwrap(),
+///     r#"{"showInput":true,"showRawInput":true,"showEffects":true,"showEvents":true,"showObjectChanges":true,"showBalanceChanges":true,"showRawEffects":true}"#
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransactionBlockResponseOptions {
+    #[serde(default, skip_serializing_if = "is_false")]
+    pub show_input: bool,
+    #[serde(default, skip_serializing_if = "is_false")]
+    pub show_raw_input: bool,
+    #[serde(default, skip_serializing_if = "is_false")]
+    pub show_effects: bool,
+    #[serde(default, skip_serializing_if = "is_false")]
+    pub show_events: bool,
+    #[serde(default, skip_serializing_if = "is_false")]
+    pub show_object_changes: bool,
+    #[serde(default, skip_serializing_if = "is_false")]
+    pub show_balance_changes: bool,
+    #[serde(default, skip_serializing_if = "is_false")]
+    pub show_raw_effects: bool,
+}
+
+impl TransactionBlockResponseOptions {
+    /// every transaction field, BCS bytes included
+    pub fn full() -> Self {
+        Self {
+            show_raw_input: true,
+            show_raw_effects: true,
+            ..Self::for_indexing()
+        }
+    }
+
+    /// decoded input, effects, events and changes, what an indexer stores of a transaction
+    pub fn for_indexing() -> Self {
+        Self::default()
+            .with_input()
+            .with_effects()
+            .with_events()
+            .with_object_changes()
+            .with_balance_changes()
+    }
+
+    /// include the signed transaction data
+    pub fn with_input(mut self) -> Self {
+        self.show_input = true;
+        self
+    }
+
+    /// include BCS bytes of the signed transaction
+    pub fn with_raw_input(mut self) -> Self {
+        self.show_raw_input = true;
+        self
+    }
+
+    /// include the effects
+    pub fn with_effects(mut self) -> Self {
+        self.show_effects = true;
+        self
+    }
+
+    /// include the events
+    pub fn with_events(mut self) -> Self {
+        self.show_events = true;
+        self
+    }
+
+    /// include object changes
+    pub fn with_object_changes(mut self) -> Self {
+        self.show_object_changes = true;
+        self
+    }
+
+    /// include balance changes
+    pub fn with_balance_changes(mut self) -> Self {
+        self.show_balance_changes = true;
+        self
+    }
+
+    /// include BCS bytes of the effects
+    pub fn with_raw_effects(mut self) -> Self {
+        self.show_raw_effects = true;
+        self
+    }
+}
+
+/// # Execute transaction request type
+///
+/// When `sui_executeTransactionBlock` answers.
+///
+/// # Variants
+/// - WaitForEffectsCert : once the effects are certified
+/// - WaitForLocalExecution : once the node executed the transaction too, so reads right
+///   after see its effects
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ExecuteTransactionRequestType {
+    WaitForEffectsCert,
+    #[default]
+    WaitForLocalExecution,
+}
+
+/// Limits of a table read, see [`SuiClient::read_table`](crate::SuiClient::read_table).
+///
+/// # Fields
+/// - max_entries : entries read at most, a larger table fails with `TableError::TooLarge`
+/// - page_size : dynamic fields listed per request
+/// - concurrency : pages whose values are fetched at once
+/// - key_type : only read entries whose key has this Move type, for bags with mixed keys
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TableReadOptions {
+    pub max_entries: usize,
+    pub page_size: usize,
+    pub concurrency: usize,
+    pub key_type: Option<String>,
+}
+
+impl Default for TableReadOptions {
+    fn default() -> Self {
+        Self {
+            max_entries: 10_000,
+            page_size: 50,
+            concurrency: 4,
+            key_type: None,
+        }
+    }
+}
+
+/// Coin metadata, `CoinMetadata` object of a coin type.
+///
+/// # Fields
+/// - decimals : number of decimal places of a display amount
+/// - name : coin name
+/// - symbol : coin symbol
+/// - description : coin description
+/// - icon_url : icon url, when set
+/// - id : metadata object id
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CoinMetadata {
+    pub decimals: u8,
+    #[serde(default)]
+    pub name: String,
+    #[serde(default)]
+    pub symbol: String,
+    #[serde(default)]
+    pub description: String,
+    pub icon_url: Option<String>,
+    pub id: Option<String>,
+}
+
+/// Total supply of a coin type.
+///
+/// # Fields
+/// - value : coins in circulation, in the coin's smallest unit
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Supply {
+    #[serde(with = "string_number")]
+    pub value: u128,
+}
+
+/// Balances of a bulk lookup.
+///
+/// # Fields
+/// - balances : balance by normalized address
+/// - failures : addresses whose balance could not be read, with the reason
+#[derive(Debug, Default)]
+pub struct BulkBalances {
+    pub balances: std::collections::HashMap<String, u64>,
+    pub failures: Vec<(String, SuiError)>,
+}
+
+/// Gas price statistics of recent transactions.
+///
+/// # Fields
+/// - min : lowest sampled gas price
+/// - p50 : median sampled gas price
+/// - p90 : 90th percentile sampled gas price
+/// - max : highest sampled gas price
+/// - reference_gas_price : current reference gas price
+/// - checkpoints_sampled : number of checkpoints sampled
+/// - transactions_sampled : number of transactions sampled, when 0 all percentiles
+///   are the reference gas price
+///
+/// ## Example
+/// ```rust
+/// use sui_network_sdk::types::GasPriceStats;
+/// let stats = GasPriceStats::from_prices(vec![1000, 750, 750, 5000, 800], 750, 2);
+/// assert_eq!((stats.min, stats.p50, stats.p90, stats.max), (750, 800, 5000, 5000));
+/// assert_eq!(stats.transactions_sampled, 5);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GasPriceStats {
+    pub min: u64,
+    pub p50: u64,
+    pub p90: u64,
+    pub max: u64,
+    pub reference_gas_price: u64,
+    pub checkpoints_sampled: usize,
+    pub transactions_sampled: usize,
+}
+
+impl GasPriceStats {
+    /// statistics of sampled prices, nearest rank percentiles
+    pub fn from_prices(
+        mut prices: Vec<u64>,
+        reference_gas_price: u64,
+        checkpoints_sampled: usize,
+    ) -> Self {
+        prices.sort_unstable();
+        let percentile = |p: usize| match prices.len() {
+            0 => reference_gas_price,
+            n => prices[((p * n).div_ceil(100)).clamp(1, n) - 1],
+        };
+        Self {
+            min: percentile(0),
+            p50: percentile(50),
+            p90: percentile(90),
+            max: percentile(100),
+            reference_gas_price,
+            checkpoints_sampled,
+            transactions_sampled: prices.len(),
+        }
+    }
+}
+
+/// Activity summary of an address.
+///
+/// # Fields
+/// - first_tx : digest and timestamp (ms) of the earliest transaction sent or received
+/// - last_tx : digest and timestamp (ms) of the latest transaction sent or received
+/// - total_txs_from : number of transactions sent by the address
+/// - total_txs_to : number of transactions received by the address
+/// - owned_object_count : number of objects owned by the address
+/// - sui_balance : SUI balance in MIST
+/// - truncated : whether a count stopped at the requested upper bound
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AddressSummary {
+    pub first_tx: Option<(String, u64)>,
+    pub last_tx: Option<(String, u64)>,
+    pub total_txs_from: u64,
+    pub total_txs_to: u64,
+    pub owned_object_count: u64,
+    pub sui_balance: u128,
+    pub truncated: bool,
+}
+
+/// Result of destroying zero balance coins.
+///
+/// # Fields
+/// - coin_ids : ids of the destroyed (or, in a dry run, to be destroyed) coins
+/// - storage_rebate : total storage rebate reported by the effects
+/// - digests : transaction digests, empty in a dry run
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DestroyZeroReport {
+    pub coin_ids: Vec<String>,
+    pub storage_rebate: u64,
+    pub digests: Vec<String>,
+}
+
+/// Ownership of transferred objects as seen by the node after execution, see
+/// `Trade::execute_and_confirm`.
+///
+/// # Fields
+/// - confirmed : objects the node shows owned by their recipient
+/// - unconfirmed : objects the node did not show owned by their recipient within the timeout
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ConsistencyReport {
+    pub confirmed: Vec<TransferredObject>,
+    pub unconfirmed: Vec<TransferredObject>,
+}
+
+impl ConsistencyReport {
+    /// whether every transferred object was confirmed
+    pub fn is_consistent(&self) -> bool {
+        self.unconfirmed.is_empty()
+    }
+}
+
+/// Object transferred to a recipient.
+///
+/// # Fields
+/// - object_id : object id
+/// - recipient : address the effects assign the object to
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TransferredObject {
+    pub object_id: String,
+    pub recipient: String,
+}
+
+/// Results of a dry run, see [`SuiClient::dry_run_transaction_block`].
+///
+/// # Fields
+/// - effects : effects the transaction would have, with its gas cost, nothing is committed
+/// - events : events the transaction would emit
+/// - object_changes : objects the transaction would change
+/// - balance_changes : balances the transaction would change
+///
+/// [`SuiClient::dry_run_transaction_block`]: crate::SuiClient::dry_run_transaction_block
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DryRunResult {
+    pub effects: TransactionEffects,
+    #[serde(default)]
+    pub events: Vec<Event>,
+    #[serde(default)]
+    pub object_changes: Vec<ObjectChange>,
+    #[serde(default)]
+    pub balance_changes: Vec<BalanceChange>,
+}
+
+/// Results of a dev inspect, see [`SuiClient::dev_inspect`].
+///
+/// # Fields
+/// - effects : effects the transaction would have, nothing is committed
+/// - events : events the transaction would emit
+/// - results : values of each command, in command order, empty when execution failed
+/// - error : execution error, `None` on success
+///
+/// [`SuiClient::dev_inspect`]: crate::SuiClient::dev_inspect
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DevInspectResults {
+    pub effects: TransactionEffects,
+    #[serde(default)]
+    pub events: Vec<Event>,
+    #[serde(default)]
+    pub results: Vec<DevInspectCommandResult>,
+    #[serde(default)]
+    pub error: Option<String>,
+}
+
+impl DevInspectResults {
+    /// values a command returned, empty for an unknown command
+    pub fn return_values(&self, command: usize) -> &[DevInspectValue] {
+        self.results
+            .get(command)
+            .map_or(&[], |result| result.return_values.as_slice())
+    }
+}
+
+/// Values of one command of a dev inspect.
+///
+/// # Fields
+/// - mutable_reference_outputs : arguments passed by mutable reference, with their value
+///   after the command
+/// - return_values : values the command returned
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DevInspectCommandResult {
+    #[serde(default)]
+    pub mutable_reference_outputs: Vec<DevInspectReference>,
+    #[serde(default)]
+    pub return_values: Vec<DevInspectValue>,
+}
+
+/// Value of a dev inspect, `[bytes, type]` on the wire.
+///
+/// # Fields
+/// - bcs : BCS bytes of the value, decoded by the caller
+/// - type_tag : Move type of the value
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(from = "(Vec<u8>, String)", into = "(Vec<u8>, String)")]
+pub struct DevInspectValue {
+    pub bcs: Vec<u8>,
+    pub type_tag: String,
+}
+
+impl From<(Vec<u8>, String)> for DevInspectValue {
+    fn from((bcs, type_tag): (Vec<u8>, String)) -> Self {
+        Self { bcs, type_tag }
+    }
+}
+
+impl From<DevInspectValue> for (Vec<u8>, String) {
+    fn from(value: DevInspectValue) -> Self {
+        (value.bcs, value.type_tag)
+    }
+}
+
+/// Argument passed by mutable reference in a dev inspect, `[argument, bytes, type]` on the wire.
+///
+/// # Fields
+/// - argument : argument of the command
+/// - bcs : BCS bytes of the value after the command
+/// - type_tag : Move type of the value
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(
+    from = "(Argument, Vec<u8>, String)",
+    into = "(Argument, Vec<u8>, String)"
+)]
+pub struct DevInspectReference {
+    pub argument: Argument,
+    pub bcs: Vec<u8>,
+    pub type_tag: String,
+}
+
+impl From<(Argument, Vec<u8>, String)> for DevInspectReference {
+    fn from((argument, bcs, type_tag): (Argument, Vec<u8>, String)) -> Self {
+        Self {
+            argument,
+            bcs,
+            type_tag,
+        }
+    }
+}
+
+impl From<DevInspectReference> for (Argument, Vec<u8>, String) {
+    fn from(reference: DevInspectReference) -> Self {
+        (reference.argument, reference.bcs, reference.type_tag)
+    }
+}
+
+/// Plan of a transaction, decoded from the bytes to be signed.
+///
+/// # Fields
+/// - digest : transaction digest, the one the node reports after execution
+/// - sender : sender address
+/// - gas : gas coins, owner, budget and price
+/// - expiration : last epoch the transaction may execute in, `None` if it does not expire
+/// - commands : human-readable command descriptions, see [`ProgrammableTransaction::describe`]
+///
+/// [`ProgrammableTransaction::describe`]: crate::ptb::ProgrammableTransaction::describe
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TransactionPlan {
+    pub digest: String,
+    pub sender: String,
+    pub gas: GasPlan,
+    pub expiration: Option<u64>,
+    pub commands: Vec<String>,
+}
+
+/// Gas of a transaction plan.
+///
+/// # Fields
+/// - payment : gas coins
+/// - owner : gas owner, the sender unless sponsored
+/// - budget : gas budget in MIST
+/// - price : gas price in MIST per unit
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GasPlan {
+    pub payment: Vec<ObjectRef>,
+    pub owner: String,
+    pub budget: u64,
+    pub price: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RpcRequest {
+    pub jsonrpc: String,
+    pub id: u64,
+    pub method: String,
+    pub params: Vec<serde_json::Value>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RpcResponse<T> {
+    pub jsonrpc: String,
+    /// present result, a `null` result is `Some` when `T` accepts null
+    #[serde(
+        default = "Option::default",
+        deserialize_with = "present",
+        bound(deserialize = "T: Deserialize<'de>")
+    )]
+    pub result: Option<T>,
+    pub error: Option<RpcError>,
+    /// id of the request, `None` for errors the node could not attribute to one
+    #[serde(default)]
+    pub id: Option<u64>,
+}
+
+/// deserialize a present field, keeping a null value for types accepting it
+fn present<'de, D: serde::Deserializer<'de>, T: Deserialize<'de>>(
+    deserializer: D,
+) -> Result<Option<T>, D::Error> {
+    T::deserialize(deserializer).map(Some)
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RpcError {
+    pub code: i32,
+    pub message: String,
+}
+
+/// Coin sent by the faucet, amounts and field names vary between faucet versions.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FaucetCoinInfo {
+    #[serde(with = "string_number")]
+    pub amount: u64,
+    #[serde(deserialize_with = "address::deserialize")]
+    pub id: String,
+    #[serde(alias = "transfer_tx_digest")]
+    pub transfer_tx_digest: String,
+}
+
+/// # Faucet response
+///
+/// Answer of the faucet's synchronous endpoints, `/gas` (v0, `transferredGasObjects`) and
+/// `/v2/gas` (`coins_sent` with a `status`).
+///
+/// ## Example
+/// ```rust
+/// use sui_network_sdk::types::{FaucetError, FaucetResponse};
+///
+/// // devnet v0
+/// let devnet: FaucetResponse = serde_json::from_str(r#"{
+///     "transferredGasObjects": [{ "amount": 10000000000, "id": "0x2a", "transferTxDigest": "A1" }],
+///     "error": null
+/// }"#).unwrap();
+/// let coins = devnet.into_result().unwrap();
+/// assert_eq!(coins[0].amount, 10_000_000_000);
+/// assert_eq!(coins[0].transfer_tx_digest, "A1");
+///
+/// // testnet v2
+/// let testnet: FaucetResponse = serde_json::from_str(r#"{
+///     "status": "Success",
+///     "coins_sent": [{ "amount": "1000000000", "id": "0x2b", "transfer_tx_digest": "B2" }]
+/// }"#).unwrap();
+/// assert_eq!(testnet.into_result().unwrap()[0].amount, 1_000_000_000);
+///
+/// let failed: FaucetResponse = serde_json::from_str(
+///     r#"{ "status": { "Failure": { "internal": "Too many requests from this client" } } }"#,
+/// ).unwrap();
+/// assert!(matches!(failed.into_result(), Err(FaucetError::RateLimited { .. })));
+/// let failed: FaucetResponse = serde_json::from_str(
+///     r#"{ "transferredGasObjects": [], "error": "Invalid address: 0xzz" }"#,
+/// ).unwrap();
+/// assert!(matches!(failed.into_result(), Err(FaucetError::InvalidAddress(_))));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FaucetResponse {
+    #[serde(default, alias = "transferred_gas_objects", alias = "coins_sent")]
+    pub transferred_gas_objects: Vec<FaucetCoinInfo>,
+    #[serde(default)]
+    pub error: Option<String>,
+    #[serde(default)]
+    pub status: Option<FaucetRequestStatus>,
+}
+
+impl FaucetResponse {
+    /// sent coins, or the classified faucet error
+    pub fn into_result(self) -> Result<Vec<FaucetCoinInfo>, FaucetError> {
+        if let Some(error) = self.error {
+            return Err(FaucetError::classify(None, &error, None));
+        }
+        if let Some(FaucetRequestStatus::Failure(failure)) = &self.status {
+            return Err(FaucetError::classify(None, &failure_message(failure), None));
+        }
+        Ok(self.transferred_gas_objects)
+    }
+}
+
+/// Request status of the v2 faucet, failures carry an error object.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum FaucetRequestStatus {
+    Success,
+    Failure(serde_json::Value),
+    #[serde(untagged)]
+    Unknown(serde_json::Value),
+}
+
+/// # Batch faucet response
+///
+/// Answer of the v1 `/v1/gas` endpoint, a task to poll with `/v1/status/{task}`.
+///
+/// ## Example
+/// ```rust
+/// use sui_network_sdk::types::{BatchFaucetResponse, BatchSendStatusType, BatchStatusFaucetResponse};
+///
+/// let queued: BatchFaucetResponse =
+///     serde_json::from_str(r#"{ "task": "0b6e7e1c-54b4-4a25-bff6-1c8d4bc7d9a8", "error": null }"#).unwrap();
+/// assert!(queued.task.is_some());
+///
+/// let status: BatchStatusFaucetResponse = serde_json::from_str(r#"{
+///     "status": {
+///         "status": "SUCCEEDED",
+///         "transferred_gas_objects": { "sent": [{ "amount": 1000, "id": "0x2c", "transfer_tx_digest": "C3" }] }
+///     },
+///     "error": null
+/// }"#).unwrap();
+/// let status = status.status.unwrap();
+/// assert_eq!(status.status, BatchSendStatusType::Succeeded);
+/// assert_eq!(status.transferred_gas_objects.unwrap().sent[0].amount, 1000);
+///
+/// let pending: BatchStatusFaucetResponse =
+///     serde_json::from_str(r#"{ "status": { "status": "INPROGRESS" } }"#).unwrap();
+/// assert_eq!(pending.status.unwrap().status, BatchSendStatusType::InProgress);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BatchFaucetResponse {
+    #[serde(default)]
+    pub task: Option<String>,
+    #[serde(default)]
+    pub error: Option<String>,
+}
+
+/// Answer of the v1 `/v1/status/{task}` endpoint.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BatchStatusFaucetResponse {
+    #[serde(default)]
+    pub status: Option<BatchSendStatus>,
+    #[serde(default)]
+    pub error: Option<String>,
+}
+
+/// Progress of a v1 faucet task.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BatchSendStatus {
+    pub status: BatchSendStatusType,
+    #[serde(default)]
+    pub transferred_gas_objects: Option<FaucetTransferredObjects>,
 }
 
-impl Default for SuiClientConfig {
-    fn default() -> Self {
-        Self {
-            rpc_url: devnet::RPC_URL.to_string(),
-            wss_url: devnet::WSS_URL.to_string(),
-            faucet_url: devnet::FAUCET_URL.to_string(),
+/// Coins sent by a v1 faucet task.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FaucetTransferredObjects {
+    #[serde(default)]
+    pub sent: Vec<FaucetCoinInfo>,
+}
+
+/// State of a v1 faucet task.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum BatchSendStatusType {
+    #[serde(alias = "IN_PROGRESS")]
+    InProgress,
+    Succeeded,
+    Discarded,
+    /// state added by a later faucet version
+    #[serde(other)]
+    Unknown,
+}
+
+/// # Faucet error
+///
+/// ## Example
+/// ```rust
+/// use std::time::Duration;
+/// use sui_network_sdk::types::FaucetError;
+///
+/// let error = FaucetError::classify(Some(429), "slow down", Some(Duration::from_secs(60)));
+/// assert_eq!(error, FaucetError::RateLimited { retry_after: Some(Duration::from_secs(60)) });
+/// assert!(matches!(FaucetError::classify(Some(503), "", None), FaucetError::ServiceUnavailable(_)));
+/// assert!(matches!(FaucetError::classify(Some(400), "bad", None), FaucetError::Other(_)));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FaucetError {
+    /// too many requests, retry after the hint when the faucet gave one
+    RateLimited {
+        retry_after: Option<std::time::Duration>,
+    },
+    /// the recipient address was rejected
+    InvalidAddress(String),
+    /// the faucet is down or out of funds
+    ServiceUnavailable(String),
+    /// any other faucet error
+    Other(String),
+}
+
+impl FaucetError {
+    /// classify a faucet error from the HTTP status, when known, and the error message
+    pub fn classify(
+        status: Option<u16>,
+        message: &str,
+        retry_after: Option<std::time::Duration>,
+    ) -> Self {
+        let lowercase = message.to_lowercase();
+        if status == Some(429)
+            || lowercase.contains("too many requests")
+            || lowercase.contains("rate limit")
+        {
+            FaucetError::RateLimited { retry_after }
+        } else if lowercase.contains("invalid address")
+            || lowercase.contains("invalid recipient")
+            || lowercase.contains("address")
+                && (lowercase.contains("invalid") || lowercase.contains("malformed"))
+        {
+            FaucetError::InvalidAddress(message.to_string())
+        } else if matches!(status, Some(502..=504))
+            || lowercase.contains("unavailable")
+            || lowercase.contains("insufficient")
+        {
+            FaucetError::ServiceUnavailable(message.to_string())
+        } else {
+            FaucetError::Other(message.to_string())
         }
     }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct Object {
-    pub object_id: String,
-    pub version: u64,
-    pub digest: String,
-    #[serde(rename = "type")]
-    pub object_type: String,
-    pub owner: Owner,
-    pub previous_transaction: String,
-    pub data: ObjectData,
+impl fmt::Display for FaucetError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FaucetError::RateLimited {
+                retry_after: Some(retry_after),
+            } => write!(f, "rate limited, retry after {}s", retry_after.as_secs()),
+            FaucetError::RateLimited { retry_after: None } => write!(f, "rate limited"),
+            FaucetError::InvalidAddress(e) => write!(f, "invalid address: {}", e),
+            FaucetError::ServiceUnavailable(e) => write!(f, "service unavailable: {}", e),
+            FaucetError::Other(e) => write!(f, "{}", e),
+        }
+    }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct Owner {
-    pub address_owner: Option<String>,
-    pub object_owner: Option<String>,
-    pub shared: Option<SharedOwner>,
-    pub immutable: Option<bool>,
+/// Coin amount errors of [`CoinRegistry`](crate::coin::CoinRegistry).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CoinAmountError {
+    /// the coin type has no metadata and no override
+    UnknownDecimals(String),
+    /// the amount is not a non-negative decimal number
+    Invalid(String),
+    /// the amount has more decimal places than the coin
+    TooManyDecimals { decimals: u8 },
+    /// the amount does not fit in a u64 of base units
+    Overflow,
+    /// a balance change would take the balance below zero
+    Underflow,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct SharedOwner {
-    pub initial_shared_version: u64,
+impl fmt::Display for CoinAmountError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CoinAmountError::UnknownDecimals(coin_type) => {
+                write!(f, "no decimals known for {}", coin_type)
+            }
+            CoinAmountError::Invalid(amount) => write!(f, "invalid amount {:?}", amount),
+            CoinAmountError::TooManyDecimals { decimals } => {
+                write!(f, "more than {} decimal places", decimals)
+            }
+            CoinAmountError::Overflow => write!(f, "amount overflows u64"),
+            CoinAmountError::Underflow => write!(f, "amount falls below zero"),
+        }
+    }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct ObjectData {
-    pub data_type: String,
-    pub fields: serde_json::Value,
-    pub has_public_transfer: bool,
+/// Session key errors of [`SessionWallet`](crate::session::SessionWallet).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SessionError {
+    /// the session expired at the given unix time in milliseconds
+    Expired { valid_until_ms: u64 },
+    /// the delegation uses an unknown format version
+    UnsupportedVersion(u8),
+    /// the delegation is malformed or not signed by its parent
+    InvalidDelegation(String),
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct TransactionResponse {
-    pub digest: String,
-    pub effects: TransactionEffects,
-    pub events: Vec<Event>,
+impl fmt::Display for SessionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SessionError::Expired { valid_until_ms } => {
+                write!(f, "session expired at {} ms", valid_until_ms)
+            }
+            SessionError::UnsupportedVersion(version) => {
+                write!(f, "unsupported delegation version {}", version)
+            }
+            SessionError::InvalidDelegation(e) => write!(f, "invalid delegation: {}", e),
+        }
+    }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct TransactionEffects {
-    pub status: ExecutionStatus,
-    pub gas_used: GasCostSummary,
-    pub transaction_digest: String,
-    pub mutated: Vec<OwnedObjectRef>,
+/// Table read errors of [`SuiClient::read_table`](crate::SuiClient::read_table), keys are
+/// the JSON of the field name.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TableError {
+    /// the table holds more entries than the limit
+    TooLarge { limit: usize },
+    /// the field name does not decode into the key type
+    Key { key: String, error: String },
+    /// the value of the key can't be read or does not decode into the value type
+    Value { key: String, error: String },
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct ExecutionStatus {
-    pub status: String,
+impl fmt::Display for TableError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TableError::TooLarge { limit } => write!(f, "table has more than {} entries", limit),
+            TableError::Key { key, error } => write!(f, "key {}: {}", key, error),
+            TableError::Value { key, error } => write!(f, "value of key {}: {}", key, error),
+        }
+    }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct GasCostSummary {
-    pub computation_cost: u64,
-    pub storage_cost: u64,
-    pub storage_rebate: u64,
+/// Errors of a [`LockedWallet`](crate::wallet::LockedWallet).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LockError {
+    /// the passphrase does not decrypt the key
+    WrongPassphrase,
+    /// the key is sealed, unlock it before signing
+    Locked,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct OwnedObjectRef {
-    pub owner: Owner,
-    pub reference: ObjectRef,
+impl fmt::Display for LockError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LockError::WrongPassphrase => write!(f, "wrong passphrase"),
+            LockError::Locked => write!(f, "wallet is locked"),
+        }
+    }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct ObjectRef {
-    pub object_id: String,
-    pub version: u64,
-    pub digest: String,
+/// Errors of a vanity address search, see
+/// [`Wallet::generate_vanity`](crate::wallet::Wallet::generate_vanity).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum VanityError {
+    /// the prefix is not hex
+    InvalidPrefix(String),
+    /// the prefix is longer than a search can be expected to finish
+    PrefixTooLong { len: usize, max: usize },
+    /// no address matched before the timeout
+    Timeout { attempts: u64 },
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct Event {
-    pub id: EventId,
-    #[serde(rename = "type")]
-    pub event_type: String,
-    pub parsed_json: serde_json::Value,
+impl fmt::Display for VanityError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            VanityError::InvalidPrefix(prefix) => write!(f, "prefix {} is not hex", prefix),
+            VanityError::PrefixTooLong { len, max } => {
+                write!(f, "prefix of {} chars, at most {} are allowed", len, max)
+            }
+            VanityError::Timeout { attempts } => {
+                write!(f, "no match after {} attempts", attempts)
+            }
+        }
+    }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct EventId {
-    pub tx_digest: String,
-    pub event_seq: u64,
+/// Errors of local zkLogin derivation, see [`zklogin`](crate::zklogin).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ZkLoginError {
+    /// the number is not a decimal element of the BN254 scalar field
+    InvalidFieldElement(String),
+    /// the issuer does not fit its one-byte length
+    IssuerTooLong(usize),
+    /// a claim is longer than the circuit accepts
+    ClaimTooLong {
+        claim: &'static str,
+        len: usize,
+        max: usize,
+    },
+    /// the Poseidon hash rejected its inputs
+    Hash(String),
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct Coin {
-    pub coin_object_id: String,
-    pub version: u64,
-    pub digest: String,
-    pub balance: u64,
+impl fmt::Display for ZkLoginError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ZkLoginError::InvalidFieldElement(value) => {
+                write!(f, "{} is not a BN254 field element", value)
+            }
+            ZkLoginError::IssuerTooLong(len) => {
+                write!(f, "issuer of {} bytes, at most 255 are allowed", len)
+            }
+            ZkLoginError::ClaimTooLong { claim, len, max } => {
+                write!(f, "{} of {} bytes, at most {} are allowed", claim, len, max)
+            }
+            ZkLoginError::Hash(e) => write!(f, "poseidon: {}", e),
+        }
+    }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct RpcRequest {
-    pub jsonrpc: String,
-    pub id: u64,
-    pub method: String,
-    pub params: Vec<serde_json::Value>,
+/// Errors of a wallet bundle, see
+/// [`Keystore::import_bundle`](crate::wallet::Keystore::import_bundle).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BundleError {
+    /// the bytes are not a wallet bundle
+    Malformed,
+    /// the bundle was written by a newer format version
+    UnsupportedVersion(u8),
+    /// the header's KDF iteration count is not the one of its format version
+    KdfIterations(u32),
+    /// the passphrase is wrong or the bundle was modified
+    Undecryptable,
+    /// an address of the bundle is already in the keystore
+    Collision(String),
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct RpcResponse<T> {
-    pub jsonrpc: String,
-    pub result: Option<T>,
-    pub error: Option<RpcError>,
-    pub id: u64,
+impl fmt::Display for BundleError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BundleError::Malformed => write!(f, "not a wallet bundle"),
+            BundleError::UnsupportedVersion(version) => {
+                write!(f, "unsupported bundle version {}", version)
+            }
+            BundleError::KdfIterations(iterations) => {
+                write!(f, "unsupported KDF iteration count {}", iterations)
+            }
+            BundleError::Undecryptable => {
+                write!(f, "wrong passphrase or modified bundle")
+            }
+            BundleError::Collision(address) => {
+                write!(f, "address {} is already in the keystore", address)
+            }
+        }
+    }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct RpcError {
-    pub code: i32,
-    pub message: String,
+/// Errors of a local network, see
+/// [`LocalnetGuard::start`](crate::localnet::LocalnetGuard::start).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LocalnetError {
+    /// the `sui` binary was not found at the path
+    BinaryNotFound(String),
+    /// a port of the network is taken, e.g. by another localnet
+    PortInUse(u16),
+    /// the process exited before the network answered, with the end of its log
+    Exited { status: String, log: String },
+    /// the network did not answer in time
+    Timeout { waited: std::time::Duration },
+}
+
+impl fmt::Display for LocalnetError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LocalnetError::BinaryNotFound(path) => write!(
+                f,
+                "sui binary not found at {}, install it or set SUI_BINARY",
+                path
+            ),
+            LocalnetError::PortInUse(port) => {
+                write!(f, "port {} is in use, is another localnet running?", port)
+            }
+            LocalnetError::Exited { status, log } => {
+                write!(f, "sui exited ({}) before answering: {}", status, log)
+            }
+            LocalnetError::Timeout { waited } => {
+                write!(f, "no answer after {}s", waited.as_secs())
+            }
+        }
+    }
 }
 
-#[derive(Debug)]
+/// message of a v2 faucet failure, `{"internal": "..."}` or any other shape
+fn failure_message(failure: &serde_json::Value) -> String {
+    match failure {
+        serde_json::Value::String(s) => s.clone(),
+        serde_json::Value::Object(map) => {
+            map.values().next().map(failure_message).unwrap_or_default()
+        }
+        other => other.to_string(),
+    }
+}
+
+/// Crate error.
+///
+/// Errors of underlying crates convert with `?`: BCS errors to `Bcs`, Ed25519 and
+/// fastcrypto (secp256k1) errors to `Sign`, base64 errors to `Base64`.
+///
+/// ## Example
+/// ```rust
+/// use fastcrypto::secp256k1::Secp256k1PrivateKey;
+/// use fastcrypto::traits::ToFromBytes;
+/// use sui_network_sdk::types::SuiError;
+///
+/// let bcs: Result<u64, SuiError> = bcs::from_bytes::<u64>(&[1, 2]).map_err(Into::into);
+/// assert!(matches!(bcs, Err(SuiError::Bcs(_))));
+///
+/// let ed25519 = ed25519_dalek::Signature::from_slice(&[0u8; 3]).map_err(SuiError::from);
+/// assert!(matches!(ed25519, Err(SuiError::Sign(_))));
+///
+/// let secp256k1 = Secp256k1PrivateKey::from_bytes(&[0u8; 3]).map_err(SuiError::from);
+/// assert!(matches!(secp256k1, Err(SuiError::Sign(_))));
+///
+/// use base64::Engine;
+/// let base64 = base64::prelude::BASE64_STANDARD.decode("%%").map_err(SuiError::from);
+/// assert!(matches!(base64, Err(SuiError::Base64(_))));
+/// ```
+#[derive(Debug, Clone)]
 pub enum SuiError {
     HttpRequest(String),
+    HttpConnect(String),
+    Timeout(String),
     WebSocket(String),
     Json(String),
     Hex(String),
@@ -150,12 +3855,151 @@ pub enum SuiError {
     CallContract(String),
     Gas(String),
     Sign(String),
+    Contended(Vec<String>),
+    KeyShare(KeyShareError),
+    Siws(SiwsError),
+    Ledger(LedgerError),
+    Unsupported(String),
+    Faucet(FaucetError),
+    CoinAmount(CoinAmountError),
+    Session(SessionError),
+    Table(TableError),
+    Lock(LockError),
+    Vanity(VanityError),
+    ZkLogin(ZkLoginError),
+    Bundle(BundleError),
+    Localnet(LocalnetError),
+    Bcs(String),
+    HttpStatus {
+        status: u16,
+        retry_after: Option<std::time::Duration>,
+        body_snippet: String,
+    },
+    Retried {
+        attempts: u32,
+        error: Box<SuiError>,
+    },
+}
+
+/// Sign-in message errors of [`SiwsVerifier`](crate::siws::SiwsVerifier).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SiwsError {
+    /// the message does not follow the sign-in message format
+    Malformed(String),
+    /// the message was issued for another domain
+    DomainMismatch { expected: String, found: String },
+    /// the message expired
+    Expired,
+    /// the message was issued in the future
+    NotYetValid,
+    /// the nonce was not issued or was already used
+    NonceRejected,
+    /// the signature is invalid or the signing key does not belong to the address
+    InvalidSignature(String),
+}
+
+impl fmt::Display for SiwsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SiwsError::Malformed(e) => write!(f, "malformed message: {}", e),
+            SiwsError::DomainMismatch { expected, found } => {
+                write!(f, "message for domain {}, expected {}", found, expected)
+            }
+            SiwsError::Expired => write!(f, "message expired"),
+            SiwsError::NotYetValid => write!(f, "message issued in the future"),
+            SiwsError::NonceRejected => write!(f, "nonce unknown or already used"),
+            SiwsError::InvalidSignature(e) => write!(f, "invalid signature: {}", e),
+        }
+    }
+}
+
+/// Ledger device errors of `LedgerSigner` (`ledger` feature).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LedgerError {
+    /// no Ledger device is connected
+    DeviceNotFound,
+    /// the device is locked, enter the PIN
+    Locked,
+    /// the Sui app is not open on the device
+    WrongApp,
+    /// the user rejected the request on the device
+    UserRejected,
+    /// the device answered with another status word
+    Status(u16),
+    /// communication with the device failed
+    Transport(String),
+    /// the device answer does not follow the Sui app protocol
+    InvalidResponse(String),
+    /// the derivation path is malformed
+    InvalidPath(String),
+}
+
+impl fmt::Display for LedgerError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LedgerError::DeviceNotFound => write!(f, "no device found"),
+            LedgerError::Locked => write!(f, "device locked"),
+            LedgerError::WrongApp => write!(f, "Sui app not open"),
+            LedgerError::UserRejected => write!(f, "rejected on the device"),
+            LedgerError::Status(status) => write!(f, "device status 0x{:04x}", status),
+            LedgerError::Transport(e) => write!(f, "transport error: {}", e),
+            LedgerError::InvalidResponse(e) => write!(f, "invalid response: {}", e),
+            LedgerError::InvalidPath(path) => write!(f, "invalid derivation path {}", path),
+        }
+    }
+}
+
+/// Key share errors of [`Wallet::from_key_shares`](crate::wallet::Wallet::from_key_shares).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum KeyShareError {
+    /// threshold and share count outside `2 <= threshold <= shares <= 255`
+    InvalidParameters { shares: u8, threshold: u8 },
+    /// fewer distinct shares than the threshold
+    InsufficientShares { threshold: u8, provided: usize },
+    /// shares come from different splits
+    MismatchedShares,
+    /// share at the index is malformed or fails its checksum
+    CorruptedShare(usize),
+    /// share at the index uses an unknown encoding version
+    UnsupportedVersion { index: usize, version: u8 },
+    /// the shares combine to a key other than the one that was split
+    ReconstructionFailed,
+}
+
+impl fmt::Display for KeyShareError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            KeyShareError::InvalidParameters { shares, threshold } => write!(
+                f,
+                "invalid threshold {} of {} shares, requires 2 <= threshold <= shares <= 255",
+                threshold, shares
+            ),
+            KeyShareError::InsufficientShares {
+                threshold,
+                provided,
+            } => write!(
+                f,
+                "{} distinct shares provided, {} required",
+                provided, threshold
+            ),
+            KeyShareError::MismatchedShares => write!(f, "shares come from different splits"),
+            KeyShareError::CorruptedShare(index) => write!(f, "share {} is corrupted", index),
+            KeyShareError::UnsupportedVersion { index, version } => {
+                write!(f, "share {} has unsupported version {}", index, version)
+            }
+            KeyShareError::ReconstructionFailed => {
+                write!(f, "shares do not reconstruct the original key")
+            }
+        }
+    }
 }
 
 impl fmt::Display for SuiError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             SuiError::HttpRequest(e) => write!(f, "HTTP request failed: {}", e),
+            SuiError::HttpConnect(e) => write!(f, "HTTP connection failed: {}", e),
+            SuiError::Timeout(e) => write!(f, "Request timed out: {}", e),
             SuiError::WebSocket(e) => write!(f, "WebSocket error: {}", e),
             SuiError::Json(e) => write!(f, "JSON error: {}", e),
             SuiError::Hex(e) => write!(f, "Hex error: {}", e),
@@ -167,12 +4011,134 @@ impl fmt::Display for SuiError {
             SuiError::CallContract(e) => write!(f, "Call Contract error: {}", e),
             SuiError::Gas(e) => write!(f, "Gas error: {}", e),
             SuiError::Sign(e) => write!(f, "Sign error: {}", e),
+            SuiError::Contended(ids) => {
+                write!(f, "Objects kept changing during read: {}", ids.join(", "))
+            }
+            SuiError::KeyShare(e) => write!(f, "Key share error: {}", e),
+            SuiError::Siws(e) => write!(f, "Sign-in error: {}", e),
+            SuiError::Ledger(e) => write!(f, "Ledger error: {}", e),
+            SuiError::Unsupported(e) => write!(f, "Unsupported: {}", e),
+            SuiError::Faucet(e) => write!(f, "Faucet error: {}", e),
+            SuiError::CoinAmount(e) => write!(f, "Coin amount error: {}", e),
+            SuiError::Session(e) => write!(f, "Session error: {}", e),
+            SuiError::Table(e) => write!(f, "Table error: {}", e),
+            SuiError::Lock(e) => write!(f, "Lock error: {}", e),
+            SuiError::Vanity(e) => write!(f, "Vanity error: {}", e),
+            SuiError::ZkLogin(e) => write!(f, "zkLogin error: {}", e),
+            SuiError::Bundle(e) => write!(f, "Bundle error: {}", e),
+            SuiError::Localnet(e) => write!(f, "Localnet error: {}", e),
+            SuiError::Bcs(e) => write!(f, "BCS error: {}", e),
+            SuiError::HttpStatus {
+                status,
+                retry_after,
+                body_snippet,
+            } => {
+                write!(f, "HTTP status {}", status)?;
+                if let Some(retry_after) = retry_after {
+                    write!(f, " (retry after {}s)", retry_after.as_secs())?;
+                }
+                write!(f, ": {}", body_snippet)
+            }
+            SuiError::Retried { attempts, error } => {
+                write!(f, "{} (gave up after {} attempts)", error, attempts)
+            }
         }
     }
 }
 
 impl std::error::Error for SuiError {}
 
+impl SuiError {
+    /// class of a rejected submission, `None` for errors other than RPC and transaction errors
+    pub fn failure_class(&self) -> Option<FailureClass> {
+        match self {
+            SuiError::Rpc(e) | SuiError::Transaction(e) => Some(FailureClass::classify(e)),
+            _ => None,
+        }
+    }
+
+    /// # Explain
+    ///
+    /// Decodes a node error of a known execution failure kind, e.g. a submission rejected
+    /// for a stale object version, see [`errors::explain`].
+    ///
+    /// ## Returns
+    /// explanation, `None` for other errors
+    ///
+    /// [`errors::explain`]: crate::errors::explain
+    pub fn explain(&self) -> Option<FailureExplanation> {
+        match self {
+            SuiError::Rpc(message) | SuiError::Transaction(message) => {
+                Some(crate::errors::explain(message)).filter(FailureExplanation::is_known)
+            }
+            _ => None,
+        }
+    }
+
+    /// # Root
+    ///
+    /// The error that ended the call, seen through the `Retried` wrapper of retried calls.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use sui_network_sdk::types::SuiError;
+    ///
+    /// let error = SuiError::Retried {
+    ///     attempts: 4,
+    ///     error: Box::new(SuiError::Timeout("no response within 100 ms".into())),
+    /// };
+    /// assert!(matches!(error.root(), SuiError::Timeout(_)));
+    /// assert!(error.is_timeout());
+    /// assert!(!SuiError::Rpc("Object not found".into()).is_timeout());
+    /// ```
+    pub fn root(&self) -> &SuiError {
+        match self {
+            SuiError::Retried { error, .. } => error.root(),
+            error => error,
+        }
+    }
+
+    /// whether the call got no response in time, after retries or not
+    pub fn is_timeout(&self) -> bool {
+        matches!(self.root(), SuiError::Timeout(_))
+    }
+
+    /// whether the node reported that the requested data does not exist
+    pub fn is_not_found(&self) -> bool {
+        match self.root() {
+            SuiError::Rpc(e) => {
+                let e = e.to_lowercase();
+                e.contains("not found") || e.contains("could not find") || e.contains("notexists")
+            }
+            _ => false,
+        }
+    }
+}
+
+impl From<RpcError> for SuiError {
+    fn from(err: RpcError) -> Self {
+        SuiError::Rpc(err.message)
+    }
+}
+
+impl From<bcs::Error> for SuiError {
+    fn from(err: bcs::Error) -> Self {
+        SuiError::Bcs(err.to_string())
+    }
+}
+
+impl From<ed25519_dalek::SignatureError> for SuiError {
+    fn from(err: ed25519_dalek::SignatureError) -> Self {
+        SuiError::Sign(err.to_string())
+    }
+}
+
+impl From<fastcrypto::error::FastCryptoError> for SuiError {
+    fn from(err: fastcrypto::error::FastCryptoError) -> Self {
+        SuiError::Sign(err.to_string())
+    }
+}
+
 impl From<reqwest::Error> for SuiError {
     fn from(err: reqwest::Error) -> Self {
         SuiError::HttpRequest(err.to_string())
@@ -214,3 +4180,485 @@ impl From<url::ParseError> for SuiError {
         SuiError::WebSocket(err.to_string())
     }
 }
+
+/// Retry policy of a client for throttled, unavailable or unreachable nodes.
+///
+/// Read requests failing with HTTP status 429 or 5xx, a connection error or a timeout are
+/// retried, after the `Retry-After` delay when the node sent one, otherwise after an
+/// exponential backoff from `base_delay` plus a random jitter. A `Retry-After` longer than
+/// `max_delay` is not waited for, the error is returned instead.
+///
+/// `sui_executeTransactionBlock` is retried only when the connection to the node failed,
+/// the request was never sent then. A gateway error or a timeout may come after the node
+/// received the transaction, resending it is left to the caller, who can check the
+/// digest first.
+///
+/// An error returned after retries is `SuiError::Retried` with the number of attempts
+/// and the last error.
+///
+/// # Fields
+/// - max_retries : retries after the first attempt, `0` disables retrying
+/// - base_delay : backoff of the first retry, doubled on each further retry
+/// - max_delay : longest delay waited before a retry
+/// - jitter : largest random delay added to a backoff, spreads the retries of clients
+///   failing together
+///
+/// ## Example
+/// ```rust
+/// use std::collections::VecDeque;
+/// use std::sync::{Arc, Mutex};
+/// use std::time::Duration;
+/// use futures::future::BoxFuture;
+/// use sui_network_sdk::SuiClient;
+/// use sui_network_sdk::transport::Transport;
+/// use sui_network_sdk::types::{RetryPolicy, SuiClientConfig, SuiError};
+///
+/// // a transport failing with queued errors, then answering every request
+/// #[derive(Default)]
+/// struct Flaky {
+///     errors: Mutex<VecDeque<SuiError>>,
+///     sent: Mutex<Vec<String>>,
+/// }
+///
+/// impl Transport for Flaky {
+///     fn send(&self, body: serde_json::Value) -> BoxFuture<'_, Result<serde_json::Value, SuiError>> {
+///         self.sent.lock().unwrap().push(body["method"].as_str().unwrap().to_string());
+///         let error = self.errors.lock().unwrap().pop_front();
+///         Box::pin(async move {
+///             match error {
+///                 Some(error) => Err(error),
+///                 None => Ok(serde_json::json!({ "jsonrpc": "2.0", "id": 1, "result": "4c78adac" })),
+///             }
+///         })
+///     }
+/// }
+///
+/// fn bad_gateway() -> SuiError {
+///     SuiError::HttpStatus { status: 502, retry_after: None, body_snippet: String::new() }
+/// }
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let config = SuiClientConfig {
+///         retry_policy: RetryPolicy {
+///             max_retries: 2,
+///             base_delay: Duration::from_millis(1),
+///             jitter: Duration::from_millis(1),
+///             ..Default::default()
+///         },
+///         ..Default::default()
+///     };
+///     let flaky = Arc::new(Flaky::default());
+///     let client = SuiClient::new_with_transport(config, flaky.clone());
+///
+///     // reads are retried
+///     flaky.errors.lock().unwrap().extend([bad_gateway(), SuiError::Timeout("timed out".into())]);
+///     let chain: String = client.request("sui_getChainIdentifier", vec![]).await.unwrap();
+///     assert_eq!(chain, "4c78adac");
+///     assert_eq!(flaky.sent.lock().unwrap().len(), 3);
+///
+///     // the last error tells how many attempts were made
+///     flaky.errors.lock().unwrap().extend([bad_gateway(), bad_gateway(), bad_gateway()]);
+///     let error = client.request::<String>("sui_getChainIdentifier", vec![]).await.unwrap_err();
+///     assert!(matches!(&error, SuiError::Retried { attempts: 3, error } if matches!(**error, SuiError::HttpStatus { status: 502, .. })));
+///     assert!(error.to_string().ends_with("(gave up after 3 attempts)"), "{}", error);
+///
+///     // a transaction is resent only when it never reached the node
+///     flaky.sent.lock().unwrap().clear();
+///     flaky.errors.lock().unwrap().push_back(bad_gateway());
+///     let error = client.request::<String>("sui_executeTransactionBlock", vec![]).await.unwrap_err();
+///     assert!(matches!(error, SuiError::HttpStatus { status: 502, .. }));
+///     flaky.errors.lock().unwrap().push_back(SuiError::HttpConnect("connection refused".into()));
+///     client.request::<String>("sui_executeTransactionBlock", vec![]).await.unwrap();
+///     assert_eq!(flaky.sent.lock().unwrap().len(), 3);
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RetryPolicy {
+    pub max_retries: u32,
+    pub base_delay: std::time::Duration,
+    pub max_delay: std::time::Duration,
+    pub jitter: std::time::Duration,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_retries: 3,
+            base_delay: std::time::Duration::from_millis(500),
+            max_delay: std::time::Duration::from_secs(30),
+            jitter: std::time::Duration::from_millis(100),
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// policy that never retries
+    pub fn none() -> Self {
+        Self {
+            max_retries: 0,
+            ..Self::default()
+        }
+    }
+
+    /// exponential backoff of retry `attempt` (0 based), capped at `max_delay`
+    pub fn backoff(&self, attempt: u32) -> std::time::Duration {
+        self.base_delay
+            .saturating_mul(2u32.saturating_pow(attempt))
+            .min(self.max_delay)
+    }
+
+    /// # Delay
+    ///
+    /// ## Parameters
+    /// - attempt : retry number, 0 based
+    /// - error : error of the last attempt
+    /// - idempotent : whether resending the request is harmless, false for transactions
+    ///
+    /// ## Returns
+    /// delay before the retry, `None` when the error is not retried
+    pub fn delay(
+        &self,
+        attempt: u32,
+        error: &SuiError,
+        idempotent: bool,
+    ) -> Option<std::time::Duration> {
+        if attempt >= self.max_retries {
+            return None;
+        }
+        match error {
+            // the request was never sent
+            SuiError::HttpConnect(_) => Some(self.jittered_backoff(attempt)),
+            _ if !idempotent => None,
+            SuiError::HttpRequest(_) | SuiError::Timeout(_) => Some(self.jittered_backoff(attempt)),
+            // 501 and 505 don't go away on their own
+            SuiError::HttpStatus {
+                status: 429 | 500 | 502..=504 | 506..=599,
+                retry_after,
+                ..
+            } => match retry_after {
+                Some(retry_after) if *retry_after > self.max_delay => None,
+                Some(retry_after) => Some(*retry_after),
+                None => Some(self.jittered_backoff(attempt)),
+            },
+            _ => None,
+        }
+    }
+
+    /// backoff of retry `attempt` with a random part of the jitter added
+    fn jittered_backoff(&self, attempt: u32) -> std::time::Duration {
+        let jitter = if self.jitter.is_zero() {
+            std::time::Duration::ZERO
+        } else {
+            rand::Rng::random_range(&mut rand::rng(), std::time::Duration::ZERO..=self.jitter)
+        };
+        (self.backoff(attempt) + jitter).min(self.max_delay)
+    }
+}
+
+/// Capabilities of the connected fullnode, detected by probing it once.
+///
+/// # Fields
+/// - earliest_checkpoint : lowest checkpoint found on the node, `0` on archival nodes.
+/// - earliest_checkpoint_lower_bound : checkpoints below it are known to be pruned, equal to
+///   `earliest_checkpoint` unless the search ran out of probes first.
+/// - has_subscriptions : whether the node advertises websocket subscription methods.
+/// - api_version : rpc api version reported by `rpc.discover`.
+/// - chain_identifier : chain identifier of the node's network.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NodeCapabilities {
+    pub earliest_checkpoint: u64,
+    #[serde(default)]
+    pub earliest_checkpoint_lower_bound: u64,
+    pub has_subscriptions: bool,
+    pub api_version: Option<String>,
+    #[serde(default)]
+    pub chain_identifier: Option<String>,
+}
+
+impl NodeCapabilities {
+    /// whether the node has pruned part of the chain history
+    pub fn is_pruned(&self) -> bool {
+        self.earliest_checkpoint > 0
+    }
+
+    /// whether `earliest_checkpoint` is the exact start of the node's history rather than
+    /// the upper bound of an unfinished search
+    pub fn is_earliest_checkpoint_exact(&self) -> bool {
+        self.earliest_checkpoint_lower_bound == self.earliest_checkpoint
+    }
+}
+
+/// # Protocol config
+///
+/// Protocol config of a version, see [`SuiClient::get_protocol_config`].
+///
+/// # Fields
+/// - min_supported_protocol_version : oldest version the node supports
+/// - max_supported_protocol_version : newest version the node supports
+/// - protocol_version : version of the config
+/// - feature_flags : feature flags by name
+/// - attributes : attributes by name, `None` when unset in the version
+///
+/// ## Example
+/// ```rust
+/// use sui_network_sdk::types::{ProtocolConfig, ProtocolConfigValue};
+///
+/// let config: ProtocolConfig = serde_json::from_value(serde_json::json!({
+///     "minSupportedProtocolVersion": "1",
+///     "maxSupportedProtocolVersion": "70",
+///     "protocolVersion": "70",
+///     "featureFlags": { "zklogin_auth": true, "enable_effects_v2": false },
+///     "attributes": {
+///         "max_tx_size_bytes": { "u64": "131072" },
+///         "max_programmable_tx_commands": { "u32": "1024" },
+///         "gas_rounding_step": { "u16": "1000" },
+///         "storage_gas_price_ratio": { "f64": "0.5" },
+///         "max_tx_gas": { "u64": "50000000000" },
+///         "random_beacon_reduction_lower_bound": null
+///     }
+/// }))
+/// .unwrap();
+/// assert_eq!(config.max_supported_protocol_version, 70);
+/// assert!(config.supports(65) && !config.supports(71));
+/// assert!(config.feature_enabled("zklogin_auth"));
+/// assert!(!config.feature_enabled("enable_effects_v2") && !config.feature_enabled("unknown"));
+/// assert_eq!(config.attribute("max_programmable_tx_commands"), Some(&ProtocolConfigValue::U32(1024)));
+/// assert_eq!(config.attribute_u64("gas_rounding_step"), Some(1000));
+/// assert_eq!(config.attribute("storage_gas_price_ratio").and_then(ProtocolConfigValue::as_f64), Some(0.5));
+/// assert!(config.attribute("random_beacon_reduction_lower_bound").is_none());
+/// ```
+///
+/// [`SuiClient::get_protocol_config`]: crate::SuiClient::get_protocol_config
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProtocolConfig {
+    #[serde(with = "string_number")]
+    pub min_supported_protocol_version: u64,
+    #[serde(with = "string_number")]
+    pub max_supported_protocol_version: u64,
+    #[serde(with = "string_number")]
+    pub protocol_version: u64,
+    #[serde(default)]
+    pub feature_flags: std::collections::BTreeMap<String, bool>,
+    #[serde(default)]
+    pub attributes: std::collections::BTreeMap<String, Option<ProtocolConfigValue>>,
+}
+
+impl ProtocolConfig {
+    /// whether the node supports the protocol version
+    pub fn supports(&self, version: u64) -> bool {
+        (self.min_supported_protocol_version..=self.max_supported_protocol_version)
+            .contains(&version)
+    }
+
+    /// whether the feature flag is set, false for unknown flags
+    pub fn feature_enabled(&self, name: &str) -> bool {
+        self.feature_flags.get(name).copied().unwrap_or(false)
+    }
+
+    /// value of an attribute, `None` when unknown or unset
+    pub fn attribute(&self, name: &str) -> Option<&ProtocolConfigValue> {
+        self.attributes.get(name)?.as_ref()
+    }
+
+    /// integer value of an attribute
+    pub fn attribute_u64(&self, name: &str) -> Option<u64> {
+        self.attribute(name)?.as_u64()
+    }
+}
+
+/// # Protocol config value
+///
+/// Value of a protocol config attribute, the node sends each as a string tagged with its
+/// type, e.g. `{"u64": "131072"}`.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ProtocolConfigValue {
+    U16(#[serde(with = "string_number")] u16),
+    U32(#[serde(with = "string_number")] u32),
+    U64(#[serde(with = "string_number")] u64),
+    F64(#[serde(with = "string_number")] f64),
+    Bool(#[serde(with = "string_number")] bool),
+}
+
+impl ProtocolConfigValue {
+    /// integer value, `None` for floats and booleans
+    pub fn as_u64(&self) -> Option<u64> {
+        match *self {
+            ProtocolConfigValue::U16(value) => Some(value.into()),
+            ProtocolConfigValue::U32(value) => Some(value.into()),
+            ProtocolConfigValue::U64(value) => Some(value),
+            ProtocolConfigValue::F64(_) | ProtocolConfigValue::Bool(_) => None,
+        }
+    }
+
+    /// numeric value, integers included
+    pub fn as_f64(&self) -> Option<f64> {
+        match *self {
+            ProtocolConfigValue::F64(value) => Some(value),
+            ProtocolConfigValue::Bool(_) => None,
+            _ => self.as_u64().map(|value| value as f64),
+        }
+    }
+
+    /// boolean value
+    pub fn as_bool(&self) -> Option<bool> {
+        match *self {
+            ProtocolConfigValue::Bool(value) => Some(value),
+            _ => None,
+        }
+    }
+}
+
+/// Transaction limits of the node's protocol version, see
+/// [`SuiClient::transaction_limits`](crate::SuiClient::transaction_limits).
+///
+/// # Fields
+/// - max_tx_size_bytes : largest serialized transaction, `max_tx_size_bytes`
+/// - max_input_objects : object inputs at most, gas coins included, `max_input_objects`
+/// - max_commands : commands of a programmable transaction at most, `max_programmable_tx_commands`
+/// - max_tx_gas : largest gas budget in MIST, `max_tx_gas`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TransactionLimits {
+    pub max_tx_size_bytes: u64,
+    pub max_input_objects: u64,
+    pub max_commands: u64,
+    pub max_tx_gas: u64,
+}
+
+impl Default for TransactionLimits {
+    /// limits of current mainnet protocol versions
+    fn default() -> Self {
+        Self {
+            max_tx_size_bytes: 128 * 1024,
+            max_input_objects: 2048,
+            max_commands: 1024,
+            max_tx_gas: 50_000_000_000,
+        }
+    }
+}
+
+impl TransactionLimits {
+    /// # From protocol config
+    ///
+    /// ## Parameters
+    /// - config : `sui_getProtocolConfig` result, attributes it lacks keep their default
+    ///
+    /// ## Returns
+    /// limits of the protocol config
+    pub fn from_protocol_config(config: &serde_json::Value) -> Self {
+        let attribute = |name: &str| {
+            config["attributes"][name]
+                .as_object()
+                .and_then(|value| value.values().next())
+                .and_then(|value| value.as_str())
+                .and_then(|value| value.parse::<u64>().ok())
+        };
+        let defaults = Self::default();
+        Self {
+            max_tx_size_bytes: attribute("max_tx_size_bytes").unwrap_or(defaults.max_tx_size_bytes),
+            max_input_objects: attribute("max_input_objects").unwrap_or(defaults.max_input_objects),
+            max_commands: attribute("max_programmable_tx_commands")
+                .unwrap_or(defaults.max_commands),
+            max_tx_gas: attribute("max_tx_gas").unwrap_or(defaults.max_tx_gas),
+        }
+    }
+
+    /// # Check
+    ///
+    /// Checks built transaction bytes against the limits before they are signed. The gas
+    /// budget, commands and input objects are only checked when the bytes decode as a
+    /// programmable transaction.
+    ///
+    /// ## Parameters
+    /// - tx_bytes : BCS `TransactionData`
+    ///
+    /// ## Returns
+    /// - Ok(()) : the transaction is within the limits
+    /// - Err(SuiError::Transaction) : the measured and allowed numbers, and how many commands
+    ///   to split off into another transaction
+    ///
+    /// ## Example
+    /// ```rust
+    /// use sui_network_sdk::ptb::{Argument, ProgrammableTransactionBuilder, TransactionData};
+    /// use sui_network_sdk::types::TransactionLimits;
+    ///
+    /// let mut builder = ProgrammableTransactionBuilder::new();
+    /// for _ in 0..40 {
+    ///     let coin = builder.split_coins(Argument::GasCoin, &[1000]).unwrap();
+    ///     builder.transfer_objects(coin, "0xa11ce").unwrap();
+    /// }
+    /// let data = TransactionData::new_programmable("0xa1", vec![], builder.finish(), 5_000_000, 1000).unwrap();
+    /// let tx_bytes = data.to_bytes().unwrap();
+    /// assert!(TransactionLimits::default().check(&tx_bytes).is_ok());
+    ///
+    /// let limits = TransactionLimits { max_commands: 64, ..Default::default() };
+    /// let error = limits.check(&tx_bytes).unwrap_err().to_string();
+    /// assert!(error.contains("80 commands, at most 64 are allowed; split off at least 16 commands"));
+    ///
+    /// let limits = TransactionLimits { max_tx_size_bytes: tx_bytes.len() as u64 / 2, ..Default::default() };
+    /// let error = limits.check(&tx_bytes).unwrap_err().to_string();
+    /// assert!(error.contains(&format!("{} bytes, at most {} are allowed", tx_bytes.len(), tx_bytes.len() / 2)));
+    ///
+    /// let limits = TransactionLimits { max_tx_gas: 1_000_000, ..Default::default() };
+    /// let error = limits.check(&tx_bytes).unwrap_err().to_string();
+    /// assert!(error.contains("gas budget of 5000000 MIST, at most 1000000 are allowed"));
+    /// ```
+    pub fn check(&self, tx_bytes: &[u8]) -> Result<(), SuiError> {
+        let size = tx_bytes.len() as u64;
+        let Ok(data) = crate::ptb::TransactionData::from_bytes(tx_bytes) else {
+            if size > self.max_tx_size_bytes {
+                return Err(SuiError::Transaction(format!(
+                    "transaction size check: transaction has {} bytes, at most {} are allowed",
+                    size, self.max_tx_size_bytes
+                )));
+            }
+            return Ok(());
+        };
+        if data.gas_budget() > self.max_tx_gas {
+            return Err(SuiError::Transaction(format!(
+                "gas budget check: transaction has a gas budget of {} MIST, at most {} are allowed",
+                data.gas_budget(),
+                self.max_tx_gas
+            )));
+        }
+        let pt = data.programmable_transaction();
+        let commands = pt.commands.len() as u64;
+        if commands > self.max_commands {
+            return Err(SuiError::Transaction(format!(
+                "command limit check: transaction has {} commands, at most {} are allowed; split off at least {} commands",
+                commands,
+                self.max_commands,
+                commands - self.max_commands
+            )));
+        }
+        let objects = pt
+            .inputs
+            .iter()
+            .filter(|input| matches!(input, crate::ptb::CallArg::Object(_)))
+            .count() as u64
+            + data.gas_payment().len() as u64;
+        if objects > self.max_input_objects {
+            return Err(SuiError::Transaction(format!(
+                "input object limit check: transaction has {} input objects, at most {} are allowed; split off commands using at least {} of them",
+                objects,
+                self.max_input_objects,
+                objects - self.max_input_objects
+            )));
+        }
+        if size > self.max_tx_size_bytes {
+            // commands are assumed to be of similar size
+            let fitting = commands * self.max_tx_size_bytes / size;
+            return Err(SuiError::Transaction(format!(
+                "transaction size check: transaction has {} bytes, at most {} are allowed; split off about {} of {} commands",
+                size,
+                self.max_tx_size_bytes,
+                commands - fitting,
+                commands
+            )));
+        }
+        Ok(())
+    }
+}
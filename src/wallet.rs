@@ -1,24 +1,44 @@
-use crate::types::SuiError;
+use crate::audit::{AuditedTransaction, now_ms};
+use crate::listener::spawn_named;
+use crate::ptb::TransactionData;
+use crate::types::{BundleError, KeyShareError, LockError, SuiError, VanityError};
+use crate::zklogin::ZKLOGIN_FLAG;
 use base64::Engine;
 use base64::prelude::BASE64_STANDARD;
-use ed25519_dalek::{Signature, VerifyingKey};
+use ed25519_dalek::{Signature, Signer as _, SigningKey, VerifyingKey};
+use fastcrypto::hash::{Blake2b256, HashFunction};
+use fastcrypto::secp256k1::{Secp256k1PrivateKey, Secp256k1PublicKey, Secp256k1Signature};
+use fastcrypto::secp256r1::{Secp256r1PublicKey, Secp256r1Signature};
+use fastcrypto::traits::{ToFromBytes, VerifyingKey as _};
+use futures::future::{self, BoxFuture};
 use rand::Rng;
 use rand::rng;
+use ring::aead::{AES_256_GCM, Aad, LessSafeKey, Nonce, UnboundKey};
+use ring::pbkdf2;
 use serde::{Deserialize, Serialize};
 use sha3::{Digest, Sha3_256};
+use sharks::{Share, Sharks};
 use std::collections::HashMap;
 use std::fs;
+use std::num::NonZeroU32;
 use std::path::Path;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, Weak, mpsc};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+use zeroize::{Zeroize, Zeroizing};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Keystore {
     keys: HashMap<String, String>,
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    aliases: HashMap<String, String>,
 }
 
 impl Keystore {
     pub fn new() -> Self {
         Self {
             keys: HashMap::new(),
+            aliases: HashMap::new(),
         }
     }
     pub fn load_from_file<P: AsRef<Path>>(path: P) -> Result<Self, SuiError> {
@@ -47,8 +67,252 @@ impl Keystore {
         self.keys.len()
     }
     pub fn remove_key(&mut self, address: &str) -> Option<String> {
+        self.aliases.remove(address);
         self.keys.remove(address)
     }
+    /// set the alias of an address, e.g. `hot-wallet`
+    pub fn set_alias(&mut self, address: &str, alias: String) {
+        self.aliases.insert(address.to_string(), alias);
+    }
+    /// alias of an address
+    pub fn get_alias(&self, address: &str) -> Option<&String> {
+        self.aliases.get(address)
+    }
+
+    /// # Export bundle
+    ///
+    /// Encrypts the keys and aliases into a single versioned blob, e.g. to move a bot to
+    /// another host. The key is derived with PBKDF2-HMAC-SHA256 and the blob sealed with
+    /// AES-256-GCM as in [`Wallet::lock`], the header is authenticated with the payload so
+    /// the whole bundle is covered. Deriving the key takes a noticeable moment by design.
+    ///
+    /// ## Parameters
+    /// - passphrase : passphrase needed to import
+    ///
+    /// ## Returns
+    /// - Ok(Vec<u8>) : bundle
+    /// - Err(SuiError) : encryption failed
+    pub fn export_bundle(&self, passphrase: &str) -> Result<Vec<u8>, SuiError> {
+        let created_at_ms = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |elapsed| elapsed.as_millis() as u64);
+        let payload = BundlePayload {
+            keys: self.keys.clone(),
+            aliases: self.aliases.clone(),
+            created_at_ms,
+        };
+        let mut sealed = Zeroizing::new(serde_json::to_vec(&payload)?);
+        let mut salt = [0u8; 16];
+        let mut nonce = [0u8; 12];
+        rng().fill(&mut salt);
+        rng().fill(&mut nonce);
+        let mut bundle = Vec::with_capacity(BUNDLE_HEADER_LEN + sealed.len() + 16);
+        bundle.extend_from_slice(&BUNDLE_MAGIC);
+        bundle.push(BUNDLE_VERSION);
+        bundle.extend_from_slice(&BUNDLE_V1_KDF_ITERATIONS.to_be_bytes());
+        bundle.extend_from_slice(&salt);
+        bundle.extend_from_slice(&nonce);
+        sealing_key(passphrase, &salt, BUNDLE_V1_KDF_ITERATIONS)?
+            .seal_in_place_append_tag(
+                Nonce::assume_unique_for_key(nonce),
+                Aad::from(&bundle[..]),
+                &mut *sealed,
+            )
+            .map_err(|_| SuiError::Sign("Bundle encryption error".to_string()))?;
+        bundle.extend_from_slice(&sealed);
+        Ok(bundle)
+    }
+
+    /// # Import bundle
+    ///
+    /// Adds the keys and aliases of a bundle of [`Keystore::export_bundle`]. Nothing is
+    /// added when the bundle is rejected. Deriving the key takes a noticeable moment by
+    /// design, call it from a blocking context in async code.
+    ///
+    /// ## Parameters
+    /// - bundle : bundle bytes
+    /// - passphrase : passphrase the bundle was exported with
+    /// - merge_policy : handling of addresses already in the keystore
+    ///
+    /// ## Returns
+    /// - Ok(BundleImport) : imported and skipped addresses
+    /// - Err(SuiError::Bundle) : `Malformed`, `UnsupportedVersion`, `KdfIterations` for a
+    ///   header not of its version's key derivation, checked before any key is derived,
+    ///   `Undecryptable` for a wrong passphrase or a modified bundle, `Collision` under
+    ///   [`MergePolicy::Error`]
+    ///
+    /// ## Example
+    /// ```rust
+    /// use sui_network_sdk::types::{BundleError, SuiError};
+    /// use sui_network_sdk::wallet::{Keystore, MergePolicy, Wallet};
+    ///
+    /// // a bundle of the first format version, kept in the repository
+    /// let v1 = include_bytes!(concat!(env!("CARGO_MANIFEST_DIR"), "/fixtures/wallet_bundle_v1.bin"));
+    /// let wallet = Wallet::from_private_key(&[7u8; 32]).unwrap();
+    /// let mut keystore = Keystore::new();
+    /// let import = keystore.import_bundle(v1, "fixture passphrase", MergePolicy::Error).unwrap();
+    /// assert_eq!(import.imported, [wallet.get_address().to_string()]);
+    /// assert!(import.created_at_ms > 1_700_000_000_000);
+    /// assert_eq!(keystore.get_key(wallet.get_address()).unwrap(), &wallet.export_base64_private_key());
+    /// assert_eq!(keystore.get_alias(wallet.get_address()).unwrap(), "hot-wallet");
+    ///
+    /// // a second key, exported and imported back next to the first
+    /// let other = Wallet::new().unwrap();
+    /// keystore.add_key(other.get_address().to_string(), other.export_base64_private_key());
+    /// let bundle = keystore.export_bundle("new host").unwrap();
+    /// let mut target = Keystore::new();
+    /// target.add_key(other.get_address().to_string(), "stale".to_string());
+    /// let err = target.import_bundle(&bundle, "new host", MergePolicy::Error).unwrap_err();
+    /// assert!(matches!(err, SuiError::Bundle(BundleError::Collision(_))));
+    /// assert_eq!(target.len(), 1);
+    /// let import = target.import_bundle(&bundle, "new host", MergePolicy::Skip).unwrap();
+    /// assert_eq!((import.imported.len(), import.skipped.len()), (1, 1));
+    /// assert_eq!(target.get_key(other.get_address()).unwrap(), "stale");
+    ///
+    /// // the header is covered as well as the payload
+    /// let mut modified = bundle.clone();
+    /// modified[10] ^= 1;
+    /// assert!(matches!(
+    ///     target.import_bundle(&modified, "new host", MergePolicy::Overwrite),
+    ///     Err(SuiError::Bundle(BundleError::Undecryptable))
+    /// ));
+    /// let mut newer = bundle.clone();
+    /// newer[4] = 2;
+    /// assert!(matches!(
+    ///     target.import_bundle(&newer, "new host", MergePolicy::Overwrite),
+    ///     Err(SuiError::Bundle(BundleError::UnsupportedVersion(2)))
+    /// ));
+    ///
+    /// // an unauthenticated iteration count can't stall the import
+    /// let mut costly = bundle.clone();
+    /// costly[5..9].copy_from_slice(&u32::MAX.to_be_bytes());
+    /// let started = std::time::Instant::now();
+    /// assert!(matches!(
+    ///     target.import_bundle(&costly, "new host", MergePolicy::Overwrite),
+    ///     Err(SuiError::Bundle(BundleError::KdfIterations(u32::MAX)))
+    /// ));
+    /// assert!(started.elapsed() < std::time::Duration::from_millis(100));
+    /// ```
+    pub fn import_bundle(
+        &mut self,
+        bundle: &[u8],
+        passphrase: &str,
+        merge_policy: MergePolicy,
+    ) -> Result<BundleImport, SuiError> {
+        if bundle.len() < BUNDLE_HEADER_LEN || bundle[..4] != BUNDLE_MAGIC {
+            return Err(SuiError::Bundle(BundleError::Malformed));
+        }
+        let (header, sealed) = bundle.split_at(BUNDLE_HEADER_LEN);
+        if header[4] != BUNDLE_VERSION {
+            return Err(SuiError::Bundle(BundleError::UnsupportedVersion(header[4])));
+        }
+        // the header is only authenticated once the key is derived, the count must be
+        // checked before
+        let iterations = u32::from_be_bytes([header[5], header[6], header[7], header[8]]);
+        if iterations != BUNDLE_V1_KDF_ITERATIONS {
+            return Err(SuiError::Bundle(BundleError::KdfIterations(iterations)));
+        }
+        let salt = &header[9..25];
+        let nonce: [u8; 12] = header[25..37]
+            .try_into()
+            .map_err(|_| SuiError::Bundle(BundleError::Malformed))?;
+        let mut sealed = Zeroizing::new(sealed.to_vec());
+        let payload = sealing_key(passphrase, salt, iterations)?
+            .open_in_place(
+                Nonce::assume_unique_for_key(nonce),
+                Aad::from(header),
+                &mut sealed,
+            )
+            .map_err(|_| SuiError::Bundle(BundleError::Undecryptable))?;
+        let mut payload: BundlePayload = serde_json::from_slice(payload)
+            .map_err(|_| SuiError::Bundle(BundleError::Malformed))?;
+        if merge_policy == MergePolicy::Error
+            && let Some(address) = payload.keys.keys().find(|a| self.keys.contains_key(*a))
+        {
+            return Err(SuiError::Bundle(BundleError::Collision(address.clone())));
+        }
+        let mut import = BundleImport {
+            created_at_ms: payload.created_at_ms,
+            ..BundleImport::default()
+        };
+        for (address, mut key) in std::mem::take(&mut payload.keys) {
+            if merge_policy == MergePolicy::Skip && self.keys.contains_key(&address) {
+                key.zeroize();
+                import.skipped.push(address);
+                continue;
+            }
+            match payload.aliases.get(&address) {
+                Some(alias) => self.aliases.insert(address.clone(), alias.clone()),
+                None => self.aliases.remove(&address),
+            };
+            if let Some(mut replaced) = self.keys.insert(address.clone(), key) {
+                replaced.zeroize();
+            }
+            import.imported.push(address);
+        }
+        import.imported.sort();
+        import.skipped.sort();
+        Ok(import)
+    }
+}
+
+/// magic bytes of a wallet bundle
+const BUNDLE_MAGIC: [u8; 4] = *b"SUIW";
+/// format version of the bundles written
+const BUNDLE_VERSION: u8 = 1;
+/// PBKDF2-HMAC-SHA256 iterations of version 1 bundles
+const BUNDLE_V1_KDF_ITERATIONS: u32 = 600_000;
+/// magic, version, KDF iterations, salt and nonce
+const BUNDLE_HEADER_LEN: usize = 4 + 1 + 4 + 16 + 12;
+
+/// encrypted content of a wallet bundle
+#[derive(Serialize, Deserialize)]
+struct BundlePayload {
+    keys: HashMap<String, String>,
+    aliases: HashMap<String, String>,
+    created_at_ms: u64,
+}
+
+impl Drop for BundlePayload {
+    fn drop(&mut self) {
+        for key in self.keys.values_mut() {
+            key.zeroize();
+        }
+    }
+}
+
+/// # Merge policy
+///
+/// Handling of bundle addresses already in the keystore, see [`Keystore::import_bundle`].
+///
+/// # Variants
+/// - Skip : keep the keystore's key and alias
+/// - Overwrite : replace them with the bundle's
+/// - Error : reject the whole bundle
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MergePolicy {
+    Skip,
+    Overwrite,
+    Error,
+}
+
+/// Result of [`Keystore::import_bundle`].
+///
+/// # Fields
+/// - imported : addresses added or overwritten, sorted
+/// - skipped : addresses kept as they were under [`MergePolicy::Skip`], sorted
+/// - created_at_ms : export time of the bundle in milliseconds
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct BundleImport {
+    pub imported: Vec<String>,
+    pub skipped: Vec<String>,
+    pub created_at_ms: u64,
+}
+
+impl Default for Keystore {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 #[derive(Clone)]
@@ -93,22 +357,15 @@ impl Ed25519KeyPair {
         })
     }
     pub fn sign(&self, message: &[u8]) -> Vec<u8> {
-        let mut signature = Vec::with_capacity(64);
-        signature.extend_from_slice(&self.private_key[..32]);
-        let mut hasher = Sha3_256::new();
-        hasher.update(message);
-        let hash = hasher.finalize();
-        signature.extend_from_slice(&hash[..32]);
-        signature.truncate(64);
-        signature
+        SigningKey::from_bytes(&self.private_key)
+            .sign(message)
+            .to_bytes()
+            .to_vec()
     }
     fn create_public_key(private_key: &[u8; 32]) -> [u8; 32] {
-        let mut public_key = [0u8; 32];
-        let mut hasher = Sha3_256::new();
-        hasher.update(private_key);
-        let hash = hasher.finalize();
-        public_key.copy_from_slice(&hash[..32]);
-        public_key
+        SigningKey::from_bytes(private_key)
+            .verifying_key()
+            .to_bytes()
     }
     pub fn get_private_key(&self) -> [u8; 32] {
         self.private_key
@@ -123,10 +380,12 @@ impl Ed25519KeyPair {
 /// # Fields
 /// - address: sui wallet address
 /// - keypair: Ed25519 key pair, Used when signing.
+/// - audit: signing audit, see [`Wallet::with_audit`]
 #[derive(Debug, Clone)]
 pub struct Wallet {
     pub address: String,
     keypair: Ed25519KeyPair,
+    audit: AuditSlot,
 }
 
 impl Wallet {
@@ -134,30 +393,258 @@ impl Wallet {
     pub fn new() -> Result<Self, SuiError> {
         let keypair = Ed25519KeyPair::generate()?;
         let address = Self::address_from_public_key_bytes(&keypair.public_key);
-        Ok(Self { address, keypair })
+        Ok(Self {
+            address,
+            keypair,
+            audit: AuditSlot::default(),
+        })
     }
     /// create new wallet from private key
     pub fn from_private_key(private_key: &[u8]) -> Result<Self, SuiError> {
         let keypair = Ed25519KeyPair::from_private_key(private_key)?;
         let address = Self::address_from_public_key_bytes(&keypair.public_key);
-        Ok(Self { address, keypair })
+        Ok(Self {
+            address,
+            keypair,
+            audit: AuditSlot::default(),
+        })
     }
-    /// create new wallet from base64 private key
+    /// create new wallet from base64 private key,
+    /// accepts both raw 32 byte keys and Sui keystore keys prefixed with the scheme flag
     pub fn from_base64_private_key(base64_key: &str) -> Result<Self, SuiError> {
         let private_key = BASE64_STANDARD.decode(base64_key)?;
-        Self::from_private_key(&private_key)
+        match private_key.len() {
+            33 if private_key[0] == SignatureScheme::Ed25519.flag() => {
+                Self::from_private_key(&private_key[1..])
+            }
+            33 => Err(SuiError::Sign(format!(
+                "Unsupported signature scheme flag 0x{:02x}, only Ed25519 keys are supported",
+                private_key[0]
+            ))),
+            _ => Self::from_private_key(&private_key),
+        }
+    }
+    /// # Generate vanity wallet
+    ///
+    /// Searches random keys on `parallelism` threads until the address starts with the
+    /// prefix. Blocks the calling thread, run it with `spawn_blocking` in async code. The
+    /// threads stop and are joined before the search returns.
+    ///
+    /// ## Parameters
+    /// - prefix : hex prefix of the address, with or without `0x`, case-insensitive
+    /// - timeout : longest search
+    /// - parallelism : search threads, at least 1
+    ///
+    /// ## Returns
+    /// - Ok(Wallet) : wallet whose address starts with the prefix
+    /// - Err(SuiError::Vanity) : the prefix is not hex or too long, or the search timed out
+    ///   with the attempt count
+    ///
+    /// ## Example
+    /// ```rust
+    /// use std::time::Duration;
+    /// use sui_network_sdk::types::{SuiError, VanityError};
+    /// use sui_network_sdk::wallet::Wallet;
+    ///
+    /// let wallet = Wallet::generate_vanity("0xA", Duration::from_secs(30), 2).unwrap();
+    /// assert!(wallet.address.starts_with("0xa"));
+    /// // the address is derived from the key like any other wallet's
+    /// let key = wallet.export_base64_private_key();
+    /// assert_eq!(Wallet::from_base64_private_key(&key).unwrap().address, wallet.address);
+    /// assert_eq!(Wallet::expected_attempts(1), 16);
+    ///
+    /// assert!(matches!(
+    ///     Wallet::generate_vanity("5afg", Duration::from_secs(1), 1),
+    ///     Err(SuiError::Vanity(VanityError::InvalidPrefix(_)))
+    /// ));
+    /// assert!(matches!(
+    ///     Wallet::generate_vanity("5afe5afe5afe", Duration::from_secs(1), 1),
+    ///     Err(SuiError::Vanity(VanityError::PrefixTooLong { len: 12, max: 10 }))
+    /// ));
+    /// match Wallet::generate_vanity("5afe5afe5a", Duration::from_millis(50), 2) {
+    ///     Err(SuiError::Vanity(VanityError::Timeout { attempts })) => assert!(attempts > 0),
+    ///     other => panic!("unexpected {:?}", other.map(|w| w.address)),
+    /// }
+    /// ```
+    pub fn generate_vanity(
+        prefix: &str,
+        timeout: Duration,
+        parallelism: usize,
+    ) -> Result<Self, SuiError> {
+        let prefix = prefix
+            .strip_prefix("0x")
+            .unwrap_or(prefix)
+            .to_ascii_lowercase();
+        if !prefix.chars().all(|c| c.is_ascii_hexdigit()) {
+            return Err(SuiError::Vanity(VanityError::InvalidPrefix(prefix)));
+        }
+        if prefix.len() > MAX_VANITY_PREFIX_LEN {
+            return Err(SuiError::Vanity(VanityError::PrefixTooLong {
+                len: prefix.len(),
+                max: MAX_VANITY_PREFIX_LEN,
+            }));
+        }
+        let target = format!("0x{}", prefix);
+        let stop = Arc::new(AtomicBool::new(false));
+        let attempts = Arc::new(AtomicU64::new(0));
+        let (found, receiver) = mpsc::channel();
+        let workers: Vec<_> = (0..parallelism.max(1))
+            .map(|_| {
+                let (target, stop, attempts, found) = (
+                    target.clone(),
+                    stop.clone(),
+                    attempts.clone(),
+                    found.clone(),
+                );
+                std::thread::spawn(move || {
+                    while !stop.load(Ordering::Relaxed) {
+                        let Ok(wallet) = Wallet::new() else { return };
+                        attempts.fetch_add(1, Ordering::Relaxed);
+                        if wallet.address.starts_with(&target) {
+                            let _ = found.send(wallet);
+                            return;
+                        }
+                    }
+                })
+            })
+            .collect();
+        drop(found);
+        let result = receiver.recv_timeout(timeout);
+        stop.store(true, Ordering::Relaxed);
+        for worker in workers {
+            let _ = worker.join();
+        }
+        result.map_err(|_| {
+            SuiError::Vanity(VanityError::Timeout {
+                attempts: attempts.load(Ordering::Relaxed),
+            })
+        })
+    }
+    /// expected number of keys a vanity search tries for a prefix of `prefix_len` hex chars
+    pub fn expected_attempts(prefix_len: usize) -> u64 {
+        16u64.saturating_pow(prefix_len as u32)
     }
     /// sign message
     pub fn sign(&self, message: &[u8]) -> Vec<u8> {
-        self.keypair.sign(message)
+        let signature = self.keypair.sign(message);
+        self.audit
+            .emit(&self.address, IntentScope::Raw, message, None);
+        signature
+    }
+    /// sign transaction bytes, the signature covers the BLAKE2b-256 digest of the
+    /// transaction intent (`[0, 0, 0]`) followed by the transaction bytes
+    pub fn sign_transaction(&self, tx_bytes: &[u8]) -> Vec<u8> {
+        let digest = transaction_digest(tx_bytes);
+        let signature = self.keypair.sign(&digest);
+        self.audit.emit(
+            &self.address,
+            IntentScope::TransactionData,
+            &digest,
+            Some(tx_bytes),
+        );
+        signature
     }
-    /// get address string from public key bytes
+    /// # Sign transactions
+    ///
+    /// Signs a batch of transactions, each like [`Wallet::sign_transaction`].
+    ///
+    /// ## Parameters
+    /// - batch : transaction bytes of each transaction
+    ///
+    /// ## Returns
+    /// signature of each transaction, in order
+    pub fn sign_transactions<T: AsRef<[u8]>>(&self, batch: &[T]) -> Vec<Vec<u8>> {
+        batch
+            .iter()
+            .map(|tx_bytes| self.sign_transaction(tx_bytes.as_ref()))
+            .collect()
+    }
+    /// # With audit
+    ///
+    /// Sets a hook called after every signature of the wallet with its intent scope,
+    /// digest and time, and the decoded sender, gas budget and commands of transactions
+    /// built by the SDK. Clones, locked copies and their unlocked guards keep the hook.
+    ///
+    /// ## Parameters
+    /// - audit : hook receiving the events
+    ///
+    /// ## Returns
+    /// wallet with the hook
+    ///
+    /// ## Example
+    /// ```rust
+    /// use std::sync::{Arc, Mutex};
+    /// use sui_network_sdk::ptb::{Argument, ProgrammableTransactionBuilder, TransactionData};
+    /// use sui_network_sdk::wallet::{IntentScope, SignEvent, Wallet};
+    ///
+    /// let events = Arc::new(Mutex::new(Vec::<SignEvent>::new()));
+    /// let sink = events.clone();
+    /// let wallet = Wallet::new()
+    ///     .unwrap()
+    ///     .with_audit(Box::new(move |event| sink.lock().unwrap().push(event)));
+    ///
+    /// let mut builder = ProgrammableTransactionBuilder::new();
+    /// let coins = builder.split_coins(Argument::GasCoin, &[1000]).unwrap();
+    /// builder.transfer_objects(coins, "0x7d20").unwrap();
+    /// let data = TransactionData::new_programmable(&wallet.address, vec![], builder.finish(), 5_000_000, 1000);
+    /// let tx_bytes = data.unwrap().to_bytes().unwrap();
+    ///
+    /// // one event per signature, batches included
+    /// let signatures = wallet.sign_transactions(&[tx_bytes.clone(), b"not bcs".to_vec()]);
+    /// wallet.sign_personal_message(b"hello");
+    /// wallet.sign(&[7u8; 32]);
+    /// let events = events.lock().unwrap();
+    /// assert_eq!(events.len(), signatures.len() + 2);
+    ///
+    /// assert_eq!(events[0].scope, IntentScope::TransactionData);
+    /// assert_eq!(events[0].digest.len(), 32);
+    /// let transaction = events[0].transaction.as_ref().unwrap();
+    /// assert_eq!(transaction.sender, wallet.address);
+    /// assert_eq!(transaction.gas_budget, 5_000_000);
+    /// assert_eq!(transaction.commands.len(), 2);
+    /// assert!(events[1].transaction.is_none());
+    /// assert_eq!(events[2].scope, IntentScope::PersonalMessage);
+    /// assert_eq!((events[3].scope, events[3].digest.as_slice()), (IntentScope::Raw, &[7u8; 32][..]));
+    /// assert!(events.iter().all(|event| event.address == wallet.address));
+    /// ```
+    pub fn with_audit(mut self, audit: SigningAudit) -> Self {
+        self.audit = AuditSlot(Some(Arc::new(audit)));
+        self
+    }
+    /// # Sign personal message
+    ///
+    /// Signs the BLAKE2b-256 digest of the personal message intent (`[3, 0, 0]`)
+    /// followed by the BCS encoded message, as wallets do for off-chain messages.
+    ///
+    /// ## Parameters
+    /// - message : message bytes
+    ///
+    /// ## Returns
+    /// base64 serialized signature (scheme flag, signature, public key)
+    ///
+    /// ## Example
+    /// ```rust
+    /// use sui_network_sdk::wallet::{Wallet, verify_personal_message};
+    /// let wallet = Wallet::new().unwrap();
+    /// let signature = wallet.sign_personal_message(b"hello");
+    /// assert!(verify_personal_message(b"hello", &signature, wallet.get_address()).is_ok());
+    /// assert!(verify_personal_message(b"hellO", &signature, wallet.get_address()).is_err());
+    /// assert!(verify_personal_message(b"hello", &signature, "0x2").is_err());
+    /// ```
+    pub fn sign_personal_message(&self, message: &[u8]) -> String {
+        let digest = personal_message_digest(message);
+        let signature = self.keypair.sign(&digest);
+        self.audit
+            .emit(&self.address, IntentScope::PersonalMessage, &digest, None);
+        serialized_signature(
+            SignatureScheme::Ed25519,
+            &signature,
+            &self.keypair.public_key,
+        )
+    }
+    /// get address string from Ed25519 public key bytes
     pub fn address_from_public_key_bytes(public_key: &[u8]) -> String {
-        let mut hasher = Sha3_256::new();
-        hasher.update(public_key);
-        let hash = hasher.finalize();
-        let address_bytes = &hash[..32];
-        format!("0x{}", hex::encode(address_bytes))
+        SignatureScheme::Ed25519.address(public_key)
     }
     /// export base64 private key string
     pub fn export_base64_private_key(&self) -> String {
@@ -171,6 +658,10 @@ impl Wallet {
     pub fn get_public_key_bytes(&self) -> &[u8] {
         &self.keypair.public_key
     }
+    /// get public key with its signature scheme
+    pub fn sui_public_key(&self) -> SuiPublicKey {
+        SuiPublicKey::Ed25519(self.keypair.public_key)
+    }
     /// get wallet address
     pub fn get_address(&self) -> &str {
         &self.address
@@ -180,14 +671,16 @@ impl Wallet {
         let public_key_bytes = self.get_public_key_bytes();
         // Check input lengths
         if signature.len() != 64 {
-            return Err(SuiError::Sign(format!(
-                "The signature byte length does not meet the requirement, Requires 32 bytes.",
-            )));
+            return Err(SuiError::Sign(
+                "The signature byte length does not meet the requirement, Requires 32 bytes."
+                    .to_string(),
+            ));
         }
         if public_key_bytes.len() != 32 {
-            return Err(SuiError::Sign(format!(
-                "The public key byte length does not meet the requirement, Requires 32 bytes.",
-            )));
+            return Err(SuiError::Sign(
+                "The public key byte length does not meet the requirement, Requires 32 bytes."
+                    .to_string(),
+            ));
         }
         // signature bytes convert to 64 bytes
         let signature_bytes: [u8; 64] = signature
@@ -197,8 +690,7 @@ impl Wallet {
         let verifying_key =
             VerifyingKey::from_bytes(public_key_bytes.try_into().map_err(|_| {
                 SuiError::Sign("Failed to convert public key bytes to array".to_string())
-            })?)
-            .map_err(|e| SuiError::Sign(format!("Invalid public key format: {}", e)))?;
+            })?)?;
         // Verify signature
         match verifying_key.verify_strict(message, &signature) {
             Ok(()) => Ok(true),
@@ -207,6 +699,1613 @@ impl Wallet {
     }
 }
 
+impl Wallet {
+    /// # Key info
+    ///
+    /// Report of the wallet's key for troubleshooting, when an expected address is given
+    /// the report diagnoses why it differs by re-deriving the address under alternative
+    /// schemes and legacy hashing.
+    ///
+    /// ## Parameters
+    /// - expected_address : address the key is expected to control
+    ///
+    /// ## Returns
+    /// key info report
+    ///
+    /// ## Example
+    /// ```rust
+    /// use sui_network_sdk::wallet::{AddressDiagnosis, Wallet};
+    /// let wallet = Wallet::from_private_key(&[7u8; 32]).unwrap();
+    /// let info = wallet.key_info(Some(wallet.get_address()));
+    /// assert_eq!(info.diagnosis, Some(AddressDiagnosis::Match));
+    /// let info = wallet.key_info(Some("0x2"));
+    /// assert_eq!(info.diagnosis, Some(AddressDiagnosis::NoMatch));
+    ///
+    /// // addresses of the same secret derived by other tools
+    /// let ed25519 = "0xa0ccc8bcc83f6c628340134f8546a21e0618fd1aaa02432bba454c4a2c2233da";
+    /// let secp256k1 = "0x3334442090548419b94695cbb1838652bb0494b54ff855494dd83491a3cfb6a6";
+    /// let sha3_flagged = "0xb433df8c8cf30ec783402ce3789e650cbfea088cbcaf1861840c1656bd54269f";
+    /// let sha3_unflagged = "0x791026e25cc1ba461167ffb5c6dc095c284bd92cdc5ca34ac589a95d24f53830";
+    /// assert_eq!(wallet.get_address(), ed25519);
+    /// assert_eq!(
+    ///     wallet.key_info(Some(secp256k1)).diagnosis,
+    ///     Some(AddressDiagnosis::WrongScheme {
+    ///         address_if_secp256k1: Some(secp256k1.to_string()),
+    ///         address_if_ed25519: ed25519.to_string(),
+    ///     })
+    /// );
+    /// for legacy_address in [sha3_flagged, sha3_unflagged] {
+    ///     assert_eq!(
+    ///         wallet.key_info(Some(legacy_address)).diagnosis,
+    ///         Some(AddressDiagnosis::LegacyHashMismatch { legacy_address: legacy_address.to_string() })
+    ///     );
+    /// }
+    /// ```
+    pub fn key_info(&self, expected_address: Option<&str>) -> KeyInfo {
+        let scheme = SignatureScheme::Ed25519;
+        let public_key = self.get_public_key_bytes();
+        let mut flagged_public_key = vec![scheme.flag()];
+        flagged_public_key.extend_from_slice(public_key);
+        KeyInfo {
+            scheme,
+            public_key_hex: hex::encode(public_key),
+            public_key_base64: BASE64_STANDARD.encode(flagged_public_key),
+            address: self.address.clone(),
+            derivation_path: None,
+            diagnosis: expected_address.map(|expected| self.diagnose_address(expected)),
+        }
+    }
+    /// diagnose why an expected address differs from the wallet address
+    fn diagnose_address(&self, expected_address: &str) -> AddressDiagnosis {
+        let expected = normalize_address(expected_address);
+        if expected == self.address {
+            return AddressDiagnosis::Match;
+        }
+        // the same secret interpreted as a secp256k1 key
+        let address_if_secp256k1 = Secp256k1PrivateKey::from_bytes(&self.keypair.private_key)
+            .ok()
+            .map(|private_key| {
+                let public_key = Secp256k1PublicKey::from(&private_key);
+                SignatureScheme::Secp256k1.address(public_key.as_bytes())
+            });
+        if address_if_secp256k1.as_deref() == Some(expected.as_str()) {
+            return AddressDiagnosis::WrongScheme {
+                address_if_secp256k1,
+                address_if_ed25519: self.address.clone(),
+            };
+        }
+        let public_key = self.get_public_key_bytes();
+        let mut flagged_public_key = vec![SignatureScheme::Ed25519.flag()];
+        flagged_public_key.extend_from_slice(public_key);
+        // SHA3-256 hashing used by earlier Sui releases and earlier versions of this crate,
+        // the last candidate is this crate's old pseudo public key (SHA3-256 of the secret)
+        let legacy_public_key = Sha3_256::digest(self.keypair.private_key);
+        let legacy_addresses = [
+            Sha3_256::digest(&flagged_public_key),
+            Sha3_256::digest(public_key),
+            Sha3_256::digest(legacy_public_key),
+        ];
+        if let Some(legacy_address) = legacy_addresses
+            .iter()
+            .map(|hash| format!("0x{}", hex::encode(hash)))
+            .find(|address| *address == expected)
+        {
+            return AddressDiagnosis::LegacyHashMismatch { legacy_address };
+        }
+        AddressDiagnosis::NoMatch
+    }
+}
+
+/// prefix of encoded key shares
+const KEY_SHARE_PREFIX: &str = "suishare:";
+/// current key share encoding version
+const KEY_SHARE_VERSION: u8 = 1;
+/// version, set id, threshold, key fingerprint
+const KEY_SHARE_HEADER_LEN: usize = 1 + 8 + 1 + 4;
+/// checksum length
+const KEY_SHARE_CHECKSUM_LEN: usize = 4;
+
+impl Wallet {
+    /// # Split key
+    ///
+    /// Splits the private key with Shamir secret sharing over GF(256), any `threshold`
+    /// of the `shares` returned share strings reconstruct the key with
+    /// [`Wallet::from_key_shares`]. Each share carries a version, an id of the split,
+    /// the threshold, a fingerprint of the public key and a checksum. Shares are
+    /// zeroized when dropped.
+    ///
+    /// ## Parameters
+    /// - shares : number of shares, at most 255
+    /// - threshold : number of shares needed to reconstruct, at least 2
+    ///
+    /// ## Returns
+    /// - Ok(Vec<Zeroizing<String>>) : encoded shares
+    /// - Err(SuiError) : invalid share count or threshold
+    ///
+    /// ## Example
+    /// ```rust
+    /// use sui_network_sdk::types::{KeyShareError, SuiError};
+    /// use sui_network_sdk::wallet::Wallet;
+    ///
+    /// let wallet = Wallet::new().unwrap();
+    /// let shares = wallet.split_key(5, 3).unwrap();
+    /// let shares: Vec<&str> = shares.iter().map(|s| s.as_str()).collect();
+    ///
+    /// // any 3 shares reconstruct the key
+    /// let restored = Wallet::from_key_shares(&[shares[4], shares[0], shares[2]]).unwrap();
+    /// assert_eq!(restored.get_address(), wallet.get_address());
+    ///
+    /// // 2 shares are not enough, repeating a share does not count
+    /// assert!(matches!(
+    ///     Wallet::from_key_shares(&[shares[0], shares[1], shares[1]]),
+    ///     Err(SuiError::KeyShare(KeyShareError::InsufficientShares { threshold: 3, provided: 2 }))
+    /// ));
+    ///
+    /// // shares of another split are rejected
+    /// let other = wallet.split_key(5, 3).unwrap();
+    /// assert!(matches!(
+    ///     Wallet::from_key_shares(&[shares[0], shares[1], other[2].as_str()]),
+    ///     Err(SuiError::KeyShare(KeyShareError::MismatchedShares))
+    /// ));
+    ///
+    /// // a changed character fails the checksum
+    /// let mut corrupted = shares[1].to_string();
+    /// let last = if corrupted.ends_with('0') { "1" } else { "0" };
+    /// corrupted.replace_range(corrupted.len() - 1.., last);
+    /// assert!(matches!(
+    ///     Wallet::from_key_shares(&[shares[0], &corrupted, shares[2]]),
+    ///     Err(SuiError::KeyShare(KeyShareError::CorruptedShare(1)))
+    /// ));
+    ///
+    /// assert!(matches!(
+    ///     wallet.split_key(2, 3),
+    ///     Err(SuiError::KeyShare(KeyShareError::InvalidParameters { .. }))
+    /// ));
+    /// ```
+    pub fn split_key(&self, shares: u8, threshold: u8) -> Result<Vec<Zeroizing<String>>, SuiError> {
+        if threshold < 2 || threshold > shares {
+            return Err(SuiError::KeyShare(KeyShareError::InvalidParameters {
+                shares,
+                threshold,
+            }));
+        }
+        let mut set_id = [0u8; 8];
+        rng().fill(&mut set_id);
+        let mut header = Zeroizing::new(Vec::with_capacity(KEY_SHARE_HEADER_LEN));
+        header.push(KEY_SHARE_VERSION);
+        header.extend_from_slice(&set_id);
+        header.push(threshold);
+        header.extend_from_slice(&key_fingerprint(&self.keypair.public_key));
+        Ok(Sharks(threshold)
+            .dealer(&self.keypair.private_key)
+            .take(shares as usize)
+            .map(|share| {
+                let mut bytes = header.clone();
+                bytes.extend_from_slice(&Zeroizing::new(Vec::from(&share)));
+                let checksum = key_share_checksum(&bytes);
+                bytes.extend_from_slice(&checksum);
+                Zeroizing::new(format!("{}{}", KEY_SHARE_PREFIX, hex::encode(&*bytes)))
+            })
+            .collect())
+    }
+
+    /// # From key shares
+    ///
+    /// Reconstructs a wallet from shares produced by [`Wallet::split_key`].
+    ///
+    /// ## Parameters
+    /// - shares : encoded shares, at least the threshold of distinct ones
+    ///
+    /// ## Returns
+    /// - Ok(Wallet) : reconstructed wallet
+    /// - Err(SuiError) : corrupted, mismatched or insufficient shares
+    pub fn from_key_shares(shares: &[&str]) -> Result<Self, SuiError> {
+        let mut header: Option<Zeroizing<Vec<u8>>> = None;
+        let mut decoded: Vec<Share> = Vec::new();
+        for (index, share) in shares.iter().enumerate() {
+            let bytes = decode_key_share(index, share)?;
+            let (share_header, share_bytes) = bytes.split_at(KEY_SHARE_HEADER_LEN);
+            match &header {
+                Some(header) if header.as_slice() != share_header => {
+                    return Err(SuiError::KeyShare(KeyShareError::MismatchedShares));
+                }
+                Some(_) => {}
+                None => header = Some(Zeroizing::new(share_header.to_vec())),
+            }
+            let share = Share::try_from(share_bytes)
+                .map_err(|_| SuiError::KeyShare(KeyShareError::CorruptedShare(index)))?;
+            // repeated shares do not count towards the threshold
+            if !decoded.iter().any(|d| d.x == share.x) {
+                decoded.push(share);
+            }
+        }
+        let header = header.ok_or(SuiError::KeyShare(KeyShareError::InsufficientShares {
+            threshold: 2,
+            provided: 0,
+        }))?;
+        let threshold = header[9];
+        if decoded.len() < threshold as usize {
+            return Err(SuiError::KeyShare(KeyShareError::InsufficientShares {
+                threshold,
+                provided: decoded.len(),
+            }));
+        }
+        let private_key = Zeroizing::new(
+            Sharks(threshold)
+                .recover(decoded.iter().take(threshold as usize))
+                .map_err(|_| SuiError::KeyShare(KeyShareError::ReconstructionFailed))?,
+        );
+        let wallet = Self::from_private_key(&private_key)
+            .map_err(|_| SuiError::KeyShare(KeyShareError::ReconstructionFailed))?;
+        if key_fingerprint(&wallet.keypair.public_key) != header[10..KEY_SHARE_HEADER_LEN] {
+            return Err(SuiError::KeyShare(KeyShareError::ReconstructionFailed));
+        }
+        Ok(wallet)
+    }
+}
+
+/// first 4 bytes of the BLAKE2b-256 digest of the public key
+fn key_fingerprint(public_key: &[u8]) -> [u8; 4] {
+    let digest = Blake2b256::digest(public_key).digest;
+    [digest[0], digest[1], digest[2], digest[3]]
+}
+
+/// first 4 bytes of the BLAKE2b-256 digest of the share bytes
+fn key_share_checksum(bytes: &[u8]) -> [u8; KEY_SHARE_CHECKSUM_LEN] {
+    let digest = Blake2b256::digest(bytes).digest;
+    [digest[0], digest[1], digest[2], digest[3]]
+}
+
+/// decode a key share and check its version and checksum, returns the bytes without checksum
+fn decode_key_share(index: usize, share: &str) -> Result<Zeroizing<Vec<u8>>, SuiError> {
+    let corrupted = || SuiError::KeyShare(KeyShareError::CorruptedShare(index));
+    let encoded = share
+        .trim()
+        .strip_prefix(KEY_SHARE_PREFIX)
+        .ok_or_else(corrupted)?;
+    let mut bytes = Zeroizing::new(hex::decode(encoded).map_err(|_| corrupted())?);
+    // header, at least one x and y byte, checksum
+    if bytes.len() < KEY_SHARE_HEADER_LEN + 2 + KEY_SHARE_CHECKSUM_LEN {
+        return Err(corrupted());
+    }
+    let checksum_start = bytes.len() - KEY_SHARE_CHECKSUM_LEN;
+    if key_share_checksum(&bytes[..checksum_start]) != bytes[checksum_start..] {
+        return Err(corrupted());
+    }
+    if bytes[0] != KEY_SHARE_VERSION {
+        return Err(SuiError::KeyShare(KeyShareError::UnsupportedVersion {
+            index,
+            version: bytes[0],
+        }));
+    }
+    bytes.truncate(checksum_start);
+    Ok(bytes)
+}
+
+/// # Verify personal message
+///
+/// Verifies a serialized signature over a personal message and checks that the
+/// signing key belongs to the claimed address, see [`Wallet::sign_personal_message`].
+/// Ed25519, secp256k1, secp256r1 and multisig signatures are verified like those of
+/// [`verify_transaction_signatures`], zkLogin and passkey signatures are refused.
+///
+/// ## Parameters
+/// - message : message bytes
+/// - signature : base64 serialized signature (scheme flag, signature, public key)
+/// - address : address the signer claims
+///
+/// ## Returns
+/// - Ok(()) : the signature is valid and binds to the address
+/// - Err(SuiError) : malformed or invalid signature, or a key of another address
+pub fn verify_personal_message(
+    message: &[u8],
+    signature: &str,
+    address: &str,
+) -> Result<(), SuiError> {
+    let signer =
+        verified_signer(&personal_message_digest(message), signature).map_err(SuiError::Sign)?;
+    if signer != normalize_address(address) {
+        return Err(SuiError::Sign(format!(
+            "Signature key belongs to {}, not {}",
+            signer, address
+        )));
+    }
+    Ok(())
+}
+
+/// flag of secp256r1 signatures
+const SECP256R1_FLAG: u8 = 0x02;
+/// flag of multisig signatures
+const MULTISIG_FLAG: u8 = 0x03;
+/// flag of passkey signatures
+const PASSKEY_FLAG: u8 = 0x06;
+
+/// # Verify transaction signatures
+///
+/// Checks the signatures a transaction is about to be submitted with: each one verifies
+/// over the transaction intent digest, its key belongs to the sender or the gas owner,
+/// and both have signed, once each. Ed25519, secp256k1 and secp256r1 signatures are
+/// checked, and multisig signatures of those keys against their weights and threshold.
+/// zkLogin and passkey signatures need the network to verify and are refused.
+///
+/// ## Parameters
+/// - tx_bytes : BCS transaction bytes
+/// - signatures : base64 serialized signatures, in submission order
+///
+/// ## Returns
+/// - Ok(()) : the node would accept the signatures
+/// - Err(SuiError::Sign) : the failing signature and why
+///
+/// ## Example
+/// ```rust
+/// use base64::Engine;
+/// use base64::prelude::BASE64_STANDARD;
+/// use fastcrypto::hash::{Blake2b256, HashFunction};
+/// use sui_network_sdk::ptb::{ProgrammableTransactionBuilder, TransactionData};
+/// use sui_network_sdk::types::SuiError;
+/// use sui_network_sdk::wallet::{Wallet, verify_transaction_signatures};
+///
+/// let serialize = |wallet: &Wallet, signature: &[u8]| {
+///     BASE64_STANDARD.encode([&[0u8][..], signature, wallet.get_public_key_bytes()].concat())
+/// };
+/// let (alice, sponsor) = (Wallet::new().unwrap(), Wallet::new().unwrap());
+/// let tx_bytes = TransactionData::new_programmable_sponsored(
+///     alice.get_address(), sponsor.get_address(), vec![], ProgrammableTransactionBuilder::new().finish(), 1000, 1000,
+/// ).unwrap().to_bytes().unwrap();
+/// let alice_signature = serialize(&alice, &alice.sign_transaction(&tx_bytes));
+/// let sponsor_signature = serialize(&sponsor, &sponsor.sign_transaction(&tx_bytes));
+/// assert!(verify_transaction_signatures(&tx_bytes, &[alice_signature.clone(), sponsor_signature]).is_ok());
+///
+/// // the sponsor's signature is missing
+/// let error = verify_transaction_signatures(&tx_bytes, &[alice_signature.clone()]).unwrap_err();
+/// assert!(error.to_string().contains("no signature of the gas owner"), "{}", error);
+///
+/// // a 1-of-2 multisig sender, signed by its second key
+/// let (first, second) = (Wallet::new().unwrap(), Wallet::new().unwrap());
+/// let mut pk_map = vec![2u8];
+/// let mut address_preimage = vec![0x03, 1, 0];
+/// for key in [&first, &second] {
+///     pk_map.extend([&[0u8][..], key.get_public_key_bytes(), &[1]].concat());
+///     address_preimage.extend([&[0u8][..], key.get_public_key_bytes(), &[1]].concat());
+/// }
+/// let multisig_address = format!("0x{}", hex::encode(Blake2b256::digest(&address_preimage).digest));
+/// let tx_bytes = TransactionData::new_programmable(
+///     &multisig_address, vec![], ProgrammableTransactionBuilder::new().finish(), 1000, 1000,
+/// ).unwrap().to_bytes().unwrap();
+/// let multisig = [
+///     &[0x03, 1, 0][..],                        // flag, one signature, Ed25519
+///     &second.sign_transaction(&tx_bytes),
+///     &[0b10, 0],                                // bitmap: key 1
+///     &pk_map,
+///     &[1, 0],                                   // threshold
+/// ]
+/// .concat();
+/// assert!(verify_transaction_signatures(&tx_bytes, &[BASE64_STANDARD.encode(&multisig)]).is_ok());
+///
+/// // the same signature claimed for the first key
+/// let mut wrong_key = multisig.clone();
+/// wrong_key[3 + 64] = 0b01;
+/// let error = verify_transaction_signatures(&tx_bytes, &[BASE64_STANDARD.encode(&wrong_key)]).unwrap_err();
+/// assert!(matches!(&error, SuiError::Sign(e) if e.contains("multisig key 0")), "{}", error);
+/// ```
+pub fn verify_transaction_signatures(
+    tx_bytes: &[u8],
+    signatures: &[String],
+) -> Result<(), SuiError> {
+    let data = TransactionData::from_bytes(tx_bytes)?;
+    let (sender, gas_owner) = (data.sender(), data.gas_owner());
+    let digest = transaction_digest(tx_bytes);
+    let mut signers = Vec::with_capacity(signatures.len());
+    for (index, signature) in signatures.iter().enumerate() {
+        let failed = |reason: String| {
+            SuiError::Sign(format!(
+                "signature {} of {}: {}",
+                index + 1,
+                signatures.len(),
+                reason
+            ))
+        };
+        let signer = verified_signer(&digest, signature).map_err(failed)?;
+        if signer != sender && signer != gas_owner {
+            return Err(failed(format!(
+                "key of {} signs for neither the sender {} nor the gas owner {}",
+                signer, sender, gas_owner
+            )));
+        }
+        if signers.contains(&signer) {
+            return Err(failed(format!("{} signed twice", signer)));
+        }
+        signers.push(signer);
+    }
+    for (role, address) in [("sender", &sender), ("gas owner", &gas_owner)] {
+        if !signers.contains(address) {
+            return Err(SuiError::Sign(format!(
+                "no signature of the {} {}",
+                role, address
+            )));
+        }
+    }
+    Ok(())
+}
+
+/// address a serialized signature binds to, once it verified over the digest
+fn verified_signer(digest: &[u8; 32], signature: &str) -> Result<String, String> {
+    let serialized = BASE64_STANDARD
+        .decode(signature)
+        .map_err(|e| format!("not base64: {}", e))?;
+    let Some((&flag, rest)) = serialized.split_first() else {
+        return Err("empty signature".to_string());
+    };
+    if flag == MULTISIG_FLAG {
+        return verified_multisig_signer(digest, rest);
+    }
+    let key_len = public_key_len(flag)?;
+    if rest.len() != 64 + key_len {
+        return Err(format!(
+            "{} signature of {} bytes, expected {}",
+            scheme_name(flag),
+            rest.len(),
+            64 + key_len
+        ));
+    }
+    let (signature, public_key) = rest.split_at(64);
+    verify_digest(flag, digest, signature, public_key)?;
+    Ok(flag_address(&[flag], public_key))
+}
+
+/// address of a multisig whose signatures verified and reached the threshold
+fn verified_multisig_signer(digest: &[u8; 32], bytes: &[u8]) -> Result<String, String> {
+    let mut reader = BcsReader(bytes);
+    let signature_count = reader.uleb()?;
+    let mut signatures = Vec::new();
+    for _ in 0..signature_count {
+        let flag = variant_flag(reader.uleb()?)?;
+        public_key_len(flag)?;
+        signatures.push((flag, reader.take(64)?));
+    }
+    let bitmap = reader.u16()?;
+    let key_count = reader.uleb()?;
+    let mut keys = Vec::new();
+    for _ in 0..key_count {
+        let flag = variant_flag(reader.uleb()?)?;
+        let public_key = match flag {
+            ZKLOGIN_FLAG => {
+                let len = reader.uleb()?;
+                reader.take(len)?
+            }
+            _ => reader.take(public_key_len(flag).unwrap_or(33))?,
+        };
+        keys.push((flag, public_key, reader.take(1)?[0]));
+    }
+    let threshold = reader.u16()?;
+    if !reader.0.is_empty() {
+        return Err("multisig with trailing bytes".to_string());
+    }
+    let signed: Vec<usize> = (0..keys.len().min(16))
+        .filter(|index| bitmap & (1 << index) != 0)
+        .collect();
+    if signed.len() != signatures.len() || u32::from(bitmap) >> keys.len().min(16) != 0 {
+        return Err(format!(
+            "multisig bitmap {:#b} does not match its {} signatures and {} keys",
+            bitmap,
+            signatures.len(),
+            keys.len()
+        ));
+    }
+    let mut weight = 0u16;
+    for (index, (flag, signature)) in signed.into_iter().zip(signatures) {
+        let (key_flag, public_key, key_weight) = keys[index];
+        if key_flag != flag {
+            return Err(format!(
+                "multisig key {} is {}, signed with {}",
+                index,
+                scheme_name(key_flag),
+                scheme_name(flag)
+            ));
+        }
+        verify_digest(flag, digest, signature, public_key)
+            .map_err(|e| format!("multisig key {}: {}", index, e))?;
+        weight += u16::from(key_weight);
+    }
+    if weight < threshold {
+        return Err(format!(
+            "multisig weight {} below the threshold {}",
+            weight, threshold
+        ));
+    }
+    let mut preimage = vec![MULTISIG_FLAG];
+    preimage.extend(threshold.to_le_bytes());
+    for (flag, public_key, weight) in keys {
+        preimage.push(flag);
+        preimage.extend(public_key);
+        preimage.push(weight);
+    }
+    Ok(flag_address(&preimage, &[]))
+}
+
+/// verify a raw signature over an intent digest
+fn verify_digest(
+    flag: u8,
+    digest: &[u8; 32],
+    signature: &[u8],
+    public_key: &[u8],
+) -> Result<(), String> {
+    let valid = match flag {
+        0x00 => <[u8; 32]>::try_from(public_key)
+            .ok()
+            .and_then(|key| VerifyingKey::from_bytes(&key).ok())
+            .zip(Signature::from_slice(signature).ok())
+            .is_some_and(|(key, signature)| key.verify_strict(digest, &signature).is_ok()),
+        0x01 => Secp256k1PublicKey::from_bytes(public_key)
+            .and_then(|key| {
+                Secp256k1Signature::from_bytes(signature)
+                    .and_then(|signature| key.verify(digest, &signature))
+            })
+            .is_ok(),
+        SECP256R1_FLAG => Secp256r1PublicKey::from_bytes(public_key)
+            .and_then(|key| {
+                Secp256r1Signature::from_bytes(signature)
+                    .and_then(|signature| key.verify(digest, &signature))
+            })
+            .is_ok(),
+        _ => false,
+    };
+    if valid {
+        Ok(())
+    } else {
+        Err(format!(
+            "invalid {} signature over the intent digest",
+            scheme_name(flag)
+        ))
+    }
+}
+
+/// public key length of a single key scheme, an error for schemes verified on chain only
+fn public_key_len(flag: u8) -> Result<usize, String> {
+    match flag {
+        0x00 => Ok(32),
+        0x01 | SECP256R1_FLAG => Ok(33),
+        ZKLOGIN_FLAG | PASSKEY_FLAG => Err(format!(
+            "{} signatures can't be verified before submission",
+            scheme_name(flag)
+        )),
+        flag => Err(format!("unknown signature scheme flag {:#04x}", flag)),
+    }
+}
+
+/// scheme flag of a multisig key or signature variant
+fn variant_flag(variant: usize) -> Result<u8, String> {
+    match variant {
+        0..=2 => Ok(variant as u8),
+        3 => Ok(ZKLOGIN_FLAG),
+        4 => Ok(PASSKEY_FLAG),
+        variant => Err(format!("unknown multisig scheme variant {}", variant)),
+    }
+}
+
+/// display name of a scheme flag
+fn scheme_name(flag: u8) -> &'static str {
+    match flag {
+        0x00 => "Ed25519",
+        0x01 => "secp256k1",
+        SECP256R1_FLAG => "secp256r1",
+        MULTISIG_FLAG => "multisig",
+        ZKLOGIN_FLAG => "zkLogin",
+        PASSKEY_FLAG => "passkey",
+        _ => "unknown",
+    }
+}
+
+/// address of a flag prefixed key, BLAKE2b-256 over the bytes
+fn flag_address(prefix: &[u8], public_key: &[u8]) -> String {
+    let mut hasher = Blake2b256::default();
+    hasher.update(prefix);
+    hasher.update(public_key);
+    format!("0x{}", hex::encode(hasher.finalize().digest))
+}
+
+/// cursor over BCS bytes
+struct BcsReader<'b>(&'b [u8]);
+
+impl<'b> BcsReader<'b> {
+    fn take(&mut self, len: usize) -> Result<&'b [u8], String> {
+        if self.0.len() < len {
+            return Err("truncated multisig".to_string());
+        }
+        let (taken, rest) = self.0.split_at(len);
+        self.0 = rest;
+        Ok(taken)
+    }
+
+    fn u16(&mut self) -> Result<u16, String> {
+        let bytes = self.take(2)?;
+        Ok(u16::from_le_bytes([bytes[0], bytes[1]]))
+    }
+
+    /// ULEB128 length or variant index, small in multisigs
+    fn uleb(&mut self) -> Result<usize, String> {
+        let mut value = 0usize;
+        for shift in (0..28).step_by(7) {
+            let byte = self.take(1)?[0];
+            value |= usize::from(byte & 0x7f) << shift;
+            if byte & 0x80 == 0 {
+                return Ok(value);
+            }
+        }
+        Err("oversized multisig length".to_string())
+    }
+}
+
+/// digest signed for a transaction, BLAKE2b-256 of the intent followed by the transaction bytes
+fn transaction_digest(tx_bytes: &[u8]) -> [u8; 32] {
+    let mut hasher = Blake2b256::default();
+    hasher.update([0u8, 0, 0]);
+    hasher.update(tx_bytes);
+    hasher.finalize().digest
+}
+
+/// digest signed for a personal message, BLAKE2b-256 of the intent followed by the BCS message
+fn personal_message_digest(message: &[u8]) -> [u8; 32] {
+    let mut hasher = Blake2b256::default();
+    hasher.update([3u8, 0, 0]);
+    hasher.update(bcs::to_bytes(message).unwrap_or_default());
+    hasher.finalize().digest
+}
+
+/// base64 serialized signature: scheme flag, signature, public key
+pub(crate) fn serialized_signature(
+    scheme: SignatureScheme,
+    signature: &[u8],
+    public_key: &[u8],
+) -> String {
+    let mut serialized = Vec::with_capacity(1 + signature.len() + public_key.len());
+    serialized.push(scheme.flag());
+    serialized.extend_from_slice(signature);
+    serialized.extend_from_slice(public_key);
+    BASE64_STANDARD.encode(serialized)
+}
+
+/// normalize an address to lowercase, 0x prefixed, 64 hex chars
+pub(crate) fn normalize_address(address: &str) -> String {
+    crate::types::address::normalize(address).unwrap_or_else(|| address.trim().to_lowercase())
+}
+
+/// Signature scheme
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SignatureScheme {
+    Ed25519,
+    Secp256k1,
+}
+
+impl SignatureScheme {
+    /// scheme flag byte
+    pub fn flag(&self) -> u8 {
+        match self {
+            SignatureScheme::Ed25519 => 0x00,
+            SignatureScheme::Secp256k1 => 0x01,
+        }
+    }
+    /// derive the address of a public key under this scheme,
+    /// BLAKE2b-256 over the scheme flag followed by the public key bytes
+    pub fn address(&self, public_key: &[u8]) -> String {
+        flag_address(&[self.flag()], public_key)
+    }
+}
+
+/// Key info report
+///
+/// # Fields
+/// - scheme : signature scheme
+/// - public_key_hex : public key hex
+/// - public_key_base64 : Sui base64 public key (scheme flag followed by the key)
+/// - address : derived address
+/// - derivation_path : derivation path, when known
+/// - diagnosis : diagnosis against an expected address, when one was given
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct KeyInfo {
+    pub scheme: SignatureScheme,
+    pub public_key_hex: String,
+    pub public_key_base64: String,
+    pub address: String,
+    pub derivation_path: Option<String>,
+    pub diagnosis: Option<AddressDiagnosis>,
+}
+
+/// Address diagnosis
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum AddressDiagnosis {
+    /// the expected address is the wallet address
+    Match,
+    /// the expected address belongs to the same secret under another scheme
+    WrongScheme {
+        address_if_secp256k1: Option<String>,
+        address_if_ed25519: String,
+    },
+    /// the expected address was derived with legacy SHA3-256 hashing
+    LegacyHashMismatch { legacy_address: String },
+    /// the expected address is unrelated to this key
+    NoMatch,
+}
+
+/// # Signing audit
+///
+/// Called with an event after every signature a signer produced, see
+/// [`Wallet::with_audit`]. It runs on the signing call and can neither refuse nor change the
+/// signature, keep it short.
+pub type SigningAudit = Box<dyn Fn(SignEvent) + Send + Sync>;
+
+/// # Intent scope
+///
+/// # Variants
+/// - TransactionData : transaction bytes, intent `[0, 0, 0]`
+/// - PersonalMessage : personal message, intent `[3, 0, 0]`
+/// - Raw : bytes signed as given, see [`Wallet::sign`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IntentScope {
+    TransactionData,
+    PersonalMessage,
+    Raw,
+}
+
+/// # Sign event
+///
+/// # Fields
+/// - address : signer address
+/// - scope : intent scope of the signed bytes
+/// - digest : bytes signed, the BLAKE2b-256 intent digest unless the scope is `Raw`
+/// - timestamp_ms : unix time of the signature, in milliseconds
+/// - transaction : decoded transaction, when the signed bytes are SDK built transaction data
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SignEvent {
+    pub address: String,
+    pub scope: IntentScope,
+    pub digest: Vec<u8>,
+    pub timestamp_ms: u64,
+    pub transaction: Option<AuditedTransaction>,
+}
+
+/// signing audit of a signer, shared by its clones and unlocked guards
+#[derive(Clone, Default)]
+struct AuditSlot(Option<Arc<SigningAudit>>);
+
+impl AuditSlot {
+    /// hand the event of a signature to the hook
+    fn emit(&self, address: &str, scope: IntentScope, digest: &[u8], tx_bytes: Option<&[u8]>) {
+        let Some(audit) = &self.0 else { return };
+        audit(SignEvent {
+            address: address.to_string(),
+            scope,
+            digest: digest.to_vec(),
+            timestamp_ms: now_ms(),
+            transaction: tx_bytes
+                .and_then(|tx_bytes| TransactionData::from_bytes(tx_bytes).ok())
+                .map(|data| AuditedTransaction::from_data(&data)),
+        });
+    }
+}
+
+impl std::fmt::Debug for AuditSlot {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(if self.0.is_some() { "Some(..)" } else { "None" })
+    }
+}
+
+/// # Signer
+///
+/// Source of signatures for an address: a local [`Wallet`], an [`ExternalSigner`] backed by
+/// a KMS or HSM, or a device such as `LedgerSigner` (`ledger` feature). [`Trade`] signs
+/// through this trait, so every signer builds and executes the same transactions.
+///
+/// [`Trade`]: crate::trade::Trade
+///
+/// ## Example
+/// ```rust
+/// use sui_network_sdk::wallet::{Signer, Wallet, verify_personal_message};
+///
+/// async fn sign_in(signer: &dyn Signer) -> String {
+///     signer.sign_personal_message(b"sign in").await.unwrap()
+/// }
+///
+/// # #[tokio::main]
+/// # async fn main() {
+/// let wallet = Wallet::new().unwrap();
+/// let signature = sign_in(&wallet).await;
+/// assert!(verify_personal_message(b"sign in", &signature, Signer::address(&wallet)).is_ok());
+/// # }
+/// ```
+pub trait Signer: Send + Sync {
+    /// signer address
+    fn address(&self) -> &str;
+    /// public key bytes, 32 bytes for Ed25519, 33 compressed bytes for secp256k1
+    fn public_key(&self) -> &[u8];
+    /// signature scheme of the key
+    fn scheme(&self) -> SignatureScheme {
+        SignatureScheme::Ed25519
+    }
+    /// sign transaction bytes, returns the signature over the transaction intent digest
+    fn sign_transaction<'s>(
+        &'s self,
+        tx_bytes: &'s [u8],
+    ) -> BoxFuture<'s, Result<Vec<u8>, SuiError>>;
+    /// sign a personal message, returns the base64 serialized signature
+    fn sign_personal_message<'s>(
+        &'s self,
+        message: &'s [u8],
+    ) -> BoxFuture<'s, Result<String, SuiError>>;
+}
+
+impl Signer for Wallet {
+    fn address(&self) -> &str {
+        &self.address
+    }
+    fn public_key(&self) -> &[u8] {
+        &self.keypair.public_key
+    }
+    fn sign_transaction<'s>(
+        &'s self,
+        tx_bytes: &'s [u8],
+    ) -> BoxFuture<'s, Result<Vec<u8>, SuiError>> {
+        Box::pin(future::ready(Ok(Wallet::sign_transaction(self, tx_bytes))))
+    }
+    fn sign_personal_message<'s>(
+        &'s self,
+        message: &'s [u8],
+    ) -> BoxFuture<'s, Result<String, SuiError>> {
+        Box::pin(future::ready(Ok(Wallet::sign_personal_message(
+            self, message,
+        ))))
+    }
+}
+
+/// PBKDF2-HMAC-SHA256 iterations deriving the sealing key of a locked wallet
+const LOCK_KDF_ITERATIONS: u32 = 600_000;
+/// default time an unlocked wallet stays unlocked
+const DEFAULT_RELOCK_TIMEOUT: Duration = Duration::from_secs(60);
+/// longest vanity prefix, 16^10 attempts take days on one machine
+const MAX_VANITY_PREFIX_LEN: usize = 10;
+
+/// private key of an unlocked guard, `None` once resealed
+type KeySlot = Mutex<Option<Zeroizing<[u8; 32]>>>;
+
+/// # Locked wallet
+///
+/// Wallet whose private key is held in memory only encrypted, with AES-256-GCM under a
+/// key derived from a passphrase by PBKDF2-HMAC-SHA256. [`LockedWallet::unlock`] hands out
+/// an [`UnlockedGuard`] that signs, the plaintext key is zeroized when the guard drops, the
+/// relock timeout passes or [`LockedWallet::lock`] is called. The locked wallet itself is
+/// a [`Signer`] that refuses to sign.
+///
+/// ## Example
+/// ```rust
+/// use std::sync::Arc;
+/// use std::time::Duration;
+/// use sui_network_sdk::types::{LockError, SuiError};
+/// use sui_network_sdk::wallet::{Signer, Wallet, verify_personal_message};
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let wallet = Wallet::new().unwrap();
+///     let address = wallet.get_address().to_string();
+///     // few iterations keep the example fast, `Wallet::lock` uses 600 000
+///     let locked = wallet.lock_with_kdf_iterations("correct horse", 1_000).unwrap();
+///     assert_eq!(locked.address(), address);
+///     assert!(matches!(
+///         locked.sign_personal_message(b"hello").await,
+///         Err(SuiError::Lock(LockError::Locked))
+///     ));
+///     assert!(matches!(locked.unlock("battery staple"), Err(SuiError::Lock(LockError::WrongPassphrase))));
+///
+///     // the guard signs until it drops
+///     let guard = locked.unlock("correct horse").unwrap();
+///     assert!(!locked.is_locked());
+///     let signature = guard.sign_personal_message(b"hello").await.unwrap();
+///     assert!(verify_personal_message(b"hello", &signature, &address).is_ok());
+///     drop(guard);
+///     assert!(locked.is_locked());
+///
+///     // relocked once the timeout passes, while the guard is still held
+///     let locked = locked.with_relock_timeout(Duration::from_millis(100));
+///     let guard = locked.unlock("correct horse").unwrap();
+///     tokio::time::sleep(Duration::from_millis(150)).await;
+///     assert!(guard.is_locked() && locked.is_locked());
+///     assert!(matches!(guard.sign_transaction(b"tx").await, Err(SuiError::Lock(LockError::Locked))));
+///
+///     // concurrent unlock attempts get their own guards or a wrong passphrase error
+///     let locked = Arc::new(locked.with_relock_timeout(Duration::from_secs(60)));
+///     let attempts: Vec<_> = (0..8)
+///         .map(|i| {
+///             let locked = locked.clone();
+///             tokio::task::spawn_blocking(move || {
+///                 locked.unlock(if i % 2 == 0 { "correct horse" } else { "wrong" })
+///             })
+///         })
+///         .collect();
+///     let mut guards = Vec::new();
+///     for (i, attempt) in attempts.into_iter().enumerate() {
+///         match attempt.await.unwrap() {
+///             Ok(guard) => guards.push(guard),
+///             Err(e) => assert!(i % 2 == 1 && matches!(e, SuiError::Lock(LockError::WrongPassphrase))),
+///         }
+///     }
+///     assert_eq!(guards.len(), 4);
+///     for guard in &guards {
+///         assert!(guard.sign_transaction(b"tx").await.is_ok());
+///     }
+///     // sealing the wallet locks every guard
+///     locked.lock();
+///     assert!(guards.iter().all(|guard| guard.is_locked()));
+/// }
+/// ```
+pub struct LockedWallet {
+    address: String,
+    public_key: [u8; 32],
+    salt: [u8; 16],
+    kdf_iterations: u32,
+    nonce: [u8; 12],
+    sealed_key: Vec<u8>,
+    relock_timeout: Duration,
+    guards: Mutex<Vec<Weak<KeySlot>>>,
+    audit: AuditSlot,
+}
+
+impl Wallet {
+    /// # Lock
+    ///
+    /// Seals the wallet's private key under a passphrase, the plaintext key is zeroized.
+    ///
+    /// ## Parameters
+    /// - passphrase : passphrase needed to unlock
+    ///
+    /// ## Returns
+    /// - Ok(LockedWallet) : wallet with its key encrypted, relocking after a minute
+    /// - Err(SuiError) : encryption failed
+    pub fn lock(self, passphrase: &str) -> Result<LockedWallet, SuiError> {
+        self.lock_with_kdf_iterations(passphrase, LOCK_KDF_ITERATIONS)
+    }
+
+    /// # Lock with KDF iterations
+    ///
+    /// [`Wallet::lock`] with another PBKDF2 iteration count, fewer iterations unlock
+    /// faster and make guessing the passphrase cheaper.
+    ///
+    /// ## Parameters
+    /// - passphrase : passphrase needed to unlock
+    /// - kdf_iterations : PBKDF2-HMAC-SHA256 iterations, 600 000 by default
+    ///
+    /// ## Returns
+    /// - Ok(LockedWallet) : wallet with its key encrypted, relocking after a minute
+    /// - Err(SuiError) : encryption failed
+    pub fn lock_with_kdf_iterations(
+        mut self,
+        passphrase: &str,
+        kdf_iterations: u32,
+    ) -> Result<LockedWallet, SuiError> {
+        let mut salt = [0u8; 16];
+        let mut nonce = [0u8; 12];
+        rng().fill(&mut salt);
+        rng().fill(&mut nonce);
+        let mut sealed_key = self.keypair.private_key.to_vec();
+        self.keypair.private_key.zeroize();
+        sealing_key(passphrase, &salt, kdf_iterations)?
+            .seal_in_place_append_tag(
+                Nonce::assume_unique_for_key(nonce),
+                Aad::from(self.address.as_bytes()),
+                &mut sealed_key,
+            )
+            .map_err(|_| SuiError::Sign("Key encryption error".to_string()))?;
+        Ok(LockedWallet {
+            address: self.address.clone(),
+            public_key: self.keypair.public_key,
+            salt,
+            kdf_iterations,
+            nonce,
+            sealed_key,
+            relock_timeout: DEFAULT_RELOCK_TIMEOUT,
+            guards: Mutex::new(Vec::new()),
+            audit: self.audit.clone(),
+        })
+    }
+}
+
+impl LockedWallet {
+    /// set how long a guard stays unlocked, a minute by default
+    pub fn with_relock_timeout(mut self, relock_timeout: Duration) -> Self {
+        self.relock_timeout = relock_timeout;
+        self
+    }
+
+    /// set the signing audit of the guards, see [`Wallet::with_audit`]
+    pub fn with_audit(mut self, audit: SigningAudit) -> Self {
+        self.audit = AuditSlot(Some(Arc::new(audit)));
+        self
+    }
+
+    /// wallet address
+    pub fn address(&self) -> &str {
+        &self.address
+    }
+
+    /// # Unlock
+    ///
+    /// Decrypts the key for signing, for at most the relock timeout. Deriving the key takes
+    /// a noticeable moment by design, call it from a blocking context in async code.
+    ///
+    /// ## Parameters
+    /// - passphrase : passphrase the wallet was locked with
+    ///
+    /// ## Returns
+    /// - Ok(UnlockedGuard) : signer holding the decrypted key
+    /// - Err(SuiError) : `LockError::WrongPassphrase`
+    pub fn unlock(&self, passphrase: &str) -> Result<UnlockedGuard, SuiError> {
+        let mut key = Zeroizing::new(self.sealed_key.clone());
+        let private_key = sealing_key(passphrase, &self.salt, self.kdf_iterations)?
+            .open_in_place(
+                Nonce::assume_unique_for_key(self.nonce),
+                Aad::from(self.address.as_bytes()),
+                &mut key,
+            )
+            .map_err(|_| SuiError::Lock(LockError::WrongPassphrase))?;
+        let private_key: Zeroizing<[u8; 32]> = Zeroizing::new(
+            private_key
+                .try_into()
+                .map_err(|_| SuiError::Lock(LockError::WrongPassphrase))?,
+        );
+        let unlocked = Arc::new(Mutex::new(Some(private_key)));
+        let mut guards = self.guards.lock().unwrap_or_else(|e| e.into_inner());
+        guards.retain(|guard| guard.strong_count() > 0);
+        guards.push(Arc::downgrade(&unlocked));
+        // without a runtime the key is still refused after the deadline, only wiped later
+        if tokio::runtime::Handle::try_current().is_ok() {
+            let weak = Arc::downgrade(&unlocked);
+            let relock_timeout = self.relock_timeout;
+            spawn_named("sui-wallet/relock", async move {
+                tokio::time::sleep(relock_timeout).await;
+                if let Some(unlocked) = weak.upgrade() {
+                    seal(&unlocked);
+                }
+            });
+        }
+        Ok(UnlockedGuard {
+            address: self.address.clone(),
+            public_key: self.public_key,
+            deadline: Instant::now() + self.relock_timeout,
+            key: unlocked,
+            audit: self.audit.clone(),
+        })
+    }
+
+    /// seal every unlocked guard now
+    pub fn lock(&self) {
+        let guards = self.guards.lock().unwrap_or_else(|e| e.into_inner());
+        for guard in guards.iter().filter_map(Weak::upgrade) {
+            seal(&guard);
+        }
+    }
+
+    /// whether no guard holds the decrypted key
+    pub fn is_locked(&self) -> bool {
+        let guards = self.guards.lock().unwrap_or_else(|e| e.into_inner());
+        guards
+            .iter()
+            .filter_map(Weak::upgrade)
+            .all(|guard| guard.lock().unwrap_or_else(|e| e.into_inner()).is_none())
+    }
+}
+
+impl std::fmt::Debug for LockedWallet {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("LockedWallet")
+            .field("address", &self.address)
+            .field("relock_timeout", &self.relock_timeout)
+            .finish()
+    }
+}
+
+impl Signer for LockedWallet {
+    fn address(&self) -> &str {
+        &self.address
+    }
+    fn public_key(&self) -> &[u8] {
+        &self.public_key
+    }
+    fn sign_transaction<'s>(
+        &'s self,
+        _tx_bytes: &'s [u8],
+    ) -> BoxFuture<'s, Result<Vec<u8>, SuiError>> {
+        Box::pin(future::ready(Err(SuiError::Lock(LockError::Locked))))
+    }
+    fn sign_personal_message<'s>(
+        &'s self,
+        _message: &'s [u8],
+    ) -> BoxFuture<'s, Result<String, SuiError>> {
+        Box::pin(future::ready(Err(SuiError::Lock(LockError::Locked))))
+    }
+}
+
+/// # Unlocked guard
+///
+/// Signer of an unlocked [`LockedWallet`], the decrypted key is zeroized when the guard
+/// drops or the relock timeout passes. Signing after that fails with `LockError::Locked`.
+pub struct UnlockedGuard {
+    address: String,
+    public_key: [u8; 32],
+    deadline: Instant,
+    key: Arc<KeySlot>,
+    audit: AuditSlot,
+}
+
+impl UnlockedGuard {
+    /// whether the key was resealed
+    pub fn is_locked(&self) -> bool {
+        self.key.lock().unwrap_or_else(|e| e.into_inner()).is_none()
+    }
+
+    /// sign a digest with the decrypted key
+    fn sign(
+        &self,
+        scope: IntentScope,
+        digest: &[u8],
+        tx_bytes: Option<&[u8]>,
+    ) -> Result<Vec<u8>, SuiError> {
+        if Instant::now() >= self.deadline {
+            seal(&self.key);
+        }
+        let signature = {
+            let key = self.key.lock().unwrap_or_else(|e| e.into_inner());
+            let key = key.as_ref().ok_or(SuiError::Lock(LockError::Locked))?;
+            SigningKey::from_bytes(key).sign(digest).to_bytes().to_vec()
+        };
+        self.audit.emit(&self.address, scope, digest, tx_bytes);
+        Ok(signature)
+    }
+}
+
+impl Drop for UnlockedGuard {
+    fn drop(&mut self) {
+        seal(&self.key);
+    }
+}
+
+impl std::fmt::Debug for UnlockedGuard {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("UnlockedGuard")
+            .field("address", &self.address)
+            .field("locked", &self.is_locked())
+            .finish()
+    }
+}
+
+impl Signer for UnlockedGuard {
+    fn address(&self) -> &str {
+        &self.address
+    }
+    fn public_key(&self) -> &[u8] {
+        &self.public_key
+    }
+    fn sign_transaction<'s>(
+        &'s self,
+        tx_bytes: &'s [u8],
+    ) -> BoxFuture<'s, Result<Vec<u8>, SuiError>> {
+        Box::pin(future::ready(self.sign(
+            IntentScope::TransactionData,
+            &transaction_digest(tx_bytes),
+            Some(tx_bytes),
+        )))
+    }
+    fn sign_personal_message<'s>(
+        &'s self,
+        message: &'s [u8],
+    ) -> BoxFuture<'s, Result<String, SuiError>> {
+        let signature = self
+            .sign(
+                IntentScope::PersonalMessage,
+                &personal_message_digest(message),
+                None,
+            )
+            .map(|signature| {
+                serialized_signature(SignatureScheme::Ed25519, &signature, &self.public_key)
+            });
+        Box::pin(future::ready(signature))
+    }
+}
+
+/// AES-256-GCM key derived from a passphrase
+fn sealing_key(passphrase: &str, salt: &[u8], iterations: u32) -> Result<LessSafeKey, SuiError> {
+    let mut key = Zeroizing::new([0u8; 32]);
+    pbkdf2::derive(
+        pbkdf2::PBKDF2_HMAC_SHA256,
+        NonZeroU32::new(iterations).unwrap_or(NonZeroU32::MIN),
+        salt,
+        passphrase.as_bytes(),
+        &mut *key,
+    );
+    UnboundKey::new(&AES_256_GCM, &*key)
+        .map(LessSafeKey::new)
+        .map_err(|_| SuiError::Sign("Key derivation error".to_string()))
+}
+
+/// zeroize the key of a guard
+fn seal(key: &KeySlot) {
+    key.lock().unwrap_or_else(|e| e.into_inner()).take();
+}
+
+/// DER SubjectPublicKeyInfo prefix of an Ed25519 key, followed by the 32 key bytes
+const ED25519_SPKI_PREFIX: [u8; 12] = [
+    0x30, 0x2a, 0x30, 0x05, 0x06, 0x03, 0x2b, 0x65, 0x70, 0x03, 0x21, 0x00,
+];
+/// DER SubjectPublicKeyInfo prefix of a secp256k1 key, followed by the 65 uncompressed point bytes
+const SECP256K1_SPKI_PREFIX: [u8; 23] = [
+    0x30, 0x56, 0x30, 0x10, 0x06, 0x07, 0x2a, 0x86, 0x48, 0xce, 0x3d, 0x02, 0x01, 0x06, 0x05, 0x2b,
+    0x81, 0x04, 0x00, 0x0a, 0x03, 0x42, 0x00,
+];
+/// order n of the secp256k1 group
+const SECP256K1_ORDER: [u8; 32] = [
+    0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xfe,
+    0xba, 0xae, 0xdc, 0xe6, 0xaf, 0x48, 0xa0, 0x3b, 0xbf, 0xd2, 0x5e, 0x8c, 0xd0, 0x36, 0x41, 0x41,
+];
+/// n / 2, the largest low s value
+const SECP256K1_HALF_ORDER: [u8; 32] = [
+    0x7f, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+    0x5d, 0x57, 0x6e, 0x73, 0x57, 0xa4, 0x50, 0x1d, 0xdf, 0xe9, 0x2f, 0x46, 0x68, 0x1b, 0x20, 0xa0,
+];
+
+/// # Sui public key
+///
+/// Public key of an externally held secret, with its signature scheme.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SuiPublicKey {
+    Ed25519([u8; 32]),
+    /// compressed SEC1 point
+    Secp256k1([u8; 33]),
+}
+
+impl SuiPublicKey {
+    /// # Ed25519 public key
+    ///
+    /// ## Parameters
+    /// - bytes : 32 raw key bytes, or the DER SubjectPublicKeyInfo a KMS returns
+    ///
+    /// ## Returns
+    /// - Ok(SuiPublicKey) : public key
+    /// - Err(SuiError) : not an Ed25519 public key
+    pub fn ed25519(bytes: &[u8]) -> Result<Self, SuiError> {
+        let bytes = bytes.strip_prefix(&ED25519_SPKI_PREFIX).unwrap_or(bytes);
+        let key: [u8; 32] = bytes.try_into().map_err(|_| {
+            SuiError::Sign(format!("Invalid Ed25519 public key length {}", bytes.len()))
+        })?;
+        VerifyingKey::from_bytes(&key)?;
+        Ok(SuiPublicKey::Ed25519(key))
+    }
+    /// # Secp256k1 public key
+    ///
+    /// ## Parameters
+    /// - bytes : 33 compressed or 65 uncompressed SEC1 bytes, or the DER
+    ///   SubjectPublicKeyInfo a KMS returns
+    ///
+    /// ## Returns
+    /// - Ok(SuiPublicKey) : public key, compressed
+    /// - Err(SuiError) : not a point of the secp256k1 curve
+    ///
+    /// ## Example
+    /// ```rust
+    /// use sui_network_sdk::wallet::SuiPublicKey;
+    /// // generator point G, uncompressed
+    /// let g = hex::decode(concat!(
+    ///     "0479be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798",
+    ///     "483ada7726a3c4655da4fbfc0e1108a8fd17b448a68554199c47d08ffb10d4b8",
+    /// ))
+    /// .unwrap();
+    /// let key = SuiPublicKey::secp256k1(&g).unwrap();
+    /// assert_eq!(
+    ///     hex::encode(key.as_bytes()),
+    ///     "0279be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798"
+    /// );
+    /// assert!(SuiPublicKey::secp256k1(&[4u8; 65]).is_err());
+    /// ```
+    pub fn secp256k1(bytes: &[u8]) -> Result<Self, SuiError> {
+        let bytes = bytes.strip_prefix(&SECP256K1_SPKI_PREFIX).unwrap_or(bytes);
+        let public_key = Secp256k1PublicKey::from_bytes(bytes)
+            .map_err(|_| SuiError::Sign("Invalid secp256k1 public key".to_string()))?;
+        let compressed: [u8; 33] = public_key
+            .as_bytes()
+            .try_into()
+            .map_err(|_| SuiError::Sign("Invalid secp256k1 public key".to_string()))?;
+        Ok(SuiPublicKey::Secp256k1(compressed))
+    }
+    /// signature scheme of the key
+    pub fn scheme(&self) -> SignatureScheme {
+        match self {
+            SuiPublicKey::Ed25519(_) => SignatureScheme::Ed25519,
+            SuiPublicKey::Secp256k1(_) => SignatureScheme::Secp256k1,
+        }
+    }
+    /// key bytes as they appear in a serialized signature
+    pub fn as_bytes(&self) -> &[u8] {
+        match self {
+            SuiPublicKey::Ed25519(key) => key,
+            SuiPublicKey::Secp256k1(key) => key,
+        }
+    }
+    /// derived Sui address
+    pub fn address(&self) -> String {
+        self.scheme().address(self.as_bytes())
+    }
+}
+
+type SignFn = Box<dyn Fn(Vec<u8>) -> BoxFuture<'static, Result<Vec<u8>, SuiError>> + Send + Sync>;
+
+/// # External signer
+///
+/// [`Signer`] whose secret never enters the process: a KMS, an HSM or a remote signing
+/// service. The SDK hashes the intent message, hands the 32 byte digest to `sign_fn`, wraps
+/// the returned raw signature into the serialized Sui signature and verifies it against the
+/// public key, so a misconfigured key id fails here instead of on chain.
+///
+/// `sign_fn` signs the digest the way the scheme expects: Ed25519 signs it directly,
+/// secp256k1 signs its SHA-256 (ECDSA_SHA_256 with a raw message, as KMS services
+/// do). A secp256k1 signature may come back as DER or compact 64 bytes, high s values are
+/// normalized.
+///
+/// ## Example
+/// ```rust
+/// use fastcrypto::secp256k1::{Secp256k1KeyPair, Secp256k1PrivateKey};
+/// use fastcrypto::traits::{KeyPair, Signer as _, ToFromBytes};
+/// use std::sync::Arc;
+/// use std::sync::atomic::{AtomicUsize, Ordering};
+/// use sui_network_sdk::wallet::{ExternalSigner, Signer, SuiPublicKey, verify_personal_message};
+///
+/// # #[tokio::main]
+/// # async fn main() {
+/// // stand-in for the KMS
+/// let kms = Arc::new(Secp256k1KeyPair::from(
+///     Secp256k1PrivateKey::from_bytes(&[7u8; 32]).unwrap(),
+/// ));
+/// let public_key = SuiPublicKey::secp256k1(kms.public().as_bytes()).unwrap();
+/// let signer = ExternalSigner::new(public_key, move |digest| {
+///     let kms = kms.clone();
+///     async move { Ok(kms.sign(&digest).as_bytes().to_vec()) }
+/// });
+/// let audited = Arc::new(AtomicUsize::new(0));
+/// let counter = audited.clone();
+/// let signer = signer.with_audit(Box::new(move |event| {
+///     assert_eq!(event.digest.len(), 32);
+///     counter.fetch_add(1, Ordering::SeqCst);
+/// }));
+/// assert_eq!(signer.address(), public_key.address());
+///
+/// let signature = signer.sign_transaction(b"tx bytes").await.unwrap();
+/// assert_eq!(signature.len(), 64);
+/// let message = signer.sign_personal_message(b"hello").await.unwrap();
+/// assert!(verify_personal_message(b"hello", &message, signer.address()).is_ok());
+/// assert!(verify_personal_message(b"hellO", &message, signer.address()).is_err());
+/// assert_eq!(audited.load(Ordering::SeqCst), 2);
+/// # }
+/// ```
+pub struct ExternalSigner {
+    public_key: SuiPublicKey,
+    address: String,
+    sign_fn: SignFn,
+    audit: AuditSlot,
+}
+
+impl ExternalSigner {
+    /// # create external signer
+    ///
+    /// ## Parameters
+    /// - public_key : public key of the external secret
+    /// - sign_fn : async closure signing a 32 byte digest, returns the raw signature
+    pub fn new<F, Fut>(public_key: SuiPublicKey, sign_fn: F) -> Self
+    where
+        F: Fn(Vec<u8>) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<Vec<u8>, SuiError>> + Send + 'static,
+    {
+        Self {
+            address: public_key.address(),
+            public_key,
+            sign_fn: Box::new(move |digest| Box::pin(sign_fn(digest))),
+            audit: AuditSlot::default(),
+        }
+    }
+    /// set the signing audit, see [`Wallet::with_audit`]
+    pub fn with_audit(mut self, audit: SigningAudit) -> Self {
+        self.audit = AuditSlot(Some(Arc::new(audit)));
+        self
+    }
+    /// public key of the external secret
+    pub fn sui_public_key(&self) -> &SuiPublicKey {
+        &self.public_key
+    }
+    /// sign a digest externally and audit it
+    async fn sign_digest(
+        &self,
+        scope: IntentScope,
+        digest: [u8; 32],
+        tx_bytes: Option<&[u8]>,
+    ) -> Result<Vec<u8>, SuiError> {
+        let signature = self.verified_signature(digest).await?;
+        self.audit.emit(&self.address, scope, &digest, tx_bytes);
+        Ok(signature)
+    }
+    /// sign a digest externally, returns the verified compact signature
+    async fn verified_signature(&self, digest: [u8; 32]) -> Result<Vec<u8>, SuiError> {
+        let signature = (self.sign_fn)(digest.to_vec()).await?;
+        match &self.public_key {
+            SuiPublicKey::Ed25519(key) => {
+                let signature: [u8; 64] = signature.as_slice().try_into().map_err(|_| {
+                    SuiError::Sign(format!(
+                        "Invalid Ed25519 signature length {}",
+                        signature.len()
+                    ))
+                })?;
+                VerifyingKey::from_bytes(key)?
+                    .verify_strict(&digest, &Signature::from_bytes(&signature))
+                    .map_err(|_| {
+                        SuiError::Sign(
+                            "External signature does not match the public key".to_string(),
+                        )
+                    })?;
+                Ok(signature.to_vec())
+            }
+            SuiPublicKey::Secp256k1(key) => {
+                let signature = if signature.len() == 64 {
+                    let mut compact = [0u8; 64];
+                    compact.copy_from_slice(&signature);
+                    compact
+                } else {
+                    secp256k1_signature_from_der(&signature)?
+                };
+                let signature = secp256k1_normalize_s(signature);
+                let verified = Secp256k1PublicKey::from_bytes(key).and_then(|key| {
+                    Secp256k1Signature::from_bytes(&signature)
+                        .and_then(|signature| key.verify(&digest, &signature))
+                });
+                verified.map_err(|_| {
+                    SuiError::Sign("External signature does not match the public key".to_string())
+                })?;
+                Ok(signature.to_vec())
+            }
+        }
+    }
+}
+
+impl Signer for ExternalSigner {
+    fn address(&self) -> &str {
+        &self.address
+    }
+    fn public_key(&self) -> &[u8] {
+        self.public_key.as_bytes()
+    }
+    fn scheme(&self) -> SignatureScheme {
+        self.public_key.scheme()
+    }
+    fn sign_transaction<'s>(
+        &'s self,
+        tx_bytes: &'s [u8],
+    ) -> BoxFuture<'s, Result<Vec<u8>, SuiError>> {
+        Box::pin(self.sign_digest(
+            IntentScope::TransactionData,
+            transaction_digest(tx_bytes),
+            Some(tx_bytes),
+        ))
+    }
+    fn sign_personal_message<'s>(
+        &'s self,
+        message: &'s [u8],
+    ) -> BoxFuture<'s, Result<String, SuiError>> {
+        Box::pin(async move {
+            let signature = self
+                .sign_digest(
+                    IntentScope::PersonalMessage,
+                    personal_message_digest(message),
+                    None,
+                )
+                .await?;
+            Ok(serialized_signature(
+                self.public_key.scheme(),
+                &signature,
+                self.public_key.as_bytes(),
+            ))
+        })
+    }
+}
+
+/// # Secp256k1 signature from DER
+///
+/// Converts a DER encoded ECDSA signature, `SEQUENCE { INTEGER r, INTEGER s }`, into the
+/// 64 byte compact `r || s` form Sui expects. The s value is kept as is, see
+/// [`secp256k1_normalize_s`].
+///
+/// ## Parameters
+/// - der : DER signature
+///
+/// ## Returns
+/// - Ok([u8; 64]) : compact signature
+/// - Err(SuiError) : malformed DER
+///
+/// ## Example
+/// ```rust
+/// use sui_network_sdk::wallet::secp256k1_signature_from_der;
+/// // r = 0x7f (short INTEGER), s with its high bit set (leading zero byte)
+/// let mut der = vec![0x30, 0x26, 0x02, 0x01, 0x7f, 0x02, 0x21, 0x00];
+/// der.extend_from_slice(&[0x80; 32]);
+/// let compact = secp256k1_signature_from_der(&der).unwrap();
+/// assert_eq!(compact[..31], [0u8; 31]);
+/// assert_eq!(compact[31], 0x7f);
+/// assert_eq!(compact[32..], [0x80; 32]);
+/// assert!(secp256k1_signature_from_der(&der[..der.len() - 1]).is_err());
+/// ```
+pub fn secp256k1_signature_from_der(der: &[u8]) -> Result<[u8; 64], SuiError> {
+    let malformed = || SuiError::Sign("Malformed DER signature".to_string());
+    let body = match der {
+        [0x30, length, body @ ..] if *length as usize == body.len() => body,
+        _ => return Err(malformed()),
+    };
+    let (r, rest) = der_integer(body).ok_or_else(malformed)?;
+    let (s, rest) = der_integer(rest).ok_or_else(malformed)?;
+    if !rest.is_empty() {
+        return Err(malformed());
+    }
+    let mut compact = [0u8; 64];
+    compact[32 - r.len()..32].copy_from_slice(r);
+    compact[64 - s.len()..].copy_from_slice(s);
+    Ok(compact)
+}
+
+/// split a DER INTEGER of at most 32 significant bytes off the input
+fn der_integer(input: &[u8]) -> Option<(&[u8], &[u8])> {
+    let [0x02, length, rest @ ..] = input else {
+        return None;
+    };
+    let (value, rest) = rest.split_at_checked(*length as usize)?;
+    let value = match value {
+        [0x00, tail @ ..] if !tail.is_empty() => tail,
+        value => value,
+    };
+    (!value.is_empty() && value.len() <= 32).then_some((value, rest))
+}
+
+/// # Secp256k1 low s normalization
+///
+/// ECDSA signatures `(r, s)` and `(r, n - s)` are both valid, Sui accepts only the low
+/// form `s <= n / 2`. Replaces a high s by `n - s`.
+///
+/// ## Parameters
+/// - signature : compact `r || s` signature
+///
+/// ## Returns
+/// - [u8; 64] : signature with a low s
+///
+/// ## Example
+/// ```rust
+/// use sui_network_sdk::wallet::secp256k1_normalize_s;
+/// let mut signature = [0u8; 64];
+/// signature[0] = 1;
+/// // s = n - 1
+/// signature[32..].copy_from_slice(
+///     &hex::decode("fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364140").unwrap(),
+/// );
+/// let normalized = secp256k1_normalize_s(signature);
+/// assert_eq!(normalized[..32], signature[..32]);
+/// let mut one = [0u8; 32];
+/// one[31] = 1;
+/// assert_eq!(normalized[32..], one);
+/// // already low
+/// assert_eq!(secp256k1_normalize_s(normalized), normalized);
+/// ```
+pub fn secp256k1_normalize_s(signature: [u8; 64]) -> [u8; 64] {
+    if signature[32..] <= SECP256K1_HALF_ORDER[..] {
+        return signature;
+    }
+    let mut normalized = signature;
+    let mut borrow = 0u16;
+    for i in (0..32).rev() {
+        let difference = 0x100 + SECP256K1_ORDER[i] as u16 - signature[32 + i] as u16 - borrow;
+        normalized[32 + i] = difference as u8;
+        borrow = if difference < 0x100 { 1 } else { 0 };
+    }
+    normalized
+}
+
 impl Default for Wallet {
     fn default() -> Self {
         Self::new().expect("Failed to create default wallet")
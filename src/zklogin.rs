@@ -0,0 +1,301 @@
+use crate::types::{SuiError, ZkLoginError};
+use fastcrypto::hash::{Blake2b256, HashFunction};
+
+/// scheme flag of zkLogin authenticators
+pub const ZKLOGIN_FLAG: u8 = 0x05;
+/// padded length of the key claim name, `sub` or `email`
+pub const MAX_KEY_CLAIM_NAME_LENGTH: usize = 32;
+/// padded length of the key claim value
+pub const MAX_KEY_CLAIM_VALUE_LENGTH: usize = 115;
+/// padded length of the audience
+pub const MAX_AUD_VALUE_LENGTH: usize = 145;
+/// bytes packed into one field element
+const PACK_BYTES: usize = 31;
+/// modulus of the BN254 scalar field, big-endian
+const BN254_MODULUS: [u8; 32] = [
+    0x30, 0x64, 0x4e, 0x72, 0xe1, 0x31, 0xa0, 0x29, 0xb8, 0x50, 0x45, 0xb6, 0x81, 0x81, 0x58, 0x5d,
+    0x28, 0x33, 0xe8, 0x48, 0x79, 0xb9, 0x70, 0x91, 0x43, 0xe1, 0xf5, 0x93, 0xf0, 0x00, 0x00, 0x01,
+];
+
+/// # Poseidon
+///
+/// Poseidon hash over the BN254 scalar field with the circom round constants, the hash the
+/// zkLogin circuit commits to. Field elements are 32 bytes big-endian. The `zklogin`
+/// feature ships [`CircomPoseidon`], any `Fn(&[[u8; 32]]) -> Result<[u8; 32], SuiError>`
+/// is one as well.
+pub trait Poseidon {
+    /// hash of 1 to 12 field elements, the widths of the circom parameters; zkLogin hashes
+    /// at most 5
+    fn hash(&self, inputs: &[[u8; 32]]) -> Result<[u8; 32], SuiError>;
+}
+
+impl<F> Poseidon for F
+where
+    F: Fn(&[[u8; 32]]) -> Result<[u8; 32], SuiError>,
+{
+    fn hash(&self, inputs: &[[u8; 32]]) -> Result<[u8; 32], SuiError> {
+        self(inputs)
+    }
+}
+
+/// # Circom Poseidon
+///
+/// Poseidon over BN254 with the circom parameters (x^5 S-box, 8 full rounds), as hashed by
+/// the zkLogin circuit and the official SDKs. Hashes 1 to 12 field elements, zkLogin hashes
+/// at most 5.
+///
+/// ## Example
+/// ```rust
+/// use sui_network_sdk::zklogin::{self, CircomPoseidon, Poseidon};
+///
+/// // poseidon([1, 2]) of circomlib
+/// let mut one = [0u8; 32];
+/// one[31] = 1;
+/// let mut two = [0u8; 32];
+/// two[31] = 2;
+/// assert_eq!(
+///     hex::encode(CircomPoseidon.hash(&[one, two]).unwrap()),
+///     "115cc0f5e7d690413df64c6b9662e9cf2a3617f2743245519e19607a4417189a"
+/// );
+///
+/// // address seeds and addresses of the fastcrypto zkLogin test vectors
+/// let seed = zklogin::compute_address_seed(
+///     &CircomPoseidon,
+///     "248191903847969014646285995941615069143",
+///     "sub",
+///     "904448692",
+///     "rs1bh065i9ya4ydvifixl4kss0uhpt",
+/// )
+/// .unwrap();
+/// assert_eq!(seed, "16657007263003735230240998439420301694514420923267872433517882233836276100450");
+///
+/// let seed = zklogin::compute_address_seed(
+///     &CircomPoseidon,
+///     "206703048842351542647799591018316385612",
+///     "sub",
+///     "106294049240999307923",
+///     "25769832374-famecqrhe2gkebt5fvqms2263046lj96.apps.googleusercontent.com",
+/// )
+/// .unwrap();
+/// assert_eq!(
+///     zklogin::derive_address("https://accounts.google.com", &seed).unwrap(),
+///     "0xa64ae946d5efd2dea396cb2fe81837f028c32f2b2f211176b65a3a152deb35a2"
+/// );
+///
+/// let seed = zklogin::compute_address_seed(
+///     &CircomPoseidon,
+///     "6588741469050502421550140105345050859",
+///     "sub",
+///     "106294049240999307923",
+///     "575519204237-msop9ep45u2uo98hapqmngv8d84qdc8k.apps.googleusercontent.com",
+/// )
+/// .unwrap();
+/// assert_eq!(
+///     zklogin::derive_address("accounts.google.com", &seed).unwrap(),
+///     "0x1c6b623a2f2c91333df730c98d220f11484953b391a3818680f922c264cc0c6b"
+/// );
+///
+/// assert!(CircomPoseidon.hash(&[]).is_err());
+/// assert!(CircomPoseidon.hash(&[[0xff; 32]]).is_err());
+/// ```
+#[cfg(feature = "zklogin")]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct CircomPoseidon;
+
+#[cfg(feature = "zklogin")]
+impl Poseidon for CircomPoseidon {
+    fn hash(&self, inputs: &[[u8; 32]]) -> Result<[u8; 32], SuiError> {
+        use light_poseidon::PoseidonBytesHasher;
+
+        let inputs: Vec<&[u8]> = inputs.iter().map(|input| input.as_slice()).collect();
+        light_poseidon::Poseidon::<ark_bn254::Fr>::new_circom(inputs.len())
+            .and_then(|mut poseidon| poseidon.hash_bytes_be(&inputs))
+            .map_err(|e| SuiError::ZkLogin(ZkLoginError::Hash(e.to_string())))
+    }
+}
+
+/// # zkLogin address
+///
+/// Derives the address of an OpenID identity locally, BLAKE2b-256 over the zkLogin flag,
+/// the issuer length and bytes and the address seed padded to 32 bytes. The legacy Google
+/// issuer `accounts.google.com` is read as `https://accounts.google.com`.
+///
+/// ## Parameters
+/// - iss : issuer of the JWT
+/// - address_seed : address seed in decimal, see [`compute_address_seed`]
+///
+/// ## Returns
+/// - Ok(String) : address
+/// - Err(SuiError::ZkLogin) : the issuer is longer than 255 bytes or the seed is not a field element
+///
+/// ## Example
+/// ```rust
+/// use fastcrypto::hash::{Blake2b256, HashFunction};
+/// use sui_network_sdk::zklogin;
+///
+/// let address = zklogin::derive_address("https://accounts.google.com", "256").unwrap();
+/// let mut seed = [0u8; 32];
+/// seed[30] = 1;
+/// let mut hasher = Blake2b256::default();
+/// hasher.update([zklogin::ZKLOGIN_FLAG, 27]);
+/// hasher.update(b"https://accounts.google.com");
+/// hasher.update(seed);
+/// assert_eq!(address, format!("0x{}", hex::encode(hasher.finalize().digest)));
+/// assert_eq!(zklogin::derive_address("accounts.google.com", "256").unwrap(), address);
+/// assert!(zklogin::derive_address("https://accounts.google.com", "0x100").is_err());
+/// ```
+pub fn derive_address(iss: &str, address_seed: &str) -> Result<String, SuiError> {
+    let iss = normalize_issuer(iss);
+    let iss_len = u8::try_from(iss.len())
+        .map_err(|_| SuiError::ZkLogin(ZkLoginError::IssuerTooLong(iss.len())))?;
+    let seed = parse_field(address_seed).ok_or_else(|| {
+        SuiError::ZkLogin(ZkLoginError::InvalidFieldElement(address_seed.to_string()))
+    })?;
+    let mut hasher = Blake2b256::default();
+    hasher.update([ZKLOGIN_FLAG, iss_len]);
+    hasher.update(iss.as_bytes());
+    hasher.update(seed);
+    Ok(format!("0x{}", hex::encode(hasher.finalize().digest)))
+}
+
+/// # zkLogin address seed
+///
+/// Computes the address seed of an identity, the Poseidon hash of the key claim name, the
+/// key claim value, the audience and the hashed salt. Each string is zero padded to its
+/// maximum length and packed big-endian into 31-byte field elements from the right, then
+/// hashed into one element.
+///
+/// ## Parameters
+/// - poseidon : Poseidon BN254 hash
+/// - salt : user salt in decimal
+/// - name : key claim name, usually `sub`
+/// - value : key claim value
+/// - aud : audience, the OAuth client id
+///
+/// ## Returns
+/// - Ok(String) : address seed in decimal
+/// - Err(SuiError::ZkLogin) : the salt is not a field element or a string is too long
+/// - Err(SuiError) : error of the hash
+///
+/// ## Example
+/// ```rust
+/// use std::sync::Mutex;
+/// use sui_network_sdk::types::SuiError;
+/// use sui_network_sdk::zklogin;
+///
+/// // checks the packing only, see `CircomPoseidon` for the published vectors;
+/// // records its inputs and answers the number of inputs
+/// let calls = Mutex::new(Vec::new());
+/// let poseidon = |inputs: &[[u8; 32]]| -> Result<[u8; 32], SuiError> {
+///     calls.lock().unwrap().push(inputs.to_vec());
+///     let mut out = [0u8; 32];
+///     out[31] = inputs.len() as u8;
+///     Ok(out)
+/// };
+/// let seed = zklogin::compute_address_seed(&poseidon, "7", "sub", "1234", "client").unwrap();
+/// assert_eq!(seed, "4");
+/// assert!(zklogin::compute_address_seed(&poseidon, "7", &"x".repeat(33), "1", "c").is_err());
+///
+/// let calls = calls.into_inner().unwrap();
+/// // salt, name, value, audience, the seed itself, then the salt of the failed call
+/// assert_eq!(calls.iter().map(Vec::len).collect::<Vec<_>>(), [1, 2, 4, 5, 4, 1]);
+/// assert_eq!(calls[0][0][31], 7);
+/// // "sub" padded to 32 bytes splits into "s" and "ub" followed by 29 zeros
+/// assert_eq!(calls[1][0][31], b's');
+/// assert_eq!(&calls[1][1][1..3], b"ub");
+/// ```
+pub fn compute_address_seed(
+    poseidon: &impl Poseidon,
+    salt: &str,
+    name: &str,
+    value: &str,
+    aud: &str,
+) -> Result<String, SuiError> {
+    let salt = parse_field(salt)
+        .ok_or_else(|| SuiError::ZkLogin(ZkLoginError::InvalidFieldElement(salt.to_string())))?;
+    let salt_hash = poseidon.hash(&[salt])?;
+    let seed = poseidon.hash(&[
+        hash_claim(poseidon, "name", name, MAX_KEY_CLAIM_NAME_LENGTH)?,
+        hash_claim(poseidon, "value", value, MAX_KEY_CLAIM_VALUE_LENGTH)?,
+        hash_claim(poseidon, "aud", aud, MAX_AUD_VALUE_LENGTH)?,
+        salt_hash,
+    ])?;
+    Ok(format_field(seed))
+}
+
+/// the issuer as the circuit reads it
+fn normalize_issuer(iss: &str) -> &str {
+    if iss == "accounts.google.com" {
+        "https://accounts.google.com"
+    } else {
+        iss
+    }
+}
+
+/// hash of a string zero padded to max bytes and packed into field elements
+fn hash_claim(
+    poseidon: &impl Poseidon,
+    claim: &'static str,
+    s: &str,
+    max: usize,
+) -> Result<[u8; 32], SuiError> {
+    if s.len() > max {
+        return Err(SuiError::ZkLogin(ZkLoginError::ClaimTooLong {
+            claim,
+            len: s.len(),
+            max,
+        }));
+    }
+    let mut padded = s.as_bytes().to_vec();
+    padded.resize(max, 0);
+    // chunks are taken from the right, the first element holds the remainder
+    let mut packed: Vec<[u8; 32]> = padded
+        .rchunks(PACK_BYTES)
+        .map(|chunk| {
+            let mut element = [0u8; 32];
+            element[32 - chunk.len()..].copy_from_slice(chunk);
+            element
+        })
+        .collect();
+    packed.reverse();
+    poseidon.hash(&packed)
+}
+
+/// decimal string to a big-endian field element, none when it is not below the modulus
+fn parse_field(decimal: &str) -> Option<[u8; 32]> {
+    if decimal.is_empty() || !decimal.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    let mut out = [0u8; 32];
+    for digit in decimal.bytes() {
+        let mut carry = u16::from(digit - b'0');
+        for byte in out.iter_mut().rev() {
+            let v = u16::from(*byte) * 10 + carry;
+            *byte = v as u8;
+            carry = v >> 8;
+        }
+        if carry != 0 {
+            return None;
+        }
+    }
+    (out < BN254_MODULUS).then_some(out)
+}
+
+/// big-endian field element to a decimal string
+fn format_field(mut element: [u8; 32]) -> String {
+    let mut digits = Vec::new();
+    while element.iter().any(|b| *b != 0) {
+        let mut remainder = 0u16;
+        for byte in element.iter_mut() {
+            let v = (remainder << 8) | u16::from(*byte);
+            *byte = (v / 10) as u8;
+            remainder = v % 10;
+        }
+        digits.push(b'0' + remainder as u8);
+    }
+    if digits.is_empty() {
+        return "0".to_string();
+    }
+    digits.reverse();
+    String::from_utf8(digits).unwrap_or_default()
+}
@@ -0,0 +1,89 @@
+//! Addresses of every fixture come out canonical after deserialization.
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use std::path::Path;
+use sui_network_sdk::types::{Coin, Object, Page, TransactionResponse};
+
+/// fields the crate passes through as raw JSON, left as the node sent them
+const RAW_FIELDS: [&str; 2] = ["parsedJson", "fields"];
+
+/// fixture deserialized into its type and serialized back
+fn reparse<T: DeserializeOwned + Serialize>(json: &str) -> serde_json::Value {
+    let value: T = serde_json::from_str(json).unwrap();
+    serde_json::to_value(value).unwrap()
+}
+
+/// deserializing type of a fixture, by its path under `fixtures/`
+fn parser(path: &str) -> fn(&str) -> serde_json::Value {
+    match path {
+        "execution_failures.json" => reparse::<serde_json::Value>,
+        "responses/coins.json" => reparse::<Page<Coin>>,
+        "responses/object.json" => reparse::<Object>,
+        "responses/transaction_block.json" => reparse::<TransactionResponse>,
+        other => panic!("fixture {} has no type in tests/addresses.rs", other),
+    }
+}
+
+/// hex strings of the value that aren't canonical addresses
+fn non_canonical(value: &serde_json::Value, path: String, found: &mut Vec<String>) {
+    match value {
+        serde_json::Value::String(s) => {
+            let is_hex = s
+                .strip_prefix("0x")
+                .is_some_and(|hex| !hex.is_empty() && hex.chars().all(|c| c.is_ascii_hexdigit()));
+            let canonical = s.len() == 66 && !s.chars().any(|c| c.is_ascii_uppercase());
+            if is_hex && !canonical {
+                found.push(format!("{} = {}", path, s));
+            }
+        }
+        serde_json::Value::Array(values) => {
+            for (i, value) in values.iter().enumerate() {
+                non_canonical(value, format!("{}[{}]", path, i), found);
+            }
+        }
+        serde_json::Value::Object(map) => {
+            for (key, value) in map {
+                if !RAW_FIELDS.contains(&key.as_str()) {
+                    non_canonical(value, format!("{}.{}", path, key), found);
+                }
+            }
+        }
+        _ => {}
+    }
+}
+
+/// json files under a directory, recursively
+fn json_files(dir: &Path, files: &mut Vec<std::path::PathBuf>) {
+    for entry in std::fs::read_dir(dir).unwrap() {
+        let path = entry.unwrap().path();
+        if path.is_dir() {
+            json_files(&path, files);
+        } else if path.extension().is_some_and(|e| e == "json") {
+            files.push(path);
+        }
+    }
+}
+
+#[test]
+fn fixtures_deserialize_to_canonical_addresses() {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("fixtures");
+    let mut files = Vec::new();
+    json_files(&root, &mut files);
+    files.sort();
+    assert!(files.len() > 1);
+    let mut found = Vec::new();
+    for file in &files {
+        let name = file
+            .strip_prefix(&root)
+            .unwrap()
+            .to_string_lossy()
+            .replace('\\', "/");
+        let json = std::fs::read_to_string(file).unwrap();
+        non_canonical(&parser(&name)(&json), name, &mut found);
+    }
+    assert!(
+        found.is_empty(),
+        "non-canonical addresses:\n{}",
+        found.join("\n")
+    );
+}
@@ -0,0 +1,196 @@
+//! Local nodes for the integration tests.
+//!
+//! An HTTP/1.1 JSON-RPC node reading each request to its `Content-Length`, and a websocket
+//! node handing each accepted connection to the test.
+#![allow(dead_code)]
+
+use futures::SinkExt;
+use std::future::Future;
+use std::net::SocketAddr;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+use tokio_tungstenite::WebSocketStream;
+use tokio_tungstenite::tungstenite::Message;
+
+/// request received by an [`HttpNode`]
+#[derive(Debug, Clone)]
+pub struct HttpRequest {
+    /// request line and headers, lowercased
+    pub head: String,
+    pub body: String,
+}
+
+impl HttpRequest {
+    /// value of a header
+    pub fn header(&self, name: &str) -> Option<&str> {
+        let prefix = format!("{}:", name.to_lowercase());
+        self.head
+            .lines()
+            .find_map(|line| line.strip_prefix(prefix.as_str()))
+            .map(str::trim)
+    }
+}
+
+/// response of an [`HttpNode`]
+#[derive(Debug, Clone)]
+pub struct HttpResponse {
+    status: String,
+    headers: Vec<(String, String)>,
+    body: String,
+    delay: Duration,
+}
+
+impl HttpResponse {
+    /// JSON-RPC result
+    pub fn result(result: serde_json::Value) -> Self {
+        let body = serde_json::json!({ "jsonrpc": "2.0", "id": 1, "result": result });
+        Self::status("200 OK", &body.to_string()).header("Content-Type", "application/json")
+    }
+
+    /// response of a status, e.g. `503 Service Unavailable`
+    pub fn status(status: &str, body: &str) -> Self {
+        Self {
+            status: status.to_string(),
+            headers: Vec::new(),
+            body: body.to_string(),
+            delay: Duration::ZERO,
+        }
+    }
+
+    /// with a header
+    pub fn header(mut self, name: &str, value: &str) -> Self {
+        self.headers.push((name.to_string(), value.to_string()));
+        self
+    }
+
+    /// sent after a delay
+    pub fn delayed(mut self, delay: Duration) -> Self {
+        self.delay = delay;
+        self
+    }
+
+    /// response as sent, closing the connection
+    fn to_bytes(&self) -> Vec<u8> {
+        let mut response = format!("HTTP/1.1 {}\r\n", self.status);
+        for (name, value) in &self.headers {
+            response.push_str(&format!("{}: {}\r\n", name, value));
+        }
+        response.push_str(&format!(
+            "Content-Length: {}\r\nConnection: close\r\n\r\n{}",
+            self.body.len(),
+            self.body
+        ));
+        response.into_bytes()
+    }
+}
+
+/// local HTTP node, one request per connection
+pub struct HttpNode {
+    pub address: SocketAddr,
+    requests: Arc<Mutex<Vec<HttpRequest>>>,
+}
+
+impl HttpNode {
+    /// start a node answering the `n`th request, counted from 0, with `respond(n, request)`
+    pub async fn start<F>(respond: F) -> Self
+    where
+        F: Fn(usize, &HttpRequest) -> HttpResponse + Send + Sync + 'static,
+    {
+        let server = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = server.local_addr().unwrap();
+        let requests = Arc::new(Mutex::new(Vec::new()));
+        let received = requests.clone();
+        let respond = Arc::new(respond);
+        tokio::spawn(async move {
+            loop {
+                let (mut socket, _) = server.accept().await.unwrap();
+                let received = received.clone();
+                let respond = respond.clone();
+                tokio::spawn(async move {
+                    let Some(request) = read_request(&mut socket).await else {
+                        return;
+                    };
+                    let response = {
+                        let mut received = received.lock().unwrap();
+                        received.push(request.clone());
+                        respond(received.len() - 1, &request)
+                    };
+                    tokio::time::sleep(response.delay).await;
+                    let _ = socket.write_all(&response.to_bytes()).await;
+                });
+            }
+        });
+        Self { address, requests }
+    }
+
+    /// http url of the node
+    pub fn url(&self) -> String {
+        format!("http://{}", self.address)
+    }
+
+    /// requests received so far
+    pub fn requests(&self) -> Vec<HttpRequest> {
+        self.requests.lock().unwrap().clone()
+    }
+}
+
+/// read the headers and the `Content-Length` body, `None` when the peer closes first
+async fn read_request(socket: &mut TcpStream) -> Option<HttpRequest> {
+    let mut request = Vec::new();
+    let mut buf = [0u8; 4096];
+    loop {
+        if let Some(end) = request.windows(4).position(|w| w == b"\r\n\r\n") {
+            let head = String::from_utf8_lossy(&request[..end]).to_lowercase();
+            let length: usize = head
+                .lines()
+                .find_map(|line| line.strip_prefix("content-length:"))
+                .map_or(0, |length| length.trim().parse().unwrap());
+            if request.len() >= end + 4 + length {
+                let body = String::from_utf8_lossy(&request[end + 4..end + 4 + length]);
+                return Some(HttpRequest {
+                    head,
+                    body: body.to_string(),
+                });
+            }
+        }
+        let read = socket.read(&mut buf).await.ok()?;
+        if read == 0 {
+            return None;
+        }
+        request.extend_from_slice(&buf[..read]);
+    }
+}
+
+/// start a websocket node handing the `n`th connection, counted from 0, to `serve(n, ws)`,
+/// returns its address
+pub async fn ws_node<F, Fut>(mut serve: F) -> SocketAddr
+where
+    F: FnMut(usize, WebSocketStream<TcpStream>) -> Fut + Send + 'static,
+    Fut: Future<Output = ()> + Send + 'static,
+{
+    let server = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let address = server.local_addr().unwrap();
+    tokio::spawn(async move {
+        for n in 0.. {
+            let (socket, _) = server.accept().await.unwrap();
+            let Ok(ws) = tokio_tungstenite::accept_async(socket).await else {
+                continue;
+            };
+            tokio::spawn(serve(n, ws));
+        }
+    });
+    address
+}
+
+/// subscription notification carrying a result
+pub fn notification(result: serde_json::Value) -> Message {
+    let notification = serde_json::json!({ "params": { "result": result } });
+    Message::Text(notification.to_string().into())
+}
+
+/// send a notification, ignoring a closed connection
+pub async fn notify(ws: &mut WebSocketStream<TcpStream>, result: serde_json::Value) {
+    let _ = ws.send(notification(result)).await;
+}
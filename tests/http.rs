@@ -0,0 +1,163 @@
+//! Client requests against a local HTTP node.
+mod common;
+
+use common::{HttpNode, HttpResponse};
+use std::time::{Duration, Instant};
+use sui_network_sdk::SuiClient;
+use sui_network_sdk::types::{RetryPolicy, SuiClientConfig, SuiError};
+
+/// chain identifier answered by the nodes
+const CHAIN: &str = "4c78adac";
+
+#[tokio::test]
+async fn http_client_sends_its_headers_on_retries() {
+    // a node requiring an api key, unavailable for the first request
+    let node = HttpNode::start(|n, _| match n {
+        0 => HttpResponse::status("503 Service Unavailable", ""),
+        _ => HttpResponse::result(CHAIN.into()),
+    })
+    .await;
+
+    let mut headers = reqwest::header::HeaderMap::new();
+    headers.insert("x-api-key", "secret".parse().unwrap());
+    let http_client = reqwest::Client::builder()
+        .default_headers(headers)
+        .timeout(Duration::from_secs(5))
+        .build()
+        .unwrap();
+    let config = SuiClientConfig {
+        rpc_url: node.url(),
+        ..Default::default()
+    };
+    let retry_policy = RetryPolicy {
+        base_delay: Duration::from_millis(10),
+        ..Default::default()
+    };
+    let client =
+        SuiClient::new_with_http_client(config, http_client).with_retry_policy(retry_policy);
+    let chain: String = client
+        .request("sui_getChainIdentifier", vec![])
+        .await
+        .unwrap();
+    assert_eq!(chain, CHAIN);
+
+    // the retry went through the injected client as well
+    let requests = node.requests();
+    assert_eq!(requests.len(), 2);
+    for request in &requests {
+        assert_eq!(request.header("x-api-key"), Some("secret"));
+        assert_eq!(request.header("content-type"), Some("application/json"));
+    }
+}
+
+#[tokio::test]
+async fn throttling_is_reported_and_retried_after_retry_after() {
+    // a node throttling the first two requests
+    let node = HttpNode::start(|n, _| match n {
+        0 | 1 => {
+            HttpResponse::status("429 Too Many Requests", "slow down").header("Retry-After", "1")
+        }
+        _ => HttpResponse::result(CHAIN.into()),
+    })
+    .await;
+
+    let client = SuiClient::new_by_rpc_url(node.url()).with_retry_policy(RetryPolicy::none());
+    match client
+        .request::<String>("sui_getChainIdentifier", vec![])
+        .await
+    {
+        Err(SuiError::HttpStatus {
+            status,
+            retry_after,
+            body_snippet,
+        }) => {
+            assert_eq!(status, 429);
+            assert_eq!(retry_after, Some(Duration::from_secs(1)));
+            assert_eq!(body_snippet, "slow down");
+        }
+        other => panic!("unexpected {:?}", other),
+    }
+
+    // the next request is throttled again and retried after Retry-After
+    let client = SuiClient::new_by_rpc_url(node.url()).with_retry_policy(RetryPolicy::default());
+    let started = Instant::now();
+    let chain: String = client
+        .request("sui_getChainIdentifier", vec![])
+        .await
+        .unwrap();
+    assert_eq!(chain, CHAIN);
+    assert!(started.elapsed() >= Duration::from_secs(1));
+}
+
+#[tokio::test]
+async fn resolved_host_reaches_the_pinned_address() {
+    let node = HttpNode::start(|_, _| HttpResponse::result(CHAIN.into())).await;
+
+    // the hostname exists only in the overrides
+    let config = SuiClientConfig {
+        rpc_url: format!("http://fullnode.sui-sdk.invalid:{}", node.address.port()),
+        ..Default::default()
+    };
+    let client = SuiClient::new(config)
+        .resolve_host("fullnode.sui-sdk.invalid", node.address)
+        .and_then(|client| client.prefer_ipv4())
+        .unwrap();
+    let chain: String = client
+        .request("sui_getChainIdentifier", vec![])
+        .await
+        .unwrap();
+    assert_eq!(chain, CHAIN);
+    assert_eq!(
+        node.requests()[0].header("host"),
+        Some(format!("fullnode.sui-sdk.invalid:{}", node.address.port()).as_str())
+    );
+    assert_eq!(
+        client.dns_overrides().host("fullnode.sui-sdk.invalid"),
+        Some(node.address)
+    );
+}
+
+#[tokio::test]
+async fn request_timeout_bounds_the_retries() {
+    // a node taking 300 ms to answer
+    let node = HttpNode::start(|_, _| {
+        HttpResponse::result(CHAIN.into()).delayed(Duration::from_millis(300))
+    })
+    .await;
+
+    // each attempt times out, the default retry policy retries them
+    let config = SuiClientConfig {
+        rpc_url: node.url(),
+        timeout: Some(Duration::from_millis(100)),
+        ..Default::default()
+    };
+    let client = SuiClient::new(config);
+    match client
+        .request::<String>("sui_getChainIdentifier", vec![])
+        .await
+    {
+        Err(e) => assert!(e.is_timeout(), "{}", e),
+        other => panic!("unexpected {:?}", other),
+    }
+
+    // a shorter deadline of the call bounds its retries as well
+    let started = Instant::now();
+    match client
+        .request_with_timeout::<String>(
+            "sui_getChainIdentifier",
+            vec![],
+            Duration::from_millis(150),
+        )
+        .await
+    {
+        Err(SuiError::Timeout(_)) => assert!(started.elapsed() < Duration::from_millis(290)),
+        other => panic!("unexpected {:?}", other),
+    }
+
+    // one call with a longer deadline
+    let chain: String = client
+        .request_with_timeout("sui_getChainIdentifier", vec![], Duration::from_secs(5))
+        .await
+        .unwrap();
+    assert_eq!(chain, CHAIN);
+}
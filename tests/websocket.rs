@@ -0,0 +1,297 @@
+//! Listeners and event streams against a local websocket node.
+mod common;
+
+use common::{notify, ws_node};
+use futures::StreamExt;
+use std::collections::HashSet;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Duration;
+use sui_network_sdk::SuiClient;
+use sui_network_sdk::events::{EventStart, LiveEvents};
+use sui_network_sdk::listener::{
+    Listener, ListenerConfig, ListenerEvent, RawFrame, SharedListener, SubscriptionFilter,
+};
+use sui_network_sdk::transport::{DnsOverrides, MockTransport};
+use sui_network_sdk::types::{EventFilter, EventId, SuiClientConfig};
+use tokio::sync::oneshot;
+use tokio_tungstenite::tungstenite::Message;
+
+#[tokio::test]
+async fn events_from_splices_history_and_live_events() {
+    let event = |n: u64| {
+        serde_json::json!({
+            "id": { "txDigest": format!("T{}", n), "eventSeq": "0" },
+            "type": "0xdee9::clob::OrderFilled",
+            "parsedJson": { "n": n }
+        })
+    };
+    let page = |events: &[u64], next: Option<u64>| {
+        serde_json::json!({
+            "data": events.iter().map(|n| event(*n)).collect::<Vec<_>>(),
+            "nextCursor": next.map(|n| serde_json::json!({ "txDigest": format!("T{}", n), "eventSeq": "0" })),
+            "hasNextPage": false
+        })
+    };
+
+    // the node sends T3 and T4, emitted while the history was read, then T5
+    let address = ws_node(move |_, mut ws| async move {
+        let subscribe = ws.next().await.unwrap().unwrap();
+        assert!(subscribe.to_text().unwrap().contains("OrderFilled"));
+        for n in [3, 4, 5] {
+            notify(&mut ws, event(n)).await;
+        }
+        ws.next().await;
+    })
+    .await;
+
+    let mut history = page(&[1, 2], Some(2));
+    history["hasNextPage"] = true.into();
+    let mock = Arc::new(
+        MockTransport::new()
+            .with_result("suix_queryEvents", history)
+            .with_result("suix_queryEvents", page(&[3], Some(3)))
+            // the last page again, T4 arrived meanwhile
+            .with_result("suix_queryEvents", page(&[3, 4], Some(4))),
+    );
+    let client = SuiClient::new_with_transport(SuiClientConfig::default(), mock.clone());
+    let filter = EventFilter::MoveEventType("0xdee9::clob::OrderFilled".to_string());
+    let start = EventStart::Cursor(EventId {
+        tx_digest: "T0".to_string(),
+        event_seq: 0,
+    });
+    let live = LiveEvents::Listener(SharedListener::new(format!("ws://{}", address)));
+    let events: Vec<_> = client
+        .events_from(filter, start, live)
+        .take(5)
+        .collect()
+        .await;
+    let digests: Vec<String> = events
+        .into_iter()
+        .map(|e| e.unwrap().id.tx_digest)
+        .collect();
+    assert_eq!(digests, ["T1", "T2", "T3", "T4", "T5"]);
+
+    // the catch-up read the last page again, after T2
+    let cursors: Vec<_> = mock
+        .requests()
+        .iter()
+        .map(|r| r.params[1]["txDigest"].clone())
+        .collect();
+    assert_eq!(cursors, ["T0", "T2", "T2"]);
+}
+
+#[tokio::test]
+async fn ordered_events_drop_an_event_arriving_after_its_gap() {
+    // C1 arrives after its gap was reported and is dropped
+    let address = ws_node(|_, mut ws| async move {
+        ws.next().await; // subscribe request
+        for (seq, delay) in [(0, 0), (2, 0), (1, 300)] {
+            tokio::time::sleep(Duration::from_millis(delay)).await;
+            notify(
+                &mut ws,
+                serde_json::json!({ "id": { "txDigest": "C", "eventSeq": seq.to_string() } }),
+            )
+            .await;
+        }
+        ws.close(None).await.unwrap();
+    })
+    .await;
+
+    let listener = Listener::new(format!("ws://{}", address));
+    let mut delivered = Vec::new();
+    listener
+        .listen_events_ordered(Duration::from_millis(100), None, |event| match event {
+            ListenerEvent::Message(event) => {
+                delivered.push(format!("C{}", event["id"]["eventSeq"].as_str().unwrap()))
+            }
+            ListenerEvent::GapDetected { digest, missing } => {
+                delivered.push(format!("gap {} {:?}", digest, missing))
+            }
+            _ => {}
+        })
+        .await
+        .unwrap();
+    assert_eq!(delivered, vec!["C0", "gap C [1]", "C2"]);
+}
+
+#[tokio::test]
+async fn watchdog_restarts_a_silent_subscription() {
+    // a node that delivers one message per connection, then goes silent
+    let address = ws_node(|n, mut ws| async move {
+        ws.next().await; // subscribe request
+        let digest = ["A1", "B2"][n.min(1)];
+        notify(&mut ws, serde_json::json!({ "digest": digest })).await;
+        while let Some(Ok(_)) = ws.next().await {}
+    })
+    .await;
+
+    let listener =
+        SharedListener::new(format!("ws://{}", address)).with_watchdog(Duration::from_millis(200));
+    let mut stream = listener.subscribe(SubscriptionFilter::AllTransactions);
+    let mut seen = Vec::new();
+    while seen.len() < 3 {
+        match stream.next().await.unwrap() {
+            ListenerEvent::Message(tx) => seen.push(tx["digest"].as_str().unwrap().to_string()),
+            ListenerEvent::WatchdogRestart { .. } => seen.push("restart".to_string()),
+            _ => {}
+        }
+    }
+    assert_eq!(seen, vec!["A1", "restart", "B2"]);
+}
+
+#[tokio::test]
+async fn dns_overrides_resolve_the_websocket_host() {
+    let address = ws_node(|_, mut ws| async move {
+        ws.next().await; // subscribe request
+        notify(&mut ws, serde_json::json!({ "digest": "A1" })).await;
+        ws.next().await;
+    })
+    .await;
+
+    // the hostname exists only in the overrides
+    let url = format!("ws://fullnode.sui-sdk.invalid:{}", address.port());
+    let overrides = DnsOverrides::new().resolve_host("fullnode.sui-sdk.invalid", address);
+    let listener = SharedListener::new(url).with_dns_overrides(overrides);
+    let mut stream = listener.subscribe(SubscriptionFilter::AllTransactions);
+    let ListenerEvent::Message(tx) = stream.next().await.unwrap() else {
+        panic!("message expected")
+    };
+    assert_eq!(tx["digest"], "A1");
+}
+
+#[tokio::test]
+async fn panicking_tap_stops_the_subscription_tasks() {
+    let (next, send_next) = oneshot::channel::<()>();
+    let (closed, connection_closed) = oneshot::channel::<()>();
+    let mut connection = Some((send_next, closed));
+    let address = ws_node(move |_, mut ws| {
+        let (send_next, closed) = connection.take().expect("one connection");
+        async move {
+            ws.next().await; // subscribe request
+            notify(&mut ws, serde_json::json!({ "digest": "A1" })).await;
+            send_next.await.unwrap();
+            notify(&mut ws, serde_json::json!({ "digest": "garbled" })).await;
+            // the connection ends once every task of the subscription stopped
+            while let Some(Ok(_)) = ws.next().await {}
+            closed.send(()).unwrap();
+        }
+    })
+    .await;
+
+    let config = ListenerConfig::default()
+        .raw_tap(|frame: &RawFrame| assert!(!frame.text.contains("garbled"), "frame out of sync"));
+    let listener = SharedListener::new(format!("ws://{}", address))
+        .with_config(config)
+        .with_watchdog(Duration::from_secs(60));
+    let mut stream = listener.subscribe(SubscriptionFilter::AllTransactions);
+    let ListenerEvent::Message(tx) = stream.next().await.unwrap() else {
+        panic!("message expected")
+    };
+    assert_eq!(tx["digest"], "A1");
+    let mut tasks = listener.task_names();
+    tasks.sort();
+    assert_eq!(
+        tasks,
+        [
+            "sui-listener/0/keepalive",
+            "sui-listener/0/read-loop",
+            "sui-listener/0/reconnect",
+            "sui-listener/0/watchdog",
+        ]
+    );
+
+    // the read loop panics in the tap
+    next.send(()).unwrap();
+    match stream.next().await.unwrap() {
+        ListenerEvent::InternalError { task, message } => {
+            assert_eq!(task, "sui-listener/0/read-loop");
+            assert_eq!(message, "frame out of sync");
+        }
+        other => panic!("unexpected {:?}", other),
+    }
+    // the other tasks stop: the stream ends and the connection closes
+    let deadline = Duration::from_secs(1);
+    assert!(
+        tokio::time::timeout(deadline, stream.next())
+            .await
+            .unwrap()
+            .is_none()
+    );
+    tokio::time::timeout(deadline, connection_closed)
+        .await
+        .unwrap()
+        .unwrap();
+    assert!(listener.task_names().is_empty());
+    assert_eq!(listener.upstream_count(), 0);
+}
+
+#[tokio::test]
+async fn watch_list_follows_added_and_removed_addresses() {
+    // a node answering each address subscription with one transaction of the address
+    let open = Arc::new(AtomicUsize::new(0));
+    let subscriptions = open.clone();
+    let address = ws_node(move |_, mut ws| {
+        let open = subscriptions.clone();
+        async move {
+            let Some(Ok(Message::Text(request))) = ws.next().await else {
+                return;
+            };
+            open.fetch_add(1, Ordering::SeqCst);
+            let request: serde_json::Value = serde_json::from_str(&request).unwrap();
+            let effects = serde_json::json!({
+                "status": { "status": "success", "error": null },
+                "gasUsed": { "computationCost": "1", "storageCost": "1", "storageRebate": "0" },
+                "transactionDigest": request["params"][0]["ToOrFromAddress"]["addr"]
+            });
+            notify(&mut ws, effects).await;
+            while let Some(Ok(_)) = ws.next().await {}
+            open.fetch_sub(1, Ordering::SeqCst);
+        }
+    })
+    .await;
+
+    let listener = SharedListener::new(format!("ws://{}", address));
+    let (watch, mut transactions) = listener.watch_list();
+
+    // 100 adds and 50 removes, every odd address is removed once the next is added
+    for i in 1..=100u32 {
+        assert!(watch.add_address(&format!("0x{:x}", i)).unwrap());
+        if i % 2 == 0 {
+            assert!(watch.remove_address(&format!("0x{:x}", i - 1)));
+        }
+    }
+    assert!(!watch.add_address("0x2").unwrap());
+    assert!(!watch.remove_address("0x1"));
+    assert!(watch.add_address("0xnot-hex").is_err());
+    assert_eq!(watch.addresses().len(), 50);
+    assert_eq!(listener.upstream_count(), 50);
+
+    // each kept address delivers its transaction, under its own address
+    let mut delivered = HashSet::new();
+    while delivered.len() < 50 {
+        let (address, effects) = transactions.next().await.unwrap();
+        assert_eq!(effects.transaction_digest, address);
+        assert!(watch.addresses().contains(&address));
+        delivered.insert(address);
+    }
+
+    // an address added while the stream waits is delivered too
+    let handle = watch.clone();
+    tokio::spawn(async move { handle.add_address("0xabc").unwrap() });
+    let (address, _) = transactions.next().await.unwrap();
+    assert_eq!(address, format!("0x{:0>64}", "abc"));
+
+    // removed addresses are unsubscribed on the node
+    for address in watch.addresses() {
+        watch.remove_address(&address);
+    }
+    assert_eq!(listener.upstream_count(), 0);
+    tokio::time::timeout(Duration::from_secs(5), async {
+        while open.load(Ordering::SeqCst) > 0 {
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+    })
+    .await
+    .unwrap();
+}